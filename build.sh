@@ -67,6 +67,7 @@ Commands:
   android     Build standalone signed Android APK
   all         Build everything (desktop + standalone APK)
   clean       Remove all build artifacts and intermediates
+  growth-tables  Download the WHO/CDC growth tables and write SHA256SUMS (maintainers)
 
 Options:
   --no-sign      Build without signing (faster, for testing)
@@ -155,7 +156,7 @@ install_linux_deps() {
         build-essential pkg-config perl
         libgtk-3-dev libwebkit2gtk-4.1-dev
         libappindicator3-dev librsvg2-dev patchelf libsoup-3.0-dev
-        libjavascriptcoregtk-4.1-dev libssl-dev unzip
+        libjavascriptcoregtk-4.1-dev libssl-dev unzip libheif-dev
     )
 
     echo ""
@@ -229,6 +230,34 @@ ensure_pdfium() {
     fi
 }
 
+# ── Growth tables ──────────────────────────────────────────────────────────
+# GRW-01: Official WHO/CDC LMS tables, fetched verbatim from the URLs in
+# resources/growth/SOURCES (file<TAB>url per line).
+GROWTH_DIR="$TAURI_DIR/resources/growth"
+
+# GRW-01: The official tables are committed; builds only verify them.
+check_growth_tables() {
+    if ! (cd "$GROWTH_DIR" && sha256sum --quiet -c SHA256SUMS); then
+        log_error "Growth tables missing or modified: run ./build.sh growth-tables and commit the result"
+        return 1
+    fi
+    log_ok "Growth tables verified: $GROWTH_DIR"
+}
+
+# Maintainer command: download the tables listed in SOURCES and record
+# their checksums, for review and commit.
+cmd_growth_tables() {
+    local file url files=()
+    while IFS=$'\t' read -r file url; do
+        [[ -z "$file" || "$file" == \#* ]] && continue
+        log_info "Downloading growth table $file"
+        curl -fsSL -o "$GROWTH_DIR/$file" "$url" || { log_error "Failed to download $url"; exit 1; }
+        files+=("$file")
+    done < "$GROWTH_DIR/SOURCES"
+    (cd "$GROWTH_DIR" && sha256sum "${files[@]}" > SHA256SUMS)
+    log_ok "Growth tables and SHA256SUMS written to $GROWTH_DIR — review and commit them"
+}
+
 stage_pdfium() {
     local lib_name="libpdfium.so"
     case "$(uname -s)" in
@@ -260,6 +289,7 @@ check_dependencies() {
             local linux_missing=()
             pkg-config --exists gtk+-3.0       2>/dev/null || linux_missing+=("libgtk-3-dev")
             pkg-config --exists webkit2gtk-4.1 2>/dev/null || linux_missing+=("libwebkit2gtk-4.1-dev")
+            pkg-config --exists libheif        2>/dev/null || linux_missing+=("libheif-dev")
             command -v patchelf >/dev/null                 || linux_missing+=("patchelf")
             command -v perl    >/dev/null                 || linux_missing+=("perl")
 
@@ -269,6 +299,7 @@ check_dependencies() {
                     local still_missing=false
                     pkg-config --exists gtk+-3.0       2>/dev/null || still_missing=true
                     pkg-config --exists webkit2gtk-4.1 2>/dev/null || still_missing=true
+                    pkg-config --exists libheif        2>/dev/null || still_missing=true
                     command -v patchelf >/dev/null                 || still_missing=true
                     command -v perl    >/dev/null                 || still_missing=true
                     if [[ "$still_missing" == true ]]; then
@@ -484,12 +515,12 @@ build_desktop() {
     log_info "Building bundles: $bundles"
 
     if [[ "$SIGN" == true ]]; then
-        npx tauri build --bundles "$bundles"
+        npx tauri build --bundles "$bundles" --features heic
     else
         # When unsigned, Tauri may error about missing private key if pubkey exists
         # in tauri.conf.json. The bundles are still created before the error.
         set +e
-        npx tauri build --bundles "$bundles" 2>&1
+        npx tauri build --bundles "$bundles" --features heic 2>&1
         local tauri_exit=$?
         set -e
 
@@ -623,6 +654,7 @@ cmd_setup() {
 
     # 4. Ensure PDFium for vision-based PDF extraction (R3)
     ensure_pdfium
+    check_growth_tables
 
     # 5. Check Rust toolchain
     if [[ -n "${CARGO:-}" && -x "$CARGO" ]]; then
@@ -647,6 +679,7 @@ cmd_desktop() {
     detect_prestaged_mobile
     check_dependencies "desktop"
     ensure_pdfium
+    check_growth_tables
     load_credentials
     build_frontend
     if [[ "$SKIP_MOBILE" != true ]]; then
@@ -691,7 +724,7 @@ cmd_all() {
 
 for arg in "$@"; do
     case "$arg" in
-        desktop|android|all|clean|setup) COMMAND="$arg" ;;
+        desktop|android|all|clean|setup|growth-tables) COMMAND="$arg" ;;
         --no-sign)      SIGN=false ;;
         --skip-mobile)  SKIP_MOBILE=true ;;
         --verbose)      VERBOSE=true; set -x ;;
@@ -715,4 +748,5 @@ case "$COMMAND" in
     android) cmd_android ;;
     all)     cmd_all ;;
     clean)   cmd_clean ;;
+    growth-tables) cmd_growth_tables ;;
 esac
@@ -0,0 +1,30 @@
+-- 002_grant_scope_expiry.sql
+-- GRANT-01: Category-scoped, time-limited access grants.
+-- scope: JSON array of entity categories (e.g. ["medications","allergies"]); NULL = all categories.
+-- expires_at: RFC 3339 UTC timestamp; NULL = never expires.
+
+INSERT INTO schema_version (version) VALUES (2);
+
+ALTER TABLE profile_access_grants ADD COLUMN scope TEXT;
+ALTER TABLE profile_access_grants ADD COLUMN expires_at TEXT;
+
+ALTER TABLE device_profile_access ADD COLUMN scope TEXT;
+ALTER TABLE device_profile_access ADD COLUMN expires_at TEXT;
+
+CREATE INDEX idx_profile_access_grants_expiry ON profile_access_grants(expires_at)
+    WHERE expires_at IS NOT NULL AND revoked_at IS NULL;
+
+-- Grant lifecycle audit (app-level: grants outlive any single profile session).
+-- grant_type: 'profile_grant' (grant_ref = grant id) or 'device_access' (grant_ref = device id).
+CREATE TABLE grant_audit_log (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    grant_type  TEXT NOT NULL CHECK (grant_type IN ('profile_grant', 'device_access')),
+    grant_ref   TEXT NOT NULL,
+    profile_id  TEXT NOT NULL,
+    grantee     TEXT NOT NULL,
+    action      TEXT NOT NULL,
+    detail      TEXT,
+    occurred_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_grant_audit_profile ON grant_audit_log(profile_id, occurred_at);
@@ -0,0 +1,29 @@
+-- Migration 024: Field-level provenance for extracted entities.
+-- PROV-01: Links each stored entity field to the page (and approximate region)
+-- of the source document it was read from, for review-screen highlighting.
+
+CREATE TABLE IF NOT EXISTS entity_sources (
+    id TEXT PRIMARY KEY NOT NULL,
+    document_id TEXT NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    entity_type TEXT NOT NULL,
+    entity_id TEXT NOT NULL,
+    field_name TEXT NOT NULL,
+    page_number INTEGER NOT NULL,
+    region_x REAL,
+    region_y REAL,
+    region_width REAL,
+    region_height REAL,
+    region_approximate INTEGER NOT NULL DEFAULT 1,
+    span_start INTEGER,
+    span_end INTEGER,
+    quote TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_entity_sources_document
+    ON entity_sources(document_id);
+
+CREATE INDEX IF NOT EXISTS idx_entity_sources_entity
+    ON entity_sources(entity_id);
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (24, datetime('now'));
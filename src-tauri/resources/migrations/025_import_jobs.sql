@@ -0,0 +1,26 @@
+-- Migration 025: Persistent import queue.
+-- IMQ-01: Import jobs and their state transitions survive an app restart or
+-- crash so interrupted batches resume instead of being silently dropped.
+-- PDF passwords are never stored (PWD-01: memory only).
+
+CREATE TABLE IF NOT EXISTS import_jobs (
+    id TEXT PRIMARY KEY NOT NULL,
+    file_path TEXT NOT NULL,
+    filename TEXT NOT NULL,
+    state TEXT NOT NULL,
+    progress_pct INTEGER NOT NULL DEFAULT 0,
+    document_id TEXT,
+    model_used TEXT,
+    error TEXT,
+    queued_at TEXT NOT NULL,
+    started_at TEXT,
+    completed_at TEXT,
+    user_document_type TEXT,
+    page_paths TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_import_jobs_state
+    ON import_jobs(state);
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (25, datetime('now'));
@@ -0,0 +1,49 @@
+-- Migration 026: Immunization records.
+-- IMM-01: One row per administered dose, extracted from vaccination booklets.
+-- Replaces the single-date `screening_records` vaccine rows for anything that
+-- needs a series position, lot number, injection site or administering professional.
+
+CREATE TABLE IF NOT EXISTS immunizations (
+    id TEXT PRIMARY KEY NOT NULL,
+    vaccine TEXT NOT NULL,
+    dose_number INTEGER,
+    administered_date TEXT,
+    lot_number TEXT,
+    site TEXT,
+    administering_professional_id TEXT REFERENCES professionals(id),
+    document_id TEXT NOT NULL REFERENCES documents(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_immunizations_date
+    ON immunizations(administered_date);
+
+CREATE INDEX IF NOT EXISTS idx_immunizations_document
+    ON immunizations(document_id);
+
+-- ═══════════════════════════════════════════
+-- IMMUNIZATIONS SYNC TRIGGERS
+-- ═══════════════════════════════════════════
+
+INSERT OR IGNORE INTO sync_versions (entity_type, version, updated_at)
+VALUES ('immunizations', 0, datetime('now'));
+
+CREATE TRIGGER IF NOT EXISTS sync_imm_insert AFTER INSERT ON immunizations
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'immunizations';
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_imm_update AFTER UPDATE ON immunizations
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'immunizations';
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_imm_delete AFTER DELETE ON immunizations
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'immunizations';
+END;
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (26, datetime('now'));
@@ -0,0 +1,18 @@
+-- Migration 027: Cross-document lab/vital reconciliation.
+-- REC-01: The same panel often arrives twice (lab PDF + GP letter). Duplicates
+-- are kept for provenance but linked to a primary record via `duplicate_of`;
+-- read paths that feed trends, counts and alerts only see primary rows.
+-- No FK constraint: primaries can be re-extracted (document reprocessing
+-- clears and re-inserts rows), the link is released before deletion.
+
+ALTER TABLE lab_results ADD COLUMN duplicate_of TEXT;
+ALTER TABLE vital_signs ADD COLUMN duplicate_of TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_lab_results_duplicate_of
+    ON lab_results(duplicate_of);
+
+CREATE INDEX IF NOT EXISTS idx_vital_signs_duplicate_of
+    ON vital_signs(duplicate_of);
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (27, datetime('now'));
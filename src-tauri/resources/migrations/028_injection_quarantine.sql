@@ -0,0 +1,12 @@
+-- Migration 028: Prompt-injection quarantine flags.
+-- INJ-01: Documents whose source text contained injection patterns (role
+-- markers, "ignore previous instructions", fake system tags) are flagged so
+-- the review screen can warn the user, and their chunks are fenced off when
+-- assembled into chat context. Rows imported before this migration default
+-- to 0; the RAG layer re-scans chunk text at retrieval time to cover them.
+
+ALTER TABLE documents ADD COLUMN suspicious_content INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE vector_chunks ADD COLUMN suspicious INTEGER NOT NULL DEFAULT 0;
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (28, datetime('now'));
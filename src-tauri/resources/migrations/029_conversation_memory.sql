@@ -0,0 +1,28 @@
+-- Migration 029: Rolling conversation memory.
+-- MEM-01: Long chats overflow the SLM context, so only the last few turns are
+-- sent verbatim. Older turns are folded into a running summary (encrypted with
+-- the profile key; `summarized_through` counts the leading messages it covers)
+-- and statements the patient made about their own health are pinned so they
+-- survive summarisation and reach the night batch extractor as signals.
+
+CREATE TABLE IF NOT EXISTS conversation_memory (
+    conversation_id     TEXT PRIMARY KEY NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    summary             BLOB NOT NULL,          -- AES-256-GCM EncryptedData bytes
+    summarized_through  INTEGER NOT NULL DEFAULT 0,
+    updated_at          TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS pinned_facts (
+    id              TEXT PRIMARY KEY NOT NULL,
+    conversation_id TEXT NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    message_id      TEXT NOT NULL REFERENCES messages(id) ON DELETE CASCADE,
+    fact            TEXT NOT NULL,
+    domain          TEXT,                       -- batch extraction domain, NULL for allergies
+    created_at      TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_pinned_facts_conversation
+    ON pinned_facts(conversation_id);
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (29, datetime('now'));
@@ -0,0 +1,27 @@
+-- Migration 030: Chat export with sources.
+-- EXP-01: Patients bring chat answers to their doctor. `messages.source_chunks`
+-- only keeps document IDs, so the citations, guideline references and grounding
+-- level shown with an answer are persisted alongside it for later export.
+-- Exported conversations (or a selection of their messages) can be attached
+-- to an appointment prep and are appended to the patient copy PDF.
+
+CREATE TABLE IF NOT EXISTS message_sources (
+    message_id          TEXT PRIMARY KEY NOT NULL REFERENCES messages(id) ON DELETE CASCADE,
+    citations           TEXT NOT NULL DEFAULT '[]',   -- JSON array of CitationView
+    guideline_citations TEXT NOT NULL DEFAULT '[]',   -- JSON array of GuidelineCitationView
+    grounding           TEXT                          -- GroundingLevel (High/Moderate/Low/None)
+);
+
+CREATE TABLE IF NOT EXISTS appointment_chat_attachments (
+    id              TEXT PRIMARY KEY NOT NULL,
+    appointment_id  TEXT NOT NULL REFERENCES appointments(id) ON DELETE CASCADE,
+    conversation_id TEXT NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    message_ids     TEXT,                       -- JSON array, NULL = whole conversation
+    attached_at     TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_appointment_chat_attachments_appointment
+    ON appointment_chat_attachments(appointment_id);
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (30, datetime('now'));
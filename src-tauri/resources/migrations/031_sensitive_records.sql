@@ -0,0 +1,33 @@
+-- Migration 031: Sensitive-record tagging.
+-- SENS-01: Psychiatric, sexual-health, genetic and similar records are
+-- flagged so they stay out of the home feed, phone caches, chat context,
+-- the timeline and appointment exports unless explicitly requested.
+-- A flag on a document hides every entity extracted from it; a flag on an
+-- entity hides only that entity. Existing rows default to visible.
+
+ALTER TABLE documents ADD COLUMN sensitive INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE medications ADD COLUMN sensitive INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE lab_results ADD COLUMN sensitive INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE diagnoses ADD COLUMN sensitive INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE allergies ADD COLUMN sensitive INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE procedures ADD COLUMN sensitive INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_documents_sensitive ON documents(sensitive) WHERE sensitive = 1;
+
+-- Chat runs through a background queue, so the "include sensitive records"
+-- override is stored on the conversation the patient enabled it for.
+ALTER TABLE conversations ADD COLUMN include_sensitive INTEGER NOT NULL DEFAULT 0;
+
+-- Entity flags bump their own sync versions through the existing UPDATE
+-- triggers. A document flag changes what phones may cache for every entity
+-- type extracted from it, so bump those versions too.
+CREATE TRIGGER IF NOT EXISTS sync_document_sensitivity_update
+AFTER UPDATE OF sensitive ON documents
+WHEN OLD.sensitive != NEW.sensitive
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type IN ('medications', 'labs', 'profile');
+END;
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (31, datetime('now'));
@@ -0,0 +1,74 @@
+-- Migration 032: Emergency medical ID card.
+-- EMC-01: Emergency contacts plus the patient's lock-screen opt-ins for the
+-- wallet card / signed QR / phone lock-screen summary. Blood type comes from
+-- the profile; allergies, medications and diagnoses from existing tables.
+
+CREATE TABLE emergency_contacts (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    relationship TEXT,
+    phone TEXT NOT NULL,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- Single row. `lock_screen_fields` is a JSON array of the fields the patient
+-- opted in to showing on the phone lock screen (empty = nothing shown).
+-- `signing_key_encrypted` holds the ed25519 seed used to sign QR payloads,
+-- encrypted with the profile key; generated on first use.
+CREATE TABLE emergency_card_settings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    lock_screen_fields TEXT NOT NULL DEFAULT '[]',
+    signing_key_encrypted BLOB,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+INSERT INTO emergency_card_settings (id) VALUES (1);
+
+-- The lock-screen card travels with the profile entity in phone sync.
+CREATE TRIGGER IF NOT EXISTS sync_emergency_contact_insert AFTER INSERT ON emergency_contacts
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'profile';
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_emergency_contact_update AFTER UPDATE ON emergency_contacts
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'profile';
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_emergency_contact_delete AFTER DELETE ON emergency_contacts
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'profile';
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_emergency_fields_update
+AFTER UPDATE OF lock_screen_fields ON emergency_card_settings
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'profile';
+END;
+
+-- Key diagnoses are on the card but had no sync trigger of their own.
+CREATE TRIGGER IF NOT EXISTS sync_emergency_diagnosis_insert AFTER INSERT ON diagnoses
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'profile';
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_emergency_diagnosis_update AFTER UPDATE ON diagnoses
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'profile';
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_emergency_diagnosis_delete AFTER DELETE ON diagnoses
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type = 'profile';
+END;
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (32, datetime('now'));
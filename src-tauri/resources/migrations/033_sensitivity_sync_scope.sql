@@ -0,0 +1,59 @@
+-- Migration 033: Sensitivity flags invalidate cached alerts and timeline.
+-- SENS-01: Alerts and timeline events cite the entities and documents they
+-- were derived from, so hiding or unhiding a record changes what phones may
+-- cache for both. Migration 031 only bumped medications, labs and profile.
+
+DROP TRIGGER IF EXISTS sync_document_sensitivity_update;
+
+CREATE TRIGGER sync_document_sensitivity_update
+AFTER UPDATE OF sensitive ON documents
+WHEN OLD.sensitive != NEW.sensitive
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type IN ('medications', 'labs', 'profile', 'alerts', 'timeline');
+END;
+
+-- Entity flags already bump their own type through the UPDATE triggers;
+-- alerts and timeline need an explicit bump.
+CREATE TRIGGER IF NOT EXISTS sync_medication_sensitivity_update
+AFTER UPDATE OF sensitive ON medications
+WHEN OLD.sensitive != NEW.sensitive
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type IN ('alerts', 'timeline');
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_lab_sensitivity_update
+AFTER UPDATE OF sensitive ON lab_results
+WHEN OLD.sensitive != NEW.sensitive
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type IN ('alerts', 'timeline');
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_diagnosis_sensitivity_update
+AFTER UPDATE OF sensitive ON diagnoses
+WHEN OLD.sensitive != NEW.sensitive
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type IN ('alerts', 'timeline');
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_allergy_sensitivity_update
+AFTER UPDATE OF sensitive ON allergies
+WHEN OLD.sensitive != NEW.sensitive
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type IN ('alerts', 'timeline');
+END;
+
+CREATE TRIGGER IF NOT EXISTS sync_procedure_sensitivity_update
+AFTER UPDATE OF sensitive ON procedures
+WHEN OLD.sensitive != NEW.sensitive
+BEGIN
+    UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+    WHERE entity_type IN ('alerts', 'timeline');
+END;
+
+-- Schema version bump
+INSERT INTO schema_version (version, applied_at) VALUES (33, datetime('now'));
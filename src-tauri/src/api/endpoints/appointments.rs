@@ -68,11 +68,15 @@ pub async fn prep(
     let date = chrono::NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
         .map_err(|_| ApiError::Internal("Invalid appointment date in database".into()))?;
 
+    let date_of_birth = ctx.core.get_date_of_birth(&device.target_profile_id);
+
     let prep = appointment::prepare_appointment_prep(
         &conn,
         &professional_id,
         date,
         &appointment_id,
+        date_of_birth,
+        false,
     )
     .map_err(ApiError::from)?;
 
@@ -45,7 +45,9 @@ pub async fn dashboard(
     let conn = ctx.resolve_db(&device)?;
 
     let stats = home::fetch_profile_stats(&conn).map_err(ApiError::from)?;
-    let recent_documents = home::fetch_recent_documents(&conn, 20, 0).map_err(ApiError::from)?;
+    // SENS-01: Phones never receive sensitive documents in the dashboard feed
+    let recent_documents =
+        home::fetch_recent_documents(&conn, 20, 0, false).map_err(ApiError::from)?;
     let onboarding = home::compute_onboarding(&conn).map_err(ApiError::from)?;
 
     // Critical alerts from trust module
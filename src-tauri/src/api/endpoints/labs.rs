@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::api::error::ApiError;
 use crate::api::types::{ApiContext, DeviceContext};
+use crate::sensitivity;
 
 #[derive(Deserialize)]
 pub struct LabsQuery {
@@ -56,7 +57,7 @@ pub async fn recent(
     let limit = query.limit.unwrap_or(20).min(100);
 
     let mut stmt = conn
-        .prepare(
+        .prepare(&format!(
             "SELECT lr.id, lr.test_name, lr.value, lr.value_text, lr.unit,
                     lr.reference_range_low, lr.reference_range_high,
                     lr.abnormal_flag, lr.collection_date,
@@ -64,12 +65,15 @@ pub async fn recent(
                      WHERE prev.test_name = lr.test_name
                        AND prev.collection_date < lr.collection_date
                        AND prev.value IS NOT NULL
+                       AND prev.duplicate_of IS NULL
                      ORDER BY prev.collection_date DESC
                      LIMIT 1) AS prev_value
              FROM lab_results lr
+             WHERE lr.duplicate_of IS NULL{}
              ORDER BY lr.collection_date DESC
              LIMIT ?1",
-        )
+            sensitivity::entity_filter_sql("lr", false)
+        ))
         .map_err(|e| ApiError::Internal(e.to_string()))?;
 
     let results = stmt
@@ -110,7 +114,7 @@ pub async fn list(
     let limit = query.limit.unwrap_or(500).min(1000);
 
     let mut stmt = conn
-        .prepare(
+        .prepare(&format!(
             "SELECT lr.id, lr.test_name, lr.value, lr.value_text, lr.unit,
                     lr.reference_range_low, lr.reference_range_high,
                     lr.abnormal_flag, lr.collection_date,
@@ -118,12 +122,15 @@ pub async fn list(
                      WHERE prev.test_name = lr.test_name
                        AND prev.collection_date < lr.collection_date
                        AND prev.value IS NOT NULL
+                       AND prev.duplicate_of IS NULL
                      ORDER BY prev.collection_date DESC
                      LIMIT 1) AS prev_value
              FROM lab_results lr
+             WHERE lr.duplicate_of IS NULL{}
              ORDER BY lr.collection_date DESC
              LIMIT ?1",
-        )
+            sensitivity::entity_filter_sql("lr", false)
+        ))
         .map_err(|e| ApiError::Internal(e.to_string()))?;
 
     let results = stmt
@@ -172,18 +179,20 @@ pub async fn history(
 
     let pattern = format!("%{test_name}%");
     let mut stmt = conn
-        .prepare(
+        .prepare(&format!(
             "SELECT lr.value, lr.collection_date,
                     (SELECT prev.value FROM lab_results prev
                      WHERE prev.test_name = lr.test_name
                        AND prev.collection_date < lr.collection_date
                        AND prev.value IS NOT NULL
+                       AND prev.duplicate_of IS NULL
                      ORDER BY prev.collection_date DESC
                      LIMIT 1) AS prev_value
              FROM lab_results lr
-             WHERE LOWER(lr.test_name) LIKE LOWER(?1)
+             WHERE LOWER(lr.test_name) LIKE LOWER(?1) AND lr.duplicate_of IS NULL{}
              ORDER BY lr.collection_date DESC",
-        )
+            sensitivity::entity_filter_sql("lr", false)
+        ))
         .map_err(|e| ApiError::Internal(e.to_string()))?;
 
     let entries = stmt
@@ -13,6 +13,7 @@ use uuid::Uuid;
 use crate::api::error::ApiError;
 use crate::api::types::{ApiContext, DeviceContext};
 use crate::medications;
+use crate::sensitivity::HiddenRecords;
 
 #[derive(Deserialize)]
 pub struct MedListQuery {
@@ -54,7 +55,11 @@ pub async fn list(
         include_otc: query.otc_only.unwrap_or(false),
     };
 
-    let cards = medications::fetch_medications_filtered(&conn, &filter).map_err(ApiError::from)?;
+    let mut cards =
+        medications::fetch_medications_filtered(&conn, &filter).map_err(ApiError::from)?;
+    // SENS-01: Phones never see records flagged sensitive
+    let hidden = HiddenRecords::load(&conn, false).map_err(ApiError::from)?;
+    cards.retain(|c| !hidden.hides_entity(&c.id, None));
     let meds = medications::enrich_medication_cards(&conn, cards);
     let (total_active, total_paused, total_stopped) =
         medications::fetch_medication_status_counts(&conn).map_err(ApiError::from)?;
@@ -96,8 +101,10 @@ pub async fn detail(
     let med_uuid = Uuid::parse_str(&medication_id)
         .map_err(|e| ApiError::BadRequest(format!("Invalid medication ID: {e}")))?;
 
+    let hidden = HiddenRecords::load(&conn, false).map_err(ApiError::from)?;
     let card = medications::fetch_single_medication_card(&conn, &med_uuid)
         .map_err(ApiError::from)?
+        .filter(|c| !hidden.hides_entity(&c.id, None))
         .ok_or_else(|| ApiError::NotFound("Medication not found".into()))?;
 
     let instructions =
@@ -52,14 +52,25 @@ pub async fn delta(
         ),
     );
 
-    let response = sync::build_sync_response(&conn, &request, &profile_name)
+    let response = sync::build_sync_response(&conn, &request, &profile_name, &device.scope)
         .map_err(|e| ApiError::Internal(e.to_string()))?;
 
     ctx.core.update_activity();
 
     match response {
         None => Ok(StatusCode::NO_CONTENT.into_response()),
-        Some(resp) => {
+        Some(mut resp) => {
+            let blood_type = ctx.core.get_blood_type(&device.target_profile_id);
+            sync::attach_lock_screen_card(
+                &conn,
+                &mut resp,
+                &profile_name,
+                ctx.core.get_date_of_birth(&device.target_profile_id),
+                blood_type.as_ref(),
+                &device.scope,
+            )
+            .map_err(|e| ApiError::Internal(e.to_string()))?;
+
             // Log what was sent
             let mut sent_types = Vec::new();
             if resp.medications.is_some() {
@@ -80,6 +91,9 @@ pub async fn delta(
             if resp.profile.is_some() {
                 sent_types.push("profile");
             }
+            if resp.emergency_card.is_some() {
+                sent_types.push("emergency_card");
+            }
 
             ctx.core.log_access(
                 crate::core_state::AccessSource::MobileDevice {
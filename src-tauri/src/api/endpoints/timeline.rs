@@ -43,6 +43,7 @@ pub async fn recent(
         professional_id: None,
         since_appointment_id: None,
         include_dismissed_alerts: None,
+        include_sensitive: None,
     };
 
     let mut data = timeline::get_timeline_data(&conn, &filter).map_err(ApiError::from)?;
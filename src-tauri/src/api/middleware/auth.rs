@@ -4,6 +4,9 @@
 //! DeviceRegistry, rotates the token, resolves profile access via
 //! the 4-rule authorization cascade, and injects `DeviceContext`
 //! into request extensions for downstream handlers.
+//!
+//! GRANT-01: Expired grants are swept before cross-profile checks, and
+//! category-scoped grants only reach the routes of their categories.
 
 use axum::http::{HeaderValue, Request};
 use axum::middleware::Next;
@@ -12,7 +15,7 @@ use uuid::Uuid;
 
 use crate::api::error::ApiError;
 use crate::api::types::{ApiContext, DeviceContext};
-use crate::authorization::{self, AccessLevel};
+use crate::authorization::{self, AccessLevel, AccessScope, EntityCategory};
 
 /// Require a valid bearer token from a paired mobile device.
 ///
@@ -127,9 +130,9 @@ async fn require_auth_inner(
         .unwrap_or(owner_profile_id);
 
     // 6. MP-01: Authorization check (4-rule cascade)
-    let access_level = if owner_profile_id == target_profile_id {
+    let (access_level, scope) = if owner_profile_id == target_profile_id {
         // Own profile — always full access (fast path, no DB lookup needed)
-        AccessLevel::Full
+        (AccessLevel::Full, AccessScope::All)
     } else {
         // Cross-profile access — run authorization cascade
         match check_cross_profile_access(&ctx, &owner_profile_id, &target_profile_id, &device_id) {
@@ -137,7 +140,7 @@ async fn require_auth_inner(
                 if !decision.allowed {
                     return Err(ApiError::Forbidden);
                 }
-                decision.level
+                (decision.level, decision.scope)
             }
             Err(_) => {
                 // Authorization check failed — deny by default
@@ -146,6 +149,11 @@ async fn require_auth_inner(
         }
     };
 
+    // 6b. GRANT-01: Category scope of the requested route
+    if !route_allowed(req.uri().path(), &scope) {
+        return Err(ApiError::Forbidden);
+    }
+
     // 7. Inject expanded device context for downstream handlers
     req.extensions_mut().insert(DeviceContext {
         device_id,
@@ -153,6 +161,7 @@ async fn require_auth_inner(
         owner_profile_id,
         target_profile_id,
         access_level,
+        scope,
     });
 
     // 8. Process request
@@ -207,6 +216,11 @@ fn check_cross_profile_access(
         .open_app_db()
         .map_err(|e| ApiError::Internal(format!("app db: {e}")))?;
 
+    // GRANT-01: Best-effort sweep — expired grants never match either way
+    if let Err(e) = authorization::expire_stale_grants(&app_conn) {
+        tracing::warn!("GRANT-01: Failed to expire stale grants: {e}");
+    }
+
     authorization::check_profile_access(
         &app_conn,
         &ctx.core.profiles_dir,
@@ -216,3 +230,79 @@ fn check_cross_profile_access(
     )
     .map_err(|e| ApiError::Internal(format!("authz: {e}")))
 }
+
+/// GRANT-01: What a protected route needs from the grant scope.
+#[derive(Debug, PartialEq, Eq)]
+enum RouteScope {
+    /// No profile data, or the handler filters by scope itself (sync).
+    Open,
+    /// Reads a single entity category.
+    Category(EntityCategory),
+    /// Mixes categories (dashboard) or is unknown — needs an unscoped grant.
+    Unscoped,
+}
+
+/// Map a protected route path to the scope it requires.
+///
+/// Paths are seen without the `/api` prefix inside the nested router,
+/// but both forms are accepted.
+fn route_scope(path: &str) -> RouteScope {
+    let path = path.strip_prefix("/api").unwrap_or(path);
+    let first = path.trim_start_matches('/').split('/').next().unwrap_or("");
+    match first {
+        "medications" => RouteScope::Category(EntityCategory::Medications),
+        "labs" => RouteScope::Category(EntityCategory::Labs),
+        "alerts" => RouteScope::Category(EntityCategory::Alerts),
+        "appointments" => RouteScope::Category(EntityCategory::Appointments),
+        "timeline" => RouteScope::Category(EntityCategory::Timeline),
+        "journal" => RouteScope::Category(EntityCategory::Journal),
+        "chat" => RouteScope::Category(EntityCategory::Conversations),
+        "documents" => RouteScope::Category(EntityCategory::Documents),
+        "health" | "profiles" | "auth" | "sync" => RouteScope::Open,
+        _ => RouteScope::Unscoped,
+    }
+}
+
+/// Whether a grant scope may reach a route.
+fn route_allowed(path: &str, scope: &AccessScope) -> bool {
+    match route_scope(path) {
+        RouteScope::Open => true,
+        RouteScope::Category(category) => scope.allows(category),
+        RouteScope::Unscoped => scope.is_all(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meds_and_allergies() -> AccessScope {
+        AccessScope::Categories(vec![EntityCategory::Medications, EntityCategory::Allergies])
+    }
+
+    #[test]
+    fn scoped_grant_reaches_only_its_categories() {
+        let scope = meds_and_allergies();
+        assert!(route_allowed("/medications", &scope));
+        assert!(route_allowed("/api/medications/abc", &scope));
+        assert!(!route_allowed("/journal/history", &scope));
+        assert!(!route_allowed("/chat/conversations/abc", &scope));
+        assert!(!route_allowed("/labs/recent", &scope));
+    }
+
+    #[test]
+    fn dashboard_and_unknown_routes_need_unscoped_grant() {
+        let scope = meds_and_allergies();
+        assert!(!route_allowed("/home", &scope));
+        assert!(!route_allowed("/something-new", &scope));
+        assert!(route_allowed("/home", &AccessScope::All));
+    }
+
+    #[test]
+    fn open_routes_ignore_scope() {
+        let empty = AccessScope::Categories(Vec::new());
+        assert!(route_allowed("/health", &empty));
+        assert!(route_allowed("/profiles/accessible", &empty));
+        assert!(route_allowed("/sync", &empty));
+    }
+}
@@ -6,7 +6,7 @@ use std::time::{Duration, Instant};
 
 use uuid::Uuid;
 
-use crate::authorization::AccessLevel;
+use crate::authorization::{AccessLevel, AccessScope, EntityCategory};
 use crate::core_state::CoreState;
 
 /// Grace period for old tokens after rotation (30 seconds).
@@ -86,6 +86,8 @@ pub struct DeviceContext {
     pub target_profile_id: Uuid,
     /// MP-01: Access level for the target profile.
     pub access_level: AccessLevel,
+    /// GRANT-01: Entity categories readable on the target profile.
+    pub scope: AccessScope,
 }
 
 impl DeviceContext {
@@ -93,6 +95,11 @@ impl DeviceContext {
     pub fn can_write(&self) -> bool {
         self.access_level == AccessLevel::Full
     }
+
+    /// GRANT-01: Whether this device may read a category on the target profile.
+    pub fn can_read(&self, category: EntityCategory) -> bool {
+        self.scope.allows(category)
+    }
 }
 
 // ═══════════════════════════════════════════════════════════
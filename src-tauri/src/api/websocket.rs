@@ -432,7 +432,7 @@ async fn handle_chat_query(
                     .map_err(|e| format!("Safety filter error: {e}"))?;
 
                 let (display_text, _confidence, _boundary) = match &filtered.filter_outcome {
-                    FilterOutcome::Passed => (
+                    FilterOutcome::Passed | FilterOutcome::Annotated { .. } => (
                         filtered.text.clone(),
                         filtered.confidence,
                         format!("{:?}", filtered.boundary_check),
@@ -474,9 +474,17 @@ async fn handle_chat_query(
                     )
                     .ok()
                 };
-                manager
+                let response_id = manager
                     .add_response(conv_uuid, &display_text, source_json.as_deref(), filtered.confidence)
                     .map_err(|e| e.to_string())?;
+                // EXP-01: Keep the sources shown with this answer for later export
+                if let Err(e) = crate::chat_export::save_message_sources(
+                    &conn,
+                    &response_id,
+                    &crate::chat_export::MessageSources::from_response(&response),
+                ) {
+                    tracing::warn!(error = %e, "EXP-01: Failed to persist answer sources");
+                }
             }
             None => {
                 // No AI available — send placeholder
@@ -543,6 +551,7 @@ fn try_ws_rag_query(
     demographics: Option<crate::crypto::profile::PatientDemographics>,
 ) -> Option<crate::pipeline::rag::types::RagResponse> {
     use crate::pipeline::rag::ollama::OllamaRagGenerator;
+    use crate::pipeline::rag::memory::ConversationMemory;
     use crate::pipeline::rag::orchestrator::DocumentRagPipeline;
     use crate::pipeline::rag::types::PatientQuery;
     use crate::pipeline::storage::vectordb::SqliteVectorStore;
@@ -553,8 +562,15 @@ fn try_ws_rag_query(
     let vector_store = SqliteVectorStore::new(db_path.to_path_buf(), db_key.copied());
     let embedder = crate::pipeline::storage::embedder::build_embedder();
 
+    // MEM-01: Summary of older turns + pinned facts (best-effort)
+    let memory = ConversationMemory::load(conn, conversation_id, db_key)
+        .map_err(|e| tracing::warn!(error = %e, "MEM-01: Conversation memory unavailable"))
+        .ok();
+
     let pipeline = DocumentRagPipeline::new(&generator, &embedder, &vector_store, conn, registry)
-        .with_demographics(demographics);
+        .with_demographics(demographics)
+        .with_memory(memory)
+        .with_sensitive(crate::commands::chat::includes_sensitive(conn, &conversation_id));
     let query = PatientQuery {
         text: query_text.to_string(),
         conversation_id,
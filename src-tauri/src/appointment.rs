@@ -12,7 +12,12 @@ use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::db::DatabaseError;
+use crate::db::{repository, DatabaseError};
+use crate::invariants::immunization::{compute_due_doses, DueDose, DueStatus};
+use crate::invariants::lab_trends::LabChange;
+use crate::models::Immunization;
+use crate::sensitivity;
+use crate::timeline::fetch_lab_changes;
 
 // ─── Types ────────────────────────────────────────────────────────────────────
 
@@ -22,6 +27,9 @@ pub struct AppointmentRequest {
     pub professional_id: Option<String>,
     pub new_professional: Option<NewProfessional>,
     pub date: String, // YYYY-MM-DD
+    /// SENS-01: Include records flagged sensitive in both copies.
+    #[serde(default)]
+    pub include_sensitive: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,6 +111,10 @@ pub struct ProfessionalCopy {
     pub patient_reported_symptoms: Vec<SymptomSummary>,
     pub observations_for_discussion: Vec<ObservationSummary>,
     pub source_documents: Vec<DocumentReference>,
+    #[serde(default)]
+    pub immunizations: Vec<ImmunizationSummary>,
+    #[serde(default)]
+    pub immunizations_due: Vec<DueImmunizationSummary>,
     pub disclaimer: String,
 }
 
@@ -139,6 +151,12 @@ pub struct LabSummary {
     pub reference_range: String,
     pub abnormal_flag: String,
     pub date: String,
+    /// LTR-01: e.g. "-18.2% since 2025-10-01 (significant)".
+    #[serde(default)]
+    pub change_since_last: Option<String>,
+    /// LTR-01: Change exceeds the reference change value.
+    #[serde(default)]
+    pub significant_change: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -156,6 +174,22 @@ pub struct ObservationSummary {
     pub source: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImmunizationSummary {
+    pub vaccine: String,
+    pub dose_number: Option<u32>,
+    pub date: String,
+    pub lot_number: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueImmunizationSummary {
+    pub vaccine: String,
+    pub dose_number: u32,
+    pub due_date: String,
+    pub status: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocumentReference {
     pub document_type: String,
@@ -198,6 +232,8 @@ struct PrepData {
     labs: Vec<RecentLab>,
     symptoms: Vec<RecentSymptom>,
     source_docs: Vec<SourceDoc>,
+    immunizations: Vec<Immunization>,
+    due_doses: Vec<DueDose>,
 }
 
 struct ActiveMedication {
@@ -217,6 +253,7 @@ struct MedChange {
 }
 
 struct RecentLab {
+    id: String,
     test_name: String,
     value: String,
     unit: String,
@@ -224,6 +261,7 @@ struct RecentLab {
     range_high: String,
     abnormal_flag: String,
     collection_date: String,
+    change: Option<LabChange>,
 }
 
 struct RecentSymptom {
@@ -373,10 +411,16 @@ fn update_last_seen(conn: &Connection, professional_id: &str, date: &NaiveDate)
 }
 
 /// Assembles all data needed for appointment prep from the database.
+///
+/// `date_of_birth` enables the immunization schedule check; without it only
+/// the recorded doses are listed. Records flagged sensitive (SENS-01) are
+/// left out unless `include_sensitive` is set.
 fn assemble_prep_data(
     conn: &Connection,
     professional_id: &str,
     appointment_date: NaiveDate,
+    date_of_birth: Option<NaiveDate>,
+    include_sensitive: bool,
 ) -> Result<PrepData, DatabaseError> {
     // Fetch professional name + specialty
     let (prof_name, prof_specialty): (String, Option<String>) = conn.query_row(
@@ -406,19 +450,29 @@ fn assemble_prep_data(
     let since_str = since_date.to_string();
 
     // Active medications with prescriber names
-    let medications = fetch_active_medications(conn)?;
+    let medications = fetch_active_medications(conn, include_sensitive)?;
 
     // Medication changes since last visit (new meds + dose changes)
-    let med_changes = fetch_medication_changes(conn, &since_str)?;
+    let med_changes = fetch_medication_changes(conn, &since_str, include_sensitive)?;
 
-    // Recent lab results since last visit
-    let labs = fetch_recent_labs(conn, &since_str)?;
+    // Recent lab results since last visit, compared against full history
+    let mut labs = fetch_recent_labs(conn, &since_str, include_sensitive)?;
+    let mut changes = fetch_lab_changes(conn)?;
+    for lab in &mut labs {
+        lab.change = changes.remove(&lab.id);
+    }
 
     // Symptoms since last visit
     let symptoms = fetch_recent_symptoms(conn, &since_str)?;
 
     // Source documents since last visit
-    let source_docs = fetch_source_documents(conn, &since_str)?;
+    let source_docs = fetch_source_documents(conn, &since_str, include_sensitive)?;
+
+    // Full immunization history (not limited to the last visit) + due doses
+    let immunizations = repository::get_all_immunizations(conn)?;
+    let due_doses = date_of_birth
+        .map(|dob| compute_due_doses(dob, &immunizations, appointment_date))
+        .unwrap_or_default();
 
     Ok(PrepData {
         professional_name: prof_name,
@@ -430,17 +484,23 @@ fn assemble_prep_data(
         labs,
         symptoms,
         source_docs,
+        immunizations,
+        due_doses,
     })
 }
 
-fn fetch_active_medications(conn: &Connection) -> Result<Vec<ActiveMedication>, DatabaseError> {
-    let mut stmt = conn.prepare(
+fn fetch_active_medications(
+    conn: &Connection,
+    include_sensitive: bool,
+) -> Result<Vec<ActiveMedication>, DatabaseError> {
+    let mut stmt = conn.prepare(&format!(
         "SELECT m.generic_name, m.dose, m.frequency, COALESCE(p.name, 'Unknown'), m.start_date
          FROM medications m
          LEFT JOIN professionals p ON m.prescriber_id = p.id
-         WHERE m.status = 'active'
+         WHERE m.status = 'active'{}
          ORDER BY m.start_date DESC",
-    )?;
+        sensitivity::entity_filter_sql("m", include_sensitive)
+    ))?;
 
     let rows = stmt.query_map([], |row| {
         Ok(ActiveMedication {
@@ -458,16 +518,18 @@ fn fetch_active_medications(conn: &Connection) -> Result<Vec<ActiveMedication>,
 fn fetch_medication_changes(
     conn: &Connection,
     since_date: &str,
+    include_sensitive: bool,
 ) -> Result<Vec<MedChange>, DatabaseError> {
+    let sensitivity_clause = sensitivity::entity_filter_sql("m", include_sensitive);
     // New medications started since last visit
     let mut new_meds: Vec<MedChange> = Vec::new();
     {
-        let mut stmt = conn.prepare(
-            "SELECT generic_name, dose, start_date
-             FROM medications
-             WHERE start_date >= ?1
-             ORDER BY start_date DESC",
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT m.generic_name, m.dose, m.start_date
+             FROM medications m
+             WHERE m.start_date >= ?1{sensitivity_clause}
+             ORDER BY m.start_date DESC"
+        ))?;
         let rows = stmt.query_map(params![since_date], |row| {
             let name: String = row.get(0)?;
             let dose: String = row.get(1)?;
@@ -487,13 +549,13 @@ fn fetch_medication_changes(
 
     // Dose changes since last visit
     {
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             "SELECT m.generic_name, dc.old_dose, dc.new_dose, dc.change_date
              FROM dose_changes dc
              JOIN medications m ON dc.medication_id = m.id
-             WHERE dc.change_date >= ?1
-             ORDER BY dc.change_date DESC",
-        )?;
+             WHERE dc.change_date >= ?1{sensitivity_clause}
+             ORDER BY dc.change_date DESC"
+        ))?;
         let rows = stmt.query_map(params![since_date], |row| {
             Ok(MedChange {
                 medication_name: row.get(0)?,
@@ -514,25 +576,29 @@ fn fetch_medication_changes(
 fn fetch_recent_labs(
     conn: &Connection,
     since_date: &str,
+    include_sensitive: bool,
 ) -> Result<Vec<RecentLab>, DatabaseError> {
-    let mut stmt = conn.prepare(
-        "SELECT test_name, COALESCE(CAST(value AS TEXT), value_text, ''),
-                COALESCE(unit, ''), COALESCE(CAST(reference_range_low AS TEXT), ''),
-                COALESCE(CAST(reference_range_high AS TEXT), ''), abnormal_flag, collection_date
-         FROM lab_results
-         WHERE collection_date >= ?1
-         ORDER BY collection_date DESC",
-    )?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT l.id, l.test_name, COALESCE(CAST(l.value AS TEXT), l.value_text, ''),
+                COALESCE(l.unit, ''), COALESCE(CAST(l.reference_range_low AS TEXT), ''),
+                COALESCE(CAST(l.reference_range_high AS TEXT), ''), l.abnormal_flag, l.collection_date
+         FROM lab_results l
+         WHERE l.collection_date >= ?1 AND l.duplicate_of IS NULL{}
+         ORDER BY l.collection_date DESC",
+        sensitivity::entity_filter_sql("l", include_sensitive)
+    ))?;
 
     let rows = stmt.query_map(params![since_date], |row| {
         Ok(RecentLab {
-            test_name: row.get(0)?,
-            value: row.get(1)?,
-            unit: row.get(2)?,
-            range_low: row.get(3)?,
-            range_high: row.get(4)?,
-            abnormal_flag: row.get(5)?,
-            collection_date: row.get(6)?,
+            id: row.get(0)?,
+            test_name: row.get(1)?,
+            value: row.get(2)?,
+            unit: row.get(3)?,
+            range_low: row.get(4)?,
+            range_high: row.get(5)?,
+            abnormal_flag: row.get(6)?,
+            collection_date: row.get(7)?,
+            change: None,
         })
     })?;
 
@@ -567,15 +633,17 @@ fn fetch_recent_symptoms(
 fn fetch_source_documents(
     conn: &Connection,
     since_date: &str,
+    include_sensitive: bool,
 ) -> Result<Vec<SourceDoc>, DatabaseError> {
-    let mut stmt = conn.prepare(
+    let mut stmt = conn.prepare(&format!(
         "SELECT d.type, d.ingestion_date, COALESCE(p.name, 'Unknown')
          FROM documents d
          LEFT JOIN professionals p ON 1=0
-         WHERE d.ingestion_date >= ?1
+         WHERE d.ingestion_date >= ?1{}
          ORDER BY d.ingestion_date DESC
          LIMIT 20",
-    )?;
+        sensitivity::document_filter_sql("d", include_sensitive)
+    ))?;
 
     let rows = stmt.query_map(params![since_date], |row| {
         Ok(SourceDoc {
@@ -650,6 +718,8 @@ fn build_professional_copy(data: &PrepData) -> ProfessionalCopy {
             reference_range: range,
             abnormal_flag: l.abnormal_flag.clone(),
             date: l.collection_date.clone(),
+            change_since_last: l.change.as_ref().map(LabChange::describe),
+            significant_change: l.change.as_ref().is_some_and(|c| c.significant),
         }
     }).collect();
 
@@ -675,6 +745,24 @@ fn build_professional_copy(data: &PrepData) -> ProfessionalCopy {
         }
     }).collect();
 
+    let immunizations = data.immunizations.iter().map(|i| {
+        ImmunizationSummary {
+            vaccine: i.vaccine.clone(),
+            dose_number: i.dose_number,
+            date: i.administered_date.map(|d| d.to_string()).unwrap_or_else(|| "undated".into()),
+            lot_number: i.lot_number.clone(),
+        }
+    }).collect();
+
+    let immunizations_due = data.due_doses.iter().map(|d| {
+        DueImmunizationSummary {
+            vaccine: d.series.label.en.into(),
+            dose_number: d.dose_number,
+            due_date: d.due_date.to_string(),
+            status: due_status_label(d.status).into(),
+        }
+    }).collect();
+
     ProfessionalCopy {
         header,
         current_medications,
@@ -684,6 +772,8 @@ fn build_professional_copy(data: &PrepData) -> ProfessionalCopy {
         // Observations deferred — coherence_observations table not in SQLite
         observations_for_discussion: Vec::new(),
         source_documents,
+        immunizations,
+        immunizations_due,
         disclaimer: "This summary is AI-generated from patient-loaded documents. \
                      It is not a clinical record and should not replace professional assessment."
             .into(),
@@ -700,7 +790,7 @@ fn build_patient_copy(data: &PrepData) -> PatientCopy {
     );
 
     // Priority items from critical lab results
-    let priority_items: Vec<PrepItem> = data.labs.iter()
+    let mut priority_items: Vec<PrepItem> = data.labs.iter()
         .filter(|l| l.abnormal_flag == "critical_low" || l.abnormal_flag == "critical_high")
         .map(|l| PrepItem {
             text: format!(
@@ -712,6 +802,20 @@ fn build_patient_copy(data: &PrepData) -> PatientCopy {
         })
         .collect();
 
+    // Overdue vaccines are worth raising even when nothing else is pressing
+    priority_items.extend(
+        data.due_doses.iter()
+            .filter(|d| d.status == DueStatus::Overdue)
+            .map(|d| PrepItem {
+                text: format!(
+                    "Your {} vaccine (dose {}) was due on {}. Ask whether you should catch up.",
+                    d.series.label.en, d.dose_number, d.due_date
+                ),
+                source: format!("Vaccination schedule ({})", d.series.source),
+                priority: "Important".into(),
+            }),
+    );
+
     // Template-based questions from patient data
     let mut questions: Vec<PrepQuestion> = Vec::new();
 
@@ -782,7 +886,22 @@ fn build_patient_copy(data: &PrepData) -> PatientCopy {
         });
     }
 
-    // Q5: General follow-up
+    // Q5: Vaccines due or coming up
+    if !data.due_doses.is_empty() {
+        let names: Vec<&str> = data.due_doses.iter()
+            .map(|d| d.series.label.en)
+            .collect();
+        questions.push(PrepQuestion {
+            question: format!(
+                "My vaccination record suggests {} may be due. Can we review my vaccinations?",
+                names.join(", ")
+            ),
+            context: "Due or overdue doses from the age-based schedule".into(),
+            relevance_score: 0.7,
+        });
+    }
+
+    // Q6: General follow-up
     if questions.len() < 5 {
         questions.push(PrepQuestion {
             question: "Is there anything from my records that you'd like to discuss or follow up on?"
@@ -842,6 +961,14 @@ fn build_patient_copy(data: &PrepData) -> PatientCopy {
     }
 }
 
+fn due_status_label(status: DueStatus) -> &'static str {
+    match status {
+        DueStatus::Upcoming => "upcoming",
+        DueStatus::Due => "due",
+        DueStatus::Overdue => "overdue",
+    }
+}
+
 fn severity_label(severity: u8) -> &'static str {
     match severity {
         1 => "minimal",
@@ -859,8 +986,16 @@ pub fn prepare_appointment_prep(
     professional_id: &str,
     appointment_date: NaiveDate,
     appointment_id: &str,
+    date_of_birth: Option<NaiveDate>,
+    include_sensitive: bool,
 ) -> Result<AppointmentPrep, DatabaseError> {
-    let data = assemble_prep_data(conn, professional_id, appointment_date)?;
+    let data = assemble_prep_data(
+        conn,
+        professional_id,
+        appointment_date,
+        date_of_birth,
+        include_sensitive,
+    )?;
 
     let patient_copy = build_patient_copy(&data);
     let professional_copy = build_professional_copy(&data);
@@ -934,6 +1069,7 @@ pub fn list_appointments(conn: &Connection) -> Result<Vec<StoredAppointment>, Da
 
 // ─── PDF generation ───────────────────────────────────────────────────────────
 
+use crate::chat_export::{write_chat_entries, ChatExport};
 use printpdf::*;
 use std::io::BufWriter;
 use std::path::{Path, PathBuf};
@@ -945,7 +1081,8 @@ const PAGE_TOP_Y: f32 = 280.0;
 const PAGE_BOTTOM_MARGIN: f32 = 20.0;
 
 /// Handles multi-page PDF rendering with automatic page breaks.
-struct PdfWriter {
+/// Shared with EXP-01 chat export.
+pub(crate) struct PdfWriter {
     doc: PdfDocumentReference,
     font: IndirectFontRef,
     bold: IndirectFontRef,
@@ -956,7 +1093,7 @@ struct PdfWriter {
 }
 
 impl PdfWriter {
-    fn new(title: &str) -> Result<Self, DatabaseError> {
+    pub(crate) fn new(title: &str) -> Result<Self, DatabaseError> {
         let (doc, page1, layer1) =
             PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
         let font = doc
@@ -992,40 +1129,40 @@ impl PdfWriter {
     }
 
     /// Ensures at least `needed_mm` of vertical space remains, adding a page if not.
-    fn ensure_space(&mut self, needed_mm: f32) {
+    pub(crate) fn ensure_space(&mut self, needed_mm: f32) {
         if self.y.0 - needed_mm < PAGE_BOTTOM_MARGIN {
             self.new_page();
         }
     }
 
     /// Write bold heading text.
-    fn heading(&mut self, text: &str, size: f32, x_mm: f32) {
+    pub(crate) fn heading(&mut self, text: &str, size: f32, x_mm: f32) {
         self.ensure_space(size);
         self.current_layer
             .use_text(text, size, Mm(x_mm), self.y, &self.bold);
     }
 
     /// Write regular text.
-    fn text(&mut self, text: &str, size: f32, x_mm: f32) {
+    pub(crate) fn text(&mut self, text: &str, size: f32, x_mm: f32) {
         self.ensure_space(size);
         self.current_layer
             .use_text(text, size, Mm(x_mm), self.y, &self.font);
     }
 
     /// Write monospace text.
-    fn mono(&mut self, text: &str, size: f32, x_mm: f32) {
+    pub(crate) fn mono(&mut self, text: &str, size: f32, x_mm: f32) {
         self.ensure_space(size);
         self.current_layer
             .use_text(text, size, Mm(x_mm), self.y, &self.courier);
     }
 
     /// Advance cursor downward.
-    fn advance(&mut self, mm: f32) {
+    pub(crate) fn advance(&mut self, mm: f32) {
         self.y -= Mm(mm);
     }
 
     /// Write wrapped text lines with auto page-break.
-    fn write_wrapped(&mut self, text: &str, max_chars: usize, size: f32, x_mm: f32, line_spacing: f32) {
+    pub(crate) fn write_wrapped(&mut self, text: &str, max_chars: usize, size: f32, x_mm: f32, line_spacing: f32) {
         for line in wrap_text(text, max_chars) {
             self.ensure_space(line_spacing);
             self.current_layer
@@ -1035,7 +1172,7 @@ impl PdfWriter {
     }
 
     /// Finalize and return PDF bytes.
-    fn finish(self) -> Result<Vec<u8>, DatabaseError> {
+    pub(crate) fn finish(self) -> Result<Vec<u8>, DatabaseError> {
         let mut buf = BufWriter::new(Vec::new());
         self.doc
             .save(&mut buf)
@@ -1047,6 +1184,14 @@ impl PdfWriter {
 
 /// Generates a PDF from the patient copy. Returns PDF bytes.
 pub fn generate_patient_pdf(copy: &PatientCopy) -> Result<Vec<u8>, DatabaseError> {
+    generate_patient_pdf_with_chats(copy, &[])
+}
+
+/// EXP-01: Patient copy PDF with attached chat exports appended.
+pub fn generate_patient_pdf_with_chats(
+    copy: &PatientCopy,
+    chats: &[ChatExport],
+) -> Result<Vec<u8>, DatabaseError> {
     let mut w = PdfWriter::new(&copy.title)?;
 
     // Title
@@ -1098,6 +1243,15 @@ pub fn generate_patient_pdf(copy: &PatientCopy) -> Result<Vec<u8>, DatabaseError
         }
     }
 
+    // EXP-01: Attached chat answers with their sources
+    for chat in chats {
+        w.advance(8.0);
+        w.heading(&format!("FROM MY CHAT: {}", chat.title), 11.0, 20.0);
+        w.advance(7.0);
+        write_chat_entries(&mut w, chat);
+        w.write_wrapped(&chat.disclaimer, 90, 7.0, 20.0, 3.5);
+    }
+
     // Reminder
     w.advance(8.0);
     w.heading(&copy.reminder, 10.0, 20.0);
@@ -1152,11 +1306,14 @@ pub fn generate_professional_pdf(copy: &ProfessionalCopy) -> Result<Vec<u8>, Dat
         w.heading("LAB RESULTS:", 11.0, 20.0);
         w.advance(6.0);
         for l in &copy.lab_results {
-            let text = format!(
+            let mut text = format!(
                 "  {}: {} {} (ref: {}) [{}] — {}",
                 l.test_name, l.value, l.unit, l.reference_range,
                 l.abnormal_flag.to_uppercase(), l.date
             );
+            if let Some(change) = &l.change_since_last {
+                text.push_str(&format!(" ({change})"));
+            }
             w.mono(&text, 8.0, 25.0);
             w.advance(4.0);
         }
@@ -1190,6 +1347,35 @@ pub fn generate_professional_pdf(copy: &ProfessionalCopy) -> Result<Vec<u8>, Dat
         w.advance(4.0);
     }
 
+    // Immunizations
+    if !copy.immunizations.is_empty() {
+        w.heading("IMMUNIZATIONS:", 11.0, 20.0);
+        w.advance(6.0);
+        for i in &copy.immunizations {
+            let dose = i.dose_number.map(|n| format!(" #{n}")).unwrap_or_default();
+            let lot = i.lot_number.as_deref().map(|l| format!(" (lot {l})")).unwrap_or_default();
+            let text = format!("  · {}{} — {}{}", i.vaccine, dose, i.date, lot);
+            w.mono(&text, 8.0, 25.0);
+            w.advance(4.0);
+        }
+        w.advance(4.0);
+    }
+
+    // Vaccines due per schedule
+    if !copy.immunizations_due.is_empty() {
+        w.heading("VACCINES DUE:", 11.0, 20.0);
+        w.advance(6.0);
+        for d in &copy.immunizations_due {
+            let text = format!(
+                "  · {} dose {} — {} [{}]",
+                d.vaccine, d.dose_number, d.due_date, d.status.to_uppercase()
+            );
+            w.mono(&text, 8.0, 25.0);
+            w.advance(4.0);
+        }
+        w.advance(4.0);
+    }
+
     // Source documents
     if !copy.source_documents.is_empty() {
         w.heading("SOURCE DOCUMENTS:", 11.0, 20.0);
@@ -1383,7 +1569,7 @@ mod tests {
     fn test_assemble_prep_data() {
         let conn = setup_db();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, "prof-1", date).unwrap();
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
 
         assert_eq!(data.professional_name, "Dr. Chen");
         assert_eq!(data.professional_specialty, "GP");
@@ -1399,6 +1585,20 @@ mod tests {
         assert_eq!(data.symptoms.len(), 1);
     }
 
+    #[test]
+    fn test_assemble_prep_data_hides_sensitive() {
+        let conn = setup_db();
+        conn.execute("UPDATE lab_results SET sensitive = 1 WHERE id = 'lab-1'", []).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
+
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
+        assert_eq!(data.labs.len(), 1);
+        assert_eq!(data.labs[0].test_name, "Creatinine");
+
+        let data = assemble_prep_data(&conn, "prof-1", date, None, true).unwrap();
+        assert_eq!(data.labs.len(), 2);
+    }
+
     #[test]
     fn test_assemble_prep_data_first_visit() {
         let conn = setup_db();
@@ -1410,7 +1610,7 @@ mod tests {
         };
         let prof_id = create_professional(&conn, &new_prof).unwrap();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, &prof_id, date).unwrap();
+        let data = assemble_prep_data(&conn, &prof_id, date, None, false).unwrap();
 
         // Since date should be 2000-01-01 (no previous visit fallback)
         assert_eq!(data.since_date, NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
@@ -1422,7 +1622,7 @@ mod tests {
     fn test_build_professional_copy() {
         let conn = setup_db();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, "prof-1", date).unwrap();
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
         let copy = build_professional_copy(&data);
 
         assert_eq!(copy.header.title, "COHEARA PATIENT SUMMARY");
@@ -1444,7 +1644,7 @@ mod tests {
     fn test_professional_copy_recent_changes_flagged() {
         let conn = setup_db();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, "prof-1", date).unwrap();
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
         let copy = build_professional_copy(&data);
 
         // Lisinopril had a dose change since last visit
@@ -1457,7 +1657,7 @@ mod tests {
     fn test_professional_copy_lab_abnormal_flags() {
         let conn = setup_db();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, "prof-1", date).unwrap();
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
         let copy = build_professional_copy(&data);
 
         let potassium = copy.lab_results.iter()
@@ -1473,7 +1673,7 @@ mod tests {
     fn test_build_patient_copy() {
         let conn = setup_db();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, "prof-1", date).unwrap();
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
         let copy = build_patient_copy(&data);
 
         assert!(copy.title.contains("Dr. Chen"));
@@ -1494,7 +1694,7 @@ mod tests {
     fn test_patient_questions_include_medication_changes() {
         let conn = setup_db();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, "prof-1", date).unwrap();
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
         let copy = build_patient_copy(&data);
 
         let has_med_question = copy.questions.iter()
@@ -1506,7 +1706,7 @@ mod tests {
     fn test_patient_questions_include_symptoms() {
         let conn = setup_db();
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
-        let data = assemble_prep_data(&conn, "prof-1", date).unwrap();
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
         let copy = build_patient_copy(&data);
 
         let has_symptom_question = copy.questions.iter()
@@ -1609,7 +1809,7 @@ mod tests {
         let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
         let appt_id = create_appointment(&conn, "prof-1", &date).unwrap();
 
-        let prep = prepare_appointment_prep(&conn, "prof-1", date, &appt_id).unwrap();
+        let prep = prepare_appointment_prep(&conn, "prof-1", date, &appt_id, None, false).unwrap();
 
         assert_eq!(prep.professional_name, "Dr. Chen");
         assert_eq!(prep.appointment_date, "2026-02-20");
@@ -1625,6 +1825,34 @@ mod tests {
         assert!(generated);
     }
 
+    #[test]
+    fn test_prepare_appointment_prep_immunizations() {
+        let conn = setup_db();
+        conn.execute(
+            "INSERT INTO immunizations (id, vaccine, dose_number, administered_date, document_id)
+             VALUES ('imm-1', 'DTaP', 1, '2024-03-01', 'doc-seed')",
+            [],
+        ).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
+        let dob = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        // Without a date of birth, doses are listed but no schedule is applied
+        let data = assemble_prep_data(&conn, "prof-1", date, None, false).unwrap();
+        let copy = build_professional_copy(&data);
+        assert_eq!(copy.immunizations.len(), 1);
+        assert_eq!(copy.immunizations[0].dose_number, Some(1));
+        assert!(copy.immunizations_due.is_empty());
+
+        let appt_id = create_appointment(&conn, "prof-1", &date).unwrap();
+        let prep = prepare_appointment_prep(&conn, "prof-1", date, &appt_id, Some(dob), false).unwrap();
+        let dtp = prep.professional_copy.immunizations_due.iter()
+            .find(|d| d.vaccine == "Diphtheria, tetanus, pertussis")
+            .expect("second DTP dose should be due");
+        assert_eq!(dtp.dose_number, 2);
+        assert_eq!(dtp.status, "overdue");
+        assert!(prep.patient_copy.priority_items.iter().any(|p| p.priority == "Important"));
+    }
+
     #[test]
     fn test_pdf_patient_generation() {
         let copy = PatientCopy {
@@ -1684,10 +1912,14 @@ mod tests {
                 reference_range: "3.5-5.0".into(),
                 abnormal_flag: "critical_high".into(),
                 date: "2026-01-10".into(),
+                change_since_last: Some("+16.0% since 2025-10-10 (significant)".into()),
+                significant_change: true,
             }],
             patient_reported_symptoms: vec![],
             observations_for_discussion: vec![],
             source_documents: vec![],
+            immunizations: vec![],
+            immunizations_due: vec![],
             disclaimer: "Not a clinical record.".into(),
         };
 
@@ -1742,7 +1974,7 @@ mod tests {
         let prof_id = create_professional(&conn, &new_prof).unwrap();
         let date = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
         let appt_id = create_appointment(&conn, &prof_id, &date).unwrap();
-        let prep = prepare_appointment_prep(&conn, &prof_id, date, &appt_id).unwrap();
+        let prep = prepare_appointment_prep(&conn, &prof_id, date, &appt_id, None, false).unwrap();
 
         assert_eq!(prep.professional_name, "Dr. Moreau");
         assert_eq!(prep.professional_specialty, "Cardiologist");
@@ -1851,6 +2083,8 @@ mod tests {
                 reference_range: "3.5-5.0".into(),
                 abnormal_flag: if i % 3 == 0 { "high".into() } else { "normal".into() },
                 date: format!("2026-01-{:02}", (i % 28) + 1),
+                change_since_last: None,
+                significant_change: false,
             })
             .collect();
 
@@ -1899,6 +2133,8 @@ mod tests {
                     date: "2026-01-10".into(),
                 },
             ],
+            immunizations: vec![],
+            immunizations_due: vec![],
             disclaimer: "This document was generated by Coheara AI. It is not a clinical record and should not be used as a substitute for professional medical judgement. All data is patient-reported or extracted from uploaded documents.".into(),
         };
 
@@ -1957,6 +2193,8 @@ mod tests {
                 },
             ],
             source_documents: vec![],
+            immunizations: vec![],
+            immunizations_due: vec![],
             disclaimer: "Not a clinical record.".into(),
         };
 
@@ -1980,6 +2218,8 @@ mod tests {
             patient_reported_symptoms: vec![],
             observations_for_discussion: vec![],
             source_documents: vec![],
+            immunizations: vec![],
+            immunizations_due: vec![],
             disclaimer: "Not a clinical record.".into(),
         };
 
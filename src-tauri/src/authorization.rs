@@ -8,14 +8,20 @@
 //! 5. Default → DENY
 //!
 //! Default-deny, checked in order. Unidirectional: Alice grants Bob != Bob grants Alice.
+//!
+//! GRANT-01: Grants (rules 3 and 4) may be limited to entity categories and
+//! carry an expiry. Expired grants never match; `expire_stale_grants` revokes
+//! them for good and records an audit entry.
 
 use std::path::Path;
 
+use chrono::Utc;
 use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::crypto::profile::{list_profiles, ProfileInfo};
-use crate::db::repository::device_registry;
+use crate::db::repository::device_registry::{self, ActiveGrant, ExpiredGrant};
 
 // ═══════════════════════════════════════════════════════════
 // Types
@@ -47,6 +53,126 @@ impl AccessLevel {
     }
 }
 
+/// GRANT-01: Entity category a grant can be scoped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityCategory {
+    Medications,
+    Allergies,
+    Labs,
+    Immunizations,
+    Appointments,
+    Alerts,
+    Timeline,
+    Documents,
+    Journal,
+    Conversations,
+}
+
+impl EntityCategory {
+    /// Every category, in display order.
+    pub const ALL: [EntityCategory; 10] = [
+        Self::Medications,
+        Self::Allergies,
+        Self::Labs,
+        Self::Immunizations,
+        Self::Appointments,
+        Self::Alerts,
+        Self::Timeline,
+        Self::Documents,
+        Self::Journal,
+        Self::Conversations,
+    ];
+
+    /// Parse from database string representation.
+    pub fn from_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+
+    /// Database string representation.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Medications => "medications",
+            Self::Allergies => "allergies",
+            Self::Labs => "labs",
+            Self::Immunizations => "immunizations",
+            Self::Appointments => "appointments",
+            Self::Alerts => "alerts",
+            Self::Timeline => "timeline",
+            Self::Documents => "documents",
+            Self::Journal => "journal",
+            Self::Conversations => "conversations",
+        }
+    }
+}
+
+/// GRANT-01: Which entity categories an access decision covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessScope {
+    /// Unscoped: every category (own, managed, and legacy grants).
+    All,
+    /// Only the listed categories.
+    Categories(Vec<EntityCategory>),
+}
+
+impl AccessScope {
+    /// Parse the `scope` column. NULL means all categories.
+    ///
+    /// A value that is not a JSON array of strings yields an empty scope
+    /// (default-deny); unknown category names are dropped.
+    pub fn from_db(scope: Option<&str>) -> Self {
+        let Some(json) = scope else {
+            return Self::All;
+        };
+        let names: Vec<String> = serde_json::from_str(json).unwrap_or_default();
+        Self::Categories(names.iter().filter_map(|n| EntityCategory::from_str(n)).collect())
+    }
+
+    /// Value for the `scope` column. `None` for an unscoped grant.
+    pub fn to_db(&self) -> Option<String> {
+        match self {
+            Self::All => None,
+            Self::Categories(categories) => {
+                let names: Vec<&str> = categories.iter().map(|c| c.as_str()).collect();
+                Some(serde_json::to_string(&names).unwrap_or_else(|_| "[]".into()))
+            }
+        }
+    }
+
+    /// Build a scope from category names (IPC input). Rejects unknown names
+    /// and an empty list — a grant that shares nothing is a mistake.
+    pub fn from_names(names: &[String]) -> Result<Self, String> {
+        if names.is_empty() {
+            return Err("Select at least one category to share".into());
+        }
+        let mut categories = Vec::new();
+        for name in names {
+            let category = EntityCategory::from_str(name)
+                .ok_or_else(|| format!("Unknown category: {name}"))?;
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+        Ok(Self::Categories(categories))
+    }
+
+    /// Whether this scope covers a category.
+    pub fn allows(&self, category: EntityCategory) -> bool {
+        match self {
+            Self::All => true,
+            Self::Categories(categories) => categories.contains(&category),
+        }
+    }
+
+    /// Whether this scope covers every category.
+    pub fn is_all(&self) -> bool {
+        match self {
+            Self::All => true,
+            Self::Categories(categories) => EntityCategory::ALL.iter().all(|c| categories.contains(c)),
+        }
+    }
+}
+
 /// Why access was granted (or denied) — for audit trail.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccessReason {
@@ -68,6 +194,10 @@ pub struct AccessDecision {
     pub allowed: bool,
     pub level: AccessLevel,
     pub reason: AccessReason,
+    /// GRANT-01: Categories the decision covers.
+    pub scope: AccessScope,
+    /// GRANT-01: When the underlying grant expires (RFC 3339 UTC), if ever.
+    pub expires_at: Option<String>,
 }
 
 impl AccessDecision {
@@ -76,14 +206,28 @@ impl AccessDecision {
             allowed: true,
             level,
             reason,
+            scope: AccessScope::All,
+            expires_at: None,
         }
     }
 
+    /// Allow through a grant row, carrying its scope and expiry.
+    fn from_grant(grant: ActiveGrant, reason: AccessReason) -> Option<Self> {
+        let level = AccessLevel::from_str(&grant.access_level)?;
+        Some(Self {
+            scope: AccessScope::from_db(grant.scope.as_deref()),
+            expires_at: grant.expires_at,
+            ..Self::allow(level, reason)
+        })
+    }
+
     fn deny() -> Self {
         Self {
             allowed: false,
             level: AccessLevel::ReadOnly,
             reason: AccessReason::Denied,
+            scope: AccessScope::Categories(Vec::new()),
+            expires_at: None,
         }
     }
 }
@@ -137,26 +281,8 @@ pub fn check_profile_access(
         return Ok(AccessDecision::allow(AccessLevel::Full, AccessReason::ManagedProfile));
     }
 
-    // Rule 3: Explicit grant (user-to-user in profile_access_grants)
-    let owner_str = owner_profile_id.to_string();
-    let target_str = target_profile_id.to_string();
-    if let Some(level_str) = device_registry::has_active_grant(app_conn, &target_str, &owner_str)? {
-        if let Some(level) = AccessLevel::from_str(&level_str) {
-            return Ok(AccessDecision::allow(level, AccessReason::ExplicitGrant));
-        }
-    }
-
-    // Rule 4: Device access (device_profile_access)
-    if let Some(level_str) =
-        device_registry::has_device_profile_access(app_conn, device_id, &target_str)?
-    {
-        if let Some(level) = AccessLevel::from_str(&level_str) {
-            return Ok(AccessDecision::allow(level, AccessReason::DeviceAccess));
-        }
-    }
-
-    // Rule 5: Default deny
-    Ok(AccessDecision::deny())
+    // Rules 3-5: Grants, then default deny
+    check_grants(app_conn, owner_profile_id, target_profile_id, device_id)
 }
 
 /// Check if the target profile is managed by the owner profile.
@@ -205,21 +331,37 @@ pub fn check_profile_access_with_profiles(
         }
     }
 
-    // Rule 3: Explicit grant
+    // Rules 3-5: Grants, then default deny
+    check_grants(app_conn, owner_profile_id, target_profile_id, device_id)
+}
+
+/// Rules 3-5 of the cascade, shared by both entry points.
+///
+/// GRANT-01: Only unexpired grants match; their scope and expiry are carried
+/// into the decision.
+fn check_grants(
+    app_conn: &Connection,
+    owner_profile_id: &Uuid,
+    target_profile_id: &Uuid,
+    device_id: &str,
+) -> Result<AccessDecision, AuthorizationError> {
+    let now = device_registry::grant_timestamp(Utc::now());
     let owner_str = owner_profile_id.to_string();
     let target_str = target_profile_id.to_string();
-    if let Some(level_str) = device_registry::has_active_grant(app_conn, &target_str, &owner_str)? {
-        if let Some(level) = AccessLevel::from_str(&level_str) {
-            return Ok(AccessDecision::allow(level, AccessReason::ExplicitGrant));
+
+    // Rule 3: Explicit grant (user-to-user in profile_access_grants)
+    if let Some(grant) = device_registry::find_active_grant(app_conn, &target_str, &owner_str, &now)? {
+        if let Some(decision) = AccessDecision::from_grant(grant, AccessReason::ExplicitGrant) {
+            return Ok(decision);
         }
     }
 
-    // Rule 4: Device access
-    if let Some(level_str) =
-        device_registry::has_device_profile_access(app_conn, device_id, &target_str)?
+    // Rule 4: Device access (device_profile_access)
+    if let Some(grant) =
+        device_registry::find_device_profile_access(app_conn, device_id, &target_str, &now)?
     {
-        if let Some(level) = AccessLevel::from_str(&level_str) {
-            return Ok(AccessDecision::allow(level, AccessReason::DeviceAccess));
+        if let Some(decision) = AccessDecision::from_grant(grant, AccessReason::DeviceAccess) {
+            return Ok(decision);
         }
     }
 
@@ -227,6 +369,25 @@ pub fn check_profile_access_with_profiles(
     Ok(AccessDecision::deny())
 }
 
+/// GRANT-01: Revoke every grant past its expiry, with a `grant_audit_log` entry each.
+///
+/// Checks already ignore expired grants; this makes the expiry permanent and
+/// visible in the audit trail. Safe to call on every request.
+pub fn expire_stale_grants(app_conn: &Connection) -> Result<Vec<ExpiredGrant>, AuthorizationError> {
+    let now = device_registry::grant_timestamp(Utc::now());
+    let expired = device_registry::expire_grants(app_conn, &now)?;
+    for grant in &expired {
+        tracing::info!(
+            grant_type = grant.grant_type,
+            profile_id = %grant.profile_id,
+            grantee = %grant.grantee,
+            expires_at = %grant.expires_at,
+            "GRANT-01: access grant expired"
+        );
+    }
+    Ok(expired)
+}
+
 // ═══════════════════════════════════════════════════════════
 // Tests
 // ═══════════════════════════════════════════════════════════
@@ -413,6 +574,8 @@ mod tests {
                 access_level: "read_only".to_string(),
                 granted_at: "2026-01-01T00:00:00Z".to_string(),
                 revoked_at: None,
+                scope: None,
+                expires_at: None,
             },
         )
         .unwrap();
@@ -446,6 +609,8 @@ mod tests {
                 access_level: "read_only".to_string(),
                 granted_at: "2026-01-01T00:00:00Z".to_string(),
                 revoked_at: None,
+                scope: None,
+                expires_at: None,
             },
         )
         .unwrap();
@@ -535,6 +700,8 @@ mod tests {
                 access_level: "read_only".to_string(),
                 granted_at: "2026-01-01T00:00:00Z".to_string(),
                 revoked_at: None,
+                scope: None,
+                expires_at: None,
             },
         )
         .unwrap();
@@ -570,4 +737,100 @@ mod tests {
         assert_eq!(AccessLevel::Full.as_str(), "full");
         assert_eq!(AccessLevel::ReadOnly.as_str(), "read_only");
     }
+
+    // ── GRANT-01: Scope and expiry ───────────────────────
+
+    fn grant_with(scope: Option<&str>, expires_at: Option<&str>) -> ProfileAccessGrantRow {
+        ProfileAccessGrantRow {
+            id: "grant-1".to_string(),
+            granter_profile_id: bob_id().to_string(),
+            grantee_profile_id: alice_id().to_string(),
+            access_level: "read_only".to_string(),
+            granted_at: "2026-01-01T00:00:00Z".to_string(),
+            revoked_at: None,
+            scope: scope.map(String::from),
+            expires_at: expires_at.map(String::from),
+        }
+    }
+
+    #[test]
+    fn scoped_grant_carries_categories_and_expiry() {
+        let conn = test_app_db();
+        let profiles = sample_profiles();
+        insert_profile_grant(
+            &conn,
+            &grant_with(Some(r#"["medications","allergies"]"#), Some("2099-01-01T00:00:00Z")),
+        )
+        .unwrap();
+
+        let decision =
+            check_profile_access_with_profiles(&conn, &profiles, &alice_id(), &bob_id(), "dev-1")
+                .unwrap();
+
+        assert!(decision.allowed);
+        assert!(decision.scope.allows(EntityCategory::Medications));
+        assert!(decision.scope.allows(EntityCategory::Allergies));
+        assert!(!decision.scope.allows(EntityCategory::Journal));
+        assert!(!decision.scope.allows(EntityCategory::Conversations));
+        assert_eq!(decision.expires_at.as_deref(), Some("2099-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn expired_grant_is_denied_and_expired_by_sweep() {
+        let conn = test_app_db();
+        let profiles = sample_profiles();
+        insert_profile_grant(&conn, &grant_with(None, Some("2026-01-15T00:00:00Z"))).unwrap();
+
+        let decision =
+            check_profile_access_with_profiles(&conn, &profiles, &alice_id(), &bob_id(), "dev-1")
+                .unwrap();
+        assert!(!decision.allowed, "Expired grant must not apply before the sweep runs");
+
+        let expired = expire_stale_grants(&conn).unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].profile_id, bob_id().to_string());
+        assert_eq!(
+            device_registry::list_grant_audit(&conn, &bob_id().to_string()).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn own_and_managed_profiles_are_unscoped() {
+        let conn = test_app_db();
+        let profiles = sample_profiles();
+
+        let own =
+            check_profile_access_with_profiles(&conn, &profiles, &alice_id(), &alice_id(), "dev-1")
+                .unwrap();
+        let managed =
+            check_profile_access_with_profiles(&conn, &profiles, &alice_id(), &child_id(), "dev-1")
+                .unwrap();
+
+        assert_eq!(own.scope, AccessScope::All);
+        assert_eq!(managed.scope, AccessScope::All);
+        assert!(managed.expires_at.is_none());
+    }
+
+    #[test]
+    fn access_scope_parsing() {
+        assert_eq!(AccessScope::from_db(None), AccessScope::All);
+        assert_eq!(
+            AccessScope::from_db(Some(r#"["labs","unknown"]"#)),
+            AccessScope::Categories(vec![EntityCategory::Labs])
+        );
+        // Corrupt scope denies everything rather than granting everything
+        let corrupt = AccessScope::from_db(Some("not json"));
+        assert!(!corrupt.allows(EntityCategory::Medications));
+
+        let names = vec!["medications".to_string(), "allergies".to_string(), "medications".to_string()];
+        let scope = AccessScope::from_names(&names).unwrap();
+        assert_eq!(scope.to_db().as_deref(), Some(r#"["medications","allergies"]"#));
+        assert!(!scope.is_all());
+        assert!(AccessScope::from_names(&[]).is_err());
+        assert!(AccessScope::from_names(&["mental_health".to_string()]).is_err());
+
+        let every: Vec<String> = EntityCategory::ALL.iter().map(|c| c.as_str().to_string()).collect();
+        assert!(AccessScope::from_names(&every).unwrap().is_all());
+    }
 }
@@ -0,0 +1,142 @@
+//! BEN-01: Extraction accuracy benchmark CLI.
+//!
+//! ```text
+//! extraction_bench --corpus <dir> --model <structuring-model>
+//!                  [--vision-model <model>] [--lang en|fr|de] [--num-ctx <n>]
+//!                  [--replay <cassette.json> | --record <cassette.json>]
+//!                  [--output <report.json>] [--baseline <report.json>]
+//!                  [--diff-output <diff.json>]
+//! ```
+//!
+//! Writes the report JSON to `--output` (stdout otherwise). With
+//! `--baseline`, prints a readable diff to stderr, optionally writes it as
+//! JSON, and exits 1 when any score regressed.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use coheara_lib::pipeline::benchmark::{
+    diff_against_baseline, render_diff, run_benchmark, BenchmarkBackend, BenchmarkOptions,
+    BenchmarkReport,
+};
+
+const USAGE: &str = "usage: extraction_bench --corpus <dir> --model <model> \
+[--vision-model <model>] [--lang <code>] [--num-ctx <n>] \
+[--replay <cassette> | --record <cassette>] [--output <file>] \
+[--baseline <file>] [--diff-output <file>]";
+
+struct Args {
+    options: BenchmarkOptions,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    diff_output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut corpus = None;
+    let mut model = None;
+    let mut vision_model = None;
+    let mut language = "en".to_string();
+    let mut num_ctx = None;
+    let mut replay = None;
+    let mut record = None;
+    let mut output = None;
+    let mut baseline = None;
+    let mut diff_output = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        if flag == "--help" || flag == "-h" {
+            return Err(USAGE.to_string());
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("{flag} needs a value\n{USAGE}"))?;
+        match flag.as_str() {
+            "--corpus" => corpus = Some(PathBuf::from(value)),
+            "--model" => model = Some(value),
+            "--vision-model" => vision_model = Some(value),
+            "--lang" => language = value,
+            "--num-ctx" => {
+                num_ctx = Some(value.parse().map_err(|_| format!("invalid --num-ctx: {value}"))?)
+            }
+            "--replay" => replay = Some(PathBuf::from(value)),
+            "--record" => record = Some(PathBuf::from(value)),
+            "--output" => output = Some(PathBuf::from(value)),
+            "--baseline" => baseline = Some(PathBuf::from(value)),
+            "--diff-output" => diff_output = Some(PathBuf::from(value)),
+            other => return Err(format!("unknown argument: {other}\n{USAGE}")),
+        }
+    }
+
+    let backend = match (replay, record) {
+        (Some(_), Some(_)) => return Err("--replay and --record are exclusive".to_string()),
+        (Some(cassette), None) => BenchmarkBackend::Replay { cassette },
+        (None, record_to) => BenchmarkBackend::Ollama { num_ctx, record_to },
+    };
+
+    Ok(Args {
+        options: BenchmarkOptions {
+            corpus_dir: corpus.ok_or_else(|| format!("--corpus is required\n{USAGE}"))?,
+            structuring_model: model.ok_or_else(|| format!("--model is required\n{USAGE}"))?,
+            vision_model,
+            language,
+            backend,
+        },
+        output,
+        baseline,
+        diff_output,
+    })
+}
+
+fn run(args: Args) -> Result<bool, String> {
+    let report = run_benchmark(&args.options).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    match &args.output {
+        Some(path) => std::fs::write(path, &json).map_err(|e| format!("{}: {e}", path.display()))?,
+        None => println!("{json}"),
+    }
+
+    let Some(baseline_path) = &args.baseline else {
+        return Ok(true);
+    };
+    let baseline: BenchmarkReport = std::fs::read_to_string(baseline_path)
+        .map_err(|e| e.to_string())
+        .and_then(|s| serde_json::from_str(&s).map_err(|e| e.to_string()))
+        .map_err(|e| format!("{}: {e}", baseline_path.display()))?;
+
+    let diff = diff_against_baseline(&baseline, &report);
+    eprint!("{}", render_diff(&diff));
+    if let Some(path) = &args.diff_output {
+        let diff_json = serde_json::to_string_pretty(&diff).map_err(|e| e.to_string())?;
+        std::fs::write(path, diff_json).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    Ok(!diff.has_regressions())
+}
+
+fn main() -> ExitCode {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("extraction_bench: {message}");
+            ExitCode::from(2)
+        }
+    }
+}
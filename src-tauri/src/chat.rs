@@ -70,6 +70,9 @@ pub enum StreamChunkPayload {
         boundary_check: String,
         /// ME-01: Data-driven grounding level ("High", "Moderate", "Low", "None").
         grounding: String,
+        /// LKP-01: Answer templated from structured records without the SLM.
+        #[serde(default)]
+        deterministic: bool,
     },
     Error { message: String },
 }
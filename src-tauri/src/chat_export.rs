@@ -0,0 +1,684 @@
+//! EXP-01 Chat Export — conversation answers with their sources, as PDF or Markdown.
+//!
+//! Each Coheara answer is paired with the patient question before it and the
+//! citations, guideline references and grounding level persisted in
+//! `message_sources` when the answer was shown. PDF rendering reuses the
+//! appointment `PdfWriter`. Exports can be attached to an appointment prep,
+//! where they are appended to the patient copy PDF.
+
+use chrono::Local;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::appointment::PdfWriter;
+use crate::chat::{CitationView, GuidelineCitationView};
+use crate::db::{repository, DatabaseError};
+use crate::models::enums::MessageRole;
+use crate::models::Message;
+use crate::pipeline::rag::types::RagResponse;
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// Output format of a chat export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Pdf,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+/// A conversation (or a selection of it) ready for rendering.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatExport {
+    pub conversation_id: String,
+    pub title: String,
+    pub exported_at: String,
+    pub entries: Vec<ExportEntry>,
+    pub disclaimer: String,
+}
+
+/// One question/answer pair with the sources shown alongside the answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportEntry {
+    pub question: Option<String>,
+    pub asked_at: Option<String>,
+    pub answer: String,
+    pub answered_at: String,
+    pub confidence: Option<f32>,
+    pub grounding: Option<String>,
+    pub citations: Vec<CitationView>,
+    pub guideline_citations: Vec<GuidelineCitationView>,
+}
+
+/// Sources persisted with a Coheara answer.
+#[derive(Debug, Clone, Default)]
+pub struct MessageSources {
+    pub citations: Vec<CitationView>,
+    pub guideline_citations: Vec<GuidelineCitationView>,
+    pub grounding: Option<String>,
+}
+
+impl MessageSources {
+    /// Sources shown with a RAG answer (citations, guidelines, grounding).
+    pub fn from_response(response: &RagResponse) -> Self {
+        Self {
+            citations: response.citations.iter().cloned().map(CitationView::from).collect(),
+            guideline_citations: response
+                .guideline_citations
+                .iter()
+                .cloned()
+                .map(GuidelineCitationView::from)
+                .collect(),
+            grounding: Some(format!("{:?}", response.grounding)),
+        }
+    }
+}
+
+/// A conversation attached to an appointment prep.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatAttachment {
+    pub id: String,
+    pub appointment_id: String,
+    pub conversation_id: String,
+    pub conversation_title: String,
+    /// None = the whole conversation.
+    pub message_ids: Option<Vec<String>>,
+    pub attached_at: String,
+}
+
+/// Longest citation excerpt rendered per source.
+const MAX_EXCERPT_CHARS: usize = 240;
+
+/// Standard chat disclaimer (matches `chat.ai_disclaimer` in the UI).
+pub fn export_disclaimer(lang: &str) -> &'static str {
+    match lang {
+        "fr" => "Coheara compare vos données aux référentiels cliniques publiés. \
+                 Ceci ne constitue pas un avis médical. Consultez toujours votre médecin.",
+        "de" => "Coheara vergleicht Ihre Daten mit veröffentlichten klinischen Leitlinien. \
+                 Dies ist kein medizinischer Rat. Konsultieren Sie immer Ihren Arzt.",
+        _ => "Coheara highlights your data against published clinical guidelines. \
+              This is not medical advice. Always consult your doctor.",
+    }
+}
+
+// ─── Message sources ──────────────────────────────────────────────────────────
+
+/// Persists the sources shown with a Coheara answer (replaces any previous row).
+pub fn save_message_sources(
+    conn: &Connection,
+    message_id: &Uuid,
+    sources: &MessageSources,
+) -> Result<(), DatabaseError> {
+    let citations = serde_json::to_string(&sources.citations)
+        .map_err(|e| DatabaseError::ConstraintViolation(format!("JSON serialization: {e}")))?;
+    let guidelines = serde_json::to_string(&sources.guideline_citations)
+        .map_err(|e| DatabaseError::ConstraintViolation(format!("JSON serialization: {e}")))?;
+
+    conn.execute(
+        "INSERT OR REPLACE INTO message_sources
+         (message_id, citations, guideline_citations, grounding)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![message_id.to_string(), citations, guidelines, sources.grounding],
+    )?;
+    Ok(())
+}
+
+/// Loads the sources of a message, if any were persisted.
+pub fn get_message_sources(
+    conn: &Connection,
+    message_id: &Uuid,
+) -> Result<Option<MessageSources>, DatabaseError> {
+    let row = conn
+        .query_row(
+            "SELECT citations, guideline_citations, grounding
+             FROM message_sources WHERE message_id = ?1",
+            params![message_id.to_string()],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                ))
+            },
+        )
+        .optional()?;
+
+    Ok(row.map(|(citations, guidelines, grounding)| MessageSources {
+        citations: serde_json::from_str(&citations).unwrap_or_default(),
+        guideline_citations: serde_json::from_str(&guidelines).unwrap_or_default(),
+        grounding,
+    }))
+}
+
+// ─── Export assembly ──────────────────────────────────────────────────────────
+
+/// Assembles a conversation export. With `message_ids`, only pairs whose
+/// question or answer is selected are kept. Unanswered questions are skipped.
+pub fn build_chat_export(
+    conn: &Connection,
+    conversation_id: &Uuid,
+    message_ids: Option<&[String]>,
+    lang: &str,
+) -> Result<ChatExport, DatabaseError> {
+    let conversation = repository::get_conversation(conn, conversation_id)?.ok_or_else(|| {
+        DatabaseError::NotFound {
+            entity_type: "Conversation".into(),
+            id: conversation_id.to_string(),
+        }
+    })?;
+    let messages = repository::get_messages_by_conversation(conn, conversation_id)?;
+
+    let is_selected = |m: &Message| match message_ids {
+        None => true,
+        Some(ids) => ids.iter().any(|id| *id == m.id.to_string()),
+    };
+
+    let mut entries = Vec::new();
+    let mut pending_question: Option<&Message> = None;
+    for msg in &messages {
+        match msg.role {
+            MessageRole::Patient => pending_question = Some(msg),
+            MessageRole::Coheara => {
+                let question = pending_question.take();
+                if !is_selected(msg) && !question.is_some_and(is_selected) {
+                    continue;
+                }
+                let sources = get_message_sources(conn, &msg.id)?.unwrap_or_default();
+                entries.push(ExportEntry {
+                    question: question.map(|q| q.content.clone()),
+                    asked_at: question.map(format_timestamp),
+                    answer: msg.content.clone(),
+                    answered_at: format_timestamp(msg),
+                    confidence: msg.confidence.filter(|c| *c > 0.0),
+                    grounding: sources.grounding,
+                    citations: sources.citations,
+                    guideline_citations: sources.guideline_citations,
+                });
+            }
+        }
+    }
+
+    if message_ids.is_some() && entries.is_empty() {
+        return Err(DatabaseError::ConstraintViolation(
+            "None of the selected messages can be exported".into(),
+        ));
+    }
+
+    Ok(ChatExport {
+        conversation_id: conversation_id.to_string(),
+        title: conversation
+            .title
+            .unwrap_or_else(|| "Coheara conversation".to_string()),
+        exported_at: Local::now().naive_local().format("%Y-%m-%d %H:%M").to_string(),
+        entries,
+        disclaimer: export_disclaimer(lang).to_string(),
+    })
+}
+
+/// File name for an export, e.g. `chat-2026-10-18-1a2b3c4d.pdf`.
+pub fn export_filename(export: &ChatExport, format: ExportFormat) -> String {
+    let date = export.exported_at.get(..10).unwrap_or("export");
+    let short_id = export.conversation_id.get(..8).unwrap_or("chat");
+    format!("chat-{date}-{short_id}.{}", format.extension())
+}
+
+fn format_timestamp(msg: &Message) -> String {
+    msg.timestamp.format("%Y-%m-%d %H:%M").to_string()
+}
+
+fn excerpt(text: &str) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match flat.char_indices().nth(MAX_EXCERPT_CHARS) {
+        Some((idx, _)) => format!("{}…", &flat[..idx]),
+        None => flat,
+    }
+}
+
+fn citation_label(c: &CitationView) -> String {
+    let mut label = c.document_title.clone();
+    if let Some(date) = &c.document_date {
+        label.push_str(&format!(" — {date}"));
+    }
+    if let Some(professional) = &c.professional_name {
+        label.push_str(&format!(" — {professional}"));
+    }
+    label
+}
+
+fn answer_meta(entry: &ExportEntry) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(grounding) = &entry.grounding {
+        parts.push(format!("Grounding: {grounding}"));
+    }
+    if let Some(confidence) = entry.confidence {
+        parts.push(format!("Confidence: {:.0}%", confidence * 100.0));
+    }
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
+
+// ─── Markdown ─────────────────────────────────────────────────────────────────
+
+/// Renders an export as Markdown.
+pub fn render_markdown(export: &ChatExport) -> String {
+    let mut md = format!("# {}\n\n", export.title);
+    md.push_str(&format!("_Exported from Coheara on {}_\n\n", export.exported_at));
+
+    for entry in &export.entries {
+        md.push_str("---\n\n");
+        if let Some(question) = &entry.question {
+            md.push_str("## Question\n\n");
+            for line in question.lines() {
+                md.push_str(&format!("> {line}\n"));
+            }
+            if let Some(asked_at) = &entry.asked_at {
+                md.push_str(&format!("\n_Asked {asked_at}_\n"));
+            }
+            md.push('\n');
+        }
+
+        md.push_str("## Answer\n\n");
+        md.push_str(entry.answer.trim());
+        md.push_str("\n\n");
+        if let Some(meta) = answer_meta(entry) {
+            md.push_str(&format!("_{meta}_\n\n"));
+        }
+
+        if !entry.citations.is_empty() {
+            md.push_str("### Sources\n\n");
+            for (i, c) in entry.citations.iter().enumerate() {
+                md.push_str(&format!("{}. **{}**\n", i + 1, citation_label(c)));
+                if !c.chunk_text.trim().is_empty() {
+                    md.push_str(&format!("   > {}\n", excerpt(&c.chunk_text)));
+                }
+            }
+            md.push('\n');
+        }
+
+        if !entry.guideline_citations.is_empty() {
+            md.push_str("### Guideline references\n\n");
+            for g in &entry.guideline_citations {
+                md.push_str(&format!("- {} ({} insights)\n", g.source, g.insight_count));
+            }
+            md.push('\n');
+        }
+    }
+
+    md.push_str("---\n\n");
+    md.push_str(&format!("> {}\n", export.disclaimer));
+    md
+}
+
+// ─── PDF ──────────────────────────────────────────────────────────────────────
+
+/// Generates a PDF from a chat export. Returns PDF bytes.
+pub fn generate_chat_pdf(export: &ChatExport) -> Result<Vec<u8>, DatabaseError> {
+    let mut w = PdfWriter::new(&export.title)?;
+
+    w.heading(&export.title, 14.0, 20.0);
+    w.advance(6.0);
+    w.text(&format!("Exported from Coheara on {}", export.exported_at), 8.0, 20.0);
+    w.advance(8.0);
+
+    write_chat_entries(&mut w, export);
+
+    w.advance(4.0);
+    w.write_wrapped(&export.disclaimer, 90, 7.0, 20.0, 3.5);
+
+    w.finish()
+}
+
+/// Writes the question/answer pairs of an export at the cursor.
+/// Shared with the appointment patient copy.
+pub(crate) fn write_chat_entries(w: &mut PdfWriter, export: &ChatExport) {
+    for entry in &export.entries {
+        if let Some(question) = &entry.question {
+            w.heading("QUESTION:", 10.0, 20.0);
+            w.advance(5.0);
+            w.write_wrapped(question, 85, 9.0, 25.0, 4.5);
+            w.advance(2.0);
+        }
+
+        w.heading("ANSWER:", 10.0, 20.0);
+        w.advance(5.0);
+        for paragraph in entry.answer.lines().filter(|l| !l.trim().is_empty()) {
+            w.write_wrapped(paragraph, 85, 9.0, 25.0, 4.5);
+        }
+        if let Some(meta) = answer_meta(entry) {
+            w.advance(1.0);
+            w.text(&meta, 7.5, 25.0);
+            w.advance(4.0);
+        }
+
+        if !entry.citations.is_empty() {
+            w.advance(2.0);
+            w.heading("SOURCES:", 9.0, 25.0);
+            w.advance(4.5);
+            for (i, c) in entry.citations.iter().enumerate() {
+                w.write_wrapped(&format!("{}. {}", i + 1, citation_label(c)), 85, 8.0, 28.0, 4.0);
+                if !c.chunk_text.trim().is_empty() {
+                    w.write_wrapped(&format!("\"{}\"", excerpt(&c.chunk_text)), 90, 7.0, 32.0, 3.5);
+                }
+                w.advance(1.0);
+            }
+        }
+
+        if !entry.guideline_citations.is_empty() {
+            w.advance(2.0);
+            w.heading("GUIDELINE REFERENCES:", 9.0, 25.0);
+            w.advance(4.5);
+            for g in &entry.guideline_citations {
+                let text = format!("· {} ({} insights)", g.source, g.insight_count);
+                w.write_wrapped(&text, 85, 8.0, 28.0, 4.0);
+            }
+        }
+
+        w.advance(6.0);
+    }
+}
+
+// ─── Appointment attachments ──────────────────────────────────────────────────
+
+/// Attaches a conversation (or selected messages) to an appointment prep.
+/// Returns the attachment ID.
+pub fn attach_to_appointment(
+    conn: &Connection,
+    appointment_id: &str,
+    conversation_id: &Uuid,
+    message_ids: Option<&[String]>,
+) -> Result<String, DatabaseError> {
+    let appointment_exists: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM appointments WHERE id = ?1)",
+        params![appointment_id],
+        |row| row.get(0),
+    )?;
+    if !appointment_exists {
+        return Err(DatabaseError::NotFound {
+            entity_type: "Appointment".into(),
+            id: appointment_id.into(),
+        });
+    }
+    if repository::get_conversation(conn, conversation_id)?.is_none() {
+        return Err(DatabaseError::NotFound {
+            entity_type: "Conversation".into(),
+            id: conversation_id.to_string(),
+        });
+    }
+
+    let message_ids_json = message_ids
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| DatabaseError::ConstraintViolation(format!("JSON serialization: {e}")))?;
+
+    let id = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO appointment_chat_attachments (id, appointment_id, conversation_id, message_ids)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![id, appointment_id, conversation_id.to_string(), message_ids_json],
+    )?;
+    Ok(id)
+}
+
+/// Lists the conversations attached to an appointment, oldest first.
+pub fn list_appointment_attachments(
+    conn: &Connection,
+    appointment_id: &str,
+) -> Result<Vec<ChatAttachment>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT a.id, a.appointment_id, a.conversation_id, COALESCE(c.title, ''),
+                a.message_ids, a.attached_at
+         FROM appointment_chat_attachments a
+         JOIN conversations c ON c.id = a.conversation_id
+         WHERE a.appointment_id = ?1
+         ORDER BY a.attached_at ASC, a.rowid ASC",
+    )?;
+
+    let rows = stmt.query_map(params![appointment_id], |row| {
+        let message_ids: Option<String> = row.get(4)?;
+        Ok(ChatAttachment {
+            id: row.get(0)?,
+            appointment_id: row.get(1)?,
+            conversation_id: row.get(2)?,
+            conversation_title: row.get(3)?,
+            message_ids: message_ids.and_then(|json| serde_json::from_str(&json).ok()),
+            attached_at: row.get(5)?,
+        })
+    })?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(DatabaseError::from)
+}
+
+/// Removes a conversation attachment from an appointment prep.
+pub fn detach_from_appointment(conn: &Connection, attachment_id: &str) -> Result<(), DatabaseError> {
+    let changed = conn.execute(
+        "DELETE FROM appointment_chat_attachments WHERE id = ?1",
+        params![attachment_id],
+    )?;
+    if changed == 0 {
+        return Err(DatabaseError::NotFound {
+            entity_type: "ChatAttachment".into(),
+            id: attachment_id.into(),
+        });
+    }
+    Ok(())
+}
+
+/// Builds the exports of every conversation attached to an appointment.
+pub fn load_attached_exports(
+    conn: &Connection,
+    appointment_id: &str,
+    lang: &str,
+) -> Result<Vec<ChatExport>, DatabaseError> {
+    let mut exports = Vec::new();
+    for attachment in list_appointment_attachments(conn, appointment_id)? {
+        let conversation_id = Uuid::parse_str(&attachment.conversation_id)
+            .map_err(|e| DatabaseError::ConstraintViolation(e.to_string()))?;
+        match build_chat_export(conn, &conversation_id, attachment.message_ids.as_deref(), lang) {
+            Ok(export) => exports.push(export),
+            // Selected messages were deleted since attaching — nothing left to show
+            Err(DatabaseError::ConstraintViolation(_)) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(exports)
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::appointment::{create_appointment, create_professional, NewProfessional};
+    use crate::db::sqlite::open_memory_database;
+    use crate::pipeline::rag::conversation::ConversationManager;
+
+    struct Seeded {
+        conn: Connection,
+        conversation_id: Uuid,
+        first_answer: Uuid,
+        second_question: Uuid,
+    }
+
+    fn citation() -> CitationView {
+        CitationView {
+            document_id: Uuid::new_v4().to_string(),
+            document_title: "Prescription Dr. Chen".into(),
+            document_date: Some("2026-01-20".into()),
+            professional_name: Some("Dr. Chen".into()),
+            chunk_text: "Metformin 500mg twice daily with meals".into(),
+            relevance_score: 0.9,
+        }
+    }
+
+    fn seed() -> Seeded {
+        let conn = open_memory_database().unwrap();
+        let manager = ConversationManager::new(&conn);
+        let conversation_id = manager.start(Some("Metformin questions")).unwrap();
+
+        manager.add_patient_message(conversation_id, "What dose of metformin am I on?").unwrap();
+        let first_answer = manager
+            .add_response(conversation_id, "Your prescription lists metformin 500mg twice daily.", None, 0.82)
+            .unwrap();
+        save_message_sources(
+            &conn,
+            &first_answer,
+            &MessageSources {
+                citations: vec![citation()],
+                guideline_citations: vec![GuidelineCitationView {
+                    source: "ADA 2024".into(),
+                    insight_count: 1,
+                }],
+                grounding: Some("High".into()),
+            },
+        )
+        .unwrap();
+
+        let second_question = manager
+            .add_patient_message(conversation_id, "When was it prescribed?")
+            .unwrap();
+        manager
+            .add_response(conversation_id, "It was prescribed on 2026-01-20.", None, 0.7)
+            .unwrap();
+
+        Seeded {
+            conn,
+            conversation_id,
+            first_answer,
+            second_question,
+        }
+    }
+
+    #[test]
+    fn message_sources_round_trip() {
+        let s = seed();
+        let sources = get_message_sources(&s.conn, &s.first_answer).unwrap().unwrap();
+        assert_eq!(sources.citations.len(), 1);
+        assert_eq!(sources.guideline_citations[0].source, "ADA 2024");
+        assert_eq!(sources.grounding.as_deref(), Some("High"));
+        assert!(get_message_sources(&s.conn, &s.second_question).unwrap().is_none());
+    }
+
+    #[test]
+    fn export_pairs_questions_with_answers() {
+        let s = seed();
+        let export = build_chat_export(&s.conn, &s.conversation_id, None, "en").unwrap();
+
+        assert_eq!(export.title, "Metformin questions");
+        assert_eq!(export.entries.len(), 2);
+        assert_eq!(
+            export.entries[0].question.as_deref(),
+            Some("What dose of metformin am I on?")
+        );
+        assert_eq!(export.entries[0].citations.len(), 1);
+        assert_eq!(export.entries[0].grounding.as_deref(), Some("High"));
+        assert!(export.entries[1].citations.is_empty());
+    }
+
+    #[test]
+    fn export_selection_matches_question_or_answer() {
+        let s = seed();
+        let by_answer = [s.first_answer.to_string()];
+        let export =
+            build_chat_export(&s.conn, &s.conversation_id, Some(&by_answer), "en").unwrap();
+        assert_eq!(export.entries.len(), 1);
+        assert!(export.entries[0].answer.contains("500mg"));
+
+        let by_question = [s.second_question.to_string()];
+        let export =
+            build_chat_export(&s.conn, &s.conversation_id, Some(&by_question), "en").unwrap();
+        assert_eq!(export.entries.len(), 1);
+        assert!(export.entries[0].answer.contains("2026-01-20"));
+
+        let unknown = [Uuid::new_v4().to_string()];
+        assert!(build_chat_export(&s.conn, &s.conversation_id, Some(&unknown), "en").is_err());
+    }
+
+    #[test]
+    fn export_unknown_conversation_is_not_found() {
+        let conn = open_memory_database().unwrap();
+        let result = build_chat_export(&conn, &Uuid::new_v4(), None, "en");
+        assert!(matches!(result, Err(DatabaseError::NotFound { .. })));
+    }
+
+    #[test]
+    fn markdown_includes_sources_and_disclaimer() {
+        let s = seed();
+        let export = build_chat_export(&s.conn, &s.conversation_id, None, "fr").unwrap();
+        let md = render_markdown(&export);
+
+        assert!(md.starts_with("# Metformin questions"));
+        assert!(md.contains("> What dose of metformin am I on?"));
+        assert!(md.contains("1. **Prescription Dr. Chen — 2026-01-20 — Dr. Chen**"));
+        assert!(md.contains("> Metformin 500mg twice daily with meals"));
+        assert!(md.contains("- ADA 2024 (1 insights)"));
+        assert!(md.contains("Grounding: High · Confidence: 82%"));
+        assert!(md.contains("Consultez toujours votre médecin"));
+    }
+
+    #[test]
+    fn pdf_is_generated() {
+        let s = seed();
+        let export = build_chat_export(&s.conn, &s.conversation_id, None, "en").unwrap();
+        let pdf = generate_chat_pdf(&export).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn export_filename_uses_date_and_short_id() {
+        let s = seed();
+        let export = build_chat_export(&s.conn, &s.conversation_id, None, "en").unwrap();
+        let name = export_filename(&export, ExportFormat::Markdown);
+        assert!(name.starts_with("chat-"));
+        assert!(name.ends_with(&format!("{}.md", &export.conversation_id[..8])));
+    }
+
+    #[test]
+    fn attach_list_load_and_detach() {
+        let s = seed();
+        let prof_id = create_professional(
+            &s.conn,
+            &NewProfessional {
+                name: "Dr. Chen".into(),
+                specialty: "GP".into(),
+                institution: None,
+            },
+        )
+        .unwrap();
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 11, 2).unwrap();
+        let appointment_id = create_appointment(&s.conn, &prof_id, &date).unwrap();
+
+        let selected = [s.first_answer.to_string()];
+        let attachment_id =
+            attach_to_appointment(&s.conn, &appointment_id, &s.conversation_id, Some(&selected))
+                .unwrap();
+
+        let attachments = list_appointment_attachments(&s.conn, &appointment_id).unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].conversation_title, "Metformin questions");
+        assert_eq!(attachments[0].message_ids.as_deref(), Some(&selected[..]));
+
+        let exports = load_attached_exports(&s.conn, &appointment_id, "en").unwrap();
+        assert_eq!(exports.len(), 1);
+        assert_eq!(exports[0].entries.len(), 1);
+
+        detach_from_appointment(&s.conn, &attachment_id).unwrap();
+        assert!(list_appointment_attachments(&s.conn, &appointment_id).unwrap().is_empty());
+        assert!(detach_from_appointment(&s.conn, &attachment_id).is_err());
+    }
+
+    #[test]
+    fn attach_to_unknown_appointment_fails() {
+        let s = seed();
+        let result = attach_to_appointment(&s.conn, "missing", &s.conversation_id, None);
+        assert!(matches!(result, Err(DatabaseError::NotFound { .. })));
+    }
+}
@@ -17,6 +17,7 @@ use crate::chat::{ChatStreamEvent, StreamChunkPayload};
 use crate::chat_queue::{ChatQueueItem, ChatQueueState};
 use crate::core_state::CoreState;
 use crate::pipeline::rag::conversation::ConversationManager;
+use crate::pipeline::rag::memory::refresh_pending_summaries;
 use crate::pipeline::safety::orchestrator::SafetyFilterImpl;
 
 // ---------------------------------------------------------------------------
@@ -68,6 +69,45 @@ async fn worker_loop(app: &AppHandle) {
         }
 
         queue.set_processing(false);
+
+        // MEM-01: Queue drained — fold long chats into their summaries
+        let summary_app = app.clone();
+        let _ = tauri::async_runtime::spawn_blocking(move || {
+            refresh_conversation_memory(&summary_app)
+        })
+        .await;
+    }
+}
+
+/// Conversations summarised per idle pass (one SLM call each).
+const SUMMARY_REFRESH_LIMIT: usize = 1;
+
+/// MEM-01: Refresh overdue conversation summaries if the Butler is free.
+///
+/// Never waits for the lock: an import or batch run in progress takes
+/// priority, and the next idle pass picks the work up.
+fn refresh_conversation_memory(app: &AppHandle) {
+    let state: tauri::State<'_, Arc<CoreState>> = app.state();
+    let (Ok(conn), Ok(db_key)) = (state.open_db(), state.db_key()) else {
+        return;
+    };
+
+    let client = crate::ollama_service::OllamaService::client();
+    let Ok(model) = state.resolver().resolve(&conn, &client).map(|r| r.name) else {
+        return;
+    };
+    let Some(_guard) = state.butler().try_acquire(
+        crate::ollama_service::OperationKind::ConversationSummary,
+        &model,
+    ) else {
+        tracing::debug!("MEM-01: Butler busy, skipping summary refresh");
+        return;
+    };
+
+    match refresh_pending_summaries(&conn, &db_key, &client, &model, SUMMARY_REFRESH_LIMIT) {
+        Ok(0) => {}
+        Ok(count) => tracing::info!(count, "MEM-01: Conversation summaries refreshed"),
+        Err(e) => tracing::warn!(error = %e, "MEM-01: Summary refresh failed"),
     }
 }
 
@@ -297,7 +337,10 @@ fn process_chat_item_blocking(
                 conv_uuid,
             )?;
             // Implicit verification: successful generation proves model works
-            state.set_ai_verified(true);
+            // (LKP-01: deterministic answers never touched the model)
+            if !rag_response.deterministic {
+                state.set_ai_verified(true);
+            }
         }
         None => {
             // Drop sender so forwarder thread exits
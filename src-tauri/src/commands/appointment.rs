@@ -1,20 +1,24 @@
 //! L4-02 Appointment Prep — Tauri IPC commands.
 //!
-//! Five commands:
+//! Commands:
 //! - `list_professionals`: known professionals for selector
 //! - `prepare_appointment`: create appointment + generate prep
 //! - `export_prep_pdf`: export prep as PDF files
 //! - `save_appointment_notes`: post-appointment guided notes
 //! - `list_appointments`: appointment history
+//! - `attach_conversation_to_appointment` / `list_appointment_chat_attachments` /
+//!   `detach_conversation_from_appointment`: EXP-01 chat exports in the prep
 
 use std::sync::Arc;
 
 use tauri::State;
+use uuid::Uuid;
 
 use crate::appointment::{
     self, AppointmentPrep, AppointmentRequest, PostAppointmentNotes,
     ProfessionalInfo, StoredAppointment, SPECIALTIES,
 };
+use crate::chat_export::{self, ChatAttachment};
 use crate::core_state::CoreState;
 
 /// Lists known professionals ordered by last_seen_date DESC.
@@ -79,9 +83,23 @@ pub fn prepare_appointment(
     let appointment_id = appointment::create_appointment(&conn, &professional_id, &date)
         .map_err(|e| e.to_string())?;
 
+    // IMM-01: Date of birth drives the immunization schedule
+    let date_of_birth = state
+        .read_session()
+        .ok()
+        .and_then(|guard| guard.as_ref().map(|s| s.profile_id))
+        .and_then(|profile_id| state.get_date_of_birth(&profile_id));
+
     // Generate full prep
-    let prep = appointment::prepare_appointment_prep(&conn, &professional_id, date, &appointment_id)
-        .map_err(|e| format!("Failed to generate preparation: {e}"))?;
+    let prep = appointment::prepare_appointment_prep(
+        &conn,
+        &professional_id,
+        date,
+        &appointment_id,
+        date_of_birth,
+        request.include_sensitive,
+    )
+    .map_err(|e| format!("Failed to generate preparation: {e}"))?;
 
     state.update_activity();
     Ok(prep)
@@ -121,7 +139,12 @@ pub fn export_prep_pdf(
     let mut paths = Vec::new();
 
     if copy_type == "patient" || copy_type == "both" {
-        let pdf = appointment::generate_patient_pdf(&prep.patient_copy)
+        // EXP-01: Conversations attached to this prep go into the patient copy
+        let conn = state.open_db().map_err(|e| e.to_string())?;
+        let lang = state.get_profile_language();
+        let chats = chat_export::load_attached_exports(&conn, &prep.appointment_id, &lang)
+            .map_err(|e| e.to_string())?;
+        let pdf = appointment::generate_patient_pdf_with_chats(&prep.patient_copy, &chats)
             .map_err(|e| format!("Patient PDF error: {e}"))?;
         let filename = format!("patient-prep-{}-{}.pdf", safe_name, prep.appointment_date);
         let path = appointment::export_pdf_to_file(&pdf, &filename, &db_path)
@@ -199,3 +222,49 @@ pub fn list_appointments(
 
     appointment::list_appointments(&conn).map_err(|e| e.to_string())
 }
+
+/// EXP-01: Attaches a conversation (or selected messages) to an appointment prep.
+/// Returns the attachment ID.
+#[tauri::command]
+pub fn attach_conversation_to_appointment(
+    appointment_id: String,
+    conversation_id: String,
+    message_ids: Option<Vec<String>>,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<String, String> {
+    let conv_uuid =
+        Uuid::parse_str(&conversation_id).map_err(|e| format!("Invalid conversation ID: {e}"))?;
+    if message_ids.as_ref().is_some_and(|ids| ids.is_empty()) {
+        return Err("Select at least one message to attach".into());
+    }
+
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    state.update_activity();
+
+    chat_export::attach_to_appointment(&conn, &appointment_id, &conv_uuid, message_ids.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+/// EXP-01: Lists the conversations attached to an appointment prep.
+#[tauri::command]
+pub fn list_appointment_chat_attachments(
+    appointment_id: String,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<Vec<ChatAttachment>, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    state.update_activity();
+
+    chat_export::list_appointment_attachments(&conn, &appointment_id).map_err(|e| e.to_string())
+}
+
+/// EXP-01: Removes a conversation attachment from an appointment prep.
+#[tauri::command]
+pub fn detach_conversation_from_appointment(
+    attachment_id: String,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    state.update_activity();
+
+    chat_export::detach_from_appointment(&conn, &attachment_id).map_err(|e| e.to_string())
+}
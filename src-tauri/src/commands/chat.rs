@@ -18,12 +18,14 @@ use crate::chat::{
     self, generate_title, update_conversation_title, ChatStreamEvent, CitationView,
     ConversationSummary, GuidelineCitationView, PromptSuggestion, StreamChunkPayload,
 };
+use crate::chat_export::{self, ExportFormat};
 use crate::chat_queue::ChatQueueSnapshot;
 use crate::core_state::CoreState;
 use crate::crypto::profile::PatientDemographics;
 use crate::invariants::InvariantRegistry;
 use crate::models::enums::{MessageFeedback, MessageRole};
 use crate::pipeline::rag::conversation::ConversationManager;
+use crate::pipeline::rag::memory::ConversationMemory;
 use crate::pipeline::rag::orchestrator::DocumentRagPipeline;
 use crate::pipeline::rag::types::{PatientQuery, RagResponse};
 use crate::pipeline::safety::orchestrator::SafetyFilterImpl;
@@ -142,8 +144,13 @@ pub(crate) fn try_rag_query(
     use crate::pipeline::storage::vectordb::SqliteVectorStore;
 
     // Use preference-resolved model if available (L6-04)
-    let model_name = resolved_model?;
-    let generator = OllamaRagGenerator::with_resolved_model(model_name.to_string())?;
+    let generator = match resolved_model
+        .and_then(|name| OllamaRagGenerator::with_resolved_model(name.to_string()))
+    {
+        Some(generator) => generator,
+        // LKP-01: Record lookups need no model — still answer them without one.
+        None => return try_lookup_without_model(query_text, conversation_id, conn, lang, &token_tx),
+    };
 
     // Production vector store — persistent SQLite-backed chunk storage
     let vector_store = SqliteVectorStore::new(db_path.to_path_buf(), db_key.copied());
@@ -152,8 +159,15 @@ pub(crate) fn try_rag_query(
     let embedder: Box<dyn crate::pipeline::storage::types::EmbeddingModel> =
         build_embedder();
 
+    // MEM-01: Summary of older turns + pinned facts (best-effort)
+    let memory = ConversationMemory::load(conn, conversation_id, db_key)
+        .map_err(|e| tracing::warn!(error = %e, "MEM-01: Conversation memory unavailable"))
+        .ok();
+
     let pipeline = DocumentRagPipeline::with_language(&generator, &embedder, &vector_store, conn, registry, lang)
-        .with_demographics(demographics);
+        .with_demographics(demographics)
+        .with_memory(memory)
+        .with_sensitive(includes_sensitive(conn, &conversation_id));
     let query = PatientQuery {
         text: query_text.to_string(),
         conversation_id,
@@ -177,6 +191,35 @@ pub(crate) fn try_rag_query(
     }
 }
 
+/// LKP-01: Deterministic record lookup when no model is available.
+fn try_lookup_without_model(
+    query_text: &str,
+    conversation_id: Uuid,
+    conn: &rusqlite::Connection,
+    lang: &str,
+    token_tx: &std::sync::mpsc::Sender<String>,
+) -> Option<RagResponse> {
+    let include_sensitive = includes_sensitive(conn, &conversation_id);
+    match crate::pipeline::rag::lookup::answer(conn, query_text, lang, include_sensitive) {
+        Ok(Some(response)) => {
+            tracing::info!("Answered record lookup without a model");
+            let _ = token_tx.send(response.text.clone());
+            Some(response)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!(error = %e, "Record lookup failed");
+            None
+        }
+    }
+}
+
+/// SENS-01: Whether the patient enabled sensitive records for this conversation.
+/// Read failures keep them hidden.
+pub(crate) fn includes_sensitive(conn: &rusqlite::Connection, conversation_id: &Uuid) -> bool {
+    crate::sensitivity::conversation_includes_sensitive(conn, conversation_id).unwrap_or(false)
+}
+
 /// Build the best available embedding model (delegates to shared builder).
 pub(crate) fn build_embedder() -> Box<dyn crate::pipeline::storage::types::EmbeddingModel> {
     crate::pipeline::storage::embedder::build_embedder()
@@ -202,7 +245,7 @@ pub(crate) fn emit_filtered_response(
         .map_err(|e| format!("Safety filter error: {e}"))?;
 
     let (display_text, confidence, boundary_str) = match &filtered.filter_outcome {
-        FilterOutcome::Passed => (
+        FilterOutcome::Passed | FilterOutcome::Annotated { .. } => (
             filtered.text.clone(),
             filtered.confidence,
             format!("{:?}", filtered.boundary_check),
@@ -254,6 +297,7 @@ pub(crate) fn emit_filtered_response(
                 confidence,
                 boundary_check: boundary_str,
                 grounding: format!("{:?}", filtered.grounding),
+                deterministic: filtered.deterministic,
             },
         },
     );
@@ -272,10 +316,19 @@ pub(crate) fn emit_filtered_response(
         .ok()
     };
 
-    manager
+    let response_id = manager
         .add_response(conv_uuid, &display_text, source_chunks_json.as_deref(), confidence)
         .map_err(|e| e.to_string())?;
 
+    // EXP-01: Keep the sources shown with this answer for later export
+    if let Err(e) = chat_export::save_message_sources(
+        manager.connection(),
+        &response_id,
+        &chat_export::MessageSources::from_response(rag_response),
+    ) {
+        tracing::warn!(error = %e, "EXP-01: Failed to persist answer sources");
+    }
+
     Ok(())
 }
 
@@ -318,6 +371,7 @@ pub(crate) fn emit_placeholder_response(
                 confidence: 0.0,
                 boundary_check: "Understanding".to_string(),
                 grounding: "None".to_string(),
+                deterministic: false,
             },
         },
     );
@@ -351,6 +405,61 @@ pub fn get_conversation_messages(
     Ok(views)
 }
 
+/// SEC-02-G06: PHI warning included with every unencrypted export result.
+pub(crate) const EXPORT_PHI_WARNING: &str = "This file is NOT encrypted. \
+Anyone with access to this file can read your medical information. \
+Store it securely and delete it when no longer needed.";
+
+/// EXP-01: Result of a chat export, with PHI safety warning.
+#[derive(serde::Serialize)]
+pub struct ChatExportResult {
+    pub path: String,
+    pub phi_warning: &'static str,
+}
+
+/// EXP-01: Export a conversation, or selected messages, as PDF or Markdown
+/// into the profile exports folder.
+#[tauri::command]
+pub fn export_conversation(
+    conversation_id: String,
+    message_ids: Option<Vec<String>>,
+    format: ExportFormat,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<ChatExportResult, String> {
+    let conv_uuid =
+        Uuid::parse_str(&conversation_id).map_err(|e| format!("Invalid conversation ID: {e}"))?;
+    if message_ids.as_ref().is_some_and(|ids| ids.is_empty()) {
+        return Err("Select at least one message to export".into());
+    }
+
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    let db_path = state.db_path().map_err(|e| e.to_string())?;
+    let lang = state.get_profile_language();
+
+    let export = chat_export::build_chat_export(&conn, &conv_uuid, message_ids.as_deref(), &lang)
+        .map_err(|e| e.to_string())?;
+    let bytes = match format {
+        ExportFormat::Pdf => chat_export::generate_chat_pdf(&export)
+            .map_err(|e| format!("Chat PDF error: {e}"))?,
+        ExportFormat::Markdown => chat_export::render_markdown(&export).into_bytes(),
+    };
+    let filename = chat_export::export_filename(&export, format);
+    let path = crate::appointment::export_pdf_to_file(&bytes, &filename, &db_path)
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!(
+        entries = export.entries.len(),
+        format = format.extension(),
+        "Chat exported — PHI warning attached"
+    );
+
+    state.update_activity();
+    Ok(ChatExportResult {
+        path: path.to_string_lossy().into_owned(),
+        phi_warning: EXPORT_PHI_WARNING,
+    })
+}
+
 /// List all conversations with summaries, ordered by last_message_at DESC.
 #[tauri::command]
 pub fn list_conversations(state: State<'_, Arc<CoreState>>) -> Result<Vec<ConversationSummary>, String> {
@@ -21,6 +21,7 @@ use crate::core_state::CoreState;
 use crate::db::repository;
 use crate::intelligence::engine::DefaultCoherenceEngine;
 use crate::intelligence::emergency::{EmergencyAction, EmergencyProtocol};
+use crate::intelligence::reconciliation;
 use crate::intelligence::reference::CoherenceReferenceData;
 use crate::intelligence::types::{
     CoherenceAlert, CoherenceEngine, CoherenceResult, RepositorySnapshot,
@@ -117,6 +118,9 @@ pub fn run_coherence_scan(
     let db_path = state.db_path().map_err(|e| e.to_string())?;
     let db_key = state.db_key().ok();
 
+    // REC-01: Link cross-document duplicates before they skew detection
+    reconciliation::reconcile(&conn).map_err(|e| e.to_string())?;
+
     let demographics = state.get_patient_demographics();
     let snapshot = build_snapshot(&conn, demographics)?;
     let engine = build_engine(&conn, &db_path, db_key, state.invariants().clone())?;
@@ -8,6 +8,7 @@ use std::sync::Arc;
 use tauri::State;
 use uuid::Uuid;
 
+use crate::authorization::{self, AccessScope};
 use crate::core_state::CoreState;
 use crate::crypto::profile;
 use crate::db::repository::device_registry;
@@ -139,10 +140,42 @@ fn verify_caller_is_granter(
     Ok(())
 }
 
+/// GRANT-01: Validate optional scope and expiry inputs for a grant.
+///
+/// Returns the `scope` and `expires_at` column values. `categories: None`
+/// shares everything; `expires_at` must be RFC 3339 and in the future.
+fn parse_grant_limits(
+    categories: Option<&[String]>,
+    expires_at: Option<&str>,
+) -> Result<(Option<String>, Option<String>), String> {
+    let scope = match categories {
+        Some(names) => AccessScope::from_names(names)?.to_db(),
+        None => None,
+    };
+
+    let expires_at = match expires_at {
+        Some(raw) => {
+            let at = chrono::DateTime::parse_from_rfc3339(raw)
+                .map_err(|_| format!("Invalid expiry date: {raw}"))?
+                .with_timezone(&chrono::Utc);
+            if at <= chrono::Utc::now() {
+                return Err("Expiry must be in the future".into());
+            }
+            Some(device_registry::grant_timestamp(at))
+        }
+        None => None,
+    };
+
+    Ok((scope, expires_at))
+}
+
 /// Grant a non-managed profile access to another profile.
 ///
 /// Used when a caregiver wants to share their medical data with a trusted
 /// family member's profile. Writes to `profile_access_grants` in `app.db`.
+/// GRANT-01: `categories` limits what is shared (`None` = everything) and
+/// `expires_at` (RFC 3339) ends the grant automatically. Granting again to
+/// the same profile replaces the previous grant.
 ///
 /// **Security**: Validates the caller is the granter (prevents cross-profile impersonation).
 #[tauri::command]
@@ -150,6 +183,8 @@ pub fn grant_profile_access(
     granter_profile_id: String,
     grantee_profile_id: String,
     access_level: String,
+    categories: Option<Vec<String>>,
+    expires_at: Option<String>,
     state: State<'_, Arc<CoreState>>,
 ) -> Result<(), String> {
     // Security: verify caller is the granter
@@ -159,6 +194,7 @@ pub fn grant_profile_access(
     if access_level != "full" && access_level != "read_only" {
         return Err("Invalid access level: must be 'full' or 'read_only'".into());
     }
+    let (scope, expires_at) = parse_grant_limits(categories.as_deref(), expires_at.as_deref())?;
 
     let app_conn = state.open_app_db().map_err(|e| e.to_string())?;
 
@@ -169,6 +205,8 @@ pub fn grant_profile_access(
         access_level,
         granted_at: chrono::Utc::now().to_rfc3339(),
         revoked_at: None,
+        scope: scope.clone(),
+        expires_at: expires_at.clone(),
     };
 
     device_registry::insert_profile_grant(&app_conn, &grant).map_err(|e| e.to_string())?;
@@ -176,7 +214,61 @@ pub fn grant_profile_access(
     state.log_access(
         crate::core_state::AccessSource::DesktopUi,
         "grant_profile_access",
-        &format!("{granter_profile_id} → {grantee_profile_id}"),
+        &format!(
+            "{granter_profile_id} → {grantee_profile_id} scope={} expires={}",
+            scope.as_deref().unwrap_or("all"),
+            expires_at.as_deref().unwrap_or("never"),
+        ),
+    );
+
+    Ok(())
+}
+
+/// GRANT-01: Limit a paired device's access to the active profile.
+///
+/// Replaces the device's entry for this profile with the given level,
+/// categories (`None` = everything) and optional expiry (RFC 3339).
+///
+/// **Security**: Only the profile being shared (the active one) can set this.
+#[tauri::command]
+pub fn set_device_profile_access(
+    device_id: String,
+    profile_id: String,
+    access_level: String,
+    categories: Option<Vec<String>>,
+    expires_at: Option<String>,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    verify_caller_is_granter(&state, &profile_id)?;
+
+    if access_level != "full" && access_level != "read_only" {
+        return Err("Invalid access level: must be 'full' or 'read_only'".into());
+    }
+    let (scope, expires_at) = parse_grant_limits(categories.as_deref(), expires_at.as_deref())?;
+
+    let app_conn = state.open_app_db().map_err(|e| e.to_string())?;
+    device_registry::get_device(&app_conn, &device_id)
+        .map_err(|e| e.to_string())?
+        .ok_or("Unknown device")?;
+
+    device_registry::grant_scoped_device_profile_access(
+        &app_conn,
+        &device_id,
+        &profile_id,
+        &access_level,
+        scope.as_deref(),
+        expires_at.as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    state.log_access(
+        crate::core_state::AccessSource::DesktopUi,
+        "set_device_profile_access",
+        &format!(
+            "{device_id} → {profile_id} scope={} expires={}",
+            scope.as_deref().unwrap_or("all"),
+            expires_at.as_deref().unwrap_or("never"),
+        ),
     );
 
     Ok(())
@@ -230,6 +322,39 @@ pub struct EnrichedGrant {
     pub grantee_name: String,
     pub access_level: String,
     pub granted_at: String,
+    /// GRANT-01: Shared categories; `None` = everything.
+    pub categories: Option<Vec<String>>,
+    /// GRANT-01: RFC 3339 UTC expiry; `None` = never.
+    pub expires_at: Option<String>,
+}
+
+impl EnrichedGrant {
+    fn from_row(state: &CoreState, g: device_registry::ProfileAccessGrantRow) -> Self {
+        let categories = match AccessScope::from_db(g.scope.as_deref()) {
+            AccessScope::All => None,
+            AccessScope::Categories(list) => {
+                Some(list.into_iter().map(|c| c.as_str().to_string()).collect())
+            }
+        };
+        Self {
+            id: g.id,
+            granter_name: resolve_profile_name(state, &g.granter_profile_id),
+            grantee_name: resolve_profile_name(state, &g.grantee_profile_id),
+            granter_profile_id: g.granter_profile_id,
+            grantee_profile_id: g.grantee_profile_id,
+            access_level: g.access_level,
+            granted_at: g.granted_at,
+            categories,
+            expires_at: g.expires_at,
+        }
+    }
+}
+
+/// GRANT-01: Expire stale grants before listing, so the UI and audit agree.
+fn sweep_expired_grants(app_conn: &rusqlite::Connection) {
+    if let Err(e) = authorization::expire_stale_grants(app_conn) {
+        tracing::warn!("GRANT-01: Failed to expire stale grants: {e}");
+    }
 }
 
 /// Resolve a profile ID to its display name from disk metadata.
@@ -259,20 +384,13 @@ pub fn list_my_grants(
     };
 
     let app_conn = state.open_app_db().map_err(|e| e.to_string())?;
+    sweep_expired_grants(&app_conn);
     let grants = device_registry::list_grants_for_granter(&app_conn, &active_id)
         .map_err(|e| e.to_string())?;
 
     let enriched = grants
         .into_iter()
-        .map(|g| EnrichedGrant {
-            id: g.id,
-            granter_name: resolve_profile_name(&state, &g.granter_profile_id),
-            grantee_name: resolve_profile_name(&state, &g.grantee_profile_id),
-            granter_profile_id: g.granter_profile_id,
-            grantee_profile_id: g.grantee_profile_id,
-            access_level: g.access_level,
-            granted_at: g.granted_at,
-        })
+        .map(|g| EnrichedGrant::from_row(&state, g))
         .collect();
 
     Ok(enriched)
@@ -292,20 +410,13 @@ pub fn list_grants_to_me(
     };
 
     let app_conn = state.open_app_db().map_err(|e| e.to_string())?;
+    sweep_expired_grants(&app_conn);
     let grants = device_registry::list_grants_for_grantee(&app_conn, &active_id)
         .map_err(|e| e.to_string())?;
 
     let enriched = grants
         .into_iter()
-        .map(|g| EnrichedGrant {
-            id: g.id,
-            granter_name: resolve_profile_name(&state, &g.granter_profile_id),
-            grantee_name: resolve_profile_name(&state, &g.grantee_profile_id),
-            granter_profile_id: g.granter_profile_id,
-            grantee_profile_id: g.grantee_profile_id,
-            access_level: g.access_level,
-            granted_at: g.granted_at,
-        })
+        .map(|g| EnrichedGrant::from_row(&state, g))
         .collect();
 
     Ok(enriched)
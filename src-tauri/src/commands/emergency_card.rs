@@ -0,0 +1,155 @@
+//! EMC-01: Emergency medical ID — Tauri IPC commands.
+//!
+//! Commands:
+//! - `get_emergency_card`: summary, signed QR payload and QR preview
+//! - `export_emergency_card_pdf`: printable wallet card (unencrypted export)
+//! - `list_emergency_contacts` / `add_emergency_contact` /
+//!   `remove_emergency_contact`: contacts shown on the card
+//! - `get_lock_screen_fields` / `set_lock_screen_fields`: per-field opt-in
+//!   for the phone lock-screen summary
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use super::chat::EXPORT_PHI_WARNING;
+use crate::core_state::{AccessSource, CoreState};
+use crate::emergency_card::{
+    self, EmergencyCard, EmergencyContact, EmergencyContactInput, EmergencyField,
+};
+
+/// Result of a wallet card export, with PHI safety warning.
+#[derive(serde::Serialize)]
+pub struct EmergencyCardExportResult {
+    pub path: String,
+    pub phi_warning: &'static str,
+}
+
+/// Assembles the card for the active profile and signs its QR payload.
+fn build_card(
+    state: &CoreState,
+    include_sensitive: bool,
+) -> Result<EmergencyCard, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    let demographics = state.get_patient_demographics();
+
+    let (profile_name, key) = {
+        let guard = state.read_session().map_err(|e| e.to_string())?;
+        let session = guard.as_ref().ok_or("No active session")?;
+        let key = emergency_card::load_or_create_signing_key(&conn, session)
+            .map_err(|e| e.to_string())?;
+        (session.profile_name.clone(), key)
+    };
+
+    let summary = emergency_card::assemble_emergency_summary(
+        &conn,
+        &profile_name,
+        demographics.as_ref().and_then(|d| d.date_of_birth),
+        demographics.as_ref().and_then(|d| d.blood_type.as_ref()),
+        include_sensitive,
+    )
+    .map_err(|e| e.to_string())?;
+
+    emergency_card::build_emergency_card(&conn, summary, &key).map_err(|e| e.to_string())
+}
+
+/// Card preview. Sensitive records are left out unless explicitly requested.
+#[tauri::command]
+pub fn get_emergency_card(
+    include_sensitive: Option<bool>,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<EmergencyCard, String> {
+    let card = build_card(&state, include_sensitive.unwrap_or(false))?;
+    state.log_access(AccessSource::DesktopUi, "view_emergency_card", "emergency_card");
+    state.update_activity();
+    Ok(card)
+}
+
+/// Writes the two-sided wallet card PDF to the profile's exports folder.
+#[tauri::command]
+pub fn export_emergency_card_pdf(
+    include_sensitive: Option<bool>,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<EmergencyCardExportResult, String> {
+    let card = build_card(&state, include_sensitive.unwrap_or(false))?;
+    let db_path = state.db_path().map_err(|e| e.to_string())?;
+
+    let pdf = emergency_card::generate_wallet_card_pdf(&card)
+        .map_err(|e| format!("Wallet card PDF error: {e}"))?;
+    let filename = format!(
+        "emergency-card-{}.pdf",
+        chrono::Local::now().date_naive()
+    );
+    let path = crate::appointment::export_pdf_to_file(&pdf, &filename, &db_path)
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!("Emergency card exported — PHI warning attached");
+    state.log_access(AccessSource::DesktopUi, "export_emergency_card", "emergency_card");
+    state.update_activity();
+    Ok(EmergencyCardExportResult {
+        path: path.to_string_lossy().into_owned(),
+        phi_warning: EXPORT_PHI_WARNING,
+    })
+}
+
+#[tauri::command]
+pub fn list_emergency_contacts(
+    state: State<'_, Arc<CoreState>>,
+) -> Result<Vec<EmergencyContact>, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    state.update_activity();
+
+    emergency_card::list_contacts(&conn).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn add_emergency_contact(
+    contact: EmergencyContactInput,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<EmergencyContact, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    let contact = emergency_card::add_contact(&conn, &contact).map_err(|e| e.to_string())?;
+
+    state.update_activity();
+    Ok(contact)
+}
+
+#[tauri::command]
+pub fn remove_emergency_contact(
+    contact_id: String,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    emergency_card::remove_contact(&conn, &contact_id).map_err(|e| e.to_string())?;
+
+    state.update_activity();
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_lock_screen_fields(
+    state: State<'_, Arc<CoreState>>,
+) -> Result<Vec<EmergencyField>, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    state.update_activity();
+
+    emergency_card::get_lock_screen_fields(&conn).map_err(|e| e.to_string())
+}
+
+/// Replaces the lock-screen opt-ins. Paired phones pick them up on next sync.
+#[tauri::command]
+pub fn set_lock_screen_fields(
+    fields: Vec<EmergencyField>,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    emergency_card::set_lock_screen_fields(&conn, &fields).map_err(|e| e.to_string())?;
+
+    state.log_access(
+        AccessSource::DesktopUi,
+        "set_lock_screen_fields",
+        &format!("fields:{}", fields.len()),
+    );
+    state.update_activity();
+    Ok(())
+}
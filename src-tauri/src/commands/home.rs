@@ -35,12 +35,19 @@ const VALID_ALERT_TYPES: &[&str] = &[
 ];
 
 /// Fetches all home screen data in a single call.
+///
+/// SENS-01: Sensitive documents are hidden unless `include_sensitive` is set.
 #[tauri::command]
-pub fn get_home_data(state: State<'_, Arc<CoreState>>) -> Result<HomeData, String> {
+pub fn get_home_data(
+    include_sensitive: Option<bool>,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<HomeData, String> {
     let conn = state.open_db().map_err(|e| e.to_string())?;
 
     let stats = fetch_profile_stats(&conn).map_err(|e| e.to_string())?;
-    let recent_documents = fetch_recent_documents(&conn, 20, 0).map_err(|e| e.to_string())?;
+    let recent_documents =
+        fetch_recent_documents(&conn, 20, 0, include_sensitive.unwrap_or(false))
+            .map_err(|e| e.to_string())?;
     let onboarding = compute_onboarding(&conn).map_err(|e| e.to_string())?;
     let critical_alerts = crate::trust::fetch_critical_alerts(&conn).unwrap_or_default();
 
@@ -55,10 +62,13 @@ pub fn get_home_data(state: State<'_, Arc<CoreState>>) -> Result<HomeData, Strin
 }
 
 /// Fetches more documents for infinite scroll.
+///
+/// SENS-01: Sensitive documents are hidden unless `include_sensitive` is set.
 #[tauri::command]
 pub fn get_more_documents(
     offset: u32,
     limit: u32,
+    include_sensitive: Option<bool>,
     state: State<'_, Arc<CoreState>>,
 ) -> Result<Vec<DocumentCard>, String> {
     let conn = state.open_db().map_err(|e| e.to_string())?;
@@ -66,7 +76,8 @@ pub fn get_more_documents(
 
     state.update_activity();
 
-    fetch_recent_documents(&conn, clamped_limit, offset).map_err(|e| e.to_string())
+    fetch_recent_documents(&conn, clamped_limit, offset, include_sensitive.unwrap_or(false))
+        .map_err(|e| e.to_string())
 }
 
 /// Fetches detailed document info with all linked entities.
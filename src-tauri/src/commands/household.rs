@@ -0,0 +1,59 @@
+//! HH-01: Household overview — Tauri IPC commands.
+//!
+//! Commands:
+//! - `get_household_overview`: merged alerts, appointments, vaccines,
+//!   screenings and refills across every unlocked profile the user may read
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::core_state::{AccessSource, CoreState};
+use crate::crypto::profile;
+use crate::household::{self, HouseholdError, HouseholdOverview};
+
+/// Builds the household overview for the active profile.
+///
+/// Reads only profiles unlocked in the session cache (active profile plus
+/// any unlocked for companion access) that pass the authorization cascade.
+#[tauri::command]
+pub fn get_household_overview(
+    state: State<'_, Arc<CoreState>>,
+) -> Result<HouseholdOverview, String> {
+    let owner_id = {
+        let guard = state.read_session().map_err(|e| e.to_string())?;
+        guard.as_ref().ok_or("No active profile session")?.profile_id
+    };
+
+    let profiles = profile::list_profiles(&state.profiles_dir).map_err(|e| e.to_string())?;
+    let app_conn = state.open_app_db().map_err(|e| e.to_string())?;
+    let unlocked = state.cached_profile_ids().map_err(|e| e.to_string())?;
+    let lang = state.get_profile_language();
+    let today = chrono::Local::now().date_naive();
+
+    let overview = household::build_household_overview(
+        &app_conn,
+        &profiles,
+        &owner_id,
+        &unlocked,
+        |id| {
+            state
+                .open_db_for_profile(id)
+                .map_err(|e| HouseholdError::Unavailable(*id, e.to_string()))
+        },
+        &lang,
+        today,
+    )
+    .map_err(|e| e.to_string())?;
+
+    for member in &overview.members {
+        state.log_access(
+            AccessSource::DesktopUi,
+            "household_overview",
+            &member.profile_id.to_string(),
+        );
+    }
+    state.update_activity();
+
+    Ok(overview)
+}
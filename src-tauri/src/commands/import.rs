@@ -773,6 +773,8 @@ pub async fn delete_document(
 
         // Clean up pending review file if it exists
         let _ = crate::commands::review::remove_pending_structuring_pub(session, &doc_id);
+        // CKP-01: Drop processing checkpoints of an unfinished run
+        crate::pipeline::checkpoint::remove_checkpoints(session, &doc_id);
 
         let _ = app.emit("document-deleted", doc_id.to_string());
 
@@ -790,6 +792,152 @@ pub async fn delete_document(
     .map_err(|e| format!("Task failed: {e}"))?
 }
 
+// ---------------------------------------------------------------------------
+// MPA-01: Merge separately imported photos into one document
+// ---------------------------------------------------------------------------
+
+/// Distinct source IDs of a merge, in order. A repeated ID is kept once, so
+/// a page is never merged (or deleted) twice.
+fn merge_source_ids(document_ids: &[String]) -> Result<Vec<uuid::Uuid>, String> {
+    let mut doc_ids = Vec::with_capacity(document_ids.len());
+    for id in document_ids {
+        let doc_id = uuid::Uuid::parse_str(id).map_err(|e| format!("Invalid document ID: {e}"))?;
+        if !doc_ids.contains(&doc_id) {
+            doc_ids.push(doc_id);
+        }
+    }
+    if doc_ids.len() < 2 {
+        return Err("Select at least two different documents to merge".to_string());
+    }
+    Ok(doc_ids)
+}
+
+/// Delete the merged sources in one transaction. Refuses a target that is
+/// also a source, which would delete the merge result itself.
+fn delete_merge_sources(
+    conn: &rusqlite::Connection,
+    target: &uuid::Uuid,
+    sources: &[uuid::Uuid],
+) -> Result<(), String> {
+    if sources.contains(target) {
+        return Err("Merge target cannot also be a source".to_string());
+    }
+    let deleted = (|| -> Result<(), crate::db::DatabaseError> {
+        let tx = conn.unchecked_transaction()?;
+        for doc_id in sources {
+            crate::db::repository::delete_document_cascade(&tx, doc_id)?;
+        }
+        tx.commit()?;
+        Ok(())
+    })();
+    deleted.map_err(|e| format!("Delete failed: {e}"))
+}
+
+/// Merge photo documents, in the given order, into one multi-page document.
+///
+/// The merged document is queued for processing; the source documents (and
+/// their extracted entities) are deleted in one transaction once the merged
+/// one is staged. If that fails, the merged document is removed instead.
+/// Returns the new document ID.
+#[tauri::command]
+pub async fn merge_documents(
+    document_ids: Vec<String>,
+    state: State<'_, Arc<CoreState>>,
+    app: AppHandle,
+) -> Result<String, String> {
+    let state = state.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let doc_ids = merge_source_ids(&document_ids)?;
+
+        let guard = state.read_session().map_err(|e| e.to_string())?;
+        let session = guard
+            .as_ref()
+            .ok_or("No active profile. Unlock a profile first.")?;
+        let conn = open_database(session.db_path(), Some(session.key_bytes()))
+            .map_err(|e| format!("Database error: {e}"))?;
+
+        let mut pages = Vec::with_capacity(doc_ids.len());
+        let mut title = String::new();
+        for doc_id in &doc_ids {
+            let doc = crate::db::repository::get_document(&conn, doc_id)
+                .map_err(|e| format!("Database error: {e}"))?
+                .ok_or_else(|| format!("Document not found: {doc_id}"))?;
+
+            if matches!(
+                doc.pipeline_status,
+                crate::models::enums::PipelineStatus::Extracting
+                    | crate::models::enums::PipelineStatus::Structuring
+            ) {
+                return Err(format!("Document '{}' is still being processed", doc.title));
+            }
+
+            let staged = Path::new(&doc.source_file);
+            let format = crate::pipeline::import::staging::detect_staged_format(staged, session)
+                .map_err(|e| format!("Cannot read '{}': {e}", doc.title))?;
+            if format.category != crate::pipeline::import::format::FileCategory::Image {
+                return Err(format!("'{}' is not a photo — only photos can be merged", doc.title));
+            }
+
+            pages.push(
+                crate::pipeline::import::staging::read_staged_file(staged, session)
+                    .map_err(|e| format!("Cannot read '{}': {e}", doc.title))?,
+            );
+            if title.is_empty() {
+                title = doc.title;
+            }
+        }
+
+        let merged = crate::pipeline::import::importer::import_page_images(&title, &pages, session, &conn)
+            .map_err(|e| format!("Merge failed: {e}"))?;
+        if merged.status != ImportStatus::Staged {
+            return Err(format!("Merge failed: {:?}", merged.status));
+        }
+
+        // Either all sources are replaced by the merged document, or none
+        // are and the merged copy is discarded.
+        if let Err(e) = delete_merge_sources(&conn, &merged.document_id, &doc_ids) {
+            if !doc_ids.contains(&merged.document_id) {
+                let _ = crate::db::repository::delete_document_cascade(&conn, &merged.document_id);
+                let _ = crate::pipeline::import::staging::remove_staged(&merged.document_id, session);
+            }
+            return Err(e);
+        }
+
+        for doc_id in &doc_ids {
+            let _ = crate::commands::review::remove_pending_structuring_pub(session, doc_id);
+            let _ = crate::pipeline::import::staging::remove_staged(doc_id, session);
+            crate::pipeline::checkpoint::remove_checkpoints(session, doc_id);
+            let _ = app.emit("document-deleted", doc_id.to_string());
+        }
+
+        state.import_queue().enqueue_document(
+            merged.document_id.to_string(),
+            merged.staged_path.clone(),
+            title,
+        );
+        // IMQ-01: Persist so the merged document is processed after a restart
+        if let Err(e) = state.import_queue().persist(&conn) {
+            tracing::warn!(error = %e, "Failed to persist import queue");
+        }
+
+        state.log_access(
+            crate::core_state::AccessSource::DesktopUi,
+            "merge_documents",
+            &format!("document:{}", merged.document_id),
+        );
+        state.update_activity();
+
+        tracing::info!(
+            document_id = %merged.document_id,
+            pages = pages.len(),
+            "Documents merged via IPC"
+        );
+        Ok(merged.document_id.to_string())
+    })
+    .await
+    .map_err(|e| format!("Task failed: {e}"))?
+}
+
 // ---------------------------------------------------------------------------
 // P.1: Reprocess Document IPC
 // ---------------------------------------------------------------------------
@@ -839,17 +987,28 @@ pub async fn reprocess_document(
             ));
         }
 
+        // CKP-01: Re-detect the staged format so vision pages go through the
+        // same extraction path (and checkpoints) as the original run.
+        let format = crate::pipeline::import::staging::detect_staged_format(
+            std::path::Path::new(&doc.source_file),
+            session,
+        )
+        .unwrap_or_else(|e| {
+            tracing::warn!(document_id = %doc_id, error = %e, "Reprocess: format detection failed, falling back to PlainText");
+            crate::pipeline::import::format::FormatDetection {
+                mime_type: "application/octet-stream".into(),
+                category: crate::pipeline::import::format::FileCategory::PlainText,
+                is_digital_pdf: None,
+                file_size_bytes: 0,
+            }
+        });
+
         // P.7: Build ImportResult from existing document (skips duplicate detection).
         let import_result = ImportResult {
             document_id: doc.id,
             original_filename: doc.title.clone(),
             staged_path: doc.source_file.clone(),
-            format: crate::pipeline::import::format::FormatDetection {
-                mime_type: "application/octet-stream".into(),
-                category: crate::pipeline::import::format::FileCategory::PlainText,
-                is_digital_pdf: None,
-                file_size_bytes: 0,
-            },
+            format,
             duplicate_of: None,
             status: ImportStatus::Staged,
         };
@@ -920,11 +1079,13 @@ pub async fn reprocess_document(
             tracing::warn!(document_id = %doc_id, error = %e, "Failed to reset pipeline status");
         }
 
-        // Reprocessing skips import stage — starts at extracting
-        let tracker: StageTracker = std::sync::Arc::new(
-            std::sync::atomic::AtomicU8::new(crate::pipeline::processor::STAGE_EXTRACTING),
-        );
-        processor.set_stage_tracker(tracker.clone());
+        // Reprocessing skips import stage — starts at extracting.
+        // CKP-01: ProgressTracker so pages restored from checkpoints are counted.
+        let progress = std::sync::Arc::new(crate::pipeline::processor::ProgressTracker::new(
+            crate::pipeline::processor::STAGE_EXTRACTING,
+        ));
+        processor.set_progress_tracker(progress.clone());
+        let tracker: StageTracker = progress.stage_tracker();
 
         let _ = app.emit(
             "processing-progress",
@@ -1064,6 +1225,40 @@ struct ProcessingBatchProgressEvent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn merge_sources_are_distinct() {
+        let (a, b) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let ids = |list: &[uuid::Uuid]| list.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(merge_source_ids(&ids(&[a, b, a])).unwrap(), vec![a, b]);
+        assert!(merge_source_ids(&ids(&[a, a])).is_err());
+        assert!(merge_source_ids(&ids(&[a])).is_err());
+        assert!(merge_source_ids(&["not-a-uuid".into(), b.to_string()]).is_err());
+    }
+
+    #[test]
+    fn merge_target_listed_as_source_is_not_deleted() {
+        let conn = crate::db::sqlite::open_memory_database().unwrap();
+        let ids: Vec<uuid::Uuid> = (0..2).map(|_| uuid::Uuid::new_v4()).collect();
+        for id in &ids {
+            conn.execute(
+                "INSERT INTO documents (id, type, title, ingestion_date, source_file, verified)
+                 VALUES (?1, 'other', 'Photo', datetime('now'), '/tmp/p.jpg', 0)",
+                rusqlite::params![id.to_string()],
+            )
+            .unwrap();
+        }
+
+        assert!(delete_merge_sources(&conn, &ids[0], &ids).is_err());
+        for id in &ids {
+            assert!(crate::db::repository::get_document(&conn, id).unwrap().is_some());
+        }
+
+        delete_merge_sources(&conn, &ids[0], &ids[1..]).unwrap();
+        assert!(crate::db::repository::get_document(&conn, &ids[0]).unwrap().is_some());
+        assert!(crate::db::repository::get_document(&conn, &ids[1]).unwrap().is_none());
+    }
+
     #[test]
     fn import_progress_event_serializes() {
         let event = ImportProgressEvent {
@@ -6,22 +6,41 @@ use tauri::State;
 
 use crate::core_state::CoreState;
 use crate::import_queue::QueueSnapshot;
+use crate::import_queue_worker::persist_queue;
+use crate::pipeline::import::pdf_password::{self, SavedPdfPassword};
 
 /// Enqueue one or more files for import. Returns job IDs.
 ///
 /// UC-01: `document_type` bypasses LLM classification when provided.
 /// Values: `"lab_report"`, `"prescription"`, `"medical_image"`.
+///
+/// MPA-01: `as_one_document` imports the photos, in the given order, as the
+/// pages of a single document (one job).
+///
+/// PWD-01: `pdf_password` is tried first on password-protected PDFs.
 #[tauri::command]
 pub fn enqueue_imports(
     file_paths: Vec<String>,
     document_type: Option<String>,
+    as_one_document: Option<bool>,
+    pdf_password: Option<String>,
     state: State<'_, Arc<CoreState>>,
 ) -> Vec<String> {
     let queue = state.import_queue();
-    file_paths
-        .into_iter()
-        .map(|path| queue.enqueue(path, document_type.clone()))
-        .collect()
+    let job_ids = if as_one_document.unwrap_or(false) {
+        queue
+            .enqueue_group(file_paths, document_type)
+            .into_iter()
+            .collect()
+    } else {
+        file_paths
+            .into_iter()
+            .map(|path| queue.enqueue_with_password(path, document_type.clone(), pdf_password.clone()))
+            .collect()
+    };
+    // IMQ-01: Queued jobs survive a restart even before the worker starts them
+    persist_queue(&state);
+    job_ids
 }
 
 /// Get a snapshot of the entire import queue.
@@ -41,19 +60,26 @@ pub fn cancel_import(
     state
         .import_queue()
         .cancel(&job_id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    persist_queue(&state);
+    Ok(())
 }
 
 /// Retry a failed import job. Returns the new job ID.
+///
+/// PWD-01: `pdf_password` replaces the password of a protected PDF job.
 #[tauri::command]
 pub fn retry_import(
     job_id: String,
+    pdf_password: Option<String>,
     state: State<'_, Arc<CoreState>>,
 ) -> Result<String, String> {
-    state
+    let new_job_id = state
         .import_queue()
-        .retry(&job_id)
-        .map_err(|e| e.to_string())
+        .retry_with_password(&job_id, pdf_password)
+        .map_err(|e| e.to_string())?;
+    persist_queue(&state);
+    Ok(new_job_id)
 }
 
 /// Delete a terminal import job from the queue.
@@ -65,5 +91,44 @@ pub fn delete_import(
     state
         .import_queue()
         .delete(&job_id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    persist_queue(&state);
+    Ok(())
+}
+
+/// PWD-01: List the profile's saved PDF passwords by ID and label.
+/// The passwords themselves never cross IPC.
+#[tauri::command]
+pub fn get_pdf_passwords(
+    state: State<'_, Arc<CoreState>>,
+) -> Result<Vec<SavedPdfPassword>, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    pdf_password::list_saved_passwords(&conn).map_err(|e| e.to_string())
+}
+
+/// PWD-01: Save a PDF password under a label (tried on protected PDFs).
+#[tauri::command]
+pub fn add_pdf_password(
+    label: String,
+    password: String,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<SavedPdfPassword, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    let saved = pdf_password::add_password(&conn, &label, &password).map_err(|e| e.to_string())?;
+    state.update_activity();
+    Ok(saved)
+}
+
+/// PWD-01: Remove a saved PDF password.
+#[tauri::command]
+pub fn remove_pdf_password(
+    password_id: String,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    let id = uuid::Uuid::parse_str(&password_id)
+        .map_err(|_| format!("Invalid password ID: {password_id}"))?;
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    pdf_password::remove_password(&conn, &id).map_err(|e| e.to_string())?;
+    state.update_activity();
+    Ok(())
 }
@@ -0,0 +1,56 @@
+//! INB-01: Inbox folder IPC commands.
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::core_state::CoreState;
+use crate::inbox_watcher::{self, InboxActivity, InboxConfig};
+
+/// Get the profile's inbox folder configuration.
+#[tauri::command]
+pub fn get_inbox_config(
+    state: State<'_, Arc<CoreState>>,
+) -> Result<InboxConfig, String> {
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+    inbox_watcher::load_config(&conn).map_err(|e| e.to_string())
+}
+
+/// Save the profile's inbox folder configuration. Returns the saved config.
+///
+/// Takes effect at the watcher's next scan.
+#[tauri::command]
+pub fn set_inbox_config(
+    config: InboxConfig,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<InboxConfig, String> {
+    let guard = state.read_session().map_err(|e| e.to_string())?;
+    let session = guard
+        .as_ref()
+        .ok_or("No active profile. Unlock a profile first.")?;
+    let conn = crate::db::open_database(session.db_path(), Some(session.key_bytes()))
+        .map_err(|e| format!("Database error: {e}"))?;
+
+    let saved = inbox_watcher::save_config(&conn, &config, &state.profiles_dir)
+        .map_err(|e| e.to_string())?;
+    state.inbox().set_config(session.profile_id, saved.clone());
+
+    state.log_access(
+        crate::core_state::AccessSource::DesktopUi,
+        "set_inbox_config",
+        if saved.enabled { "inbox:enabled" } else { "inbox:disabled" },
+    );
+    state.update_activity();
+    Ok(saved)
+}
+
+/// Recent inbox activity for the unlocked profile, newest first.
+#[tauri::command]
+pub fn get_inbox_activity(
+    state: State<'_, Arc<CoreState>>,
+) -> Vec<InboxActivity> {
+    if state.is_locked() {
+        return vec![];
+    }
+    state.inbox().activity()
+}
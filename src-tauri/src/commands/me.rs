@@ -3,6 +3,7 @@
 //! Commands:
 //! - `get_me_overview`: unified fetch for the entire Me screen
 //! - `record_vital_sign`: record a vital sign measurement (ME-04)
+//! - `import_vitals`: bulk-import a wearable/home-device export (VIT-01)
 //! - `record_screening`: record a screening/vaccination date (ME-06)
 //! - `delete_screening_record`: remove a screening record (ME-06)
 
@@ -11,8 +12,10 @@ use std::sync::Arc;
 use tauri::State;
 
 use crate::core_state::CoreState;
+use crate::invariants::growth;
 use crate::me::MeOverview;
 use crate::models::{VitalSign, VitalSource, VitalTrendPoint, VitalType};
+use crate::pipeline::import::vitals::{CsvMapping, VitalsFormat, VitalsImportSummary};
 
 /// Fetches all Me screen data in a single call.
 ///
@@ -73,6 +76,47 @@ pub fn record_vital_sign(
     Ok(())
 }
 
+/// VIT-01: Import vitals from an Apple Health, Google Fit, Health Connect or
+/// CSV export.
+///
+/// `format` is detected from the file when omitted; CSV needs `csv_mapping`.
+/// Runs on a blocking thread via `spawn_blocking` — Apple Health exports can
+/// hold millions of records.
+#[tauri::command]
+pub async fn import_vitals(
+    path: String,
+    format: Option<VitalsFormat>,
+    csv_mapping: Option<CsvMapping>,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<VitalsImportSummary, String> {
+    let state = state.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = std::path::Path::new(&path);
+        if !path.is_file() {
+            return Err(format!("File not found: {}", path.display()));
+        }
+
+        let conn = state.open_db().map_err(|e| e.to_string())?;
+        let summary = crate::pipeline::import::vitals::import_vitals(
+            &conn,
+            path,
+            format,
+            csv_mapping.as_ref(),
+        )
+        .map_err(|e| format!("Vitals import failed: {e}"))?;
+
+        state.log_access(
+            crate::core_state::AccessSource::DesktopUi,
+            "import_vitals",
+            &format!("vital_signs:{}", summary.inserted),
+        );
+        state.update_activity();
+        Ok(summary)
+    })
+    .await
+    .map_err(|e| format!("Task failed: {e}"))?
+}
+
 /// ME-06: Record a screening or vaccination date.
 ///
 /// Validates screening_key against known schedules, date is not in the future,
@@ -137,6 +181,10 @@ pub fn record_screening(
 ///
 /// Returns lightweight `(value, recorded_at)` pairs for the last N days,
 /// ordered chronologically. Used by MetricTile sparklines.
+///
+/// GRW-01: For child profiles, weight, height and head circumference points
+/// carry their growth-chart percentile and reference curves, and the default
+/// window covers the whole chart period instead of 30 days.
 #[tauri::command]
 pub fn get_vital_trend(
     vital_type: String,
@@ -146,10 +194,23 @@ pub fn get_vital_trend(
     let vtype = VitalType::from_str(&vital_type)
         .ok_or_else(|| format!("Invalid vital type: {vital_type}"))?;
     let conn = state.open_db().map_err(|e| e.to_string())?;
-    crate::db::get_vital_trend(&conn, &vtype, days.unwrap_or(30))
-        .map_err(|e| e.to_string())
+
+    let demographics = state.get_patient_demographics();
+    let growth_profile = growth::GrowthIndicator::for_vital(vtype)
+        .and(growth::growth_profile(demographics.as_ref(), chrono::Local::now().date_naive()));
+    let default_days = if growth_profile.is_some() { GROWTH_TREND_DAYS } else { 30 };
+
+    let mut points = crate::db::get_vital_trend(&conn, &vtype, days.unwrap_or(default_days))
+        .map_err(|e| e.to_string())?;
+    if let Some((sex, dob)) = growth_profile {
+        growth::annotate_trend(&mut points, vtype, sex, dob);
+    }
+    Ok(points)
 }
 
+/// GRW-01: Growth charts span from birth to 20 years.
+const GROWTH_TREND_DAYS: u32 = 20 * 366;
+
 /// ME-06: Delete a screening record by ID.
 ///
 /// Scoped to the active profile for safety.
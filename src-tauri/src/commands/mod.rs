@@ -6,18 +6,22 @@ pub mod coherence;
 pub mod companion_access;
 pub mod devices;
 pub mod distribution;
+pub mod emergency_card;
 
 pub mod extraction;
 pub mod home;
+pub mod household;
 pub mod import;
 pub mod me;
 pub mod import_queue;
+pub mod inbox;
 pub mod journal;
 pub mod medications;
 pub mod mobile_api;
 pub mod pairing;
 pub mod profile;
 pub mod review;
+pub mod sensitivity;
 pub mod state;
 pub mod sync;
 pub mod timeline;
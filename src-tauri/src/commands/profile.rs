@@ -174,6 +174,9 @@ pub async fn unlock_profile(
             }
         }
 
+        // IMQ-01: Wake the import queue worker to restore this profile's queue
+        state.import_queue().notifier().notify_one();
+
         // Notify connected phones about profile change (RS-M0-03-003)
         if let Ok(mut devices) = state.write_devices() {
             devices.broadcast(WsOutgoing::ProfileChanged {
@@ -250,6 +253,9 @@ pub async fn recover_profile(
             tracing::warn!("Failed to hydrate devices: {e}");
         }
 
+        // IMQ-01: Wake the import queue worker to restore this profile's queue
+        state.import_queue().notifier().notify_one();
+
         // Notify connected phones about profile change (RS-M0-03-003)
         if let Ok(mut devices) = state.write_devices() {
             devices.broadcast(WsOutgoing::ProfileChanged { profile_name });
@@ -402,14 +408,32 @@ pub fn update_profile_demographics(
         .map(|bt| BloodType::from_str(bt).map_err(|_| format!("Invalid blood type: {bt}")))
         .transpose()?;
 
-    profile::update_profile_demographics(
+    let info = profile::update_profile_demographics(
         &state.profiles_dir,
         &id,
         parsed_sex,
         parsed_ethnicities,
         parsed_blood_type,
     )
-    .map_err(|e| e.to_string())
+    .map_err(|e| e.to_string())?;
+
+    // EMC-01: Blood type lives on the profile, not in the database, so phones
+    // need an explicit nudge to refresh their lock-screen emergency card.
+    let is_active = state
+        .read_session()
+        .ok()
+        .and_then(|guard| guard.as_ref().map(|s| s.profile_id == id))
+        .unwrap_or(false);
+    if is_active {
+        let refreshed = state.open_db().map_err(|e| e.to_string()).and_then(|conn| {
+            crate::emergency_card::touch_lock_screen_card(&conn).map_err(|e| e.to_string())
+        });
+        if let Err(e) = refreshed {
+            tracing::warn!(error = %e, "Failed to refresh lock-screen emergency card version");
+        }
+    }
+
+    Ok(info)
 }
 
 /// Spec 46 [CG-02]: Get caregiver summaries for all dependents managed by the current user.
@@ -14,8 +14,9 @@ use uuid::Uuid;
 
 use crate::core_state::CoreState;
 use crate::crypto::encryption::EncryptedData;
-use crate::db::repository::get_document;
+use crate::db::repository::{get_document, is_document_suspicious};
 use crate::db::sqlite::open_database;
+use crate::pipeline::extraction::{heic, preprocess};
 use crate::pipeline::structuring::types::StructuringResult;
 use crate::review::{
     apply_corrections, count_extracted_fields, detect_file_type, flatten_entities_to_fields,
@@ -23,6 +24,7 @@ use crate::review::{
     update_document_verified, EntitiesStoredSummary, ExcludedEntity, FieldCorrection,
     ReviewConfirmResult, ReviewData, ReviewOutcome, ReviewRejectResult,
 };
+use crate::sensitivity;
 
 // ---------------------------------------------------------------------------
 // Structuring result persistence (encrypted JSON in profile directory)
@@ -140,6 +142,14 @@ pub fn get_review_data(
     let plausibility_warnings =
         generate_plausibility_warnings(&conn, &structuring, &extracted_fields);
 
+    // INJ-01: Flag from this structuring run, or from an earlier import of the document
+    let suspicious_content = structuring.suspicious_content
+        || is_document_suspicious(&conn, &doc_id).unwrap_or(false);
+
+    // SENS-01: Current flag + keyword suggestion for the sensitivity toggle
+    let sensitive = sensitivity::is_document_sensitive(&conn, &doc_id).unwrap_or(false);
+    let sensitivity_suggestion = sensitivity::suggest_for_document(&structuring, Some(&doc.title));
+
     // Determine original file type
     let original_file_type = detect_file_type(&doc.source_file);
 
@@ -164,6 +174,9 @@ pub fn get_review_data(
         extracted_fields,
         plausibility_warnings,
         overall_confidence: doc.ocr_confidence.unwrap_or(0.0),
+        suspicious_content,
+        sensitive,
+        sensitivity_suggestion,
     })
 }
 
@@ -195,6 +208,15 @@ pub fn get_original_file(
         .decrypt(&encrypted)
         .map_err(|e| format!("Decryption failed: {e}"))?;
 
+    // HEIC-01: webviews cannot display HEIC, so show an upright PNG copy.
+    // The stored original stays untouched.
+    let decrypted = if heic::is_heic(&decrypted) {
+        let img = preprocess::decode_oriented(&decrypted).map_err(|e| e.to_string())?;
+        preprocess::encode_png(&img.to_rgb8()).map_err(|e| e.to_string())?
+    } else {
+        decrypted
+    };
+
     // Encode as base64
     use base64::Engine;
     let encoded = base64::engine::general_purpose::STANDARD.encode(&decrypted);
@@ -246,6 +268,7 @@ pub fn confirm_review(
     document_id: String,
     corrections: Vec<FieldCorrection>,
     excluded_entities: Vec<ExcludedEntity>,
+    sensitive: Option<bool>,
     state: State<'_, Arc<CoreState>>,
 ) -> Result<ReviewConfirmResult, String> {
     let guard = state.read_session().map_err(|e| e.to_string())?;
@@ -321,6 +344,19 @@ pub fn confirm_review(
     // Step 5: Mark document as verified
     update_document_verified(&conn, &doc_id).map_err(|e| e.to_string())?;
 
+    // SENS-01: Patient's choice from the review toggle; without one, keep an
+    // existing flag or follow the keyword suggestion so likely-sensitive
+    // documents start hidden.
+    let sensitive = match sensitive {
+        Some(flag) => flag,
+        None => {
+            let title = get_document(&conn, &doc_id).ok().flatten().map(|d| d.title);
+            sensitivity::is_document_sensitive(&conn, &doc_id).unwrap_or(false)
+                || sensitivity::suggest_for_document(&structuring, title.as_deref()).is_some()
+        }
+    };
+    sensitivity::set_document_sensitive(&conn, &doc_id, sensitive).map_err(|e| e.to_string())?;
+
     // O.5: Update pipeline status → Confirmed
     if let Err(e) = crate::db::repository::update_pipeline_status(
         &conn,
@@ -380,6 +416,7 @@ pub fn confirm_review(
             procedures: storage_result.entities_stored.procedures,
             referrals: storage_result.entities_stored.referrals,
             instructions: storage_result.entities_stored.instructions,
+            immunizations: storage_result.entities_stored.immunizations,
         },
         corrections_applied,
         chunks_stored: storage_result.chunks_stored,
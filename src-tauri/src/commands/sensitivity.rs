@@ -0,0 +1,79 @@
+//! SENS-01: Sensitive-record tagging — Tauri IPC commands.
+//!
+//! Commands:
+//! - `set_document_sensitivity`: flag/unflag a document (hides its entities)
+//! - `set_entity_sensitivity`: flag/unflag a single medication, lab, diagnosis,
+//!   allergy or procedure
+//! - `set_conversation_sensitive_context`: per-conversation override letting
+//!   chat read flagged records
+
+use std::sync::Arc;
+
+use tauri::State;
+use uuid::Uuid;
+
+use crate::core_state::{AccessSource, CoreState};
+use crate::sensitivity::{self, SensitiveEntity};
+
+/// Sets or clears the sensitivity flag on a document.
+#[tauri::command]
+pub fn set_document_sensitivity(
+    document_id: String,
+    sensitive: bool,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    let doc_id =
+        Uuid::parse_str(&document_id).map_err(|e| format!("Invalid document ID: {e}"))?;
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+
+    sensitivity::set_document_sensitive(&conn, &doc_id, sensitive).map_err(|e| e.to_string())?;
+
+    state.log_access(
+        AccessSource::DesktopUi,
+        if sensitive { "mark_sensitive" } else { "unmark_sensitive" },
+        &format!("document:{doc_id}"),
+    );
+    state.update_activity();
+    Ok(())
+}
+
+/// Sets or clears the sensitivity flag on a single extracted entity.
+#[tauri::command]
+pub fn set_entity_sensitivity(
+    entity_type: SensitiveEntity,
+    entity_id: String,
+    sensitive: bool,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    let id = Uuid::parse_str(&entity_id).map_err(|e| format!("Invalid entity ID: {e}"))?;
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+
+    sensitivity::set_entity_sensitive(&conn, entity_type, &id, sensitive)
+        .map_err(|e| e.to_string())?;
+
+    state.log_access(
+        AccessSource::DesktopUi,
+        if sensitive { "mark_sensitive" } else { "unmark_sensitive" },
+        &format!("{}:{id}", entity_type.table()),
+    );
+    state.update_activity();
+    Ok(())
+}
+
+/// Lets (or stops) chat reading sensitive records in one conversation.
+#[tauri::command]
+pub fn set_conversation_sensitive_context(
+    conversation_id: String,
+    include_sensitive: bool,
+    state: State<'_, Arc<CoreState>>,
+) -> Result<(), String> {
+    let conv_id = Uuid::parse_str(&conversation_id)
+        .map_err(|e| format!("Invalid conversation ID: {e}"))?;
+    let conn = state.open_db().map_err(|e| e.to_string())?;
+
+    sensitivity::set_conversation_include_sensitive(&conn, &conv_id, include_sensitive)
+        .map_err(|e| e.to_string())?;
+
+    state.update_activity();
+    Ok(())
+}
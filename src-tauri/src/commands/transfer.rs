@@ -4,18 +4,19 @@
 //! Files received are staged in the profile directory, then processed through
 //! the L1-01 document import pipeline on demand.
 
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use tauri::State;
 
 use crate::core_state::CoreState;
 use crate::db::sqlite::open_database;
-use crate::pipeline::import::importer::import_file;
+use crate::pipeline::import::importer::{import_file, import_image_group};
 use crate::pipeline::import::staging::decrypt_staging_to_temp;
 use crate::wifi_transfer::{
-    generate_qr_code, start_transfer_server, QrCodeData, TransferConfig,
-    TransferStatusResponse,
+    generate_qr_code, parse_group_staging_name, start_transfer_server, QrCodeData,
+    TransferConfig, TransferStatusResponse,
 };
 
 /// Derive the WiFi staging directory from the profile's database path.
@@ -107,83 +108,147 @@ pub async fn process_staged_files(
 ) -> Result<u32, String> {
     let state = state.inner().clone();
     tauri::async_runtime::spawn_blocking(move || {
-        let (staging_dir, db_path) = {
-            let guard = state.read_session().map_err(|e| e.to_string())?;
-            let session = guard
-                .as_ref()
-                .ok_or("No active profile session")?;
-            let staging = staging_dir_from_db_path(session.db_path())?;
-            (staging, session.db_path().to_path_buf())
-        };
-
-        if !staging_dir.exists() {
-            return Ok(0);
-        }
-
-        let entries: Vec<_> = std::fs::read_dir(&staging_dir)
-            .map_err(|e| format!("Failed to read staging dir: {e}"))?
-            .filter_map(|e| e.ok())
-            .collect();
-
-        if entries.is_empty() {
-            return Ok(0);
-        }
+        let count = import_staged_transfers(&state)?;
+        state.update_activity();
+        Ok(count)
+    })
+    .await
+    .map_err(|e| format!("Task failed: {e}"))?
+}
 
-        // Re-acquire session for import_file (needs ProfileSession reference)
+/// Import the active profile's staged WiFi transfer files. Blocking.
+///
+/// Imported files are securely deleted; failed ones stay staged for retry.
+/// IMQ-01: Also run by the import queue worker after unlock, so transfers
+/// interrupted by a crash are imported rather than discarded.
+pub(crate) fn import_staged_transfers(state: &CoreState) -> Result<u32, String> {
+    let (staging_dir, db_path) = {
         let guard = state.read_session().map_err(|e| e.to_string())?;
         let session = guard
             .as_ref()
             .ok_or("No active profile session")?;
+        let staging = staging_dir_from_db_path(session.db_path())?;
+        (staging, session.db_path().to_path_buf())
+    };
+
+    if !staging_dir.exists() {
+        return Ok(0);
+    }
 
-        let conn = open_database(&db_path, Some(session.key_bytes()))
-            .map_err(|e| format!("Database error: {e}"))?;
+    let entries: Vec<_> = std::fs::read_dir(&staging_dir)
+        .map_err(|e| format!("Failed to read staging dir: {e}"))?
+        .filter_map(|e| e.ok())
+        .collect();
 
-        let key = session.key_bytes();
-        let mut count = 0u32;
-        for entry in entries {
-            let path = entry.path();
-            if !path.is_file() {
+    if entries.is_empty() {
+        return Ok(0);
+    }
+
+    // Re-acquire session for import_file (needs ProfileSession reference)
+    let guard = state.read_session().map_err(|e| e.to_string())?;
+    let session = guard
+        .as_ref()
+        .ok_or("No active profile session")?;
+
+    let conn = open_database(&db_path, Some(session.key_bytes()))
+        .map_err(|e| format!("Database error: {e}"))?;
+
+    let key = session.key_bytes();
+    let mut count = 0u32;
+
+    // MPA-01: Pages of a multi-photo upload are imported together, in order
+    let mut groups: BTreeMap<uuid::Uuid, Vec<(usize, PathBuf)>> = BTreeMap::new();
+    let mut singles = Vec::new();
+    for entry in entries {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let name = entry.file_name();
+        match parse_group_staging_name(&name.to_string_lossy()) {
+            Some((group_id, index)) => groups.entry(group_id).or_default().push((index, path)),
+            None => singles.push(path),
+        }
+    }
+
+    for path in singles {
+        // SEC-02-G04: Decrypt encrypted staging file to temp for import
+        let temp_file = match decrypt_staging_to_temp(&path, key) {
+            Ok(t) => t,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    "WiFi transfer: staging decryption failed"
+                );
                 continue;
             }
+        };
 
-            // SEC-02-G04: Decrypt encrypted staging file to temp for import
-            let temp_file = match decrypt_staging_to_temp(&path, key) {
-                Ok(t) => t,
-                Err(e) => {
-                    tracing::warn!(
-                        error = %e,
-                        "WiFi transfer: staging decryption failed"
-                    );
-                    continue;
-                }
-            };
-
-            match import_file(temp_file.path(), session, &conn) {
-                Ok(result) => {
-                    tracing::info!(
-                        document_id = %result.document_id,
-                        filename = %result.original_filename,
-                        status = ?result.status,
-                        "WiFi transfer file imported"
-                    );
-                    // Remove encrypted staged file after successful import (SEC-02-G05)
-                    crate::crypto::secure_delete_file(&path).ok();
-                    count += 1;
-                }
-                Err(e) => {
-                    tracing::warn!(
-                        error = %e,
-                        "Failed to import WiFi transfer file"
-                    );
-                    // Leave failed files for retry
+        match import_file(temp_file.path(), session, &conn) {
+            Ok(result) => {
+                tracing::info!(
+                    document_id = %result.document_id,
+                    filename = %result.original_filename,
+                    status = ?result.status,
+                    "WiFi transfer file imported"
+                );
+                // Remove encrypted staged file after successful import (SEC-02-G05)
+                crate::crypto::secure_delete_file(&path).ok();
+                count += 1;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    "Failed to import WiFi transfer file"
+                );
+                // Leave failed files for retry
+            }
+        }
+        // temp_file dropped here → auto-deleted
+    }
+
+    for (group_id, mut pages) in groups {
+        pages.sort_by_key(|(index, _)| *index);
+
+        let temp_files = match pages
+            .iter()
+            .map(|(_, path)| decrypt_staging_to_temp(path, key))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(t) => t,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    group = %group_id,
+                    "WiFi transfer: staging decryption failed"
+                );
+                continue;
+            }
+        };
+        let temp_paths: Vec<PathBuf> = temp_files.iter().map(|t| t.path().to_path_buf()).collect();
+
+        match import_image_group(&temp_paths, session, &conn) {
+            Ok(result) => {
+                tracing::info!(
+                    document_id = %result.document_id,
+                    pages = pages.len(),
+                    status = ?result.status,
+                    "WiFi transfer page group imported"
+                );
+                for (_, path) in &pages {
+                    crate::crypto::secure_delete_file(path).ok();
                 }
+                count += 1;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    group = %group_id,
+                    "Failed to import WiFi transfer page group"
+                );
             }
-            // temp_file dropped here → auto-deleted
         }
+    }
 
-        state.update_activity();
-        Ok(count)
-    })
-    .await
-    .map_err(|e| format!("Task failed: {e}"))?
+    Ok(count)
 }
@@ -20,6 +20,7 @@ use crate::distribution::DistributionServer;
 use crate::butler_service::ButlerService;
 use crate::chat_queue::ChatQueueService;
 use crate::import_queue::ImportQueueService;
+use crate::inbox_watcher::InboxWatcher;
 use crate::invariants::InvariantRegistry;
 use crate::ollama_service::OllamaService;
 use crate::pairing::PairingManager;
@@ -77,6 +78,8 @@ pub struct CoreState {
     ai_verified: AtomicBool,
     /// BTL-10: Import queue service — document import lifecycle manager.
     import_queue: ImportQueueService,
+    /// INB-01: Watched inbox folder of the unlocked profile.
+    inbox: InboxWatcher,
     /// CHAT-QUEUE-01: Chat queue service — deferred message lifecycle manager.
     chat_queue: ChatQueueService,
     /// ME-03: Invariant Reference Engine — curated medical knowledge.
@@ -133,6 +136,7 @@ impl CoreState {
             butler: ButlerService::new(),
             ai_verified: AtomicBool::new(false),
             import_queue: ImportQueueService::new(),
+            inbox: InboxWatcher::new(),
             chat_queue: ChatQueueService::new(),
             invariant_registry,
         }
@@ -456,6 +460,11 @@ impl CoreState {
         &self.import_queue
     }
 
+    /// INB-01: Access the inbox folder watcher.
+    pub fn inbox(&self) -> &InboxWatcher {
+        &self.inbox
+    }
+
     /// CHAT-QUEUE-01: Access the chat queue service.
     pub fn chat_queue(&self) -> &ChatQueueService {
         &self.chat_queue
@@ -487,6 +496,26 @@ impl CoreState {
         Some(PatientDemographics::from_profile(&info))
     }
 
+    /// IMM-01: Date of birth of a profile (immunization schedule).
+    /// Returns None if the profile is unknown or has no date of birth set.
+    pub fn get_date_of_birth(&self, profile_id: &Uuid) -> Option<chrono::NaiveDate> {
+        let profiles = crate::crypto::profile::list_profiles(&self.profiles_dir).ok()?;
+        profiles
+            .into_iter()
+            .find(|p| p.id == *profile_id)?
+            .date_of_birth
+    }
+
+    /// EMC-01: Blood type of a profile (lock-screen emergency card).
+    /// Returns None if the profile is unknown or has no blood type set.
+    pub fn get_blood_type(&self, profile_id: &Uuid) -> Option<crate::models::enums::BloodType> {
+        let profiles = crate::crypto::profile::list_profiles(&self.profiles_dir).ok()?;
+        profiles
+            .into_iter()
+            .find(|p| p.id == *profile_id)?
+            .blood_type
+    }
+
     /// I18N-03: Get the user's preferred language from user_preferences.
     /// Returns "en" if no preference set or if profile is locked.
     pub fn get_profile_language(&self) -> String {
@@ -716,6 +745,7 @@ mod tests {
             butler: ButlerService::new(),
             ai_verified: AtomicBool::new(false),
             import_queue: ImportQueueService::new(),
+            inbox: InboxWatcher::new(),
             chat_queue: ChatQueueService::new(),
             invariant_registry: InvariantRegistry::empty(),
         };
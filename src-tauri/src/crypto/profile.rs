@@ -145,6 +145,8 @@ pub struct PatientDemographics {
     pub age_years: Option<u16>,
     /// BT-01: Blood type for Rh-awareness enrichment and RAG context.
     pub blood_type: Option<BloodType>,
+    /// GRW-01: Exact date of birth — growth percentiles need age in months.
+    pub date_of_birth: Option<NaiveDate>,
 }
 
 impl PatientDemographics {
@@ -165,6 +167,7 @@ impl PatientDemographics {
             age_context,
             age_years,
             blood_type: profile.blood_type.clone(),
+            date_of_birth: profile.date_of_birth,
         }
     }
 
@@ -1201,6 +1204,7 @@ mod tests {
             age_context: None,
             age_years: None,
             blood_type: None,
+            date_of_birth: None,
         };
         assert!(demo.has_asian_bmi_thresholds());
 
@@ -1210,6 +1214,7 @@ mod tests {
             age_context: None,
             age_years: None,
             blood_type: None,
+            date_of_birth: None,
         };
         assert!(demo2.has_asian_bmi_thresholds());
 
@@ -1219,6 +1224,7 @@ mod tests {
             age_context: None,
             age_years: None,
             blood_type: None,
+            date_of_birth: None,
         };
         assert!(demo3.has_asian_bmi_thresholds());
     }
@@ -1231,6 +1237,7 @@ mod tests {
             age_context: None,
             age_years: None,
             blood_type: None,
+            date_of_birth: None,
         };
         assert!(!demo.has_asian_bmi_thresholds());
     }
@@ -1243,6 +1250,7 @@ mod tests {
             age_context: None,
             age_years: None,
             blood_type: None,
+            date_of_birth: None,
         };
         assert!(demo.has_asian_bmi_thresholds());
     }
@@ -1255,6 +1263,7 @@ mod tests {
             age_context: None,
             age_years: None,
             blood_type: None,
+            date_of_birth: None,
         };
         assert!(!demo.has_asian_bmi_thresholds());
     }
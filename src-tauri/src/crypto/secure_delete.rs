@@ -47,7 +47,12 @@ pub fn secure_delete_file(path: &Path) -> std::io::Result<()> {
 /// Clean orphaned staging files from all profile directories (SEC-02-G08).
 ///
 /// Called at startup to remove files left behind by previous crashes.
-/// Scans: `profiles/{uuid}/staging/mobile/` and `profiles/{uuid}/wifi_staging/`.
+/// Scans: `profiles/{uuid}/staging/mobile/` — upload buffers whose request
+/// died with the app; the phone reports the failure and the user re-sends.
+///
+/// IMQ-01: `profiles/{uuid}/wifi_staging/` is left alone. Its files are
+/// encrypted transfers still waiting for import; the import queue worker
+/// imports them after the profile is unlocked.
 pub fn cleanup_orphaned_staging(profiles_dir: &Path) {
     let entries = match fs::read_dir(profiles_dir) {
         Ok(e) => e,
@@ -65,10 +70,6 @@ pub fn cleanup_orphaned_staging(profiles_dir: &Path) {
         // Clean staging/mobile/
         let mobile_staging = profile_path.join("staging").join("mobile");
         total_cleaned += clean_staging_dir(&mobile_staging);
-
-        // Clean wifi_staging/
-        let wifi_staging = profile_path.join("wifi_staging");
-        total_cleaned += clean_staging_dir(&wifi_staging);
     }
 
     if total_cleaned > 0 {
@@ -151,9 +152,10 @@ mod tests {
         // Run cleanup
         cleanup_orphaned_staging(profiles_dir.path());
 
-        // All files should be gone
+        // Mobile upload buffers are gone
         assert!(fs::read_dir(&mobile_staging).unwrap().count() == 0);
-        assert!(fs::read_dir(&wifi_staging).unwrap().count() == 0);
+        // IMQ-01: Pending WiFi transfers are kept for import after unlock
+        assert!(wifi_staging.join("orphan3.pdf").exists());
     }
 
     #[test]
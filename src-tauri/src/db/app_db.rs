@@ -47,10 +47,16 @@ fn configure_pragmas(conn: &Connection) -> Result<(), DatabaseError> {
 fn run_app_migrations(conn: &Connection) -> Result<(), DatabaseError> {
     let current_version = get_current_version(conn);
 
-    let migrations: Vec<(i64, &str)> = vec![(
-        1,
-        include_str!("../../resources/app_migrations/001_device_registry.sql"),
-    )];
+    let migrations: Vec<(i64, &str)> = vec![
+        (
+            1,
+            include_str!("../../resources/app_migrations/001_device_registry.sql"),
+        ),
+        (
+            2,
+            include_str!("../../resources/app_migrations/002_grant_scope_expiry.sql"),
+        ),
+    ];
 
     for (version, sql) in migrations {
         if version > current_version {
@@ -95,8 +101,9 @@ mod tests {
     fn app_database_initializes_all_tables() {
         let conn = open_memory_app_database().unwrap();
         let count = count_app_tables(&conn).unwrap();
-        // schema_version + device_registry + device_profile_access + profile_access_grants = 4
-        assert_eq!(count, 4, "Expected 4 tables, got {count}");
+        // schema_version + device_registry + device_profile_access + profile_access_grants
+        // + grant_audit_log = 5
+        assert_eq!(count, 5, "Expected 5 tables, got {count}");
     }
 
     #[test]
@@ -109,7 +116,7 @@ mod tests {
                 |row| row.get(0),
             )
             .unwrap();
-        assert_eq!(version, 1);
+        assert_eq!(version, 2);
     }
 
     #[test]
@@ -133,12 +140,12 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let conn = open_app_database(dir.path()).unwrap();
         let count = count_app_tables(&conn).unwrap();
-        assert_eq!(count, 4);
+        assert_eq!(count, 5);
 
         // Re-open — should be idempotent
         let conn2 = open_app_database(dir.path()).unwrap();
         let count2 = count_app_tables(&conn2).unwrap();
-        assert_eq!(count2, 4);
+        assert_eq!(count2, 5);
     }
 
     #[test]
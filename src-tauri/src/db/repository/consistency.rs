@@ -140,7 +140,8 @@ pub fn check_consistency(conn: &Connection) -> Result<ConsistencyReport, Databas
 ///
 /// Currently repairs:
 /// - Trust count drift -> recalculates from actual data
-/// - Stuck pipeline states -> resets to Failed
+/// - Stuck pipeline states -> resets to Imported (IMQ-01: the import queue
+///   resumes them from extraction)
 ///
 /// Returns the number of issues repaired.
 pub fn repair_consistency(conn: &Connection) -> Result<usize, DatabaseError> {
@@ -168,14 +169,14 @@ pub fn repair_consistency(conn: &Connection) -> Result<usize, DatabaseError> {
         repaired += 1;
     }
 
-    // Repair stuck pipeline states -> Failed
+    // Repair stuck pipeline states -> Imported (resumed by the import queue)
     let stuck_fixed = conn.execute(
-        "UPDATE documents SET pipeline_status = 'failed'
+        "UPDATE documents SET pipeline_status = 'imported'
          WHERE pipeline_status IN ('extracting', 'structuring')",
         [],
     )?;
     if stuck_fixed > 0 {
-        tracing::info!(count = stuck_fixed, "Repaired stuck pipeline documents -> Failed");
+        tracing::info!(count = stuck_fixed, "Repaired stuck pipeline documents -> Imported");
         repaired += stuck_fixed;
     }
 
@@ -0,0 +1,201 @@
+//! MEM-01: Rolling conversation summaries and pinned patient facts.
+
+use chrono::NaiveDateTime;
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+use crate::db::DatabaseError;
+use crate::models::PinnedFact;
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Encrypted running summary of a conversation's older turns.
+#[derive(Debug, Clone)]
+pub struct StoredSummary {
+    /// `EncryptedData::to_bytes()` of the UTF-8 summary.
+    pub encrypted: Vec<u8>,
+    /// Number of leading messages (oldest first) the summary covers.
+    pub summarized_through: usize,
+}
+
+/// A conversation whose unsummarised backlog has grown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryCandidate {
+    pub conversation_id: Uuid,
+    pub message_count: usize,
+    pub summarized_through: usize,
+}
+
+pub fn get_conversation_summary(
+    conn: &Connection,
+    conversation_id: &Uuid,
+) -> Result<Option<StoredSummary>, DatabaseError> {
+    let result = conn.query_row(
+        "SELECT summary, summarized_through FROM conversation_memory WHERE conversation_id = ?1",
+        params![conversation_id.to_string()],
+        |row| {
+            Ok(StoredSummary {
+                encrypted: row.get(0)?,
+                summarized_through: row.get::<_, i64>(1)?.max(0) as usize,
+            })
+        },
+    );
+
+    match result {
+        Ok(summary) => Ok(Some(summary)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn upsert_conversation_summary(
+    conn: &Connection,
+    conversation_id: &Uuid,
+    encrypted: &[u8],
+    summarized_through: usize,
+) -> Result<(), DatabaseError> {
+    conn.execute(
+        "INSERT INTO conversation_memory (conversation_id, summary, summarized_through, updated_at)
+         VALUES (?1, ?2, ?3, datetime('now'))
+         ON CONFLICT(conversation_id) DO UPDATE SET
+             summary = excluded.summary,
+             summarized_through = excluded.summarized_through,
+             updated_at = excluded.updated_at",
+        params![conversation_id.to_string(), encrypted, summarized_through as i64],
+    )?;
+    Ok(())
+}
+
+/// Conversations with at least `min_backlog` messages that are neither in the
+/// summary nor among the `recent_turns` sent verbatim. Oldest activity first.
+pub fn get_summary_candidates(
+    conn: &Connection,
+    recent_turns: usize,
+    min_backlog: usize,
+    limit: usize,
+) -> Result<Vec<SummaryCandidate>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT c.id, COUNT(m.id) AS message_count,
+                COALESCE(cm.summarized_through, 0) AS summarized_through
+         FROM conversations c
+         JOIN messages m ON m.conversation_id = c.id
+         LEFT JOIN conversation_memory cm ON cm.conversation_id = c.id
+         GROUP BY c.id
+         HAVING message_count - ?1 - summarized_through >= ?2
+         ORDER BY MAX(m.timestamp) ASC
+         LIMIT ?3",
+    )?;
+
+    let rows = stmt.query_map(
+        params![recent_turns as i64, min_backlog as i64, limit as i64],
+        |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        },
+    )?;
+
+    let mut candidates = Vec::new();
+    for row in rows {
+        let (id, message_count, summarized_through) = row?;
+        candidates.push(SummaryCandidate {
+            conversation_id: Uuid::parse_str(&id)
+                .map_err(|e| DatabaseError::ConstraintViolation(e.to_string()))?,
+            message_count: message_count.max(0) as usize,
+            summarized_through: summarized_through.max(0) as usize,
+        });
+    }
+    Ok(candidates)
+}
+
+pub fn insert_pinned_fact(conn: &Connection, fact: &PinnedFact) -> Result<(), DatabaseError> {
+    conn.execute(
+        "INSERT INTO pinned_facts (id, conversation_id, message_id, fact, domain, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            fact.id.to_string(),
+            fact.conversation_id.to_string(),
+            fact.message_id.to_string(),
+            fact.fact,
+            fact.domain,
+            fact.created_at.format(TIMESTAMP_FORMAT).to_string(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// Pinned facts of a conversation, oldest first.
+pub fn get_pinned_facts(
+    conn: &Connection,
+    conversation_id: &Uuid,
+) -> Result<Vec<PinnedFact>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, conversation_id, message_id, fact, domain, created_at
+         FROM pinned_facts WHERE conversation_id = ?1
+         ORDER BY created_at ASC, rowid ASC",
+    )?;
+
+    let rows = stmt.query_map(params![conversation_id.to_string()], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+            row.get::<_, Option<String>>(4)?,
+            row.get::<_, String>(5)?,
+        ))
+    })?;
+
+    let parse_id = |s: &str| {
+        Uuid::parse_str(s).map_err(|e| DatabaseError::ConstraintViolation(e.to_string()))
+    };
+
+    let mut facts = Vec::new();
+    for row in rows {
+        let (id, conversation_id, message_id, fact, domain, created_at) = row?;
+        facts.push(PinnedFact {
+            id: parse_id(&id)?,
+            conversation_id: parse_id(&conversation_id)?,
+            message_id: parse_id(&message_id)?,
+            fact,
+            domain,
+            created_at: NaiveDateTime::parse_from_str(&created_at, TIMESTAMP_FORMAT)
+                .unwrap_or_default(),
+        });
+    }
+    Ok(facts)
+}
+
+pub fn delete_pinned_fact(conn: &Connection, id: &Uuid) -> Result<(), DatabaseError> {
+    let rows = conn.execute(
+        "DELETE FROM pinned_facts WHERE id = ?1",
+        params![id.to_string()],
+    )?;
+    if rows == 0 {
+        return Err(DatabaseError::NotFound {
+            entity_type: "PinnedFact".into(),
+            id: id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Keep only the `keep` newest pinned facts of a conversation.
+pub fn prune_pinned_facts(
+    conn: &Connection,
+    conversation_id: &Uuid,
+    keep: usize,
+) -> Result<usize, DatabaseError> {
+    let removed = conn.execute(
+        "DELETE FROM pinned_facts
+         WHERE conversation_id = ?1
+           AND id NOT IN (
+               SELECT id FROM pinned_facts WHERE conversation_id = ?1
+               ORDER BY created_at DESC, rowid DESC LIMIT ?2
+           )",
+        params![conversation_id.to_string(), keep as i64],
+    )?;
+    Ok(removed)
+}
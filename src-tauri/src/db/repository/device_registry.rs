@@ -3,6 +3,7 @@
 //! Operates on `app.db` (unencrypted, global) — NOT per-profile databases.
 //! Follows the same function-based pattern as per-profile repositories.
 
+use chrono::{DateTime, SecondsFormat, Utc};
 use rusqlite::{params, Connection};
 
 use crate::db::DatabaseError;
@@ -126,6 +127,10 @@ pub struct DeviceProfileAccessRow {
     pub profile_id: String,
     pub access_level: String,
     pub granted_at: String,
+    /// GRANT-01: JSON array of entity categories; `None` = all categories.
+    pub scope: Option<String>,
+    /// GRANT-01: RFC 3339 UTC expiry; `None` = never expires.
+    pub expires_at: Option<String>,
 }
 
 /// Grant a device access to a specific profile.
@@ -134,11 +139,25 @@ pub fn grant_device_profile_access(
     device_id: &str,
     profile_id: &str,
     access_level: &str,
+) -> Result<(), DatabaseError> {
+    grant_scoped_device_profile_access(conn, device_id, profile_id, access_level, None, None)
+}
+
+/// GRANT-01: Grant a device category-scoped and/or time-limited access to a profile.
+///
+/// Replaces any existing entry for the same device and profile.
+pub fn grant_scoped_device_profile_access(
+    conn: &Connection,
+    device_id: &str,
+    profile_id: &str,
+    access_level: &str,
+    scope: Option<&str>,
+    expires_at: Option<&str>,
 ) -> Result<(), DatabaseError> {
     conn.execute(
-        "INSERT OR REPLACE INTO device_profile_access (device_id, profile_id, access_level, granted_at)
-         VALUES (?1, ?2, ?3, datetime('now'))",
-        params![device_id, profile_id, access_level],
+        "INSERT OR REPLACE INTO device_profile_access (device_id, profile_id, access_level, granted_at, scope, expires_at)
+         VALUES (?1, ?2, ?3, datetime('now'), ?4, ?5)",
+        params![device_id, profile_id, access_level, scope, expires_at],
     )?;
     Ok(())
 }
@@ -162,20 +181,25 @@ pub fn list_accessible_profiles(
     device_id: &str,
 ) -> Result<Vec<DeviceProfileAccessRow>, DatabaseError> {
     let mut stmt = conn.prepare(
-        "SELECT dpa.device_id, dpa.profile_id, dpa.access_level, dpa.granted_at
+        "SELECT dpa.device_id, dpa.profile_id, dpa.access_level, dpa.granted_at,
+                dpa.scope, dpa.expires_at
          FROM device_profile_access dpa
          JOIN device_registry dr ON dr.device_id = dpa.device_id
          WHERE dpa.device_id = ?1 AND dr.is_revoked = 0
+           AND (dpa.expires_at IS NULL OR dpa.expires_at > ?2)
          ORDER BY dpa.granted_at",
     )?;
 
+    let now = grant_timestamp(Utc::now());
     let rows = stmt
-        .query_map(params![device_id], |row| {
+        .query_map(params![device_id, now], |row| {
             Ok(DeviceProfileAccessRow {
                 device_id: row.get(0)?,
                 profile_id: row.get(1)?,
                 access_level: row.get(2)?,
                 granted_at: row.get(3)?,
+                scope: row.get(4)?,
+                expires_at: row.get(5)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -189,20 +213,32 @@ pub fn has_device_profile_access(
     device_id: &str,
     profile_id: &str,
 ) -> Result<Option<String>, DatabaseError> {
+    let now = grant_timestamp(Utc::now());
+    Ok(find_device_profile_access(conn, device_id, profile_id, &now)?.map(|g| g.access_level))
+}
+
+/// GRANT-01: Unexpired device access to a profile, with its scope.
+///
+/// `now` must come from [`grant_timestamp`] so the string comparison holds.
+pub fn find_device_profile_access(
+    conn: &Connection,
+    device_id: &str,
+    profile_id: &str,
+    now: &str,
+) -> Result<Option<ActiveGrant>, DatabaseError> {
     let mut stmt = conn.prepare(
-        "SELECT dpa.access_level
+        "SELECT dpa.access_level, dpa.scope, dpa.expires_at
          FROM device_profile_access dpa
          JOIN device_registry dr ON dr.device_id = dpa.device_id
-         WHERE dpa.device_id = ?1 AND dpa.profile_id = ?2 AND dr.is_revoked = 0",
+         WHERE dpa.device_id = ?1 AND dpa.profile_id = ?2 AND dr.is_revoked = 0
+           AND (dpa.expires_at IS NULL OR dpa.expires_at > ?3)",
     )?;
 
-    let level = stmt
-        .query_row(params![device_id, profile_id], |row| {
-            row.get::<_, String>(0)
-        })
+    let grant = stmt
+        .query_row(params![device_id, profile_id, now], ActiveGrant::from_row)
         .optional()?;
 
-    Ok(level)
+    Ok(grant)
 }
 
 // ═══════════════════════════════════════════════════════════
@@ -218,16 +254,30 @@ pub struct ProfileAccessGrantRow {
     pub access_level: String,
     pub granted_at: String,
     pub revoked_at: Option<String>,
+    /// GRANT-01: JSON array of entity categories; `None` = all categories.
+    pub scope: Option<String>,
+    /// GRANT-01: RFC 3339 UTC expiry; `None` = never expires.
+    pub expires_at: Option<String>,
 }
 
 /// Insert a new profile access grant.
+///
+/// GRANT-01: A grant between the same two profiles (active, revoked or
+/// expired) is replaced, so an expired grant can be renewed.
 pub fn insert_profile_grant(
     conn: &Connection,
     grant: &ProfileAccessGrantRow,
 ) -> Result<(), DatabaseError> {
     conn.execute(
-        "INSERT INTO profile_access_grants (id, granter_profile_id, grantee_profile_id, access_level, granted_at, revoked_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        "INSERT INTO profile_access_grants (id, granter_profile_id, grantee_profile_id, access_level, granted_at, revoked_at, scope, expires_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+         ON CONFLICT(granter_profile_id, grantee_profile_id) DO UPDATE SET
+            id = excluded.id,
+            access_level = excluded.access_level,
+            granted_at = excluded.granted_at,
+            revoked_at = excluded.revoked_at,
+            scope = excluded.scope,
+            expires_at = excluded.expires_at",
         params![
             grant.id,
             grant.granter_profile_id,
@@ -235,6 +285,8 @@ pub fn insert_profile_grant(
             grant.access_level,
             grant.granted_at,
             grant.revoked_at,
+            grant.scope,
+            grant.expires_at,
         ],
     )?;
     Ok(())
@@ -254,20 +306,23 @@ pub fn revoke_profile_grant(
     Ok(updated > 0)
 }
 
-/// List all active (non-revoked) grants where the given profile is the grantee.
+/// List all active (non-revoked, unexpired) grants where the given profile is the grantee.
 pub fn list_grants_for_grantee(
     conn: &Connection,
     grantee_id: &str,
 ) -> Result<Vec<ProfileAccessGrantRow>, DatabaseError> {
     let mut stmt = conn.prepare(
-        "SELECT id, granter_profile_id, grantee_profile_id, access_level, granted_at, revoked_at
+        "SELECT id, granter_profile_id, grantee_profile_id, access_level, granted_at, revoked_at,
+                scope, expires_at
          FROM profile_access_grants
          WHERE grantee_profile_id = ?1 AND revoked_at IS NULL
+           AND (expires_at IS NULL OR expires_at > ?2)
          ORDER BY granted_at",
     )?;
 
+    let now = grant_timestamp(Utc::now());
     let rows = stmt
-        .query_map(params![grantee_id], |row| {
+        .query_map(params![grantee_id, now], |row| {
             Ok(ProfileAccessGrantRow {
                 id: row.get(0)?,
                 granter_profile_id: row.get(1)?,
@@ -275,6 +330,8 @@ pub fn list_grants_for_grantee(
                 access_level: row.get(3)?,
                 granted_at: row.get(4)?,
                 revoked_at: row.get(5)?,
+                scope: row.get(6)?,
+                expires_at: row.get(7)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -282,20 +339,23 @@ pub fn list_grants_for_grantee(
     Ok(rows)
 }
 
-/// List all active (non-revoked) grants where the given profile is the granter.
+/// List all active (non-revoked, unexpired) grants where the given profile is the granter.
 pub fn list_grants_for_granter(
     conn: &Connection,
     granter_id: &str,
 ) -> Result<Vec<ProfileAccessGrantRow>, DatabaseError> {
     let mut stmt = conn.prepare(
-        "SELECT id, granter_profile_id, grantee_profile_id, access_level, granted_at, revoked_at
+        "SELECT id, granter_profile_id, grantee_profile_id, access_level, granted_at, revoked_at,
+                scope, expires_at
          FROM profile_access_grants
          WHERE granter_profile_id = ?1 AND revoked_at IS NULL
+           AND (expires_at IS NULL OR expires_at > ?2)
          ORDER BY granted_at",
     )?;
 
+    let now = grant_timestamp(Utc::now());
     let rows = stmt
-        .query_map(params![granter_id], |row| {
+        .query_map(params![granter_id, now], |row| {
             Ok(ProfileAccessGrantRow {
                 id: row.get(0)?,
                 granter_profile_id: row.get(1)?,
@@ -303,6 +363,8 @@ pub fn list_grants_for_granter(
                 access_level: row.get(3)?,
                 granted_at: row.get(4)?,
                 revoked_at: row.get(5)?,
+                scope: row.get(6)?,
+                expires_at: row.get(7)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -316,18 +378,193 @@ pub fn has_active_grant(
     granter_id: &str,
     grantee_id: &str,
 ) -> Result<Option<String>, DatabaseError> {
+    let now = grant_timestamp(Utc::now());
+    Ok(find_active_grant(conn, granter_id, grantee_id, &now)?.map(|g| g.access_level))
+}
+
+/// GRANT-01: Active (non-revoked, unexpired) grant from granter to grantee, with its scope.
+///
+/// `now` must come from [`grant_timestamp`] so the string comparison holds.
+pub fn find_active_grant(
+    conn: &Connection,
+    granter_id: &str,
+    grantee_id: &str,
+    now: &str,
+) -> Result<Option<ActiveGrant>, DatabaseError> {
     let mut stmt = conn.prepare(
-        "SELECT access_level FROM profile_access_grants
-         WHERE granter_profile_id = ?1 AND grantee_profile_id = ?2 AND revoked_at IS NULL",
+        "SELECT access_level, scope, expires_at FROM profile_access_grants
+         WHERE granter_profile_id = ?1 AND grantee_profile_id = ?2 AND revoked_at IS NULL
+           AND (expires_at IS NULL OR expires_at > ?3)",
     )?;
 
-    let level = stmt
-        .query_row(params![granter_id, grantee_id], |row| {
-            row.get::<_, String>(0)
-        })
+    let grant = stmt
+        .query_row(params![granter_id, grantee_id, now], ActiveGrant::from_row)
         .optional()?;
 
-    Ok(level)
+    Ok(grant)
+}
+
+// ═══════════════════════════════════════════════════════════
+// GRANT-01: Scope, expiry and grant audit
+// ═══════════════════════════════════════════════════════════
+
+/// Format a timestamp for `expires_at` columns and expiry comparisons.
+///
+/// Fixed-width RFC 3339 UTC (`2026-10-18T09:30:00Z`): SQLite compares these
+/// as strings, so every stored and compared value must use this format.
+pub fn grant_timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Level, scope and expiry of a grant that currently applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveGrant {
+    pub access_level: String,
+    pub scope: Option<String>,
+    pub expires_at: Option<String>,
+}
+
+impl ActiveGrant {
+    fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            access_level: row.get(0)?,
+            scope: row.get(1)?,
+            expires_at: row.get(2)?,
+        })
+    }
+}
+
+/// A grant removed by [`expire_grants`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiredGrant {
+    /// `"profile_grant"` or `"device_access"`.
+    pub grant_type: &'static str,
+    /// Grant ID, or device ID for device access.
+    pub grant_ref: String,
+    /// Profile whose data was shared.
+    pub profile_id: String,
+    /// Grantee profile ID, or device ID for device access.
+    pub grantee: String,
+    pub expires_at: String,
+}
+
+/// A row from the `grant_audit_log` table.
+#[derive(Debug, Clone)]
+pub struct GrantAuditRow {
+    pub grant_type: String,
+    pub grant_ref: String,
+    pub profile_id: String,
+    pub grantee: String,
+    pub action: String,
+    pub detail: Option<String>,
+    pub occurred_at: String,
+}
+
+/// Expire every grant whose `expires_at` is at or before `now`.
+///
+/// Profile grants are soft-revoked (`revoked_at = expires_at`); device access
+/// entries are deleted, matching their manual revoke. Each expiry writes an
+/// `expired` row to `grant_audit_log`. All changes commit together.
+pub fn expire_grants(conn: &Connection, now: &str) -> Result<Vec<ExpiredGrant>, DatabaseError> {
+    let tx = conn.unchecked_transaction()?;
+    let mut expired = Vec::new();
+
+    {
+        let mut stmt = tx.prepare(
+            "SELECT id, granter_profile_id, grantee_profile_id, expires_at
+             FROM profile_access_grants
+             WHERE revoked_at IS NULL AND expires_at IS NOT NULL AND expires_at <= ?1",
+        )?;
+        let rows = stmt.query_map(params![now], |row| {
+            Ok(ExpiredGrant {
+                grant_type: "profile_grant",
+                grant_ref: row.get(0)?,
+                profile_id: row.get(1)?,
+                grantee: row.get(2)?,
+                expires_at: row.get(3)?,
+            })
+        })?;
+        for row in rows {
+            expired.push(row?);
+        }
+
+        let mut stmt = tx.prepare(
+            "SELECT device_id, profile_id, expires_at
+             FROM device_profile_access
+             WHERE expires_at IS NOT NULL AND expires_at <= ?1",
+        )?;
+        let rows = stmt.query_map(params![now], |row| {
+            let device_id: String = row.get(0)?;
+            Ok(ExpiredGrant {
+                grant_type: "device_access",
+                grantee: device_id.clone(),
+                grant_ref: device_id,
+                profile_id: row.get(1)?,
+                expires_at: row.get(2)?,
+            })
+        })?;
+        for row in rows {
+            expired.push(row?);
+        }
+    }
+
+    for grant in &expired {
+        if grant.grant_type == "profile_grant" {
+            tx.execute(
+                "UPDATE profile_access_grants SET revoked_at = expires_at WHERE id = ?1",
+                params![grant.grant_ref],
+            )?;
+        } else {
+            tx.execute(
+                "DELETE FROM device_profile_access WHERE device_id = ?1 AND profile_id = ?2",
+                params![grant.grant_ref, grant.profile_id],
+            )?;
+        }
+        tx.execute(
+            "INSERT INTO grant_audit_log (grant_type, grant_ref, profile_id, grantee, action, detail, occurred_at)
+             VALUES (?1, ?2, ?3, ?4, 'expired', ?5, ?6)",
+            params![
+                grant.grant_type,
+                grant.grant_ref,
+                grant.profile_id,
+                grant.grantee,
+                format!("expires_at={}", grant.expires_at),
+                now,
+            ],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok(expired)
+}
+
+/// Grant audit entries for a shared profile, newest first.
+pub fn list_grant_audit(
+    conn: &Connection,
+    profile_id: &str,
+) -> Result<Vec<GrantAuditRow>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT grant_type, grant_ref, profile_id, grantee, action, detail, occurred_at
+         FROM grant_audit_log
+         WHERE profile_id = ?1
+         ORDER BY occurred_at DESC, id DESC",
+    )?;
+
+    let rows = stmt
+        .query_map(params![profile_id], |row| {
+            Ok(GrantAuditRow {
+                grant_type: row.get(0)?,
+                grant_ref: row.get(1)?,
+                profile_id: row.get(2)?,
+                grantee: row.get(3)?,
+                action: row.get(4)?,
+                detail: row.get(5)?,
+                occurred_at: row.get(6)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(rows)
 }
 
 // ═══════════════════════════════════════════════════════════
@@ -506,6 +743,8 @@ mod tests {
             access_level: "read_only".to_string(),
             granted_at: "2026-02-23T10:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         insert_profile_grant(&conn, &grant).unwrap();
@@ -524,6 +763,8 @@ mod tests {
             access_level: "read_only".to_string(),
             granted_at: "2026-02-23T10:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         insert_profile_grant(&conn, &grant).unwrap();
@@ -547,6 +788,8 @@ mod tests {
             access_level: "read_only".to_string(),
             granted_at: "2026-02-23T10:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         insert_profile_grant(&conn, &grant).unwrap();
@@ -569,6 +812,8 @@ mod tests {
             access_level: "read_only".to_string(),
             granted_at: "2026-02-23T10:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         let grant2 = ProfileAccessGrantRow {
@@ -578,6 +823,8 @@ mod tests {
             access_level: "full".to_string(),
             granted_at: "2026-02-23T11:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         let grant3 = ProfileAccessGrantRow {
@@ -587,6 +834,8 @@ mod tests {
             access_level: "read_only".to_string(),
             granted_at: "2026-02-23T12:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         insert_profile_grant(&conn, &grant1).unwrap();
@@ -618,6 +867,8 @@ mod tests {
             access_level: "read_only".to_string(),
             granted_at: "2026-02-23T10:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         let grant2 = ProfileAccessGrantRow {
@@ -627,6 +878,8 @@ mod tests {
             access_level: "full".to_string(),
             granted_at: "2026-02-23T11:00:00Z".to_string(),
             revoked_at: None,
+            scope: None,
+            expires_at: None,
         };
 
         insert_profile_grant(&conn, &grant1).unwrap();
@@ -639,4 +892,124 @@ mod tests {
         assert_eq!(grants.len(), 1);
         assert_eq!(grants[0].granter_profile_id, "charlie");
     }
+
+    // ── GRANT-01: Scope and expiry ───────────────────────────
+
+    fn scoped_grant(id: &str, granter: &str, grantee: &str, expires_at: &str) -> ProfileAccessGrantRow {
+        ProfileAccessGrantRow {
+            id: id.to_string(),
+            granter_profile_id: granter.to_string(),
+            grantee_profile_id: grantee.to_string(),
+            access_level: "read_only".to_string(),
+            granted_at: "2026-10-01T08:00:00Z".to_string(),
+            revoked_at: None,
+            scope: Some(r#"["medications","allergies"]"#.to_string()),
+            expires_at: Some(expires_at.to_string()),
+        }
+    }
+
+    #[test]
+    fn grant_timestamp_is_fixed_width_utc() {
+        let at = DateTime::parse_from_rfc3339("2026-10-18T11:30:00.123+02:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(grant_timestamp(at), "2026-10-18T09:30:00Z");
+    }
+
+    #[test]
+    fn find_active_grant_returns_scope_and_ignores_expired() {
+        let conn = test_db();
+        insert_profile_grant(&conn, &scoped_grant("grant-1", "mom", "sister", "2026-10-20T00:00:00Z"))
+            .unwrap();
+
+        let active = find_active_grant(&conn, "mom", "sister", "2026-10-18T09:00:00Z")
+            .unwrap()
+            .unwrap();
+        assert_eq!(active.access_level, "read_only");
+        assert_eq!(active.scope.as_deref(), Some(r#"["medications","allergies"]"#));
+
+        let after = find_active_grant(&conn, "mom", "sister", "2026-10-20T00:00:00Z").unwrap();
+        assert!(after.is_none(), "Grant must not apply at or after its expiry");
+    }
+
+    #[test]
+    fn expired_device_access_is_ignored() {
+        let conn = test_db();
+        insert_device(&conn, &sample_device("dev-1", "profile-1")).unwrap();
+        grant_scoped_device_profile_access(
+            &conn,
+            "dev-1",
+            "profile-2",
+            "read_only",
+            Some(r#"["medications"]"#),
+            Some("2026-10-20T00:00:00Z"),
+        )
+        .unwrap();
+
+        let before = find_device_profile_access(&conn, "dev-1", "profile-2", "2026-10-19T00:00:00Z")
+            .unwrap()
+            .unwrap();
+        assert_eq!(before.scope.as_deref(), Some(r#"["medications"]"#));
+
+        let after = find_device_profile_access(&conn, "dev-1", "profile-2", "2026-10-21T00:00:00Z").unwrap();
+        assert!(after.is_none());
+    }
+
+    #[test]
+    fn expire_grants_revokes_and_audits() {
+        let conn = test_db();
+        insert_device(&conn, &sample_device("dev-1", "sister")).unwrap();
+        insert_profile_grant(&conn, &scoped_grant("grant-1", "mom", "sister", "2026-10-15T00:00:00Z"))
+            .unwrap();
+        insert_profile_grant(&conn, &scoped_grant("grant-2", "mom", "brother", "2099-01-01T00:00:00Z"))
+            .unwrap();
+        grant_scoped_device_profile_access(
+            &conn,
+            "dev-1",
+            "mom",
+            "read_only",
+            None,
+            Some("2026-10-16T00:00:00Z"),
+        )
+        .unwrap();
+
+        let expired = expire_grants(&conn, "2026-10-18T00:00:00Z").unwrap();
+        assert_eq!(expired.len(), 2);
+        assert!(expired.iter().any(|g| g.grant_type == "profile_grant" && g.grant_ref == "grant-1"));
+        assert!(expired.iter().any(|g| g.grant_type == "device_access" && g.grant_ref == "dev-1"));
+
+        // Expired grant is revoked at its expiry time; the other stays active
+        let revoked_at: Option<String> = conn
+            .query_row(
+                "SELECT revoked_at FROM profile_access_grants WHERE id = 'grant-1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(revoked_at.as_deref(), Some("2026-10-15T00:00:00Z"));
+        assert!(has_active_grant(&conn, "mom", "brother").unwrap().is_some());
+        assert!(has_device_profile_access(&conn, "dev-1", "mom").unwrap().is_none());
+
+        let audit = list_grant_audit(&conn, "mom").unwrap();
+        assert_eq!(audit.len(), 2);
+        assert!(audit.iter().all(|a| a.action == "expired"));
+
+        // Second sweep is a no-op
+        assert!(expire_grants(&conn, "2026-10-18T00:00:00Z").unwrap().is_empty());
+        assert_eq!(list_grant_audit(&conn, "mom").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn expired_grant_can_be_renewed() {
+        let conn = test_db();
+        insert_profile_grant(&conn, &scoped_grant("grant-1", "mom", "sister", "2026-10-15T00:00:00Z"))
+            .unwrap();
+        expire_grants(&conn, "2026-10-18T00:00:00Z").unwrap();
+
+        insert_profile_grant(&conn, &scoped_grant("grant-2", "mom", "sister", "2099-01-01T00:00:00Z"))
+            .unwrap();
+        let renewed = find_active_grant(&conn, "mom", "sister", "2026-10-18T00:00:00Z").unwrap();
+        assert!(renewed.is_some());
+        assert_eq!(list_grants_for_granter(&conn, "mom").unwrap()[0].id, "grant-2");
+    }
 }
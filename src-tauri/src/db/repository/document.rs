@@ -143,6 +143,32 @@ pub fn update_pipeline_status(
     Ok(())
 }
 
+/// INJ-01: Flag a document whose source text contained prompt-injection patterns.
+pub fn mark_document_suspicious(conn: &Connection, document_id: &Uuid) -> Result<(), DatabaseError> {
+    let rows = conn.execute(
+        "UPDATE documents SET suspicious_content = 1 WHERE id = ?1",
+        params![document_id.to_string()],
+    )?;
+    if rows == 0 {
+        return Err(DatabaseError::NotFound {
+            entity_type: "Document".into(),
+            id: document_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// INJ-01: Whether a document (or any of its chunks) was flagged as suspicious.
+pub fn is_document_suspicious(conn: &Connection, document_id: &Uuid) -> Result<bool, DatabaseError> {
+    let flagged: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM documents WHERE id = ?1 AND suspicious_content = 1)
+             OR EXISTS(SELECT 1 FROM vector_chunks WHERE document_id = ?1 AND suspicious = 1)",
+        params![document_id.to_string()],
+        |row| row.get(0),
+    )?;
+    Ok(flagged)
+}
+
 /// Update only the page_count of a document (set after extraction).
 pub fn update_document_page_count(
     conn: &Connection,
@@ -197,7 +223,7 @@ pub fn get_documents_by_pipeline_status(
 /// Delete a document and all its child entities.
 ///
 /// Entity tables (medications, lab_results, diagnoses, allergies, procedures,
-/// referrals) lack CASCADE on document_id FK, so we delete children first.
+/// referrals, immunizations) lack CASCADE on document_id FK, so we delete children first.
 /// Vector chunks DO have CASCADE but we delete them explicitly for logging.
 /// Uses a transaction for atomicity.
 pub fn delete_document_cascade(conn: &Connection, document_id: &Uuid) -> Result<(), DatabaseError> {
@@ -226,11 +252,18 @@ pub fn delete_document_cascade(conn: &Connection, document_id: &Uuid) -> Result<
 
     // Delete entity tables that reference document_id
     let deleted_meds = conn.execute("DELETE FROM medications WHERE document_id = ?1", params![doc_id_str])?;
+    // REC-01: Promote duplicates of this document's results back to primary
+    conn.execute(
+        "UPDATE lab_results SET duplicate_of = NULL
+         WHERE duplicate_of IN (SELECT id FROM lab_results WHERE document_id = ?1)",
+        params![doc_id_str],
+    )?;
     let deleted_labs = conn.execute("DELETE FROM lab_results WHERE document_id = ?1", params![doc_id_str])?;
     let deleted_diag = conn.execute("DELETE FROM diagnoses WHERE document_id = ?1", params![doc_id_str])?;
     let deleted_allergy = conn.execute("DELETE FROM allergies WHERE document_id = ?1", params![doc_id_str])?;
     let deleted_procs = conn.execute("DELETE FROM procedures WHERE document_id = ?1", params![doc_id_str])?;
     conn.execute("DELETE FROM referrals WHERE document_id = ?1", params![doc_id_str])?;
+    conn.execute("DELETE FROM immunizations WHERE document_id = ?1", params![doc_id_str])?;
 
     // Delete vector chunks (has CASCADE but explicit for logging)
     let deleted_chunks = conn.execute("DELETE FROM vector_chunks WHERE document_id = ?1", params![doc_id_str])?;
@@ -244,6 +277,12 @@ pub fn delete_document_cascade(conn: &Connection, document_id: &Uuid) -> Result<
         });
     }
 
+    // REC-01: The promoted copies may still duplicate each other — re-link
+    // them so a third copy does not come back as a second primary
+    if let Err(e) = crate::intelligence::reconciliation::reconcile(conn) {
+        tracing::warn!(error = %e, "Failed to reconcile results after document deletion");
+    }
+
     // Recalculate trust metrics from actual data
     if let Err(e) = super::profile_trust::recalculate_profile_trust(conn) {
         tracing::warn!(error = %e, "Failed to recalculate trust after document deletion");
@@ -290,12 +329,20 @@ pub fn clear_document_entities(conn: &Connection, document_id: &Uuid) -> Result<
 
     // Delete entity tables
     conn.execute("DELETE FROM medications WHERE document_id = ?1", params![doc_id_str])?;
+    // REC-01: Promote duplicates of this document's results back to primary
+    conn.execute(
+        "UPDATE lab_results SET duplicate_of = NULL
+         WHERE duplicate_of IN (SELECT id FROM lab_results WHERE document_id = ?1)",
+        params![doc_id_str],
+    )?;
     conn.execute("DELETE FROM lab_results WHERE document_id = ?1", params![doc_id_str])?;
     conn.execute("DELETE FROM diagnoses WHERE document_id = ?1", params![doc_id_str])?;
     conn.execute("DELETE FROM allergies WHERE document_id = ?1", params![doc_id_str])?;
     conn.execute("DELETE FROM procedures WHERE document_id = ?1", params![doc_id_str])?;
     conn.execute("DELETE FROM referrals WHERE document_id = ?1", params![doc_id_str])?;
+    conn.execute("DELETE FROM immunizations WHERE document_id = ?1", params![doc_id_str])?;
     conn.execute("DELETE FROM vector_chunks WHERE document_id = ?1", params![doc_id_str])?;
+    super::delete_entity_sources_for_document(conn, document_id)?;
 
     tracing::debug!(document_id = %document_id, "Cleared entities and chunks for document");
     Ok(())
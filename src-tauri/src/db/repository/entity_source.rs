@@ -0,0 +1,122 @@
+//! PROV-01: Repository functions for entity_sources (field-level provenance).
+
+use std::str::FromStr;
+
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+use crate::db::DatabaseError;
+use crate::models::entity_connection::EntityType;
+use crate::models::provenance::*;
+
+/// Insert a field provenance record.
+pub fn insert_entity_source(conn: &Connection, source: &EntitySource) -> Result<(), DatabaseError> {
+    let loc = &source.location;
+    conn.execute(
+        "INSERT INTO entity_sources (id, document_id, entity_type, entity_id, field_name,
+         page_number, region_x, region_y, region_width, region_height, region_approximate,
+         span_start, span_end, quote)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        params![
+            source.id.to_string(),
+            source.document_id.to_string(),
+            source.entity_type.as_str(),
+            source.entity_id.to_string(),
+            source.field_name,
+            loc.page_number as i64,
+            loc.region.map(|r| r.x as f64),
+            loc.region.map(|r| r.y as f64),
+            loc.region.map(|r| r.width as f64),
+            loc.region.map(|r| r.height as f64),
+            loc.region_approximate as i32,
+            loc.text_span.map(|s| s.start as i64),
+            loc.text_span.map(|s| s.end as i64),
+            loc.quote,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Get all field provenance records for a document, in page order.
+pub fn get_entity_sources_for_document(
+    conn: &Connection,
+    document_id: &Uuid,
+) -> Result<Vec<EntitySource>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, document_id, entity_type, entity_id, field_name, page_number,
+                region_x, region_y, region_width, region_height, region_approximate,
+                span_start, span_end, quote
+         FROM entity_sources
+         WHERE document_id = ?1
+         ORDER BY page_number ASC, span_start ASC",
+    )?;
+
+    let rows = stmt.query_map(params![document_id.to_string()], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+            row.get::<_, String>(4)?,
+            row.get::<_, i64>(5)?,
+            (
+                row.get::<_, Option<f64>>(6)?,
+                row.get::<_, Option<f64>>(7)?,
+                row.get::<_, Option<f64>>(8)?,
+                row.get::<_, Option<f64>>(9)?,
+            ),
+            row.get::<_, bool>(10)?,
+            row.get::<_, Option<i64>>(11)?,
+            row.get::<_, Option<i64>>(12)?,
+            row.get::<_, Option<String>>(13)?,
+        ))
+    })?;
+
+    let mut sources = Vec::new();
+    for row in rows {
+        let (id, doc_id, entity_type, entity_id, field_name, page_number, rect, approximate, span_start, span_end, quote) = row?;
+        let region = match rect {
+            (Some(x), Some(y), Some(width), Some(height)) => Some(SourceRegion {
+                x: x as f32,
+                y: y as f32,
+                width: width as f32,
+                height: height as f32,
+            }),
+            _ => None,
+        };
+        let text_span = match (span_start, span_end) {
+            (Some(start), Some(end)) => Some(TextSpan {
+                start: start as usize,
+                end: end as usize,
+            }),
+            _ => None,
+        };
+        sources.push(EntitySource {
+            id: Uuid::parse_str(&id).map_err(|e| DatabaseError::InvalidData(e.to_string()))?,
+            document_id: Uuid::parse_str(&doc_id).map_err(|e| DatabaseError::InvalidData(e.to_string()))?,
+            entity_type: EntityType::from_str(&entity_type)?,
+            entity_id: Uuid::parse_str(&entity_id).map_err(|e| DatabaseError::InvalidData(e.to_string()))?,
+            field_name,
+            location: SourceLocation {
+                page_number: page_number as usize,
+                region,
+                region_approximate: approximate,
+                text_span,
+                quote,
+            },
+        });
+    }
+    Ok(sources)
+}
+
+/// Delete all provenance records for a document (idempotent reprocessing).
+pub fn delete_entity_sources_for_document(
+    conn: &Connection,
+    document_id: &Uuid,
+) -> Result<(), DatabaseError> {
+    conn.execute(
+        "DELETE FROM entity_sources WHERE document_id = ?1",
+        params![document_id.to_string()],
+    )?;
+    Ok(())
+}
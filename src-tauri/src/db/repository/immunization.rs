@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+use crate::db::DatabaseError;
+use crate::models::*;
+
+pub fn insert_immunization(conn: &Connection, imm: &Immunization) -> Result<(), DatabaseError> {
+    conn.execute(
+        "INSERT INTO immunizations (id, vaccine, dose_number, administered_date, lot_number,
+         site, administering_professional_id, document_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            imm.id.to_string(),
+            imm.vaccine,
+            imm.dose_number,
+            imm.administered_date.map(|d| d.to_string()),
+            imm.lot_number,
+            imm.site,
+            imm.administering_professional_id.map(|id| id.to_string()),
+            imm.document_id.to_string(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// All immunizations, oldest dose first (series order).
+pub fn get_all_immunizations(conn: &Connection) -> Result<Vec<Immunization>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, vaccine, dose_number, administered_date, lot_number, site,
+         administering_professional_id, document_id
+         FROM immunizations ORDER BY administered_date ASC, dose_number ASC",
+    )?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<u32>>(2)?,
+            row.get::<_, Option<String>>(3)?,
+            row.get::<_, Option<String>>(4)?,
+            row.get::<_, Option<String>>(5)?,
+            row.get::<_, Option<String>>(6)?,
+            row.get::<_, String>(7)?,
+        ))
+    })?;
+
+    let mut immunizations = Vec::new();
+    for row in rows {
+        let (id, vaccine, dose_number, date, lot_number, site, prof_id, doc_id) = row?;
+        immunizations.push(Immunization {
+            id: Uuid::parse_str(&id)
+                .map_err(|e| DatabaseError::ConstraintViolation(e.to_string()))?,
+            vaccine,
+            dose_number,
+            administered_date: date.and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()),
+            lot_number,
+            site,
+            administering_professional_id: prof_id.and_then(|s| Uuid::parse_str(&s).ok()),
+            document_id: Uuid::parse_str(&doc_id)
+                .map_err(|e| DatabaseError::ConstraintViolation(e.to_string()))?,
+        });
+    }
+    Ok(immunizations)
+}
@@ -0,0 +1,126 @@
+//! IMQ-01: Repository functions for import_jobs (persistent import queue).
+//!
+//! PDF passwords are never written: `ImportJob::pdf_password` is memory-only.
+
+use rusqlite::{params, Connection};
+
+use crate::db::DatabaseError;
+use crate::import_queue::{ImportJob, JobState};
+
+/// Insert or update an import job.
+pub fn upsert_import_job(conn: &Connection, job: &ImportJob) -> Result<(), DatabaseError> {
+    let page_paths = if job.page_paths.is_empty() {
+        None
+    } else {
+        Some(
+            serde_json::to_string(&job.page_paths)
+                .map_err(|e| DatabaseError::InvalidData(e.to_string()))?,
+        )
+    };
+    conn.execute(
+        "INSERT INTO import_jobs (id, file_path, filename, state, progress_pct, document_id,
+         model_used, error, queued_at, started_at, completed_at, user_document_type, page_paths)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+         ON CONFLICT(id) DO UPDATE SET
+         state = excluded.state,
+         progress_pct = excluded.progress_pct,
+         document_id = excluded.document_id,
+         model_used = excluded.model_used,
+         error = excluded.error,
+         started_at = excluded.started_at,
+         completed_at = excluded.completed_at",
+        params![
+            job.id,
+            job.file_path,
+            job.filename,
+            job.state.as_str(),
+            job.progress_pct as i64,
+            job.document_id,
+            job.model_used,
+            job.error,
+            job.queued_at,
+            job.started_at,
+            job.completed_at,
+            job.user_document_type,
+            page_paths,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Get all persisted import jobs, oldest first.
+pub fn list_import_jobs(conn: &Connection) -> Result<Vec<ImportJob>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, file_path, filename, state, progress_pct, document_id, model_used, error,
+                queued_at, started_at, completed_at, user_document_type, page_paths
+         FROM import_jobs
+         ORDER BY queued_at ASC",
+    )?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            (
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, i64>(4)?,
+            ),
+            row.get::<_, Option<String>>(5)?,
+            row.get::<_, Option<String>>(6)?,
+            row.get::<_, Option<String>>(7)?,
+            row.get::<_, String>(8)?,
+            row.get::<_, Option<String>>(9)?,
+            row.get::<_, Option<String>>(10)?,
+            row.get::<_, Option<String>>(11)?,
+            row.get::<_, Option<String>>(12)?,
+        ))
+    })?;
+
+    let mut jobs = Vec::new();
+    for row in rows {
+        let (
+            (id, file_path, filename, state, progress_pct),
+            document_id,
+            model_used,
+            error,
+            queued_at,
+            started_at,
+            completed_at,
+            user_document_type,
+            page_paths,
+        ) = row?;
+        let state = JobState::parse(&state).ok_or_else(|| DatabaseError::InvalidEnum {
+            field: "import_jobs.state".into(),
+            value: state.clone(),
+        })?;
+        let page_paths = match page_paths {
+            Some(json) => serde_json::from_str(&json)
+                .map_err(|e| DatabaseError::InvalidData(e.to_string()))?,
+            None => vec![],
+        };
+        jobs.push(ImportJob {
+            id,
+            file_path,
+            filename,
+            state,
+            progress_pct: progress_pct.clamp(0, 100) as u8,
+            document_id,
+            model_used,
+            error,
+            queued_at,
+            started_at,
+            completed_at,
+            user_document_type,
+            page_paths,
+            pdf_password: None,
+        });
+    }
+    Ok(jobs)
+}
+
+/// Delete an import job (idempotent).
+pub fn delete_import_job(conn: &Connection, job_id: &str) -> Result<(), DatabaseError> {
+    conn.execute("DELETE FROM import_jobs WHERE id = ?1", params![job_id])?;
+    Ok(())
+}
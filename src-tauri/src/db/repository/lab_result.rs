@@ -38,7 +38,8 @@ pub fn get_critical_labs(conn: &Connection) -> Result<Vec<LabResult>, DatabaseEr
         "SELECT id, test_name, test_code, value, value_text, unit,
          reference_range_low, reference_range_high, abnormal_flag, collection_date,
          lab_facility, ordering_physician_id, document_id
-         FROM lab_results WHERE abnormal_flag IN ('critical_low', 'critical_high')"
+         FROM lab_results WHERE abnormal_flag IN ('critical_low', 'critical_high')
+         AND duplicate_of IS NULL"
     )?;
 
     let rows = stmt.query_map([], |row| Ok(lab_row_from_rusqlite(row)))?;
@@ -58,7 +59,7 @@ pub fn get_lab_results_since(
         "SELECT id, test_name, test_code, value, value_text, unit,
          reference_range_low, reference_range_high, abnormal_flag, collection_date,
          lab_facility, ordering_physician_id, document_id
-         FROM lab_results WHERE collection_date >= ?1 ORDER BY collection_date DESC",
+         FROM lab_results WHERE collection_date >= ?1 AND duplicate_of IS NULL ORDER BY collection_date DESC",
     )?;
 
     let rows = stmt.query_map(params![since.to_string()], |row| Ok(lab_row_from_rusqlite(row)))?;
@@ -79,7 +80,7 @@ pub fn get_lab_results_by_test_name(
         "SELECT id, test_name, test_code, value, value_text, unit,
          reference_range_low, reference_range_high, abnormal_flag, collection_date,
          lab_facility, ordering_physician_id, document_id
-         FROM lab_results WHERE LOWER(test_name) LIKE LOWER(?1) ORDER BY collection_date DESC",
+         FROM lab_results WHERE LOWER(test_name) LIKE LOWER(?1) AND duplicate_of IS NULL ORDER BY collection_date DESC",
     )?;
 
     let rows = stmt.query_map(params![pattern], |row| Ok(lab_row_from_rusqlite(row)))?;
@@ -97,7 +98,7 @@ pub fn get_all_lab_results(conn: &Connection) -> Result<Vec<LabResult>, Database
         "SELECT id, test_name, test_code, value, value_text, unit,
          reference_range_low, reference_range_high, abnormal_flag, collection_date,
          lab_facility, ordering_physician_id, document_id
-         FROM lab_results ORDER BY collection_date DESC",
+         FROM lab_results WHERE duplicate_of IS NULL ORDER BY collection_date DESC",
     )?;
 
     let rows = stmt.query_map([], |row| Ok(lab_row_from_rusqlite(row)))?;
@@ -109,6 +110,20 @@ pub fn get_all_lab_results(conn: &Connection) -> Result<Vec<LabResult>, Database
     Ok(labs)
 }
 
+/// REC-01: Mark `id` as a duplicate of `primary_id`. Results already linked
+/// to `id` are re-pointed so links never chain.
+pub fn link_lab_duplicate(
+    conn: &Connection,
+    id: &Uuid,
+    primary_id: &Uuid,
+) -> Result<(), DatabaseError> {
+    conn.execute(
+        "UPDATE lab_results SET duplicate_of = ?2 WHERE id = ?1 OR duplicate_of = ?1",
+        params![id.to_string(), primary_id.to_string()],
+    )?;
+    Ok(())
+}
+
 // Internal row type for LabResult mapping
 struct LabRow {
     id: String,
@@ -9,10 +9,13 @@ mod audit;
 mod cached_explanation;
 mod consistency;
 mod conversation;
+mod conversation_memory;
 pub mod device_registry;
 mod diagnosis;
 mod document;
 mod document_search;
+mod immunization;
+mod import_job;
 mod lab_result;
 mod medication;
 mod preference;
@@ -22,6 +25,7 @@ mod profile_trust;
 mod referral;
 mod symptom;
 mod entity_connection;
+mod entity_source;
 mod vital_sign;
 mod screening_record;
 
@@ -44,9 +48,12 @@ pub use audit::*;
 pub use cached_explanation::*;
 pub use consistency::*;
 pub use conversation::*;
+pub use conversation_memory::*;
 pub use diagnosis::*;
 pub use document::*;
 pub use document_search::*;
+pub use immunization::*;
+pub use import_job::*;
 pub use lab_result::*;
 pub use medication::*;
 pub use preference::*;
@@ -56,6 +63,7 @@ pub use profile_trust::*;
 pub use referral::*;
 pub use symptom::*;
 pub use entity_connection::*;
+pub use entity_source::*;
 pub use vital_sign::*;
 pub use screening_record::*;
 
@@ -386,6 +394,30 @@ mod tests {
         assert_eq!(count, 1);
     }
 
+    #[test]
+    fn immunization_insert_and_series_order() {
+        let conn = test_db();
+        let doc_id = make_document(&conn, None);
+
+        for (dose, date) in [(2, "2024-04-01"), (1, "2024-02-01")] {
+            insert_immunization(&conn, &Immunization {
+                id: Uuid::new_v4(),
+                vaccine: "DTaP-IPV-Hib-HepB".into(),
+                dose_number: Some(dose),
+                administered_date: NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(),
+                lot_number: Some("A21CB123".into()),
+                site: Some("left thigh".into()),
+                administering_professional_id: None,
+                document_id: doc_id,
+            }).unwrap();
+        }
+
+        let all = get_all_immunizations(&conn).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].dose_number, Some(1));
+        assert_eq!(all[1].lot_number.as_deref(), Some("A21CB123"));
+    }
+
     #[test]
     fn referral_insert() {
         let conn = test_db();
@@ -760,6 +792,17 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn mark_document_suspicious_sets_flag() {
+        let conn = test_db();
+        let doc_id = make_document(&conn, None);
+        assert!(!is_document_suspicious(&conn, &doc_id).unwrap());
+
+        mark_document_suspicious(&conn, &doc_id).unwrap();
+        assert!(is_document_suspicious(&conn, &doc_id).unwrap());
+        assert!(mark_document_suspicious(&conn, &Uuid::new_v4()).is_err());
+    }
+
     #[test]
     fn get_documents_by_pipeline_status_filters() {
         let conn = test_db();
@@ -948,6 +991,46 @@ mod tests {
         assert_eq!(med_count, 1);
     }
 
+    #[test]
+    fn delete_document_cascade_relinks_remaining_duplicates() {
+        let conn = test_db();
+        let docs = [make_document(&conn, None), make_document(&conn, None), make_document(&conn, None)];
+        let labs: Vec<LabResult> = docs
+            .iter()
+            .map(|doc| LabResult {
+                id: Uuid::new_v4(),
+                test_name: "HbA1c".into(),
+                test_code: None,
+                value: Some(6.8),
+                value_text: None,
+                unit: Some("%".into()),
+                reference_range_low: None,
+                reference_range_high: None,
+                abnormal_flag: AbnormalFlag::Normal,
+                collection_date: NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+                lab_facility: None,
+                ordering_physician_id: None,
+                document_id: *doc,
+            })
+            .collect();
+        for lab in &labs {
+            insert_lab_result(&conn, lab).unwrap();
+        }
+        crate::intelligence::reconciliation::reconcile(&conn).unwrap();
+        let primary = get_all_lab_results(&conn).unwrap();
+        assert_eq!(primary.len(), 1);
+
+        // Deleting the primary's document leaves exactly one primary copy
+        delete_document_cascade(&conn, &primary[0].document_id).unwrap();
+        let remaining = get_all_lab_results(&conn).unwrap();
+        assert_eq!(remaining.len(), 1);
+        let linked: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM lab_results WHERE duplicate_of = ?1",
+            params![remaining[0].id.to_string()], |r| r.get(0),
+        ).unwrap();
+        assert_eq!(linked, 1);
+    }
+
     #[test]
     fn pipeline_status_round_trip() {
         for (variant, s) in [
@@ -1033,8 +1116,9 @@ mod tests {
 
         repair_consistency(&conn).unwrap();
 
+        // IMQ-01: Reset for the import queue to resume, not failed
         let doc_after = get_document(&conn, &doc_id).unwrap().unwrap();
-        assert_eq!(doc_after.pipeline_status, PipelineStatus::Failed);
+        assert_eq!(doc_after.pipeline_status, PipelineStatus::Imported);
 
         let report = check_consistency(&conn).unwrap();
         let stuck_after: Vec<_> = report.issues.iter()
@@ -1239,6 +1323,7 @@ mod tests {
             (EntityType::Allergy, "Allergy"),
             (EntityType::Procedure, "Procedure"),
             (EntityType::Referral, "Referral"),
+            (EntityType::Immunization, "Immunization"),
         ] {
             assert_eq!(variant.as_str(), s);
             assert_eq!(EntityType::from_str(s).unwrap(), variant);
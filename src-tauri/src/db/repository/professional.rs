@@ -26,6 +26,26 @@ pub fn find_or_create_professional(
     name: &str,
     specialty: Option<&str>,
 ) -> Result<Professional, DatabaseError> {
+    if let Some(prof) = find_professional_by_name(conn, name)? {
+        return Ok(prof);
+    }
+    let prof = Professional {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        specialty: specialty.map(|s| s.to_string()),
+        institution: None,
+        first_seen_date: Some(chrono::Local::now().date_naive()),
+        last_seen_date: Some(chrono::Local::now().date_naive()),
+    };
+    insert_professional(conn, &prof)?;
+    Ok(prof)
+}
+
+/// EML-01: An existing professional with exactly this name, if any.
+pub fn find_professional_by_name(
+    conn: &Connection,
+    name: &str,
+) -> Result<Option<Professional>, DatabaseError> {
     let mut stmt = conn.prepare(
         "SELECT id, name, specialty, institution, first_seen_date, last_seen_date
          FROM professionals WHERE name = ?1 LIMIT 1",
@@ -47,19 +67,8 @@ pub fn find_or_create_professional(
     });
 
     match result {
-        Ok(prof) => Ok(prof),
-        Err(rusqlite::Error::QueryReturnedNoRows) => {
-            let prof = Professional {
-                id: Uuid::new_v4(),
-                name: name.to_string(),
-                specialty: specialty.map(|s| s.to_string()),
-                institution: None,
-                first_seen_date: Some(chrono::Local::now().date_naive()),
-                last_seen_date: Some(chrono::Local::now().date_naive()),
-            };
-            insert_professional(conn, &prof)?;
-            Ok(prof)
-        }
+        Ok(prof) => Ok(Some(prof)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
         Err(e) => Err(e.into()),
     }
 }
@@ -25,6 +25,40 @@ pub fn insert_vital_sign(conn: &Connection, vs: &VitalSign) -> Result<(), Databa
     Ok(())
 }
 
+/// VIT-01: Bulk-insert vital signs in one transaction, skipping any whose
+/// `(vital_type, recorded_at)` already exists. Returns the number inserted.
+pub fn insert_vital_signs_deduped(
+    conn: &Connection,
+    vitals: &[VitalSign],
+) -> Result<usize, DatabaseError> {
+    let tx = conn.unchecked_transaction()?;
+    let mut inserted = 0;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO vital_signs (id, vital_type, value_primary, value_secondary, unit, recorded_at, notes, source, created_at)
+             SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM vital_signs WHERE vital_type = ?2 AND recorded_at = ?6
+             )",
+        )?;
+        for vs in vitals {
+            inserted += stmt.execute(params![
+                vs.id.to_string(),
+                vs.vital_type.as_str(),
+                vs.value_primary,
+                vs.value_secondary,
+                vs.unit,
+                vs.recorded_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                vs.notes,
+                vs.source.as_str(),
+                vs.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ])?;
+        }
+    }
+    tx.commit()?;
+    Ok(inserted)
+}
+
 /// Get all vital signs of a given type, ordered by recorded_at descending.
 pub fn get_vital_signs_by_type(
     conn: &Connection,
@@ -33,7 +67,7 @@ pub fn get_vital_signs_by_type(
     let mut stmt = conn.prepare(
         "SELECT id, vital_type, value_primary, value_secondary, unit, recorded_at, notes, source, created_at
          FROM vital_signs
-         WHERE vital_type = ?1
+         WHERE vital_type = ?1 AND duplicate_of IS NULL
          ORDER BY recorded_at DESC",
     )?;
     let rows = stmt.query_map(params![vital_type.as_str()], row_to_vital_sign)?;
@@ -49,7 +83,7 @@ pub fn get_vital_signs_in_range(
     let mut stmt = conn.prepare(
         "SELECT id, vital_type, value_primary, value_secondary, unit, recorded_at, notes, source, created_at
          FROM vital_signs
-         WHERE recorded_at >= ?1 AND recorded_at <= ?2
+         WHERE recorded_at >= ?1 AND recorded_at <= ?2 AND duplicate_of IS NULL
          ORDER BY recorded_at ASC",
     )?;
     let rows = stmt.query_map(
@@ -70,7 +104,7 @@ pub fn get_latest_vital_sign(
     let mut stmt = conn.prepare(
         "SELECT id, vital_type, value_primary, value_secondary, unit, recorded_at, notes, source, created_at
          FROM vital_signs
-         WHERE vital_type = ?1
+         WHERE vital_type = ?1 AND duplicate_of IS NULL
          ORDER BY recorded_at DESC
          LIMIT 1",
     )?;
@@ -87,6 +121,7 @@ pub fn get_all_vital_signs(conn: &Connection) -> Result<Vec<VitalSign>, Database
     let mut stmt = conn.prepare(
         "SELECT id, vital_type, value_primary, value_secondary, unit, recorded_at, notes, source, created_at
          FROM vital_signs
+         WHERE duplicate_of IS NULL
          ORDER BY recorded_at ASC",
     )?;
     let rows = stmt.query_map([], row_to_vital_sign)?;
@@ -95,6 +130,11 @@ pub fn get_all_vital_signs(conn: &Connection) -> Result<Vec<VitalSign>, Database
 
 /// Delete a vital sign by ID.
 pub fn delete_vital_sign(conn: &Connection, id: &Uuid) -> Result<(), DatabaseError> {
+    // REC-01: Promote any duplicates of this reading back to primary
+    conn.execute(
+        "UPDATE vital_signs SET duplicate_of = NULL WHERE duplicate_of = ?1",
+        params![id.to_string()],
+    )?;
     let affected = conn.execute(
         "DELETE FROM vital_signs WHERE id = ?1",
         params![id.to_string()],
@@ -108,6 +148,20 @@ pub fn delete_vital_sign(conn: &Connection, id: &Uuid) -> Result<(), DatabaseErr
     Ok(())
 }
 
+/// REC-01: Mark `id` as a duplicate of `primary_id`. Readings already linked
+/// to `id` are re-pointed so links never chain.
+pub fn link_vital_duplicate(
+    conn: &Connection,
+    id: &Uuid,
+    primary_id: &Uuid,
+) -> Result<(), DatabaseError> {
+    conn.execute(
+        "UPDATE vital_signs SET duplicate_of = ?2 WHERE id = ?1 OR duplicate_of = ?1",
+        params![id.to_string(), primary_id.to_string()],
+    )?;
+    Ok(())
+}
+
 /// REVIEW-01: Get recent trend data points for sparkline charts.
 ///
 /// Returns `value_primary` and `recorded_at` for the last `days` days,
@@ -121,13 +175,18 @@ pub fn get_vital_trend(
         "SELECT value_primary, recorded_at
          FROM vital_signs
          WHERE vital_type = ?1 AND recorded_at >= datetime('now', ?2)
+           AND duplicate_of IS NULL
          ORDER BY recorded_at ASC",
     )?;
     let cutoff = format!("-{days} days");
     let rows = stmt.query_map(params![vital_type.as_str(), cutoff], |row| {
         let value: f64 = row.get(0)?;
         let recorded_at: String = row.get(1)?;
-        Ok(VitalTrendPoint { value, recorded_at })
+        Ok(VitalTrendPoint {
+            value,
+            recorded_at,
+            growth: None,
+        })
     })?;
     rows.collect::<Result<Vec<_>, _>>().map_err(DatabaseError::from)
 }
@@ -267,6 +326,25 @@ mod tests {
         assert!((trend[1].value - 37.5).abs() < 0.01);
     }
 
+    #[test]
+    fn bulk_insert_skips_same_type_and_timestamp() {
+        let conn = test_db();
+        let existing = make_vital(VitalType::HeartRate, 70.0);
+        insert_vital_sign(&conn, &existing).unwrap();
+
+        let mut same_slot = make_vital(VitalType::HeartRate, 75.0);
+        same_slot.recorded_at = existing.recorded_at;
+        let mut other_type = make_vital(VitalType::Weight, 80.0);
+        other_type.recorded_at = existing.recorded_at;
+        let mut repeated = make_vital(VitalType::Weight, 81.0);
+        repeated.recorded_at = existing.recorded_at;
+
+        let inserted =
+            insert_vital_signs_deduped(&conn, &[same_slot, other_type, repeated]).unwrap();
+        assert_eq!(inserted, 1);
+        assert_eq!(get_all_vital_signs(&conn).unwrap().len(), 2);
+    }
+
     #[test]
     fn get_vital_trend_empty_for_no_data() {
         let conn = test_db();
@@ -74,6 +74,16 @@ pub fn run_migrations(conn: &Connection) -> Result<(), DatabaseError> {
         (21, include_str!("../../resources/migrations/021_screening_records.sql")),
         (22, include_str!("../../resources/migrations/022_vital_source_extracted.sql")),
         (23, include_str!("../../resources/migrations/023_allergen_category.sql")),
+        (24, include_str!("../../resources/migrations/024_entity_sources.sql")),
+        (25, include_str!("../../resources/migrations/025_import_jobs.sql")),
+        (26, include_str!("../../resources/migrations/026_immunizations.sql")),
+        (27, include_str!("../../resources/migrations/027_result_reconciliation.sql")),
+        (28, include_str!("../../resources/migrations/028_injection_quarantine.sql")),
+        (29, include_str!("../../resources/migrations/029_conversation_memory.sql")),
+        (30, include_str!("../../resources/migrations/030_chat_export.sql")),
+        (31, include_str!("../../resources/migrations/031_sensitive_records.sql")),
+        (32, include_str!("../../resources/migrations/032_emergency_card.sql")),
+        (33, include_str!("../../resources/migrations/033_sensitivity_sync_scope.sql")),
     ];
 
     for (version, sql) in migrations {
@@ -127,7 +137,7 @@ mod tests {
         let version: i64 = conn
             .query_row("SELECT MAX(version) FROM schema_version", [], |row| row.get(0))
             .unwrap();
-        assert_eq!(version, 23);
+        assert_eq!(version, 33);
     }
 
     #[test]
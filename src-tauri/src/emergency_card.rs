@@ -0,0 +1,914 @@
+//! EMC-01: Emergency medical ID — wallet card, signed QR payload and phone
+//! lock-screen summary.
+//!
+//! The summary is assembled from the profile: blood type (resolved through
+//! `invariants::blood_types`), allergies by severity, active medications,
+//! key diagnoses and patient-entered emergency contacts. Records flagged
+//! sensitive (SENS-01) stay out unless the patient includes them on the
+//! printed card; they never reach the lock screen.
+//!
+//! Artifacts:
+//! - Wallet card: two-page ID-1 PDF (85.6 × 54 mm), QR code on the back
+//! - QR payload: `EMC1.<payload>.<signature>.<public key>` (base64url), the
+//!   compact JSON payload signed with a per-profile ed25519 key
+//! - Lock-screen card: only the fields the patient opted in to, one by one;
+//!   travels with the profile entity in phone sync
+
+use std::io::BufWriter;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{Local, NaiveDate};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::RngCore;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::crypto::{EncryptedData, ProfileSession};
+use crate::db::DatabaseError;
+use crate::invariants::blood_types::find_blood_type;
+use crate::models::enums::BloodType;
+use crate::sensitivity;
+
+/// Prefix (and format version) of the QR payload string.
+const QR_PREFIX: &str = "EMC1";
+
+/// ID-1 card dimensions (ISO/IEC 7810), in millimetres.
+const CARD_WIDTH: f32 = 85.6;
+const CARD_HEIGHT: f32 = 54.0;
+
+const MAX_CONTACTS: usize = 5;
+
+// ─── Errors ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum EmergencyCardError {
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+    #[error("Invalid emergency contact: {0}")]
+    InvalidContact(String),
+    #[error("Signing key error: {0}")]
+    SigningKey(String),
+    #[error("Invalid QR payload: {0}")]
+    InvalidPayload(String),
+    #[error("QR code error: {0}")]
+    Qr(String),
+    #[error("PDF error: {0}")]
+    Pdf(String),
+}
+
+impl From<rusqlite::Error> for EmergencyCardError {
+    fn from(e: rusqlite::Error) -> Self {
+        Self::Database(DatabaseError::from(e))
+    }
+}
+
+// ─── Types ────────────────────────────────────────────────────────────────────
+
+/// A summary field the patient can opt in to showing on the lock screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmergencyField {
+    Name,
+    DateOfBirth,
+    BloodType,
+    Allergies,
+    Medications,
+    Diagnoses,
+    Contacts,
+}
+
+impl EmergencyField {
+    pub const ALL: [EmergencyField; 7] = [
+        Self::Name,
+        Self::DateOfBirth,
+        Self::BloodType,
+        Self::Allergies,
+        Self::Medications,
+        Self::Diagnoses,
+        Self::Contacts,
+    ];
+}
+
+/// Someone to call in an emergency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmergencyContact {
+    pub id: String,
+    pub name: String,
+    pub relationship: Option<String>,
+    pub phone: String,
+}
+
+/// New emergency contact from the settings form.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmergencyContactInput {
+    pub name: String,
+    pub relationship: Option<String>,
+    pub phone: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmergencyAllergy {
+    pub allergen: String,
+    pub severity: String,
+    pub reaction: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmergencyMedication {
+    pub name: String,
+    pub dose: String,
+    pub frequency: String,
+}
+
+/// Everything a paramedic needs, assembled from the profile.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmergencySummary {
+    pub profile_name: String,
+    pub date_of_birth: Option<String>,
+    /// Display label from the blood type catalog, e.g. "O+".
+    pub blood_type: Option<String>,
+    /// Most severe first.
+    pub allergies: Vec<EmergencyAllergy>,
+    pub medications: Vec<EmergencyMedication>,
+    pub diagnoses: Vec<String>,
+    pub contacts: Vec<EmergencyContact>,
+    pub generated_at: String,
+}
+
+/// Desktop preview: summary plus the signed QR payload.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmergencyCard {
+    pub summary: EmergencySummary,
+    pub qr_payload: String,
+    pub qr_svg: String,
+    /// Short fingerprint of the signing key, printed on the card so a reader
+    /// can check the QR was signed by the same profile.
+    pub key_fingerprint: String,
+    pub lock_screen_fields: Vec<EmergencyField>,
+}
+
+/// Compact, signed QR content. Short keys keep the code scannable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QrPayload {
+    /// Name
+    pub n: String,
+    /// Date of birth
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub dob: Option<String>,
+    /// Blood type
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub bt: Option<String>,
+    /// Allergies: "allergen (severity: reaction)"
+    pub a: Vec<String>,
+    /// Medications: "name dose frequency"
+    pub m: Vec<String>,
+    /// Diagnoses
+    pub dx: Vec<String>,
+    /// Contacts: "name (relationship) phone"
+    pub c: Vec<String>,
+    /// Generated at
+    pub ts: String,
+}
+
+/// Lock-screen-safe summary for the phone. Fields the patient did not opt in
+/// to are empty; `fields` lists the ones shown.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockScreenCard {
+    pub fields: Vec<EmergencyField>,
+    pub name: Option<String>,
+    pub date_of_birth: Option<String>,
+    pub blood_type: Option<String>,
+    pub allergies: Vec<EmergencyAllergy>,
+    pub medications: Vec<EmergencyMedication>,
+    pub diagnoses: Vec<String>,
+    pub contacts: Vec<EmergencyContact>,
+}
+
+impl LockScreenCard {
+    /// Keeps only the opted-in fields of `summary`.
+    pub fn from_summary(summary: EmergencySummary, fields: &[EmergencyField]) -> Self {
+        let shows = |f: EmergencyField| fields.contains(&f);
+        Self {
+            fields: EmergencyField::ALL.into_iter().filter(|f| shows(*f)).collect(),
+            name: shows(EmergencyField::Name).then_some(summary.profile_name),
+            date_of_birth: summary.date_of_birth.filter(|_| shows(EmergencyField::DateOfBirth)),
+            blood_type: summary.blood_type.filter(|_| shows(EmergencyField::BloodType)),
+            allergies: if shows(EmergencyField::Allergies) { summary.allergies } else { Vec::new() },
+            medications: if shows(EmergencyField::Medications) {
+                summary.medications
+            } else {
+                Vec::new()
+            },
+            diagnoses: if shows(EmergencyField::Diagnoses) { summary.diagnoses } else { Vec::new() },
+            contacts: if shows(EmergencyField::Contacts) { summary.contacts } else { Vec::new() },
+        }
+    }
+}
+
+// ─── Summary assembly ─────────────────────────────────────────────────────────
+
+/// Builds the emergency summary. `date_of_birth` and `blood_type` come from
+/// the profile record, everything else from the profile database.
+pub fn assemble_emergency_summary(
+    conn: &Connection,
+    profile_name: &str,
+    date_of_birth: Option<NaiveDate>,
+    blood_type: Option<&BloodType>,
+    include_sensitive: bool,
+) -> Result<EmergencySummary, DatabaseError> {
+    Ok(EmergencySummary {
+        profile_name: profile_name.to_string(),
+        date_of_birth: date_of_birth.map(|d| d.to_string()),
+        blood_type: blood_type
+            .and_then(|bt| find_blood_type(bt.as_str()))
+            .map(|info| info.display.to_string()),
+        allergies: fetch_allergies(conn, include_sensitive)?,
+        medications: fetch_active_medications(conn, include_sensitive)?,
+        diagnoses: fetch_key_diagnoses(conn, include_sensitive)?,
+        contacts: list_contacts(conn)?,
+        generated_at: Local::now().naive_local().format("%Y-%m-%d %H:%M").to_string(),
+    })
+}
+
+fn fetch_allergies(
+    conn: &Connection,
+    include_sensitive: bool,
+) -> Result<Vec<EmergencyAllergy>, DatabaseError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT a.allergen, a.severity, a.reaction
+         FROM allergies a
+         WHERE 1=1{}
+         ORDER BY CASE a.severity
+                      WHEN 'life_threatening' THEN 0
+                      WHEN 'severe' THEN 1
+                      WHEN 'moderate' THEN 2
+                      ELSE 3
+                  END,
+                  a.allergen",
+        sensitivity::entity_filter_sql("a", include_sensitive)
+    ))?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok(EmergencyAllergy {
+            allergen: row.get(0)?,
+            severity: row.get(1)?,
+            reaction: row.get(2)?,
+        })
+    })?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(DatabaseError::from)
+}
+
+fn fetch_active_medications(
+    conn: &Connection,
+    include_sensitive: bool,
+) -> Result<Vec<EmergencyMedication>, DatabaseError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT m.generic_name, m.dose, m.frequency
+         FROM medications m
+         WHERE m.status = 'active'{}
+         ORDER BY m.generic_name",
+        sensitivity::entity_filter_sql("m", include_sensitive)
+    ))?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok(EmergencyMedication {
+            name: row.get(0)?,
+            dose: row.get(1)?,
+            frequency: row.get(2)?,
+        })
+    })?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(DatabaseError::from)
+}
+
+/// Active and monitored diagnoses, one entry per name.
+fn fetch_key_diagnoses(
+    conn: &Connection,
+    include_sensitive: bool,
+) -> Result<Vec<String>, DatabaseError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT DISTINCT d.name
+         FROM diagnoses d
+         WHERE d.status IN ('active', 'monitoring'){}
+         ORDER BY d.name",
+        sensitivity::entity_filter_sql("d", include_sensitive)
+    ))?;
+
+    let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(DatabaseError::from)
+}
+
+// ─── Emergency contacts ───────────────────────────────────────────────────────
+
+pub fn list_contacts(conn: &Connection) -> Result<Vec<EmergencyContact>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, relationship, phone
+         FROM emergency_contacts
+         ORDER BY sort_order, created_at",
+    )?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok(EmergencyContact {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            relationship: row.get(2)?,
+            phone: row.get(3)?,
+        })
+    })?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(DatabaseError::from)
+}
+
+/// Adds a contact at the end of the list.
+pub fn add_contact(
+    conn: &Connection,
+    input: &EmergencyContactInput,
+) -> Result<EmergencyContact, EmergencyCardError> {
+    let name = input.name.trim();
+    let phone = input.phone.trim();
+    let relationship = input
+        .relationship
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+
+    if name.is_empty() || name.len() > 100 {
+        return Err(EmergencyCardError::InvalidContact("name must be 1-100 characters".into()));
+    }
+    if phone.is_empty()
+        || phone.len() > 30
+        || !phone.chars().all(|c| c.is_ascii_digit() || " +-().".contains(c))
+    {
+        return Err(EmergencyCardError::InvalidContact("invalid phone number".into()));
+    }
+    if relationship.is_some_and(|r| r.len() > 50) {
+        return Err(EmergencyCardError::InvalidContact("relationship too long".into()));
+    }
+
+    let count: usize = conn.query_row("SELECT COUNT(*) FROM emergency_contacts", [], |row| {
+        row.get(0)
+    })?;
+    if count >= MAX_CONTACTS {
+        return Err(EmergencyCardError::InvalidContact(format!(
+            "at most {MAX_CONTACTS} contacts"
+        )));
+    }
+
+    let contact = EmergencyContact {
+        id: Uuid::new_v4().to_string(),
+        name: name.to_string(),
+        relationship: relationship.map(str::to_string),
+        phone: phone.to_string(),
+    };
+    conn.execute(
+        "INSERT INTO emergency_contacts (id, name, relationship, phone, sort_order)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![contact.id, contact.name, contact.relationship, contact.phone, count as i64],
+    )?;
+
+    Ok(contact)
+}
+
+pub fn remove_contact(conn: &Connection, contact_id: &str) -> Result<(), DatabaseError> {
+    let changed = conn.execute(
+        "DELETE FROM emergency_contacts WHERE id = ?1",
+        params![contact_id],
+    )?;
+    if changed == 0 {
+        return Err(DatabaseError::NotFound {
+            entity_type: "EmergencyContact".into(),
+            id: contact_id.into(),
+        });
+    }
+    Ok(())
+}
+
+// ─── Lock-screen opt-in ───────────────────────────────────────────────────────
+
+/// Fields the patient opted in to showing on the lock screen.
+pub fn get_lock_screen_fields(conn: &Connection) -> Result<Vec<EmergencyField>, DatabaseError> {
+    let json: Option<String> = conn
+        .query_row(
+            "SELECT lock_screen_fields FROM emergency_card_settings WHERE id = 1",
+            [],
+            |row| row.get(0),
+        )
+        .optional()?;
+
+    Ok(json
+        .and_then(|j| serde_json::from_str(&j).ok())
+        .unwrap_or_default())
+}
+
+/// Replaces the lock-screen opt-ins. Bumps the profile sync version so phones
+/// pick up the change on their next sync.
+pub fn set_lock_screen_fields(
+    conn: &Connection,
+    fields: &[EmergencyField],
+) -> Result<(), DatabaseError> {
+    let ordered: Vec<EmergencyField> = EmergencyField::ALL
+        .into_iter()
+        .filter(|f| fields.contains(f))
+        .collect();
+    let json = serde_json::to_string(&ordered)
+        .map_err(|e| DatabaseError::ConstraintViolation(format!("JSON serialization: {e}")))?;
+
+    conn.execute(
+        "UPDATE emergency_card_settings
+         SET lock_screen_fields = ?1, updated_at = datetime('now')
+         WHERE id = 1",
+        params![json],
+    )?;
+    Ok(())
+}
+
+/// Marks the lock-screen card stale after a profile-level change that the
+/// database cannot see (blood type and date of birth live on the profile).
+pub fn touch_lock_screen_card(conn: &Connection) -> Result<(), DatabaseError> {
+    conn.execute(
+        "UPDATE sync_versions SET version = version + 1, updated_at = datetime('now')
+         WHERE entity_type = 'profile'",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Lock-screen card for phone sync. Never includes sensitive records; only
+/// sent to unscoped devices (see `sync::attach_lock_screen_card`).
+pub fn lock_screen_card(
+    conn: &Connection,
+    profile_name: &str,
+    date_of_birth: Option<NaiveDate>,
+    blood_type: Option<&BloodType>,
+) -> Result<LockScreenCard, DatabaseError> {
+    let fields = get_lock_screen_fields(conn)?;
+    if fields.is_empty() {
+        return Ok(LockScreenCard::default());
+    }
+
+    let summary =
+        assemble_emergency_summary(conn, profile_name, date_of_birth, blood_type, false)?;
+    Ok(LockScreenCard::from_summary(summary, &fields))
+}
+
+// ─── Signed QR payload ────────────────────────────────────────────────────────
+
+/// Loads the profile's QR signing key, generating and storing it (encrypted
+/// with the profile key) on first use.
+pub fn load_or_create_signing_key(
+    conn: &Connection,
+    session: &ProfileSession,
+) -> Result<SigningKey, EmergencyCardError> {
+    let stored: Option<Vec<u8>> = conn
+        .query_row(
+            "SELECT signing_key_encrypted FROM emergency_card_settings WHERE id = 1",
+            [],
+            |row| row.get(0),
+        )
+        .optional()?
+        .flatten();
+
+    if let Some(bytes) = stored {
+        let encrypted = EncryptedData::from_bytes(&bytes)
+            .map_err(|e| EmergencyCardError::SigningKey(e.to_string()))?;
+        let seed = session
+            .decrypt(&encrypted)
+            .map_err(|e| EmergencyCardError::SigningKey(e.to_string()))?;
+        let seed: [u8; 32] = seed
+            .as_slice()
+            .try_into()
+            .map_err(|_| EmergencyCardError::SigningKey("stored key has wrong length".into()))?;
+        return Ok(SigningKey::from_bytes(&seed));
+    }
+
+    let mut seed = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut seed);
+    let encrypted = session
+        .encrypt(&seed)
+        .map_err(|e| EmergencyCardError::SigningKey(e.to_string()))?;
+    conn.execute(
+        "UPDATE emergency_card_settings SET signing_key_encrypted = ?1 WHERE id = 1",
+        params![encrypted.to_bytes()],
+    )?;
+
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+/// Short, human-comparable fingerprint of a verifying key.
+pub fn key_fingerprint(key: &VerifyingKey) -> String {
+    let digest = Sha256::digest(key.to_bytes());
+    digest[..6]
+        .chunks(2)
+        .map(|pair| format!("{:02X}{:02X}", pair[0], pair[1]))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+impl QrPayload {
+    pub fn from_summary(summary: &EmergencySummary) -> Self {
+        Self {
+            n: summary.profile_name.clone(),
+            dob: summary.date_of_birth.clone(),
+            bt: summary.blood_type.clone(),
+            a: summary.allergies.iter().map(format_allergy).collect(),
+            m: summary.medications.iter().map(format_medication).collect(),
+            dx: summary.diagnoses.clone(),
+            c: summary.contacts.iter().map(format_contact).collect(),
+            ts: summary.generated_at.clone(),
+        }
+    }
+}
+
+/// Signs the payload: `EMC1.<payload>.<signature>.<public key>`.
+pub fn encode_qr_payload(
+    payload: &QrPayload,
+    key: &SigningKey,
+) -> Result<String, EmergencyCardError> {
+    let json = serde_json::to_vec(payload)
+        .map_err(|e| EmergencyCardError::InvalidPayload(e.to_string()))?;
+    let signature = key.sign(&json);
+
+    Ok(format!(
+        "{QR_PREFIX}.{}.{}.{}",
+        URL_SAFE_NO_PAD.encode(&json),
+        URL_SAFE_NO_PAD.encode(signature.to_bytes()),
+        URL_SAFE_NO_PAD.encode(key.verifying_key().to_bytes()),
+    ))
+}
+
+/// Checks the signature and returns the payload with the signer's key.
+/// Callers compare the key fingerprint against the one printed on the card.
+pub fn verify_qr_payload(qr: &str) -> Result<(QrPayload, VerifyingKey), EmergencyCardError> {
+    let invalid = |msg: &str| EmergencyCardError::InvalidPayload(msg.to_string());
+
+    let parts: Vec<&str> = qr.trim().split('.').collect();
+    let [prefix, payload, signature, public_key] = parts[..] else {
+        return Err(invalid("expected four segments"));
+    };
+    if prefix != QR_PREFIX {
+        return Err(invalid("unknown format"));
+    }
+
+    let decode = |s: &str| URL_SAFE_NO_PAD.decode(s).map_err(|_| invalid("bad base64"));
+    let json = decode(payload)?;
+    let signature: [u8; 64] = decode(signature)?
+        .try_into()
+        .map_err(|_| invalid("bad signature length"))?;
+    let public_key: [u8; 32] = decode(public_key)?
+        .try_into()
+        .map_err(|_| invalid("bad key length"))?;
+
+    let key = VerifyingKey::from_bytes(&public_key).map_err(|_| invalid("bad public key"))?;
+    key.verify(&json, &Signature::from_bytes(&signature))
+        .map_err(|_| invalid("signature does not match"))?;
+
+    let payload = serde_json::from_slice(&json).map_err(|e| invalid(&e.to_string()))?;
+    Ok((payload, key))
+}
+
+/// Builds the desktop preview: summary, signed QR payload and its SVG.
+pub fn build_emergency_card(
+    conn: &Connection,
+    summary: EmergencySummary,
+    key: &SigningKey,
+) -> Result<EmergencyCard, EmergencyCardError> {
+    let qr_payload = encode_qr_payload(&QrPayload::from_summary(&summary), key)?;
+    let qr_svg =
+        crate::wifi_transfer::generate_qr_code(&qr_payload).map_err(EmergencyCardError::Qr)?;
+
+    Ok(EmergencyCard {
+        summary,
+        qr_payload,
+        qr_svg,
+        key_fingerprint: key_fingerprint(&key.verifying_key()),
+        lock_screen_fields: get_lock_screen_fields(conn)?,
+    })
+}
+
+fn format_allergy(a: &EmergencyAllergy) -> String {
+    let severity = a.severity.replace('_', " ");
+    match &a.reaction {
+        Some(reaction) => format!("{} ({severity}: {reaction})", a.allergen),
+        None => format!("{} ({severity})", a.allergen),
+    }
+}
+
+fn format_medication(m: &EmergencyMedication) -> String {
+    format!("{} {} {}", m.name, m.dose, m.frequency)
+}
+
+fn format_contact(c: &EmergencyContact) -> String {
+    match &c.relationship {
+        Some(rel) => format!("{} ({rel}) {}", c.name, c.phone),
+        None => format!("{} {}", c.name, c.phone),
+    }
+}
+
+// ─── Wallet card PDF ──────────────────────────────────────────────────────────
+
+/// Renders the two-sided wallet card. Front: identity, blood type, allergies,
+/// medications, diagnoses. Back: contacts, signed QR and key fingerprint.
+pub fn generate_wallet_card_pdf(card: &EmergencyCard) -> Result<Vec<u8>, EmergencyCardError> {
+    use printpdf::{BuiltinFont, Mm, PdfDocument, Rect};
+
+    let summary = &card.summary;
+
+    let (doc, front_page, front_layer) = PdfDocument::new(
+        "Emergency medical ID",
+        Mm(CARD_WIDTH),
+        Mm(CARD_HEIGHT),
+        "Front",
+    );
+    let font = doc.add_builtin_font(BuiltinFont::Helvetica).map_err(pdf_err)?;
+    let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold).map_err(pdf_err)?;
+
+    // Front
+    let layer = doc.get_page(front_page).get_layer(front_layer);
+    let mut lines = CardLines::new(CARD_HEIGHT - 6.0, 4.0);
+    layer.use_text("EMERGENCY MEDICAL ID", 8.0, Mm(4.0), Mm(lines.y), &bold);
+    lines.advance(4.5);
+
+    let mut identity = summary.profile_name.clone();
+    if let Some(dob) = &summary.date_of_birth {
+        identity.push_str(&format!("  ·  born {dob}"));
+    }
+    layer.use_text(fit(&identity, 55), 7.0, Mm(4.0), Mm(lines.y), &bold);
+    lines.advance(3.8);
+    let blood_type = summary.blood_type.as_deref().unwrap_or("unknown");
+    layer.use_text(format!("Blood type: {blood_type}"), 6.5, Mm(4.0), Mm(lines.y), &font);
+    lines.advance(3.8);
+
+    let sections: [(&str, Vec<String>); 3] = [
+        ("ALLERGIES", summary.allergies.iter().map(format_allergy).collect()),
+        ("MEDICATIONS", summary.medications.iter().map(format_medication).collect()),
+        ("CONDITIONS", summary.diagnoses.clone()),
+    ];
+    for (title, items) in &sections {
+        if !lines.has_room() {
+            break;
+        }
+        let text = if items.is_empty() { "none recorded".to_string() } else { items.join("; ") };
+        layer.use_text(format!("{title}:"), 6.0, Mm(4.0), Mm(lines.y), &bold);
+        for line in wrap(&text, 62).into_iter().take(3) {
+            if !lines.has_room() {
+                break;
+            }
+            layer.use_text(line, 5.5, Mm(18.0), Mm(lines.y), &font);
+            lines.advance(2.8);
+        }
+        lines.advance(0.6);
+    }
+
+    // Back
+    let (back_page, back_layer) = doc.add_page(Mm(CARD_WIDTH), Mm(CARD_HEIGHT), "Back");
+    let layer = doc.get_page(back_page).get_layer(back_layer);
+    let mut lines = CardLines::new(CARD_HEIGHT - 6.0, 4.0);
+    layer.use_text("IN CASE OF EMERGENCY CALL", 6.5, Mm(4.0), Mm(lines.y), &bold);
+    lines.advance(4.0);
+    if summary.contacts.is_empty() {
+        layer.use_text("No contacts recorded", 6.0, Mm(4.0), Mm(lines.y), &font);
+    }
+    for contact in &summary.contacts {
+        if !lines.has_room() {
+            break;
+        }
+        let who = match &contact.relationship {
+            Some(rel) => format!("{} ({rel})", contact.name),
+            None => contact.name.clone(),
+        };
+        layer.use_text(fit(&who, 30), 6.0, Mm(4.0), Mm(lines.y), &font);
+        lines.advance(2.8);
+        layer.use_text(&contact.phone, 6.5, Mm(4.0), Mm(lines.y), &bold);
+        lines.advance(3.8);
+    }
+    layer.use_text(
+        format!("Key {}", card.key_fingerprint),
+        5.0,
+        Mm(4.0),
+        Mm(7.0),
+        &font,
+    );
+    layer.use_text(
+        format!("Generated {}", summary.generated_at),
+        5.0,
+        Mm(4.0),
+        Mm(4.0),
+        &font,
+    );
+
+    // Signed QR, drawn module by module so it prints sharp at any size
+    let code = qrcode::QrCode::new(card.qr_payload.as_bytes())
+        .map_err(|e| EmergencyCardError::Qr(e.to_string()))?;
+    let modules = code.width();
+    let size = 44.0_f32;
+    let module = size / modules as f32;
+    let (left, top) = (CARD_WIDTH - size - 3.0, CARD_HEIGHT - 5.0);
+    for (i, color) in code.to_colors().into_iter().enumerate() {
+        if color == qrcode::Color::Dark {
+            let x = left + (i % modules) as f32 * module;
+            let y = top - (i / modules) as f32 * module;
+            layer.add_rect(Rect::new(Mm(x), Mm(y - module), Mm(x + module), Mm(y)));
+        }
+    }
+
+    let mut buf = BufWriter::new(Vec::new());
+    doc.save(&mut buf).map_err(pdf_err)?;
+    buf.into_inner().map_err(pdf_err)
+}
+
+fn pdf_err(e: impl std::fmt::Display) -> EmergencyCardError {
+    EmergencyCardError::Pdf(e.to_string())
+}
+
+/// Vertical cursor for a single card face (no page breaks).
+struct CardLines {
+    y: f32,
+    bottom: f32,
+}
+
+impl CardLines {
+    fn new(top: f32, bottom: f32) -> Self {
+        Self { y: top, bottom }
+    }
+
+    fn advance(&mut self, mm: f32) {
+        self.y -= mm;
+    }
+
+    fn has_room(&self) -> bool {
+        self.y > self.bottom
+    }
+}
+
+/// Truncates to `max_chars`, marking the cut.
+fn fit(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let cut: String = text.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{cut}...")
+    }
+}
+
+/// Word wrap for the narrow card columns.
+fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > max_chars {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&fit(word, max_chars));
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::sqlite::open_memory_database;
+
+    fn seed(conn: &Connection) {
+        conn.execute_batch(
+            "INSERT INTO documents (id, type, title, ingestion_date, source_file)
+             VALUES ('doc-1', 'prescription', 'GP', '2026-01-01', 'gp.pdf'),
+                    ('doc-2', 'clinical_note', 'Psychiatry', '2026-01-02', 'psy.pdf');
+             INSERT INTO medications (id, generic_name, dose, frequency, frequency_type, status, document_id)
+             VALUES ('med-1', 'Metformin', '500mg', 'twice daily', 'scheduled', 'active', 'doc-1'),
+                    ('med-2', 'Sertraline', '50mg', 'daily', 'scheduled', 'active', 'doc-2'),
+                    ('med-3', 'Amoxicillin', '250mg', 'daily', 'scheduled', 'stopped', 'doc-1');
+             INSERT INTO allergies (id, allergen, reaction, severity, source, document_id)
+             VALUES ('al-1', 'Latex', NULL, 'mild', 'patient_reported', NULL),
+                    ('al-2', 'Penicillin', 'anaphylaxis', 'life_threatening', 'document_extracted', 'doc-1');
+             INSERT INTO diagnoses (id, name, status, document_id)
+             VALUES ('dx-1', 'Type 2 diabetes', 'active', 'doc-1'),
+                    ('dx-2', 'Appendicitis', 'resolved', 'doc-1');
+             UPDATE documents SET sensitive = 1 WHERE id = 'doc-2';",
+        )
+        .unwrap();
+    }
+
+    fn contact(name: &str, phone: &str) -> EmergencyContactInput {
+        EmergencyContactInput {
+            name: name.into(),
+            relationship: Some("sister".into()),
+            phone: phone.into(),
+        }
+    }
+
+    #[test]
+    fn summary_orders_allergies_and_hides_sensitive() {
+        let conn = open_memory_database().unwrap();
+        seed(&conn);
+        add_contact(&conn, &contact("Marie", "+33 6 12 34 56 78")).unwrap();
+
+        let summary =
+            assemble_emergency_summary(&conn, "Léa", None, Some(&BloodType::ONegative), false)
+                .unwrap();
+        assert_eq!(summary.blood_type.as_deref(), Some("O-"));
+        assert_eq!(summary.allergies[0].allergen, "Penicillin");
+        let meds: Vec<_> = summary.medications.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(meds, ["Metformin"]);
+        assert_eq!(summary.diagnoses, ["Type 2 diabetes"]);
+        assert_eq!(summary.contacts.len(), 1);
+
+        let all = assemble_emergency_summary(&conn, "Léa", None, None, true).unwrap();
+        assert_eq!(all.medications.len(), 2);
+        assert!(all.blood_type.is_none());
+    }
+
+    #[test]
+    fn contact_validation() {
+        let conn = open_memory_database().unwrap();
+        assert!(add_contact(&conn, &contact("", "112")).is_err());
+        assert!(add_contact(&conn, &contact("Marie", "call me")).is_err());
+        for i in 0..MAX_CONTACTS {
+            add_contact(&conn, &contact(&format!("Contact {i}"), "112")).unwrap();
+        }
+        assert!(add_contact(&conn, &contact("One more", "112")).is_err());
+
+        let first = list_contacts(&conn).unwrap().remove(0);
+        assert_eq!(first.name, "Contact 0");
+        remove_contact(&conn, &first.id).unwrap();
+        assert!(remove_contact(&conn, &first.id).is_err());
+    }
+
+    #[test]
+    fn lock_screen_shows_only_opted_in_fields() {
+        let conn = open_memory_database().unwrap();
+        seed(&conn);
+        let bt = BloodType::APositive;
+
+        let card = lock_screen_card(&conn, "Léa", None, Some(&bt)).unwrap();
+        assert_eq!(card, LockScreenCard::default());
+
+        let before = crate::sync::get_sync_versions(&conn).unwrap().profile;
+        set_lock_screen_fields(&conn, &[EmergencyField::Allergies, EmergencyField::BloodType])
+            .unwrap();
+        assert!(crate::sync::get_sync_versions(&conn).unwrap().profile > before);
+
+        let card = lock_screen_card(&conn, "Léa", None, Some(&bt)).unwrap();
+        assert_eq!(card.fields, [EmergencyField::BloodType, EmergencyField::Allergies]);
+        assert_eq!(card.blood_type.as_deref(), Some("A+"));
+        assert_eq!(card.allergies.len(), 2);
+        assert!(card.name.is_none());
+        assert!(card.medications.is_empty());
+    }
+
+    #[test]
+    fn qr_payload_round_trips_and_detects_tampering() {
+        let conn = open_memory_database().unwrap();
+        seed(&conn);
+        let summary = assemble_emergency_summary(&conn, "Léa", None, None, false).unwrap();
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+
+        let qr = encode_qr_payload(&QrPayload::from_summary(&summary), &key).unwrap();
+        let (payload, signer) = verify_qr_payload(&qr).unwrap();
+        assert_eq!(payload.n, "Léa");
+        assert_eq!(payload.a[0], "Penicillin (life threatening: anaphylaxis)");
+        assert_eq!(key_fingerprint(&signer), key_fingerprint(&key.verifying_key()));
+
+        let mut forged = payload.clone();
+        forged.a.clear();
+        let forged_json = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&forged).unwrap());
+        let parts: Vec<&str> = qr.split('.').collect();
+        let tampered = format!("{}.{}.{}.{}", parts[0], forged_json, parts[2], parts[3]);
+        assert!(verify_qr_payload(&tampered).is_err());
+        assert!(verify_qr_payload("EMC1.abc").is_err());
+    }
+
+    #[test]
+    fn wallet_card_pdf_is_generated() {
+        let conn = open_memory_database().unwrap();
+        seed(&conn);
+        add_contact(&conn, &contact("Marie", "+33 6 12 34 56 78")).unwrap();
+        let summary = assemble_emergency_summary(&conn, "Léa", None, None, false).unwrap();
+        let card =
+            build_emergency_card(&conn, summary, &SigningKey::from_bytes(&[7u8; 32])).unwrap();
+
+        let pdf = generate_wallet_card_pdf(&card).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn wrap_and_fit_respect_width() {
+        assert_eq!(fit("Penicillin", 20), "Penicillin");
+        assert_eq!(fit("Amoxicillin/clavulanate", 10), "Amoxici...");
+        let lines = wrap("one two three four five", 9);
+        assert!(lines.iter().all(|l| l.chars().count() <= 9));
+        assert_eq!(lines.join(" "), "one two three four five");
+    }
+}
@@ -10,7 +10,9 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::db::DatabaseError;
-use crate::db::repository::get_last_processing_error;
+use crate::db::repository::{get_entity_sources_for_document, get_last_processing_error};
+use crate::models::provenance::EntitySource;
+use crate::sensitivity;
 
 // ---------------------------------------------------------------------------
 // Types
@@ -58,6 +60,9 @@ pub struct DocumentCard {
     pub status: DocumentStatus,
     pub error_message: Option<String>,
     pub entity_summary: EntitySummary,
+    /// SENS-01: Flagged sensitive; only present when explicitly requested.
+    #[serde(default)]
+    pub sensitive: bool,
 }
 
 /// Counts of entities extracted from a document.
@@ -161,21 +166,27 @@ fn extract_filename(path: &str) -> String {
 }
 
 /// Fetches recent documents with professional info for the home feed.
+///
+/// SENS-01: Sensitive documents are left out unless `include_sensitive`.
 pub fn fetch_recent_documents(
     conn: &Connection,
     limit: u32,
     offset: u32,
+    include_sensitive: bool,
 ) -> Result<Vec<DocumentCard>, DatabaseError> {
-    let mut stmt = conn.prepare(
+    let sql = format!(
         "SELECT d.id, d.type, d.source_file, d.document_date, d.ingestion_date,
                 d.pipeline_status,
                 p.name AS prof_name, p.specialty AS prof_specialty,
-                d.title
+                d.title, d.sensitive
          FROM documents d
          LEFT JOIN professionals p ON d.professional_id = p.id
+         WHERE 1 = 1{}
          ORDER BY d.ingestion_date DESC
          LIMIT ?1 OFFSET ?2",
-    )?;
+        sensitivity::document_filter_sql("d", include_sensitive)
+    );
+    let mut stmt = conn.prepare(&sql)?;
 
     let rows = stmt.query_map(params![limit, offset], |row| {
         let id: String = row.get(0)?;
@@ -187,6 +198,7 @@ pub fn fetch_recent_documents(
         let prof_name: Option<String> = row.get(6)?;
         let prof_specialty: Option<String> = row.get(7)?;
         let title: Option<String> = row.get(8)?;
+        let sensitive: bool = row.get(9)?;
 
         Ok(DocumentCard {
             id,
@@ -199,6 +211,7 @@ pub fn fetch_recent_documents(
             status: DocumentStatus::from_pipeline_status(pipeline_status.as_deref()),
             error_message: None,
             entity_summary: EntitySummary::default(),
+            sensitive,
         })
     })?;
 
@@ -262,8 +275,12 @@ pub fn fetch_profile_stats(conn: &Connection) -> Result<ProfileStats, DatabaseEr
         |row| row.get(0),
     )?;
 
-    let total_lab_results: u32 =
-        conn.query_row("SELECT COUNT(*) FROM lab_results", [], |row| row.get(0))?;
+    // REC-01: Reconciled copies are linked to their primary, not counted twice
+    let total_lab_results: u32 = conn.query_row(
+        "SELECT COUNT(*) FROM lab_results WHERE duplicate_of IS NULL",
+        [],
+        |row| row.get(0),
+    )?;
 
     let last_doc_date: Option<String> = conn
         .query_row(
@@ -464,6 +481,7 @@ pub fn generate_extraction_suggestions(
             "SELECT lr.id, lr.test_name, lr.abnormal_flag
              FROM lab_results lr
              WHERE lr.abnormal_flag != 'normal'
+               AND lr.duplicate_of IS NULL
                AND lr.collection_date > date('now', '-14 days')
                AND NOT EXISTS (
                  SELECT 1 FROM messages m
@@ -624,6 +642,8 @@ pub struct DocumentDetail {
     pub allergies: Vec<AllergyEntry>,
     pub procedures: Vec<ProcedureEntry>,
     pub referrals: Vec<ReferralEntry>,
+    /// PROV-01: Page/region each stored field was read from.
+    pub field_sources: Vec<EntitySource>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -733,6 +753,10 @@ pub fn fetch_document_detail(
     let allergies = fetch_allergies(conn, document_id)?;
     let procedures = fetch_procedures(conn, document_id)?;
     let referrals = fetch_referrals(conn, document_id)?;
+    let field_sources = match Uuid::parse_str(&id) {
+        Ok(uuid) => get_entity_sources_for_document(conn, &uuid)?,
+        Err(_) => vec![],
+    };
 
     Ok(DocumentDetail {
         id,
@@ -754,6 +778,7 @@ pub fn fetch_document_detail(
         allergies,
         procedures,
         referrals,
+        field_sources,
     })
 }
 
@@ -957,6 +982,7 @@ mod tests {
             status: DocumentStatus::Failed,
             error_message: Some("Vision OCR timed out".into()),
             entity_summary: EntitySummary::default(),
+            sensitive: false,
         };
         let json = serde_json::to_string(&card).unwrap();
         assert!(json.contains("\"error_message\":\"Vision OCR timed out\""));
@@ -1033,7 +1059,7 @@ mod tests {
     #[test]
     fn fetch_recent_documents_empty() {
         let conn = open_memory_database().unwrap();
-        let docs = fetch_recent_documents(&conn, 20, 0).unwrap();
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
         assert!(docs.is_empty());
     }
 
@@ -1056,7 +1082,7 @@ mod tests {
         )
         .unwrap();
 
-        let docs = fetch_recent_documents(&conn, 20, 0).unwrap();
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
         assert_eq!(docs.len(), 2);
         // Most recent first
         assert_eq!(docs[0].id, id2);
@@ -1077,7 +1103,7 @@ mod tests {
         )
         .unwrap();
 
-        let docs = fetch_recent_documents(&conn, 20, 0).unwrap();
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
         assert_eq!(docs.len(), 1);
         assert_eq!(docs[0].professional_name.as_deref(), Some("Dr. Chen"));
         assert_eq!(
@@ -1095,7 +1121,7 @@ mod tests {
         insert_test_document(&conn, &id1, "prescription", false, "/tmp/a.pdf");
         insert_test_document(&conn, &id2, "lab_result", true, "/tmp/b.pdf");
 
-        let docs = fetch_recent_documents(&conn, 20, 0).unwrap();
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
         let unverified = docs.iter().find(|d| d.id == id1).unwrap();
         let confirmed = docs.iter().find(|d| d.id == id2).unwrap();
 
@@ -1124,7 +1150,7 @@ mod tests {
             )
             .unwrap();
 
-            let docs = fetch_recent_documents(&conn, 100, 0).unwrap();
+            let docs = fetch_recent_documents(&conn, 100, 0, false).unwrap();
             let card = docs.iter().find(|d| d.id == id).unwrap();
             assert_eq!(&card.status, expected, "pipeline_status '{status_str}' should map to {expected:?}");
         }
@@ -1136,7 +1162,7 @@ mod tests {
         let id = Uuid::new_v4().to_string();
         insert_test_document(&conn, &id, "prescription", false, "/home/user/docs/blood_work.pdf");
 
-        let docs = fetch_recent_documents(&conn, 20, 0).unwrap();
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
         // source_filename should use `title` column (original filename), not the encrypted path
         assert_eq!(docs[0].source_filename, "Test Doc");
     }
@@ -1152,7 +1178,7 @@ mod tests {
             params![id],
         ).unwrap();
 
-        let docs = fetch_recent_documents(&conn, 20, 0).unwrap();
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
         assert_eq!(docs[0].source_filename, "abc.pdf.enc");
     }
 
@@ -1162,10 +1188,28 @@ mod tests {
         let id = Uuid::new_v4().to_string();
         insert_test_document(&conn, &id, "discharge_summary", false, "/tmp/ds.pdf");
 
-        let docs = fetch_recent_documents(&conn, 20, 0).unwrap();
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
         assert_eq!(docs[0].document_type, "Discharge Summary");
     }
 
+    #[test]
+    fn fetch_recent_documents_hides_sensitive_by_default() {
+        let conn = open_memory_database().unwrap();
+        let visible = Uuid::new_v4().to_string();
+        let hidden = Uuid::new_v4();
+        insert_test_document(&conn, &visible, "prescription", false, "/tmp/a.pdf");
+        insert_test_document(&conn, &hidden.to_string(), "clinical_note", false, "/tmp/b.pdf");
+        sensitivity::set_document_sensitive(&conn, &hidden, true).unwrap();
+
+        let docs = fetch_recent_documents(&conn, 20, 0, false).unwrap();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].id, visible);
+
+        let all = fetch_recent_documents(&conn, 20, 0, true).unwrap();
+        assert_eq!(all.len(), 2);
+        assert!(all.iter().any(|d| d.sensitive));
+    }
+
     // -----------------------------------------------------------------------
     // fetch_entity_counts
     // -----------------------------------------------------------------------
@@ -1235,6 +1279,16 @@ mod tests {
         assert_eq!(stats.total_medications, 1);
         assert_eq!(stats.total_lab_results, 1);
         assert!(stats.last_document_date.is_some());
+
+        // A reconciled copy from another document is not counted
+        insert_test_lab_result(&conn, &doc1);
+        conn.execute(
+            "UPDATE lab_results SET duplicate_of = (SELECT id FROM lab_results WHERE document_id = ?1)
+             WHERE document_id = ?2",
+            params![doc2, doc1],
+        )
+        .unwrap();
+        assert_eq!(fetch_profile_stats(&conn).unwrap().total_lab_results, 1);
     }
 
     // -----------------------------------------------------------------------
@@ -1326,13 +1380,13 @@ mod tests {
             .unwrap();
         }
 
-        let page1 = fetch_recent_documents(&conn, 2, 0).unwrap();
+        let page1 = fetch_recent_documents(&conn, 2, 0, false).unwrap();
         assert_eq!(page1.len(), 2);
 
-        let page2 = fetch_recent_documents(&conn, 2, 2).unwrap();
+        let page2 = fetch_recent_documents(&conn, 2, 2, false).unwrap();
         assert_eq!(page2.len(), 2);
 
-        let page3 = fetch_recent_documents(&conn, 2, 4).unwrap();
+        let page3 = fetch_recent_documents(&conn, 2, 4, false).unwrap();
         assert_eq!(page3.len(), 1);
 
         // No overlap
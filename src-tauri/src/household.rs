@@ -0,0 +1,589 @@
+//! HH-01: Household overview across unlocked profiles.
+//!
+//! A caregiver managing several profiles gets one merged, profile-tagged list
+//! of what needs attention: critical lab alerts, upcoming appointments,
+//! vaccine doses and screenings due, and medications running out.
+//!
+//! Key properties:
+//! - Only profiles currently unlocked in the `SessionCache` are read
+//! - Each profile passes the MP-01 authorization cascade before it is opened
+//! - Each profile is read through its own encrypted connection
+//! - Read-only: the overview is assembled in memory, nothing is persisted
+
+use std::collections::HashMap;
+
+use chrono::{Duration, Local, NaiveDate};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::authorization::{self, AuthorizationError};
+use crate::crypto::profile::{PatientDemographics, ProfileInfo};
+use crate::db::{repository, DatabaseError};
+use crate::invariants::immunization::{compute_due_doses, DueStatus};
+use crate::invariants::screening::ScreeningCategory;
+use crate::trust::{fetch_critical_alerts, TrustError};
+
+/// Device ID used for authorization checks from the desktop UI.
+/// The desktop is not a paired device, so device-level grants never apply.
+const DESKTOP_DEVICE_ID: &str = "";
+
+/// Active medications ending within this many days are listed as refills.
+const REFILL_WINDOW_DAYS: i64 = 14;
+
+// ═══════════════════════════════════════════════════════════
+// Types
+// ═══════════════════════════════════════════════════════════
+
+/// What a household item is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HouseholdItemKind {
+    CriticalAlert,
+    Appointment,
+    ImmunizationDue,
+    ScreeningDue,
+    MedicationRefill,
+}
+
+/// Ordering bucket for the merged list — most urgent first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HouseholdPriority {
+    Critical,
+    Attention,
+    Info,
+}
+
+/// One item of the merged overview, tagged with the profile it belongs to.
+#[derive(Debug, Clone, Serialize)]
+pub struct HouseholdItem {
+    pub profile_id: Uuid,
+    pub profile_name: String,
+    pub color_index: Option<u8>,
+    pub kind: HouseholdItemKind,
+    pub priority: HouseholdPriority,
+    /// Display title (test, professional, vaccine, screening or medication name).
+    pub title: String,
+    pub detail: Option<String>,
+    /// Machine status for the frontend to localize ("overdue", "ends_soon", ...).
+    pub status: Option<String>,
+    /// Relevant date (YYYY-MM-DD): lab date, appointment, due date or end date.
+    pub date: Option<String>,
+    /// Source entity ID within the profile's database, when there is one.
+    pub entity_id: Option<String>,
+}
+
+/// A profile included in the overview.
+#[derive(Debug, Clone, Serialize)]
+pub struct HouseholdMember {
+    pub profile_id: Uuid,
+    pub profile_name: String,
+    pub color_index: Option<u8>,
+    pub access_level: String,
+    pub item_count: u32,
+}
+
+/// An unlocked profile left out of the overview, with the reason.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedProfile {
+    pub profile_id: Uuid,
+    pub profile_name: String,
+    /// "access_denied" or "unavailable".
+    pub reason: String,
+}
+
+/// Merged view across every unlocked profile the caller may read.
+#[derive(Debug, Clone, Serialize)]
+pub struct HouseholdOverview {
+    pub members: Vec<HouseholdMember>,
+    pub items: Vec<HouseholdItem>,
+    pub skipped: Vec<SkippedProfile>,
+    pub generated_at: String,
+}
+
+// ═══════════════════════════════════════════════════════════
+// Error type
+// ═══════════════════════════════════════════════════════════
+
+/// Errors from household aggregation.
+#[derive(Debug, thiserror::Error)]
+pub enum HouseholdError {
+    #[error("Database error: {0}")]
+    Database(#[from] DatabaseError),
+    #[error("Trust error: {0}")]
+    Trust(#[from] TrustError),
+    #[error("Authorization error: {0}")]
+    Authorization(#[from] AuthorizationError),
+    #[error("Profile {0} is not available: {1}")]
+    Unavailable(Uuid, String),
+}
+
+// ═══════════════════════════════════════════════════════════
+// Aggregation
+// ═══════════════════════════════════════════════════════════
+
+/// Build the household overview for `owner_profile_id`.
+///
+/// `unlocked` lists the profiles held in the session cache; `open_profile_db`
+/// opens one of them with its own key. Profiles the owner may not access are
+/// reported in `skipped` and never opened. A profile that fails to open or
+/// query (e.g. evicted in the meantime) is skipped rather than failing the
+/// whole overview.
+pub fn build_household_overview<F>(
+    app_conn: &Connection,
+    profiles: &[ProfileInfo],
+    owner_profile_id: &Uuid,
+    unlocked: &[Uuid],
+    mut open_profile_db: F,
+    lang: &str,
+    today: NaiveDate,
+) -> Result<HouseholdOverview, HouseholdError>
+where
+    F: FnMut(&Uuid) -> Result<Connection, HouseholdError>,
+{
+    let by_id: HashMap<Uuid, &ProfileInfo> = profiles.iter().map(|p| (p.id, p)).collect();
+
+    let mut members = Vec::new();
+    let mut items = Vec::new();
+    let mut skipped = Vec::new();
+
+    for profile_id in unlocked {
+        let Some(profile) = by_id.get(profile_id) else {
+            continue;
+        };
+
+        let decision = authorization::check_profile_access_with_profiles(
+            app_conn,
+            profiles,
+            owner_profile_id,
+            profile_id,
+            DESKTOP_DEVICE_ID,
+        )?;
+        if !decision.allowed {
+            skipped.push(SkippedProfile {
+                profile_id: *profile_id,
+                profile_name: profile.name.clone(),
+                reason: "access_denied".into(),
+            });
+            continue;
+        }
+
+        let collected = open_profile_db(profile_id)
+            .and_then(|conn| collect_profile_items(&conn, profile, lang, today));
+        match collected {
+            Ok(profile_items) => {
+                members.push(HouseholdMember {
+                    profile_id: *profile_id,
+                    profile_name: profile.name.clone(),
+                    color_index: profile.color_index,
+                    access_level: decision.level.as_str().into(),
+                    item_count: profile_items.len() as u32,
+                });
+                items.extend(profile_items);
+            }
+            Err(e) => {
+                tracing::warn!(profile_id = %profile_id, error = %e, "Household: profile skipped");
+                skipped.push(SkippedProfile {
+                    profile_id: *profile_id,
+                    profile_name: profile.name.clone(),
+                    reason: "unavailable".into(),
+                });
+            }
+        }
+    }
+
+    members.sort_by(|a, b| a.profile_name.cmp(&b.profile_name));
+    sort_items(&mut items);
+
+    Ok(HouseholdOverview {
+        members,
+        items,
+        skipped,
+        generated_at: Local::now().naive_local().to_string(),
+    })
+}
+
+/// Most urgent first, then soonest date (undated last), then profile name.
+fn sort_items(items: &mut [HouseholdItem]) {
+    items.sort_by(|a, b| {
+        a.priority
+            .cmp(&b.priority)
+            .then_with(|| match (&a.date, &b.date) {
+                (Some(x), Some(y)) => x.cmp(y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            })
+            .then_with(|| a.profile_name.cmp(&b.profile_name))
+    });
+}
+
+/// Collect every household item from one profile's database.
+pub fn collect_profile_items(
+    conn: &Connection,
+    profile: &ProfileInfo,
+    lang: &str,
+    today: NaiveDate,
+) -> Result<Vec<HouseholdItem>, HouseholdError> {
+    let tag = |kind, priority, title: String| HouseholdItem {
+        profile_id: profile.id,
+        profile_name: profile.name.clone(),
+        color_index: profile.color_index,
+        kind,
+        priority,
+        title,
+        detail: None,
+        status: None,
+        date: None,
+        entity_id: None,
+    };
+
+    let mut items = Vec::new();
+
+    // Critical lab alerts (not dismissed)
+    for alert in fetch_critical_alerts(conn)? {
+        items.push(HouseholdItem {
+            detail: Some(format!("{} {}", alert.value, alert.unit).trim().to_string()),
+            status: Some(alert.abnormal_flag),
+            date: Some(alert.lab_date),
+            entity_id: Some(alert.id),
+            ..tag(HouseholdItemKind::CriticalAlert, HouseholdPriority::Critical, alert.test_name)
+        });
+    }
+
+    // Upcoming appointments without post-appointment notes
+    for appt in crate::appointment::list_appointments(conn)? {
+        let upcoming = NaiveDate::parse_from_str(&appt.date, "%Y-%m-%d")
+            .map(|d| d >= today)
+            .unwrap_or(false);
+        if !upcoming || appt.has_post_notes {
+            continue;
+        }
+        let detail = (!appt.professional_specialty.is_empty()).then_some(appt.professional_specialty);
+        items.push(HouseholdItem {
+            detail,
+            status: Some(if appt.prep_generated { "prepared" } else { "scheduled" }.into()),
+            date: Some(appt.date),
+            entity_id: Some(appt.id),
+            ..tag(HouseholdItemKind::Appointment, HouseholdPriority::Info, appt.professional_name)
+        });
+    }
+
+    // IMM-01: Vaccine doses due (needs a date of birth)
+    if let Some(dob) = profile.date_of_birth {
+        let records = repository::get_all_immunizations(conn)?;
+        for due in compute_due_doses(dob, &records, today) {
+            let (status, priority) = match due.status {
+                DueStatus::Overdue => ("overdue", HouseholdPriority::Attention),
+                DueStatus::Due => ("due", HouseholdPriority::Attention),
+                DueStatus::Upcoming => ("upcoming", HouseholdPriority::Info),
+            };
+            items.push(HouseholdItem {
+                detail: Some(format!("#{}", due.dose_number)),
+                status: Some(status.into()),
+                date: Some(due.due_date.format("%Y-%m-%d").to_string()),
+                ..tag(
+                    HouseholdItemKind::ImmunizationDue,
+                    priority,
+                    due.series.label.get(lang).to_string(),
+                )
+            });
+        }
+    }
+
+    // ME-06: Screenings due — vaccines are covered by the immunization schedule above
+    let demographics = PatientDemographics::from_profile(profile);
+    let screening_records = repository::get_all_screening_records(conn)?;
+    for screening in crate::me::build_screening_info(lang, Some(&demographics), &screening_records) {
+        if !screening.eligible
+            || screening.is_complete
+            || screening.category == ScreeningCategory::Vaccine.as_str()
+        {
+            continue;
+        }
+        let status = if screening.next_due.is_some() { "expired" } else { "never_done" };
+        items.push(HouseholdItem {
+            status: Some(status.into()),
+            date: screening.next_due,
+            ..tag(HouseholdItemKind::ScreeningDue, HouseholdPriority::Info, screening.label)
+        });
+    }
+
+    // Medication refills: active medications whose end date is close or past
+    for refill in fetch_refills_due(conn, today)? {
+        let (status, priority) = if refill.end_date < today {
+            ("ended", HouseholdPriority::Attention)
+        } else {
+            ("ends_soon", HouseholdPriority::Info)
+        };
+        items.push(HouseholdItem {
+            detail: Some(refill.dose),
+            status: Some(status.into()),
+            date: Some(refill.end_date.format("%Y-%m-%d").to_string()),
+            entity_id: Some(refill.id),
+            ..tag(HouseholdItemKind::MedicationRefill, priority, refill.name)
+        });
+    }
+
+    Ok(items)
+}
+
+struct RefillDue {
+    id: String,
+    name: String,
+    dose: String,
+    end_date: NaiveDate,
+}
+
+/// Active medications whose end date falls before `today + REFILL_WINDOW_DAYS`.
+fn fetch_refills_due(conn: &Connection, today: NaiveDate) -> Result<Vec<RefillDue>, DatabaseError> {
+    let limit = (today + Duration::days(REFILL_WINDOW_DAYS)).format("%Y-%m-%d").to_string();
+    let mut stmt = conn.prepare(
+        "SELECT id, COALESCE(brand_name, generic_name), dose, end_date
+         FROM medications
+         WHERE status = 'active' AND end_date IS NOT NULL AND end_date <= ?1
+         ORDER BY end_date",
+    )?;
+    let rows = stmt.query_map(params![limit], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+        ))
+    })?;
+
+    let mut refills = Vec::new();
+    for row in rows {
+        let (id, name, dose, end_date) = row?;
+        // Skip unparseable dates rather than guessing
+        if let Ok(end_date) = NaiveDate::parse_from_str(&end_date, "%Y-%m-%d") {
+            refills.push(RefillDue { id, name, dose, end_date });
+        }
+    }
+    Ok(refills)
+}
+
+// ═══════════════════════════════════════════════════════════
+// Tests
+// ═══════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::appointment::{create_appointment, create_professional, NewProfessional};
+    use crate::db::app_db::open_memory_app_database;
+    use crate::db::repository::device_registry::{insert_profile_grant, ProfileAccessGrantRow};
+    use crate::db::sqlite::open_memory_database;
+    use chrono::NaiveDateTime;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn profile(id: u128, name: &str, managed_by: Option<&str>) -> ProfileInfo {
+        ProfileInfo {
+            id: Uuid::from_u128(id),
+            name: name.into(),
+            created_at: NaiveDateTime::parse_from_str("2026-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")
+                .unwrap(),
+            managed_by: managed_by.map(String::from),
+            password_hint: None,
+            date_of_birth: None,
+            color_index: Some(id as u8),
+            country: None,
+            address: None,
+            sex: None,
+            ethnicities: vec![],
+            blood_type: None,
+        }
+    }
+
+    fn insert_document(conn: &Connection) -> String {
+        let id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO documents (id, type, title, ingestion_date, source_file, verified)
+             VALUES (?1, 'lab_result', 'Labs', datetime('now'), 'test.pdf', 1)",
+            params![id],
+        )
+        .unwrap();
+        id
+    }
+
+    fn insert_critical_lab(conn: &Connection, test_name: &str, lab_date: &str) {
+        let doc = insert_document(conn);
+        conn.execute(
+            "INSERT INTO lab_results (id, test_name, value, unit, abnormal_flag, collection_date, document_id)
+             VALUES (?1, ?2, 6.8, 'mmol/L', 'critical_high', ?3, ?4)",
+            params![Uuid::new_v4().to_string(), test_name, lab_date, doc],
+        )
+        .unwrap();
+    }
+
+    fn insert_medication(conn: &Connection, name: &str, status: &str, end_date: Option<&str>) {
+        let doc = insert_document(conn);
+        conn.execute(
+            "INSERT INTO medications (id, generic_name, dose, frequency, frequency_type,
+                                      start_date, end_date, status, document_id)
+             VALUES (?1, ?2, '10mg', 'Once daily', 'scheduled', '2026-01-01', ?3, ?4, ?5)",
+            params![Uuid::new_v4().to_string(), name, end_date, status, doc],
+        )
+        .unwrap();
+    }
+
+    fn insert_appointment(conn: &Connection, name: &str, on: NaiveDate) {
+        let prof = create_professional(
+            conn,
+            &NewProfessional {
+                name: name.into(),
+                specialty: "Pediatrics".into(),
+                institution: None,
+            },
+        )
+        .unwrap();
+        create_appointment(conn, &prof, &on).unwrap();
+    }
+
+    #[test]
+    fn collects_alerts_appointments_and_refills() {
+        let conn = open_memory_database().unwrap();
+        let child = profile(3, "Léa", Some("Alice"));
+        let today = date(2026, 10, 18);
+
+        insert_critical_lab(&conn, "Potassium", "2026-10-10");
+        insert_appointment(&conn, "Dr. Martin", date(2026, 11, 2));
+        insert_appointment(&conn, "Dr. Old", date(2026, 9, 1));
+        insert_medication(&conn, "Salbutamol", "active", Some("2026-10-25"));
+        insert_medication(&conn, "Amoxicillin", "active", Some("2027-03-01"));
+        insert_medication(&conn, "Ibuprofen", "stopped", Some("2026-10-20"));
+
+        let items = collect_profile_items(&conn, &child, "en", today).unwrap();
+
+        assert!(items.iter().all(|i| i.profile_id == child.id && i.profile_name == "Léa"));
+        let alert = items.iter().find(|i| i.kind == HouseholdItemKind::CriticalAlert).unwrap();
+        assert_eq!(alert.title, "Potassium");
+        assert_eq!(alert.priority, HouseholdPriority::Critical);
+
+        let appts: Vec<_> = items.iter().filter(|i| i.kind == HouseholdItemKind::Appointment).collect();
+        assert_eq!(appts.len(), 1, "past appointments are excluded");
+        assert_eq!(appts[0].title, "Dr. Martin");
+
+        let refills: Vec<_> = items
+            .iter()
+            .filter(|i| i.kind == HouseholdItemKind::MedicationRefill)
+            .collect();
+        assert_eq!(refills.len(), 1, "only active medications ending within the window");
+        assert_eq!(refills[0].title, "Salbutamol");
+        assert_eq!(refills[0].status.as_deref(), Some("ends_soon"));
+    }
+
+    #[test]
+    fn overdue_vaccines_need_date_of_birth() {
+        let conn = open_memory_database().unwrap();
+        let today = date(2026, 10, 18);
+
+        let mut child = profile(3, "Léa", Some("Alice"));
+        let without_dob = collect_profile_items(&conn, &child, "en", today).unwrap();
+        assert!(!without_dob.iter().any(|i| i.kind == HouseholdItemKind::ImmunizationDue));
+
+        child.date_of_birth = Some(date(2026, 1, 5));
+        let items = collect_profile_items(&conn, &child, "en", today).unwrap();
+        let vaccines: Vec<_> = items
+            .iter()
+            .filter(|i| i.kind == HouseholdItemKind::ImmunizationDue)
+            .collect();
+        assert!(!vaccines.is_empty(), "infant series are due with no records");
+        assert!(vaccines.iter().any(|i| i.status.as_deref() == Some("overdue")));
+    }
+
+    #[test]
+    fn overview_merges_authorized_profiles_and_skips_others() {
+        let app_conn = open_memory_app_database().unwrap();
+        let alice = profile(1, "Alice", None);
+        let bob = profile(2, "Bob", None);
+        let child = profile(3, "Léa", Some("Alice"));
+        let profiles = vec![alice.clone(), bob.clone(), child.clone()];
+        let today = date(2026, 10, 18);
+
+        let mut dbs: HashMap<Uuid, Connection> = HashMap::new();
+        for p in &profiles {
+            dbs.insert(p.id, open_memory_database().unwrap());
+        }
+        insert_critical_lab(&dbs[&child.id], "Glucose", "2026-10-12");
+        insert_appointment(&dbs[&alice.id], "Dr. Chen", date(2026, 10, 30));
+        insert_critical_lab(&dbs[&bob.id], "Sodium", "2026-10-01");
+
+        let unlocked = vec![alice.id, bob.id, child.id];
+        let overview = build_household_overview(
+            &app_conn,
+            &profiles,
+            &alice.id,
+            &unlocked,
+            |id| dbs.remove(id).ok_or_else(|| HouseholdError::Unavailable(*id, "not cached".into())),
+            "en",
+            today,
+        )
+        .unwrap();
+
+        let names: Vec<_> = overview.members.iter().map(|m| m.profile_name.as_str()).collect();
+        assert_eq!(names, vec!["Alice", "Léa"]);
+        assert_eq!(overview.skipped.len(), 1);
+        assert_eq!(overview.skipped[0].profile_name, "Bob");
+        assert_eq!(overview.skipped[0].reason, "access_denied");
+
+        // Bob's data was never read
+        assert!(!overview.items.iter().any(|i| i.title == "Sodium"));
+        // Critical alert sorts before the appointment
+        assert_eq!(overview.items[0].title, "Glucose");
+        assert_eq!(overview.items[0].profile_id, child.id);
+        assert_eq!(overview.items[1].title, "Dr. Chen");
+    }
+
+    #[test]
+    fn explicit_grant_includes_profile_and_open_failure_is_skipped() {
+        let app_conn = open_memory_app_database().unwrap();
+        let alice = profile(1, "Alice", None);
+        let bob = profile(2, "Bob", None);
+        let child = profile(3, "Léa", Some("Alice"));
+        let profiles = vec![alice.clone(), bob.clone(), child.clone()];
+
+        insert_profile_grant(
+            &app_conn,
+            &ProfileAccessGrantRow {
+                id: Uuid::new_v4().to_string(),
+                granter_profile_id: bob.id.to_string(),
+                grantee_profile_id: alice.id.to_string(),
+                access_level: "read_only".into(),
+                granted_at: "2026-10-01 00:00:00".into(),
+                revoked_at: None,
+                scope: None,
+                expires_at: None,
+            },
+        )
+        .unwrap();
+
+        let overview = build_household_overview(
+            &app_conn,
+            &profiles,
+            &alice.id,
+            &[bob.id, child.id],
+            |id| {
+                if *id == child.id {
+                    Err(HouseholdError::Unavailable(*id, "evicted".into()))
+                } else {
+                    Ok(open_memory_database().unwrap())
+                }
+            },
+            "en",
+            date(2026, 10, 18),
+        )
+        .unwrap();
+
+        assert_eq!(overview.members.len(), 1);
+        assert_eq!(overview.members[0].profile_name, "Bob");
+        assert_eq!(overview.members[0].access_level, "read_only");
+        assert_eq!(overview.skipped.len(), 1);
+        assert_eq!(overview.skipped[0].reason, "unavailable");
+    }
+}
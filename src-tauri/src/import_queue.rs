@@ -1,9 +1,13 @@
-//! BTL-10 C3: Import queue service — in-memory job lifecycle manager.
+//! BTL-10 C3: Import queue service — job lifecycle manager.
 //!
-//! Pure state machine with no I/O. The worker loop (C4) drives actual processing.
+//! State machine with no I/O of its own. The worker loop (C4) drives actual processing.
 //! Thread-safe via Mutex. Notify wakes the worker when jobs are enqueued.
+//!
+//! IMQ-01: Jobs persist to the profile DB (`import_jobs`). Mutations mark jobs
+//! unsaved; `persist` writes them through a caller-supplied connection and
+//! `hydrate_from_db` restores the queue after a restart or crash.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -39,7 +43,8 @@ impl JobState {
     fn valid_transitions(&self) -> &'static [JobState] {
         match self {
             Self::Queued => &[Self::Importing, Self::Cancelled, Self::Failed],
-            Self::Importing => &[Self::Extracting, Self::Failed, Self::Cancelled],
+            // EML-01: An email job is done once its parts are imported and queued
+            Self::Importing => &[Self::Extracting, Self::Done, Self::Failed, Self::Cancelled],
             Self::Extracting => &[Self::Structuring, Self::Failed, Self::Cancelled],
             Self::Structuring => &[Self::PendingReview, Self::Done, Self::Failed, Self::Cancelled],
             Self::PendingReview => &[Self::Done],
@@ -52,6 +57,35 @@ impl JobState {
     pub fn can_transition_to(&self, target: &JobState) -> bool {
         self.valid_transitions().contains(target)
     }
+
+    /// IMQ-01: Stable name stored in the `import_jobs` table.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Importing => "importing",
+            Self::Extracting => "extracting",
+            Self::Structuring => "structuring",
+            Self::PendingReview => "pending_review",
+            Self::Done => "done",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// IMQ-01: Parse a name written by [`JobState::as_str`].
+    pub fn parse(value: &str) -> Option<Self> {
+        Some(match value {
+            "queued" => Self::Queued,
+            "importing" => Self::Importing,
+            "extracting" => Self::Extracting,
+            "structuring" => Self::Structuring,
+            "pending_review" => Self::PendingReview,
+            "done" => Self::Done,
+            "failed" => Self::Failed,
+            "cancelled" => Self::Cancelled,
+            _ => return None,
+        })
+    }
 }
 
 /// A single import job in the queue.
@@ -71,6 +105,14 @@ pub struct ImportJob {
     /// UC-01: User-selected document type at import time.
     /// When Some, bypasses LLM classifier. Values: "lab_report", "prescription", "medical_image".
     pub user_document_type: Option<String>,
+    /// MPA-01: Ordered photo paths imported together as one multi-page
+    /// document. Empty for single-file jobs (`file_path` is the first page).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub page_paths: Vec<String>,
+    /// PWD-01: Password for a protected PDF, supplied at enqueue or retry.
+    /// Never serialized: it stays in memory and is not sent to the frontend.
+    #[serde(skip)]
+    pub pdf_password: Option<String>,
 }
 
 /// A snapshot of the entire queue (for IPC serialization).
@@ -84,13 +126,26 @@ pub struct QueueSnapshot {
 // Service
 // ---------------------------------------------------------------------------
 
-/// In-memory import queue with job lifecycle management.
+/// IMQ-01: Changes not yet written to the profile DB.
+#[derive(Default)]
+struct PersistState {
+    /// Jobs created or changed since the last `persist`.
+    unsaved: HashSet<String>,
+    /// Jobs deleted since the last `persist`.
+    removed: HashSet<String>,
+    /// Profile whose persisted jobs are loaded (None until hydrated).
+    hydrated_profile: Option<Uuid>,
+}
+
+/// Import queue with job lifecycle management.
 pub struct ImportQueueService {
     jobs: Mutex<Vec<ImportJob>>,
     notify: tokio::sync::Notify,
     running: AtomicBool,
     /// §21 Fix C: Cooperative cancellation tokens shared with worker/processor.
     cancellation_tokens: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    /// IMQ-01: Pending DB writes. Lock order: `jobs` before `persistence`.
+    persistence: Mutex<PersistState>,
 }
 
 impl ImportQueueService {
@@ -100,14 +155,32 @@ impl ImportQueueService {
             notify: tokio::sync::Notify::new(),
             running: AtomicBool::new(false),
             cancellation_tokens: Mutex::new(HashMap::new()),
+            persistence: Mutex::new(PersistState::default()),
         }
     }
 
+    /// IMQ-01: Mark a job as changed since the last `persist`.
+    fn mark_unsaved(&self, job_id: &str) {
+        let mut persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+        persistence.removed.remove(job_id);
+        persistence.unsaved.insert(job_id.to_string());
+    }
+
     /// Enqueue a file for import. Returns the job ID.
     ///
     /// UC-01: `user_document_type` bypasses LLM classification when provided.
     /// Values: `"lab_report"`, `"prescription"`, `"medical_image"`.
     pub fn enqueue(&self, file_path: String, user_document_type: Option<String>) -> String {
+        self.enqueue_with_password(file_path, user_document_type, None)
+    }
+
+    /// PWD-01: Enqueue a file with the password of a protected PDF.
+    pub fn enqueue_with_password(
+        &self,
+        file_path: String,
+        user_document_type: Option<String>,
+        pdf_password: Option<String>,
+    ) -> String {
         let filename = std::path::Path::new(&file_path)
             .file_name()
             .and_then(|n| n.to_str())
@@ -127,11 +200,83 @@ impl ImportQueueService {
             started_at: None,
             completed_at: None,
             user_document_type,
+            page_paths: vec![],
+            pdf_password,
+        };
+
+        self.push(job)
+    }
+
+    /// MPA-01: Enqueue an ordered group of photos as one document. Returns the job ID.
+    ///
+    /// A group of one is a regular single-file job.
+    pub fn enqueue_group(
+        &self,
+        file_paths: Vec<String>,
+        user_document_type: Option<String>,
+    ) -> Result<String, QueueError> {
+        let Some(first) = file_paths.first().cloned() else {
+            return Err(QueueError::EmptyGroup);
+        };
+        if file_paths.len() == 1 {
+            return Ok(self.enqueue(first, user_document_type));
+        }
+
+        let first_name = std::path::Path::new(&first)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(&first)
+            .to_string();
+
+        let job = ImportJob {
+            id: Uuid::new_v4().to_string(),
+            file_path: first,
+            filename: format!("{first_name} (+{} pages)", file_paths.len() - 1),
+            state: JobState::Queued,
+            progress_pct: 0,
+            document_id: None,
+            model_used: None,
+            error: None,
+            queued_at: Utc::now().to_rfc3339(),
+            started_at: None,
+            completed_at: None,
+            user_document_type,
+            page_paths: file_paths,
+            pdf_password: None,
+        };
+
+        Ok(self.push(job))
+    }
+
+    /// MPA-01: Enqueue processing of a document that is already staged in the
+    /// DB (e.g. the result of merging separately imported photos).
+    pub fn enqueue_document(&self, document_id: String, staged_path: String, title: String) -> String {
+        let job = ImportJob {
+            id: Uuid::new_v4().to_string(),
+            file_path: staged_path,
+            filename: title,
+            state: JobState::Queued,
+            progress_pct: 0,
+            document_id: Some(document_id),
+            model_used: None,
+            error: None,
+            queued_at: Utc::now().to_rfc3339(),
+            started_at: None,
+            completed_at: None,
+            user_document_type: None,
+            page_paths: vec![],
+            pdf_password: None,
         };
 
+        self.push(job)
+    }
+
+    /// Append a job and wake the worker. Returns the job ID.
+    fn push(&self, job: ImportJob) -> String {
         let id = job.id.clone();
         let mut jobs = self.jobs.lock().expect("import queue lock poisoned");
         jobs.push(job);
+        self.mark_unsaved(&id);
         drop(jobs);
 
         self.notify.notify_one();
@@ -144,6 +289,7 @@ impl ImportQueueService {
         let pos = jobs.iter().position(|j| j.state == JobState::Queued)?;
         jobs[pos].state = JobState::Importing;
         jobs[pos].started_at = Some(Utc::now().to_rfc3339());
+        self.mark_unsaved(&jobs[pos].id);
         Some(jobs[pos].clone())
     }
 
@@ -185,6 +331,7 @@ impl ImportQueueService {
         if new_state.is_terminal() {
             job.completed_at = Some(Utc::now().to_rfc3339());
         }
+        self.mark_unsaved(job_id);
 
         Ok(())
     }
@@ -202,6 +349,7 @@ impl ImportQueueService {
             JobState::Queued | JobState::Importing | JobState::Extracting | JobState::Structuring => {
                 job.state = JobState::Cancelled;
                 job.completed_at = Some(Utc::now().to_rfc3339());
+                self.mark_unsaved(job_id);
 
                 // Signal the processor to stop at its next checkpoint
                 let tokens = self.cancellation_tokens.lock()
@@ -272,7 +420,17 @@ impl ImportQueueService {
     ///
     /// UC-01: Preserves `user_document_type` from the original job.
     pub fn retry(&self, job_id: &str) -> Result<String, QueueError> {
-        let (file_path, user_document_type) = {
+        self.retry_with_password(job_id, None)
+    }
+
+    /// PWD-01: Retry a failed job, optionally with a (new) PDF password.
+    /// Without one, the original job's password is kept.
+    pub fn retry_with_password(
+        &self,
+        job_id: &str,
+        pdf_password: Option<String>,
+    ) -> Result<String, QueueError> {
+        let (file_path, user_document_type, page_paths, pdf_password) = {
             let mut jobs = self.jobs.lock().expect("import queue lock poisoned");
             let job = jobs.iter_mut().find(|j| j.id == job_id)
                 .ok_or(QueueError::JobNotFound)?;
@@ -286,13 +444,19 @@ impl ImportQueueService {
 
             let file_path = job.file_path.clone();
             let user_document_type = job.user_document_type.clone();
+            let page_paths = job.page_paths.clone();
+            let pdf_password = pdf_password.or_else(|| job.pdf_password.clone());
             // Auto-dismiss: mark old failed job as Cancelled (filtered from visibleItems)
             job.state = JobState::Cancelled;
             job.completed_at = Some(Utc::now().to_rfc3339());
-            (file_path, user_document_type)
+            self.mark_unsaved(job_id);
+            (file_path, user_document_type, page_paths, pdf_password)
         };
 
-        Ok(self.enqueue(file_path, user_document_type))
+        if page_paths.len() > 1 {
+            return self.enqueue_group(page_paths, user_document_type);
+        }
+        Ok(self.enqueue_with_password(file_path, user_document_type, pdf_password))
     }
 
     /// Delete a terminal job from the queue.
@@ -309,6 +473,9 @@ impl ImportQueueService {
         }
 
         jobs.remove(pos);
+        let mut persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+        persistence.unsaved.remove(job_id);
+        persistence.removed.insert(job_id.to_string());
         Ok(())
     }
 
@@ -345,6 +512,9 @@ impl ImportQueueService {
         let mut tokens = self.cancellation_tokens.lock()
             .expect("cancellation tokens lock poisoned");
         tokens.clear();
+        // IMQ-01: The next profile's jobs are hydrated from its own DB
+        let mut persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+        *persistence = PersistState::default();
     }
 
     /// Get a single job by ID.
@@ -359,11 +529,164 @@ impl ImportQueueService {
         jobs.iter().filter(|j| !j.state.is_terminal()).count()
     }
 
+    /// IMQ-01: Whether there are changes not yet written by `persist`.
+    pub fn has_unsaved_changes(&self) -> bool {
+        let persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+        !persistence.unsaved.is_empty() || !persistence.removed.is_empty()
+    }
+
+    /// IMQ-01: Write changed and deleted jobs to the profile DB.
+    ///
+    /// Returns the number of rows written. On error the changes stay pending
+    /// and are retried by the next call.
+    pub fn persist(&self, conn: &rusqlite::Connection) -> Result<usize, String> {
+        let (changed, removed) = {
+            let jobs = self.jobs.lock().expect("import queue lock poisoned");
+            let mut persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+            let unsaved = std::mem::take(&mut persistence.unsaved);
+            let removed = std::mem::take(&mut persistence.removed);
+            let changed: Vec<ImportJob> = jobs
+                .iter()
+                .filter(|j| unsaved.contains(&j.id))
+                .cloned()
+                .collect();
+            (changed, removed)
+        };
+
+        if let Err(e) = write_jobs(conn, &changed, &removed) {
+            let mut persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+            persistence.unsaved.extend(changed.into_iter().map(|j| j.id));
+            persistence.removed.extend(removed);
+            return Err(e.to_string());
+        }
+
+        Ok(changed.len() + removed.len())
+    }
+
+    /// IMQ-01: Whether `profile_id`'s persisted jobs are already loaded.
+    pub fn is_hydrated_for(&self, profile_id: &Uuid) -> bool {
+        let persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+        persistence.hydrated_profile.as_ref() == Some(profile_id)
+    }
+
+    /// IMQ-01: Restore the queue from the profile DB after a restart or crash.
+    ///
+    /// Interrupted jobs resume from their last completed stage: a job whose
+    /// document was already imported is re-queued with its `document_id`
+    /// (the worker skips import and reprocesses from extraction); one that
+    /// never got that far is re-queued from the start. Jobs whose document
+    /// has since reached review count as done. Failed jobs stay visible so the
+    /// user can see which files need attention; finished ones are pruned.
+    /// Interrupted documents no job owns are then picked up by `recover_from_db`.
+    ///
+    /// Loading a different profile first clears the previous profile's jobs.
+    /// Returns the number of jobs queued for processing.
+    pub fn hydrate_from_db(
+        &self,
+        conn: &rusqlite::Connection,
+        profile_id: Uuid,
+    ) -> Result<usize, String> {
+        use crate::db::repository;
+        use crate::models::enums::PipelineStatus;
+
+        let previous = {
+            let persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+            persistence.hydrated_profile
+        };
+        match previous {
+            Some(id) if id == profile_id => return Ok(0),
+            Some(_) => self.reset(),
+            None => {}
+        }
+
+        let persisted = repository::list_import_jobs(conn).map_err(|e| e.to_string())?;
+        let known: HashSet<String> = {
+            let jobs = self.jobs.lock().expect("import queue lock poisoned");
+            jobs.iter().map(|j| j.id.clone()).collect()
+        };
+
+        let mut restored = Vec::new();
+        let mut pruned = Vec::new();
+        let mut resumed = 0;
+
+        for mut job in persisted.into_iter().filter(|j| !known.contains(&j.id)) {
+            match job.state {
+                JobState::Done | JobState::Cancelled => {
+                    pruned.push(job.id);
+                    continue;
+                }
+                JobState::Failed => {
+                    restored.push(job);
+                    continue;
+                }
+                _ => {}
+            }
+
+            let document_status = match job.document_id.as_deref().map(Uuid::parse_str) {
+                Some(Ok(doc_id)) => repository::get_document(conn, &doc_id)
+                    .map_err(|e| e.to_string())?
+                    .map(|doc| doc.pipeline_status),
+                _ => None,
+            };
+            let now = Utc::now().to_rfc3339();
+
+            let finished = job.state == JobState::PendingReview
+                || matches!(
+                    document_status,
+                    Some(PipelineStatus::PendingReview | PipelineStatus::Confirmed | PipelineStatus::Rejected)
+                );
+            if finished {
+                pruned.push(job.id);
+                continue;
+            }
+
+            match (job.document_id.is_some(), document_status) {
+                (true, Some(PipelineStatus::Failed)) => {
+                    job.state = JobState::Failed;
+                    job.error = Some("Processing failed before the app was closed".into());
+                    job.completed_at = Some(now);
+                }
+                (true, None) => {
+                    job.state = JobState::Failed;
+                    job.error = Some("The document was removed before its import finished".into());
+                    job.completed_at = Some(now);
+                }
+                _ => {
+                    // Imported/Extracting/Structuring, or not imported yet
+                    job.state = JobState::Queued;
+                    job.progress_pct = 0;
+                    job.started_at = None;
+                    job.error = None;
+                    resumed += 1;
+                }
+            }
+            restored.push(job);
+        }
+
+        {
+            let mut jobs = self.jobs.lock().expect("import queue lock poisoned");
+            let mut persistence = self.persistence.lock().expect("import queue persistence lock poisoned");
+            persistence.unsaved.extend(restored.iter().map(|j| j.id.clone()));
+            persistence.removed.extend(pruned);
+            persistence.hydrated_profile = Some(profile_id);
+            // Restored jobs were queued before anything enqueued this session
+            jobs.splice(0..0, restored);
+        }
+
+        if resumed > 0 {
+            tracing::info!(resumed, "Resumed interrupted import jobs");
+            self.notify.notify_one();
+        }
+
+        Ok(resumed + self.recover_from_db(conn)?)
+    }
+
     /// C11: Recover interrupted imports from DB on app restart.
     ///
     /// Queries documents with non-terminal pipeline_status (Imported, Extracting,
     /// Structuring), resets them to Imported, and enqueues them as Queued jobs.
-    /// Called during profile hydration after `open_db()`.
+    /// IMQ-01: Documents already owned by a queued or active job are skipped.
+    /// Called from `hydrate_from_db`.
     pub fn recover_from_db(
         &self,
         conn: &rusqlite::Connection,
@@ -377,13 +700,21 @@ impl ImportQueueService {
             PipelineStatus::Structuring,
         ];
 
+        let owned: HashSet<String> = {
+            let jobs = self.jobs.lock().expect("import queue lock poisoned");
+            jobs.iter()
+                .filter(|j| !j.state.is_terminal())
+                .filter_map(|j| j.document_id.clone())
+                .collect()
+        };
+
         let mut recovered = 0;
 
         for status in &interrupted_statuses {
             let docs = repository::get_documents_by_pipeline_status(conn, status)
                 .map_err(|e| e.to_string())?;
 
-            for doc in docs {
+            for doc in docs.into_iter().filter(|d| !owned.contains(&d.id.to_string())) {
                 // Reset to Imported so the pipeline can start fresh.
                 if *status != PipelineStatus::Imported {
                     repository::update_pipeline_status(
@@ -414,9 +745,12 @@ impl ImportQueueService {
                     started_at: None,
                     completed_at: None,
                     user_document_type: None, // Recovery: fallback to LLM classifier
+                    page_paths: vec![],
+                    pdf_password: None,
                 };
 
                 let mut jobs = self.jobs.lock().expect("import queue lock poisoned");
+                self.mark_unsaved(&job.id);
                 jobs.push(job);
                 recovered += 1;
             }
@@ -431,6 +765,25 @@ impl ImportQueueService {
     }
 }
 
+/// IMQ-01: Upsert changed jobs and delete removed ones in one transaction.
+fn write_jobs(
+    conn: &rusqlite::Connection,
+    changed: &[ImportJob],
+    removed: &HashSet<String>,
+) -> Result<(), crate::db::DatabaseError> {
+    use crate::db::repository;
+
+    let tx = conn.unchecked_transaction()?;
+    for job in changed {
+        repository::upsert_import_job(&tx, job)?;
+    }
+    for job_id in removed {
+        repository::delete_import_job(&tx, job_id)?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Errors
 // ---------------------------------------------------------------------------
@@ -439,12 +792,14 @@ impl ImportQueueService {
 pub enum QueueError {
     JobNotFound,
     InvalidTransition { from: String, to: String },
+    EmptyGroup,
 }
 
 impl std::fmt::Display for QueueError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::JobNotFound => write!(f, "Job not found in queue"),
+            Self::EmptyGroup => write!(f, "No files in document group"),
             Self::InvalidTransition { from, to } => {
                 write!(f, "Invalid state transition: {from} → {to}")
             }
@@ -494,6 +849,62 @@ mod tests {
         assert_eq!(snap.jobs[2].id, id3);
     }
 
+    #[test]
+    fn enqueue_group_creates_single_job() {
+        let svc = service();
+        let id = svc
+            .enqueue_group(
+                vec!["/tmp/p1.jpg".into(), "/tmp/p2.jpg".into(), "/tmp/p3.jpg".into()],
+                Some("lab_report".into()),
+            )
+            .unwrap();
+
+        let snap = svc.snapshot();
+        assert_eq!(snap.jobs.len(), 1);
+        let job = &snap.jobs[0];
+        assert_eq!(job.id, id);
+        assert_eq!(job.file_path, "/tmp/p1.jpg");
+        assert_eq!(job.filename, "p1.jpg (+2 pages)");
+        assert_eq!(job.page_paths.len(), 3);
+    }
+
+    #[test]
+    fn enqueue_group_of_one_is_plain_job() {
+        let svc = service();
+        svc.enqueue_group(vec!["/tmp/p1.jpg".into()], None).unwrap();
+        let job = &svc.snapshot().jobs[0];
+        assert_eq!(job.filename, "p1.jpg");
+        assert!(job.page_paths.is_empty());
+    }
+
+    #[test]
+    fn enqueue_empty_group_rejected() {
+        let svc = service();
+        assert_eq!(svc.enqueue_group(vec![], None), Err(QueueError::EmptyGroup));
+    }
+
+    #[test]
+    fn retry_group_preserves_pages() {
+        let svc = service();
+        let id = svc
+            .enqueue_group(vec!["/tmp/p1.jpg".into(), "/tmp/p2.jpg".into()], None)
+            .unwrap();
+        svc.next_queued();
+        svc.update_job_state(&id, JobState::Failed, None, None, None, Some("err".into())).unwrap();
+
+        let new_id = svc.retry(&id).unwrap();
+        assert_eq!(svc.get_job(&new_id).unwrap().page_paths.len(), 2);
+    }
+
+    #[test]
+    fn enqueue_document_carries_document_id() {
+        let svc = service();
+        let id = svc.enqueue_document("doc-1".into(), "/profile/originals/doc-1.pdf.enc".into(), "scan.jpg".into());
+        let job = svc.get_job(&id).unwrap();
+        assert_eq!(job.document_id.as_deref(), Some("doc-1"));
+        assert_eq!(job.state, JobState::Queued);
+    }
+
     // -- next_queued --
 
     #[test]
@@ -914,6 +1325,169 @@ mod tests {
 
             assert_eq!(svc.snapshot().jobs[0].filename, "ordonnance.pdf");
         }
+
+        // -- Persistence (IMQ-01) --
+
+        #[test]
+        fn job_state_names_round_trip() {
+            for state in [
+                JobState::Queued, JobState::Importing, JobState::Extracting, JobState::Structuring,
+                JobState::PendingReview, JobState::Done, JobState::Failed, JobState::Cancelled,
+            ] {
+                assert_eq!(JobState::parse(state.as_str()), Some(state));
+            }
+            assert_eq!(JobState::parse("bogus"), None);
+        }
+
+        #[test]
+        fn persisted_queue_survives_restart() {
+            let conn = open_memory_database().unwrap();
+            let svc = service();
+            let id = svc.enqueue("/tmp/a.pdf".into(), Some("lab_report".into()));
+            let group = svc.enqueue_group(vec!["/tmp/p1.jpg".into(), "/tmp/p2.jpg".into()], None).unwrap();
+            assert!(svc.has_unsaved_changes());
+            assert_eq!(svc.persist(&conn).unwrap(), 2);
+            assert!(!svc.has_unsaved_changes());
+
+            let restarted = service();
+            assert_eq!(restarted.hydrate_from_db(&conn, Uuid::new_v4()).unwrap(), 2);
+            let jobs = restarted.snapshot().jobs;
+            assert_eq!(jobs[0].id, id);
+            assert_eq!(jobs[0].state, JobState::Queued);
+            assert_eq!(jobs[0].user_document_type.as_deref(), Some("lab_report"));
+            assert_eq!(jobs[1].id, group);
+            assert_eq!(jobs[1].page_paths.len(), 2);
+        }
+
+        #[test]
+        fn pdf_password_is_not_persisted() {
+            let conn = open_memory_database().unwrap();
+            let svc = service();
+            svc.enqueue_with_password("/tmp/lab.pdf".into(), None, Some("15061990".into()));
+            svc.persist(&conn).unwrap();
+
+            let restarted = service();
+            restarted.hydrate_from_db(&conn, Uuid::new_v4()).unwrap();
+            assert_eq!(restarted.snapshot().jobs[0].pdf_password, None);
+        }
+
+        #[test]
+        fn interrupted_job_resumes_after_import() {
+            let conn = open_memory_database().unwrap();
+            let doc_id = insert_doc(&conn, "/profiles/x/originals/a.pdf.enc", PipelineStatus::Extracting);
+            let svc = service();
+            let id = svc.enqueue("/tmp/a.pdf".into(), None);
+            svc.next_queued().unwrap();
+            svc.update_job_state(&id, JobState::Extracting, Some(10), Some(doc_id.to_string()), None, None).unwrap();
+            svc.persist(&conn).unwrap();
+
+            let restarted = service();
+            assert_eq!(restarted.hydrate_from_db(&conn, Uuid::new_v4()).unwrap(), 1);
+            let jobs = restarted.snapshot().jobs;
+            // Resumed as a recovery job; the document is not enqueued twice
+            assert_eq!(jobs.len(), 1);
+            assert_eq!(jobs[0].id, id);
+            assert_eq!(jobs[0].state, JobState::Queued);
+            assert_eq!(jobs[0].progress_pct, 0);
+            assert_eq!(jobs[0].document_id.as_deref(), Some(doc_id.to_string().as_str()));
+        }
+
+        #[test]
+        fn interrupted_job_before_import_restarts() {
+            let conn = open_memory_database().unwrap();
+            let svc = service();
+            let id = svc.enqueue("/tmp/a.pdf".into(), None);
+            svc.next_queued().unwrap();
+            svc.persist(&conn).unwrap();
+
+            let restarted = service();
+            restarted.hydrate_from_db(&conn, Uuid::new_v4()).unwrap();
+            let job = restarted.get_job(&id).unwrap();
+            assert_eq!(job.state, JobState::Queued);
+            assert!(job.document_id.is_none());
+            assert!(job.started_at.is_none());
+        }
+
+        #[test]
+        fn hydrate_settles_jobs_from_document_status() {
+            let conn = open_memory_database().unwrap();
+            let reviewed = insert_doc(&conn, "/tmp/r.pdf.enc", PipelineStatus::PendingReview);
+            let failed = insert_doc(&conn, "/tmp/f.pdf.enc", PipelineStatus::Failed);
+            let svc = service();
+            for doc_id in [reviewed, failed, Uuid::new_v4()] {
+                let id = svc.enqueue("/tmp/x.pdf".into(), None);
+                svc.next_queued().unwrap();
+                svc.update_job_state(&id, JobState::Extracting, None, Some(doc_id.to_string()), None, None).unwrap();
+            }
+            svc.persist(&conn).unwrap();
+
+            let restarted = service();
+            assert_eq!(restarted.hydrate_from_db(&conn, Uuid::new_v4()).unwrap(), 0);
+            let jobs = restarted.snapshot().jobs;
+            // Reviewed document's job is done (pruned); the others failed
+            assert_eq!(jobs.len(), 2);
+            assert!(jobs.iter().all(|j| j.state == JobState::Failed && j.error.is_some()));
+        }
+
+        #[test]
+        fn hydrate_prunes_finished_and_keeps_failed() {
+            let conn = open_memory_database().unwrap();
+            let svc = service();
+            let done = svc.enqueue("/tmp/done.pdf".into(), None);
+            let failed = svc.enqueue("/tmp/failed.pdf".into(), None);
+            let cancelled = svc.enqueue("/tmp/cancelled.pdf".into(), None);
+            svc.next_queued().unwrap();
+            for state in [JobState::Extracting, JobState::Structuring, JobState::Done] {
+                svc.update_job_state(&done, state, None, None, None, None).unwrap();
+            }
+            svc.update_job_state(&failed, JobState::Failed, None, None, None, Some("bad".into())).unwrap();
+            svc.cancel(&cancelled).unwrap();
+            svc.persist(&conn).unwrap();
+
+            let restarted = service();
+            restarted.hydrate_from_db(&conn, Uuid::new_v4()).unwrap();
+            let jobs = restarted.snapshot().jobs;
+            assert_eq!(jobs.len(), 1);
+            assert_eq!(jobs[0].id, failed);
+            assert_eq!(jobs[0].error.as_deref(), Some("bad"));
+
+            // Pruned rows are deleted on the next persist
+            restarted.persist(&conn).unwrap();
+            assert_eq!(repository::list_import_jobs(&conn).unwrap().len(), 1);
+        }
+
+        #[test]
+        fn deleted_job_is_removed_from_db() {
+            let conn = open_memory_database().unwrap();
+            let svc = service();
+            let id = svc.enqueue("/tmp/a.pdf".into(), None);
+            svc.cancel(&id).unwrap();
+            svc.persist(&conn).unwrap();
+            svc.delete(&id).unwrap();
+            svc.persist(&conn).unwrap();
+            assert!(repository::list_import_jobs(&conn).unwrap().is_empty());
+        }
+
+        #[test]
+        fn hydrate_runs_once_per_profile() {
+            let conn = open_memory_database().unwrap();
+            let svc = service();
+            svc.enqueue("/tmp/a.pdf".into(), None);
+            svc.persist(&conn).unwrap();
+
+            let restarted = service();
+            let profile = Uuid::new_v4();
+            assert!(!restarted.is_hydrated_for(&profile));
+            restarted.hydrate_from_db(&conn, profile).unwrap();
+            assert!(restarted.is_hydrated_for(&profile));
+            assert_eq!(restarted.hydrate_from_db(&conn, profile).unwrap(), 0);
+            assert_eq!(restarted.snapshot().jobs.len(), 1);
+
+            // Switching profile drops the previous profile's jobs
+            let other = open_memory_database().unwrap();
+            restarted.hydrate_from_db(&other, Uuid::new_v4()).unwrap();
+            assert!(restarted.snapshot().jobs.is_empty());
+        }
     }
 
     // -- Update job progress (§21 Fix B) --
@@ -1044,4 +1618,32 @@ mod tests {
         let snap = svc.snapshot();
         assert_eq!(snap.jobs[0].user_document_type.as_deref(), Some("medical_image"));
     }
+
+    // -- PWD-01: Protected PDF passwords --
+
+    #[test]
+    fn pdf_password_is_never_serialized() {
+        let svc = service();
+        let id = svc.enqueue_with_password("/tmp/lab.pdf".into(), None, Some("15061990".into()));
+        assert_eq!(svc.get_job(&id).unwrap().pdf_password.as_deref(), Some("15061990"));
+
+        let json = serde_json::to_string(&svc.snapshot()).unwrap();
+        assert!(!json.contains("15061990"));
+        assert!(!json.contains("pdf_password"));
+    }
+
+    #[test]
+    fn retry_keeps_or_replaces_pdf_password() {
+        let svc = service();
+        let id = svc.enqueue_with_password("/tmp/lab.pdf".into(), None, Some("old".into()));
+        svc.next_queued();
+        svc.update_job_state(&id, JobState::Failed, None, None, None, Some("error".into())).unwrap();
+        let kept = svc.retry(&id).unwrap();
+        assert_eq!(svc.get_job(&kept).unwrap().pdf_password.as_deref(), Some("old"));
+
+        svc.next_queued();
+        svc.update_job_state(&kept, JobState::Failed, None, None, None, Some("error".into())).unwrap();
+        let replaced = svc.retry_with_password(&kept, Some("new".into())).unwrap();
+        assert_eq!(svc.get_job(&replaced).unwrap().pdf_password.as_deref(), Some("new"));
+    }
 }
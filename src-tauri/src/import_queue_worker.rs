@@ -42,9 +42,13 @@ pub struct ImportQueueEvent {
 ///
 /// Call from Tauri `setup`. The task runs for the lifetime of the app.
 /// It awaits `ImportQueueService::notifier()` for new jobs.
+///
+/// IMQ-01: The queue is re-hydrated from the profile DB on launch and on the
+/// first wake-up after each unlock (`unlock_profile` notifies the worker).
 pub fn start_import_queue_worker(app_handle: AppHandle) {
     tauri::async_runtime::spawn(async move {
         tracing::info!("Import queue worker started");
+        hydrate_queue(&app_handle).await;
         worker_loop(&app_handle).await;
     });
 }
@@ -54,12 +58,15 @@ async fn worker_loop(app: &AppHandle) {
     let queue = state.import_queue();
 
     loop {
-        // Wait for notification (enqueue/retry calls notify_one)
+        // Wait for notification (enqueue/retry/unlock calls notify_one)
         queue.notifier().notified().await;
 
+        hydrate_queue(app).await;
+
         // Drain all queued jobs (sequential — Ollama serves one request at a time)
         while let Some(job) = queue.next_queued() {
             queue.set_running(true);
+            persist_queue(&state);
             emit_job_snapshot(app, &job);
             process_job(app, job).await;
         }
@@ -89,11 +96,98 @@ fn emit_job_snapshot(app: &AppHandle, job: &ImportJob) {
 
 /// Re-read a job from the queue and emit its current state.
 fn emit_current_state(app: &AppHandle, state: &CoreState, job_id: &str) {
+    persist_queue(state);
     if let Some(job) = state.import_queue().get_job(job_id) {
         emit_job_snapshot(app, &job);
     }
 }
 
+/// IMQ-01: Write pending queue changes to the active profile's DB.
+///
+/// Cheap when nothing changed (progress-only updates are not persisted).
+/// Without an active session the changes stay pending until the next call.
+pub fn persist_queue(state: &CoreState) {
+    let queue = state.import_queue();
+    if !queue.has_unsaved_changes() {
+        return;
+    }
+    let conn = match state.open_db() {
+        Ok(conn) => conn,
+        Err(e) => {
+            tracing::debug!(error = %e, "Import queue not persisted: no profile database");
+            return;
+        }
+    };
+    if let Err(e) = queue.persist(&conn) {
+        tracing::warn!(error = %e, "Failed to persist import queue");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hydration (IMQ-01)
+// ---------------------------------------------------------------------------
+
+/// Restore the active profile's persisted queue, once per unlock.
+///
+/// Before loading jobs, reconciles the profile's staging areas: WiFi
+/// transfers left in `wifi_staging/` by a crash are imported (their documents
+/// are then resumed like any interrupted import), and encrypted originals no
+/// document refers to are securely deleted.
+async fn hydrate_queue(app: &AppHandle) {
+    let app = app.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || -> Result<(), String> {
+        let state: tauri::State<'_, Arc<CoreState>> = app.state();
+        let queue = state.import_queue();
+
+        let profile_id = {
+            let guard = state.read_session().map_err(|e| e.to_string())?;
+            match guard.as_ref() {
+                Some(session) => session.profile_id,
+                None => return Ok(()),
+            }
+        };
+        if queue.is_hydrated_for(&profile_id) {
+            return Ok(());
+        }
+
+        match crate::commands::transfer::import_staged_transfers(&state) {
+            Ok(0) => {}
+            Ok(count) => tracing::info!(count, "Imported WiFi transfers left by a previous session"),
+            Err(e) => tracing::warn!(error = %e, "Could not import pending WiFi transfers"),
+        }
+
+        let conn = state.open_db().map_err(|e| e.to_string())?;
+        {
+            let guard = state.read_session().map_err(|e| e.to_string())?;
+            if let Some(session) = guard.as_ref() {
+                match crate::pipeline::import::staging::remove_orphaned_originals(session, &conn) {
+                    Ok(0) => {}
+                    Ok(count) => tracing::info!(count, "Removed orphaned staged originals"),
+                    Err(e) => tracing::warn!(error = %e, "Could not reconcile staged originals"),
+                }
+            }
+        }
+
+        let resumed = queue.hydrate_from_db(&conn, profile_id)?;
+        queue.persist(&conn)?;
+
+        for job in queue.snapshot().jobs {
+            emit_job_snapshot(&app, &job);
+        }
+        if resumed > 0 {
+            tracing::info!(resumed, "Import queue restored from previous session");
+        }
+        Ok(())
+    })
+    .await;
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => tracing::warn!(error = %e, "Failed to restore import queue"),
+        Err(e) => tracing::warn!(error = %e, "Import queue hydration task failed"),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Job processing
 // ---------------------------------------------------------------------------
@@ -110,6 +204,8 @@ async fn process_job(app: &AppHandle, job: ImportJob) {
     let filename = job.filename.clone();
     let is_recovery = job.document_id.is_some();
     let user_document_type = job.user_document_type.clone();
+    let page_paths = job.page_paths.clone();
+    let pdf_password = job.pdf_password.clone();
 
     // §21 Fix C: Create cancellation token before blocking work
     let state: tauri::State<'_, Arc<CoreState>> = app.state();
@@ -120,7 +216,7 @@ async fn process_job(app: &AppHandle, job: ImportJob) {
         if is_recovery {
             process_recovery_job(&app_clone, &job_id, &file_path, &filename, cancel_token_clone, user_document_type.as_deref())
         } else {
-            process_fresh_job(&app_clone, &job_id, &file_path, &filename, cancel_token_clone, user_document_type.as_deref())
+            process_fresh_job(&app_clone, &job_id, &file_path, &page_paths, &filename, cancel_token_clone, user_document_type.as_deref(), pdf_password.as_deref())
         }
     })
     .await;
@@ -170,23 +266,31 @@ async fn process_job(app: &AppHandle, job: ImportJob) {
 /// Process a fresh import job (no existing document — full pipeline).
 ///
 /// UC-01: `user_document_type` bypasses LLM classification when provided.
+/// MPA-01: A job with several `page_paths` imports them as one document.
+/// PWD-01: `pdf_password` is tried first if the file is a protected PDF.
+#[allow(clippy::too_many_arguments)]
 fn process_fresh_job(
     app: &AppHandle,
     job_id: &str,
     file_path: &str,
+    page_paths: &[String],
     filename: &str,
     cancel_token: Arc<AtomicBool>,
     user_document_type: Option<&str>,
+    pdf_password: Option<&str>,
 ) -> Result<(), String> {
     let state: tauri::State<'_, Arc<CoreState>> = app.state();
     let queue = state.import_queue();
 
     let path = std::path::Path::new(file_path);
-    if !path.exists() {
-        return Err(format!("File not found: {file_path}"));
-    }
-    if !path.is_file() {
-        return Err("Path is not a regular file".into());
+    for page_path in page_paths.iter().map(String::as_str).chain(std::iter::once(file_path)) {
+        let page = std::path::Path::new(page_path);
+        if !page.exists() {
+            return Err(format!("File not found: {page_path}"));
+        }
+        if !page.is_file() {
+            return Err("Path is not a regular file".into());
+        }
     }
 
     // Acquire session + DB
@@ -197,6 +301,14 @@ fn process_fresh_job(
     let conn = crate::db::sqlite::open_database(session.db_path(), Some(session.key_bytes()))
         .map_err(|e| format!("Database error: {e}"))?;
 
+    // EML-01: An email is split into documents, each queued as its own job
+    let is_email = page_paths.len() <= 1
+        && crate::pipeline::import::detect_format(path)
+            .is_ok_and(|f| crate::pipeline::import::email::is_email_mime(&f.mime_type));
+    if is_email {
+        return process_email_job(app, &state, &conn, session, job_id, path, filename);
+    }
+
     // Resolve pipeline assignment
     let (assignment, mut ollama, pipeline_config) =
         resolve_pipeline_setup(&conn, &state, path)?;
@@ -251,14 +363,21 @@ fn process_fresh_job(
     // §21 Fix C: Pass cancellation token to processor
     processor.set_cancellation_token(cancel_token);
 
+    // PWD-01: Candidate passwords in case the file is a protected PDF
+    processor.set_pdf_passwords(pdf_password_candidates(&state, session, &conn, pdf_password));
+
     // §22: Work-based progress tracker (maps processor stages + page counters → events)
     let tracker = Arc::new(ProgressTracker::new(STAGE_IMPORTING));
     processor.set_progress_tracker(tracker.clone());
     let _watcher = StageWatcher::start(app, &state, job_id, filename, tracker);
 
     // Run the full pipeline
-    let output = processor
-        .process_file(path, session, &conn)
+    let output = if page_paths.len() > 1 {
+        let paths: Vec<std::path::PathBuf> = page_paths.iter().map(std::path::PathBuf::from).collect();
+        processor.process_file_group(&paths, session, &conn)
+    } else {
+        processor.process_file(path, session, &conn)
+    }
         .map_err(|e| {
             let patient_err = e.to_patient_error();
             format!("{}: {}", patient_err.title, patient_err.message)
@@ -287,6 +406,84 @@ fn process_fresh_job(
     Ok(())
 }
 
+/// EML-01: Import an email's attachments and clinical body, and queue each
+/// new document for processing. The email job itself completes here.
+fn process_email_job(
+    app: &AppHandle,
+    state: &CoreState,
+    conn: &rusqlite::Connection,
+    session: &crate::crypto::ProfileSession,
+    job_id: &str,
+    path: &std::path::Path,
+    filename: &str,
+) -> Result<(), String> {
+    use crate::pipeline::import::ImportStatus;
+
+    let outcome = crate::pipeline::import::email::import_email(path, session, conn)
+        .map_err(|e| format!("Email import failed: {e}"))?;
+    if outcome.documents.is_empty() {
+        if !outcome.failed_parts.is_empty() {
+            return Err(format!("Email parts could not be imported: {}", outcome.failed_parts.join("; ")));
+        }
+        return Err("No PDF or image attachments or clinical text found in this email".into());
+    }
+
+    let queue = state.import_queue();
+    let mut queued = 0;
+    for document in &outcome.documents {
+        if document.status == ImportStatus::Staged {
+            queue.enqueue_document(
+                document.document_id.to_string(),
+                document.staged_path.clone(),
+                document.original_filename.clone(),
+            );
+            queued += 1;
+        }
+    }
+
+    let _ = queue.update_job_state(job_id, JobState::Done, Some(100), None, None, None);
+    emit_current_state(app, state, job_id);
+
+    state.log_access(
+        crate::core_state::AccessSource::DesktopUi,
+        "import_queue_email",
+        &format!("documents:{queued}"),
+    );
+    state.update_activity();
+
+    tracing::info!(
+        job_id = %job_id,
+        file = %filename,
+        messages = outcome.messages,
+        queued = queued,
+        duplicates = outcome.documents.len() - queued,
+        failed = outcome.failed_parts.len(),
+        "Queue worker: email split into documents"
+    );
+    Ok(())
+}
+
+/// PWD-01: Job password, then the profile's saved PDF passwords, then
+/// birthdate patterns from the profile's date of birth.
+fn pdf_password_candidates(
+    state: &CoreState,
+    session: &crate::crypto::ProfileSession,
+    conn: &rusqlite::Connection,
+    job_password: Option<&str>,
+) -> Vec<String> {
+    use crate::pipeline::import::pdf_password;
+
+    let saved = pdf_password::load_saved_passwords(conn).unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Could not load saved PDF passwords");
+        Vec::new()
+    });
+    let date_of_birth = crate::crypto::profile::list_profiles(&state.profiles_dir)
+        .ok()
+        .and_then(|profiles| profiles.into_iter().find(|p| p.id == session.profile_id))
+        .and_then(|info| info.date_of_birth);
+    pdf_password::candidate_passwords(job_password, &saved, date_of_birth)
+}
+
 /// Process a recovery job (document exists in DB — reprocess only).
 ///
 /// UC-01: `user_document_type` may be None for recovery jobs — falls back to LLM classifier.
@@ -325,8 +522,9 @@ fn process_recovery_job(
 
     // §21 Fix A: Re-detect format from staged file (magic bytes, not extension).
     // This ensures recovery jobs use the correct extraction strategy (e.g. VisionOCR for PDFs).
+    // Staged files are encrypted — detection runs on the decrypted content.
     let staged_path = std::path::Path::new(&doc.source_file);
-    let format = crate::pipeline::import::format::detect_format(staged_path)
+    let format = crate::pipeline::import::staging::detect_staged_format(staged_path, session)
         .unwrap_or_else(|e| {
             tracing::warn!(
                 document_id = %doc_id,
@@ -702,11 +900,14 @@ impl StageWatcher {
                             if let Some(job) = queue.get_job(&watcher_job_id) {
                                 if job.state != new_state {
                                     let (min_pct, _) = stage_pct_range(current);
+                                    // IMQ-01: Persisted with the stage so a crash
+                                    // after import resumes from extraction
+                                    let document_id = tracker.document_id().map(|id| id.to_string());
                                     let _ = queue.update_job_state(
                                         &watcher_job_id,
                                         new_state,
                                         Some(min_pct),
-                                        None,
+                                        document_id,
                                         None,
                                         None,
                                     );
@@ -0,0 +1,743 @@
+//! INB-01: Watched "inbox" folder for automatic import.
+//!
+//! Scanners and phone sync apps drop PDFs and photos into a folder. While the
+//! profile is unlocked, the watcher polls the profile's configured inbox,
+//! waits until each new file has stopped changing, skips files whose hash is
+//! already in the profile, and enqueues the rest into the import queue.
+//! Only an exact content match is disposed of as a duplicate: photos are
+//! matched by perceptual hash, so a look-alike photo stays in the inbox.
+//! Once a file has been imported (the queue job owns a document), it is moved
+//! to `<inbox>/imported/` or securely deleted, per the profile setting.
+//! Files are tracked by path, size and modification time, so a new scan saved
+//! under a reused name (`scan.pdf`) is imported like any other new file.
+//!
+//! The configuration lives in the profile's encrypted database
+//! (`user_preferences`). The activity log is in memory and cleared on lock.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use uuid::Uuid;
+
+use crate::core_state::CoreState;
+use crate::db::repository;
+use crate::import_queue::{ImportQueueService, JobState};
+use crate::pipeline::import::format::{detect_format, FileCategory};
+use crate::pipeline::import::hash::compute_hash;
+use crate::pipeline::import::ImportError;
+
+/// `user_preferences` key holding the inbox configuration (JSON).
+const INBOX_CONFIG_KEY: &str = "inbox_folder";
+
+/// Subfolder of the inbox that imported files are moved to.
+pub const IMPORTED_SUBDIR: &str = "imported";
+
+/// Seconds between two scans of the inbox.
+const POLL_INTERVAL_SECS: u64 = 5;
+
+/// Entries kept in the activity log (oldest dropped first).
+const MAX_ACTIVITY_ENTRIES: usize = 200;
+
+/// Suffixes of files still being written by browsers and sync apps.
+const PARTIAL_SUFFIXES: &[&str] = &[".part", ".partial", ".crdownload", ".download", ".tmp"];
+
+/// What happens to an inbox file once it is in the profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InboxDisposal {
+    /// Move to `<inbox>/imported/`.
+    Move,
+    /// Overwrite and delete (`crypto::secure_delete_file`).
+    SecureDelete,
+}
+
+/// Per-profile inbox configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InboxConfig {
+    pub enabled: bool,
+    /// Absolute path of the watched folder.
+    pub folder: Option<String>,
+    pub after_import: InboxDisposal,
+}
+
+impl Default for InboxConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            folder: None,
+            after_import: InboxDisposal::Move,
+        }
+    }
+}
+
+impl InboxConfig {
+    /// The folder to watch, when watching is switched on.
+    fn active_folder(&self) -> Option<PathBuf> {
+        self.folder
+            .as_deref()
+            .filter(|_| self.enabled)
+            .map(PathBuf::from)
+    }
+}
+
+/// Load the profile's inbox configuration (default: disabled).
+pub fn load_config(conn: &Connection) -> Result<InboxConfig, ImportError> {
+    let Some(raw) = repository::get_user_preference(conn, INBOX_CONFIG_KEY)? else {
+        return Ok(InboxConfig::default());
+    };
+    serde_json::from_str(&raw)
+        .map_err(|e| ImportError::FileReadError(format!("Inbox configuration is corrupt: {e}")))
+}
+
+/// Validate and save the profile's inbox configuration.
+///
+/// An enabled inbox needs an existing, absolute directory outside the
+/// profiles directory (watching the vault itself would re-import staged files).
+pub fn save_config(
+    conn: &Connection,
+    config: &InboxConfig,
+    profiles_dir: &Path,
+) -> Result<InboxConfig, ImportError> {
+    let mut config = config.clone();
+    config.folder = config
+        .folder
+        .map(|f| f.trim().to_string())
+        .filter(|f| !f.is_empty());
+
+    if let Some(ref folder) = config.folder {
+        let path = Path::new(folder);
+        if !path.is_absolute() {
+            return Err(ImportError::FileReadError("Inbox folder must be an absolute path".into()));
+        }
+        if config.enabled && !path.is_dir() {
+            return Err(ImportError::FileReadError(format!("Inbox folder not found: {folder}")));
+        }
+        let inside_vault = match (path.canonicalize(), profiles_dir.canonicalize()) {
+            (Ok(p), Ok(vault)) => p.starts_with(vault),
+            _ => path.starts_with(profiles_dir),
+        };
+        if inside_vault {
+            return Err(ImportError::FileReadError(
+                "Inbox folder cannot be inside the Coheara profiles directory".into(),
+            ));
+        }
+    } else if config.enabled {
+        return Err(ImportError::FileReadError("Choose an inbox folder first".into()));
+    }
+
+    let json = serde_json::to_string(&config).map_err(|e| ImportError::FileReadError(e.to_string()))?;
+    repository::set_user_preference(conn, INBOX_CONFIG_KEY, &json)?;
+    Ok(config)
+}
+
+/// Kind of inbox activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InboxAction {
+    /// New file enqueued for import.
+    Queued,
+    /// Hash already in the profile — not imported again.
+    Duplicate,
+    /// Photo that looks like one already in the profile — left in place.
+    PossibleDuplicate,
+    /// Moved to the `imported` subfolder.
+    Moved,
+    /// Securely deleted after import.
+    Deleted,
+    /// Not importable or import failed — file left in place.
+    Skipped,
+    /// Move/delete or read error.
+    Error,
+}
+
+/// One entry of the inbox activity log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboxActivity {
+    pub at: String,
+    pub filename: String,
+    pub action: InboxAction,
+    pub detail: Option<String>,
+}
+
+/// Size and modification time seen at the previous scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+/// Watcher state of the unlocked profile.
+#[derive(Default)]
+struct WatchState {
+    profile_id: Option<Uuid>,
+    /// Configuration cached from the DB (None until loaded).
+    config: Option<InboxConfig>,
+    /// Files seen at the last scan, not yet handled.
+    candidates: HashMap<PathBuf, FileStamp>,
+    /// Files queued, skipped or left in place this session, with the stamp
+    /// they had then. A file whose stamp changes is a new file.
+    handled: HashMap<PathBuf, FileStamp>,
+    /// Import jobs of inbox files: job ID → file and its stamp when queued.
+    jobs: HashMap<String, (PathBuf, FileStamp)>,
+    activity: VecDeque<InboxActivity>,
+}
+
+/// Inbox watcher for the active profile.
+pub struct InboxWatcher {
+    state: Mutex<WatchState>,
+}
+
+impl Default for InboxWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InboxWatcher {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(WatchState::default()),
+        }
+    }
+
+    /// Forget everything (profile locked or switched).
+    pub fn reset(&self) {
+        *self.state.lock().expect("inbox watcher lock poisoned") = WatchState::default();
+    }
+
+    /// Activity log, newest first.
+    pub fn activity(&self) -> Vec<InboxActivity> {
+        let state = self.state.lock().expect("inbox watcher lock poisoned");
+        state.activity.iter().rev().cloned().collect()
+    }
+
+    /// Replace the cached configuration after it was saved for `profile_id`.
+    pub fn set_config(&self, profile_id: Uuid, config: InboxConfig) {
+        let mut state = self.state.lock().expect("inbox watcher lock poisoned");
+        if state.profile_id != Some(profile_id) {
+            *state = WatchState {
+                profile_id: Some(profile_id),
+                ..WatchState::default()
+            };
+        }
+        state.candidates.clear();
+        state.config = Some(config);
+    }
+
+    /// One scan of the inbox for the unlocked profile.
+    ///
+    /// Disposes of files whose import finished, then enqueues new files that
+    /// have not changed since the previous scan. Returns the number enqueued.
+    pub fn poll(&self, profile_id: Uuid, conn: &Connection, queue: &ImportQueueService) -> usize {
+        let mut state = self.state.lock().expect("inbox watcher lock poisoned");
+        if state.profile_id != Some(profile_id) {
+            *state = WatchState {
+                profile_id: Some(profile_id),
+                ..WatchState::default()
+            };
+        }
+        if state.config.is_none() {
+            match load_config(conn) {
+                Ok(config) => state.config = Some(config),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Inbox: failed to load configuration");
+                    return 0;
+                }
+            }
+        }
+        let config = state.config.clone().unwrap_or_default();
+        let Some(folder) = config.active_folder() else {
+            return 0;
+        };
+
+        dispose_imported(&mut state, &folder, config.after_import, queue);
+
+        // Jobs still running for a path (e.g. queued before the app restarted)
+        let running: HashMap<PathBuf, String> = queue
+            .snapshot()
+            .jobs
+            .into_iter()
+            .filter(|job| !job.state.is_terminal() && job.state != JobState::PendingReview)
+            .map(|job| (PathBuf::from(job.file_path), job.id))
+            .collect();
+
+        let mut enqueued = 0;
+        for (path, stamp) in scan_stable(&mut state, &folder) {
+            if let Some(job_id) = running.get(&path) {
+                state.jobs.insert(job_id.clone(), (path.clone(), stamp));
+                state.handled.insert(path, stamp);
+                continue;
+            }
+            let filename = file_name(&path);
+            match check_duplicate(&path, conn) {
+                Ok(None) => {
+                    let job_id = queue.enqueue(path.to_string_lossy().into_owned(), None);
+                    tracing::info!(job_id = %job_id, "Inbox: file queued for import");
+                    log(&mut state, &filename, InboxAction::Queued, None);
+                    state.jobs.insert(job_id, (path.clone(), stamp));
+                    state.handled.insert(path, stamp);
+                    enqueued += 1;
+                }
+                Ok(Some(InboxDuplicate::Exact(existing))) => {
+                    log(&mut state, &filename, InboxAction::Duplicate, Some(format!("already imported as {existing}")));
+                    dispose(&mut state, &folder, &path, config.after_import);
+                }
+                Ok(Some(InboxDuplicate::Similar(existing))) => {
+                    log(
+                        &mut state,
+                        &filename,
+                        InboxAction::PossibleDuplicate,
+                        Some(format!("looks like {existing}; left in the inbox")),
+                    );
+                    state.handled.insert(path, stamp);
+                }
+                Err(e) => {
+                    log(&mut state, &filename, InboxAction::Skipped, Some(e.to_string()));
+                    state.handled.insert(path, stamp);
+                }
+            }
+        }
+        enqueued
+    }
+}
+
+/// Move or delete inbox files whose queue job has produced a document.
+/// Failed or cancelled jobs leave their file in place, as does a file that
+/// was replaced since it was queued (it is picked up as a new file).
+fn dispose_imported(
+    state: &mut WatchState,
+    folder: &Path,
+    disposal: InboxDisposal,
+    queue: &ImportQueueService,
+) {
+    if state.jobs.is_empty() {
+        return;
+    }
+    let jobs = queue.snapshot().jobs;
+    state.jobs.retain(|id, _| jobs.iter().any(|job| &job.id == id));
+
+    for job in jobs {
+        let Some((path, stamp)) = state.jobs.get(&job.id).cloned() else {
+            continue;
+        };
+        let imported = job.document_id.is_some()
+            || matches!(job.state, JobState::PendingReview | JobState::Done);
+        if imported {
+            state.jobs.remove(&job.id);
+            if file_stamp(&path) == Some(stamp) {
+                dispose(state, folder, &path, disposal);
+            }
+        } else if matches!(job.state, JobState::Failed | JobState::Cancelled) {
+            state.jobs.remove(&job.id);
+            log(state, &job.filename, InboxAction::Skipped, job.error.clone());
+        }
+    }
+}
+
+/// Current size and modification time of a file.
+fn file_stamp(path: &Path) -> Option<FileStamp> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some(FileStamp {
+        len: metadata.len(),
+        modified: metadata.modified().ok(),
+    })
+}
+
+/// Files directly in `folder` unchanged since the previous scan, with their
+/// stamp. Handled files are skipped until they change.
+fn scan_stable(state: &mut WatchState, folder: &Path) -> Vec<(PathBuf, FileStamp)> {
+    let Ok(entries) = std::fs::read_dir(folder) else {
+        return vec![];
+    };
+
+    let mut seen = HashMap::new();
+    let mut stable = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() || is_partial(&path) {
+            continue;
+        }
+        let stamp = FileStamp {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+        };
+        if state.handled.get(&path) == Some(&stamp) {
+            continue;
+        }
+        if stamp.len > 0 && state.candidates.get(&path) == Some(&stamp) {
+            stable.push((path, stamp));
+        } else {
+            seen.insert(path, stamp);
+        }
+    }
+    state.candidates = seen;
+    stable.sort_by(|a, b| a.0.cmp(&b.0));
+    stable
+}
+
+/// Hidden files and downloads still in progress.
+fn is_partial(path: &Path) -> bool {
+    let name = file_name(path).to_lowercase();
+    name.starts_with('.') || name.starts_with("~$") || PARTIAL_SUFFIXES.iter().any(|s| name.ends_with(s))
+}
+
+/// Document already in the profile that an inbox file matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InboxDuplicate {
+    /// Same SHA-256 content hash: safe to dispose of.
+    Exact(Uuid),
+    /// Same perceptual hash (photos): may be a different page or form.
+    Similar(Uuid),
+}
+
+/// Document with the same hash, if any.
+fn check_duplicate(path: &Path, conn: &Connection) -> Result<Option<InboxDuplicate>, ImportError> {
+    let format = detect_format(path)?;
+    if format.category == FileCategory::Unsupported {
+        return Err(ImportError::UnsupportedFormat(format.mime_type));
+    }
+    let hash = compute_hash(path, &format.category)?;
+    let perceptual = format.category == FileCategory::Image;
+    Ok(repository::get_document_by_hash(conn, &hash)?.map(|doc| {
+        if perceptual {
+            InboxDuplicate::Similar(doc.id)
+        } else {
+            InboxDuplicate::Exact(doc.id)
+        }
+    }))
+}
+
+/// Apply the disposal setting to an imported inbox file.
+fn dispose(state: &mut WatchState, folder: &Path, path: &Path, disposal: InboxDisposal) {
+    let filename = file_name(path);
+    let result = match disposal {
+        InboxDisposal::Move => move_to_imported(folder, path).map(|_| InboxAction::Moved),
+        InboxDisposal::SecureDelete => {
+            crate::crypto::secure_delete_file(path).map(|()| InboxAction::Deleted)
+        }
+    };
+    match result {
+        Ok(action) => {
+            // Gone from the inbox: a later file with this name is a new file
+            state.handled.remove(path);
+            log(state, &filename, action, None);
+        }
+        Err(e) => {
+            if let Some(stamp) = file_stamp(path) {
+                state.handled.insert(path.to_path_buf(), stamp);
+            }
+            tracing::warn!(error = %e, "Inbox: failed to clear imported file");
+            log(state, &filename, InboxAction::Error, Some(e.to_string()));
+        }
+    }
+}
+
+/// Move `path` into `<folder>/imported/`, never overwriting an earlier file.
+fn move_to_imported(folder: &Path, path: &Path) -> std::io::Result<PathBuf> {
+    let target_dir = folder.join(IMPORTED_SUBDIR);
+    std::fs::create_dir_all(&target_dir)?;
+
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("file");
+    let ext = path.extension().and_then(|e| e.to_str());
+    let mut target = target_dir.join(file_name(path));
+    let mut n = 1;
+    while target.exists() {
+        let name = match ext {
+            Some(ext) => format!("{stem} ({n}).{ext}"),
+            None => format!("{stem} ({n})"),
+        };
+        target = target_dir.join(name);
+        n += 1;
+    }
+    std::fs::rename(path, &target)?;
+    Ok(target)
+}
+
+fn log(state: &mut WatchState, filename: &str, action: InboxAction, detail: Option<String>) {
+    if state.activity.len() >= MAX_ACTIVITY_ENTRIES {
+        state.activity.pop_front();
+    }
+    state.activity.push_back(InboxActivity {
+        at: chrono::Utc::now().to_rfc3339(),
+        filename: filename.to_string(),
+        action,
+        detail,
+    });
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+// ---------------------------------------------------------------------------
+// Background poller
+// ---------------------------------------------------------------------------
+
+/// Start the inbox poller. Idle while no profile is unlocked.
+pub fn start_inbox_watcher(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        tracing::info!("Inbox watcher started");
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(POLL_INTERVAL_SECS)).await;
+            let state = app_handle.state::<Arc<CoreState>>().inner().clone();
+            let _ = tauri::async_runtime::spawn_blocking(move || tick(&state)).await;
+        }
+    });
+}
+
+/// One poll for the active profile; wakes the import worker on new jobs.
+///
+/// The session lock is held only to open the database: hashing inbox files
+/// must not block unlock, lock or profile switching.
+fn tick(state: &CoreState) {
+    let (profile_id, conn) = {
+        let Ok(guard) = state.read_session() else {
+            return;
+        };
+        let Some(session) = guard.as_ref() else {
+            state.inbox().reset();
+            return;
+        };
+        match crate::db::open_database(session.db_path(), Some(session.key_bytes())) {
+            Ok(conn) => (session.profile_id, conn),
+            Err(e) => {
+                tracing::warn!(error = %e, "Inbox: failed to open profile database");
+                return;
+            }
+        }
+    };
+    let enqueued = state.inbox().poll(profile_id, &conn, state.import_queue());
+
+    if enqueued > 0 {
+        crate::import_queue_worker::persist_queue(state);
+        state.import_queue().notifier().notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{profile, ProfileSession};
+    use crate::db::sqlite::open_database;
+
+    fn setup() -> (tempfile::TempDir, ProfileSession, Connection) {
+        let dir = tempfile::tempdir().unwrap();
+        let (info, _phrase) =
+            profile::create_profile(dir.path(), "InboxTest", "test_pass_123", None, None, None, None).unwrap();
+        let session = profile::open_profile(dir.path(), &info.id, "test_pass_123").unwrap();
+        let conn = open_database(session.db_path(), Some(session.key_bytes())).unwrap();
+        (dir, session, conn)
+    }
+
+    fn enable(watcher: &InboxWatcher, session: &ProfileSession, inbox: &Path, after_import: InboxDisposal) {
+        watcher.set_config(session.profile_id, InboxConfig {
+            enabled: true,
+            folder: Some(inbox.to_string_lossy().into_owned()),
+            after_import,
+        });
+    }
+
+    #[test]
+    fn config_round_trips_and_validates() {
+        let (dir, _session, conn) = setup();
+        let inbox = tempfile::tempdir().unwrap();
+
+        assert_eq!(load_config(&conn).unwrap(), InboxConfig::default());
+
+        let config = InboxConfig {
+            enabled: true,
+            folder: Some(inbox.path().to_string_lossy().into_owned()),
+            after_import: InboxDisposal::SecureDelete,
+        };
+        save_config(&conn, &config, dir.path()).unwrap();
+        assert_eq!(load_config(&conn).unwrap(), config);
+
+        let relative = InboxConfig { folder: Some("scans".into()), ..config.clone() };
+        assert!(save_config(&conn, &relative, dir.path()).is_err());
+
+        let in_vault = InboxConfig { folder: Some(dir.path().to_string_lossy().into_owned()), ..config };
+        assert!(save_config(&conn, &in_vault, dir.path()).is_err());
+    }
+
+    #[test]
+    fn file_is_queued_only_once_it_is_stable() {
+        let (_dir, session, conn) = setup();
+        let inbox = tempfile::tempdir().unwrap();
+        let queue = ImportQueueService::new();
+        let watcher = InboxWatcher::new();
+        enable(&watcher, &session, inbox.path(), InboxDisposal::Move);
+
+        std::fs::write(inbox.path().join("labs.txt"), "Hemoglobin 13.5 g/dL").unwrap();
+        std::fs::write(inbox.path().join("scan.pdf.part"), "partial").unwrap();
+
+        // First sighting: not yet known to be complete
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 0);
+        // Unchanged since the last scan → queued
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 1);
+        // Already handled → not queued again
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 0);
+
+        let jobs = queue.snapshot().jobs;
+        assert_eq!(jobs.len(), 1);
+        assert!(jobs[0].file_path.ends_with("labs.txt"));
+        assert_eq!(watcher.activity()[0].action, InboxAction::Queued);
+    }
+
+    #[test]
+    fn imported_file_is_moved_out_of_the_inbox() {
+        let (_dir, session, conn) = setup();
+        let inbox = tempfile::tempdir().unwrap();
+        let queue = ImportQueueService::new();
+        let watcher = InboxWatcher::new();
+        enable(&watcher, &session, inbox.path(), InboxDisposal::Move);
+
+        let file = inbox.path().join("labs.txt");
+        std::fs::write(&file, "Hemoglobin 13.5 g/dL").unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+        watcher.poll(session.profile_id, &conn, &queue);
+
+        let job = queue.next_queued().unwrap();
+        queue
+            .update_job_state(&job.id, JobState::Extracting, None, Some(Uuid::new_v4().to_string()), None, None)
+            .unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+
+        assert!(!file.exists());
+        assert!(inbox.path().join(IMPORTED_SUBDIR).join("labs.txt").exists());
+        assert_eq!(watcher.activity()[0].action, InboxAction::Moved);
+    }
+
+    #[test]
+    fn new_file_under_a_reused_name_is_imported_again() {
+        let (_dir, session, conn) = setup();
+        let inbox = tempfile::tempdir().unwrap();
+        let queue = ImportQueueService::new();
+        let watcher = InboxWatcher::new();
+        enable(&watcher, &session, inbox.path(), InboxDisposal::Move);
+
+        let file = inbox.path().join("scan.txt");
+        std::fs::write(&file, "Hemoglobin 13.5 g/dL").unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+        watcher.poll(session.profile_id, &conn, &queue);
+        let first = queue.next_queued().unwrap();
+        queue
+            .update_job_state(&first.id, JobState::Extracting, None, Some(Uuid::new_v4().to_string()), None, None)
+            .unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+        assert!(!file.exists());
+
+        // The scanner saves the next scan under the same name
+        std::fs::write(&file, "Ferritin 45 ng/mL").unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 1);
+        assert_eq!(queue.snapshot().jobs.len(), 2);
+    }
+
+    #[test]
+    fn file_replaced_while_queued_is_not_disposed_with_the_old_job() {
+        let (_dir, session, conn) = setup();
+        let inbox = tempfile::tempdir().unwrap();
+        let queue = ImportQueueService::new();
+        let watcher = InboxWatcher::new();
+        enable(&watcher, &session, inbox.path(), InboxDisposal::SecureDelete);
+
+        let file = inbox.path().join("scan.txt");
+        std::fs::write(&file, "Hemoglobin 13.5 g/dL").unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+        watcher.poll(session.profile_id, &conn, &queue);
+
+        std::fs::write(&file, "Ferritin 45 ng/mL, a different report").unwrap();
+        let job = queue.next_queued().unwrap();
+        queue
+            .update_job_state(&job.id, JobState::Extracting, None, Some(Uuid::new_v4().to_string()), None, None)
+            .unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+
+        assert!(file.exists(), "the replacement has not been imported yet");
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 1);
+    }
+
+    #[test]
+    fn duplicate_is_not_queued_and_is_securely_deleted() {
+        let (_dir, session, conn) = setup();
+        let source = tempfile::tempdir().unwrap();
+        let earlier = source.path().join("labs.txt");
+        std::fs::write(&earlier, "Hemoglobin 13.5 g/dL").unwrap();
+        crate::pipeline::import::importer::import_file(&earlier, &session, &conn).unwrap();
+
+        let inbox = tempfile::tempdir().unwrap();
+        let queue = ImportQueueService::new();
+        let watcher = InboxWatcher::new();
+        enable(&watcher, &session, inbox.path(), InboxDisposal::SecureDelete);
+
+        let file = inbox.path().join("labs-copy.txt");
+        std::fs::write(&file, "Hemoglobin 13.5 g/dL").unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 0);
+
+        assert!(queue.snapshot().jobs.is_empty());
+        assert!(!file.exists());
+        let actions: Vec<_> = watcher.activity().iter().map(|a| a.action).collect();
+        assert_eq!(actions, vec![InboxAction::Deleted, InboxAction::Duplicate]);
+    }
+
+    #[test]
+    fn look_alike_photo_is_left_in_the_inbox() {
+        let (_dir, session, conn) = setup();
+        let photo = |path: &Path| {
+            img_hash::image::RgbImage::from_fn(40, 60, |x, y| {
+                img_hash::image::Rgb([(x * 6) as u8, (y * 4) as u8, 90])
+            })
+            .save(path)
+            .unwrap();
+        };
+        let source = tempfile::tempdir().unwrap();
+        let earlier = source.path().join("form.png");
+        photo(&earlier);
+        crate::pipeline::import::importer::import_file(&earlier, &session, &conn).unwrap();
+
+        let inbox = tempfile::tempdir().unwrap();
+        let queue = ImportQueueService::new();
+        let watcher = InboxWatcher::new();
+        enable(&watcher, &session, inbox.path(), InboxDisposal::SecureDelete);
+
+        let file = inbox.path().join("form-2.png");
+        photo(&file);
+        watcher.poll(session.profile_id, &conn, &queue);
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 0);
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 0);
+
+        assert!(file.exists(), "a perceptual match is never deleted");
+        assert!(queue.snapshot().jobs.is_empty());
+        let actions: Vec<_> = watcher.activity().iter().map(|a| a.action).collect();
+        assert_eq!(actions, vec![InboxAction::PossibleDuplicate]);
+    }
+
+    #[test]
+    fn disabled_inbox_is_ignored() {
+        let (_dir, session, conn) = setup();
+        let inbox = tempfile::tempdir().unwrap();
+        let queue = ImportQueueService::new();
+        let watcher = InboxWatcher::new();
+        watcher.set_config(session.profile_id, InboxConfig {
+            enabled: false,
+            folder: Some(inbox.path().to_string_lossy().into_owned()),
+            after_import: InboxDisposal::Move,
+        });
+
+        std::fs::write(inbox.path().join("labs.txt"), "Hemoglobin 13.5 g/dL").unwrap();
+        watcher.poll(session.profile_id, &conn, &queue);
+        assert_eq!(watcher.poll(session.profile_id, &conn, &queue), 0);
+        assert!(queue.snapshot().jobs.is_empty());
+    }
+}
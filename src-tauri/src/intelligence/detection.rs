@@ -11,6 +11,7 @@ use super::helpers::{
     resolve_generic_name,
 };
 use super::messages::MessageTemplates;
+use super::reconciliation::{lab_match_key, same_unit, values_match};
 use super::reference::CoherenceReferenceData;
 use super::types::*;
 
@@ -1033,6 +1034,81 @@ pub fn detect_critical_labs(
     alerts
 }
 
+// ---------------------------------------------------------------------------
+// [12] LAB CONFLICT detection (REC-01)
+// ---------------------------------------------------------------------------
+
+/// Detect lab values that disagree across documents: same canonical analyte,
+/// collection date and unit, but values beyond the reconciliation tolerance.
+/// Matching values are duplicates and handled by `reconciliation`, not here.
+pub fn detect_lab_value_conflicts(
+    document_id: &Uuid,
+    data: &RepositorySnapshot,
+) -> Vec<CoherenceAlert> {
+    let mut alerts = Vec::new();
+
+    let new_labs: Vec<&_> = data
+        .lab_results
+        .iter()
+        .filter(|l| l.document_id == *document_id || document_id.is_nil())
+        .filter(|l| l.value.is_some())
+        .collect();
+
+    for new_lab in &new_labs {
+        let key = lab_match_key(new_lab);
+        let new_value = new_lab.value.unwrap_or_default();
+
+        for existing in data.lab_results.iter().filter(|l| {
+            l.id != new_lab.id
+                && l.document_id != new_lab.document_id
+                && same_unit(l.unit.as_deref(), new_lab.unit.as_deref())
+                && lab_match_key(l) == key
+        }) {
+            let Some(existing_value) = existing.value else {
+                continue;
+            };
+            if values_match(new_value, existing_value) {
+                continue;
+            }
+
+            let unit = new_lab.unit.clone().unwrap_or_default();
+            let message = MessageTemplates::lab_conflict(
+                &new_lab.test_name,
+                &new_lab.collection_date.to_string(),
+                &format!("{new_value} {unit}"),
+                &format!("{existing_value} {unit}"),
+            );
+
+            alerts.push(CoherenceAlert {
+                id: Uuid::new_v4(),
+                alert_type: AlertType::Conflict,
+                severity: AlertSeverity::Standard,
+                entity_ids: vec![new_lab.id, existing.id],
+                source_document_ids: vec![new_lab.document_id, existing.document_id],
+                patient_message: message,
+                detail: AlertDetail::LabConflict(LabConflictDetail {
+                    test_name: new_lab.test_name.clone(),
+                    collection_date: new_lab.collection_date,
+                    unit,
+                    value_a: new_value,
+                    lab_result_id_a: new_lab.id,
+                    document_id_a: new_lab.document_id,
+                    value_b: existing_value,
+                    lab_result_id_b: existing.id,
+                    document_id_b: existing.document_id,
+                }),
+                detected_at: chrono::Local::now().naive_local(),
+                surfaced: false,
+                dismissed: false,
+                dismissal: None,
+            });
+        }
+    }
+
+    dedup_symmetric_alerts(&mut alerts);
+    alerts
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1534,6 +1610,57 @@ mod tests {
         assert!(alerts.is_empty(), "Non-critical high should not trigger CRITICAL alert");
     }
 
+    fn make_lab(name: &str, value: f64, unit: &str, doc: Uuid) -> LabResult {
+        LabResult {
+            id: Uuid::new_v4(),
+            test_name: name.into(),
+            test_code: None,
+            value: Some(value),
+            value_text: None,
+            unit: Some(unit.into()),
+            reference_range_low: None,
+            reference_range_high: None,
+            abnormal_flag: AbnormalFlag::Normal,
+            collection_date: NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+            lab_facility: None,
+            ordering_physician_id: None,
+            document_id: doc,
+        }
+    }
+
+    /// REC-01: Same test and date, different value across documents -> CONFLICT.
+    #[test]
+    fn lab_value_conflict_across_documents() {
+        let (doc_a, doc_b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut data = empty_snapshot();
+        data.lab_results = vec![
+            make_lab("Potassium", 4.1, "mmol/L", doc_a),
+            make_lab("K", 5.1, "mmol/L", doc_b),
+        ];
+
+        let alerts = detect_lab_value_conflicts(&doc_b, &data);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].alert_type, AlertType::Conflict);
+        assert!(matches!(alerts[0].detail, AlertDetail::LabConflict(_)));
+
+        // Full scan reports the pair once
+        assert_eq!(detect_lab_value_conflicts(&Uuid::nil(), &data).len(), 1);
+    }
+
+    /// REC-01: Rounding differences and unit mismatches are not conflicts.
+    #[test]
+    fn lab_value_within_tolerance_or_other_unit_no_alert() {
+        let (doc_a, doc_b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut data = empty_snapshot();
+        data.lab_results = vec![
+            make_lab("Glucose", 5.43, "mmol/L", doc_a),
+            make_lab("Glucose", 5.4, "mmol/L", doc_b),
+            make_lab("Glucose", 98.0, "mg/dL", doc_b),
+        ];
+
+        assert!(detect_lab_value_conflicts(&Uuid::nil(), &data).is_empty());
+    }
+
     /// T-15: Penicillin allergy + amoxicillin prescribed -> CRITICAL alert.
     #[test]
     fn allergy_penicillin_amoxicillin() {
@@ -11,7 +11,7 @@ use crate::models::enums::{AlertType, DismissedBy};
 use super::detection::{
     detect_allergy_conflicts, detect_conflicts, detect_critical_labs,
     detect_daily_dose_accumulation, detect_dose_issues, detect_drift, detect_duplicates,
-    detect_gaps, detect_temporal,
+    detect_gaps, detect_lab_value_conflicts, detect_temporal,
 };
 use super::invariant_bridge;
 use super::emergency::EmergencyProtocol;
@@ -90,7 +90,8 @@ impl DefaultCoherenceEngine {
         document_id: &Uuid,
         data: &RepositorySnapshot,
     ) -> (Vec<CoherenceAlert>, AlertCounts) {
-        let conflicts = detect_conflicts(document_id, data, &self.reference);
+        let mut conflicts = detect_conflicts(document_id, data, &self.reference);
+        conflicts.extend(detect_lab_value_conflicts(document_id, data));
         let duplicates = detect_duplicates(document_id, data, &self.reference);
         let gaps = detect_gaps(document_id, data);
         let drifts = detect_drift(document_id, data, &self.reference);
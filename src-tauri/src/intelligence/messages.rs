@@ -112,6 +112,15 @@ impl MessageTemplates {
         )
     }
 
+    /// REC-01: LAB CONFLICT message (same test and date, different values).
+    pub fn lab_conflict(test: &str, date: &str, value_a: &str, value_b: &str) -> String {
+        format!(
+            "Two of your documents report different {} results for {}: {} and {}. \
+             You may want to ask which value is correct at your next appointment.",
+            test, date, value_a, value_b,
+        )
+    }
+
     /// Patient-reported data disclaimer (NC-08).
     pub fn patient_reported_note(symptom: &str) -> String {
         format!(
@@ -305,6 +314,22 @@ impl MessageTemplatesI18n {
         }
     }
 
+    pub fn lab_conflict(lang: &str, test: &str, date: &str, value_a: &str, value_b: &str) -> String {
+        match lang {
+            "fr" => format!(
+                "Deux de vos documents indiquent des résultats différents de {} pour le {} : {} et {}. \
+                 Vous pourriez demander quelle valeur est correcte lors de votre prochain rendez-vous.",
+                test, date, value_a, value_b,
+            ),
+            "de" => format!(
+                "Zwei Ihrer Dokumente nennen unterschiedliche {}-Werte für den {}: {} und {}. \
+                 Sie könnten bei Ihrem nächsten Termin fragen, welcher Wert korrekt ist.",
+                test, date, value_a, value_b,
+            ),
+            _ => MessageTemplates::lab_conflict(test, date, value_a, value_b),
+        }
+    }
+
     pub fn patient_reported_note(lang: &str, symptom: &str) -> String {
         match lang {
             "fr" => format!(
@@ -347,6 +372,7 @@ mod tests {
             MessageTemplates::dose("5000mg", "Metformin", "500mg", "2000mg"),
             MessageTemplates::daily_dose("Metformin", "3000mg", "2550mg"),
             MessageTemplates::critical_lab("2026-01-15", "Potassium"),
+            MessageTemplates::lab_conflict("Potassium", "2026-01-15", "4.1 mmol/L", "5.1 mmol/L"),
         ];
 
         for message in &messages {
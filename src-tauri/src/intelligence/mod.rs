@@ -5,5 +5,6 @@ pub mod messages;
 pub mod emergency;
 pub mod store;
 pub mod detection;
+pub mod reconciliation;
 pub mod engine;
 pub mod invariant_bridge;
@@ -0,0 +1,361 @@
+//! REC-01: Cross-document reconciliation of lab results and vital signs.
+//!
+//! The same panel often arrives twice — as the lab's own PDF and again in the
+//! GP's letter quoting it. Matching results are linked to a primary record via
+//! `duplicate_of` instead of being deleted, so provenance is preserved while
+//! trends, counts and alerts see each measurement once.
+//!
+//! Same analyte, same date, different value is *not* a duplicate: it is left
+//! untouched and surfaced by `detection::detect_lab_value_conflicts`.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use rusqlite::Connection;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::db::{repository, DatabaseError};
+use crate::invariants::lab_trends::series_key;
+use crate::models::{LabResult, VitalSign};
+
+/// Relative difference up to which two values are the same measurement
+/// (a letter quoting 5.4 for a lab-reported 5.43).
+pub const VALUE_TOLERANCE_PCT: f64 = 1.0;
+
+/// Readings of the same vital this close together are the same measurement.
+pub const VITAL_TIME_WINDOW_MINUTES: i64 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateLink {
+    pub duplicate_id: Uuid,
+    pub primary_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReconciliationSummary {
+    pub lab_duplicates: usize,
+    pub vital_duplicates: usize,
+}
+
+/// Two numeric values are equal within [`VALUE_TOLERANCE_PCT`].
+pub fn values_match(a: f64, b: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    let scale = a.abs().max(b.abs());
+    (a - b).abs() / scale * 100.0 <= VALUE_TOLERANCE_PCT
+}
+
+fn normalized_unit(unit: Option<&str>) -> Option<String> {
+    unit.map(|u| u.trim().to_lowercase()).filter(|u| !u.is_empty())
+}
+
+/// Same unit, or one side omits it (letters often quote bare values).
+fn units_compatible(a: Option<&str>, b: Option<&str>) -> bool {
+    match (normalized_unit(a), normalized_unit(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => true,
+    }
+}
+
+/// Same unit on both sides, both present.
+pub fn same_unit(a: Option<&str>, b: Option<&str>) -> bool {
+    matches!((normalized_unit(a), normalized_unit(b)), (Some(a), Some(b)) if a == b)
+}
+
+/// Canonical analyte + collection date: results that may describe one sample.
+pub fn lab_match_key(lab: &LabResult) -> (String, NaiveDate) {
+    (series_key(&lab.test_name), lab.collection_date)
+}
+
+/// Two results from the same sample with the same reported value.
+pub fn same_lab_measurement(a: &LabResult, b: &LabResult) -> bool {
+    if lab_match_key(a) != lab_match_key(b)
+        || !units_compatible(a.unit.as_deref(), b.unit.as_deref())
+    {
+        return false;
+    }
+    match (a.value, b.value) {
+        (Some(x), Some(y)) => values_match(x, y),
+        (None, None) => match (&a.value_text, &b.value_text) {
+            (Some(x), Some(y)) => x.trim().eq_ignore_ascii_case(y.trim()),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// More complete records make better primaries (lab PDF over a letter).
+fn lab_completeness(lab: &LabResult) -> usize {
+    [
+        lab.unit.is_some(),
+        lab.reference_range_low.is_some(),
+        lab.reference_range_high.is_some(),
+        lab.test_code.is_some(),
+        lab.lab_facility.is_some(),
+        lab.ordering_physician_id.is_some(),
+    ]
+    .iter()
+    .filter(|present| **present)
+    .count()
+}
+
+/// Link results from different documents that describe the same measurement.
+/// The most complete record of each match set becomes the primary.
+pub fn find_lab_duplicates(labs: &[LabResult]) -> Vec<DuplicateLink> {
+    let mut groups: HashMap<(String, NaiveDate), Vec<&LabResult>> = HashMap::new();
+    for lab in labs {
+        groups.entry(lab_match_key(lab)).or_default().push(lab);
+    }
+
+    let mut links = Vec::new();
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| {
+            lab_completeness(b)
+                .cmp(&lab_completeness(a))
+                .then(a.document_id.cmp(&b.document_id))
+                .then(a.id.cmp(&b.id))
+        });
+        let mut linked: HashSet<Uuid> = HashSet::new();
+        for (i, primary) in group.iter().enumerate() {
+            if linked.contains(&primary.id) {
+                continue;
+            }
+            for candidate in &group[i + 1..] {
+                if linked.contains(&candidate.id)
+                    || candidate.document_id == primary.document_id
+                    || !same_lab_measurement(primary, candidate)
+                {
+                    continue;
+                }
+                linked.insert(candidate.id);
+                links.push(DuplicateLink {
+                    duplicate_id: candidate.id,
+                    primary_id: primary.id,
+                });
+            }
+        }
+    }
+    links
+}
+
+fn same_vital_reading(a: &VitalSign, b: &VitalSign) -> bool {
+    let secondary_match = match (a.value_secondary, b.value_secondary) {
+        (Some(x), Some(y)) => values_match(x, y),
+        (None, None) => true,
+        _ => false,
+    };
+    a.vital_type == b.vital_type
+        && a.source != b.source
+        && (a.recorded_at - b.recorded_at).num_minutes().abs() <= VITAL_TIME_WINDOW_MINUTES
+        && units_compatible(Some(&a.unit), Some(&b.unit))
+        && values_match(a.value_primary, b.value_primary)
+        && secondary_match
+}
+
+/// Link readings of the same vital from different sources taken within
+/// [`VITAL_TIME_WINDOW_MINUTES`] with matching values (e.g. a manual entry
+/// later imported from a device). Dense same-source series are real repeated
+/// readings and never linked. The first-recorded entry stays primary.
+pub fn find_vital_duplicates(vitals: &[VitalSign]) -> Vec<DuplicateLink> {
+    let mut groups: HashMap<&'static str, Vec<&VitalSign>> = HashMap::new();
+    for vital in vitals {
+        groups.entry(vital.vital_type.as_str()).or_default().push(vital);
+    }
+
+    let mut links = Vec::new();
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        let mut linked: HashSet<Uuid> = HashSet::new();
+        for (i, primary) in group.iter().enumerate() {
+            if linked.contains(&primary.id) {
+                continue;
+            }
+            for candidate in &group[i + 1..] {
+                if linked.contains(&candidate.id) || !same_vital_reading(primary, candidate) {
+                    continue;
+                }
+                linked.insert(candidate.id);
+                links.push(DuplicateLink {
+                    duplicate_id: candidate.id,
+                    primary_id: primary.id,
+                });
+            }
+        }
+    }
+    links
+}
+
+/// Reconcile all primary lab results and vital signs in the profile database.
+/// Idempotent: already-linked duplicates are excluded from the read paths.
+/// Opens no transaction of its own so it can run inside the storage one.
+pub fn reconcile(conn: &Connection) -> Result<ReconciliationSummary, DatabaseError> {
+    let lab_links = find_lab_duplicates(&repository::get_all_lab_results(conn)?);
+    let vital_links = find_vital_duplicates(&repository::get_all_vital_signs(conn)?);
+
+    if lab_links.is_empty() && vital_links.is_empty() {
+        return Ok(ReconciliationSummary::default());
+    }
+
+    for link in &lab_links {
+        repository::link_lab_duplicate(conn, &link.duplicate_id, &link.primary_id)?;
+    }
+    for link in &vital_links {
+        repository::link_vital_duplicate(conn, &link.duplicate_id, &link.primary_id)?;
+    }
+
+    tracing::info!(
+        lab_duplicates = lab_links.len(),
+        vital_duplicates = vital_links.len(),
+        "Reconciled duplicate results"
+    );
+
+    Ok(ReconciliationSummary {
+        lab_duplicates: lab_links.len(),
+        vital_duplicates: vital_links.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::sqlite::open_memory_database;
+    use crate::models::enums::AbnormalFlag;
+    use crate::models::{VitalSource, VitalType};
+    use chrono::NaiveDateTime;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn lab(name: &str, value: f64, unit: Option<&str>, on: &str, doc: Uuid) -> LabResult {
+        LabResult {
+            id: Uuid::new_v4(),
+            test_name: name.into(),
+            test_code: None,
+            value: Some(value),
+            value_text: None,
+            unit: unit.map(Into::into),
+            reference_range_low: None,
+            reference_range_high: None,
+            abnormal_flag: AbnormalFlag::Normal,
+            collection_date: date(on),
+            lab_facility: None,
+            ordering_physician_id: None,
+            document_id: doc,
+        }
+    }
+
+    fn vital(value: f64, at: &str, created: &str, source: VitalSource) -> VitalSign {
+        VitalSign {
+            id: Uuid::new_v4(),
+            vital_type: VitalType::Weight,
+            value_primary: value,
+            value_secondary: None,
+            unit: "kg".into(),
+            recorded_at: datetime(at),
+            notes: None,
+            source,
+            created_at: datetime(created),
+        }
+    }
+
+    #[test]
+    fn values_match_within_rounding() {
+        assert!(values_match(5.43, 5.4));
+        assert!(!values_match(5.4, 5.6));
+        assert!(values_match(0.0, 0.0));
+    }
+
+    #[test]
+    fn letter_copy_links_to_complete_lab_report() {
+        let (pdf, letter) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut report = lab("Potassium", 4.62, Some("mmol/L"), "2026-01-10", pdf);
+        report.reference_range_low = Some(3.5);
+        report.reference_range_high = Some(5.0);
+        let quoted = lab("K+", 4.6, None, "2026-01-10", letter);
+
+        let links = find_lab_duplicates(&[quoted.clone(), report.clone()]);
+        assert_eq!(
+            links,
+            vec![DuplicateLink { duplicate_id: quoted.id, primary_id: report.id }]
+        );
+    }
+
+    #[test]
+    fn different_value_or_date_is_not_duplicate() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let labs = [
+            lab("Sodium", 140.0, Some("mmol/L"), "2026-01-10", a),
+            lab("Sodium", 134.0, Some("mmol/L"), "2026-01-10", b),
+            lab("Sodium", 140.0, Some("mmol/L"), "2026-01-11", b),
+        ];
+        assert!(find_lab_duplicates(&labs).is_empty());
+    }
+
+    #[test]
+    fn same_document_repeats_are_kept() {
+        let doc = Uuid::new_v4();
+        let labs = [
+            lab("Glucose", 5.5, Some("mmol/L"), "2026-01-10", doc),
+            lab("Glucose", 5.5, Some("mmol/L"), "2026-01-10", doc),
+        ];
+        assert!(find_lab_duplicates(&labs).is_empty());
+    }
+
+    #[test]
+    fn vital_reentered_after_import_links_to_first() {
+        let first = vital(72.4, "2026-01-10 08:00:00", "2026-01-10 08:01:00", VitalSource::Manual);
+        let imported = vital(72.4, "2026-01-10 08:03:00", "2026-01-12 20:00:00", VitalSource::Imported);
+        let later = vital(72.4, "2026-01-10 19:00:00", "2026-01-12 20:00:00", VitalSource::Imported);
+
+        let links = find_vital_duplicates(&[imported.clone(), first.clone(), later]);
+        assert_eq!(
+            links,
+            vec![DuplicateLink { duplicate_id: imported.id, primary_id: first.id }]
+        );
+    }
+
+    #[test]
+    fn reconcile_links_rows_and_hides_duplicates() {
+        let conn = open_memory_database().unwrap();
+        let (pdf, letter) = (Uuid::new_v4(), Uuid::new_v4());
+        for doc in [pdf, letter] {
+            conn.execute(
+                "INSERT INTO documents (id, type, title, source_file, ingestion_date, verified)
+                 VALUES (?1, 'lab_result', 'Lab', 'test.pdf', datetime('now'), 0)",
+                [doc.to_string()],
+            )
+            .unwrap();
+        }
+        let mut report = lab("HbA1c", 6.8, Some("%"), "2026-01-10", pdf);
+        report.test_code = Some("4548-4".into());
+        let quoted = lab("HbA1c", 6.8, Some("%"), "2026-01-10", letter);
+        repository::insert_lab_result(&conn, &report).unwrap();
+        repository::insert_lab_result(&conn, &quoted).unwrap();
+
+        let summary = reconcile(&conn).unwrap();
+        assert_eq!(summary.lab_duplicates, 1);
+
+        let remaining = repository::get_all_lab_results(&conn).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, report.id);
+
+        let linked: Option<String> = conn
+            .query_row(
+                "SELECT duplicate_of FROM lab_results WHERE id = ?1",
+                [quoted.id.to_string()],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(linked, Some(report.id.to_string()));
+
+        // Idempotent
+        assert_eq!(reconcile(&conn).unwrap(), ReconciliationSummary::default());
+    }
+}
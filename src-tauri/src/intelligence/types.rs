@@ -90,6 +90,8 @@ pub enum AlertDetail {
     Screening(ScreeningBridgeDetail),
     /// B2: Vital sign trend bridged from invariant engine.
     Trend(TrendBridgeDetail),
+    /// REC-01: Same test and date reported with different values.
+    LabConflict(LabConflictDetail),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -209,6 +211,20 @@ pub struct CriticalDetail {
     pub document_id: Uuid,
 }
 
+/// REC-01: Two documents report different values for the same test and date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabConflictDetail {
+    pub test_name: String,
+    pub collection_date: NaiveDate,
+    pub unit: String,
+    pub value_a: f64,
+    pub lab_result_id_a: Uuid,
+    pub document_id_a: Uuid,
+    pub value_b: f64,
+    pub lab_result_id_b: Uuid,
+    pub document_id_b: Uuid,
+}
+
 // ---------------------------------------------------------------------------
 // B2: Bridge detail types (ClinicalInsight → CoherenceAlert)
 // ---------------------------------------------------------------------------
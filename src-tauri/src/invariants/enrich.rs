@@ -15,10 +15,12 @@
 //! 7. `detect_screening_due` — Demographics → age+sex-gated screening schedules → ScreeningDue
 //! 8. `detect_vital_trends` — Multiple readings → temporal comparison → AbnormalTrend
 //! 9. `detect_food_cross_reactivity` — Allergy × allergen chains → OAS/food cross-reactivity
+//! 10. `growth::detect_percentile_crossings` — Child growth measurements → WHO/CDC LMS → AbnormalTrend
 
 use chrono::NaiveDate;
 
 use crate::crypto::profile::{BiologicalSex, PatientDemographics};
+use crate::invariants::growth;
 use crate::invariants::labs;
 use crate::invariants::types::{
     ClinicalInsight, InsightKind, InsightSeverity, InvariantLabel, MeaningFactors,
@@ -118,7 +120,16 @@ pub fn enrich(
 ) -> Vec<ClinicalInsight> {
     let mut insights = Vec::new();
 
-    insights.extend(classify_vitals(vital_signs, demographics));
+    // GRW-01: Children are charted against growth references instead —
+    // adult BMI tiers and GLIM weight-change thresholds do not apply.
+    let pediatric = growth::growth_profile(demographics, reference_date).is_some();
+    let adult_vitals: Vec<VitalSign> = vital_signs
+        .iter()
+        .filter(|v| !pediatric || v.vital_type != VitalType::Weight)
+        .cloned()
+        .collect();
+
+    insights.extend(classify_vitals(&adult_vitals, demographics));
     insights.extend(classify_labs(lab_results, registry, demographics));
     insights.extend(detect_interactions(medications, registry));
     insights.extend(detect_cross_reactivity(allergies, medications, registry));
@@ -130,7 +141,12 @@ pub fn enrich(
         reference_date,
     ));
     insights.extend(crate::invariants::screening::detect_screening_due(demographics));
-    insights.extend(detect_vital_trends(vital_signs));
+    insights.extend(detect_vital_trends(&adult_vitals));
+    insights.extend(growth::detect_percentile_crossings(
+        vital_signs,
+        demographics,
+        reference_date,
+    ));
     insights.extend(detect_food_cross_reactivity(allergies, registry));
     insights.extend(detect_rh_negative_awareness(demographics));
 
@@ -1012,7 +1028,7 @@ mod tests {
                 VitalType::OxygenSaturation => "%".to_string(),
                 VitalType::BloodGlucose => "mmol/L".to_string(),
                 VitalType::Weight => "kg".to_string(),
-                VitalType::Height => "cm".to_string(),
+                VitalType::Height | VitalType::HeadCircumference => "cm".to_string(),
             },
             recorded_at: NaiveDateTime::parse_from_str("2026-01-15 10:00:00", "%Y-%m-%d %H:%M:%S")
                 .unwrap(),
@@ -1745,6 +1761,7 @@ mod tests {
             age_context: None,
             age_years: None,
             blood_type: None,
+            date_of_birth: None,
         }
     }
 
@@ -2014,6 +2031,32 @@ mod tests {
         assert_eq!(insights.len(), 2);
     }
 
+    #[test]
+    fn child_weight_charted_on_growth_reference_not_glim() {
+        // Growth charts come from the official tables in resources/growth
+        let registry = loaded_registry();
+        let mut demo = make_demographics(Some(BiologicalSex::Male), vec![]);
+        demo.date_of_birth = NaiveDate::from_ymd_opt(2025, 1, 1);
+
+        // Normal infant growth: +22% would be a GLIM weight gain in an adult
+        let growing = vec![
+            make_vital_at(VitalType::Weight, 7.93, None, "2025-07-02"),
+            make_vital_at(VitalType::Weight, 9.65, None, "2026-01-01"),
+        ];
+        let insights = enrich(&[], &[], &[], &growing, &registry, today(), Some(&demo));
+        assert!(insights.iter().all(|i| i.kind != InsightKind::AbnormalTrend));
+
+        // Falling from the 50th to below the 10th percentile
+        let faltering = vec![
+            make_vital_at(VitalType::Weight, 7.93, None, "2025-07-02"),
+            make_vital_at(VitalType::Weight, 8.1, None, "2026-01-01"),
+        ];
+        let insights = enrich(&[], &[], &[], &faltering, &registry, today(), Some(&demo));
+        assert!(insights
+            .iter()
+            .any(|i| i.description.key == "growth_percentile_drop"));
+    }
+
     // ── Sub-algorithm 9: Food/environmental cross-reactivity ─
 
     #[test]
@@ -2094,6 +2137,7 @@ mod tests {
             age_context: None,
             age_years: age,
             blood_type,
+            date_of_birth: None,
         }
     }
 
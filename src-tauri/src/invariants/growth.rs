@@ -0,0 +1,919 @@
+//! GRW-01: Pediatric growth references — WHO 2006 / CDC 2000 LMS tables.
+//!
+//! Deterministic, no LLM involved. Each reference is a list of LMS
+//! parameters (Box-Cox power L, median M, coefficient of variation S) by
+//! age; a measurement X converts to a z-score with
+//!
+//! ```text
+//! z = ((X / M)^L − 1) / (L · S)      (L ≠ 0)
+//! z = ln(X / M) / S                  (L = 0)
+//! ```
+//!
+//! WHO Child Growth Standards are used up to 24 months (head circumference
+//! up to 60 months) and CDC 2000 references from 2 to 20 years, as the CDC
+//! and AAP recommend. The official monthly LMS tables are loaded verbatim
+//! from `resources/growth/` (sources in `SOURCES`, checksums in `SHA256SUMS`)
+//! and linearly interpolated between rows.
+//!
+//! Percentile crossings: crossing two or more major percentile lines
+//! (3rd, 10th, 25th, 50th, 75th, 90th, 97th) within a year is the usual
+//! trigger for a growth review (NICE NG75, AAP 2021).
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sha2::{Digest, Sha256};
+
+use crate::crypto::profile::{BiologicalSex, PatientDemographics};
+use crate::invariants::loader::LoadError;
+use crate::invariants::types::{
+    ClinicalInsight, InsightKind, InsightSeverity, InvariantLabel, MeaningFactors,
+};
+use crate::models::{GrowthPercentile, VitalSign, VitalTrendPoint, VitalType};
+
+// ═══════════════════════════════════════════════════════════
+// Indicators
+// ═══════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowthIndicator {
+    WeightForAge,
+    /// Recumbent length under 24 months, standing height afterwards.
+    LengthHeightForAge,
+    HeadCircumferenceForAge,
+    BmiForAge,
+}
+
+impl GrowthIndicator {
+    pub fn label(self) -> InvariantLabel {
+        match self {
+            Self::WeightForAge => InvariantLabel {
+                key: "growth_weight_for_age",
+                en: "Weight-for-age",
+                fr: "Poids pour l'âge",
+                de: "Gewicht für das Alter",
+            },
+            Self::LengthHeightForAge => InvariantLabel {
+                key: "growth_height_for_age",
+                en: "Length/height-for-age",
+                fr: "Taille pour l'âge",
+                de: "Länge/Größe für das Alter",
+            },
+            Self::HeadCircumferenceForAge => InvariantLabel {
+                key: "growth_head_circumference_for_age",
+                en: "Head circumference-for-age",
+                fr: "Périmètre crânien pour l'âge",
+                de: "Kopfumfang für das Alter",
+            },
+            Self::BmiForAge => InvariantLabel {
+                key: "growth_bmi_for_age",
+                en: "BMI-for-age",
+                fr: "IMC pour l'âge",
+                de: "BMI für das Alter",
+            },
+        }
+    }
+
+    /// Indicator charted directly from a stored vital type.
+    /// BMI-for-age is derived from weight + height pairs instead.
+    pub fn for_vital(vital_type: VitalType) -> Option<Self> {
+        match vital_type {
+            VitalType::Weight => Some(Self::WeightForAge),
+            VitalType::Height => Some(Self::LengthHeightForAge),
+            VitalType::HeadCircumference => Some(Self::HeadCircumferenceForAge),
+            _ => None,
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// LMS reference tables
+// ═══════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LmsPoint {
+    pub age_months: f64,
+    pub l: f64,
+    pub m: f64,
+    pub s: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct GrowthReference {
+    pub indicator: GrowthIndicator,
+    pub sex: BiologicalSex,
+    /// Clinical guideline source.
+    pub source: &'static str,
+    /// Official file the table was loaded from (see `resources/growth/SOURCES`).
+    pub file: &'static str,
+    /// Ascending by age.
+    pub points: Vec<LmsPoint>,
+}
+
+/// Oldest age covered by any reference (CDC 2000 ends at 20 years).
+pub const MAX_GROWTH_AGE_MONTHS: f64 = 240.0;
+
+/// WHO standards are used up to and including this age, CDC afterwards
+/// (CDC/AAP recommendation). Head circumference stays on WHO throughout.
+const WHO_CDC_SWITCH_MONTHS: f64 = 24.0;
+
+const WHO: &str = "WHO Child Growth Standards 2006";
+const CDC: &str = "CDC 2000 Growth Charts";
+
+/// One official table file. `sex: None` means the file holds both sexes in
+/// a `Sex` column (CDC: 1 = male, 2 = female).
+struct TableFile {
+    indicator: GrowthIndicator,
+    sex: Option<BiologicalSex>,
+    source: &'static str,
+    file: &'static str,
+}
+
+const fn table(
+    indicator: GrowthIndicator,
+    sex: Option<BiologicalSex>,
+    source: &'static str,
+    file: &'static str,
+) -> TableFile {
+    TableFile { indicator, sex, source, file }
+}
+
+/// WHO monthly "simplified field tables" (0–60 / 0–24 months) and the CDC
+/// 2000 LMS files (24–240 months), kept verbatim under `resources/growth/`.
+const TABLE_FILES: &[TableFile] = &[
+    table(GrowthIndicator::WeightForAge, Some(BiologicalSex::Male), WHO, "tab_wfa_boys_p_0_5.txt"),
+    table(GrowthIndicator::WeightForAge, Some(BiologicalSex::Female), WHO, "tab_wfa_girls_p_0_5.txt"),
+    table(GrowthIndicator::LengthHeightForAge, Some(BiologicalSex::Male), WHO, "tab_lhfa_boys_p_0_2.txt"),
+    table(GrowthIndicator::LengthHeightForAge, Some(BiologicalSex::Female), WHO, "tab_lhfa_girls_p_0_2.txt"),
+    table(GrowthIndicator::HeadCircumferenceForAge, Some(BiologicalSex::Male), WHO, "tab_hcfa_boys_p_0_5.txt"),
+    table(GrowthIndicator::HeadCircumferenceForAge, Some(BiologicalSex::Female), WHO, "tab_hcfa_girls_p_0_5.txt"),
+    table(GrowthIndicator::BmiForAge, Some(BiologicalSex::Male), WHO, "tab_bmi_boys_p_0_2.txt"),
+    table(GrowthIndicator::BmiForAge, Some(BiologicalSex::Female), WHO, "tab_bmi_girls_p_0_2.txt"),
+    table(GrowthIndicator::WeightForAge, None, CDC, "wtage.csv"),
+    table(GrowthIndicator::LengthHeightForAge, None, CDC, "statage.csv"),
+    table(GrowthIndicator::BmiForAge, None, CDC, "bmiagerev.csv"),
+];
+
+static GROWTH_REFERENCES: OnceLock<Vec<GrowthReference>> = OnceLock::new();
+
+/// Checksums of the committed tables (`sha256sum` format).
+const CHECKSUM_FILE: &str = "SHA256SUMS";
+
+/// Load the official LMS tables from `resources/growth/`.
+///
+/// Called once at startup by the InvariantRegistry. Every table must be
+/// present and match its entry in `SHA256SUMS`: a missing or modified table
+/// is an error, never a silently missing chart. A resources directory with
+/// no `growth/` at all loads nothing (graceful degradation, like the JSON
+/// tier). Returns the number of references available.
+pub fn load_references(resources_dir: &Path) -> Result<usize, LoadError> {
+    if let Some(loaded) = GROWTH_REFERENCES.get() {
+        return Ok(loaded.len());
+    }
+    let growth_dir = resources_dir.join("growth");
+    if !growth_dir.exists() {
+        tracing::warn!(dir = %growth_dir.display(), "Growth tables not bundled");
+        return Ok(0);
+    }
+    let references = read_references(&growth_dir)?;
+    Ok(GROWTH_REFERENCES.get_or_init(|| references).len())
+}
+
+/// Read and verify every table in `TABLE_FILES`.
+fn read_references(growth_dir: &Path) -> Result<Vec<GrowthReference>, LoadError> {
+    let checksums = read_checksums(growth_dir)?;
+
+    let mut references = Vec::new();
+    for spec in TABLE_FILES {
+        let path = growth_dir.join(spec.file);
+        let table_err = |reason: String| LoadError::Table {
+            path: path.display().to_string(),
+            reason,
+        };
+        let bytes = std::fs::read(&path).map_err(|e| LoadError::Io {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        let expected = checksums
+            .iter()
+            .find(|(_, file)| file == spec.file)
+            .map(|(digest, _)| digest.as_str())
+            .ok_or_else(|| table_err(format!("not listed in {CHECKSUM_FILE}")))?;
+        if sha256_hex(&bytes) != expected {
+            return Err(table_err(format!("checksum does not match {CHECKSUM_FILE}")));
+        }
+        let content = String::from_utf8(bytes).map_err(|e| table_err(e.to_string()))?;
+        let sexes = match spec.sex {
+            Some(sex) => vec![sex],
+            None => vec![BiologicalSex::Male, BiologicalSex::Female],
+        };
+        for sex in sexes {
+            let points =
+                parse_lms_table(&content, spec.sex.is_none().then_some(sex)).map_err(table_err)?;
+            references.push(GrowthReference {
+                indicator: spec.indicator,
+                sex,
+                source: spec.source,
+                file: spec.file,
+                points,
+            });
+        }
+    }
+    Ok(references)
+}
+
+/// `(hex digest, file name)` pairs from `SHA256SUMS`.
+fn read_checksums(growth_dir: &Path) -> Result<Vec<(String, String)>, LoadError> {
+    let path = growth_dir.join(CHECKSUM_FILE);
+    let content = std::fs::read_to_string(&path).map_err(|e| LoadError::Io {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+    Ok(content
+        .lines()
+        .filter_map(|line| line.split_once(char::is_whitespace))
+        .map(|(digest, file)| {
+            let file = file.trim_start().trim_start_matches('*');
+            (digest.to_ascii_lowercase(), file.to_string())
+        })
+        .collect())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// All loaded references (empty until [`load_references`] has run).
+pub fn growth_references() -> &'static [GrowthReference] {
+    GROWTH_REFERENCES.get().map(Vec::as_slice).unwrap_or(&[])
+}
+
+/// Split an official table line (WHO: tab-separated, CDC: comma-separated).
+fn fields(line: &str) -> Vec<&str> {
+    line.split(['\t', ','])
+        .map(|f| f.trim().trim_matches('"'))
+        .collect()
+}
+
+/// Parse the LMS columns of an official table, located by header name
+/// (`Month`/`Agemos`, `L`, `M`, `S`, optional `Sex`). Other columns are
+/// ignored; rows that are not numeric (repeated headers, blank lines) are
+/// skipped. `sex` selects rows of a combined CDC file.
+fn parse_lms_table(content: &str, sex: Option<BiologicalSex>) -> Result<Vec<LmsPoint>, String> {
+    let mut lines = content.lines().filter(|l| !l.trim().is_empty());
+    let header = fields(lines.next().ok_or("empty table")?);
+    let column = |names: &[&str]| {
+        header
+            .iter()
+            .position(|h| names.iter().any(|n| h.eq_ignore_ascii_case(n)))
+            .ok_or_else(|| format!("missing column {}", names[0]))
+    };
+    let (age_col, l_col, m_col, s_col) = (
+        column(&["Month", "Agemos"])?,
+        column(&["L"])?,
+        column(&["M"])?,
+        column(&["S"])?,
+    );
+    let sex_filter = match sex {
+        Some(sex) => Some((
+            column(&["Sex"])?,
+            if sex == BiologicalSex::Male { 1.0 } else { 2.0 },
+        )),
+        None => None,
+    };
+
+    let mut points: Vec<LmsPoint> = Vec::new();
+    for line in lines {
+        let row = fields(line);
+        let number = |col: usize| row.get(col).and_then(|v| v.parse::<f64>().ok());
+        let Some(age_months) = number(age_col) else {
+            continue;
+        };
+        if let Some((col, code)) = sex_filter {
+            if number(col) != Some(code) {
+                continue;
+            }
+        }
+        let (Some(l), Some(m), Some(s)) = (number(l_col), number(m_col), number(s_col)) else {
+            return Err(format!("incomplete LMS row at {age_months} months"));
+        };
+        if m <= 0.0 || s <= 0.0 {
+            return Err(format!("non-positive M or S at {age_months} months"));
+        }
+        if points.last().is_some_and(|p| p.age_months >= age_months) {
+            return Err(format!("ages not ascending at {age_months} months"));
+        }
+        points.push(LmsPoint { age_months, l, m, s });
+    }
+    if points.is_empty() {
+        return Err("no LMS rows".to_string());
+    }
+    Ok(points)
+}
+
+/// Select the reference covering `age_months` (WHO ≤ 24 months, CDC after;
+/// head circumference WHO only).
+pub fn find_reference(
+    indicator: GrowthIndicator,
+    sex: BiologicalSex,
+    age_months: f64,
+) -> Option<&'static GrowthReference> {
+    if !(0.0..=MAX_GROWTH_AGE_MONTHS).contains(&age_months) {
+        return None;
+    }
+    let source = if age_months <= WHO_CDC_SWITCH_MONTHS
+        || indicator == GrowthIndicator::HeadCircumferenceForAge
+    {
+        WHO
+    } else {
+        CDC
+    };
+    growth_references()
+        .iter()
+        .find(|r| r.indicator == indicator && r.sex == sex && r.source == source)
+}
+
+impl GrowthReference {
+    /// LMS parameters at `age_months`, linearly interpolated between rows.
+    pub fn lms_at(&self, age_months: f64) -> Option<LmsPoint> {
+        let first = self.points.first()?;
+        let last = self.points.last()?;
+        if age_months < first.age_months || age_months > last.age_months {
+            return None;
+        }
+        let upper = self.points.iter().position(|p| p.age_months >= age_months)?;
+        let hi = self.points[upper];
+        if upper == 0 || hi.age_months == age_months {
+            return Some(hi);
+        }
+        let lo = self.points[upper - 1];
+        let t = (age_months - lo.age_months) / (hi.age_months - lo.age_months);
+        Some(LmsPoint {
+            age_months,
+            l: lo.l + (hi.l - lo.l) * t,
+            m: lo.m + (hi.m - lo.m) * t,
+            s: lo.s + (hi.s - lo.s) * t,
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// LMS arithmetic
+// ═══════════════════════════════════════════════════════════
+
+/// Z-score of a measurement given LMS parameters.
+pub fn z_score(value: f64, lms: &LmsPoint) -> f64 {
+    if lms.l.abs() < 1e-9 {
+        (value / lms.m).ln() / lms.s
+    } else {
+        ((value / lms.m).powf(lms.l) - 1.0) / (lms.l * lms.s)
+    }
+}
+
+/// Measurement lying at z-score `z` (inverse of [`z_score`]).
+pub fn value_at_z(z: f64, lms: &LmsPoint) -> f64 {
+    if lms.l.abs() < 1e-9 {
+        lms.m * (lms.s * z).exp()
+    } else {
+        lms.m * (1.0 + lms.l * lms.s * z).powf(1.0 / lms.l)
+    }
+}
+
+/// Standard normal CDF as a percentile (0–100).
+///
+/// Abramowitz & Stegun 7.1.26 erf approximation (|error| < 1.5e-7).
+pub fn z_to_percentile(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736 + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    let cdf = if z >= 0.0 { 0.5 * (1.0 + erf) } else { 0.5 * (1.0 - erf) };
+    cdf * 100.0
+}
+
+/// Age in months (average month length, as used by the WHO tables).
+pub fn age_in_months(date_of_birth: NaiveDate, date: NaiveDate) -> f64 {
+    (date - date_of_birth).num_days() as f64 / 30.4375
+}
+
+/// Z-scores of the major percentile lines (3rd, 10th, 25th, 50th, 75th, 90th, 97th).
+pub const MAJOR_PERCENTILE_Z: &[f64] = &[-1.881, -1.2816, -0.6745, 0.0, 0.6745, 1.2816, 1.881];
+
+/// Z-score of the 3rd / 97th percentile reference curves.
+const P97_Z: f64 = 1.881;
+
+/// A measurement placed on its growth chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrowthAssessment {
+    pub indicator: GrowthIndicator,
+    pub age_months: f64,
+    pub value: f64,
+    pub z_score: f64,
+    pub percentile: f64,
+    pub source: &'static str,
+}
+
+/// Place a measurement on the growth chart. `None` outside 0–20 years.
+pub fn assess(
+    indicator: GrowthIndicator,
+    sex: BiologicalSex,
+    age_months: f64,
+    value: f64,
+) -> Option<GrowthAssessment> {
+    if value <= 0.0 || !value.is_finite() {
+        return None;
+    }
+    let reference = find_reference(indicator, sex, age_months)?;
+    let lms = reference.lms_at(age_months)?;
+    let z = z_score(value, &lms);
+    Some(GrowthAssessment {
+        indicator,
+        age_months,
+        value,
+        z_score: z,
+        percentile: z_to_percentile(z),
+        source: reference.source,
+    })
+}
+
+/// Sex and date of birth when the profile is still within growth-chart age.
+pub fn growth_profile(
+    demographics: Option<&PatientDemographics>,
+    reference_date: NaiveDate,
+) -> Option<(BiologicalSex, NaiveDate)> {
+    let demographics = demographics?;
+    let sex = demographics.sex?;
+    let dob = demographics.date_of_birth?;
+    let age = age_in_months(dob, reference_date);
+    (0.0..=MAX_GROWTH_AGE_MONTHS).contains(&age).then_some((sex, dob))
+}
+
+// ═══════════════════════════════════════════════════════════
+// Growth-curve series (get_vital_trend)
+// ═══════════════════════════════════════════════════════════
+
+/// Annotate trend points with their growth-chart position and the
+/// 3rd/50th/97th reference curves. Points outside the chart ages stay as-is.
+pub fn annotate_trend(
+    points: &mut [VitalTrendPoint],
+    vital_type: VitalType,
+    sex: BiologicalSex,
+    date_of_birth: NaiveDate,
+) {
+    let Some(indicator) = GrowthIndicator::for_vital(vital_type) else {
+        return;
+    };
+    for point in points.iter_mut() {
+        let Ok(recorded) = NaiveDateTime::parse_from_str(&point.recorded_at, "%Y-%m-%d %H:%M:%S")
+        else {
+            continue;
+        };
+        let age = age_in_months(date_of_birth, recorded.date());
+        let Some(lms) = find_reference(indicator, sex, age).and_then(|r| r.lms_at(age)) else {
+            continue;
+        };
+        let z = z_score(point.value, &lms);
+        point.growth = Some(GrowthPercentile {
+            age_months: (age * 10.0).round() / 10.0,
+            z_score: (z * 100.0).round() / 100.0,
+            percentile: (z_to_percentile(z) * 10.0).round() / 10.0,
+            p3: round1(value_at_z(-P97_Z, &lms)),
+            p50: round1(lms.m),
+            p97: round1(value_at_z(P97_Z, &lms)),
+        });
+    }
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+// ═══════════════════════════════════════════════════════════
+// Percentile crossings
+// ═══════════════════════════════════════════════════════════
+
+/// Crossings are judged over this look-back window…
+const CROSSING_WINDOW_DAYS: i64 = 365;
+/// …between measurements at least this far apart.
+const CROSSING_MIN_DAYS: i64 = 30;
+/// Weight and height taken within this many days are paired for BMI.
+const BMI_PAIRING_DAYS: i64 = 31;
+
+const GROWTH_DROP_LABEL: InvariantLabel = InvariantLabel {
+    key: "growth_percentile_drop",
+    en: "Growth percentile drop (crossed two or more major percentile lines)",
+    fr: "Chute de percentile de croissance (au moins deux couloirs franchis)",
+    de: "Perzentilenabfall im Wachstum (zwei oder mehr Hauptperzentilen gekreuzt)",
+};
+
+const GROWTH_RISE_LABEL: InvariantLabel = InvariantLabel {
+    key: "growth_percentile_rise",
+    en: "Growth percentile rise (crossed two or more major percentile lines)",
+    fr: "Hausse de percentile de croissance (au moins deux couloirs franchis)",
+    de: "Perzentilenanstieg im Wachstum (zwei oder mehr Hauptperzentilen gekreuzt)",
+};
+
+struct ChartPoint {
+    recorded_at: NaiveDateTime,
+    assessment: GrowthAssessment,
+    entities: Vec<uuid::Uuid>,
+}
+
+/// Detect major-percentile crossings for weight, length/height, head
+/// circumference and BMI-for-age. Requires sex and date of birth.
+///
+/// Pure function — no side effects, no I/O.
+pub fn detect_percentile_crossings(
+    vital_signs: &[VitalSign],
+    demographics: Option<&PatientDemographics>,
+    reference_date: NaiveDate,
+) -> Vec<ClinicalInsight> {
+    let Some((sex, dob)) = growth_profile(demographics, reference_date) else {
+        return Vec::new();
+    };
+
+    let chart = |indicator: GrowthIndicator, v: &VitalSign, value: f64| {
+        let age = age_in_months(dob, v.recorded_at.date());
+        assess(indicator, sex, age, value).map(|assessment| ChartPoint {
+            recorded_at: v.recorded_at,
+            assessment,
+            entities: vec![v.id],
+        })
+    };
+
+    let mut series: Vec<(GrowthIndicator, Vec<ChartPoint>)> = Vec::new();
+    for indicator in [
+        GrowthIndicator::WeightForAge,
+        GrowthIndicator::LengthHeightForAge,
+        GrowthIndicator::HeadCircumferenceForAge,
+    ] {
+        let points = vital_signs
+            .iter()
+            .filter(|v| GrowthIndicator::for_vital(v.vital_type) == Some(indicator))
+            .filter_map(|v| chart(indicator, v, v.value_primary))
+            .collect();
+        series.push((indicator, points));
+    }
+
+    // BMI-for-age: each weight paired with the closest height
+    let heights: Vec<&VitalSign> = vital_signs
+        .iter()
+        .filter(|v| v.vital_type == VitalType::Height)
+        .collect();
+    let bmi_points = vital_signs
+        .iter()
+        .filter(|v| v.vital_type == VitalType::Weight)
+        .filter_map(|w| {
+            let h = heights
+                .iter()
+                .min_by_key(|h| (h.recorded_at - w.recorded_at).num_days().abs())?;
+            if (h.recorded_at - w.recorded_at).num_days().abs() > BMI_PAIRING_DAYS {
+                return None;
+            }
+            let bmi = crate::invariants::vitals::compute_bmi(w.value_primary, h.value_primary)?;
+            let mut point = chart(GrowthIndicator::BmiForAge, w, bmi)?;
+            point.entities.push(h.id);
+            Some(point)
+        })
+        .collect();
+    series.push((GrowthIndicator::BmiForAge, bmi_points));
+
+    series
+        .into_iter()
+        .filter_map(|(indicator, points)| crossing_insight(indicator, points))
+        .collect()
+}
+
+fn crossing_insight(indicator: GrowthIndicator, mut points: Vec<ChartPoint>) -> Option<ClinicalInsight> {
+    points.sort_by_key(|p| p.recorded_at);
+    let latest = points.last()?;
+    let baseline = points.iter().find(|p| {
+        let days = (latest.recorded_at - p.recorded_at).num_days();
+        (CROSSING_MIN_DAYS..=CROSSING_WINDOW_DAYS).contains(&days)
+    })?;
+
+    let (z0, z1) = (baseline.assessment.z_score, latest.assessment.z_score);
+    let (low, high) = if z0 < z1 { (z0, z1) } else { (z1, z0) };
+    let lines_crossed = MAJOR_PERCENTILE_Z
+        .iter()
+        .filter(|&&line| low < line && line < high)
+        .count();
+    if lines_crossed < 2 {
+        return None;
+    }
+
+    let severity = if lines_crossed >= 3 {
+        InsightSeverity::Critical
+    } else {
+        InsightSeverity::Warning
+    };
+    let days = (latest.recorded_at - baseline.recorded_at).num_days();
+    let mut related_entities = baseline.entities.clone();
+    related_entities.extend(latest.entities.iter().copied());
+
+    Some(ClinicalInsight {
+        kind: InsightKind::AbnormalTrend,
+        severity,
+        summary_key: format!(
+            "{}: P{:.0} -> P{:.0} over {} days",
+            indicator.label().en,
+            baseline.assessment.percentile,
+            latest.assessment.percentile,
+            days
+        ),
+        description: if z1 < z0 { GROWTH_DROP_LABEL } else { GROWTH_RISE_LABEL },
+        source: latest.assessment.source.to_string(),
+        related_entities,
+        meaning_factors: MeaningFactors {
+            significance: if severity == InsightSeverity::Critical { 1.5 } else { 1.0 },
+            ..MeaningFactors::default()
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::VitalSource;
+    use uuid::Uuid;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn vital(vital_type: VitalType, value: f64, on: NaiveDate) -> VitalSign {
+        let at = on.and_hms_opt(10, 0, 0).unwrap();
+        VitalSign {
+            id: Uuid::new_v4(),
+            vital_type,
+            value_primary: value,
+            value_secondary: None,
+            unit: vital_type.default_unit().to_string(),
+            recorded_at: at,
+            notes: None,
+            source: VitalSource::Manual,
+            created_at: at,
+        }
+    }
+
+    fn child(sex: BiologicalSex, dob: NaiveDate) -> PatientDemographics {
+        PatientDemographics {
+            sex: Some(sex),
+            ethnicities: vec![],
+            age_context: None,
+            age_years: None,
+            blood_type: None,
+            date_of_birth: Some(dob),
+        }
+    }
+
+    /// Load the official tables shipped in `resources/growth/`.
+    fn load_official_tables() {
+        let resources = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources");
+        let loaded = load_references(&resources).unwrap_or_else(|e| {
+            panic!("official growth tables unusable ({e}): run ./build.sh growth-tables and commit")
+        });
+        assert_eq!(loaded, 14);
+    }
+
+    #[test]
+    fn parses_who_monthly_table() {
+        let content = "Month\tL\tM\tS\tP50\n0\t0.3487\t3.3464\t0.14602\t3.3464\n1\t0.2297\t4.4709\t0.13395\t4.4709\n";
+        let points = parse_lms_table(content, None).unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0], LmsPoint { age_months: 0.0, l: 0.3487, m: 3.3464, s: 0.14602 });
+    }
+
+    #[test]
+    fn parses_one_sex_of_cdc_table() {
+        // Synthetic rows in the CDC layout, including a repeated header
+        let content = "Sex,Agemos,L,M,S,P3\n1,24,-0.2,12.7,0.11,10.5\n1,24.5,-0.2,12.8,0.11,10.6\n\
+                       Sex,Agemos,L,M,S,P3\n2,24,-0.7,12.1,0.12,10.0\n";
+        let boys = parse_lms_table(content, Some(BiologicalSex::Male)).unwrap();
+        assert_eq!(boys.iter().map(|p| p.age_months).collect::<Vec<_>>(), vec![24.0, 24.5]);
+        let girls = parse_lms_table(content, Some(BiologicalSex::Female)).unwrap();
+        assert_eq!(girls.len(), 1);
+        assert_eq!(girls[0].m, 12.1);
+    }
+
+    #[test]
+    fn rejects_malformed_tables() {
+        assert!(parse_lms_table("Month,L,M\n0,1,2\n", None).is_err());
+        assert!(parse_lms_table("Month,L,M,S\n1,1,50,0.03\n0,1,49,0.03\n", None).is_err());
+        assert!(parse_lms_table("Month,L,M,S\n0,1,0,0.03\n", None).is_err());
+        assert!(parse_lms_table("Month,L,M,S\n", None).is_err());
+    }
+
+    #[test]
+    fn rejects_missing_or_modified_tables() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut sums = String::new();
+        for spec in TABLE_FILES {
+            let content = match spec.sex {
+                Some(_) => "Month\tL\tM\tS\n0\t1\t3.3\t0.14\n",
+                None => "Sex,Agemos,L,M,S\n1,24,1,12.7,0.11\n2,24,1,12.1,0.12\n",
+            };
+            std::fs::write(dir.path().join(spec.file), content).unwrap();
+            sums.push_str(&format!("{}  {}\n", sha256_hex(content.as_bytes()), spec.file));
+        }
+        std::fs::write(dir.path().join(CHECKSUM_FILE), sums).unwrap();
+        assert_eq!(read_references(dir.path()).unwrap().len(), 14);
+
+        std::fs::write(dir.path().join("wtage.csv"), "Sex,Agemos,L,M,S\n1,24,1,99,0.11\n").unwrap();
+        let err = read_references(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("checksum"), "{err}");
+
+        std::fs::remove_file(dir.path().join("wtage.csv")).unwrap();
+        assert!(matches!(read_references(dir.path()), Err(LoadError::Io { .. })));
+    }
+
+    #[test]
+    fn references_are_sorted_and_positive() {
+        load_official_tables();
+        for r in growth_references() {
+            assert!(r.points.windows(2).all(|w| w[0].age_months < w[1].age_months));
+            assert!(r.points.iter().all(|p| p.m > 0.0 && p.s > 0.0));
+        }
+    }
+
+    #[test]
+    fn lms_reproduces_published_percentiles() {
+        load_official_tables();
+        // Exact z of the 3rd / 97th percentiles
+        const Z97: f64 = 1.880_793_608;
+        let resources = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/growth");
+        for spec in TABLE_FILES {
+            let content = std::fs::read_to_string(resources.join(spec.file)).unwrap();
+            let mut lines = content.lines().filter(|l| !l.trim().is_empty());
+            let header = fields(lines.next().unwrap());
+            let col = |name: &str| header.iter().position(|h| h.eq_ignore_ascii_case(name));
+            let (p3, p50, p97) = (col("P3").unwrap(), col("P50").unwrap(), col("P97").unwrap());
+            let sex_col = col("Sex");
+            for line in lines {
+                let row = fields(line);
+                let number = |c: usize| row[c].parse::<f64>().ok();
+                let Some(age) = number(col("Month").or(col("Agemos")).unwrap()) else {
+                    continue;
+                };
+                let sex = match sex_col.and_then(number) {
+                    Some(code) if code == 2.0 => BiologicalSex::Female,
+                    Some(_) => BiologicalSex::Male,
+                    None => spec.sex.unwrap(),
+                };
+                let reference = growth_references()
+                    .iter()
+                    .find(|r| r.file == spec.file && r.sex == sex)
+                    .unwrap();
+                let lms = reference.lms_at(age).unwrap();
+                for (z, published) in [(-Z97, number(p3)), (0.0, number(p50)), (Z97, number(p97))] {
+                    let published = published.unwrap();
+                    let computed = value_at_z(z, &lms);
+                    assert!(
+                        (computed - published).abs() <= 1e-3 * published,
+                        "{} {sex:?} {age} months z={z}: {computed} vs published {published}",
+                        spec.file
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_who_z_score_charts_at_birth() {
+        load_official_tables();
+        // WHO z-score tables, month 0: -2 SD / +2 SD (rounded to 0.1)
+        let cases = [
+            (GrowthIndicator::WeightForAge, BiologicalSex::Male, 2.5, 4.4),
+            (GrowthIndicator::WeightForAge, BiologicalSex::Female, 2.4, 4.2),
+            (GrowthIndicator::LengthHeightForAge, BiologicalSex::Male, 46.1, 53.7),
+        ];
+        for (indicator, sex, minus2, plus2) in cases {
+            let lms = find_reference(indicator, sex, 0.0).unwrap().lms_at(0.0).unwrap();
+            assert_eq!(round1(value_at_z(-2.0, &lms)), minus2);
+            assert_eq!(round1(value_at_z(2.0, &lms)), plus2);
+        }
+    }
+
+    #[test]
+    fn median_is_50th_percentile() {
+        load_official_tables();
+        let a = assess(GrowthIndicator::WeightForAge, BiologicalSex::Male, 0.0, 3.3464).unwrap();
+        assert!(a.z_score.abs() < 1e-6);
+        assert!((a.percentile - 50.0).abs() < 0.01);
+        assert_eq!(a.source, WHO);
+    }
+
+    #[test]
+    fn z_score_round_trips_through_value_at_z() {
+        load_official_tables();
+        let lms = find_reference(GrowthIndicator::BmiForAge, BiologicalSex::Female, 100.0)
+            .unwrap()
+            .lms_at(100.0)
+            .unwrap();
+        for z in [-2.0, -1.0, 0.5, 2.0] {
+            assert!((z_score(value_at_z(z, &lms), &lms) - z).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn percentile_matches_normal_table() {
+        assert!((z_to_percentile(1.881) - 97.0).abs() < 0.05);
+        assert!((z_to_percentile(-1.2816) - 10.0).abs() < 0.05);
+        assert!((z_to_percentile(0.0) - 50.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn who_until_two_years_then_cdc() {
+        load_official_tables();
+        let who = find_reference(GrowthIndicator::LengthHeightForAge, BiologicalSex::Female, 24.0);
+        let cdc = find_reference(GrowthIndicator::LengthHeightForAge, BiologicalSex::Female, 24.5);
+        assert_eq!(who.unwrap().source, WHO);
+        assert_eq!(cdc.unwrap().source, CDC);
+        let head = find_reference(GrowthIndicator::HeadCircumferenceForAge, BiologicalSex::Male, 48.0);
+        assert_eq!(head.unwrap().source, WHO);
+        assert!(find_reference(GrowthIndicator::HeadCircumferenceForAge, BiologicalSex::Male, 72.0)
+            .and_then(|r| r.lms_at(72.0))
+            .is_none());
+        assert!(find_reference(GrowthIndicator::WeightForAge, BiologicalSex::Male, 241.0).is_none());
+    }
+
+    #[test]
+    fn interpolates_between_monthly_rows() {
+        load_official_tables();
+        let r = find_reference(GrowthIndicator::WeightForAge, BiologicalSex::Male, 5.5).unwrap();
+        let (m5, m6) = (r.lms_at(5.0).unwrap().m, r.lms_at(6.0).unwrap().m);
+        let mid = r.lms_at(5.5).unwrap().m;
+        assert!((mid - (m5 + m6) / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn weight_falling_through_percentiles_is_flagged() {
+        load_official_tables();
+        let dob = date(2025, 1, 1);
+        // ~50th percentile at 6 months, well under the 10th at 12 months
+        let vitals = vec![
+            vital(VitalType::Weight, 7.93, date(2025, 7, 2)),
+            vital(VitalType::Weight, 8.1, date(2026, 1, 1)),
+        ];
+        let demo = child(BiologicalSex::Male, dob);
+        let insights = detect_percentile_crossings(&vitals, Some(&demo), date(2026, 1, 10));
+        assert_eq!(insights.len(), 1);
+        assert_eq!(insights[0].description.key, "growth_percentile_drop");
+        assert_eq!(insights[0].kind, InsightKind::AbnormalTrend);
+        assert!(insights[0].summary_key.starts_with("Weight-for-age"));
+    }
+
+    #[test]
+    fn steady_channel_is_not_flagged() {
+        load_official_tables();
+        let dob = date(2025, 1, 1);
+        let vitals = vec![
+            vital(VitalType::Weight, 7.93, date(2025, 7, 2)),
+            vital(VitalType::Weight, 9.65, date(2026, 1, 1)),
+            vital(VitalType::Height, 67.6, date(2025, 7, 2)),
+            vital(VitalType::Height, 75.7, date(2026, 1, 1)),
+        ];
+        let demo = child(BiologicalSex::Male, dob);
+        assert!(detect_percentile_crossings(&vitals, Some(&demo), date(2026, 1, 10)).is_empty());
+    }
+
+    #[test]
+    fn adults_and_unknown_sex_are_skipped() {
+        let vitals = vec![
+            vital(VitalType::Weight, 70.0, date(2025, 1, 1)),
+            vital(VitalType::Weight, 50.0, date(2026, 1, 1)),
+        ];
+        let adult = child(BiologicalSex::Female, date(1980, 1, 1));
+        assert!(detect_percentile_crossings(&vitals, Some(&adult), date(2026, 1, 10)).is_empty());
+        let mut no_sex = child(BiologicalSex::Female, date(2024, 1, 1));
+        no_sex.sex = None;
+        assert!(detect_percentile_crossings(&vitals, Some(&no_sex), date(2026, 1, 10)).is_empty());
+        assert!(detect_percentile_crossings(&vitals, None, date(2026, 1, 10)).is_empty());
+    }
+
+    #[test]
+    fn annotate_trend_adds_reference_curves() {
+        load_official_tables();
+        let mut points = vec![
+            VitalTrendPoint {
+                value: 46.1,
+                recorded_at: "2026-01-01 09:00:00".into(),
+                growth: None,
+            },
+            VitalTrendPoint {
+                value: 45.0,
+                recorded_at: "not a date".into(),
+                growth: None,
+            },
+        ];
+        annotate_trend(&mut points, VitalType::HeadCircumference, BiologicalSex::Male, date(2025, 1, 1));
+        let g = points[0].growth.as_ref().unwrap();
+        assert!((g.age_months - 12.0).abs() < 0.1);
+        assert!(g.p3 < g.p50 && g.p50 < g.p97);
+        assert!((g.percentile - 50.0).abs() < 5.0);
+        assert!(points[1].growth.is_none());
+    }
+}
@@ -0,0 +1,645 @@
+//! IMM-01: Age-based immunization schedule and due-dose computation.
+//!
+//! Deterministic, no LLM involved. Each vaccine series is a list of doses
+//! keyed by the recommended age in months, with a minimum interval from the
+//! previous dose. Given a date of birth and the stored `immunizations` rows,
+//! computes the next dose of every series and whether it is upcoming, due
+//! or overdue.
+//!
+//! Combination vaccines (e.g. hexavalent DTaP-IPV-Hib-HepB) count towards
+//! every series whose aliases they match. Aliases match whole words of the
+//! recorded name, never fragments of a longer word.
+//!
+//! Data sources: WHO SAGE 2024 routine immunization tables (summary of
+//! recommendations), ECDC vaccine scheduler 2024.
+
+use chrono::{Months, NaiveDate};
+use serde::Serialize;
+
+use crate::crypto::profile::AgeContext;
+use crate::invariants::types::InvariantLabel;
+use crate::models::Immunization;
+
+// ═══════════════════════════════════════════════════════════
+// Schedule definition
+// ═══════════════════════════════════════════════════════════
+
+/// Age band a scheduled dose belongs to.
+///
+/// Newborns and toddlers share the infant schedule (primary series up to
+/// the second-year boosters); adolescents share the child schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum ImmunizationBand {
+    Infant,
+    Child,
+    Adult,
+}
+
+impl ImmunizationBand {
+    /// Map the profile's age context onto a schedule band.
+    pub fn for_age_context(age: AgeContext) -> Self {
+        match age {
+            AgeContext::Newborn | AgeContext::Infant | AgeContext::Toddler => Self::Infant,
+            AgeContext::Child | AgeContext::Adolescent => Self::Child,
+            AgeContext::Adult => Self::Adult,
+        }
+    }
+
+    /// Band for an age in completed months.
+    pub fn for_age_months(months: u32) -> Self {
+        match months {
+            0..=35 => Self::Infant,
+            36..=215 => Self::Child,
+            _ => Self::Adult,
+        }
+    }
+}
+
+/// One dose of a vaccine series.
+#[derive(Debug, Clone, Copy)]
+pub struct ScheduledDose {
+    /// Recommended age in months.
+    pub age_months: u32,
+    /// Minimum interval from the previous dose, in months.
+    pub min_interval_months: u32,
+}
+
+/// A routine vaccine series with its dose schedule.
+#[derive(Debug, Clone, Copy)]
+pub struct VaccineSeries {
+    /// Machine key for the series.
+    pub key: &'static str,
+    /// Trilingual display label.
+    pub label: InvariantLabel,
+    /// Clinical guideline source.
+    pub source: &'static str,
+    /// Lowercase alphanumeric words matched against the words of the recorded
+    /// vaccine name. Multi-word names are written together ("hepatitisb").
+    pub aliases: &'static [&'static str],
+    /// Doses in order.
+    pub doses: &'static [ScheduledDose],
+    /// Adult booster interval once the series is complete. `None` = no booster.
+    pub adult_booster_months: Option<u32>,
+    /// No catch-up past this age in months. `None` = catch up at any age.
+    pub catch_up_until_months: Option<u32>,
+}
+
+const fn dose(age_months: u32, min_interval_months: u32) -> ScheduledDose {
+    ScheduledDose { age_months, min_interval_months }
+}
+
+/// Look up a vaccine series by key.
+pub fn find_series(key: &str) -> Option<&'static VaccineSeries> {
+    VACCINE_SERIES.iter().find(|s| s.key == key)
+}
+
+pub static VACCINE_SERIES: &[VaccineSeries] = &[
+    // ─── INFANT PRIMARY SERIES ───────────────────────────
+    VaccineSeries {
+        key: "imm_hepatitis_b",
+        label: InvariantLabel {
+            key: "imm_hepatitis_b",
+            en: "Hepatitis B",
+            fr: "H\u{00e9}patite B",
+            de: "Hepatitis B",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &[
+            "hepatitisb", "hepb", "hbv", "engerix", "hbvaxpro", "hepatiteb", "hexa", "hexavalent",
+            "hexyon", "hexaxim", "vaxelis",
+        ],
+        doses: &[dose(0, 0), dose(2, 1), dose(6, 2)],
+        adult_booster_months: None,
+        catch_up_until_months: None,
+    },
+    VaccineSeries {
+        key: "imm_dtp",
+        label: InvariantLabel {
+            key: "imm_dtp",
+            en: "Diphtheria, tetanus, pertussis",
+            fr: "Dipht\u{00e9}rie, t\u{00e9}tanos, coqueluche",
+            de: "Diphtherie, Tetanus, Pertussis",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &[
+            "dtap", "dtp", "tdap", "td", "dtpa", "dtcap", "dtcp", "diphtheria", "diphterie",
+            "diphtherie", "tetanus", "tetanos", "pertussis", "coqueluche", "infanrix", "pentavac",
+            "tetravac", "boostrix", "repevax", "revaxis", "hexa", "hexavalent", "hexyon", "hexaxim",
+            "vaxelis",
+        ],
+        doses: &[dose(2, 0), dose(4, 1), dose(11, 6), dose(72, 12), dose(132, 60)],
+        adult_booster_months: Some(120),
+        catch_up_until_months: None,
+    },
+    VaccineSeries {
+        key: "imm_polio",
+        label: InvariantLabel {
+            key: "imm_polio",
+            en: "Poliomyelitis",
+            fr: "Poliomy\u{00e9}lite",
+            de: "Poliomyelitis",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &[
+            "polio", "poliomyelitis", "poliomyelite", "ipv", "opv", "pentavac", "tetravac",
+            "repevax", "revaxis", "dtcap", "dtcp", "hexa", "hexavalent", "hexyon", "hexaxim",
+            "vaxelis",
+        ],
+        doses: &[dose(2, 0), dose(4, 1), dose(11, 6), dose(72, 12)],
+        adult_booster_months: None,
+        catch_up_until_months: None,
+    },
+    VaccineSeries {
+        key: "imm_hib",
+        label: InvariantLabel {
+            key: "imm_hib",
+            en: "Haemophilus influenzae type b",
+            fr: "Haemophilus influenzae de type b",
+            de: "Haemophilus influenzae Typ b",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &[
+            "hib", "haemophilus", "hiberix", "pentavac", "hexa", "hexavalent", "hexyon", "hexaxim",
+            "vaxelis",
+        ],
+        doses: &[dose(2, 0), dose(4, 1), dose(11, 6)],
+        adult_booster_months: None,
+        catch_up_until_months: Some(60),
+    },
+    VaccineSeries {
+        key: "imm_pneumococcal_conjugate",
+        label: InvariantLabel {
+            key: "imm_pneumococcal_conjugate",
+            en: "Pneumococcal (conjugate)",
+            fr: "Pneumocoque (conjugu\u{00e9})",
+            de: "Pneumokokken (Konjugat)",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &[
+            "pcv", "pneumococcalconjugate", "pneumocoqueconjugue", "prevenar", "prevnar",
+            "synflorix", "vaxneuvance",
+        ],
+        doses: &[dose(2, 0), dose(4, 2), dose(11, 6)],
+        adult_booster_months: None,
+        catch_up_until_months: Some(60),
+    },
+    VaccineSeries {
+        key: "imm_rotavirus",
+        label: InvariantLabel {
+            key: "imm_rotavirus",
+            en: "Rotavirus",
+            fr: "Rotavirus",
+            de: "Rotavirus",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &["rotavirus", "rotarix", "rotateq"],
+        doses: &[dose(2, 0), dose(3, 1)],
+        adult_booster_months: None,
+        catch_up_until_months: Some(8),
+    },
+    VaccineSeries {
+        key: "imm_mmr",
+        label: InvariantLabel {
+            key: "imm_mmr",
+            en: "Measles, mumps, rubella",
+            fr: "Rougeole, oreillons, rub\u{00e9}ole",
+            de: "Masern, Mumps, R\u{00f6}teln",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &["mmr", "mmrv", "ror", "measles", "rougeole", "masern", "mmrvaxpro", "priorix", "proquad"],
+        doses: &[dose(12, 0), dose(16, 1)],
+        adult_booster_months: None,
+        catch_up_until_months: None,
+    },
+    VaccineSeries {
+        key: "imm_meningococcal_c",
+        label: InvariantLabel {
+            key: "imm_meningococcal_c",
+            en: "Meningococcal C",
+            fr: "M\u{00e9}ningocoque C",
+            de: "Meningokokken C",
+        },
+        source: "ECDC 2024",
+        aliases: &[
+            "menc", "menacwy", "acwy", "meningococcalc", "meningocoquec", "meningokokkenc",
+            "neisvac", "menjugate", "nimenrix", "menveo", "menquadfi",
+        ],
+        doses: &[dose(5, 0), dose(12, 6)],
+        adult_booster_months: None,
+        catch_up_until_months: Some(288),
+    },
+    // ─── CHILD / ADOLESCENT ──────────────────────────────
+    VaccineSeries {
+        key: "imm_hpv",
+        label: InvariantLabel {
+            key: "imm_hpv",
+            en: "Human papillomavirus (HPV)",
+            fr: "Papillomavirus humain (HPV)",
+            de: "Humane Papillomviren (HPV)",
+        },
+        source: "WHO SAGE 2024",
+        aliases: &["hpv", "papillomavirus", "gardasil", "cervarix"],
+        doses: &[dose(132, 0), dose(138, 5)],
+        adult_booster_months: None,
+        catch_up_until_months: Some(312),
+    },
+    // ─── ADULT ───────────────────────────────────────────
+    VaccineSeries {
+        key: "imm_zoster",
+        label: InvariantLabel {
+            key: "imm_zoster",
+            en: "Shingles (recombinant zoster)",
+            fr: "Zona (vaccin recombinant)",
+            de: "G\u{00fc}rtelrose (rekombinant)",
+        },
+        source: "ECDC 2024",
+        aliases: &["zoster", "herpeszoster", "shingles", "shingrix", "zona", "gurtelrose"],
+        doses: &[dose(600, 0), dose(602, 2)],
+        adult_booster_months: None,
+        catch_up_until_months: None,
+    },
+    VaccineSeries {
+        key: "imm_pneumococcal_adult",
+        label: InvariantLabel {
+            key: "imm_pneumococcal_adult",
+            en: "Pneumococcal (adult)",
+            fr: "Pneumocoque (adulte)",
+            de: "Pneumokokken (Erwachsene)",
+        },
+        source: "ECDC 2024",
+        aliases: &[
+            "pneumococcal", "pneumocoque", "pneumokokken", "pcv", "ppsv", "pneumovax", "prevenar",
+            "prevnar", "vaxneuvance", "capvaxive",
+        ],
+        doses: &[dose(780, 0)],
+        adult_booster_months: None,
+        catch_up_until_months: None,
+    },
+];
+
+// ═══════════════════════════════════════════════════════════
+// Due-dose computation
+// ═══════════════════════════════════════════════════════════
+
+/// Doses due within this many months are reported as upcoming.
+const UPCOMING_WINDOW_MONTHS: u32 = 2;
+
+/// A due dose becomes overdue after this grace period.
+const OVERDUE_GRACE_MONTHS: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DueStatus {
+    Upcoming,
+    Due,
+    Overdue,
+}
+
+/// The next dose of a series that the patient has not received yet.
+#[derive(Debug, Clone)]
+pub struct DueDose {
+    pub series: &'static VaccineSeries,
+    /// 1-based dose number in the series (boosters continue the count).
+    pub dose_number: u32,
+    pub due_date: NaiveDate,
+    pub band: ImmunizationBand,
+    pub status: DueStatus,
+}
+
+/// Longest run of adjacent words joined when matching an alias.
+const MAX_ALIAS_WORDS: usize = 3;
+
+/// Words of a vaccine name for alias matching: lowercase, accents folded,
+/// split at anything that is not an ASCII letter or digit and between
+/// letters and digits ("DTaP-IPV" → dtap, ipv; "PCV13" → pcv, 13).
+fn words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for c in name.to_lowercase().chars() {
+        let c = match c {
+            'à' | 'â' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'î' | 'ï' => 'i',
+            'ô' | 'ö' => 'o',
+            'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            other => other,
+        };
+        let boundary = !c.is_ascii_alphanumeric()
+            || current
+                .chars()
+                .last()
+                .is_some_and(|prev| prev.is_ascii_digit() != c.is_ascii_digit());
+        if boundary && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        if c.is_ascii_alphanumeric() {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Whether a recorded vaccine name counts towards a series.
+///
+/// An alias matches one whole word, or a few adjacent words written together
+/// ("Hépatite B" → "hepatiteb"). "ROR" matches MMR, but the "ror" inside an
+/// unrelated brand name does not.
+pub fn matches_series(series: &VaccineSeries, vaccine: &str) -> bool {
+    let words = words(vaccine);
+    (0..words.len()).any(|start| {
+        let mut joined = String::new();
+        words[start..].iter().take(MAX_ALIAS_WORDS).any(|word| {
+            joined.push_str(word);
+            series.aliases.contains(&joined.as_str())
+        })
+    })
+}
+
+fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    date.checked_add_months(Months::new(months)).unwrap_or(date)
+}
+
+/// Completed months between two dates (0 if `on` precedes `from`).
+fn months_between(from: NaiveDate, on: NaiveDate) -> u32 {
+    use chrono::Datelike;
+    let months = (on.year() - from.year()) * 12 + on.month() as i32 - from.month() as i32
+        - i32::from(on.day() < from.day());
+    months.max(0) as u32
+}
+
+/// Compute the next dose of every series relevant to the patient.
+///
+/// Doses given well before a series starts (e.g. an infant PCV dose for the
+/// adult pneumococcal series) are ignored. The received count is the larger of
+/// the number of distinct recorded doses and the highest recorded dose number,
+/// so a booklet that only shows later doses still advances the series.
+///
+/// Doses from an earlier age band are only reported for a series whose
+/// records reach back before the current band: an adult whose only tetanus
+/// record is a recent Tdap booster has unknown childhood history, not an
+/// overdue infant series — and records of one series say nothing about
+/// another.
+pub fn compute_due_doses(
+    date_of_birth: NaiveDate,
+    records: &[Immunization],
+    today: NaiveDate,
+) -> Vec<DueDose> {
+    let mut due = Vec::new();
+    let upcoming_limit = add_months(today, UPCOMING_WINDOW_MONTHS);
+    let current_band = ImmunizationBand::for_age_months(months_between(date_of_birth, today));
+
+    for series in VACCINE_SERIES {
+        let Some(first) = series.doses.first() else { continue };
+        let series_start = add_months(date_of_birth, first.age_months.saturating_sub(1));
+
+        let mut dates: Vec<NaiveDate> = Vec::new();
+        let mut undated = 0u32;
+        let mut highest_dose = 0u32;
+        let mut earlier_history = false;
+        for record in records.iter().filter(|r| matches_series(series, &r.vaccine)) {
+            // An undated booklet entry counts as history of the earlier bands
+            earlier_history |= match record.administered_date {
+                Some(d) => ImmunizationBand::for_age_months(months_between(date_of_birth, d)) < current_band,
+                None => true,
+            };
+            match record.administered_date {
+                Some(d) if d < series_start => continue,
+                Some(d) => dates.push(d),
+                None => undated += 1,
+            }
+            highest_dose = highest_dose.max(record.dose_number.unwrap_or(0));
+        }
+        dates.sort();
+        dates.dedup();
+        let received = (dates.len() as u32 + undated).max(highest_dose);
+        let last_dose = dates.last().copied();
+
+        let (dose_number, due_date) = if (received as usize) < series.doses.len() {
+            if let Some(limit) = series.catch_up_until_months {
+                if today >= add_months(date_of_birth, limit) {
+                    continue;
+                }
+            }
+            let scheduled = series.doses[received as usize];
+            let by_age = add_months(date_of_birth, scheduled.age_months);
+            let by_interval = last_dose.map(|d| add_months(d, scheduled.min_interval_months));
+            (received + 1, by_interval.map_or(by_age, |i| i.max(by_age)))
+        } else {
+            // Series complete: adult boosters only, from the last dated dose
+            let (Some(interval), Some(last)) = (series.adult_booster_months, last_dose) else {
+                continue;
+            };
+            let adult_from = add_months(date_of_birth, 216);
+            (received + 1, add_months(last, interval).max(adult_from))
+        };
+
+        if due_date > upcoming_limit {
+            continue;
+        }
+
+        let status = if due_date > today {
+            DueStatus::Upcoming
+        } else if add_months(due_date, OVERDUE_GRACE_MONTHS) >= today {
+            DueStatus::Due
+        } else {
+            DueStatus::Overdue
+        };
+
+        let band = ImmunizationBand::for_age_months(months_between(date_of_birth, due_date));
+        if band < current_band && !earlier_history {
+            continue;
+        }
+
+        due.push(DueDose {
+            series,
+            dose_number,
+            due_date,
+            band,
+            status,
+        });
+    }
+
+    due.sort_by(|a, b| a.due_date.cmp(&b.due_date));
+    due
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn record(vaccine: &str, dose: Option<u32>, on: Option<NaiveDate>) -> Immunization {
+        Immunization {
+            id: Uuid::new_v4(),
+            vaccine: vaccine.into(),
+            dose_number: dose,
+            administered_date: on,
+            lot_number: None,
+            site: None,
+            administering_professional_id: None,
+            document_id: Uuid::new_v4(),
+        }
+    }
+
+    fn find<'a>(due: &'a [DueDose], key: &str) -> Option<&'a DueDose> {
+        due.iter().find(|d| d.series.key == key)
+    }
+
+    #[test]
+    fn series_keys_unique() {
+        let mut keys: Vec<_> = VACCINE_SERIES.iter().map(|s| s.key).collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), VACCINE_SERIES.len());
+        assert!(find_series("imm_mmr").is_some());
+    }
+
+    #[test]
+    fn band_mapping() {
+        assert_eq!(ImmunizationBand::for_age_context(AgeContext::Toddler), ImmunizationBand::Infant);
+        assert_eq!(ImmunizationBand::for_age_context(AgeContext::Adolescent), ImmunizationBand::Child);
+        assert_eq!(ImmunizationBand::for_age_context(AgeContext::Adult), ImmunizationBand::Adult);
+        assert_eq!(ImmunizationBand::for_age_months(11), ImmunizationBand::Infant);
+        assert_eq!(ImmunizationBand::for_age_months(132), ImmunizationBand::Child);
+    }
+
+    #[test]
+    fn hexavalent_counts_towards_each_component() {
+        for key in ["imm_dtp", "imm_polio", "imm_hib", "imm_hepatitis_b"] {
+            assert!(matches_series(find_series(key).unwrap(), "Hexyon"), "{key}");
+            assert!(matches_series(find_series(key).unwrap(), "DTaP-IPV-Hib-HepB"), "{key}");
+        }
+        assert!(!matches_series(find_series("imm_mmr").unwrap(), "Hexyon"));
+        assert!(matches_series(find_series("imm_hepatitis_b").unwrap(), "Hépatite B"));
+        assert!(matches_series(find_series("imm_hepatitis_b").unwrap(), "Infanrix Hexa"));
+    }
+
+    #[test]
+    fn aliases_match_whole_words_only() {
+        let mmr = find_series("imm_mmr").unwrap();
+        assert!(matches_series(mmr, "ROR"));
+        assert!(matches_series(mmr, "Priorix"));
+        assert!(!matches_series(mmr, "Fluarix Tetra (terror lot)"));
+        // Polysaccharide Pneumovax is not a conjugate dose
+        let conjugate = find_series("imm_pneumococcal_conjugate").unwrap();
+        assert!(matches_series(conjugate, "PCV13"));
+        assert!(!matches_series(conjugate, "Pneumovax 23"));
+        assert!(matches_series(find_series("imm_pneumococcal_adult").unwrap(), "Pneumovax 23"));
+        // "Hexa" is a word of "Infanrix Hexa", not a fragment of other names
+        assert!(!matches_series(find_series("imm_hib").unwrap(), "Hexachlorophene rinse"));
+    }
+
+    #[test]
+    fn adult_with_only_tdap_gets_no_infant_series_alerts() {
+        let dob = date(1980, 6, 1);
+        let records = vec![record("Tdap", None, Some(date(2023, 9, 12)))];
+        let due = compute_due_doses(dob, &records, date(2026, 1, 1));
+        assert!(
+            due.iter().all(|d| d.band == ImmunizationBand::Adult),
+            "unexpected childhood doses: {:?}",
+            due.iter().map(|d| d.series.key).collect::<Vec<_>>()
+        );
+        assert!(find(&due, "imm_dtp").is_none());
+        assert!(find(&due, "imm_mmr").is_none());
+        assert!(find(&due, "imm_zoster").is_none(), "zoster starts at 50");
+    }
+
+    #[test]
+    fn childhood_history_of_one_series_does_not_unlock_another() {
+        let dob = date(2015, 2, 1);
+        let records = vec![
+            record("DTaP", Some(1), Some(date(2015, 4, 1))),
+            record("DTaP", Some(2), Some(date(2015, 6, 1))),
+        ];
+        let due = compute_due_doses(dob, &records, date(2026, 1, 1));
+        // DTP history reaches back to infancy: the missed dose is reported
+        assert_eq!(find(&due, "imm_dtp").unwrap().band, ImmunizationBand::Infant);
+        // No MMR records at all: unknown history, not overdue
+        assert!(find(&due, "imm_mmr").is_none());
+    }
+
+    #[test]
+    fn adult_without_records_only_sees_adult_doses() {
+        let dob = date(1970, 3, 1);
+        let due = compute_due_doses(dob, &[], date(2026, 1, 1));
+        assert!(find(&due, "imm_dtp").is_none());
+        assert!(find(&due, "imm_hepatitis_b").is_none());
+        let zoster = find(&due, "imm_zoster").unwrap();
+        assert_eq!(zoster.band, ImmunizationBand::Adult);
+        assert_eq!(zoster.status, DueStatus::Overdue);
+    }
+
+    #[test]
+    fn unvaccinated_infant_is_overdue_for_primary_series() {
+        let dob = date(2025, 1, 10);
+        let due = compute_due_doses(dob, &[], date(2025, 6, 1));
+        let dtp = find(&due, "imm_dtp").unwrap();
+        assert_eq!(dtp.dose_number, 1);
+        assert_eq!(dtp.due_date, date(2025, 3, 10));
+        assert_eq!(dtp.status, DueStatus::Overdue);
+        assert_eq!(dtp.band, ImmunizationBand::Infant);
+        // MMR at 12 months is outside the upcoming window
+        assert!(find(&due, "imm_mmr").is_none());
+    }
+
+    #[test]
+    fn next_dose_respects_minimum_interval() {
+        let dob = date(2025, 1, 10);
+        // First dose given late: second dose is due one month after, not at 4 months
+        let records = vec![record("Hexyon", Some(1), Some(date(2025, 5, 1)))];
+        let due = compute_due_doses(dob, &records, date(2025, 5, 15));
+        let dtp = find(&due, "imm_dtp").unwrap();
+        assert_eq!(dtp.dose_number, 2);
+        assert_eq!(dtp.due_date, date(2025, 6, 1));
+        assert_eq!(dtp.status, DueStatus::Upcoming);
+    }
+
+    #[test]
+    fn rotavirus_not_caught_up_after_cutoff() {
+        let dob = date(2024, 1, 1);
+        let due = compute_due_doses(dob, &[], date(2025, 1, 1));
+        assert!(find(&due, "imm_rotavirus").is_none());
+        assert!(find(&due, "imm_mmr").is_some());
+    }
+
+    #[test]
+    fn adult_tetanus_booster_after_ten_years() {
+        let dob = date(1980, 6, 1);
+        let mut records: Vec<Immunization> = (0..5)
+            .map(|i| record("DTaP", Some(i + 1), None))
+            .collect();
+        records.push(record("Boostrix", Some(6), Some(date(2014, 3, 1))));
+        let due = compute_due_doses(dob, &records, date(2026, 1, 1));
+        let dtp = find(&due, "imm_dtp").unwrap();
+        assert_eq!(dtp.dose_number, 7);
+        assert_eq!(dtp.due_date, date(2024, 3, 1));
+        assert_eq!(dtp.status, DueStatus::Overdue);
+        assert_eq!(dtp.band, ImmunizationBand::Adult);
+    }
+
+    #[test]
+    fn childhood_pcv_does_not_satisfy_adult_pneumococcal() {
+        let dob = date(1955, 1, 1);
+        let records = vec![record("Prevenar 13", Some(1), Some(date(1955, 3, 1)))];
+        let due = compute_due_doses(dob, &records, date(2026, 1, 1));
+        assert_eq!(find(&due, "imm_pneumococcal_adult").unwrap().dose_number, 1);
+    }
+
+    #[test]
+    fn completed_series_without_booster_not_reported() {
+        let dob = date(2010, 1, 1);
+        let records = vec![
+            record("MMR", Some(1), Some(date(2011, 1, 15))),
+            record("MMR", Some(2), Some(date(2011, 6, 1))),
+        ];
+        let due = compute_due_doses(dob, &records, date(2026, 1, 1));
+        assert!(find(&due, "imm_mmr").is_none());
+    }
+}
@@ -0,0 +1,645 @@
+//! LTR-01: Lab series analytics — slope, reference change value, reversal,
+//! time-in-range.
+//!
+//! Deterministic, no LLM involved. Works for every analyte: tests with an
+//! entry in the biological variation table use their published analytical
+//! (CVa) and within-subject (CVi) variation, others fall back to a
+//! conservative default.
+//!
+//! ## Reference change value (RCV)
+//!
+//! The smallest difference between two consecutive results that exceeds
+//! analytical noise plus normal day-to-day biological fluctuation
+//! (two-sided, 95%):
+//!
+//! ```text
+//! RCV% = √2 × 1.96 × √(CVa² + CVi²)
+//! ```
+//!
+//! Guideline-specific absolute triggers (KDIGO eGFR change, IDF HbA1c rise,
+//! WHO hemoglobin drop) also mark a change as significant.
+//!
+//! Data source: EFLM Biological Variation Database (2024 estimates).
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::invariants::labs;
+use crate::models::LabResult;
+
+// ═══════════════════════════════════════════════════════════
+// Biological variation reference data
+// ═══════════════════════════════════════════════════════════
+
+/// Guideline-specific change that is significant regardless of RCV.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClinicalDelta {
+    /// Relative change in either direction, in percent.
+    Percent(f64),
+    /// Absolute increase, in the given unit.
+    Rise(f64, &'static str),
+    /// Absolute decrease, in the given unit.
+    Drop(f64, &'static str),
+}
+
+/// Factors converting a guideline amount between units of the same analyte
+/// (from, to, multiply by). HbA1c: IFCC mmol/mol = 10.929 × NGSP %.
+/// Hemoglobin: 1 g/dL = 10 g/L = 0.6206 mmol/L.
+const DELTA_UNIT_FACTORS: &[(&str, &str, f64)] = &[
+    ("%", "mmol/mol", 10.929),
+    ("g/dl", "g/l", 10.0),
+    ("g/dl", "mmol/l", 0.6206),
+];
+
+/// Comparable form of a unit: lowercase, no spaces, µ as u, common aliases
+/// folded ("µg" → "mcg", "UI" → "iu").
+pub(crate) fn normalize_unit(unit: &str) -> String {
+    let lower = unit
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase()
+        .replace('µ', "u");
+    match lower.as_str() {
+        "ug" => "mcg".to_string(),
+        "ui" => "iu".to_string(),
+        _ => lower,
+    }
+}
+
+/// A guideline amount expressed in `unit`; `None` when the result's unit is
+/// missing or not convertible (the guideline trigger is then not applied).
+fn amount_in(amount: f64, amount_unit: &str, unit: Option<&str>) -> Option<f64> {
+    let (from, to) = (normalize_unit(amount_unit), normalize_unit(unit?));
+    if from == to {
+        return Some(amount);
+    }
+    DELTA_UNIT_FACTORS
+        .iter()
+        .find(|(f, t, _)| *f == from && *t == to)
+        .map(|(_, _, factor)| amount * factor)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BiologicalVariation {
+    /// Matches `LabThreshold::test_key` where one exists.
+    pub test_key: &'static str,
+    /// Extra name aliases for analytes without a `LabThreshold` (EN/FR/DE).
+    pub aliases: &'static [&'static str],
+    /// Analytical coefficient of variation, percent.
+    pub cv_analytical: f64,
+    /// Within-subject biological coefficient of variation, percent.
+    pub cv_within_subject: f64,
+    pub clinical_delta: Option<ClinicalDelta>,
+    pub source: &'static str,
+}
+
+const EFLM: &str = "EFLM BV Database 2024";
+
+const fn bv(
+    test_key: &'static str,
+    aliases: &'static [&'static str],
+    cv_analytical: f64,
+    cv_within_subject: f64,
+    clinical_delta: Option<ClinicalDelta>,
+) -> BiologicalVariation {
+    BiologicalVariation {
+        test_key,
+        aliases,
+        cv_analytical,
+        cv_within_subject,
+        clinical_delta,
+        source: EFLM,
+    }
+}
+
+pub const BIOLOGICAL_VARIATION: &[BiologicalVariation] = &[
+    // ── Analytes with a LabThreshold (matched via its aliases) ──
+    bv("egfr", &[], 2.5, 4.5, Some(ClinicalDelta::Percent(labs::EGFR_CHANGE_THRESHOLD_PCT))),
+    bv("hba1c", &[], 1.5, 1.6, Some(ClinicalDelta::Rise(labs::HBA1C_TREND_THRESHOLD, "%"))),
+    bv("ldl", &[], 2.5, 7.8, None),
+    bv("potassium", &[], 1.5, 4.1, None),
+    bv("sodium", &[], 0.7, 0.5, None),
+    bv("alt", &[], 3.0, 9.6, None),
+    bv("hemoglobin", &[], 1.2, 2.7, Some(ClinicalDelta::Drop(labs::HB_DROP_THRESHOLD, "g/dL"))),
+    bv("tsh", &[], 3.0, 17.7, None),
+    bv("uacr", &[], 5.0, 31.0, None),
+    bv("vitamin_d", &[], 5.0, 10.0, None),
+    // ── Analytes matched by their own aliases ──
+    bv("creatinine", &["creatinine", "créatinine", "kreatinin", "creat"], 2.2, 4.4, None),
+    bv("glucose", &["glucose", "fasting glucose", "glycémie", "glycemie", "glukose", "blutzucker"], 2.0, 5.0, None),
+    bv("total_cholesterol", &["cholesterol", "total cholesterol", "cholestérol total", "gesamtcholesterin"], 2.0, 5.3, None),
+    bv("hdl", &["hdl", "hdl cholesterol", "hdl-c", "cholestérol hdl", "hdl-cholesterin"], 2.0, 5.7, None),
+    bv("triglycerides", &["triglycerides", "triglycérides", "triglyceride", "triglyzeride"], 3.0, 19.9, None),
+    bv("ast", &["ast", "sgot", "asat", "aspartate aminotransferase"], 3.0, 9.5, None),
+    bv("platelets", &["platelets", "platelet count", "plaquettes", "thrombozyten"], 2.5, 7.3, None),
+    bv("wbc", &["wbc", "white blood cells", "leukocytes", "leucocytes", "leukozyten"], 2.0, 11.4, None),
+    bv("crp", &["crp", "c-reactive protein", "protéine c réactive", "c-reaktives protein"], 4.0, 34.0, None),
+    bv("ferritin", &["ferritin", "ferritine"], 3.0, 10.0, None),
+    bv("free_t4", &["free t4", "ft4", "t4 libre", "freies t4"], 3.0, 4.8, None),
+    bv("psa", &["psa", "prostate specific antigen", "antigène prostatique spécifique"], 3.0, 8.0, None),
+];
+
+/// Used for analytes with no biological variation entry.
+pub const DEFAULT_VARIATION: BiologicalVariation = BiologicalVariation {
+    test_key: "default",
+    aliases: &[],
+    cv_analytical: 3.0,
+    cv_within_subject: 10.0,
+    clinical_delta: None,
+    source: "Default (no EFLM entry)",
+};
+
+/// Biological variation for a test name; falls back to [`DEFAULT_VARIATION`].
+pub fn find_variation(test_name: &str) -> &'static BiologicalVariation {
+    if let Some(threshold) = labs::find_threshold(test_name) {
+        if let Some(v) = BIOLOGICAL_VARIATION.iter().find(|v| v.test_key == threshold.test_key) {
+            return v;
+        }
+    }
+    let normalized = test_name.trim().to_lowercase();
+    BIOLOGICAL_VARIATION
+        .iter()
+        .find(|v| v.aliases.iter().any(|a| *a == normalized))
+        .unwrap_or(&DEFAULT_VARIATION)
+}
+
+/// Two-sided 95% reference change value, in percent.
+pub fn reference_change_value(variation: &BiologicalVariation) -> f64 {
+    std::f64::consts::SQRT_2
+        * 1.96
+        * (variation.cv_analytical.powi(2) + variation.cv_within_subject.powi(2)).sqrt()
+}
+
+/// Key grouping results of the same analyte across naming variants.
+pub fn series_key(test_name: &str) -> String {
+    let variation = find_variation(test_name);
+    if variation.test_key == DEFAULT_VARIATION.test_key {
+        test_name.trim().to_lowercase()
+    } else {
+        variation.test_key.to_string()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// Series analysis
+// ═══════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendDirection {
+    Rising,
+    Falling,
+    Stable,
+}
+
+/// Change between a result and the previous result of the same analyte.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabChange {
+    pub previous_value: f64,
+    pub previous_date: NaiveDate,
+    pub delta: f64,
+    pub delta_pct: f64,
+    pub rcv_pct: f64,
+    /// Exceeds the RCV or a guideline-specific trigger.
+    pub significant: bool,
+}
+
+impl LabChange {
+    /// Compact display, e.g. "+24.5% since 2025-10-01 (significant)".
+    pub fn describe(&self) -> String {
+        format!(
+            "{:+.1}% since {}{}",
+            self.delta_pct,
+            self.previous_date,
+            if self.significant { " (significant)" } else { "" }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LabTrend {
+    /// Display name of the most recent result.
+    pub test_name: String,
+    pub unit: Option<String>,
+    pub points: usize,
+    pub first_date: NaiveDate,
+    pub last_date: NaiveDate,
+    pub latest_value: f64,
+    /// Least-squares slope in the test's unit per year.
+    pub slope_per_year: Option<f64>,
+    pub direction: TrendDirection,
+    pub rcv_pct: f64,
+    pub last_change: Option<LabChange>,
+    /// Latest significant change opposes the preceding trend.
+    pub reversal: bool,
+    /// Share of the observed period spent inside the reference range
+    /// (linear interpolation between results). `None` without a range.
+    pub time_in_range_pct: Option<f64>,
+}
+
+/// Compare two consecutive results of the same analyte, both in `unit`.
+fn compare(
+    previous: (NaiveDate, f64),
+    current: f64,
+    unit: Option<&str>,
+    variation: &BiologicalVariation,
+) -> Option<LabChange> {
+    let (previous_date, previous_value) = previous;
+    if previous_value == 0.0 {
+        return None;
+    }
+    let delta = current - previous_value;
+    let delta_pct = delta / previous_value.abs() * 100.0;
+    let rcv_pct = reference_change_value(variation);
+    let guideline = match variation.clinical_delta {
+        Some(ClinicalDelta::Percent(pct)) => delta_pct.abs() > pct,
+        Some(ClinicalDelta::Rise(amount, amount_unit)) => {
+            amount_in(amount, amount_unit, unit).is_some_and(|amount| delta > amount)
+        }
+        Some(ClinicalDelta::Drop(amount, amount_unit)) => {
+            amount_in(amount, amount_unit, unit).is_some_and(|amount| -delta > amount)
+        }
+        None => false,
+    };
+    Some(LabChange {
+        previous_value,
+        previous_date,
+        delta,
+        delta_pct,
+        rcv_pct,
+        significant: delta_pct.abs() >= rcv_pct || guideline,
+    })
+}
+
+/// Ordinary least-squares slope (value per year). Needs a non-zero time span.
+fn slope_per_year(points: &[(NaiveDate, f64)]) -> Option<f64> {
+    let (first, _) = *points.first()?;
+    let xs: Vec<f64> = points
+        .iter()
+        .map(|(d, _)| (*d - first).num_days() as f64 / 365.25)
+        .collect();
+    let n = points.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, v)| v).sum::<f64>() / n;
+    let sxx: f64 = xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+    if sxx <= 0.0 {
+        return None;
+    }
+    let sxy: f64 = xs
+        .iter()
+        .zip(points)
+        .map(|(x, (_, y))| (x - mean_x) * (y - mean_y))
+        .sum();
+    Some(sxy / sxx)
+}
+
+/// Direction from the fitted change over the span, relative to the RCV.
+fn direction(points: &[(NaiveDate, f64)], rcv_pct: f64) -> (Option<f64>, TrendDirection) {
+    let Some(slope) = slope_per_year(points) else {
+        return (None, TrendDirection::Stable);
+    };
+    let span_years = (points[points.len() - 1].0 - points[0].0).num_days() as f64 / 365.25;
+    let mean = points.iter().map(|(_, v)| v).sum::<f64>() / points.len() as f64;
+    let fitted_pct = if mean == 0.0 { 0.0 } else { slope * span_years / mean.abs() * 100.0 };
+    let dir = if fitted_pct >= rcv_pct {
+        TrendDirection::Rising
+    } else if fitted_pct <= -rcv_pct {
+        TrendDirection::Falling
+    } else {
+        TrendDirection::Stable
+    };
+    (Some(slope), dir)
+}
+
+/// Fraction of a linear segment from `v0` to `v1` lying inside `[low, high]`.
+fn segment_in_range(v0: f64, v1: f64, low: f64, high: f64) -> f64 {
+    if (v1 - v0).abs() < f64::EPSILON {
+        return if (low..=high).contains(&v0) { 1.0 } else { 0.0 };
+    }
+    let t_low = (low - v0) / (v1 - v0);
+    let t_high = (high - v0) / (v1 - v0);
+    let (start, end) = if t_low < t_high { (t_low, t_high) } else { (t_high, t_low) };
+    (end.min(1.0) - start.max(0.0)).max(0.0)
+}
+
+/// Time-weighted share of the period inside the reference range (Rosendaal).
+fn time_in_range(points: &[(NaiveDate, f64)], low: Option<f64>, high: Option<f64>) -> Option<f64> {
+    if low.is_none() && high.is_none() {
+        return None;
+    }
+    let (low, high) = (low.unwrap_or(f64::NEG_INFINITY), high.unwrap_or(f64::INFINITY));
+    let mut total_days = 0.0;
+    let mut in_range_days = 0.0;
+    for pair in points.windows(2) {
+        let days = (pair[1].0 - pair[0].0).num_days() as f64;
+        if days <= 0.0 {
+            continue;
+        }
+        total_days += days;
+        in_range_days += days * segment_in_range(pair[0].1, pair[1].1, low, high);
+    }
+    if total_days > 0.0 {
+        Some(in_range_days / total_days * 100.0)
+    } else {
+        None
+    }
+}
+
+/// Analyze results of one analyte. Results may be in any order; text-only
+/// results and results in a different unit than the latest are skipped.
+pub fn analyze_series(results: &[&LabResult]) -> Option<LabTrend> {
+    let latest = results
+        .iter()
+        .filter(|r| r.value.is_some())
+        .max_by_key(|r| r.collection_date)?;
+    let unit = latest.unit.as_deref().map(|u| u.trim().to_lowercase());
+    let variation = find_variation(&latest.test_name);
+    let rcv_pct = reference_change_value(variation);
+
+    let mut series: Vec<(NaiveDate, f64)> = results
+        .iter()
+        .filter(|r| r.unit.as_deref().map(|u| u.trim().to_lowercase()) == unit)
+        .filter_map(|r| Some((r.collection_date, r.value?)))
+        .collect();
+    series.sort_by_key(|(d, _)| *d);
+
+    let n = series.len();
+    let last_change = (n >= 2)
+        .then(|| compare(series[n - 2], series[n - 1].1, latest.unit.as_deref(), variation))
+        .flatten();
+    let (slope, dir) = direction(&series, rcv_pct);
+
+    // Reversal: the latest significant change opposes the preceding trend
+    let reversal = match (&last_change, n >= 3) {
+        (Some(change), true) if change.significant => {
+            let (_, previous_dir) = direction(&series[..n - 1], rcv_pct);
+            matches!(
+                (previous_dir, change.delta > 0.0),
+                (TrendDirection::Rising, false) | (TrendDirection::Falling, true)
+            )
+        }
+        _ => false,
+    };
+
+    Some(LabTrend {
+        test_name: latest.test_name.clone(),
+        unit: latest.unit.clone(),
+        points: n,
+        first_date: series[0].0,
+        last_date: series[n - 1].0,
+        latest_value: series[n - 1].1,
+        slope_per_year: slope,
+        direction: dir,
+        rcv_pct,
+        last_change,
+        reversal,
+        time_in_range_pct: time_in_range(
+            &series,
+            latest.reference_range_low,
+            latest.reference_range_high,
+        ),
+    })
+}
+
+/// Analyze every analyte in a result set, most recently measured first.
+pub fn analyze_all(results: &[LabResult]) -> Vec<LabTrend> {
+    let mut groups: HashMap<String, Vec<&LabResult>> = HashMap::new();
+    for r in results {
+        groups.entry(series_key(&r.test_name)).or_default().push(r);
+    }
+    let mut trends: Vec<LabTrend> = groups
+        .values()
+        .filter_map(|group| analyze_series(group))
+        .collect();
+    trends.sort_by(|a, b| b.last_date.cmp(&a.last_date).then(a.test_name.cmp(&b.test_name)));
+    trends
+}
+
+/// Minimal numeric result for change computation, keyed by any row ID.
+#[derive(Debug, Clone)]
+pub struct LabPoint<K> {
+    pub key: K,
+    pub test_name: String,
+    pub unit: Option<String>,
+    pub date: NaiveDate,
+    pub value: f64,
+}
+
+/// Change of every point against the previous point of the same analyte and
+/// unit. First points of a series have no entry.
+pub fn changes_by_key<K: Clone + Eq + Hash>(points: &[LabPoint<K>]) -> HashMap<K, LabChange> {
+    let mut groups: HashMap<(String, Option<String>), Vec<&LabPoint<K>>> = HashMap::new();
+    for p in points {
+        let unit = p.unit.as_deref().map(|u| u.trim().to_lowercase());
+        groups.entry((series_key(&p.test_name), unit)).or_default().push(p);
+    }
+
+    let mut changes = HashMap::new();
+    for group in groups.values_mut() {
+        group.sort_by_key(|p| p.date);
+        for pair in group.windows(2) {
+            let (prev, cur) = (pair[0], pair[1]);
+            let variation = find_variation(&cur.test_name);
+            if let Some(change) =
+                compare((prev.date, prev.value), cur.value, cur.unit.as_deref(), variation)
+            {
+                changes.insert(cur.key.clone(), change);
+            }
+        }
+    }
+    changes
+}
+
+/// [`changes_by_key`] over stored results, keyed by result ID.
+pub fn changes_by_result(results: &[LabResult]) -> HashMap<Uuid, LabChange> {
+    let points: Vec<LabPoint<Uuid>> = results
+        .iter()
+        .filter_map(|r| {
+            Some(LabPoint {
+                key: r.id,
+                test_name: r.test_name.clone(),
+                unit: r.unit.clone(),
+                date: r.collection_date,
+                value: r.value?,
+            })
+        })
+        .collect();
+    changes_by_key(&points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::enums::AbnormalFlag;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn lab(name: &str, value: f64, unit: &str, on: &str) -> LabResult {
+        LabResult {
+            id: Uuid::new_v4(),
+            test_name: name.into(),
+            test_code: None,
+            value: Some(value),
+            value_text: None,
+            unit: Some(unit.into()),
+            reference_range_low: None,
+            reference_range_high: None,
+            abnormal_flag: AbnormalFlag::Normal,
+            collection_date: date(on),
+            lab_facility: None,
+            ordering_physician_id: None,
+            document_id: Uuid::nil(),
+        }
+    }
+
+    #[test]
+    fn rcv_follows_formula() {
+        let potassium = find_variation("Potassium");
+        assert_eq!(potassium.test_key, "potassium");
+        let expected = 2f64.sqrt() * 1.96 * (1.5f64.powi(2) + 4.1f64.powi(2)).sqrt();
+        assert!((reference_change_value(potassium) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unknown_analyte_uses_default_variation() {
+        let v = find_variation("Lipase");
+        assert_eq!(v.test_key, "default");
+        assert_eq!(series_key("  Lipase "), "lipase");
+        assert_eq!(series_key("Créatinine"), "creatinine");
+    }
+
+    #[test]
+    fn small_change_within_rcv_not_significant() {
+        let results = [
+            lab("Potassium", 4.0, "mmol/L", "2025-06-01"),
+            lab("Potassium", 4.3, "mmol/L", "2025-12-01"),
+        ];
+        let refs: Vec<&LabResult> = results.iter().collect();
+        let trend = analyze_series(&refs).unwrap();
+        let change = trend.last_change.unwrap();
+        assert!((change.delta_pct - 7.5).abs() < 1e-9);
+        assert!(!change.significant); // RCV ≈ 12.1%
+        assert_eq!(trend.direction, TrendDirection::Stable);
+    }
+
+    #[test]
+    fn guideline_trigger_marks_hemoglobin_drop() {
+        // 14.5 → 12.3: -15.2%, above both the 8.2% RCV and the 2 g/dL drop
+        let results = [
+            lab("Hb", 14.5, "g/dL", "2025-09-01"),
+            lab("Hemoglobin", 12.3, "g/dL", "2025-11-15"),
+        ];
+        let changes = changes_by_result(&results);
+        assert!(changes[&results[1].id].significant);
+        assert!(!changes.contains_key(&results[0].id));
+    }
+
+    #[test]
+    fn hba1c_rise_uses_guideline_threshold() {
+        // +0.6 points is only ~9% — above RCV (≈6%) and the IDF 0.5 rise
+        let results = [
+            lab("HbA1c", 6.4, "%", "2025-01-10"),
+            lab("HbA1c", 7.0, "%", "2025-07-10"),
+        ];
+        let refs: Vec<&LabResult> = results.iter().collect();
+        assert!(analyze_series(&refs).unwrap().last_change.unwrap().significant);
+    }
+
+    #[test]
+    fn guideline_thresholds_follow_the_result_unit() {
+        // 140 → 137 g/L is a 0.3 g/dL drop, not the 2 g/dL trigger
+        let hb = [
+            lab("Hemoglobin", 140.0, "g/L", "2025-09-01"),
+            lab("Hemoglobin", 137.0, "g/L", "2025-11-15"),
+        ];
+        assert!(!changes_by_result(&hb)[&hb[1].id].significant);
+
+        // +0.6 mmol/mol is ~0.05 %, far below the 0.5 % rise
+        let hba1c = [
+            lab("HbA1c", 48.0, "mmol/mol", "2025-01-10"),
+            lab("HbA1c", 48.6, "mmol/mol", "2025-07-10"),
+        ];
+        let refs: Vec<&LabResult> = hba1c.iter().collect();
+        assert!(!analyze_series(&refs).unwrap().last_change.unwrap().significant);
+
+        assert_eq!(amount_in(2.0, "g/dL", Some("g/L")), Some(20.0));
+        assert_eq!(amount_in(0.5, "%", Some("mmol/mol")), Some(5.4645));
+        assert_eq!(amount_in(2.0, "g/dL", Some("mg/mL")), None);
+        assert_eq!(amount_in(2.0, "g/dL", None), None);
+    }
+
+    #[test]
+    fn slope_per_year_and_direction() {
+        let results = [
+            lab("Creatinine", 80.0, "µmol/L", "2024-01-01"),
+            lab("Creatinine", 90.0, "µmol/L", "2024-12-31"),
+            lab("Creatinine", 100.0, "µmol/L", "2025-12-31"),
+        ];
+        let refs: Vec<&LabResult> = results.iter().collect();
+        let trend = analyze_series(&refs).unwrap();
+        let slope = trend.slope_per_year.unwrap();
+        assert!((slope - 10.0).abs() < 0.1, "slope {slope}");
+        assert_eq!(trend.direction, TrendDirection::Rising);
+        assert!(!trend.reversal);
+    }
+
+    #[test]
+    fn reversal_detected_after_rising_trend() {
+        let results = [
+            lab("TSH", 1.0, "mIU/L", "2024-01-01"),
+            lab("TSH", 2.0, "mIU/L", "2024-07-01"),
+            lab("TSH", 3.0, "mIU/L", "2025-01-01"),
+            lab("TSH", 1.2, "mIU/L", "2025-07-01"),
+        ];
+        let refs: Vec<&LabResult> = results.iter().collect();
+        let trend = analyze_series(&refs).unwrap();
+        assert!(trend.last_change.as_ref().unwrap().significant);
+        assert!(trend.reversal);
+    }
+
+    #[test]
+    fn time_in_range_interpolates_between_results() {
+        let mut results = vec![
+            lab("Glucose", 100.0, "mg/dL", "2025-01-01"),
+            lab("Glucose", 140.0, "mg/dL", "2025-01-11"),
+        ];
+        results[1].reference_range_low = Some(70.0);
+        results[1].reference_range_high = Some(120.0);
+        let refs: Vec<&LabResult> = results.iter().collect();
+        let tir = analyze_series(&refs).unwrap().time_in_range_pct.unwrap();
+        assert!((tir - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn different_units_are_not_mixed() {
+        let results = [
+            lab("Glucose", 5.5, "mmol/L", "2025-01-01"),
+            lab("Glucose", 99.0, "mg/dL", "2025-02-01"),
+        ];
+        let refs: Vec<&LabResult> = results.iter().collect();
+        let trend = analyze_series(&refs).unwrap();
+        assert_eq!(trend.points, 1);
+        assert!(trend.last_change.is_none());
+        assert!(changes_by_result(&results).is_empty());
+    }
+
+    #[test]
+    fn analyze_all_groups_aliases() {
+        let results = vec![
+            lab("eGFR", 80.0, "mL/min/1.73m²", "2025-01-01"),
+            lab("DFG", 60.0, "mL/min/1.73m²", "2025-06-01"),
+            lab("Sodium", 140.0, "mmol/L", "2025-03-01"),
+        ];
+        let trends = analyze_all(&results);
+        assert_eq!(trends.len(), 2);
+        assert_eq!(trends[0].test_name, "DFG");
+        assert!(trends[0].last_change.as_ref().unwrap().significant);
+    }
+}
@@ -120,6 +120,8 @@ pub enum LoadError {
         path: String,
         source: serde_json::Error,
     },
+    #[error("Invalid growth table {path}: {reason}")]
+    Table { path: String, reason: String },
 }
 
 /// Load a JSON file from the invariants resource directory.
@@ -4,8 +4,9 @@
 //! grounded clinical insights — deterministic, computable, no SLM required.
 //!
 //! Two-tier storage:
-//! - **Const tier**: Vital sign and lab thresholds (compiled into binary)
-//! - **Bundled tier**: Drug families, interactions, cross-reactivity (JSON at startup)
+//! - **Const tier**: Vital sign and lab thresholds, lab biological variation (compiled into binary)
+//! - **Bundled tier**: Drug families, interactions, cross-reactivity (JSON at startup),
+//!   pediatric growth LMS tables (official WHO/CDC files at startup)
 //!
 //! All data sourced from international clinical guidelines
 //! (ISH, ESC, KDIGO, IDF, WHO, BTS, GLIM, WAO, EAACI, EASL, ETA, IOF).
@@ -13,10 +14,13 @@
 pub mod types;
 pub mod vitals;
 pub mod labs;
+pub mod lab_trends;
 pub mod loader;
 pub mod enrich;
 pub mod demographics;
 pub mod screening;
+pub mod immunization;
+pub mod growth;
 pub mod allergens;
 pub mod blood_types;
 
@@ -42,10 +46,12 @@ impl InvariantRegistry {
     /// Load the registry from the resources directory.
     ///
     /// Const tier is always available (compiled in).
-    /// Bundled tier loads from `resources/invariants/*.json`.
-    /// Missing JSON files are treated as empty (graceful degradation).
+    /// Bundled tier loads from `resources/invariants/*.json` and the
+    /// growth tables from `resources/growth/`.
+    /// Missing files are treated as empty (graceful degradation).
     pub fn load(resources_dir: &Path) -> Result<Self, LoadError> {
         let bundled = loader::load_bundled(resources_dir)?;
+        growth::load_references(resources_dir)?;
         Ok(Self { bundled })
     }
 
@@ -95,6 +101,11 @@ impl InvariantRegistry {
 
     // ── Bundled tier access ───────────────────────────────
 
+    /// Pediatric growth LMS references (WHO 2006 / CDC 2000).
+    pub fn growth_references(&self) -> &'static [growth::GrowthReference] {
+        growth::growth_references()
+    }
+
     /// Drug families (loaded from JSON).
     pub fn drug_families(&self) -> &[loader::DrugFamily] {
         &self.bundled.drug_families
@@ -426,6 +426,7 @@ mod tests {
             }),
             age_years: age,
             blood_type: None,
+            date_of_birth: None,
         }
     }
 
@@ -15,7 +15,11 @@ pub mod pipeline;
 pub mod intelligence; // L2-01 through L2-03: RAG + Safety + Coherence
 pub mod home; // L3-02: Home & Document Feed
 pub mod chat; // L3-03: Chat Interface
+pub mod chat_export; // EXP-01: Chat export with citations (PDF/Markdown)
+pub mod household; // HH-01: Caregiver household overview across unlocked profiles
 pub mod review; // L3-04: Review Screen
+pub mod sensitivity; // SENS-01: Sensitive-record tagging, hidden by default
+pub mod emergency_card; // EMC-01: Emergency medical ID card and lock-screen summary
 pub mod medications; // L3-05: Medication List
 pub mod journal; // L4-01: Symptom Journal
 pub mod appointment; // L4-02: Appointment Prep
@@ -32,6 +36,7 @@ pub mod ollama_service; // Centralized SLM access controller
 pub mod butler_service; // BTL-04: SLM lifecycle orchestrator
 pub mod import_queue; // BTL-10: Document import queue service
 pub mod import_queue_worker; // BTL-10 C4: Import queue background worker
+pub mod inbox_watcher; // INB-01: Watched inbox folder for automatic import
 pub mod chat_queue; // CHAT-QUEUE-01: Chat queue service
 pub mod chat_queue_worker; // CHAT-QUEUE-01: Chat queue background worker
 pub mod invariants; // ME-03: Invariant Reference Engine
@@ -54,7 +59,8 @@ pub fn run() {
 
     tracing::info!("Coheara starting v{}", config::APP_VERSION);
 
-    // SEC-02-G08: Clean orphaned staging files from previous crashes
+    // SEC-02-G08: Clean orphaned mobile upload buffers from previous crashes
+    // (IMQ-01: pending WiFi transfers are imported by the queue worker after unlock)
     crypto::cleanup_orphaned_staging(&config::profiles_dir());
 
     // ME-03: Resolve resources directory for invariant registry loading.
@@ -87,6 +93,9 @@ pub fn run() {
             // BTL-10 C4: Start import queue worker (processes queued jobs sequentially)
             import_queue_worker::start_import_queue_worker(app.handle().clone());
 
+            // INB-01: Poll the unlocked profile's inbox folder
+            inbox_watcher::start_inbox_watcher(app.handle().clone());
+
             // CHAT-QUEUE-01: Start chat queue worker (processes queued messages sequentially)
             chat_queue_worker::start_chat_queue_worker(app.handle().clone());
 
@@ -103,6 +112,12 @@ pub fn run() {
             commands::import_queue::cancel_import,
             commands::import_queue::retry_import,
             commands::import_queue::delete_import,
+            commands::import_queue::get_pdf_passwords,
+            commands::import_queue::add_pdf_password,
+            commands::import_queue::remove_pdf_password,
+            commands::inbox::get_inbox_config,
+            commands::inbox::set_inbox_config,
+            commands::inbox::get_inbox_activity,
             commands::profile::list_profiles,
             commands::profile::create_profile,
             commands::profile::unlock_profile,
@@ -113,6 +128,7 @@ pub fn run() {
             commands::profile::get_active_profile_name,
             commands::profile::get_active_profile_info,
             commands::profile::get_caregiver_summaries,
+            commands::household::get_household_overview,
             commands::profile::delete_profile,
             commands::profile::check_inactivity,
             commands::profile::update_activity,
@@ -135,6 +151,7 @@ pub fn run() {
             commands::allergy::get_allergen_references,
             commands::me::get_me_overview,
             commands::me::record_vital_sign,
+            commands::me::import_vitals,
             commands::me::record_screening,
             commands::me::delete_screening_record,
             commands::me::get_vital_trend,
@@ -147,11 +164,23 @@ pub fn run() {
             commands::chat::get_prompt_suggestions,
             commands::chat::get_chat_queue,
             commands::chat::get_chat_queue_for_conversation,
+            commands::chat::export_conversation,
             commands::review::get_review_data,
             commands::review::get_original_file,
             commands::review::update_extracted_field,
             commands::review::confirm_review,
             commands::review::reject_review,
+            // SENS-01: Sensitive-record tagging
+            commands::sensitivity::set_document_sensitivity,
+            commands::sensitivity::set_entity_sensitivity,
+            commands::sensitivity::set_conversation_sensitive_context,
+            commands::emergency_card::get_emergency_card,
+            commands::emergency_card::export_emergency_card_pdf,
+            commands::emergency_card::list_emergency_contacts,
+            commands::emergency_card::add_emergency_contact,
+            commands::emergency_card::remove_emergency_contact,
+            commands::emergency_card::get_lock_screen_fields,
+            commands::emergency_card::set_lock_screen_fields,
             commands::medications::get_medications,
             commands::medications::get_medication_detail,
             commands::medications::add_otc_medication,
@@ -168,6 +197,9 @@ pub fn run() {
             commands::appointment::export_prep_pdf,
             commands::appointment::save_appointment_notes,
             commands::appointment::list_appointments,
+            commands::appointment::attach_conversation_to_appointment,
+            commands::appointment::list_appointment_chat_attachments,
+            commands::appointment::detach_conversation_from_appointment,
             commands::timeline::get_timeline_data,
             commands::transfer::start_wifi_transfer,
             commands::transfer::stop_wifi_transfer,
@@ -199,6 +231,7 @@ pub fn run() {
             commands::companion_access::list_companion_profiles,
             commands::companion_access::grant_profile_access,
             commands::companion_access::revoke_profile_access,
+            commands::companion_access::set_device_profile_access,
             // MP-02: Data sharing grant queries
             commands::companion_access::list_my_grants,
             commands::companion_access::list_grants_to_me,
@@ -211,6 +244,7 @@ pub fn run() {
             commands::import::process_document,
             commands::import::process_documents_batch,
             commands::import::delete_document,
+            commands::import::merge_documents,
             commands::import::reprocess_document,
             commands::coherence::run_coherence_scan,
             commands::coherence::run_coherence_scan_document,
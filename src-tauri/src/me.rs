@@ -812,7 +812,7 @@ fn compute_screening_status(
     }
 }
 
-fn build_screening_info(
+pub(crate) fn build_screening_info(
     lang: &str,
     demographics: Option<&PatientDemographics>,
     records: &[ScreeningRecord],
@@ -1206,6 +1206,7 @@ mod tests {
             age_context: Some(AgeContext::Adult),
             age_years: Some(45),
             blood_type: None,
+            date_of_birth: None,
         };
         let ranges = build_reference_ranges("en", Some(&male_demo), &[], &[]);
         let hb = ranges.iter().find(|r| r.key == "hemoglobin").unwrap();
@@ -1226,6 +1227,7 @@ mod tests {
             age_context: Some(AgeContext::Adult),
             age_years: Some(35),
             blood_type: None,
+            date_of_birth: None,
         };
         let ranges = build_reference_ranges("en", Some(&asian_demo), &[], &[]);
         let bmi = ranges.iter().find(|r| r.key == "bmi").unwrap();
@@ -1280,6 +1282,7 @@ mod tests {
             age_context: Some(AgeContext::Adult),
             age_years: Some(39),
             blood_type: None,
+            date_of_birth: None,
         };
         let screenings = build_screening_info("en", Some(&demo), &[]);
         // Male sees: prostate, colorectal, AAA (3 cancer) + 8 vaccines = 11
@@ -1303,6 +1306,7 @@ mod tests {
             age_context: Some(AgeContext::Adult),
             age_years: Some(55),
             blood_type: None,
+            date_of_birth: None,
         };
         let screenings = build_screening_info("en", Some(&demo), &[]);
         // Female sees: mammography, cervical, colorectal, osteoporosis (4 cancer) + 8 vaccines = 12
@@ -1326,6 +1330,7 @@ mod tests {
             age_context: Some(AgeContext::Adult),
             age_years: Some(55),
             blood_type: None,
+            date_of_birth: None,
         };
         let screenings = build_screening_info("en", Some(&demo), &[]);
         assert_eq!(screenings.len(), 14);
@@ -1344,6 +1349,7 @@ mod tests {
             age_context: Some(AgeContext::Adult),
             age_years: Some(55),
             blood_type: None,
+            date_of_birth: None,
         };
         let screenings = build_screening_info("en", Some(&demo), &[]);
         // ME-04 B1: Female now sees 12 (4 cancer + 8 vaccines) — male schedules filtered
@@ -1576,6 +1582,7 @@ mod tests {
             age_context: Some(AgeContext::Adult),
             age_years: Some(55),
             blood_type: None,
+            date_of_birth: None,
         };
         let info = build_screening_info("en", Some(&demo), &[]);
         // Should have both cancer screenings and vaccines
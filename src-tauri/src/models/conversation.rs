@@ -22,3 +22,16 @@ pub struct Message {
     pub confidence: Option<f32>,
     pub feedback: Option<MessageFeedback>,
 }
+
+/// MEM-01: A statement the patient made about their own health in chat
+/// (e.g. "I stopped ibuprofen last week"), kept verbatim across summaries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PinnedFact {
+    pub id: Uuid,
+    pub conversation_id: Uuid,
+    pub message_id: Uuid,
+    pub fact: String,
+    /// Batch extraction domain ("medication", "symptom", ...), if any.
+    pub domain: Option<String>,
+    pub created_at: NaiveDateTime,
+}
@@ -47,6 +47,7 @@ str_enum!(EntityType {
     Allergy => "Allergy",
     Procedure => "Procedure",
     Referral => "Referral",
+    Immunization => "Immunization",
 });
 
 str_enum!(RelationshipType {
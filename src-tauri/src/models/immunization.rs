@@ -0,0 +1,18 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// IMM-01: One administered vaccine dose.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Immunization {
+    pub id: Uuid,
+    pub vaccine: String,
+    /// Position in the vaccine's series (1-based), when written on the record.
+    pub dose_number: Option<u32>,
+    pub administered_date: Option<NaiveDate>,
+    pub lot_number: Option<String>,
+    /// Injection site (e.g., "left deltoid").
+    pub site: Option<String>,
+    pub administering_professional_id: Option<Uuid>,
+    pub document_id: Uuid,
+}
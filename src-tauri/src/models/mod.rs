@@ -6,6 +6,7 @@ pub mod professional;
 pub mod symptom;
 pub mod allergy;
 pub mod procedure;
+pub mod immunization;
 pub mod appointment;
 pub mod conversation;
 pub mod referral;
@@ -17,6 +18,7 @@ pub mod vital_sign;
 pub mod cached_explanation;
 pub mod caregiver;
 pub mod entity_connection;
+pub mod provenance;
 
 pub use document::*;
 pub use medication::*;
@@ -26,6 +28,7 @@ pub use professional::*;
 pub use symptom::*;
 pub use allergy::*;
 pub use procedure::*;
+pub use immunization::*;
 pub use appointment::*;
 pub use conversation::*;
 pub use referral::*;
@@ -36,3 +39,4 @@ pub use vital_sign::*;
 pub use cached_explanation::*;
 pub use caregiver::*;
 pub use entity_connection::*;
+pub use provenance::*;
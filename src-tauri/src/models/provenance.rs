@@ -0,0 +1,114 @@
+//! PROV-01: Field-level provenance types.
+//!
+//! Links an extracted value back to where it was read in the source document:
+//! the page, an approximate region on that page, and (for text-layer sources)
+//! the character span in the page text.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::entity_connection::EntityType;
+
+/// Page-relative rectangle. All values are fractions of the page size
+/// (0.0–1.0) with the origin at the top-left, so the frontend can scale the
+/// region onto whatever rendering of the page it displays.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SourceRegion {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl SourceRegion {
+    /// Clamp all coordinates into the unit square.
+    pub fn clamped(self) -> Self {
+        let x = self.x.clamp(0.0, 1.0);
+        let y = self.y.clamp(0.0, 1.0);
+        Self {
+            x,
+            y,
+            width: self.width.clamp(0.0, 1.0 - x),
+            height: self.height.clamp(0.0, 1.0 - y),
+        }
+    }
+}
+
+/// Character offsets (start inclusive, end exclusive) into a page's text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Where on the source document a value was read.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// 1-indexed page number (matches `PageExtraction::page_number`).
+    pub page_number: usize,
+    /// Region on the page, when one could be determined.
+    pub region: Option<SourceRegion>,
+    /// `true` when the region is estimated from the text position rather than
+    /// measured from the PDF text layer.
+    pub region_approximate: bool,
+    /// Span in the page text (digital PDFs and plain text).
+    pub text_span: Option<TextSpan>,
+    /// The matched text, for display next to the highlight.
+    pub quote: Option<String>,
+}
+
+/// Provenance of one field of a not-yet-stored entity.
+///
+/// Entities are addressed by category + index into `ExtractedEntities`,
+/// matching the addressing used by the review screen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldSource {
+    pub entity_type: EntityType,
+    pub entity_index: usize,
+    pub field_name: String,
+    pub location: SourceLocation,
+}
+
+/// Persisted provenance of one field of a stored entity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntitySource {
+    pub id: Uuid,
+    pub document_id: Uuid,
+    pub entity_type: EntityType,
+    pub entity_id: Uuid,
+    pub field_name: String,
+    pub location: SourceLocation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn region_clamped_into_unit_square() {
+        let r = SourceRegion { x: -0.2, y: 0.9, width: 2.0, height: 0.5 }.clamped();
+        assert_eq!(r.x, 0.0);
+        assert_eq!(r.y, 0.9);
+        assert_eq!(r.width, 1.0);
+        assert!((r.height - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn field_source_serde_roundtrip() {
+        let fs = FieldSource {
+            entity_type: EntityType::LabResult,
+            entity_index: 2,
+            field_name: "value".into(),
+            location: SourceLocation {
+                page_number: 3,
+                region: Some(SourceRegion { x: 0.1, y: 0.4, width: 0.5, height: 0.02 }),
+                region_approximate: false,
+                text_span: Some(TextSpan { start: 10, end: 14 }),
+                quote: Some("5.1".into()),
+            },
+        };
+        let json = serde_json::to_string(&fs).unwrap();
+        let back: FieldSource = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, fs);
+    }
+}
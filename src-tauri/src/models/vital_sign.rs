@@ -13,6 +13,8 @@ pub enum VitalType {
     HeartRate,
     BloodGlucose,
     OxygenSaturation,
+    /// Occipitofrontal head circumference (pediatric growth monitoring).
+    HeadCircumference,
 }
 
 impl VitalType {
@@ -25,6 +27,7 @@ impl VitalType {
             VitalType::HeartRate => "heart_rate",
             VitalType::BloodGlucose => "blood_glucose",
             VitalType::OxygenSaturation => "oxygen_saturation",
+            VitalType::HeadCircumference => "head_circumference",
         }
     }
 
@@ -37,6 +40,7 @@ impl VitalType {
             "heart_rate" => Some(VitalType::HeartRate),
             "blood_glucose" => Some(VitalType::BloodGlucose),
             "oxygen_saturation" => Some(VitalType::OxygenSaturation),
+            "head_circumference" => Some(VitalType::HeadCircumference),
             _ => None,
         }
     }
@@ -51,6 +55,7 @@ impl VitalType {
             VitalType::HeartRate => "bpm",
             VitalType::BloodGlucose => "mg/dL",
             VitalType::OxygenSaturation => "%",
+            VitalType::HeadCircumference => "cm",
         }
     }
 }
@@ -88,6 +93,21 @@ impl VitalSource {
 pub struct VitalTrendPoint {
     pub value: f64,
     pub recorded_at: String,
+    /// GRW-01: Growth-chart position, set for child profiles only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub growth: Option<GrowthPercentile>,
+}
+
+/// GRW-01: Position of a pediatric measurement on the WHO/CDC growth chart,
+/// with the 3rd/50th/97th reference curves at the same age for plotting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrowthPercentile {
+    pub age_months: f64,
+    pub z_score: f64,
+    pub percentile: f64,
+    pub p3: f64,
+    pub p50: f64,
+    pub p97: f64,
 }
 
 /// A single vital sign measurement.
@@ -36,6 +36,8 @@ pub enum OperationKind {
     BatchExtraction,
     /// Health/capability verification (test generation)
     ModelVerification,
+    /// MEM-01: Idle-time conversation summary refresh
+    ConversationSummary,
 }
 
 impl std::fmt::Display for OperationKind {
@@ -46,6 +48,7 @@ impl std::fmt::Display for OperationKind {
             Self::ChatGeneration => write!(f, "Chat generation"),
             Self::BatchExtraction => write!(f, "Batch extraction"),
             Self::ModelVerification => write!(f, "Model verification"),
+            Self::ConversationSummary => write!(f, "Conversation summary"),
         }
     }
 }
@@ -314,6 +317,7 @@ mod tests {
         assert_eq!(OperationKind::ChatGeneration.to_string(), "Chat generation");
         assert_eq!(OperationKind::BatchExtraction.to_string(), "Batch extraction");
         assert_eq!(OperationKind::ModelVerification.to_string(), "Model verification");
+        assert_eq!(OperationKind::ConversationSummary.to_string(), "Conversation summary");
     }
 
     #[test]
@@ -439,6 +439,7 @@ mod tests {
                 .and_hms_opt(10, 0, 0)
                 .unwrap(),
             message_count: count,
+            pinned_facts: vec![],
         }
     }
 
@@ -50,7 +50,8 @@ impl BatchRunner {
         let start = Instant::now();
 
         // Step 1: Analyze conversation for domains
-        let analysis = self.analyzer.analyze(conversation);
+        let mut analysis = self.analyzer.analyze(conversation);
+        analysis.apply_pinned_signals(conversation);
 
         if analysis.is_pure_qa || analysis.domains.is_empty() {
             return Ok(ConversationExtractionResult {
@@ -395,6 +396,7 @@ mod tests {
                 .and_hms_opt(10, 0, 0)
                 .unwrap(),
             message_count: msg_count,
+            pinned_facts: vec![],
         }
     }
 
@@ -74,6 +74,7 @@ impl BatchScheduler for SqliteBatchScheduler {
 
             // Load messages for this conversation
             let messages = self.load_messages(conn, &row.id)?;
+            let pinned_facts = self.load_pinned_signals(conn, &row.id)?;
 
             let last_message_at = NaiveDateTime::parse_from_str(
                 &row.last_message_at,
@@ -86,6 +87,7 @@ impl BatchScheduler for SqliteBatchScheduler {
                 messages,
                 last_message_at,
                 message_count: row.message_count,
+                pinned_facts,
             });
         }
 
@@ -166,6 +168,38 @@ impl SqliteBatchScheduler {
 
         Ok(messages)
     }
+
+    /// MEM-01: Pinned chat facts that map to an extraction domain.
+    fn load_pinned_signals(
+        &self,
+        conn: &Connection,
+        conversation_id: &str,
+    ) -> Result<Vec<PinnedSignal>, ExtractionError> {
+        let mut stmt = conn.prepare(
+            "SELECT message_id, domain, fact
+             FROM pinned_facts
+             WHERE conversation_id = ?1 AND domain IS NOT NULL
+             ORDER BY created_at ASC"
+        ).map_err(|e| ExtractionError::Database(DatabaseError::Sqlite(e)))?;
+
+        let rows = stmt.query_map(params![conversation_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        }).map_err(|e| ExtractionError::Database(DatabaseError::Sqlite(e)))?;
+
+        let mut signals = Vec::new();
+        for row in rows {
+            let (message_id, domain, fact) =
+                row.map_err(|e| ExtractionError::Database(DatabaseError::Sqlite(e)))?;
+            if let Some(domain) = ExtractionDomain::from_str(&domain) {
+                signals.push(PinnedSignal { message_id, domain, fact });
+            }
+        }
+        Ok(signals)
+    }
 }
 
 struct ConversationRow {
@@ -232,6 +266,36 @@ mod tests {
         assert_eq!(eligible[0].message_count, 2);
     }
 
+    #[test]
+    fn loads_pinned_signals_with_domain() {
+        let conn = setup_db();
+        let scheduler = SqliteBatchScheduler::new();
+        insert_conversation(&conn, "conv-pin", &[
+            ("patient", "I stopped ibuprofen last week", "2026-02-19 10:00:00"),
+            ("coheara", "Noted", "2026-02-19 10:01:00"),
+        ]);
+        let msg_id: String = conn.query_row(
+            "SELECT id FROM messages WHERE conversation_id = 'conv-pin' AND role = 'patient'",
+            [],
+            |row| row.get(0),
+        ).unwrap();
+        for (fact, domain) in [("I stopped ibuprofen last week", Some("medication")), ("I'm allergic to latex", None)] {
+            conn.execute(
+                "INSERT INTO pinned_facts (id, conversation_id, message_id, fact, domain, created_at)
+                 VALUES (?1, 'conv-pin', ?2, ?3, ?4, '2026-02-19 10:00:00')",
+                params![Uuid::new_v4().to_string(), msg_id, fact, domain],
+            ).unwrap();
+        }
+
+        let eligible = scheduler
+            .get_eligible_conversations(&conn, &ExtractionConfig::default())
+            .unwrap();
+        let pins = &eligible[0].pinned_facts;
+        assert_eq!(pins.len(), 1);
+        assert_eq!(pins[0].domain, ExtractionDomain::Medication);
+        assert_eq!(pins[0].message_id, msg_id);
+    }
+
     #[test]
     fn skips_too_recent_conversation() {
         let conn = setup_db();
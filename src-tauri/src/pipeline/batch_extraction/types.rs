@@ -64,6 +64,16 @@ pub struct ConversationBatch {
     pub messages: Vec<ConversationMessage>,
     pub last_message_at: NaiveDateTime,
     pub message_count: u32,
+    /// MEM-01: Health statements pinned in chat, offered as explicit signals.
+    pub pinned_facts: Vec<PinnedSignal>,
+}
+
+/// MEM-01: A patient statement pinned while chatting, tied to its message.
+#[derive(Debug, Clone)]
+pub struct PinnedSignal {
+    pub message_id: String,
+    pub domain: ExtractionDomain,
+    pub fact: String,
 }
 
 /// A single message within a conversation batch.
@@ -102,6 +112,36 @@ pub struct DomainMatch {
 }
 
 impl AnalysisResult {
+    /// MEM-01: Merge pinned facts into the analysis. A pinned message is a
+    /// signal for its domain even when the keyword analyzer missed it.
+    pub fn apply_pinned_signals(&mut self, conversation: &ConversationBatch) {
+        for pin in &conversation.pinned_facts {
+            let Some(idx) = conversation
+                .messages
+                .iter()
+                .position(|m| m.id == pin.message_id)
+            else {
+                continue;
+            };
+
+            match self.domains.iter_mut().find(|d| d.domain == pin.domain) {
+                Some(domain_match) => {
+                    if !domain_match.signal_message_indices.contains(&idx) {
+                        domain_match.signal_message_indices.push(idx);
+                        domain_match.signal_message_indices.sort_unstable();
+                    }
+                    domain_match.detection_confidence = domain_match.detection_confidence.max(0.9);
+                }
+                None => self.domains.push(DomainMatch {
+                    domain: pin.domain,
+                    signal_message_indices: vec![idx],
+                    detection_confidence: 0.9,
+                }),
+            }
+            self.is_pure_qa = false;
+        }
+    }
+
     /// Build an ExtractionInput for a specific domain match.
     pub fn build_input(
         &self,
@@ -124,10 +164,18 @@ impl AnalysisResult {
         selected_indices.sort_unstable();
         selected_indices.dedup();
 
+        // MEM-01: Pinned messages are explicit signals ([S] in prompts, kept on trim)
         let messages: Vec<ConversationMessage> = selected_indices
             .iter()
             .filter_map(|&idx| conversation.messages.get(idx))
-            .cloned()
+            .map(|m| {
+                let mut m = m.clone();
+                m.is_signal |= conversation
+                    .pinned_facts
+                    .iter()
+                    .any(|p| p.domain == domain_match.domain && p.message_id == m.id);
+                m
+            })
             .collect();
 
         ExtractionInput {
@@ -876,6 +924,7 @@ mod tests {
                 .and_hms_opt(10, 0, 0)
                 .unwrap(),
             message_count: 5,
+            pinned_facts: vec![],
         };
 
         let analysis = AnalysisResult {
@@ -924,6 +973,7 @@ mod tests {
                 .and_hms_opt(10, 0, 0)
                 .unwrap(),
             message_count: 3,
+            pinned_facts: vec![],
         };
 
         let analysis = AnalysisResult {
@@ -971,6 +1021,7 @@ mod tests {
                 .and_hms_opt(10, 0, 0)
                 .unwrap(),
             message_count: 3,
+            pinned_facts: vec![],
         };
 
         let analysis = AnalysisResult {
@@ -1018,6 +1069,7 @@ mod tests {
                 .and_hms_opt(10, 0, 0)
                 .unwrap(),
             message_count: 5,
+            pinned_facts: vec![],
         };
 
         let analysis = AnalysisResult {
@@ -1044,4 +1096,60 @@ mod tests {
         assert_eq!(input.messages[2].id, "msg-2");
         assert_eq!(input.messages[3].id, "msg-3");
     }
+
+    #[test]
+    fn pinned_signals_add_domain_and_mark_messages() {
+        let conversation = ConversationBatch {
+            id: "conv-1".to_string(),
+            title: None,
+            messages: (0..4)
+                .map(|i| ConversationMessage {
+                    id: format!("msg-{i}"),
+                    index: i,
+                    role: "patient".to_string(),
+                    content: format!("Message {i}"),
+                    created_at: chrono::NaiveDate::from_ymd_opt(2026, 2, 20)
+                        .unwrap()
+                        .and_hms_opt(10, 0, 0)
+                        .unwrap(),
+                    is_signal: false,
+                })
+                .collect(),
+            last_message_at: chrono::NaiveDate::from_ymd_opt(2026, 2, 20)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap(),
+            message_count: 4,
+            pinned_facts: vec![PinnedSignal {
+                message_id: "msg-2".to_string(),
+                domain: ExtractionDomain::Medication,
+                fact: "I stopped ibuprofen last week".to_string(),
+            }],
+        };
+
+        let mut analysis = AnalysisResult {
+            domains: vec![],
+            is_pure_qa: true,
+        };
+        analysis.apply_pinned_signals(&conversation);
+
+        assert!(!analysis.is_pure_qa);
+        assert_eq!(analysis.domains.len(), 1);
+        assert_eq!(analysis.domains[0].domain, ExtractionDomain::Medication);
+        assert_eq!(analysis.domains[0].signal_message_indices, vec![2]);
+
+        let input = analysis.build_input(
+            &conversation,
+            &analysis.domains[0],
+            PatientContext::default(),
+            chrono::NaiveDate::from_ymd_opt(2026, 2, 20).unwrap(),
+        );
+        let signals: Vec<&str> = input
+            .messages
+            .iter()
+            .filter(|m| m.is_signal)
+            .map(|m| m.id.as_str())
+            .collect();
+        assert_eq!(signals, vec!["msg-2"]);
+    }
 }
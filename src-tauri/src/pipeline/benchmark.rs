@@ -0,0 +1,1103 @@
+//! BEN-01: Extraction accuracy benchmark over golden documents.
+//!
+//! A corpus is a directory of source documents, each paired with a
+//! `<stem>.expected.json` file holding the `ExtractedEntities` a correct
+//! extraction produces. Expected files may be partial: only fields present
+//! and non-null are scored, and `confidence` is never scored.
+//!
+//! Documents run through the real `DocumentProcessor` inside a throwaway
+//! profile, against live Ollama (optionally recording a cassette) or a
+//! replay cassette. The report carries per-domain precision/recall,
+//! field-level accuracy, degeneration rate and timing, and can be diffed
+//! against a saved baseline report.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::pipeline::model_router::{ExtractionStrategy, PipelineAssignment, ProcessingMode};
+use crate::pipeline::processor::{build_processor_with_clients, PipelineClients};
+use crate::pipeline::structuring::cassette::{Cassette, CassetteClient, CassetteError};
+use crate::pipeline::structuring::ollama_types::OllamaError;
+use crate::pipeline::structuring::types::{
+    ExtractedEntities, LlmClient, VisionCallParams, VisionClient,
+};
+use crate::pipeline::structuring::StructuringError;
+
+/// Report format version — bump when fields change meaning.
+pub const REPORT_VERSION: u32 = 1;
+
+/// Score drops smaller than this are noise, not regressions.
+const REGRESSION_TOLERANCE: f64 = 0.005;
+
+/// Suffix of the golden file paired with each corpus document.
+const EXPECTED_SUFFIX: &str = ".expected.json";
+
+/// Domain name → fields that identify an item (any match pairs two items).
+const DOMAINS: &[(&str, &[&str])] = &[
+    ("medications", &["generic_name", "brand_name"]),
+    ("lab_results", &["test_name"]),
+    ("diagnoses", &["name"]),
+    ("allergies", &["allergen"]),
+    ("procedures", &["name"]),
+    ("referrals", &["referred_to"]),
+    ("instructions", &["text"]),
+    ("immunizations", &["vaccine"]),
+];
+
+/// Fields excluded from field-level scoring.
+const UNSCORED_FIELDS: &[&str] = &["confidence"];
+
+#[derive(Error, Debug)]
+pub enum BenchmarkError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Cassette error: {0}")]
+    Cassette(#[from] CassetteError),
+
+    #[error("Corpus error: {0}")]
+    Corpus(String),
+
+    #[error("Benchmark profile error: {0}")]
+    Profile(String),
+}
+
+// ═══════════════════════════════════════════════════════════
+// Options
+// ═══════════════════════════════════════════════════════════
+
+/// Where inference responses come from.
+#[derive(Debug, Clone)]
+pub enum BenchmarkBackend {
+    /// Live Ollama (from `OLLAMA_HOST`), optionally recording a cassette.
+    Ollama {
+        num_ctx: Option<u32>,
+        record_to: Option<PathBuf>,
+    },
+    /// Offline replay of a cassette recorded earlier.
+    Replay { cassette: PathBuf },
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchmarkOptions {
+    pub corpus_dir: PathBuf,
+    pub structuring_model: String,
+    /// Required for images and scanned PDFs; digital PDFs use it when set.
+    pub vision_model: Option<String>,
+    pub language: String,
+    pub backend: BenchmarkBackend,
+}
+
+// ═══════════════════════════════════════════════════════════
+// Report
+// ═══════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DomainScore {
+    pub expected: usize,
+    pub extracted: usize,
+    pub matched: usize,
+    /// matched / extracted (1.0 when nothing was extracted).
+    pub precision: f64,
+    /// matched / expected (1.0 when nothing was expected).
+    pub recall: f64,
+}
+
+impl DomainScore {
+    fn add(&mut self, other: &DomainScore) {
+        self.expected += other.expected;
+        self.extracted += other.extracted;
+        self.matched += other.matched;
+        self.finalize();
+    }
+
+    fn finalize(&mut self) {
+        self.precision = ratio(self.matched, self.extracted);
+        self.recall = ratio(self.matched, self.expected);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldScore {
+    pub compared: usize,
+    pub correct: usize,
+    pub accuracy: f64,
+}
+
+impl FieldScore {
+    fn record(&mut self, correct: bool) {
+        self.compared += 1;
+        if correct {
+            self.correct += 1;
+        }
+        self.accuracy = ratio(self.correct, self.compared);
+    }
+
+    fn add(&mut self, other: &FieldScore) {
+        self.compared += other.compared;
+        self.correct += other.correct;
+        self.accuracy = ratio(self.correct, self.compared);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DegenerationStats {
+    pub calls: usize,
+    pub degenerations: usize,
+    pub rate: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimingStats {
+    pub total_ms: u64,
+    pub mean_ms: f64,
+    pub max_ms: u64,
+}
+
+/// Scores for one corpus document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocumentScore {
+    pub domains: BTreeMap<String, DomainScore>,
+    /// Keyed `domain.field`.
+    pub fields: BTreeMap<String, FieldScore>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentResult {
+    pub name: String,
+    pub elapsed_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub degeneration: DegenerationStats,
+    pub score: DocumentScore,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    pub version: u32,
+    pub generated_at: String,
+    pub structuring_model: String,
+    pub vision_model: Option<String>,
+    pub language: String,
+    pub failed_documents: usize,
+    pub domains: BTreeMap<String, DomainScore>,
+    pub field_accuracy: FieldScore,
+    pub fields: BTreeMap<String, FieldScore>,
+    pub degeneration: DegenerationStats,
+    pub timing: TimingStats,
+    pub documents: Vec<DocumentResult>,
+}
+
+impl BenchmarkReport {
+    /// Aggregate per-document results into corpus-level scores.
+    pub fn from_documents(options: &BenchmarkOptions, documents: Vec<DocumentResult>) -> Self {
+        let mut domains: BTreeMap<String, DomainScore> = BTreeMap::new();
+        let mut fields: BTreeMap<String, FieldScore> = BTreeMap::new();
+        let mut field_accuracy = FieldScore::default();
+        let mut degeneration = DegenerationStats::default();
+        let mut timing = TimingStats::default();
+
+        for doc in &documents {
+            for (name, score) in &doc.score.domains {
+                domains.entry(name.clone()).or_default().add(score);
+            }
+            for (name, score) in &doc.score.fields {
+                fields.entry(name.clone()).or_default().add(score);
+                field_accuracy.add(score);
+            }
+            degeneration.calls += doc.degeneration.calls;
+            degeneration.degenerations += doc.degeneration.degenerations;
+            timing.total_ms += doc.elapsed_ms;
+            timing.max_ms = timing.max_ms.max(doc.elapsed_ms);
+        }
+        degeneration.rate = ratio_or_zero(degeneration.degenerations, degeneration.calls);
+        if !documents.is_empty() {
+            timing.mean_ms = timing.total_ms as f64 / documents.len() as f64;
+        }
+
+        Self {
+            version: REPORT_VERSION,
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            structuring_model: options.structuring_model.clone(),
+            vision_model: options.vision_model.clone(),
+            language: options.language.clone(),
+            failed_documents: documents.iter().filter(|d| d.error.is_some()).count(),
+            domains,
+            field_accuracy,
+            fields,
+            degeneration,
+            timing,
+            documents,
+        }
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        1.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+fn ratio_or_zero(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// Scoring
+// ═══════════════════════════════════════════════════════════
+
+/// Score extracted entities against a golden `ExtractedEntities` JSON value.
+///
+/// Items pair greedily within a domain on any identity field (case- and
+/// whitespace-insensitive). Unpaired expected items cost recall, unpaired
+/// extracted items cost precision; paired items are compared field by field.
+pub fn score_document(expected: &Value, actual: &ExtractedEntities) -> DocumentScore {
+    let actual = serde_json::to_value(actual).unwrap_or(Value::Null);
+    let mut score = DocumentScore::default();
+
+    for (domain, identity_fields) in DOMAINS {
+        let expected_items = items(expected, domain);
+        let actual_items = items(&actual, domain);
+        let mut used = vec![false; actual_items.len()];
+        let mut domain_score = DomainScore {
+            expected: expected_items.len(),
+            extracted: actual_items.len(),
+            ..Default::default()
+        };
+
+        for expected_item in &expected_items {
+            let wanted = identities(expected_item, identity_fields);
+            let found = actual_items.iter().enumerate().position(|(i, item)| {
+                !used[i] && identities(item, identity_fields).iter().any(|id| wanted.contains(id))
+            });
+            let Some(index) = found else { continue };
+            used[index] = true;
+            domain_score.matched += 1;
+            score_fields(&mut score.fields, domain, expected_item, actual_items[index]);
+        }
+
+        domain_score.finalize();
+        score.domains.insert(domain.to_string(), domain_score);
+    }
+
+    // Blood type is a scalar — scored as a zero-or-one item domain.
+    let expected_bt = expected.get("blood_type").filter(|v| !v.is_null());
+    let actual_bt = actual.get("blood_type").filter(|v| !v.is_null());
+    let mut blood_type = DomainScore {
+        expected: usize::from(expected_bt.is_some()),
+        extracted: usize::from(actual_bt.is_some()),
+        matched: usize::from(matches!((expected_bt, actual_bt), (Some(e), Some(a)) if values_match(e, a))),
+        ..Default::default()
+    };
+    blood_type.finalize();
+    score.domains.insert("blood_type".to_string(), blood_type);
+
+    score
+}
+
+fn items<'a>(entities: &'a Value, domain: &str) -> Vec<&'a Value> {
+    entities
+        .get(domain)
+        .and_then(Value::as_array)
+        .map(|a| a.iter().collect())
+        .unwrap_or_default()
+}
+
+fn identities(item: &Value, fields: &[&str]) -> Vec<String> {
+    fields
+        .iter()
+        .filter_map(|f| item.get(*f).and_then(Value::as_str))
+        .map(normalize_text)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn score_fields(
+    fields: &mut BTreeMap<String, FieldScore>,
+    domain: &str,
+    expected: &Value,
+    actual: &Value,
+) {
+    let Some(expected) = expected.as_object() else { return };
+    for (field, expected_value) in expected {
+        if UNSCORED_FIELDS.contains(&field.as_str()) || is_blank(expected_value) {
+            continue;
+        }
+        let correct = actual
+            .get(field)
+            .is_some_and(|actual_value| values_match(expected_value, actual_value));
+        fields
+            .entry(format!("{domain}.{field}"))
+            .or_default()
+            .record(correct);
+    }
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(a) => a.is_empty(),
+        _ => false,
+    }
+}
+
+fn values_match(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::String(e), Value::String(a)) => normalize_text(e) == normalize_text(a),
+        (Value::Number(e), Value::Number(a)) => match (e.as_f64(), a.as_f64()) {
+            (Some(e), Some(a)) => (e - a).abs() <= 1e-6 * e.abs().max(1.0),
+            _ => false,
+        },
+        (Value::Array(e), Value::Array(a)) => {
+            e.len() == a.len() && e.iter().zip(a).all(|(e, a)| values_match(e, a))
+        }
+        (Value::Object(e), Value::Object(a)) => e
+            .iter()
+            .filter(|(k, v)| !UNSCORED_FIELDS.contains(&k.as_str()) && !is_blank(v))
+            .all(|(k, v)| a.get(k).is_some_and(|av| values_match(v, av))),
+        _ => expected == actual,
+    }
+}
+
+fn normalize_text(s: &str) -> String {
+    s.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// ═══════════════════════════════════════════════════════════
+// Baseline diff
+// ═══════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Delta {
+    pub baseline: f64,
+    pub current: f64,
+    pub change: f64,
+}
+
+impl Delta {
+    fn new(baseline: f64, current: f64) -> Self {
+        Self {
+            baseline,
+            current,
+            change: current - baseline,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainDelta {
+    pub precision: Delta,
+    pub recall: Delta,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineDiff {
+    pub domains: BTreeMap<String, DomainDelta>,
+    pub field_accuracy: Delta,
+    pub fields: BTreeMap<String, Delta>,
+    pub degeneration_rate: Delta,
+    pub mean_ms: Delta,
+    /// Human-readable descriptions of every score that got worse.
+    pub regressions: Vec<String>,
+}
+
+impl BaselineDiff {
+    pub fn has_regressions(&self) -> bool {
+        !self.regressions.is_empty()
+    }
+}
+
+/// Compare a run against a saved baseline report.
+///
+/// Lower precision, recall or field accuracy, a higher degeneration rate and
+/// newly failing documents count as regressions. Timing is reported only —
+/// it varies too much between machines to gate on.
+pub fn diff_against_baseline(baseline: &BenchmarkReport, current: &BenchmarkReport) -> BaselineDiff {
+    let mut regressions = Vec::new();
+    let mut domains = BTreeMap::new();
+
+    let names: std::collections::BTreeSet<&String> =
+        baseline.domains.keys().chain(current.domains.keys()).collect();
+    for name in names {
+        let before = baseline.domains.get(name).cloned().unwrap_or_default();
+        let after = current.domains.get(name).cloned().unwrap_or_default();
+        let delta = DomainDelta {
+            precision: Delta::new(before.precision, after.precision),
+            recall: Delta::new(before.recall, after.recall),
+        };
+        if delta.precision.change < -REGRESSION_TOLERANCE {
+            regressions.push(format!(
+                "{name} precision {:.3} → {:.3}",
+                before.precision, after.precision
+            ));
+        }
+        if delta.recall.change < -REGRESSION_TOLERANCE {
+            regressions.push(format!("{name} recall {:.3} → {:.3}", before.recall, after.recall));
+        }
+        domains.insert(name.clone(), delta);
+    }
+
+    let mut fields = BTreeMap::new();
+    for (name, after) in &current.fields {
+        if let Some(before) = baseline.fields.get(name) {
+            fields.insert(name.clone(), Delta::new(before.accuracy, after.accuracy));
+        }
+    }
+
+    let field_accuracy = Delta::new(baseline.field_accuracy.accuracy, current.field_accuracy.accuracy);
+    if field_accuracy.change < -REGRESSION_TOLERANCE {
+        regressions.push(format!(
+            "field accuracy {:.3} → {:.3}",
+            field_accuracy.baseline, field_accuracy.current
+        ));
+    }
+
+    let degeneration_rate = Delta::new(baseline.degeneration.rate, current.degeneration.rate);
+    if degeneration_rate.change > REGRESSION_TOLERANCE {
+        regressions.push(format!(
+            "degeneration rate {:.3} → {:.3}",
+            degeneration_rate.baseline, degeneration_rate.current
+        ));
+    }
+
+    for doc in current.documents.iter().filter(|d| d.error.is_some()) {
+        let failed_before = baseline
+            .documents
+            .iter()
+            .any(|b| b.name == doc.name && b.error.is_some());
+        if !failed_before {
+            regressions.push(format!("{} now fails", doc.name));
+        }
+    }
+
+    BaselineDiff {
+        domains,
+        field_accuracy,
+        fields,
+        degeneration_rate,
+        mean_ms: Delta::new(baseline.timing.mean_ms, current.timing.mean_ms),
+        regressions,
+    }
+}
+
+/// Plain-text rendering of a baseline diff for terminals and CI logs.
+pub fn render_diff(diff: &BaselineDiff) -> String {
+    fn line(out: &mut String, label: &str, d: &Delta) {
+        out.push_str(&format!(
+            "{label:<28} {:>8.3} → {:>8.3}  ({:+.3})\n",
+            d.baseline, d.current, d.change
+        ));
+    }
+
+    let mut out = String::new();
+    for (name, delta) in &diff.domains {
+        line(&mut out, &format!("{name} precision"), &delta.precision);
+        line(&mut out, &format!("{name} recall"), &delta.recall);
+    }
+    line(&mut out, "field accuracy", &diff.field_accuracy);
+    line(&mut out, "degeneration rate", &diff.degeneration_rate);
+    line(&mut out, "mean ms/document", &diff.mean_ms);
+    if diff.regressions.is_empty() {
+        out.push_str("No regressions.\n");
+    } else {
+        out.push_str(&format!("{} regression(s):\n", diff.regressions.len()));
+        for regression in &diff.regressions {
+            out.push_str(&format!("  - {regression}\n"));
+        }
+    }
+    out
+}
+
+// ═══════════════════════════════════════════════════════════
+// Corpus
+// ═══════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldenDocument {
+    pub name: String,
+    pub document: PathBuf,
+    pub expected: PathBuf,
+}
+
+/// Pair each document in `dir` with its `<stem>.expected.json`, sorted by name.
+/// Documents without a golden file are skipped with a warning.
+pub fn discover_corpus(dir: &Path) -> Result<Vec<GoldenDocument>, BenchmarkError> {
+    let mut corpus = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else { continue };
+        if !path.is_file() || file_name.ends_with(EXPECTED_SUFFIX) || file_name.starts_with('.') {
+            continue;
+        }
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or(file_name);
+        let expected = dir.join(format!("{stem}{EXPECTED_SUFFIX}"));
+        if !expected.is_file() {
+            tracing::warn!(document = %file_name, "BEN-01: No golden file, skipping");
+            continue;
+        }
+        corpus.push(GoldenDocument {
+            name: file_name.to_string(),
+            document: path,
+            expected,
+        });
+    }
+    if corpus.is_empty() {
+        return Err(BenchmarkError::Corpus(format!(
+            "no documents with {EXPECTED_SUFFIX} golden files in {}",
+            dir.display()
+        )));
+    }
+    corpus.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(corpus)
+}
+
+// ═══════════════════════════════════════════════════════════
+// Instrumented clients
+// ═══════════════════════════════════════════════════════════
+
+/// Inference call and degeneration counters shared by a run's clients.
+#[derive(Debug, Default)]
+pub struct CallStats {
+    calls: AtomicUsize,
+    degenerations: AtomicUsize,
+}
+
+impl CallStats {
+    fn snapshot(&self) -> (usize, usize) {
+        (
+            self.calls.load(Ordering::Relaxed),
+            self.degenerations.load(Ordering::Relaxed),
+        )
+    }
+
+    fn observe<T, E>(&self, result: &Result<T, E>, is_degeneration: impl Fn(&E) -> bool) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        if matches!(result, Err(e) if is_degeneration(e)) {
+            self.degenerations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Client wrapper that counts calls and degeneration aborts.
+pub struct Instrumented<C> {
+    inner: C,
+    stats: Arc<CallStats>,
+}
+
+impl<C> Instrumented<C> {
+    pub fn new(inner: C, stats: Arc<CallStats>) -> Self {
+        Self { inner, stats }
+    }
+}
+
+fn is_vision_degeneration(e: &OllamaError) -> bool {
+    matches!(e, OllamaError::VisionDegeneration { .. })
+}
+
+impl<C: VisionClient> VisionClient for Instrumented<C> {
+    fn generate_with_images(
+        &self,
+        model: &str,
+        prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+    ) -> Result<String, OllamaError> {
+        let result = self.inner.generate_with_images(model, prompt, images, system);
+        self.stats.observe(&result, is_vision_degeneration);
+        result
+    }
+
+    fn chat_with_images(
+        &self,
+        model: &str,
+        user_prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+    ) -> Result<String, OllamaError> {
+        let result = self.inner.chat_with_images(model, user_prompt, images, system);
+        self.stats.observe(&result, is_vision_degeneration);
+        result
+    }
+
+    fn chat_with_images_with_params(
+        &self,
+        model: &str,
+        user_prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+        params: VisionCallParams,
+    ) -> Result<String, OllamaError> {
+        let result = self
+            .inner
+            .chat_with_images_with_params(model, user_prompt, images, system, params);
+        self.stats.observe(&result, is_vision_degeneration);
+        result
+    }
+}
+
+impl<C: LlmClient> LlmClient for Instrumented<C> {
+    fn generate(&self, model: &str, prompt: &str, system: &str) -> Result<String, StructuringError> {
+        let result = self.inner.generate(model, prompt, system);
+        self.stats
+            .observe(&result, |e| matches!(e, StructuringError::Degeneration { .. }));
+        result
+    }
+
+    fn is_model_available(&self, model: &str) -> Result<bool, StructuringError> {
+        self.inner.is_model_available(model)
+    }
+
+    fn list_models(&self) -> Result<Vec<String>, StructuringError> {
+        self.inner.list_models()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// Runner
+// ═══════════════════════════════════════════════════════════
+
+/// Source of fresh client sets — one per document, sharing a cassette.
+enum ClientSource {
+    Ollama {
+        num_ctx: Option<u32>,
+        cassette: Option<Arc<Cassette>>,
+    },
+    Replay(Arc<Cassette>),
+}
+
+impl ClientSource {
+    fn from_backend(backend: &BenchmarkBackend) -> Result<Self, BenchmarkError> {
+        Ok(match backend {
+            BenchmarkBackend::Ollama { num_ctx, record_to } => Self::Ollama {
+                num_ctx: *num_ctx,
+                cassette: record_to.as_deref().map(|p| Arc::new(Cassette::new(p))),
+            },
+            BenchmarkBackend::Replay { cassette } => Self::Replay(Arc::new(Cassette::load(cassette)?)),
+        })
+    }
+
+    fn clients(&self, stats: &Arc<CallStats>) -> PipelineClients {
+        match self {
+            Self::Ollama { num_ctx, cassette } => {
+                let make = || {
+                    let mut client = crate::ollama_service::OllamaService::client();
+                    if let Some(ctx) = num_ctx {
+                        client.set_vision_num_ctx(*ctx);
+                    }
+                    match cassette {
+                        Some(c) => client.with_cassette(Arc::clone(c)),
+                        None => client,
+                    }
+                };
+                let mut options = crate::pipeline::structuring::ollama_types::GenerationOptions::default();
+                options.num_ctx = *num_ctx;
+                PipelineClients {
+                    vision: Arc::new(Instrumented::new(make(), Arc::clone(stats))),
+                    drill: Box::new(Instrumented::new(make(), Arc::clone(stats))),
+                    structuring: Box::new(Instrumented::new(
+                        make().with_options(options),
+                        Arc::clone(stats),
+                    )),
+                }
+            }
+            Self::Replay(cassette) => {
+                let make = || CassetteClient::new(Arc::clone(cassette));
+                PipelineClients {
+                    vision: Arc::new(Instrumented::new(make(), Arc::clone(stats))),
+                    drill: Box::new(Instrumented::new(make(), Arc::clone(stats))),
+                    structuring: Box::new(Instrumented::new(make(), Arc::clone(stats))),
+                }
+            }
+        }
+    }
+}
+
+/// Run every golden document through `DocumentProcessor` and score it.
+///
+/// Processing failures are recorded per document (all expected items count
+/// as missed) rather than aborting the run.
+pub fn run_benchmark(options: &BenchmarkOptions) -> Result<BenchmarkReport, BenchmarkError> {
+    use crate::crypto::profile;
+    use crate::db::sqlite::open_database;
+
+    let corpus = discover_corpus(&options.corpus_dir)?;
+    let source = ClientSource::from_backend(&options.backend)?;
+
+    // Throwaway profile — imports and staging need a real encrypted vault.
+    let profiles = tempfile::tempdir()?;
+    let password = "benchmark-profile-password";
+    let (info, _phrase) = profile::create_profile(profiles.path(), "Benchmark", password, None, None, None, None)
+        .map_err(|e| BenchmarkError::Profile(e.to_string()))?;
+    let session = profile::open_profile(profiles.path(), &info.id, password)
+        .map_err(|e| BenchmarkError::Profile(e.to_string()))?;
+    let conn = open_database(session.db_path(), Some(session.key_bytes()))
+        .map_err(|e| BenchmarkError::Profile(e.to_string()))?;
+
+    let mut results = Vec::with_capacity(corpus.len());
+    for golden in &corpus {
+        let expected: Value = serde_json::from_str(&std::fs::read_to_string(&golden.expected)?)?;
+        let stats = Arc::new(CallStats::default());
+        let start = std::time::Instant::now();
+
+        let outcome = assignment_for(&golden.document, options)
+            .and_then(|assignment| {
+                build_processor_with_clients(
+                    &assignment,
+                    source.clients(&stats),
+                    &options.language,
+                    false,
+                    None,
+                )
+                .map_err(|e| e.to_string())
+            })
+            .and_then(|processor| {
+                processor
+                    .process_file(&golden.document, &session, &conn)
+                    .map_err(|e| e.to_string())
+            })
+            .and_then(|output| {
+                output
+                    .structuring_result
+                    .map(|r| r.extracted_entities)
+                    .ok_or_else(|| format!("not structured ({:?})", output.outcome.import_status))
+            });
+
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        let (calls, degenerations) = stats.snapshot();
+        let (entities, error) = match outcome {
+            Ok(entities) => (entities, None),
+            Err(e) => (ExtractedEntities::default(), Some(e)),
+        };
+        tracing::info!(
+            document = %golden.name,
+            elapsed_ms,
+            calls,
+            failed = error.is_some(),
+            "BEN-01: Document benchmarked"
+        );
+
+        results.push(DocumentResult {
+            name: golden.name.clone(),
+            elapsed_ms,
+            error,
+            degeneration: DegenerationStats {
+                calls,
+                degenerations,
+                rate: ratio_or_zero(degenerations, calls),
+            },
+            score: score_document(&expected, &entities),
+        });
+    }
+
+    Ok(BenchmarkReport::from_documents(options, results))
+}
+
+/// Route a corpus document the way `model_router` would, with the
+/// benchmark's fixed models instead of the profile's tags.
+fn assignment_for(document: &Path, options: &BenchmarkOptions) -> Result<PipelineAssignment, String> {
+    use crate::pipeline::import::format::{detect_format, FileCategory};
+    use crate::pipeline::strategy::{detect_model_variant, resolve_strategy, ContextType};
+
+    let detection = detect_format(document).map_err(|e| e.to_string())?;
+    let vision = options.vision_model.clone();
+    let extraction = match (detection.category, vision) {
+        (FileCategory::PlainText, _) => ExtractionStrategy::DirectText,
+        (FileCategory::DigitalPdf, Some(model)) => ExtractionStrategy::VisionOcr { model },
+        (FileCategory::DigitalPdf, None) => ExtractionStrategy::PdfiumText,
+        (FileCategory::ScannedPdf | FileCategory::Image, Some(model)) => {
+            ExtractionStrategy::VisionOcr { model }
+        }
+        (FileCategory::ScannedPdf | FileCategory::Image, None) => {
+            return Err("needs a vision model (--vision-model)".to_string())
+        }
+        (FileCategory::Unsupported, _) => return Err("unsupported file format".to_string()),
+    };
+
+    let processing_mode = match &extraction {
+        ExtractionStrategy::VisionOcr { model } if *model != options.structuring_model => {
+            ProcessingMode::BatchStages
+        }
+        _ => ProcessingMode::Interleaved,
+    };
+
+    Ok(PipelineAssignment {
+        extraction,
+        structuring_model: options.structuring_model.clone(),
+        processing_mode,
+        prompt_strategy: Some(resolve_strategy(
+            ContextType::DocumentExtraction,
+            detect_model_variant(&options.structuring_model),
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::structuring::types::{ExtractedLabResult, ExtractedMedication};
+    use serde_json::json;
+
+    fn medication(generic: &str, brand: Option<&str>, dose: &str) -> ExtractedMedication {
+        ExtractedMedication {
+            generic_name: Some(generic.into()),
+            brand_name: brand.map(Into::into),
+            dose: dose.into(),
+            frequency: "twice daily".into(),
+            frequency_type: "scheduled".into(),
+            route: "oral".into(),
+            reason: None,
+            instructions: vec![],
+            is_compound: false,
+            compound_ingredients: vec![],
+            tapering_steps: vec![],
+            max_daily_dose: None,
+            condition: None,
+            confidence: 0.9,
+        }
+    }
+
+    fn lab(name: &str, value: f64, unit: &str) -> ExtractedLabResult {
+        ExtractedLabResult {
+            test_name: name.into(),
+            test_code: None,
+            value: Some(value),
+            value_text: None,
+            unit: Some(unit.into()),
+            reference_range_low: None,
+            reference_range_high: None,
+            reference_range_text: None,
+            abnormal_flag: None,
+            collection_date: None,
+            confidence: 0.8,
+        }
+    }
+
+    fn options() -> BenchmarkOptions {
+        BenchmarkOptions {
+            corpus_dir: PathBuf::from("corpus"),
+            structuring_model: "medgemma:4b".into(),
+            vision_model: None,
+            language: "en".into(),
+            backend: BenchmarkBackend::Replay {
+                cassette: PathBuf::from("corpus.cassette.json"),
+            },
+        }
+    }
+
+    fn result(name: &str, score: DocumentScore, error: Option<&str>) -> DocumentResult {
+        DocumentResult {
+            name: name.into(),
+            elapsed_ms: 100,
+            error: error.map(Into::into),
+            degeneration: DegenerationStats::default(),
+            score,
+        }
+    }
+
+    #[test]
+    fn perfect_extraction_scores_one() {
+        let mut actual = ExtractedEntities::default();
+        actual.medications.push(medication("Metformin", None, "500 mg"));
+        let expected = json!({"medications": [{"generic_name": "metformin", "dose": "500  mg"}]});
+
+        let score = score_document(&expected, &actual);
+        let meds = &score.domains["medications"];
+        assert_eq!((meds.expected, meds.extracted, meds.matched), (1, 1, 1));
+        assert_eq!(meds.precision, 1.0);
+        assert_eq!(meds.recall, 1.0);
+        assert_eq!(score.fields["medications.dose"].accuracy, 1.0);
+        assert!(!score.fields.contains_key("medications.route"), "unspecified fields are not scored");
+    }
+
+    #[test]
+    fn missed_and_spurious_items_cost_recall_and_precision() {
+        let mut actual = ExtractedEntities::default();
+        actual.lab_results.push(lab("Potassium", 4.2, "mmol/L"));
+        actual.lab_results.push(lab("Glucose", 5.1, "mmol/L"));
+        let expected = json!({"lab_results": [
+            {"test_name": "Potassium", "value": 4.2},
+            {"test_name": "Sodium", "value": 140.0},
+            {"test_name": "Creatinine", "value": 80.0}
+        ]});
+
+        let labs = &score_document(&expected, &actual).domains["lab_results"];
+        assert_eq!(labs.matched, 1);
+        assert!((labs.precision - 0.5).abs() < 1e-9);
+        assert!((labs.recall - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wrong_field_value_lowers_field_accuracy_only() {
+        let mut actual = ExtractedEntities::default();
+        actual.lab_results.push(lab("Potassium", 4.5, "mmol/L"));
+        let expected = json!({"lab_results": [{"test_name": "Potassium", "value": 4.2, "unit": "mmol/l", "confidence": 1.0}]});
+
+        let score = score_document(&expected, &actual);
+        assert_eq!(score.domains["lab_results"].recall, 1.0);
+        assert_eq!(score.fields["lab_results.value"].correct, 0);
+        assert_eq!(score.fields["lab_results.unit"].correct, 1);
+        assert!(!score.fields.contains_key("lab_results.confidence"));
+    }
+
+    #[test]
+    fn medication_matches_on_brand_name() {
+        let mut actual = ExtractedEntities::default();
+        actual.medications.push(medication("Metformin", Some("Glucophage"), "500 mg"));
+        let expected = json!({"medications": [{"brand_name": "Glucophage"}]});
+        assert_eq!(score_document(&expected, &actual).domains["medications"].matched, 1);
+    }
+
+    #[test]
+    fn duplicate_expected_items_do_not_reuse_one_extraction() {
+        let mut actual = ExtractedEntities::default();
+        actual.medications.push(medication("Metformin", None, "500 mg"));
+        let expected = json!({"medications": [{"generic_name": "Metformin"}, {"generic_name": "Metformin"}]});
+        let meds = &score_document(&expected, &actual).domains["medications"];
+        assert_eq!(meds.matched, 1);
+        assert_eq!(meds.recall, 0.5);
+    }
+
+    #[test]
+    fn blood_type_scored_as_scalar() {
+        let actual = ExtractedEntities {
+            blood_type: Some("A+".into()),
+            ..Default::default()
+        };
+        let hit = score_document(&json!({"blood_type": "a+"}), &actual);
+        assert_eq!(hit.domains["blood_type"].matched, 1);
+        let miss = score_document(&json!({"blood_type": "O-"}), &actual);
+        assert_eq!(miss.domains["blood_type"].precision, 0.0);
+    }
+
+    #[test]
+    fn empty_domains_score_perfectly() {
+        let score = score_document(&json!({}), &ExtractedEntities::default());
+        assert!(score.domains.values().all(|d| d.precision == 1.0 && d.recall == 1.0));
+        assert!(score.fields.is_empty());
+    }
+
+    #[test]
+    fn report_aggregates_documents() {
+        let mut actual = ExtractedEntities::default();
+        actual.medications.push(medication("Metformin", None, "500 mg"));
+        let good = score_document(&json!({"medications": [{"generic_name": "Metformin"}]}), &actual);
+        let failed = score_document(
+            &json!({"medications": [{"generic_name": "Aspirin"}]}),
+            &ExtractedEntities::default(),
+        );
+        let mut degenerate = result("b.png", failed, Some("timeout"));
+        degenerate.degeneration = DegenerationStats { calls: 4, degenerations: 1, rate: 0.25 };
+
+        let report = BenchmarkReport::from_documents(&options(), vec![result("a.txt", good, None), degenerate]);
+        assert_eq!(report.failed_documents, 1);
+        assert_eq!(report.domains["medications"].recall, 0.5);
+        assert_eq!(report.degeneration.rate, 0.25);
+        assert_eq!(report.timing.total_ms, 200);
+        assert_eq!(report.timing.mean_ms, 100.0);
+    }
+
+    #[test]
+    fn baseline_diff_flags_regressions() {
+        let mut actual = ExtractedEntities::default();
+        actual.medications.push(medication("Metformin", None, "500 mg"));
+        let expected = json!({"medications": [{"generic_name": "Metformin", "dose": "500 mg"}]});
+        let baseline = BenchmarkReport::from_documents(
+            &options(),
+            vec![result("a.txt", score_document(&expected, &actual), None)],
+        );
+
+        let current = BenchmarkReport::from_documents(
+            &options(),
+            vec![result("a.txt", score_document(&expected, &ExtractedEntities::default()), Some("boom"))],
+        );
+        let diff = diff_against_baseline(&baseline, &current);
+        assert!(diff.has_regressions());
+        assert!(diff.regressions.iter().any(|r| r.starts_with("medications recall")));
+        assert!(diff.regressions.iter().any(|r| r == "a.txt now fails"));
+        assert_eq!(diff.domains["medications"].recall.change, -1.0);
+        assert!(render_diff(&diff).contains("regression(s)"));
+
+        let unchanged = diff_against_baseline(&baseline, &baseline);
+        assert!(!unchanged.has_regressions());
+        assert!(render_diff(&unchanged).contains("No regressions."));
+    }
+
+    #[test]
+    fn report_round_trips_through_json() {
+        let report = BenchmarkReport::from_documents(&options(), vec![]);
+        let json = serde_json::to_string(&report).unwrap();
+        let back: BenchmarkReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, report);
+    }
+
+    #[test]
+    fn corpus_pairs_documents_with_golden_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("b_lab.txt"), "Potassium 4.2").unwrap();
+        std::fs::write(dir.path().join("b_lab.expected.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("a_rx.pdf"), b"%PDF").unwrap();
+        std::fs::write(dir.path().join("a_rx.expected.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("orphan.png"), b"png").unwrap();
+
+        let corpus = discover_corpus(dir.path()).unwrap();
+        let names: Vec<&str> = corpus.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, vec!["a_rx.pdf", "b_lab.txt"]);
+        assert_eq!(corpus[1].expected, dir.path().join("b_lab.expected.json"));
+    }
+
+    #[test]
+    fn empty_corpus_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(discover_corpus(dir.path()), Err(BenchmarkError::Corpus(_))));
+    }
+
+    #[test]
+    fn instrumented_client_counts_degenerations() {
+        use crate::pipeline::structuring::cassette::{CassetteEndpoint, CassetteRequest, Interaction};
+
+        let request = CassetteRequest {
+            endpoint: CassetteEndpoint::Chat,
+            model: "medgemma:4b",
+            system: None,
+            prompt: "Read",
+            images: &[],
+            params: VisionCallParams::default(),
+        };
+        let mut body = String::new();
+        for _ in 0..30 {
+            body.push_str(r#"{"message":{"content":"\n"},"done":false}"#);
+            body.push('\n');
+        }
+        let cassette = Arc::new(Cassette::from_interactions(vec![Interaction {
+            key: request.key(),
+            endpoint: CassetteEndpoint::Chat,
+            model: "medgemma:4b".into(),
+            status: 200,
+            body,
+        }]));
+
+        let stats = Arc::new(CallStats::default());
+        let client = Instrumented::new(CassetteClient::new(cassette), Arc::clone(&stats));
+        assert!(client.chat_with_images("medgemma:4b", "Read", &[], None).is_err());
+        assert!(client.chat_with_images("medgemma:4b", "unrecorded", &[], None).is_err());
+        assert_eq!(stats.snapshot(), (2, 1));
+    }
+}
@@ -0,0 +1,283 @@
+//! CKP-01: Per-page processing checkpoints.
+//!
+//! Vision OCR and LLM structuring run page by page; on CPU tiers a long
+//! document can take an hour. Each completed page is checkpointed, encrypted
+//! with the profile key, under `profiles/<uuid>/checkpoints/<doc_id>/` so a
+//! cancelled, failed or crashed run resumes at the first unfinished page.
+//!
+//! Checkpoints are an optimisation: read/write failures are logged and the
+//! page is simply processed again. They are cleared once a document finishes
+//! processing, and when it is deleted.
+//!
+//! Layout:
+//! - `source.enc` — fingerprint of the input the OCR pages were read from
+//! - `page-<n>.ocr.enc` — `PageExtraction` for page index n
+//! - `page-<n>.structured.enc` — `StructuringResult` for page n, bound to a
+//!   SHA-256 of the page text it was structured from and to the structurer
+//!   (model and strategy) that produced it
+
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::crypto::{EncryptedData, ProfileSession};
+use crate::pipeline::extraction::types::PageExtraction;
+use crate::pipeline::structuring::types::StructuringResult;
+
+const SOURCE_FILE: &str = "source.enc";
+
+/// Structuring checkpoint: result plus the text and structurer it was
+/// produced from.
+#[derive(Serialize, Deserialize)]
+struct StructuredPage {
+    /// Base64 SHA-256 of the page text.
+    text_sha256: String,
+    /// `MedicalStructurer::fingerprint` (model and strategy).
+    #[serde(default)]
+    structurer: String,
+    result: StructuringResult,
+}
+
+/// Checkpoints of one document, read and written with the profile key.
+pub struct DocumentCheckpoints<'a> {
+    session: &'a ProfileSession,
+    dir: PathBuf,
+}
+
+impl<'a> DocumentCheckpoints<'a> {
+    /// Checkpoints for `document_id`. `None` if the profile layout is invalid.
+    pub fn open(session: &'a ProfileSession, document_id: &Uuid) -> Option<Self> {
+        let dir = checkpoints_root(session)?.join(document_id.to_string());
+        Some(Self { session, dir })
+    }
+
+    /// Bind OCR checkpoints to their input. If the stored fingerprint differs
+    /// (re-staged file, other DPI or language), stale checkpoints are dropped.
+    pub fn bind_source(&self, fingerprint: &str) {
+        let stored = self.read(SOURCE_FILE);
+        if stored.as_deref() == Some(fingerprint.as_bytes()) {
+            return;
+        }
+        if stored.is_some() {
+            tracing::info!(dir = %self.dir.display(), "Checkpoint source changed — discarding");
+            self.clear();
+        }
+        self.write(SOURCE_FILE, fingerprint.as_bytes());
+    }
+
+    /// OCR result of page `page_idx` from an earlier run.
+    pub fn load_page_extraction(&self, page_idx: usize) -> Option<PageExtraction> {
+        let bytes = self.read(&format!("page-{page_idx}.ocr.enc"))?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Record the OCR result of page `page_idx`.
+    pub fn save_page_extraction(&self, page_idx: usize, page: &PageExtraction) {
+        if let Ok(json) = serde_json::to_vec(page) {
+            self.write(&format!("page-{page_idx}.ocr.enc"), &json);
+        }
+    }
+
+    /// Structuring result of page `page_idx`, if it was produced from
+    /// `page_text` by the same `structurer` (model and strategy).
+    pub fn load_page_structuring(
+        &self,
+        page_idx: usize,
+        page_text: &str,
+        structurer: &str,
+    ) -> Option<StructuringResult> {
+        let bytes = self.read(&format!("page-{page_idx}.structured.enc"))?;
+        let page: StructuredPage = serde_json::from_slice(&bytes).ok()?;
+        (page.text_sha256 == text_digest(page_text) && page.structurer == structurer)
+            .then_some(page.result)
+    }
+
+    /// Record the structuring result of page `page_idx`.
+    pub fn save_page_structuring(
+        &self,
+        page_idx: usize,
+        page_text: &str,
+        structurer: &str,
+        result: &StructuringResult,
+    ) {
+        let page = StructuredPage {
+            text_sha256: text_digest(page_text),
+            structurer: structurer.to_string(),
+            result: result.clone(),
+        };
+        if let Ok(json) = serde_json::to_vec(&page) {
+            self.write(&format!("page-{page_idx}.structured.enc"), &json);
+        }
+    }
+
+    /// Securely delete all checkpoints of this document.
+    pub fn clear(&self) {
+        remove_dir(&self.dir);
+    }
+
+    fn read(&self, name: &str) -> Option<Vec<u8>> {
+        let bytes = std::fs::read(self.dir.join(name)).ok()?;
+        let encrypted = EncryptedData::from_bytes(&bytes).ok()?;
+        match self.session.decrypt(&encrypted) {
+            Ok(plaintext) => Some(plaintext),
+            Err(e) => {
+                tracing::warn!(file = name, error = %e, "Unreadable checkpoint — ignoring");
+                None
+            }
+        }
+    }
+
+    fn write(&self, name: &str, plaintext: &[u8]) {
+        let result = std::fs::create_dir_all(&self.dir)
+            .map_err(|e| e.to_string())
+            .and_then(|()| self.session.encrypt(plaintext).map_err(|e| e.to_string()))
+            .and_then(|encrypted| {
+                std::fs::write(self.dir.join(name), encrypted.to_bytes()).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            tracing::warn!(file = name, error = %e, "Failed to write checkpoint");
+        }
+    }
+}
+
+/// Securely delete a document's checkpoints (e.g. when it is deleted).
+pub fn remove_checkpoints(session: &ProfileSession, document_id: &Uuid) {
+    if let Some(root) = checkpoints_root(session) {
+        remove_dir(&root.join(document_id.to_string()));
+    }
+}
+
+/// `profiles/<uuid>/checkpoints/`
+fn checkpoints_root(session: &ProfileSession) -> Option<PathBuf> {
+    session
+        .db_path()
+        .parent() // database/
+        .and_then(|p| p.parent()) // profile dir
+        .map(|p| p.join("checkpoints"))
+}
+
+fn remove_dir(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if let Err(e) = crate::crypto::secure_delete_file(&entry.path()) {
+            tracing::warn!(error = %e, "Failed to delete checkpoint");
+        }
+    }
+    let _ = std::fs::remove_dir(dir);
+}
+
+fn text_digest(text: &str) -> String {
+    base64::engine::general_purpose::STANDARD.encode(Sha256::digest(text.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::profile;
+
+    fn setup() -> (tempfile::TempDir, ProfileSession) {
+        let dir = tempfile::tempdir().unwrap();
+        let (info, _phrase) =
+            profile::create_profile(dir.path(), "CheckpointTest", "test_pass_123", None, None, None, None).unwrap();
+        let session = profile::open_profile(dir.path(), &info.id, "test_pass_123").unwrap();
+        (dir, session)
+    }
+
+    fn page(text: &str) -> PageExtraction {
+        PageExtraction {
+            page_number: 1,
+            text: text.into(),
+            confidence: 0.8,
+            regions: vec![],
+            warnings: vec![],
+            content_type: None,
+            drill_output: None,
+        }
+    }
+
+    #[test]
+    fn page_extraction_round_trips_encrypted() {
+        let (_dir, session) = setup();
+        let doc_id = Uuid::new_v4();
+        let checkpoints = DocumentCheckpoints::open(&session, &doc_id).unwrap();
+        checkpoints.bind_source("scan.pdf|1024|300");
+
+        assert!(checkpoints.load_page_extraction(0).is_none());
+        checkpoints.save_page_extraction(0, &page("Hemoglobin 13.5 g/dL"));
+
+        let loaded = checkpoints.load_page_extraction(0).unwrap();
+        assert_eq!(loaded.text, "Hemoglobin 13.5 g/dL");
+
+        // Stored encrypted — plaintext never hits disk
+        let raw = std::fs::read(checkpoints.dir.join("page-0.ocr.enc")).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains("Hemoglobin"));
+    }
+
+    #[test]
+    fn changed_source_discards_pages() {
+        let (_dir, session) = setup();
+        let doc_id = Uuid::new_v4();
+        let checkpoints = DocumentCheckpoints::open(&session, &doc_id).unwrap();
+        checkpoints.bind_source("scan.pdf|1024|300");
+        checkpoints.save_page_extraction(0, &page("old"));
+
+        checkpoints.bind_source("scan.pdf|1024|300");
+        assert!(checkpoints.load_page_extraction(0).is_some());
+
+        checkpoints.bind_source("scan.pdf|2048|300");
+        assert!(checkpoints.load_page_extraction(0).is_none());
+    }
+
+    #[test]
+    fn structuring_checkpoint_requires_same_text_and_structurer() {
+        let (_dir, session) = setup();
+        let doc_id = Uuid::new_v4();
+        let checkpoints = DocumentCheckpoints::open(&session, &doc_id).unwrap();
+        let result = StructuringResult {
+            document_id: doc_id,
+            document_type: crate::models::enums::DocumentType::LabResult,
+            document_date: None,
+            professional: None,
+            structured_markdown: "# Labs".into(),
+            extracted_entities: Default::default(),
+            structuring_confidence: 0.9,
+            markdown_file_path: None,
+            validation_warnings: vec![],
+            raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
+        };
+
+        checkpoints.save_page_structuring(2, "page text", "medgemma:4b|iterative_drill", &result);
+        assert!(checkpoints
+            .load_page_structuring(2, "page text", "medgemma:4b|iterative_drill")
+            .is_some());
+        assert!(checkpoints
+            .load_page_structuring(2, "re-read text", "medgemma:4b|iterative_drill")
+            .is_none());
+        // Another model or strategy structures the page again
+        assert!(checkpoints
+            .load_page_structuring(2, "page text", "medgemma:27b|iterative_drill")
+            .is_none());
+        assert!(checkpoints
+            .load_page_structuring(2, "page text", "medgemma:4b|markdown_list")
+            .is_none());
+    }
+
+    #[test]
+    fn remove_checkpoints_deletes_directory() {
+        let (_dir, session) = setup();
+        let doc_id = Uuid::new_v4();
+        let checkpoints = DocumentCheckpoints::open(&session, &doc_id).unwrap();
+        checkpoints.save_page_extraction(0, &page("text"));
+        assert!(checkpoints.dir.exists());
+
+        remove_checkpoints(&session, &doc_id);
+        assert!(!checkpoints.dir.exists());
+    }
+}
@@ -554,6 +554,53 @@ pub const INSTRUCTIONS: DomainContract = DomainContract {
     ],
 };
 
+// ── Immunizations ───────────────────────────────────────
+
+/// IMM-01: One item per administered dose, as written in a vaccination booklet.
+pub const IMMUNIZATIONS: DomainContract = DomainContract {
+    domain: "immunizations",
+    item_label: "vaccine",
+    item_label_plural: "vaccines",
+    enumerate_hint: "vaccines or immunizations administered",
+    fields: &[
+        FieldDescriptor {
+            name: "dose_number",
+            prompt_label: "dose number in the series",
+            field_type: FieldType::Numeric,
+            required: false,
+            db_column: "dose_number",
+        },
+        FieldDescriptor {
+            name: "date",
+            prompt_label: "date the dose was given",
+            field_type: FieldType::Date,
+            required: false,
+            db_column: "administered_date",
+        },
+        FieldDescriptor {
+            name: "lot_number",
+            prompt_label: "lot or batch number",
+            field_type: FieldType::Text,
+            required: false,
+            db_column: "lot_number",
+        },
+        FieldDescriptor {
+            name: "site",
+            prompt_label: "injection site",
+            field_type: FieldType::Text,
+            required: false,
+            db_column: "site",
+        },
+        FieldDescriptor {
+            name: "administered_by",
+            prompt_label: "professional who administered the dose",
+            field_type: FieldType::Text,
+            required: false,
+            db_column: "administering_professional_id",
+        },
+    ],
+};
+
 // ═══════════════════════════════════════════════════════════
 // Full prompt builders (document wrapping + escaping)
 // ═══════════════════════════════════════════════════════════
@@ -601,7 +648,7 @@ fn escape_xml_tags(text: &str) -> String {
 // Lookup
 // ═══════════════════════════════════════════════════════════
 
-/// All 7 document domain contracts.
+/// All 8 document domain contracts.
 pub const ALL_DOCUMENT_CONTRACTS: &[&DomainContract] = &[
     &MEDICATIONS,
     &LAB_RESULTS,
@@ -610,6 +657,7 @@ pub const ALL_DOCUMENT_CONTRACTS: &[&DomainContract] = &[
     &PROCEDURES,
     &REFERRALS,
     &INSTRUCTIONS,
+    &IMMUNIZATIONS,
 ];
 
 /// Look up a contract by domain name.
@@ -636,6 +684,7 @@ pub fn contract_for_document_domain(
         DocumentDomain::Procedures => &PROCEDURES,
         DocumentDomain::Referrals => &REFERRALS,
         DocumentDomain::Instructions => &INSTRUCTIONS,
+        DocumentDomain::Immunizations => &IMMUNIZATIONS,
     }
 }
 
@@ -650,10 +699,11 @@ use crate::pipeline::prompt_templates::DocumentDomain;
 ///
 /// Lab reports contain lab results and occasionally interpretive diagnoses.
 /// Prescriptions contain medications, administration instructions, and occasionally indications.
+/// Vaccination records (IMM-01) contain administered doses only.
 /// Medical images are routed to MedicalImageInterpreter — they never reach IterativeDrill.
 ///
 /// Domains not mapped to any category (Allergies, Procedures, Referrals) remain
-/// available for chat extraction (NightBatch) where all 7 domains apply.
+/// available for chat extraction (NightBatch) where all 8 domains apply.
 pub fn domains_for_document_type(doc_type: UserDocumentType) -> &'static [DocumentDomain] {
     match doc_type {
         UserDocumentType::LabReport => &[
@@ -665,6 +715,7 @@ pub fn domains_for_document_type(doc_type: UserDocumentType) -> &'static [Docume
             DocumentDomain::Instructions,
             DocumentDomain::Diagnoses,
         ],
+        UserDocumentType::VaccinationRecord => &[DocumentDomain::Immunizations],
         UserDocumentType::MedicalImage => &[], // never reaches IterativeDrill
     }
 }
@@ -677,6 +728,7 @@ pub fn category_context(doc_type: UserDocumentType) -> &'static str {
     match doc_type {
         UserDocumentType::LabReport => "This is a laboratory analysis report.",
         UserDocumentType::Prescription => "This is a medical prescription.",
+        UserDocumentType::VaccinationRecord => "This is a vaccination record.",
         UserDocumentType::MedicalImage => "", // never reaches IterativeDrill
     }
 }
@@ -1023,6 +1075,107 @@ const DIAGNOSES_DE: PromptLocale = PromptLocale {
     answer_end: "</answer>",
 };
 
+// ── Immunizations locales (IMM-01) ─────────────────────────
+
+const IMMUNIZATIONS_EN: PromptLocale = PromptLocale {
+    lang: "en",
+    item_label: "vaccine",
+    item_label_plural: "administered vaccines",
+    vision_enumerate:
+        "What vaccines are recorded as administered in this document?\n\
+         One line per dose: vaccine name, then the date in parentheses.\n\
+         If no vaccines are visible, respond: <answer>NONE</answer>",
+    vision_drill_value:
+        "For the vaccine '{item}', give: dose number | date | lot number | site | administered by.",
+    vision_drill_range: "",
+    none_keyword: "NONE",
+    system_prompt:
+        "You are a medical data extraction assistant.\n\
+         Reason inside <think>...</think>, then give your answer inside <answer>...</answer>.\n\n\
+         Example:\n\
+         Question: What vaccines are recorded?\n\
+         <think>\n\
+         I see two doses of DTaP-IPV-Hib-HepB and one dose of MMR.\n\
+         </think>\n\
+         <answer>\n\
+         DTaP-IPV-Hib-HepB (2024-03-04)\n\
+         DTaP-IPV-Hib-HepB (2024-05-06)\n\
+         MMR (2025-02-10)\n\
+         </answer>\n\n\
+         Rules:\n\
+         - Only extract what is asked\n\
+         - Answer must match what you see in the document\n\
+         - Close all tags",
+    answer_start: "<answer>",
+    answer_end: "</answer>",
+};
+
+const IMMUNIZATIONS_FR: PromptLocale = PromptLocale {
+    lang: "fr",
+    item_label: "vaccin",
+    item_label_plural: "vaccins administrés",
+    vision_enumerate:
+        "Quels vaccins sont enregistrés comme administrés dans ce document ?\n\
+         Une ligne par dose : nom du vaccin, puis la date entre parenthèses.\n\
+         Si aucun vaccin n'est visible, répondre : <answer>AUCUN</answer>",
+    vision_drill_value:
+        "Pour le vaccin « {item} », donner : numéro de dose | date | numéro de lot | site | administré par.",
+    vision_drill_range: "",
+    none_keyword: "AUCUN",
+    system_prompt:
+        "Tu es un assistant d'extraction médicale.\n\
+         Raisonne dans <think>...</think>, puis donne ta réponse dans <answer>...</answer>.\n\n\
+         Exemple :\n\
+         Question : Quels vaccins sont enregistrés ?\n\
+         <think>\n\
+         Je vois deux doses d'Infanrix Hexa et une dose de ROR.\n\
+         </think>\n\
+         <answer>\n\
+         Infanrix Hexa (04/03/2024)\n\
+         Infanrix Hexa (06/05/2024)\n\
+         ROR (10/02/2025)\n\
+         </answer>\n\n\
+         Règles :\n\
+         - Extrais uniquement ce qui est demandé\n\
+         - La réponse doit correspondre au document\n\
+         - Ferme toujours les balises",
+    answer_start: "<answer>",
+    answer_end: "</answer>",
+};
+
+const IMMUNIZATIONS_DE: PromptLocale = PromptLocale {
+    lang: "de",
+    item_label: "Impfstoff",
+    item_label_plural: "verabreichte Impfungen",
+    vision_enumerate:
+        "Welche Impfungen sind in diesem Dokument als verabreicht eingetragen?\n\
+         Eine Zeile pro Dosis: Impfstoff, dann das Datum in Klammern.\n\
+         Falls keine Impfungen sichtbar sind, antworte: <answer>KEINE</answer>",
+    vision_drill_value:
+        "Fuer die Impfung '{item}': Dosisnummer | Datum | Chargennummer | Stelle | verabreicht von.",
+    vision_drill_range: "",
+    none_keyword: "KEINE",
+    system_prompt:
+        "Du bist ein Assistent fuer medizinische Datenextraktion.\n\
+         Denke in <think>...</think>, dann gib deine Antwort in <answer>...</answer>.\n\n\
+         Beispiel:\n\
+         Frage: Welche Impfungen sind eingetragen?\n\
+         <think>\n\
+         Ich sehe zwei Dosen Hexyon und eine Dosis MMR.\n\
+         </think>\n\
+         <answer>\n\
+         Hexyon (04.03.2024)\n\
+         Hexyon (06.05.2024)\n\
+         MMR (10.02.2025)\n\
+         </answer>\n\n\
+         Regeln:\n\
+         - Nur extrahieren, was gefragt wird\n\
+         - Antwort muss dem Dokument entsprechen\n\
+         - Alle Tags schliessen",
+    answer_start: "<answer>",
+    answer_end: "</answer>",
+};
+
 // ── Generic locales (allergies, procedures, referrals, instructions) ──
 
 const GENERIC_EN: PromptLocale = PromptLocale {
@@ -1141,6 +1294,10 @@ pub fn locale_for_domain(domain: &str, lang: &str) -> &'static PromptLocale {
         ("diagnoses", "de") => &DIAGNOSES_DE,
         ("diagnoses", _) => &DIAGNOSES_EN,
 
+        ("immunizations", "fr") => &IMMUNIZATIONS_FR,
+        ("immunizations", "de") => &IMMUNIZATIONS_DE,
+        ("immunizations", _) => &IMMUNIZATIONS_EN,
+
         (_, "fr") => &GENERIC_FR,
         (_, "de") => &GENERIC_DE,
         (_, _) => &GENERIC_EN,
@@ -1217,8 +1374,8 @@ mod tests {
     // ── Contract completeness ───────────────────────────
 
     #[test]
-    fn all_seven_document_contracts_declared() {
-        assert_eq!(ALL_DOCUMENT_CONTRACTS.len(), 7);
+    fn all_eight_document_contracts_declared() {
+        assert_eq!(ALL_DOCUMENT_CONTRACTS.len(), 8);
     }
 
     #[test]
@@ -1645,6 +1802,15 @@ mod tests {
         assert_eq!(domains[2], DocumentDomain::Diagnoses);
     }
 
+    #[test]
+    fn domains_for_vaccination_record() {
+        let domains = domains_for_document_type(UserDocumentType::VaccinationRecord);
+        assert_eq!(domains, &[DocumentDomain::Immunizations]);
+        let contract = contract_for_document_domain(domains[0]);
+        assert_eq!(contract.domain, "immunizations");
+        assert_eq!(contract.field("date").unwrap().db_column, "administered_date");
+    }
+
     #[test]
     fn domains_for_medical_image() {
         let domains = domains_for_document_type(UserDocumentType::MedicalImage);
@@ -1806,7 +1972,7 @@ mod tests {
 
     #[test]
     fn locale_has_answer_tokens() {
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 assert!(
@@ -1832,7 +1998,7 @@ mod tests {
     fn answer_tokens_consistent_across_locales() {
         // All locales must use the same answer tokens (language-independent delimiters)
         let reference = locale_for_domain("lab_results", "en");
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 assert_eq!(
@@ -1851,7 +2017,7 @@ mod tests {
 
     #[test]
     fn system_prompt_contains_think_answer_all_locales() {
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 assert!(
@@ -1878,7 +2044,7 @@ mod tests {
     fn system_prompt_contains_example_all_locales() {
         // Each locale must have a domain-appropriate example
         let en_example = ["Example", "Beispiel", "Exemple"];
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 let has_example = en_example.iter().any(|kw| locale.system_prompt.contains(kw));
@@ -1893,7 +2059,7 @@ mod tests {
     #[test]
     fn enumerate_prompt_none_has_answer_token_all_locales() {
         // NONE instruction in enumerate prompts must be wrapped in answer tokens
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 let expected = format!(
@@ -1911,7 +2077,7 @@ mod tests {
     #[test]
     fn system_prompt_under_400_tokens_estimate() {
         // Proxy: system prompt should be under 2000 chars (~400 tokens for 4B model)
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 assert!(
@@ -1926,7 +2092,7 @@ mod tests {
     #[test]
     fn system_prompt_no_numbered_steps() {
         // 11-SRP: No numbered reasoning steps — examples teach, not rules
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 assert!(
@@ -1944,7 +2110,7 @@ mod tests {
     #[test]
     fn system_prompt_has_three_rules() {
         // Each locale should have exactly 3 rules (minimal, not overwhelming for 4B model)
-        for domain in &["lab_results", "medications", "diagnoses", "allergies"] {
+        for domain in &["lab_results", "medications", "diagnoses", "allergies", "immunizations"] {
             for lang in &["en", "fr", "de"] {
                 let locale = locale_for_domain(domain, lang);
                 let rule_count = locale.system_prompt.matches("\n- ").count();
@@ -0,0 +1,808 @@
+//! DOC-01: Text extraction for word-processor and web documents.
+//!
+//! Word (DOCX), RTF and saved patient-portal HTML pages carry their text
+//! digitally, so they take the no-model text path like plain text files.
+//! Each converter emits plain text with tables rendered as Markdown pipe
+//! tables — the same shape vision OCR produces — so lab tables reach
+//! structuring with their rows and columns intact.
+
+use std::io::{Cursor, Read};
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::ExtractionError;
+
+/// MIME type reported by format detection for Word documents.
+pub const DOCX_MIME: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document";
+/// MIME type reported by format detection for RTF documents.
+pub const RTF_MIME: &str = "application/rtf";
+/// MIME type reported by format detection for HTML pages.
+pub const HTML_MIME: &str = "text/html";
+
+/// Upper bound on the decompressed size of `word/document.xml` (zip bomb guard).
+const MAX_DOCX_XML_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Convert a text-bearing document to plain text, dispatching on MIME type.
+///
+/// Anything that isn't DOCX, RTF or HTML is read as UTF-8 plain text.
+pub fn extract_document_text(bytes: Vec<u8>, mime_type: &str) -> Result<String, ExtractionError> {
+    match mime_type {
+        DOCX_MIME => docx_to_text(&bytes),
+        RTF_MIME => Ok(rtf_to_text(&bytes)),
+        HTML_MIME => Ok(html_to_text(&String::from_utf8_lossy(&bytes))),
+        _ => String::from_utf8(bytes).map_err(|e| ExtractionError::EncodingError(e.to_string())),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shared text sink (paragraphs + Markdown tables)
+// ---------------------------------------------------------------------------
+
+#[derive(Default)]
+struct Table {
+    rows: Vec<Vec<String>>,
+    row: Vec<String>,
+    cell: String,
+    cell_open: bool,
+}
+
+impl Table {
+    fn end_cell(&mut self) {
+        if self.cell_open {
+            self.row.push(std::mem::take(&mut self.cell));
+            self.cell_open = false;
+        } else {
+            // Stray text between cells (e.g. whitespace in HTML) is not content
+            self.cell.clear();
+        }
+    }
+
+    fn end_row(&mut self) {
+        self.end_cell();
+        if !self.row.is_empty() {
+            self.rows.push(std::mem::take(&mut self.row));
+        }
+    }
+}
+
+/// Accumulates document text. Text written while a table is open goes into the
+/// current cell; closing a table renders it as Markdown into the parent.
+#[derive(Default)]
+struct TextSink {
+    out: String,
+    tables: Vec<Table>,
+}
+
+impl TextSink {
+    fn target(&mut self) -> &mut String {
+        match self.tables.last_mut() {
+            Some(table) => &mut table.cell,
+            None => &mut self.out,
+        }
+    }
+
+    fn push_str(&mut self, text: &str) {
+        self.target().push_str(text);
+    }
+
+    fn push_char(&mut self, c: char) {
+        self.target().push(c);
+    }
+
+    /// Append text collapsing whitespace runs (HTML semantics).
+    fn push_collapsed(&mut self, text: &str) {
+        let target = self.target();
+        for c in text.chars() {
+            if c.is_whitespace() {
+                if !target.is_empty() && !target.ends_with(char::is_whitespace) {
+                    target.push(' ');
+                }
+            } else {
+                target.push(c);
+            }
+        }
+    }
+
+    /// End a line: a newline in body text, a space inside a table cell.
+    fn line_break(&mut self) {
+        match self.tables.last_mut() {
+            Some(table) => {
+                if !table.cell.is_empty() && !table.cell.ends_with(' ') {
+                    table.cell.push(' ');
+                }
+            }
+            None => {
+                while self.out.ends_with(' ') {
+                    self.out.pop();
+                }
+                self.out.push('\n');
+            }
+        }
+    }
+
+    /// Start a block on a fresh line (no-op if already at a line start).
+    fn block_break(&mut self) {
+        let at_line_start = match self.tables.last() {
+            Some(table) => table.cell.is_empty() || table.cell.ends_with(' '),
+            None => self.out.is_empty() || self.out.ends_with('\n'),
+        };
+        if !at_line_start {
+            self.line_break();
+        }
+    }
+
+    fn in_table(&self) -> bool {
+        !self.tables.is_empty()
+    }
+
+    fn start_table(&mut self) {
+        self.block_break();
+        self.tables.push(Table::default());
+    }
+
+    fn start_row(&mut self) {
+        if let Some(table) = self.tables.last_mut() {
+            table.end_row();
+        }
+    }
+
+    fn start_cell(&mut self) {
+        if let Some(table) = self.tables.last_mut() {
+            table.end_cell();
+            table.cell_open = true;
+        }
+    }
+
+    fn end_cell(&mut self) {
+        if let Some(table) = self.tables.last_mut() {
+            table.end_cell();
+        }
+    }
+
+    /// Close a cell that has no explicit start (RTF `\cell` terminates cells).
+    fn close_cell(&mut self) {
+        if let Some(table) = self.tables.last_mut() {
+            table.cell_open = true;
+            table.end_cell();
+        }
+    }
+
+    fn end_row(&mut self) {
+        if let Some(table) = self.tables.last_mut() {
+            table.end_row();
+        }
+    }
+
+    fn end_table(&mut self) {
+        let Some(mut table) = self.tables.pop() else {
+            return;
+        };
+        table.end_row();
+        let rendered = render_markdown_table(&table.rows);
+        if rendered.is_empty() {
+            return;
+        }
+        if self.in_table() {
+            // Nested table: flatten into the enclosing cell
+            let flat = table.rows.iter().flatten().map(String::as_str).collect::<Vec<_>>().join(" ");
+            self.push_str(&flat);
+        } else {
+            self.block_break();
+            self.out.push('\n');
+            self.out.push_str(&rendered);
+            self.out.push_str("\n\n");
+        }
+    }
+
+    fn finish(mut self) -> String {
+        while self.in_table() {
+            self.end_table();
+        }
+        tidy(&self.out)
+    }
+}
+
+/// Render rows as a Markdown pipe table (first row is the header).
+fn render_markdown_table(rows: &[Vec<String>]) -> String {
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| clean_cell(cell)).collect::<Vec<_>>())
+        .filter(|row: &Vec<String>| row.iter().any(|cell| !cell.is_empty()))
+        .collect();
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return String::new();
+    }
+
+    let render_row = |row: &Vec<String>| {
+        let mut line = String::from("|");
+        for i in 0..columns {
+            line.push(' ');
+            line.push_str(row.get(i).map(String::as_str).unwrap_or(""));
+            line.push_str(" |");
+        }
+        line
+    };
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    lines.push(render_row(&rows[0]));
+    lines.push(format!("|{}", " --- |".repeat(columns)));
+    lines.extend(rows[1..].iter().map(render_row));
+    lines.join("\n")
+}
+
+fn clean_cell(cell: &str) -> String {
+    cell.split_whitespace().collect::<Vec<_>>().join(" ").replace('|', "\\|")
+}
+
+/// Trim trailing spaces per line and collapse runs of blank lines.
+fn tidy(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank_run = 0;
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 || out.is_empty() {
+                continue;
+            }
+            out.push('\n');
+        } else {
+            blank_run = 0;
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.trim_end().to_string()
+}
+
+// ---------------------------------------------------------------------------
+// DOCX (Office Open XML)
+// ---------------------------------------------------------------------------
+
+/// Extract text from a DOCX file's main document part (`word/document.xml`).
+fn docx_to_text(bytes: &[u8]) -> Result<String, ExtractionError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| ExtractionError::DocumentText(format!("invalid DOCX archive: {e}")))?;
+    let part = archive
+        .by_name("word/document.xml")
+        .map_err(|e| ExtractionError::DocumentText(format!("DOCX has no document body: {e}")))?;
+
+    let mut xml = String::new();
+    part.take(MAX_DOCX_XML_BYTES)
+        .read_to_string(&mut xml)
+        .map_err(|e| ExtractionError::DocumentText(format!("unreadable DOCX body: {e}")))?;
+
+    docx_xml_to_text(&xml)
+}
+
+/// Walk WordprocessingML: paragraphs (`w:p`), runs (`w:r` → `w:t`, `w:tab`,
+/// `w:br`) and tables (`w:tbl` → `w:tr` → `w:tc`).
+fn docx_xml_to_text(xml: &str) -> Result<String, ExtractionError> {
+    let mut reader = Reader::from_str(xml);
+    let mut sink = TextSink::default();
+    let mut in_text = false;
+    let mut run_depth = 0usize;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| ExtractionError::DocumentText(format!("malformed DOCX XML: {e}")))?;
+        match event {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"t" => in_text = true,
+                b"r" => run_depth += 1,
+                b"tbl" => sink.start_table(),
+                b"tr" => sink.start_row(),
+                b"tc" => sink.start_cell(),
+                _ => {}
+            },
+            Event::End(e) => match e.local_name().as_ref() {
+                b"t" => in_text = false,
+                b"r" => run_depth = run_depth.saturating_sub(1),
+                b"p" => sink.line_break(),
+                b"tc" => sink.end_cell(),
+                b"tr" => sink.end_row(),
+                b"tbl" => sink.end_table(),
+                _ => {}
+            },
+            // Tab stops in paragraph properties are also `w:tab` — only count run content
+            Event::Empty(e) if run_depth > 0 => match e.local_name().as_ref() {
+                b"tab" => sink.push_char('\t'),
+                b"br" | b"cr" => sink.line_break(),
+                b"noBreakHyphen" => sink.push_char('-'),
+                _ => {}
+            },
+            Event::Text(e) if in_text => {
+                let text = e
+                    .decode()
+                    .map_err(|e| ExtractionError::DocumentText(format!("DOCX text encoding: {e}")))?;
+                sink.push_str(&text);
+            }
+            Event::GeneralRef(e) if in_text => {
+                if let Ok(Some(c)) = e.resolve_char_ref() {
+                    sink.push_char(c);
+                } else if let Ok(name) = e.decode() {
+                    if let Some(s) = quick_xml::escape::resolve_xml_entity(&name) {
+                        sink.push_str(s);
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(sink.finish())
+}
+
+// ---------------------------------------------------------------------------
+// RTF
+// ---------------------------------------------------------------------------
+
+/// Destinations whose content is metadata, not document text.
+const RTF_SKIP_DESTINATIONS: &[&str] = &[
+    "fonttbl", "colortbl", "stylesheet", "info", "pict", "object", "header", "headerl",
+    "headerr", "headerf", "footer", "footerl", "footerr", "footerf", "listtable",
+    "listoverridetable", "themedata", "colorschememapping", "datastore", "latentstyles",
+    "xmlnstbl", "rsidtbl", "generator", "fldinst", "revtbl", "filetbl", "pgdsctbl",
+];
+
+#[derive(Clone, Copy)]
+struct RtfGroup {
+    skip: bool,
+    /// `\ucN`: fallback characters to skip after each `\uN`.
+    uc: usize,
+}
+
+/// Extract text from RTF: paragraphs, tabs, unicode escapes and tables
+/// (`\cell` / `\row`). Formatting and metadata groups are dropped.
+fn rtf_to_text(bytes: &[u8]) -> String {
+    let mut sink = TextSink::default();
+    let mut stack: Vec<RtfGroup> = Vec::new();
+    let mut group = RtfGroup { skip: false, uc: 1 };
+    let mut intbl = false;
+    let mut skip_fallback = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            b'{' => {
+                stack.push(group);
+                i += 1;
+            }
+            b'}' => {
+                group = stack.pop().unwrap_or(group);
+                i += 1;
+            }
+            b'\\' => {
+                i += 1;
+                let Some(&next) = bytes.get(i) else { break };
+                if next.is_ascii_alphabetic() {
+                    let start = i;
+                    while i < bytes.len() && bytes[i].is_ascii_alphabetic() {
+                        i += 1;
+                    }
+                    let word = std::str::from_utf8(&bytes[start..i]).unwrap_or("");
+                    let num_start = i;
+                    if i < bytes.len() && bytes[i] == b'-' {
+                        i += 1;
+                    }
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    let param: Option<i32> = std::str::from_utf8(&bytes[num_start..i])
+                        .ok()
+                        .and_then(|s| s.parse().ok());
+                    // A single space delimits the control word
+                    if i < bytes.len() && bytes[i] == b' ' {
+                        i += 1;
+                    }
+
+                    if skip_fallback > 0 {
+                        skip_fallback -= 1;
+                        continue;
+                    }
+                    if RTF_SKIP_DESTINATIONS.contains(&word) {
+                        group.skip = true;
+                        continue;
+                    }
+                    if group.skip {
+                        continue;
+                    }
+                    match word {
+                        "par" | "sect" | "page" => {
+                            if intbl {
+                                sink.line_break();
+                            } else {
+                                close_rtf_table(&mut sink);
+                                sink.line_break();
+                            }
+                        }
+                        "line" => sink.line_break(),
+                        "tab" => sink.push_char('\t'),
+                        "pard" => intbl = false,
+                        "intbl" => intbl = true,
+                        "cell" => {
+                            open_rtf_table(&mut sink);
+                            sink.close_cell();
+                        }
+                        "row" => {
+                            open_rtf_table(&mut sink);
+                            sink.end_row();
+                        }
+                        "uc" => group.uc = param.unwrap_or(1).max(0) as usize,
+                        "u" => {
+                            if let Some(n) = param {
+                                // Negative values encode code points above 32767
+                                let code = if n < 0 { n + 65536 } else { n } as u32;
+                                if let Some(c) = char::from_u32(code) {
+                                    push_rtf_char(&mut sink, intbl, c);
+                                }
+                                skip_fallback = group.uc;
+                            }
+                        }
+                        "emdash" => push_rtf_char(&mut sink, intbl, '—'),
+                        "endash" => push_rtf_char(&mut sink, intbl, '–'),
+                        "bullet" => push_rtf_char(&mut sink, intbl, '•'),
+                        "lquote" => push_rtf_char(&mut sink, intbl, '‘'),
+                        "rquote" => push_rtf_char(&mut sink, intbl, '’'),
+                        "ldblquote" => push_rtf_char(&mut sink, intbl, '“'),
+                        "rdblquote" => push_rtf_char(&mut sink, intbl, '”'),
+                        _ => {}
+                    }
+                } else {
+                    i += 1;
+                    match next {
+                        // `{\*\dest ...}` — ignorable destination
+                        b'*' => group.skip = true,
+                        b'\'' => {
+                            let hex = bytes.get(i..i + 2).and_then(|h| std::str::from_utf8(h).ok());
+                            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                                i += 2;
+                                if skip_fallback > 0 {
+                                    skip_fallback -= 1;
+                                } else if !group.skip {
+                                    push_rtf_char(&mut sink, intbl, cp1252_to_char(byte));
+                                }
+                            }
+                        }
+                        _ if skip_fallback > 0 => skip_fallback -= 1,
+                        _ if group.skip => {}
+                        b'~' => push_rtf_char(&mut sink, intbl, ' '),
+                        b'_' => push_rtf_char(&mut sink, intbl, '-'),
+                        b'\\' | b'{' | b'}' => push_rtf_char(&mut sink, intbl, next as char),
+                        b'\n' | b'\r' => {
+                            if intbl {
+                                sink.line_break();
+                            } else {
+                                close_rtf_table(&mut sink);
+                                sink.line_break();
+                            }
+                        }
+                        // `\-` optional hyphen and other symbols carry no text
+                        _ => {}
+                    }
+                }
+            }
+            // Raw line breaks in RTF source are not content
+            b'\r' | b'\n' => i += 1,
+            _ => {
+                i += 1;
+                if skip_fallback > 0 {
+                    skip_fallback -= 1;
+                } else if !group.skip {
+                    push_rtf_char(&mut sink, intbl, cp1252_to_char(b));
+                }
+            }
+        }
+    }
+
+    sink.finish()
+}
+
+fn push_rtf_char(sink: &mut TextSink, intbl: bool, c: char) {
+    if intbl {
+        open_rtf_table(sink);
+    } else {
+        close_rtf_table(sink);
+    }
+    sink.push_char(c);
+}
+
+fn open_rtf_table(sink: &mut TextSink) {
+    if !sink.in_table() {
+        sink.start_table();
+    }
+}
+
+fn close_rtf_table(sink: &mut TextSink) {
+    if sink.in_table() {
+        sink.end_table();
+    }
+}
+
+/// Decode a Windows-1252 byte (RTF's default `\ansi` code page).
+fn cp1252_to_char(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž',
+        '\u{8f}', '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ',
+        '\u{9d}', 'ž', 'Ÿ',
+    ];
+    match byte {
+        0x80..=0x9F => HIGH[(byte - 0x80) as usize],
+        _ => byte as char,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// HTML
+// ---------------------------------------------------------------------------
+
+/// Elements whose content is never visible text.
+const HTML_SKIP_ELEMENTS: &[&str] = &["script", "style", "head", "noscript", "template", "svg"];
+
+/// Elements that start a new line.
+const HTML_BLOCK_ELEMENTS: &[&str] = &[
+    "p", "div", "section", "article", "header", "footer", "main", "aside", "nav", "ul", "ol",
+    "dl", "dt", "dd", "blockquote", "pre", "hr", "form", "fieldset", "address", "figure",
+    "figcaption", "caption",
+];
+
+/// Extract visible text from an HTML page, keeping headings, list items and
+/// tables (as Markdown).
+fn html_to_text(html: &str) -> String {
+    let mut sink = TextSink::default();
+    let bytes = html.as_bytes();
+    let lower = html.to_ascii_lowercase();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'<' {
+            let end = html[i..].find('<').map_or(html.len(), |p| i + p);
+            sink.push_collapsed(&decode_html_entities(&html[i..end]));
+            i = end;
+            continue;
+        }
+
+        if html[i..].starts_with("<!--") {
+            i = html[i + 4..].find("-->").map_or(html.len(), |p| i + 4 + p + 3);
+            continue;
+        }
+
+        let Some(tag_end) = find_tag_end(bytes, i + 1) else {
+            // Unterminated tag — treat the rest as text
+            sink.push_collapsed(&decode_html_entities(&html[i..]));
+            break;
+        };
+        let tag = &html[i + 1..tag_end];
+        i = tag_end + 1;
+
+        let closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if name.is_empty() {
+            // Doctype, processing instruction, stray '<'
+            continue;
+        }
+
+        if !closing && HTML_SKIP_ELEMENTS.contains(&name.as_str()) {
+            if !tag.ends_with('/') {
+                let close = format!("</{name}");
+                i = match lower[i..].find(&close) {
+                    Some(p) => find_tag_end(bytes, i + p + 1).map_or(html.len(), |e| e + 1),
+                    None => html.len(),
+                };
+            }
+            continue;
+        }
+
+        match (name.as_str(), closing) {
+            ("br", _) => sink.line_break(),
+            ("table", false) => sink.start_table(),
+            ("table", true) => sink.end_table(),
+            ("tr", false) => sink.start_row(),
+            ("tr", true) => sink.end_row(),
+            ("td" | "th", false) => sink.start_cell(),
+            ("td" | "th", true) => sink.end_cell(),
+            ("li", false) => {
+                sink.block_break();
+                sink.push_str("- ");
+            }
+            ("li", true) => sink.block_break(),
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => {
+                sink.block_break();
+                if !sink.in_table() {
+                    let level = (name.as_bytes()[1] - b'0') as usize;
+                    sink.push_str(&format!("{} ", "#".repeat(level)));
+                }
+            }
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => sink.block_break(),
+            (block, _) if HTML_BLOCK_ELEMENTS.contains(&block) => sink.block_break(),
+            _ => {}
+        }
+    }
+
+    sink.finish()
+}
+
+/// Find the `>` closing a tag that starts at `from`, skipping quoted attributes.
+fn find_tag_end(bytes: &[u8], from: usize) -> Option<usize> {
+    let mut quote: Option<u8> = None;
+    for (offset, &b) in bytes.get(from..)?.iter().enumerate() {
+        match (quote, b) {
+            (Some(q), _) if b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(b),
+            (None, b'>') => return Some(from + offset),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Decode named (`&eacute;`) and numeric (`&#233;`, `&#xE9;`) character references.
+fn decode_html_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let resolved = rest[1..]
+            .find(';')
+            .filter(|&semi| semi > 0 && semi <= 32)
+            .and_then(|semi| {
+                let entity = &rest[1..1 + semi];
+                let decoded = match entity.strip_prefix('#') {
+                    Some(num) => {
+                        let code = match num.strip_prefix(['x', 'X']) {
+                            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                            None => num.parse().ok(),
+                        };
+                        code.and_then(char::from_u32).map(String::from)
+                    }
+                    None => quick_xml::escape::resolve_html5_entity(entity).map(String::from),
+                };
+                decoded.map(|d| (d, semi + 2))
+            });
+        match resolved {
+            Some((decoded, len)) => {
+                out.push_str(&decoded.replace('\u{a0}', " "));
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn docx_with_body(body: &str) -> Vec<u8> {
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{body}</w:body></w:document>"#
+        );
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let mut writer = zip::ZipWriter::new(&mut buffer);
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated);
+            writer.start_file("[Content_Types].xml", options).unwrap();
+            writer.write_all(b"<Types/>").unwrap();
+            writer.start_file("word/document.xml", options).unwrap();
+            writer.write_all(xml.as_bytes()).unwrap();
+            writer.finish().unwrap();
+        }
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn docx_paragraphs_and_runs() {
+        let docx = docx_with_body(
+            r#"<w:p><w:pPr><w:tabs><w:tab w:val="left" w:pos="720"/></w:tabs></w:pPr>
+<w:r><w:t>Discharge </w:t></w:r><w:r><w:t>letter</w:t></w:r></w:p>
+<w:p><w:r><w:t>Diagnosis:</w:t><w:tab/><w:t>Pneumonia &amp; sepsis</w:t></w:r></w:p>"#,
+        );
+        let text = extract_document_text(docx, DOCX_MIME).unwrap();
+        assert_eq!(text, "Discharge letter\nDiagnosis:\tPneumonia & sepsis");
+    }
+
+    #[test]
+    fn docx_table_becomes_markdown() {
+        let docx = docx_with_body(
+            r#"<w:p><w:r><w:t>Results</w:t></w:r></w:p>
+<w:tbl>
+<w:tr><w:tc><w:p><w:r><w:t>Test</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>Value</w:t></w:r></w:p></w:tc></w:tr>
+<w:tr><w:tc><w:p><w:r><w:t>HbA1c</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>7.2</w:t></w:r></w:p><w:p><w:r><w:t>%</w:t></w:r></w:p></w:tc></w:tr>
+</w:tbl>
+<w:p><w:r><w:t>End</w:t></w:r></w:p>"#,
+        );
+        let text = extract_document_text(docx, DOCX_MIME).unwrap();
+        assert_eq!(
+            text,
+            "Results\n\n| Test | Value |\n| --- | --- |\n| HbA1c | 7.2 % |\n\nEnd"
+        );
+    }
+
+    #[test]
+    fn invalid_docx_is_an_error() {
+        let err = extract_document_text(b"PK\x03\x04 not a zip".to_vec(), DOCX_MIME).unwrap_err();
+        assert!(matches!(err, ExtractionError::DocumentText(_)));
+    }
+
+    #[test]
+    fn rtf_text_skips_metadata_groups() {
+        let rtf = br"{\rtf1\ansi\deff0{\fonttbl{\f0 Times;}}{\colortbl;\red0\green0\blue0;}
+{\*\generator Word;}\pard\plain\f0 Cr\'e9atinine: 72 \u181?mol/L\par
+Second line\tab end\par}";
+        let text = extract_document_text(rtf.to_vec(), RTF_MIME).unwrap();
+        assert_eq!(text, "Créatinine: 72 µmol/L\nSecond line\tend");
+    }
+
+    #[test]
+    fn rtf_table_becomes_markdown() {
+        let rtf = br"{\rtf1\ansi Lab results\par
+\trowd\cellx2000\cellx4000\pard\intbl Test\cell Value\cell\row
+\trowd\cellx2000\cellx4000\pard\intbl Glucose\cell 5.4 mmol/L\cell\row
+\pard After table\par}";
+        let text = extract_document_text(rtf.to_vec(), RTF_MIME).unwrap();
+        assert_eq!(
+            text,
+            "Lab results\n\n| Test | Value |\n| --- | --- |\n| Glucose | 5.4 mmol/L |\n\nAfter table"
+        );
+    }
+
+    #[test]
+    fn html_visible_text_and_table() {
+        let html = r#"<!DOCTYPE html><html><head><title>Portal</title>
+<style>td { color: red; }</style><script>var x = "<td>";</script></head>
+<body><h2>Lab&nbsp;results</h2><!-- hidden -->
+<p>Patient:   Marie
+Dubois</p>
+<table><tr><th>Test</th><th>Result</th></tr>
+<tr><td>Hémoglobine</td><td>13,5 g/dL</td>
+<tr><td>Plaquettes</td><td>250 &times; 10&#179;/&micro;L</td></tr></table>
+<ul><li>Fasting</li><li>Morning draw</li></ul></body></html>"#;
+        let text = extract_document_text(html.as_bytes().to_vec(), HTML_MIME).unwrap();
+        assert_eq!(
+            text,
+            "## Lab results\nPatient: Marie Dubois\n\n| Test | Result |\n| --- | --- |\n\
+             | Hémoglobine | 13,5 g/dL |\n| Plaquettes | 250 × 10³/µL |\n\n- Fasting\n- Morning draw"
+        );
+    }
+
+    #[test]
+    fn html_unknown_entity_kept_literally() {
+        assert_eq!(decode_html_entities("A &notanentity; B & C"), "A &notanentity; B & C");
+    }
+
+    #[test]
+    fn plain_text_passthrough() {
+        let text = extract_document_text(b"Hello".to_vec(), "text/plain").unwrap();
+        assert_eq!(text, "Hello");
+    }
+
+    #[test]
+    fn markdown_table_escapes_pipes_and_pads_columns() {
+        let rows = vec![
+            vec!["A".to_string(), "B".to_string()],
+            vec!["x|y".to_string()],
+        ];
+        assert_eq!(render_markdown_table(&rows), "| A | B |\n| --- | --- |\n| x\\|y |  |");
+    }
+}
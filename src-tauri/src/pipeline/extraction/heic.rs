@@ -0,0 +1,185 @@
+//! HEIC-01: HEIC/HEIF photo decoding (iPhone camera format).
+//!
+//! iPhones save photos as HEIF files holding HEVC-coded still images, usually
+//! as a grid of 512x512 tiles. Decoding is delegated to libheif (`heic`
+//! feature), the reference implementation shipped by most image tools, so
+//! untrusted HEVC bitstreams never go through hand-written parsing here.
+//!
+//! libheif applies the clean aperture and the `irot`/`imir` transforms while
+//! decoding: images come out upright, and the HEIF orientation must not be
+//! applied a second time (see `ExifOrientationCorrector`). Builds without the
+//! `heic` feature still recognise HEIC files and reject them with a clear
+//! error instead of misreading them.
+
+use image::DynamicImage;
+use thiserror::Error;
+
+/// Largest image we are willing to reconstruct (pixels), guarding memory.
+#[cfg(feature = "heic")]
+const MAX_PIXELS: u64 = 100_000_000;
+
+/// Brands that identify HEVC-coded HEIF files.
+const HEVC_BRANDS: [&[u8; 4]; 6] = [b"heic", b"heix", b"heim", b"heis", b"hevc", b"hevx"];
+
+#[derive(Error, Debug)]
+pub enum HeicError {
+    #[error("invalid HEIF file: {0}")]
+    Decode(String),
+
+    #[error("unsupported HEIF content: {0}")]
+    Unsupported(String),
+}
+
+/// Whether `bytes` look like an HEVC-coded HEIF file (`.heic` / `.heif`).
+///
+/// Generic `mif1`/`msf1` files count unless they declare AVIF.
+pub fn is_heic(bytes: &[u8]) -> bool {
+    let Some(brands) = brands(bytes) else {
+        return false;
+    };
+    if brands.iter().any(|b| HEVC_BRANDS.contains(&b)) {
+        return true;
+    }
+    let generic = brands.iter().any(|b| b == b"mif1" || b == b"msf1");
+    let avif = brands.iter().any(|b| b == b"avif" || b == b"avis");
+    generic && !avif
+}
+
+/// Decode the primary image of a HEIC file to upright RGB, cropped to its
+/// clean aperture.
+#[cfg(feature = "heic")]
+pub fn decode_heic(bytes: &[u8]) -> Result<DynamicImage, HeicError> {
+    use libheif_rs::{ColorSpace, HeifContext, LibHeif, RgbChroma};
+
+    let decode_err = |e: libheif_rs::HeifError| HeicError::Decode(e.to_string());
+
+    let context = HeifContext::read_from_bytes(bytes).map_err(decode_err)?;
+    let handle = context.primary_image_handle().map_err(decode_err)?;
+    let (width, height) = (handle.width(), handle.height());
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(HeicError::Unsupported(format!("{width}x{height} image is too large")));
+    }
+
+    let image = LibHeif::new()
+        .decode(&handle, ColorSpace::Rgb(RgbChroma::Rgb), None)
+        .map_err(decode_err)?;
+    let plane = image
+        .planes()
+        .interleaved
+        .ok_or_else(|| HeicError::Decode("decoder returned no RGB plane".into()))?;
+    pack_rgb_rows(plane.data, plane.width, plane.height, plane.stride)
+}
+
+/// Without libheif, HEIC photos are recognised but cannot be read.
+#[cfg(not(feature = "heic"))]
+pub fn decode_heic(_bytes: &[u8]) -> Result<DynamicImage, HeicError> {
+    Err(HeicError::Unsupported(
+        "HEIC photos are not supported in this build".into(),
+    ))
+}
+
+/// Copy a row-padded, interleaved RGB plane into a tightly packed image.
+#[cfg(any(feature = "heic", test))]
+fn pack_rgb_rows(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    stride: usize,
+) -> Result<DynamicImage, HeicError> {
+    let row_bytes = width as usize * 3;
+    let needed = stride
+        .checked_mul((height as usize).saturating_sub(1))
+        .and_then(|n| n.checked_add(row_bytes));
+    if width == 0 || height == 0 || stride < row_bytes || !needed.is_some_and(|n| data.len() >= n) {
+        return Err(HeicError::Decode("decoded RGB plane is truncated".into()));
+    }
+
+    let mut packed = Vec::with_capacity(row_bytes * height as usize);
+    for row in data.chunks(stride).take(height as usize) {
+        packed.extend_from_slice(&row[..row_bytes]);
+    }
+    image::RgbImage::from_raw(width, height, packed)
+        .map(DynamicImage::ImageRgb8)
+        .ok_or_else(|| HeicError::Decode("decoded RGB plane is truncated".into()))
+}
+
+/// Major and compatible brands from the `ftyp` box, if the data starts with one.
+fn brands(data: &[u8]) -> Option<Vec<[u8; 4]>> {
+    if data.len() < 16 || &data[4..8] != b"ftyp" {
+        return None;
+    }
+    let size =
+        (u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize).clamp(16, data.len());
+    let mut out = vec![[data[8], data[9], data[10], data[11]]];
+    out.extend(
+        data[16..size]
+            .chunks_exact(4)
+            .map(|c| [c[0], c[1], c[2], c[3]]),
+    );
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ftyp(major: &[u8; 4], compatible: &[&[u8; 4]]) -> Vec<u8> {
+        let mut out = ((16 + 4 * compatible.len()) as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(b"ftyp");
+        out.extend_from_slice(major);
+        out.extend_from_slice(&[0, 0, 0, 0]);
+        for b in compatible {
+            out.extend_from_slice(*b);
+        }
+        out
+    }
+
+    #[test]
+    fn recognises_heic_brands() {
+        assert!(is_heic(&ftyp(b"heic", &[b"mif1", b"heic"])));
+        assert!(is_heic(&ftyp(b"mif1", &[b"mif1", b"heic"])));
+        assert!(is_heic(&ftyp(b"mif1", &[b"mif1"])));
+        assert!(!is_heic(&ftyp(b"avif", &[b"mif1", b"avif"])));
+        assert!(!is_heic(&ftyp(b"isom", &[b"isom", b"mp41"])));
+        assert!(!is_heic(b"\xFF\xD8\xFF\xE0 not a heif file"));
+    }
+
+    #[test]
+    fn brands_tolerate_oversized_box_length() {
+        let mut bytes = ftyp(b"heic", &[b"mif1"]);
+        bytes[3] = 0xFF;
+        assert_eq!(brands(&bytes).unwrap(), vec![*b"heic", *b"mif1"]);
+    }
+
+    #[test]
+    fn malformed_and_truncated_files_are_errors_not_panics() {
+        let header = ftyp(b"heic", &[b"mif1"]);
+        let mut meta = header.clone();
+        meta.extend_from_slice(&[0, 0, 0, 12, b'm', b'e', b't', b'a', 0, 0, 0, 0]);
+        let mut oversized_box = header.clone();
+        oversized_box.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, b'm', b'e', b't', b'a']);
+
+        for bytes in [&header[..], &header[..10], &meta[..], &oversized_box[..], &[][..]] {
+            assert!(decode_heic(bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn packs_padded_rows() {
+        // 2x2 RGB with 2 bytes of row padding
+        let data = [
+            1, 2, 3, 4, 5, 6, 0, 0, //
+            7, 8, 9, 10, 11, 12, 0, 0,
+        ];
+        let image = pack_rgb_rows(&data, 2, 2, 8).unwrap().to_rgb8();
+        assert_eq!(image.into_raw(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    }
+
+    #[test]
+    fn rejects_truncated_planes() {
+        let data = [0u8; 13];
+        assert!(pack_rgb_rows(&data, 2, 2, 8).is_err());
+        assert!(pack_rgb_rows(&data, 4, 1, 8).is_err());
+        assert!(pack_rgb_rows(&data, 0, 2, 8).is_err());
+    }
+}
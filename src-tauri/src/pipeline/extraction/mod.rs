@@ -6,6 +6,8 @@ pub mod preprocess;
 pub mod orchestrator;
 pub mod vision_ocr;
 pub mod text_only; // CT-01: Text-only extraction (PlainText + DigitalPdf, no vision model)
+pub mod document_text; // DOC-01: DOCX / RTF / HTML → text with Markdown tables
+pub mod heic; // HEIC-01: HEIC/HEIF (iPhone photo) decoding via libheif
 pub mod vision_classifier; // C4-FIX: Lightweight image classification (Document vs MedicalImage)
 
 pub use types::*;
@@ -40,6 +42,14 @@ pub enum ExtractionError {
     #[error("Unsupported format for extraction")]
     UnsupportedFormat,
 
+    /// DOC-01: A DOCX/RTF/HTML document could not be converted to text.
+    #[error("Could not read document text: {0}")]
+    DocumentText(String),
+
+    /// HEIC-01: An iPhone HEIC/HEIF photo could not be decoded.
+    #[error("Could not decode HEIC photo: {0}")]
+    Heic(#[from] heic::HeicError),
+
     // ── R3: Vision-based extraction errors ──
 
     /// pdfium-render failed to render a PDF page to image.
@@ -16,6 +16,7 @@ use base64::Engine;
 use uuid::Uuid;
 
 use super::confidence::compute_overall_confidence;
+use super::document_text::extract_document_text;
 use super::preprocess::ImagePreprocessor;
 use super::sanitize::sanitize_extracted_text;
 use super::types::{
@@ -26,6 +27,7 @@ use super::vision_classifier::VisionClassifier;
 use super::ExtractionError;
 use crate::butler_service::VisionSession;
 use crate::crypto::ProfileSession;
+use crate::pipeline::checkpoint::DocumentCheckpoints;
 use crate::pipeline::diagnostic;
 use crate::pipeline::extraction::types::ExtractionWarning;
 use crate::pipeline::import::format::FileCategory;
@@ -129,12 +131,16 @@ impl DocumentExtractor {
     }
 
     /// Extract text from a PDF: render each page, classify, then extract.
+    ///
+    /// CKP-01: Pages already in `checkpoints` are reused without rendering;
+    /// each newly extracted page is checkpointed before moving on.
     fn extract_pdf(
         &self,
         pdf_bytes: &[u8],
         dpi: u32,
         dump_dir: &Option<std::path::PathBuf>,
         progress: Option<&crate::pipeline::processor::ProgressTracker>,
+        checkpoints: Option<&DocumentCheckpoints>,
     ) -> Result<(ExtractionMethod, Vec<PageExtraction>), ExtractionError> {
         let num_pages = self.pdf_renderer.page_count(pdf_bytes)?;
 
@@ -146,11 +152,23 @@ impl DocumentExtractor {
         if let Some(tracker) = progress {
             tracker.page_total.store(num_pages.min(255) as u8, std::sync::atomic::Ordering::Relaxed);
             tracker.page_current.store(0, std::sync::atomic::Ordering::Relaxed);
+            tracker.pages_resumed.store(0, std::sync::atomic::Ordering::Relaxed);
         }
 
         let mut pages = Vec::with_capacity(num_pages);
 
         for page_idx in 0..num_pages {
+            // CKP-01: Reuse the page from an interrupted run
+            if let Some(page) = checkpoints.and_then(|c| c.load_page_extraction(page_idx)) {
+                tracing::info!(page = page_idx + 1, total = num_pages, "Resuming: page OCR restored from checkpoint");
+                pages.push(page);
+                if let Some(tracker) = progress {
+                    tracker.pages_resumed.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    tracker.page_current.store((page_idx + 1).min(255) as u8, std::sync::atomic::Ordering::Relaxed);
+                }
+                continue;
+            }
+
             let page_image = self
                 .pdf_renderer
                 .render_page(pdf_bytes, page_idx, dpi)?;
@@ -190,6 +208,9 @@ impl DocumentExtractor {
                     self.interpret_medical_page(&prepared.png_bytes, page_idx, prepared.warnings)?
                 }
             };
+            if let Some(c) = checkpoints {
+                c.save_page_extraction(page_idx, &page);
+            }
             pages.push(page);
 
             // §22: Update extraction page progress
@@ -202,12 +223,25 @@ impl DocumentExtractor {
     }
 
     /// Extract text from an image: preprocess, classify, then extract.
+    ///
+    /// CKP-01: A checkpointed result from an interrupted run is reused.
     fn extract_image(
         &self,
         image_bytes: &[u8],
         dump_dir: &Option<std::path::PathBuf>,
         progress: Option<&crate::pipeline::processor::ProgressTracker>,
+        checkpoints: Option<&DocumentCheckpoints>,
     ) -> Result<(ExtractionMethod, Vec<PageExtraction>), ExtractionError> {
+        if let Some(page) = checkpoints.and_then(|c| c.load_page_extraction(0)) {
+            tracing::info!("Resuming: image OCR restored from checkpoint");
+            if let Some(tracker) = progress {
+                tracker.page_total.store(1, std::sync::atomic::Ordering::Relaxed);
+                tracker.page_current.store(1, std::sync::atomic::Ordering::Relaxed);
+                tracker.pages_resumed.store(1, std::sync::atomic::Ordering::Relaxed);
+            }
+            return Ok((ExtractionMethod::VisionOcr, vec![page]));
+        }
+
         if let Some(ref dir) = dump_dir {
             diagnostic::dump_binary(dir, "01-raw-image-0.bin", image_bytes);
         }
@@ -243,6 +277,9 @@ impl DocumentExtractor {
                 self.interpret_medical_page(&prepared.png_bytes, 0, prepared.warnings)?
             }
         };
+        if let Some(c) = checkpoints {
+            c.save_page_extraction(0, &page);
+        }
 
         // §22: Single-page image — set progress as complete
         if let Some(tracker) = progress {
@@ -296,7 +333,8 @@ impl DocumentExtractor {
                     + drill_result.entities.allergies.len()
                     + drill_result.entities.procedures.len()
                     + drill_result.entities.referrals.len()
-                    + drill_result.entities.instructions.len(),
+                    + drill_result.entities.instructions.len()
+                    + drill_result.entities.immunizations.len(),
                 "lab_results": drill_result.entities.lab_results.len(),
                 "medications": drill_result.entities.medications.len(),
                 "diagnoses": drill_result.entities.diagnoses.len(),
@@ -416,7 +454,8 @@ fn compute_drill_confidence(
         + e.allergies.len()
         + e.procedures.len()
         + e.referrals.len()
-        + e.instructions.len();
+        + e.instructions.len()
+        + e.immunizations.len();
 
     if total == 0 {
         return 0.3; // Empty extraction — low confidence
@@ -477,17 +516,38 @@ impl TextExtractor for DocumentExtractor {
             }));
         }
 
+        // CKP-01: Page checkpoints for vision pages, valid only for this
+        // input and settings (plain text extraction is instant)
+        let checkpoints = match format.category {
+            FileCategory::DigitalPdf | FileCategory::ScannedPdf | FileCategory::Image => {
+                DocumentCheckpoints::open(session, document_id)
+            }
+            _ => None,
+        };
+        if let Some(ref c) = checkpoints {
+            c.bind_source(&format!(
+                "{}|{}|{}|{}|{:?}",
+                staged_path.file_name().and_then(|n| n.to_str()).unwrap_or_default(),
+                decrypted_bytes.len(),
+                dpi,
+                self.language,
+                self.user_document_type,
+            ));
+        }
+
         let (method, mut pages) = match &format.category {
             // All PDFs → pdfium render → classify → extract
             FileCategory::DigitalPdf | FileCategory::ScannedPdf => {
-                self.extract_pdf(&decrypted_bytes, dpi, &dump_dir, progress)?
+                self.extract_pdf(&decrypted_bytes, dpi, &dump_dir, progress, checkpoints.as_ref())?
             }
             // Images → classify → extract
-            FileCategory::Image => self.extract_image(&decrypted_bytes, &dump_dir, progress)?,
-            // Plain text → UTF-8 read (no model needed)
+            FileCategory::Image => {
+                self.extract_image(&decrypted_bytes, &dump_dir, progress, checkpoints.as_ref())?
+            }
+            // Plain text / DOCX / RTF / HTML → direct text read (no model needed)
             FileCategory::PlainText => {
-                let text = String::from_utf8(decrypted_bytes)
-                    .map_err(|e| ExtractionError::EncodingError(e.to_string()))?;
+                // DOC-01: DOCX / RTF / HTML are converted by MIME type; plain text as-is
+                let text = extract_document_text(decrypted_bytes, &format.mime_type)?;
                 let page = PageExtraction {
                     page_number: 1,
                     text,
@@ -170,73 +170,133 @@ impl PdfPageRenderer for PdfiumRenderer {
         let document = pdfium
             .load_pdf_from_byte_slice(pdf_bytes, None)
             .map_err(map_load_error)?;
+        render_document_page(&document, page_number, dpi)
+    }
+}
 
-        let pages = document.pages();
-
-        let page_index = u16::try_from(page_number).map_err(|_| ExtractionError::PdfRendering {
+/// Render one page of a loaded document to PNG bytes.
+fn render_document_page(
+    document: &PdfDocument,
+    page_number: usize,
+    dpi: u32,
+) -> Result<Vec<u8>, ExtractionError> {
+    let pages = document.pages();
+
+    let page_index = u16::try_from(page_number).map_err(|_| ExtractionError::PdfRendering {
+        page: page_number,
+        reason: format!("Page index {page_number} exceeds u16 maximum"),
+    })?;
+
+    let page = pages
+        .get(page_index)
+        .map_err(|_| ExtractionError::PdfRendering {
             page: page_number,
-            reason: format!("Page index {page_number} exceeds u16 maximum"),
+            reason: format!(
+                "Page {page_number} out of range (document has {} pages)",
+                pages.len()
+            ),
         })?;
 
-        let page = pages
-            .get(page_index)
-            .map_err(|_| ExtractionError::PdfRendering {
-                page: page_number,
-                reason: format!(
-                    "Page {page_number} out of range (document has {} pages)",
-                    pages.len()
-                ),
-            })?;
-
-        let width_points = page.width().value;
-        let height_points = page.height().value;
-        let (target_w, target_h) = compute_render_dimensions(width_points, height_points, dpi);
-
-        let uncapped_w = (width_points * dpi as f32 / POINTS_PER_INCH) as u32;
-        let uncapped_h = (height_points * dpi as f32 / POINTS_PER_INCH) as u32;
-        if target_w != uncapped_w || target_h != uncapped_h {
-            warn!(
-                page = page_number,
-                raw_width = uncapped_w,
-                raw_height = uncapped_h,
-                capped_width = target_w,
-                capped_height = target_h,
-                "Page dimensions capped to {MAX_DIMENSION_PX}px",
-            );
-        }
+    let width_points = page.width().value;
+    let height_points = page.height().value;
+    let (target_w, target_h) = compute_render_dimensions(width_points, height_points, dpi);
 
-        let config = PdfRenderConfig::new()
-            .set_target_width(target_w as i32)
-            .set_maximum_height(target_h as i32);
+    let uncapped_w = (width_points * dpi as f32 / POINTS_PER_INCH) as u32;
+    let uncapped_h = (height_points * dpi as f32 / POINTS_PER_INCH) as u32;
+    if target_w != uncapped_w || target_h != uncapped_h {
+        warn!(
+            page = page_number,
+            raw_width = uncapped_w,
+            raw_height = uncapped_h,
+            capped_width = target_w,
+            capped_height = target_h,
+            "Page dimensions capped to {MAX_DIMENSION_PX}px",
+        );
+    }
 
-        let bitmap = page
-            .render_with_config(&config)
-            .map_err(|e| ExtractionError::PdfRendering {
-                page: page_number,
-                reason: format!("Rendering failed: {e}"),
-            })?;
+    let config = PdfRenderConfig::new()
+        .set_target_width(target_w as i32)
+        .set_maximum_height(target_h as i32);
 
-        let dynamic_image = bitmap.as_image();
-        let mut cursor = Cursor::new(Vec::new());
-        dynamic_image
-            .write_to(
-                &mut cursor,
-                ImageOutputFormat::Png,
-            )
-            .map_err(|e| ExtractionError::ImageProcessing(format!("PNG encoding failed: {e}")))?;
+    let bitmap = page
+        .render_with_config(&config)
+        .map_err(|e| ExtractionError::PdfRendering {
+            page: page_number,
+            reason: format!("Rendering failed: {e}"),
+        })?;
 
-        let png_bytes = cursor.into_inner();
+    let dynamic_image = bitmap.as_image();
+    let mut cursor = Cursor::new(Vec::new());
+    dynamic_image
+        .write_to(
+            &mut cursor,
+            ImageOutputFormat::Png,
+        )
+        .map_err(|e| ExtractionError::ImageProcessing(format!("PNG encoding failed: {e}")))?;
+
+    let png_bytes = cursor.into_inner();
+
+    debug!(
+        page = page_number,
+        width = target_w,
+        height = target_h,
+        png_size = png_bytes.len(),
+        "Rendered PDF page to PNG"
+    );
+
+    Ok(png_bytes)
+}
 
-        debug!(
-            page = page_number,
-            width = target_w,
-            height = target_h,
-            png_size = png_bytes.len(),
-            "Rendered PDF page to PNG"
-        );
+/// PWD-01: A password-protected PDF opened and copied without its encryption.
+pub struct UnlockedPdf {
+    /// Unencrypted PDF holding every page of the original, text layer included.
+    pub bytes: Vec<u8>,
+    pub page_count: usize,
+    /// Whether any page carries extractable text (digital vs scanned PDF).
+    pub has_text_layer: bool,
+}
 
-        Ok(png_bytes)
+/// PWD-01: Open a password-protected PDF and copy its pages into a new,
+/// unprotected document.
+///
+/// Candidate passwords are tried in order. Pages are copied as-is, so text,
+/// fonts and vector content survive; the caller stages the result encrypted
+/// at rest like any other document. Returns `PdfEncrypted` when none opens it.
+pub fn decrypt_protected_pdf(
+    pdf_bytes: &[u8],
+    passwords: &[String],
+) -> Result<UnlockedPdf, ExtractionError> {
+    let pdfium = load_pdfium()?;
+    for password in passwords {
+        let source = match pdfium.load_pdf_from_byte_slice(pdf_bytes, Some(password.as_str())) {
+            Ok(document) => document,
+            Err(e) => match map_load_error(e) {
+                ExtractionError::PdfEncrypted => continue,
+                other => return Err(other),
+            },
+        };
+        let page_count = source.pages().len() as usize;
+        let has_text_layer = source
+            .pages()
+            .iter()
+            .any(|page| page.text().is_ok_and(|text| !text.all().trim().is_empty()));
+
+        let copy_err = |e: PdfiumError| ExtractionError::PdfRendering {
+            page: 0,
+            reason: format!("Failed to copy unlocked PDF: {e}"),
+        };
+        let mut unlocked = pdfium.create_new_pdf().map_err(copy_err)?;
+        unlocked.pages_mut().append(&source).map_err(copy_err)?;
+        let bytes = unlocked.save_to_bytes().map_err(copy_err)?;
+
+        debug!(pages = page_count, has_text_layer, "Protected PDF unlocked");
+        return Ok(UnlockedPdf {
+            bytes,
+            page_count,
+            has_text_layer,
+        });
     }
+    Err(ExtractionError::PdfEncrypted)
 }
 
 // ── Mock for testing ──────────────────────────────────────
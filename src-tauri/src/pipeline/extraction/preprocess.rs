@@ -21,6 +21,7 @@ use image::imageops::FilterType;
 use image::{DynamicImage, GenericImageView, GrayImage, ImageOutputFormat, Luma, Rgb, RgbImage};
 use tracing::debug;
 
+use super::heic;
 use super::types::ExtractionWarning;
 use super::ExtractionError;
 
@@ -313,9 +314,7 @@ impl ImagePreprocessor for PreprocessingPipeline {
         validate_image_bytes(image_bytes)?;
 
         // 2. Decode image
-        let img = image::load_from_memory(image_bytes).map_err(|e| {
-            ExtractionError::ImageProcessing(format!("Failed to decode image: {e}"))
-        })?;
+        let img = decode_image(image_bytes)?;
         let (orig_w, orig_h) = img.dimensions();
 
         // 3. Fix EXIF orientation
@@ -381,7 +380,13 @@ pub struct ExifOrientationCorrector;
 
 impl OrientationCorrector for ExifOrientationCorrector {
     fn correct(&self, raw_bytes: &[u8], image: DynamicImage) -> DynamicImage {
-        let orientation = read_exif_orientation(raw_bytes);
+        // HEIC-01: libheif already applied the irot/imir transforms, and any
+        // EXIF orientation inside a HEIF file must be ignored.
+        let orientation = if heic::is_heic(raw_bytes) {
+            1
+        } else {
+            read_exif_orientation(raw_bytes)
+        };
         apply_orientation(image, orientation)
     }
 }
@@ -1034,6 +1039,22 @@ pub fn validate_image_bytes(bytes: &[u8]) -> Result<(), ExtractionError> {
     Ok(())
 }
 
+/// Decode image bytes, including iPhone HEIC photos (HEIC-01).
+/// Orientation is NOT applied — see `decode_oriented`.
+pub fn decode_image(bytes: &[u8]) -> Result<DynamicImage, ExtractionError> {
+    if heic::is_heic(bytes) {
+        return Ok(heic::decode_heic(bytes)?);
+    }
+    image::load_from_memory(bytes)
+        .map_err(|e| ExtractionError::ImageProcessing(format!("Failed to decode image: {e}")))
+}
+
+/// Decode image bytes and apply their EXIF (or HEIF) orientation.
+pub fn decode_oriented(bytes: &[u8]) -> Result<DynamicImage, ExtractionError> {
+    let img = decode_image(bytes)?;
+    Ok(ExifOrientationCorrector.correct(bytes, img))
+}
+
 /// Pre-downscale oversized images to reduce memory before the main resize.
 /// Uses `Cow` to avoid cloning when no downscale is needed.
 fn pre_downscale(img: &RgbImage, max_dim: u32) -> Cow<'_, RgbImage> {
@@ -1,7 +1,7 @@
 //! CT-01: Text-only extraction — no vision model required.
 //!
 //! Handles two file categories without any LLM:
-//! - `PlainText`: UTF-8 read (same as DocumentExtractor's plain text path)
+//! - `PlainText`: UTF-8 read, or DOCX/RTF/HTML conversion (same as DocumentExtractor's plain text path)
 //! - `DigitalPdf`: PDFium native text layer extraction (no rendering, no OCR)
 //!
 //! Returns `ExtractionError::UnsupportedFormat` for `ScannedPdf`/`Image` —
@@ -12,10 +12,12 @@ use std::path::Path;
 use uuid::Uuid;
 
 use super::confidence::compute_overall_confidence;
+use super::document_text::extract_document_text;
 use super::pdfium::{load_pdfium, map_load_error};
 use super::sanitize::sanitize_extracted_text;
 use super::types::{
-    ExtractionMethod, ExtractionResult, PageExtraction, TextExtractor,
+    BoundingBox, ExtractionMethod, ExtractionResult, PageExtraction, RegionConfidence,
+    TextExtractor,
 };
 use super::ExtractionError;
 use crate::crypto::ProfileSession;
@@ -48,8 +50,8 @@ impl TextExtractor for PlainTextExtractor {
 
         let (method, mut pages) = match &format.category {
             FileCategory::PlainText => {
-                let text = String::from_utf8(decrypted_bytes)
-                    .map_err(|e| ExtractionError::EncodingError(e.to_string()))?;
+                // DOC-01: DOCX / RTF / HTML are converted by MIME type; plain text as-is
+                let text = extract_document_text(decrypted_bytes, &format.mime_type)?;
                 let page = PageExtraction {
                     page_number: 1,
                     text,
@@ -131,16 +133,46 @@ fn extract_pdf_text_layer(
     let mut pages = Vec::with_capacity(page_count as usize);
 
     for (idx, page) in document.pages().iter().enumerate() {
-        let text = page
-            .text()
-            .map(|t| t.all())
-            .unwrap_or_default();
+        let page_width = page.width().value;
+        let page_height = page.height().value;
+
+        let (text, regions) = match page.text() {
+            Ok(page_text) => {
+                // PROV-01: One region per text segment (same line + font run),
+                // so extracted values can be traced back to a box on the page.
+                let regions = page_text
+                    .segments()
+                    .iter()
+                    .filter_map(|segment| {
+                        let bounds = segment.bounds();
+                        let segment_text = segment.text();
+                        if segment_text.trim().is_empty() {
+                            return None;
+                        }
+                        Some(RegionConfidence {
+                            text: segment_text,
+                            confidence: 0.95,
+                            bounding_box: normalize_pdf_rect(
+                                bounds.left().value,
+                                bounds.top().value,
+                                bounds.right().value,
+                                bounds.bottom().value,
+                                page_width,
+                                page_height,
+                            ),
+                        })
+                    })
+                    .collect();
+                (page_text.all(), regions)
+            }
+            Err(_) => (String::new(), vec![]),
+        };
 
         pages.push(PageExtraction {
             page_number: idx + 1,
             text,
             confidence: 0.95,
-            regions: vec![],
+            regions,
             warnings: vec![],
             content_type: None,
             drill_output: None,
@@ -150,6 +182,31 @@ fn extract_pdf_text_layer(
     Ok((ExtractionMethod::PdfDirect, pages))
 }
 
+/// PROV-01: Convert a PDF rectangle (points, origin bottom-left) into a
+/// page-relative bounding box (fractions, origin top-left).
+///
+/// Returns `None` for degenerate pages or rectangles.
+fn normalize_pdf_rect(
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+    page_width: f32,
+    page_height: f32,
+) -> Option<BoundingBox> {
+    if page_width <= 0.0 || page_height <= 0.0 || right <= left || top <= bottom {
+        return None;
+    }
+    let x = (left / page_width).clamp(0.0, 1.0);
+    let y = (1.0 - top / page_height).clamp(0.0, 1.0);
+    Some(BoundingBox {
+        x,
+        y,
+        width: ((right - left) / page_width).clamp(0.0, 1.0 - x),
+        height: ((top - bottom) / page_height).clamp(0.0, 1.0 - y),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,4 +314,20 @@ mod tests {
         assert!(!result.full_text.contains('\x01'));
         assert!(result.full_text.contains("test"));
     }
+
+    #[test]
+    fn pdf_rect_normalized_to_top_left_origin() {
+        // A4 page, segment near the top-left corner
+        let b = normalize_pdf_rect(59.5, 800.0, 297.5, 780.0, 595.0, 842.0).unwrap();
+        assert!((b.x - 0.1).abs() < 1e-3);
+        assert!((b.y - (1.0 - 800.0 / 842.0)).abs() < 1e-3);
+        assert!((b.width - 0.4).abs() < 1e-3);
+        assert!((b.height - 20.0 / 842.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn pdf_rect_degenerate_rejected() {
+        assert!(normalize_pdf_rect(10.0, 10.0, 5.0, 0.0, 595.0, 842.0).is_none());
+        assert!(normalize_pdf_rect(0.0, 10.0, 5.0, 0.0, 0.0, 842.0).is_none());
+    }
 }
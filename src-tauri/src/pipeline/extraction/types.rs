@@ -22,7 +22,7 @@ pub struct ExtractionResult {
 pub enum ExtractionMethod {
     /// R3: Vision model read page image → structured Markdown.
     VisionOcr,
-    /// Plain text file — direct read, no model needed.
+    /// Text-bearing file (plain text, DOCX, RTF, HTML) — direct read, no model needed.
     PlainTextRead,
     /// Legacy: Digital PDF text operators (kept for deserialization compat).
     PdfDirect,
@@ -58,13 +58,28 @@ pub struct RegionConfidence {
     pub bounding_box: Option<BoundingBox>,
 }
 
-/// Bounding box for a text region (for highlighting in review screen)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Bounding box for a text region (for highlighting in review screen).
+///
+/// PROV-01: Page-relative coordinates — fractions of the page size (0.0–1.0),
+/// origin at the top-left — so the box is independent of the render DPI.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct BoundingBox {
-    pub x: u32,
-    pub y: u32,
-    pub width: u32,
-    pub height: u32,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl From<BoundingBox> for crate::models::provenance::SourceRegion {
+    fn from(b: BoundingBox) -> Self {
+        Self {
+            x: b.x,
+            y: b.y,
+            width: b.width,
+            height: b.height,
+        }
+        .clamped()
+    }
 }
 
 /// Warnings about extraction quality
@@ -114,6 +114,8 @@ fn parse_classification_response(response: &str) -> ImageContentType {
 pub enum UserDocumentType {
     LabReport,
     Prescription,
+    /// IMM-01: Vaccination booklet or immunization certificate.
+    VaccinationRecord,
     MedicalImage,
 }
 
@@ -123,6 +125,7 @@ impl UserDocumentType {
         match self {
             Self::LabReport => ImageContentType::Document,
             Self::Prescription => ImageContentType::Document,
+            Self::VaccinationRecord => ImageContentType::Document,
             Self::MedicalImage => ImageContentType::MedicalImage,
         }
     }
@@ -132,6 +135,7 @@ impl UserDocumentType {
         match s {
             "lab_report" => Some(Self::LabReport),
             "prescription" => Some(Self::Prescription),
+            "vaccination_record" => Some(Self::VaccinationRecord),
             "medical_image" => Some(Self::MedicalImage),
             _ => None,
         }
@@ -327,6 +331,7 @@ mod tests {
     fn user_doc_type_from_str_parses_valid() {
         assert_eq!(UserDocumentType::from_str("lab_report"), Some(UserDocumentType::LabReport));
         assert_eq!(UserDocumentType::from_str("prescription"), Some(UserDocumentType::Prescription));
+        assert_eq!(UserDocumentType::from_str("vaccination_record"), Some(UserDocumentType::VaccinationRecord));
         assert_eq!(UserDocumentType::from_str("medical_image"), Some(UserDocumentType::MedicalImage));
         assert_eq!(UserDocumentType::from_str("unknown"), None);
         assert_eq!(UserDocumentType::from_str(""), None);
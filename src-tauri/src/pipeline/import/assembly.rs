@@ -0,0 +1,160 @@
+//! MPA-01: Multi-photo document assembly.
+//!
+//! A multi-page report photographed page by page arrives as several images.
+//! The ordered photos are combined into a single image-only PDF (one photo per
+//! page) so the document goes through the same per-page render → classify →
+//! drill path as any scanned PDF, and ends up as one `documents` row.
+//!
+//! Page size is derived from the photo's pixel size at `ASSEMBLY_DPI`, which
+//! matches the scanned-PDF render DPI — rendering the page back yields the
+//! photo at its original resolution.
+
+use base64::Engine;
+use printpdf::{
+    ColorBits, ColorSpace, Image, ImageFilter, ImageTransform, ImageXObject, Mm, PdfDocument, Px,
+};
+use sha2::{Digest, Sha256};
+
+use crate::pipeline::extraction::preprocess;
+
+use super::ImportError;
+
+/// Resolution used to size pages (matches `SCANNED_PDF_DPI` in extraction).
+const ASSEMBLY_DPI: f32 = 300.0;
+
+/// JPEG quality for re-encoded pages.
+const PAGE_JPEG_QUALITY: u8 = 92;
+
+/// Combine ordered page images (JPEG/PNG/TIFF bytes) into one PDF.
+///
+/// Returns the PDF bytes. Fails if any page cannot be decoded as an image.
+pub fn assemble_image_pages(pages: &[Vec<u8>]) -> Result<Vec<u8>, ImportError> {
+    if pages.is_empty() {
+        return Err(ImportError::ImageProcessing("No pages to assemble".into()));
+    }
+
+    let encoded = pages
+        .iter()
+        .enumerate()
+        .map(|(i, bytes)| {
+            encode_page(bytes).map_err(|e| {
+                ImportError::ImageProcessing(format!("Page {}: {e}", i + 1))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (first_w, first_h, _) = &encoded[0];
+    let (doc, page1, layer1) = PdfDocument::new(
+        "Assembled document",
+        px_to_mm(*first_w),
+        px_to_mm(*first_h),
+        "Page 1",
+    );
+
+    for (i, (width, height, jpeg)) in encoded.into_iter().enumerate() {
+        let layer = if i == 0 {
+            doc.get_page(page1).get_layer(layer1)
+        } else {
+            let (page, layer) =
+                doc.add_page(px_to_mm(width), px_to_mm(height), format!("Page {}", i + 1));
+            doc.get_page(page).get_layer(layer)
+        };
+
+        let image = Image::from(ImageXObject {
+            width: Px(width as usize),
+            height: Px(height as usize),
+            color_space: ColorSpace::Rgb,
+            bits_per_component: ColorBits::Bit8,
+            interpolate: true,
+            image_data: jpeg,
+            image_filter: Some(ImageFilter::DCT),
+            smask: None,
+            clipping_bbox: None,
+        });
+        image.add_to_layer(
+            layer,
+            ImageTransform {
+                dpi: Some(ASSEMBLY_DPI),
+                ..Default::default()
+            },
+        );
+    }
+
+    doc.save_to_bytes()
+        .map_err(|e| ImportError::ImageProcessing(format!("PDF assembly failed: {e}")))
+}
+
+/// Content hash of an ordered page group (exact duplicate detection).
+///
+/// Hashes the ordered per-page digests, so the same photos in a different
+/// order form a different document.
+pub fn page_group_hash(pages: &[Vec<u8>]) -> String {
+    let mut hasher = Sha256::new();
+    for page in pages {
+        hasher.update(Sha256::digest(page));
+    }
+    base64::engine::general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+/// Decode one page (upright, HEIC included) and re-encode it as baseline RGB JPEG.
+fn encode_page(bytes: &[u8]) -> Result<(u32, u32, Vec<u8>), String> {
+    let rgb = preprocess::decode_oriented(bytes)
+        .map_err(|e| e.to_string())?
+        .to_rgb8();
+    let (width, height) = rgb.dimensions();
+
+    let mut jpeg = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg, PAGE_JPEG_QUALITY)
+        .encode(rgb.as_raw(), width, height, image::ColorType::Rgb8)
+        .map_err(|e| e.to_string())?;
+
+    Ok((width, height, jpeg))
+}
+
+fn px_to_mm(px: u32) -> Mm {
+    Mm(px as f32 * 25.4 / ASSEMBLY_DPI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_page(width: u32, height: u32, shade: u8) -> Vec<u8> {
+        let img = image::RgbImage::from_pixel(width, height, image::Rgb([shade, shade, shade]));
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut bytes, image::ImageOutputFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn assembles_one_pdf_page_per_image() {
+        let pages = vec![png_page(60, 80, 200), png_page(80, 60, 100), png_page(60, 80, 50)];
+        let pdf = assemble_image_pages(&pages).unwrap();
+
+        assert!(pdf.starts_with(b"%PDF"));
+        // Page tree root counts the pages
+        assert!(String::from_utf8_lossy(&pdf).contains("/Count 3"));
+    }
+
+    #[test]
+    fn empty_group_rejected() {
+        assert!(assemble_image_pages(&[]).is_err());
+    }
+
+    #[test]
+    fn undecodable_page_rejected() {
+        let pages = vec![png_page(10, 10, 0), b"not an image".to_vec()];
+        let err = assemble_image_pages(&pages).unwrap_err();
+        assert!(err.to_string().contains("Page 2"));
+    }
+
+    #[test]
+    fn group_hash_depends_on_order() {
+        let a = png_page(10, 10, 0);
+        let b = png_page(10, 10, 255);
+        assert_eq!(page_group_hash(&[a.clone(), b.clone()]), page_group_hash(&[a.clone(), b.clone()]));
+        assert_ne!(page_group_hash(&[a.clone(), b.clone()]), page_group_hash(&[b, a]));
+    }
+}
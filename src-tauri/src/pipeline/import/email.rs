@@ -0,0 +1,536 @@
+//! EML-01: Email (.eml / .mbox) import.
+//!
+//! Lab results and specialist letters often arrive by email. An email is a
+//! container, not a document: each PDF or image attachment is imported as its
+//! own document, and the text body becomes a plain-text document when it
+//! carries clinical content. The message `Date` and `From` headers are kept
+//! as hints — the document date is set from them unless structuring finds a
+//! better one, and the sender is linked only when they already are a known
+//! professional (a `From` header can be anyone) — and the subject goes into
+//! the notes. Parts are imported from memory: nothing is written to disk
+//! unencrypted.
+
+use std::path::Path;
+
+use chrono::NaiveDate;
+use mail_parser::{Message, MessageParser, MimeHeaders};
+use rusqlite::Connection;
+
+use crate::crypto::ProfileSession;
+use crate::db::repository;
+
+use super::format::{detect_format, detect_format_bytes, sanitize_filename, FileCategory};
+use super::importer::{import_bytes, ImportResult, ImportStatus};
+use super::ImportError;
+
+/// MIME type reported by format detection for a single message (.eml).
+pub const EML_MIME: &str = "message/rfc822";
+/// MIME type reported by format detection for a mailbox (.mbox).
+pub const MBOX_MIME: &str = "application/mbox";
+
+/// Messages read from one mailbox (guards against importing a whole archive).
+const MAX_MBOX_MESSAGES: usize = 500;
+
+/// Headers that mark the start of an RFC 5322 message.
+const MESSAGE_HEADERS: &[&str] = &[
+    "from", "to", "date", "subject", "message-id", "mime-version", "received", "return-path",
+    "reply-to", "cc", "delivered-to", "content-type",
+];
+
+/// Body shorter than this is a cover note ("Please find attached…").
+const MIN_CLINICAL_BODY_CHARS: usize = 80;
+
+/// Words that mark a body as clinical (en/fr/de, lowercase).
+const CLINICAL_TERMS: &[&str] = &[
+    "diagnosis", "diagnosed", "prescription", "prescribed", "medication", "dosage", "dose",
+    "blood pressure", "cholesterol", "glucose", "hba1c", "hemoglobin", "haemoglobin",
+    "creatinine", "laboratory", "lab result", "test result", "biopsy", "x-ray", "mri", "scan",
+    "symptom", "treatment", "allergy", "vaccine", "referral", "follow-up", "discharge",
+    "diagnostic", "ordonnance", "traitement", "posologie", "analyse", "résultat", "tension",
+    "allergie", "vaccin", "diagnose", "befund", "medikament", "blutdruck", "behandlung",
+    "überweisung", "laborwert",
+];
+
+/// Dose and lab-unit suffixes following a number (e.g. "500 mg", "5.4 mmol/l").
+const CLINICAL_UNITS: &[&str] = &[
+    "mg", "mcg", "µg", "ml", "mmol/l", "mg/dl", "g/dl", "g/l", "ui", "iu", "mmhg", "bpm",
+];
+
+/// One message of an email file.
+#[derive(Debug, Clone, Default)]
+pub struct EmailMessage {
+    pub date: Option<NaiveDate>,
+    /// Sender display name, or address when there is none.
+    pub sender: Option<String>,
+    pub subject: Option<String>,
+    pub attachments: Vec<EmailAttachment>,
+    /// Plain-text body (HTML bodies converted).
+    pub body_text: Option<String>,
+}
+
+/// An attached file.
+#[derive(Debug, Clone)]
+pub struct EmailAttachment {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Outcome of importing an email file.
+#[derive(Debug, Default)]
+pub struct EmailImport {
+    /// Attachment and body documents (staged or duplicate).
+    pub documents: Vec<ImportResult>,
+    /// Messages read from the file.
+    pub messages: usize,
+    /// Attachments that are not PDFs or images.
+    pub skipped_attachments: usize,
+    /// Parts that failed to import ("name: reason"); the others still are.
+    pub failed_parts: Vec<String>,
+}
+
+/// Detect an email file from its first bytes: `Some(EML_MIME)` for a
+/// message, `Some(MBOX_MIME)` for a mailbox.
+pub fn detect_email(head: &[u8]) -> Option<&'static str> {
+    let text = String::from_utf8_lossy(head);
+    let text = text.trim_start_matches('\u{feff}');
+
+    // mbox: "From sender date" separator line, then the first message
+    if let Some(rest) = text.strip_prefix("From ") {
+        let (_, message) = rest.split_once('\n')?;
+        return is_message_head(message).then_some(MBOX_MIME);
+    }
+    is_message_head(text).then_some(EML_MIME)
+}
+
+/// A header block with a `From:` and at least one other message header.
+fn is_message_head(text: &str) -> bool {
+    let mut known = 0;
+    let mut has_from = false;
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            continue; // folded header
+        }
+        let Some((name, _)) = line.split_once(':') else {
+            return false;
+        };
+        if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-') {
+            return false;
+        }
+        let name = name.to_ascii_lowercase();
+        if MESSAGE_HEADERS.contains(&name.as_str()) {
+            known += 1;
+            has_from |= name == "from";
+        }
+    }
+    has_from && known >= 2
+}
+
+/// Whether detected format is an email container.
+pub fn is_email_mime(mime_type: &str) -> bool {
+    mime_type == EML_MIME || mime_type == MBOX_MIME
+}
+
+/// Read all messages of an `.eml` or `.mbox` file.
+pub fn read_messages(path: &Path, mime_type: &str) -> Result<Vec<EmailMessage>, ImportError> {
+    let bytes = std::fs::read(path)?;
+    if mime_type != MBOX_MIME {
+        let message = parse_message(&bytes)
+            .ok_or_else(|| ImportError::FileReadError("Not a readable email message".into()))?;
+        return Ok(vec![message]);
+    }
+
+    let mut messages = Vec::new();
+    let reader = mail_parser::mailbox::mbox::MessageIterator::new(std::io::Cursor::new(bytes));
+    for entry in reader.take(MAX_MBOX_MESSAGES) {
+        match entry {
+            Ok(raw) => {
+                if let Some(message) = parse_message(raw.contents()) {
+                    messages.push(message);
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "Skipping unreadable mailbox entry"),
+        }
+    }
+    Ok(messages)
+}
+
+/// Parse one RFC 5322 message.
+pub fn parse_message(raw: &[u8]) -> Option<EmailMessage> {
+    let message = MessageParser::default().parse(raw)?;
+    let mut email = EmailMessage {
+        date: message
+            .date()
+            .and_then(|d| NaiveDate::from_ymd_opt(d.year.into(), d.month.into(), d.day.into())),
+        sender: message.from().and_then(|from| from.first()).and_then(|addr| {
+            addr.name()
+                .or(addr.address())
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        }),
+        subject: message.subject().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()),
+        attachments: Vec::new(),
+        body_text: message
+            .body_text(0)
+            .map(|body| body.trim().to_string())
+            .filter(|body| !body.is_empty()),
+    };
+    collect_attachments(&message, &mut email.attachments);
+    Some(email)
+}
+
+/// Attachments of a message, including those of forwarded messages.
+fn collect_attachments(message: &Message, out: &mut Vec<EmailAttachment>) {
+    for part in message.attachments() {
+        if let Some(nested) = part.message() {
+            collect_attachments(nested, out);
+            continue;
+        }
+        if part.contents().is_empty() {
+            continue;
+        }
+        let filename = part
+            .attachment_name()
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("attachment-{}", out.len() + 1));
+        out.push(EmailAttachment {
+            filename,
+            bytes: part.contents().to_vec(),
+        });
+    }
+}
+
+/// Whether an email body is worth importing as a document.
+///
+/// Heuristic: long enough to be more than a cover note, and either two
+/// clinical terms or one clinical term next to a dose/lab value. Terms match
+/// whole words only ("mri" is not in "primary").
+pub fn is_clinical_text(text: &str) -> bool {
+    if text.trim().chars().count() < MIN_CLINICAL_BODY_CHARS {
+        return false;
+    }
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .map(|w| w.trim_matches('-'))
+        .filter(|w| !w.is_empty())
+        .collect();
+    let terms = CLINICAL_TERMS.iter().filter(|t| contains_term(&words, t)).count();
+    terms >= 2 || (terms == 1 && has_measurement(&lower))
+}
+
+/// Whether the words of `term` ("blood pressure") appear consecutively.
+fn contains_term(words: &[&str], term: &str) -> bool {
+    let parts: Vec<&str> = term.split(' ').collect();
+    words.windows(parts.len()).any(|w| w == parts.as_slice())
+}
+
+/// A number followed by a clinical unit ("500 mg", "5.4mmol/l").
+fn has_measurement(lower: &str) -> bool {
+    let tokens: Vec<&str> = lower.split_whitespace().collect();
+    tokens.iter().enumerate().any(|(i, token)| {
+        let digits = token.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c == ',');
+        let has_number = digits.len() < token.len();
+        let unit = if has_number && !digits.is_empty() {
+            Some(digits)
+        } else if has_number {
+            tokens.get(i + 1).copied()
+        } else {
+            None
+        };
+        unit.map(|u| u.trim_end_matches(|c: char| !c.is_alphanumeric()))
+            .is_some_and(|u| CLINICAL_UNITS.contains(&u))
+    })
+}
+
+/// Import every PDF/image attachment and clinical body of an email file.
+///
+/// Parts are imported from memory with `import_bytes`, so duplicate
+/// detection and encrypted staging work as for any file. Each message's body
+/// is imported before its attachments, and a part that fails is recorded in
+/// `failed_parts` without abandoning the parts already imported.
+pub fn import_email(
+    source_path: &Path,
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<EmailImport, ImportError> {
+    let format = detect_format(source_path)?;
+    if !is_email_mime(&format.mime_type) {
+        return Err(ImportError::UnsupportedFormat("Not an email file".into()));
+    }
+    let messages = read_messages(source_path, &format.mime_type)?;
+
+    let mut outcome = EmailImport {
+        messages: messages.len(),
+        ..EmailImport::default()
+    };
+    for message in &messages {
+        if let Some(body) = message.body_text.as_deref().filter(|b| is_clinical_text(b)) {
+            let title = message.subject.as_deref().unwrap_or("Email");
+            let filename = format!("{title}.txt");
+            match import_bytes(&filename, body_document(message, body).as_bytes(), session, conn) {
+                Ok(result) => {
+                    apply_message_hints(conn, &result, message);
+                    outcome.documents.push(result);
+                }
+                Err(e) => record_failure(&mut outcome, &filename, &e),
+            }
+        }
+
+        for attachment in &message.attachments {
+            match import_attachment(attachment, message, session, conn) {
+                Ok(Some(result)) => outcome.documents.push(result),
+                Ok(None) => outcome.skipped_attachments += 1,
+                Err(e) => record_failure(&mut outcome, &attachment.filename, &e),
+            }
+        }
+    }
+
+    tracing::info!(
+        messages = outcome.messages,
+        documents = outcome.documents.len(),
+        skipped = outcome.skipped_attachments,
+        failed = outcome.failed_parts.len(),
+        "Email imported"
+    );
+    Ok(outcome)
+}
+
+fn record_failure(outcome: &mut EmailImport, name: &str, error: &ImportError) {
+    tracing::warn!(error = %error, "Email part could not be imported");
+    outcome
+        .failed_parts
+        .push(format!("{}: {error}", sanitize_filename(name)));
+}
+
+/// Import one attachment if it is a PDF or image. `None` when skipped.
+fn import_attachment(
+    attachment: &EmailAttachment,
+    message: &EmailMessage,
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<Option<ImportResult>, ImportError> {
+    let category = detect_format_bytes(&attachment.bytes)?.category;
+    if !matches!(category, FileCategory::DigitalPdf | FileCategory::ScannedPdf | FileCategory::Image) {
+        tracing::debug!(category = category.as_str(), "Skipping non-document email attachment");
+        return Ok(None);
+    }
+    let result = import_bytes(&attachment.filename, &attachment.bytes, session, conn)?;
+    apply_message_hints(conn, &result, message);
+    Ok(Some(result))
+}
+
+/// Body document text: the message headers keep sender and date context.
+fn body_document(message: &EmailMessage, body: &str) -> String {
+    let mut text = String::new();
+    if let Some(ref sender) = message.sender {
+        text.push_str(&format!("From: {sender}\n"));
+    }
+    if let Some(date) = message.date {
+        text.push_str(&format!("Date: {date}\n"));
+    }
+    if let Some(ref subject) = message.subject {
+        text.push_str(&format!("Subject: {subject}\n"));
+    }
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text.push_str(body);
+    text
+}
+
+/// Record the message date, sender and subject on a newly staged document.
+///
+/// The sender becomes the document's professional only when a professional
+/// of that name already exists; otherwise it stays in the note, for review.
+fn apply_message_hints(conn: &Connection, result: &ImportResult, message: &EmailMessage) {
+    if result.status != ImportStatus::Staged {
+        return;
+    }
+    let update = || -> Result<(), ImportError> {
+        let Some(mut doc) = repository::get_document(conn, &result.document_id)? else {
+            return Ok(());
+        };
+        doc.document_date = doc.document_date.or(message.date);
+        if doc.professional_id.is_none() {
+            if let Some(ref sender) = message.sender {
+                doc.professional_id = repository::find_professional_by_name(conn, sender)?.map(|p| p.id);
+            }
+        }
+        if doc.notes.is_none() {
+            doc.notes = email_note(message);
+        }
+        repository::update_document(conn, &doc)?;
+        Ok(())
+    };
+    if let Err(e) = update() {
+        tracing::warn!(document_id = %result.document_id, error = %e, "Failed to record email context");
+    }
+}
+
+/// "Email from Dr. Martin — Subject" note for the document.
+fn email_note(message: &EmailMessage) -> Option<String> {
+    match (&message.sender, &message.subject) {
+        (Some(sender), Some(subject)) => Some(format!("Email from {sender} — {subject}")),
+        (Some(sender), None) => Some(format!("Email from {sender}")),
+        (None, Some(subject)) => Some(format!("Email — {subject}")),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::profile;
+    use crate::db::sqlite::open_database;
+
+    const LETTER: &str = "Dear Ms Durand,\r\n\r\nYour blood test shows hemoglobin 13.5 g/dL and glucose 5.4 mmol/L.\r\nNo change to your medication is needed. Follow-up in 6 months.\r\n";
+
+    fn message_with_attachment(attachment: &[u8], name: &str) -> Vec<u8> {
+        use base64::Engine;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(attachment);
+        format!(
+            "From: \"Dr. Claire Martin\" <c.martin@clinic.example>\r\n\
+             To: patient@example.com\r\n\
+             Subject: Blood test results\r\n\
+             Date: Tue, 14 May 2024 09:30:00 +0200\r\n\
+             MIME-Version: 1.0\r\n\
+             Content-Type: multipart/mixed; boundary=\"XYZ\"\r\n\
+             \r\n\
+             --XYZ\r\n\
+             Content-Type: text/plain; charset=utf-8\r\n\
+             \r\n\
+             {LETTER}\r\n\
+             --XYZ\r\n\
+             Content-Type: application/octet-stream; name=\"{name}\"\r\n\
+             Content-Disposition: attachment; filename=\"{name}\"\r\n\
+             Content-Transfer-Encoding: base64\r\n\
+             \r\n\
+             {encoded}\r\n\
+             --XYZ--\r\n"
+        )
+        .into_bytes()
+    }
+
+    fn png_bytes() -> Vec<u8> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scan.png");
+        img_hash::image::RgbImage::from_pixel(32, 32, img_hash::image::Rgb([120u8, 80, 200]))
+            .save(&path)
+            .unwrap();
+        std::fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn detects_eml_and_mbox() {
+        let eml = message_with_attachment(b"x", "a.bin");
+        assert_eq!(detect_email(&eml), Some(EML_MIME));
+
+        let mut mbox = b"From c.martin@clinic.example Tue May 14 09:30:00 2024\n".to_vec();
+        mbox.extend_from_slice(&eml);
+        assert_eq!(detect_email(&mbox), Some(MBOX_MIME));
+
+        assert_eq!(detect_email(b"From: me\nNot a header line\n"), None);
+        assert_eq!(detect_email(b"Hemoglobin 13.5 g/dL\nGlucose 5.4 mmol/L\n"), None);
+    }
+
+    #[test]
+    fn parses_headers_body_and_attachments() {
+        let message = parse_message(&message_with_attachment(b"%PDF-1.4 test", "results.pdf")).unwrap();
+        assert_eq!(message.sender.as_deref(), Some("Dr. Claire Martin"));
+        assert_eq!(message.subject.as_deref(), Some("Blood test results"));
+        assert_eq!(message.date, NaiveDate::from_ymd_opt(2024, 5, 14));
+        assert!(message.body_text.unwrap().contains("hemoglobin 13.5"));
+        assert_eq!(message.attachments.len(), 1);
+        assert_eq!(message.attachments[0].filename, "results.pdf");
+        assert_eq!(message.attachments[0].bytes, b"%PDF-1.4 test");
+    }
+
+    #[test]
+    fn clinical_body_heuristic() {
+        assert!(is_clinical_text(LETTER));
+        assert!(!is_clinical_text("Please find attached your results. Kind regards, the lab team."));
+        assert!(!is_clinical_text(
+            "Hi! Just a reminder that our office will be closed on Monday for the holiday. See you soon."
+        ));
+        // Clinical terms inside other words do not count
+        assert!(!is_clinical_text(
+            "Our primary newsletter this month: the extension of our opening hours deserves your \
+             attention, plus a scandal-free recap of the town fair and two doses of good news."
+        ));
+        assert!(is_clinical_text(
+            "Your blood pressure was 150/95 at the visit; we discussed a follow-up MRI next month."
+        ));
+    }
+
+    fn setup() -> (tempfile::TempDir, ProfileSession, Connection) {
+        let dir = tempfile::tempdir().unwrap();
+        let (info, _phrase) =
+            profile::create_profile(dir.path(), "EmailTest", "test_pass_123", None, None, None, None).unwrap();
+        let session = profile::open_profile(dir.path(), &info.id, "test_pass_123").unwrap();
+        let conn = open_database(session.db_path(), Some(session.key_bytes())).unwrap();
+        (dir, session, conn)
+    }
+
+    #[test]
+    fn import_creates_documents_with_email_context() {
+        let (_dir, session, conn) = setup();
+
+        let src = tempfile::tempdir().unwrap();
+        let path = src.path().join("results.eml");
+        std::fs::write(&path, message_with_attachment(&png_bytes(), "scan.png")).unwrap();
+
+        let outcome = import_email(&path, &session, &conn).unwrap();
+        assert_eq!(outcome.messages, 1);
+        assert_eq!(outcome.documents.len(), 2, "attachment + clinical body");
+        assert!(outcome.documents.iter().all(|d| d.status == ImportStatus::Staged));
+
+        for result in &outcome.documents {
+            let doc = repository::get_document(&conn, &result.document_id).unwrap().unwrap();
+            assert_eq!(doc.document_date, NaiveDate::from_ymd_opt(2024, 5, 14));
+            assert!(doc.professional_id.is_none(), "unknown sender is not made a professional");
+            assert_eq!(doc.notes.as_deref(), Some("Email from Dr. Claire Martin — Blood test results"));
+        }
+
+        assert!(repository::get_all_professionals(&conn).unwrap().is_empty());
+
+        // Re-importing the same email finds the documents already present
+        let again = import_email(&path, &session, &conn).unwrap();
+        assert!(again.documents.iter().all(|d| d.status == ImportStatus::Duplicate));
+    }
+
+    #[test]
+    fn known_sender_is_linked_as_professional() {
+        let (_dir, session, conn) = setup();
+        let known = repository::find_or_create_professional(&conn, "Dr. Claire Martin", None).unwrap();
+
+        let src = tempfile::tempdir().unwrap();
+        let path = src.path().join("results.eml");
+        std::fs::write(&path, message_with_attachment(&png_bytes(), "scan.png")).unwrap();
+
+        let outcome = import_email(&path, &session, &conn).unwrap();
+        for result in &outcome.documents {
+            let doc = repository::get_document(&conn, &result.document_id).unwrap().unwrap();
+            assert_eq!(doc.professional_id, Some(known.id));
+        }
+        assert_eq!(repository::get_all_professionals(&conn).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn failed_attachment_keeps_the_other_parts() {
+        let (_dir, session, conn) = setup();
+        let src = tempfile::tempdir().unwrap();
+        let path = src.path().join("results.eml");
+        let protected = b"%PDF-1.7\n1 0 obj << /Type /Catalog >> endobj\ntrailer << /Encrypt 2 0 R >>\n%%EOF";
+        std::fs::write(&path, message_with_attachment(protected, "locked.pdf")).unwrap();
+
+        let outcome = import_email(&path, &session, &conn).unwrap();
+        assert_eq!(outcome.documents.len(), 1, "body imported");
+        assert_eq!(outcome.documents[0].status, ImportStatus::Staged);
+        assert_eq!(outcome.failed_parts.len(), 1);
+        assert!(outcome.failed_parts[0].starts_with("locked.pdf"));
+    }
+}
@@ -1,9 +1,11 @@
-use std::io::Read;
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
 use super::ImportError;
+use crate::pipeline::extraction::document_text::{DOCX_MIME, HTML_MIME, RTF_MIME};
+use crate::pipeline::extraction::heic;
+use super::email;
 
 /// Broad file categories we handle
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -50,28 +52,28 @@ const MAX_FILE_SIZE: u64 = 100 * 1024 * 1024; // 100MB
 /// Magic bytes don't lie — extensions can be wrong.
 pub fn detect_format(path: &Path) -> Result<FormatDetection, ImportError> {
     let metadata = std::fs::metadata(path)?;
-    let file_size = metadata.len();
+    check_size(metadata.len())?;
+    detect_format_bytes(&std::fs::read(path)?)
+}
 
-    if file_size > MAX_FILE_SIZE {
-        return Err(ImportError::FileTooLarge {
-            size_mb: file_size as f64 / (1024.0 * 1024.0),
-            max_mb: MAX_FILE_SIZE / (1024 * 1024),
-        });
-    }
+/// EML-01: Detect the format of in-memory content (e.g. an email attachment),
+/// so it never has to be written out in plaintext first.
+pub fn detect_format_bytes(bytes: &[u8]) -> Result<FormatDetection, ImportError> {
+    let file_size = bytes.len() as u64;
+    check_size(file_size)?;
 
-    // Read first 16 bytes for magic number detection
-    let mut file = std::fs::File::open(path)?;
-    let mut header = [0u8; 16];
-    let bytes_read = file.read(&mut header)?;
+    // First 16 bytes for magic number detection
+    let header = head(bytes, 16);
+    let bytes_read = header.len();
 
     let (mime_type, category, is_digital_pdf) = match &header[..bytes_read.min(8)] {
         // PDF: starts with %PDF
         [0x25, 0x50, 0x44, 0x46, ..] => {
             // EXT-03-G03: Check for encryption before processing
-            if check_pdf_encrypted(path).unwrap_or(false) {
+            if check_pdf_encrypted(bytes) {
                 return Err(ImportError::EncryptedPdf);
             }
-            let is_digital = check_pdf_has_text(path).unwrap_or(false);
+            let is_digital = check_pdf_has_text(bytes);
             let category = if is_digital {
                 FileCategory::DigitalPdf
             } else {
@@ -92,40 +94,48 @@ pub fn detect_format(path: &Path) -> Result<FormatDetection, ImportError> {
             ("image/tiff".to_string(), FileCategory::Image, None)
         }
         // HEIC/HEIF: "ftyp" at offset 4 (iPhone photos)
-        // EXT-03-G01: HEIC not supported by image crate v0.23 — return clear error
+        // HEIC-01: HEVC-coded HEIF is decoded through libheif
         _ if bytes_read >= 12 && &header[4..8] == b"ftyp" => {
-            return Err(ImportError::UnsupportedFormat(
-                "HEIC/HEIF photos (iPhone format) are not yet supported — please convert to JPEG or PNG first".into(),
-            ));
+            if heic::is_heic(head(bytes, 256)) {
+                ("image/heic".to_string(), FileCategory::Image, None)
+            } else {
+                return Err(ImportError::UnsupportedFormat(
+                    "AVIF images and video files are not supported — please convert to JPEG or PNG first".into(),
+                ));
+            }
         }
         // DOCX/XLSX/PPTX: ZIP archive with PK signature (Office Open XML)
         [0x50, 0x4B, 0x03, 0x04, ..] => {
-            // Check if it's a DOCX by looking for word/ directory
-            if check_zip_contains(path, "word/") {
+            // DOC-01: Word documents are read via their text (tables kept as Markdown)
+            if check_docx(bytes) {
+                (DOCX_MIME.to_string(), FileCategory::PlainText, None)
+            } else {
                 return Err(ImportError::UnsupportedFormat(
-                    "DOCX (Word) documents are not yet supported — please export as PDF".into(),
+                    "ZIP-based documents (XLSX/PPTX) are not yet supported — please export as PDF".into(),
                 ));
             }
-            return Err(ImportError::UnsupportedFormat(
-                "ZIP-based documents (DOCX/XLSX) are not yet supported — please export as PDF".into(),
-            ));
         }
         // RTF: starts with {\rtf
-        [0x7B, 0x5C, 0x72, 0x74, ..] => {
-            return Err(ImportError::UnsupportedFormat(
-                "RTF documents are not yet supported — please export as PDF".into(),
-            ));
-        }
+        [0x7B, 0x5C, 0x72, 0x74, ..] => (RTF_MIME.to_string(), FileCategory::PlainText, None),
         // DICOM: "DICM" at offset 128
-        _ if bytes_read >= 8 && file_size > 132 && check_dicom_magic(path) => {
+        _ if bytes_read >= 8 && file_size > 132 && check_dicom_magic(bytes) => {
             return Err(ImportError::UnsupportedFormat(
                 "DICOM medical images are not yet supported — please export as PDF or JPEG".into(),
             ));
         }
         _ => {
             // Try as plain text (UTF-8 validation on first chunk)
-            if is_likely_text(path)? {
-                ("text/plain".to_string(), FileCategory::PlainText, None)
+            if is_likely_text(bytes) {
+                // DOC-01: Saved portal pages are converted from HTML, not read raw
+                // EML-01: Emails are split into their attachments and body
+                let mime = if let Some(email_mime) = email::detect_email(head(bytes, 4096)) {
+                    email_mime
+                } else if check_html(bytes) {
+                    HTML_MIME
+                } else {
+                    "text/plain"
+                };
+                (mime.to_string(), FileCategory::PlainText, None)
             } else {
                 (
                     "application/octet-stream".to_string(),
@@ -144,16 +154,26 @@ pub fn detect_format(path: &Path) -> Result<FormatDetection, ImportError> {
     })
 }
 
+fn check_size(file_size: u64) -> Result<(), ImportError> {
+    if file_size > MAX_FILE_SIZE {
+        return Err(ImportError::FileTooLarge {
+            size_mb: file_size as f64 / (1024.0 * 1024.0),
+            max_mb: MAX_FILE_SIZE / (1024 * 1024),
+        });
+    }
+    Ok(())
+}
+
+/// The first `len` bytes (or all of them, if shorter).
+fn head(bytes: &[u8], len: usize) -> &[u8] {
+    &bytes[..bytes.len().min(len)]
+}
+
 /// Check if a PDF has extractable text (digital vs scanned).
 /// Uses a heuristic: search for text stream markers in raw PDF bytes.
-fn check_pdf_has_text(path: &Path) -> Result<bool, ImportError> {
-    let file = std::fs::File::open(path)?;
-    let mut buffer = Vec::new();
-    // Read up to 256KB to check for text markers
-    let mut limited = file.take(256 * 1024);
-    limited.read_to_end(&mut buffer)?;
-
-    let content = String::from_utf8_lossy(&buffer);
+fn check_pdf_has_text(bytes: &[u8]) -> bool {
+    // Check up to 256KB for text markers
+    let content = String::from_utf8_lossy(head(bytes, 256 * 1024));
 
     // Count text-related PDF operators:
     // BT/ET = begin/end text, Tj/TJ = show text, Tf = set font
@@ -164,65 +184,49 @@ fn check_pdf_has_text(path: &Path) -> Result<bool, ImportError> {
         .sum();
 
     // Heuristic: >= 3 text markers suggests a digital PDF with text layer
-    Ok(marker_count >= 3)
+    marker_count >= 3
 }
 
 /// Check if a PDF is password-protected by looking for /Encrypt dictionary.
 /// This is a fast heuristic check on raw bytes — no full PDF parsing needed.
-fn check_pdf_encrypted(path: &Path) -> Result<bool, ImportError> {
-    let file = std::fs::File::open(path)?;
-    let mut buffer = Vec::new();
-    // Read up to 64KB — /Encrypt entry is typically in the trailer/xref area
-    let mut limited = file.take(64 * 1024);
-    limited.read_to_end(&mut buffer)?;
-
-    let content = String::from_utf8_lossy(&buffer);
+fn check_pdf_encrypted(bytes: &[u8]) -> bool {
+    // Check up to 64KB — /Encrypt entry is typically in the trailer/xref area
+    let content = String::from_utf8_lossy(head(bytes, 64 * 1024));
 
     // PDF encrypted files contain an /Encrypt dictionary entry
-    Ok(content.contains("/Encrypt"))
+    content.contains("/Encrypt")
 }
 
-/// Check if a ZIP archive likely contains a DOCX by scanning for "word/" in raw bytes.
-/// This is a heuristic — ZIP local file headers contain the filename in plaintext.
-fn check_zip_contains(path: &Path, pattern: &str) -> bool {
-    let Ok(file) = std::fs::File::open(path) else {
+/// Check if a ZIP archive is a Word document (has a `word/document.xml` part).
+fn check_docx(bytes: &[u8]) -> bool {
+    let Ok(archive) = zip::ZipArchive::new(std::io::Cursor::new(bytes)) else {
         return false;
     };
-    let mut buffer = Vec::new();
-    let mut limited = file.take(32 * 1024);
-    if limited.read_to_end(&mut buffer).is_err() {
-        return false;
-    }
-    let content = String::from_utf8_lossy(&buffer);
-    content.contains(pattern)
+    archive.index_for_name("word/document.xml").is_some()
+}
+
+/// Check if a text file is an HTML page (markup at the start of the file).
+fn check_html(bytes: &[u8]) -> bool {
+    let head = String::from_utf8_lossy(head(bytes, 1024)).to_ascii_lowercase();
+    let head = head.trim_start_matches('\u{feff}').trim_start();
+    head.starts_with('<') && (head.contains("<!doctype html") || head.contains("<html") || head.contains("<body"))
 }
 
 /// Check if a file has DICOM magic ("DICM" at offset 128).
-fn check_dicom_magic(path: &Path) -> bool {
-    let Ok(mut file) = std::fs::File::open(path) else {
-        return false;
-    };
-    let mut buffer = [0u8; 132];
-    if file.read_exact(&mut buffer).is_err() {
-        return false;
-    }
-    &buffer[128..132] == b"DICM"
+fn check_dicom_magic(bytes: &[u8]) -> bool {
+    bytes.get(128..132) == Some(b"DICM".as_slice())
 }
 
 /// Check if a file is likely plain text (valid UTF-8, mostly printable)
-fn is_likely_text(path: &Path) -> Result<bool, ImportError> {
-    let mut file = std::fs::File::open(path)?;
-    let mut buffer = vec![0u8; 4096];
-    let n = file.read(&mut buffer)?;
-    buffer.truncate(n);
-
-    if n == 0 {
-        return Ok(false);
+fn is_likely_text(bytes: &[u8]) -> bool {
+    let buffer = head(bytes, 4096);
+    if buffer.is_empty() {
+        return false;
     }
 
-    let text = match std::str::from_utf8(&buffer) {
+    let text = match std::str::from_utf8(buffer) {
         Ok(t) => t,
-        Err(_) => return Ok(false),
+        Err(_) => return false,
     };
 
     // At least 80% printable characters (or whitespace)
@@ -231,7 +235,7 @@ fn is_likely_text(path: &Path) -> Result<bool, ImportError> {
         .filter(|c| !c.is_control() || c.is_whitespace())
         .count();
     let ratio = printable as f64 / text.len().max(1) as f64;
-    Ok(ratio > 0.80)
+    ratio > 0.80
 }
 
 /// Sanitize a filename — strip path components, limit length
@@ -396,48 +400,106 @@ mod tests {
     }
 
     #[test]
-    fn heic_detected_with_helpful_message() {
+    fn heic_detected_as_image() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("photo.heic");
         // HEIC: "ftyp" at offset 4
         let mut data = vec![0x00, 0x00, 0x00, 0x18]; // box size
         data.extend_from_slice(b"ftyp");
         data.extend_from_slice(b"heic"); // brand
-        data.extend_from_slice(&[0u8; 100]); // rest of header
+        data.extend_from_slice(&[0u8; 4]); // minor version
+        data.extend_from_slice(b"mif1heic"); // compatible brands
+        data.extend_from_slice(&[0u8; 100]); // rest of file
         std::fs::write(&path, &data).unwrap();
-        let err = detect_format(&path).unwrap_err();
-        let msg = err.to_string();
-        assert!(msg.contains("HEIC") || msg.contains("HEIF"), "Should mention HEIC: {}", msg);
-        assert!(msg.contains("JPEG") || msg.contains("PNG"), "Should suggest conversion: {}", msg);
+        let format = detect_format(&path).unwrap();
+        assert_eq!(format.mime_type, "image/heic");
+        assert_eq!(format.category, FileCategory::Image);
+    }
+
+    #[test]
+    fn avif_and_video_rejected_with_helpful_message() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, brand) in [("photo.avif", b"avif"), ("clip.mp4", b"isom")] {
+            let path = dir.path().join(name);
+            let mut data = vec![0x00, 0x00, 0x00, 0x14];
+            data.extend_from_slice(b"ftyp");
+            data.extend_from_slice(brand);
+            data.extend_from_slice(&[0u8; 4]);
+            data.extend_from_slice(brand);
+            data.extend_from_slice(&[0u8; 100]);
+            std::fs::write(&path, &data).unwrap();
+            let msg = detect_format(&path).unwrap_err().to_string();
+            assert!(msg.contains("JPEG") || msg.contains("PNG"), "Should suggest conversion: {}", msg);
+        }
     }
 
     #[test]
-    fn docx_detected_with_helpful_message() {
+    fn docx_detected_as_text_document() {
+        use std::io::Write;
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("report.docx");
-        // Minimal ZIP/DOCX: PK signature + "word/" in local file header
-        let mut data = vec![0x50, 0x4B, 0x03, 0x04]; // PK signature
-        data.extend_from_slice(b"\x14\x00\x00\x00\x08\x00"); // version, flags
-        data.extend_from_slice(b"\x00\x00\x00\x00\x00\x00\x00\x00"); // timestamps
-        data.extend_from_slice(b"\x00\x00\x00\x00"); // crc, sizes
-        data.extend_from_slice(b"\x05\x00\x00\x00"); // filename length = 5
-        data.extend_from_slice(b"word/"); // filename
-        std::fs::write(&path, &data).unwrap();
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        writer
+            .start_file("word/document.xml", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"<w:document/>").unwrap();
+        writer.finish().unwrap();
+
+        let format = detect_format(&path).unwrap();
+        assert_eq!(format.category, FileCategory::PlainText);
+        assert_eq!(format.mime_type, DOCX_MIME);
+    }
+
+    #[test]
+    fn other_zip_rejected_with_helpful_message() {
+        use std::io::Write;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sheet.xlsx");
+        let mut writer = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        writer
+            .start_file("xl/workbook.xml", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"<workbook/>").unwrap();
+        writer.finish().unwrap();
+
         let err = detect_format(&path).unwrap_err();
         let msg = err.to_string();
-        assert!(msg.contains("DOCX") || msg.contains("Word"), "Should mention DOCX: {}", msg);
+        assert!(msg.contains("XLSX"), "Should mention XLSX: {}", msg);
         assert!(msg.contains("PDF"), "Should suggest PDF export: {}", msg);
     }
 
     #[test]
-    fn rtf_detected_with_helpful_message() {
+    fn rtf_detected_as_text_document() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("notes.rtf");
         std::fs::write(&path, b"{\\rtf1\\ansi Hello world}").unwrap();
-        let err = detect_format(&path).unwrap_err();
-        let msg = err.to_string();
-        assert!(msg.contains("RTF"), "Should mention RTF: {}", msg);
-        assert!(msg.contains("PDF"), "Should suggest PDF export: {}", msg);
+        let format = detect_format(&path).unwrap();
+        assert_eq!(format.category, FileCategory::PlainText);
+        assert_eq!(format.mime_type, RTF_MIME);
+    }
+
+    #[test]
+    fn html_page_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("portal.htm");
+        std::fs::write(&path, "\u{feff}<!DOCTYPE html>\n<html><body><p>Résultats</p></body></html>").unwrap();
+        let format = detect_format(&path).unwrap();
+        assert_eq!(format.category, FileCategory::PlainText);
+        assert_eq!(format.mime_type, HTML_MIME);
+    }
+
+    #[test]
+    fn email_message_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("results.eml");
+        std::fs::write(
+            &path,
+            "From: Lab <results@lab.example>\r\nSubject: Your results\r\nDate: Tue, 14 May 2024 09:30:00 +0200\r\n\r\nSee attached.\r\n",
+        )
+        .unwrap();
+        let format = detect_format(&path).unwrap();
+        assert_eq!(format.category, FileCategory::PlainText);
+        assert_eq!(format.mime_type, email::EML_MIME);
     }
 
     #[test]
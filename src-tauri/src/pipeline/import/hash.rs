@@ -3,15 +3,22 @@ use std::path::Path;
 use base64::Engine;
 use sha2::{Digest, Sha256};
 
+use crate::pipeline::extraction::heic;
+
 use super::format::FileCategory;
 use super::ImportError;
 
 /// Compute the appropriate hash based on file category
 pub fn compute_hash(path: &Path, category: &FileCategory) -> Result<String, ImportError> {
+    compute_hash_bytes(&std::fs::read(path)?, category)
+}
+
+/// EML-01: Hash in-memory content (e.g. an email attachment).
+pub fn compute_hash_bytes(bytes: &[u8], category: &FileCategory) -> Result<String, ImportError> {
     match category {
-        FileCategory::Image => compute_image_hash(path),
-        FileCategory::DigitalPdf | FileCategory::ScannedPdf => compute_content_hash(path),
-        FileCategory::PlainText => compute_content_hash(path),
+        FileCategory::Image => image_hash(bytes),
+        FileCategory::DigitalPdf | FileCategory::ScannedPdf => Ok(content_hash(bytes)),
+        FileCategory::PlainText => Ok(content_hash(bytes)),
         FileCategory::Unsupported => Err(ImportError::UnsupportedFormat("cannot hash unsupported format".into())),
     }
 }
@@ -20,8 +27,17 @@ pub fn compute_hash(path: &Path, category: &FileCategory) -> Result<String, Impo
 /// Uses DoubleGradient algorithm (256-bit hash) for near-duplicate detection.
 /// Uses img_hash's re-exported image crate for compatibility.
 pub fn compute_image_hash(path: &Path) -> Result<String, ImportError> {
-    let img =
-        img_hash::image::open(path).map_err(|e| ImportError::ImageProcessing(e.to_string()))?;
+    image_hash(&std::fs::read(path)?)
+}
+
+fn image_hash(bytes: &[u8]) -> Result<String, ImportError> {
+    // HEIC-01: iPhone photos are decoded through libheif.
+    let img = if heic::is_heic(bytes) {
+        heic::decode_heic(bytes).map_err(|e| ImportError::ImageProcessing(e.to_string()))?
+    } else {
+        img_hash::image::load_from_memory(bytes)
+            .map_err(|e| ImportError::ImageProcessing(e.to_string()))?
+    };
 
     let hasher = img_hash::HasherConfig::new()
         .hash_alg(img_hash::HashAlg::DoubleGradient)
@@ -34,9 +50,11 @@ pub fn compute_image_hash(path: &Path) -> Result<String, ImportError> {
 
 /// Compute SHA-256 content hash for PDFs and text files
 pub fn compute_content_hash(path: &Path) -> Result<String, ImportError> {
-    let content = std::fs::read(path)?;
-    let hash = Sha256::digest(&content);
-    Ok(base64::engine::general_purpose::STANDARD.encode(hash))
+    Ok(content_hash(&std::fs::read(path)?))
+}
+
+fn content_hash(bytes: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(Sha256::digest(bytes))
 }
 
 /// Compare two perceptual hashes and return similarity score (0.0-1.0)
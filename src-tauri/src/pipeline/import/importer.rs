@@ -8,9 +8,12 @@ use crate::crypto::ProfileSession;
 use crate::db::repository;
 use crate::models::document::Document;
 use crate::models::enums::{DocumentType, PipelineStatus};
-use super::format::{detect_format, sanitize_filename, FileCategory, FormatDetection};
-use super::hash::{compute_hash, hash_similarity};
-use super::staging::stage_file;
+use crate::pipeline::extraction::pdfium::{decrypt_protected_pdf, UnlockedPdf};
+use crate::pipeline::extraction::ExtractionError;
+use super::format::{detect_format, detect_format_bytes, sanitize_filename, FileCategory, FormatDetection};
+use super::hash::{compute_hash, compute_hash_bytes, hash_similarity};
+use super::assembly::{assemble_image_pages, page_group_hash};
+use super::staging::stage_bytes;
 use super::ImportError;
 
 /// Import result returned to the frontend
@@ -44,6 +47,9 @@ pub struct DuplicateCheck {
 
 const DUPLICATE_SIMILARITY_THRESHOLD: f64 = 0.85;
 
+/// PWD-01: Extension of the protected original kept next to its unlocked copy.
+const PROTECTED_ORIGINAL_EXTENSION: &str = "protected.pdf";
+
 /// Import a single file into a profile
 pub fn import_file(
     source_path: &Path,
@@ -61,6 +67,40 @@ pub fn import_file(
 
     // Step 1: Format detection
     let format = detect_format(source_path)?;
+    let content = std::fs::read(source_path)?;
+    import_detected(original_filename, format, &content, session, conn)
+}
+
+/// EML-01: Import in-memory content (e.g. an email attachment) as a document.
+///
+/// The content is only ever written to disk encrypted, by staging.
+pub fn import_bytes(
+    filename: &str,
+    bytes: &[u8],
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<ImportResult, ImportError> {
+    let original_filename = sanitize_filename(filename);
+    tracing::info!(file = %original_filename, "Starting in-memory document import");
+
+    let format = detect_format_bytes(bytes)?;
+    import_detected(original_filename, format, bytes, session, conn)
+}
+
+/// Steps 2-6 of an import, once the format of `content` is known.
+fn import_detected(
+    original_filename: String,
+    format: FormatDetection,
+    content: &[u8],
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<ImportResult, ImportError> {
+    // EML-01: An email is a container — `email::import_email` imports its parts
+    if super::email::is_email_mime(&format.mime_type) {
+        return Err(ImportError::UnsupportedFormat(
+            "Email files are imported through the import queue, one document per attachment".into(),
+        ));
+    }
 
     if !format.category.is_supported() {
         return Ok(ImportResult {
@@ -86,7 +126,7 @@ pub fn import_file(
     }
 
     // Step 3: Compute hash for duplicate detection
-    let hash = compute_hash(source_path, &format.category)?;
+    let hash = compute_hash_bytes(content, &format.category)?;
 
     // Step 4: Check for duplicates
     let dup_check = check_duplicate_in_db(&hash, &format.category, conn)?;
@@ -112,7 +152,11 @@ pub fn import_file(
     }
 
     // Step 5: Stage file (copy to profile directory, encrypted)
-    let staged_path = stage_file(source_path, &document_id, session)?;
+    let extension = Path::new(&original_filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("bin");
+    let staged_path = stage_bytes(content, extension, &document_id, session)?;
 
     // Step 6: Create document record in SQLite
     let doc = Document {
@@ -150,6 +194,271 @@ pub fn import_file(
     })
 }
 
+/// MPA-01: Import an ordered group of photos as one multi-page document.
+///
+/// Every file must be an image. The photos are assembled into a single PDF
+/// (one page per photo, in the given order) and staged as one document, so
+/// extraction runs the scanned-PDF per-page path. A single path falls back to
+/// `import_file`.
+pub fn import_image_group(
+    source_paths: &[PathBuf],
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<ImportResult, ImportError> {
+    match source_paths {
+        [] => return Err(ImportError::FileReadError("No files in group".into())),
+        [single] => return import_file(single, session, conn),
+        _ => {}
+    }
+
+    let mut pages = Vec::with_capacity(source_paths.len());
+    for path in source_paths {
+        let format = detect_format(path)?;
+        if format.category != FileCategory::Image {
+            return Err(ImportError::UnsupportedFormat(
+                "only photos can be combined into one document".into(),
+            ));
+        }
+        pages.push(std::fs::read(path)?);
+    }
+
+    let original_filename = sanitize_filename(
+        source_paths[0]
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown"),
+    );
+
+    import_page_images(&original_filename, &pages, session, conn)
+}
+
+/// MPA-01: Assemble decrypted page images into one staged document.
+///
+/// Shared by group import and the merge of separately imported photos.
+/// Duplicate detection is exact (same photos in the same order).
+pub fn import_page_images(
+    original_filename: &str,
+    pages: &[Vec<u8>],
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<ImportResult, ImportError> {
+    let total_bytes: u64 = pages.iter().map(|p| p.len() as u64).sum();
+    let mut format = FormatDetection {
+        mime_type: "application/pdf".into(),
+        category: FileCategory::ScannedPdf,
+        is_digital_pdf: Some(false),
+        file_size_bytes: total_bytes,
+    };
+
+    if total_bytes > 100 * 1024 * 1024 {
+        return Ok(ImportResult {
+            document_id: Uuid::new_v4(),
+            original_filename: original_filename.to_string(),
+            format,
+            staged_path: String::new(),
+            duplicate_of: None,
+            status: ImportStatus::TooLarge,
+        });
+    }
+
+    let hash = page_group_hash(pages);
+    let document_id = Uuid::new_v4();
+
+    if let Some(existing) = repository::get_document_by_hash(conn, &hash)? {
+        tracing::info!(
+            file = %original_filename,
+            duplicate_of = %existing.id,
+            "Duplicate page group detected"
+        );
+        return Ok(ImportResult {
+            document_id,
+            original_filename: original_filename.to_string(),
+            format,
+            staged_path: String::new(),
+            duplicate_of: Some(existing.id),
+            status: ImportStatus::Duplicate,
+        });
+    }
+
+    let pdf = assemble_image_pages(pages)?;
+    format.file_size_bytes = pdf.len() as u64;
+    let staged_path = stage_bytes(&pdf, "pdf", &document_id, session)?;
+
+    let doc = Document {
+        id: document_id,
+        doc_type: DocumentType::Other,
+        title: original_filename.to_string(),
+        document_date: None,
+        ingestion_date: chrono::Local::now().naive_local(),
+        professional_id: None,
+        source_file: staged_path.to_string_lossy().to_string(),
+        markdown_file: None,
+        ocr_confidence: None,
+        verified: false,
+        source_deleted: false,
+        perceptual_hash: Some(hash),
+        notes: None,
+        pipeline_status: PipelineStatus::Imported,
+    };
+    repository::insert_document(conn, &doc)?;
+    repository::update_document_page_count(conn, &document_id, pages.len() as u32)?;
+
+    tracing::info!(
+        document_id = %document_id,
+        file = %original_filename,
+        pages = pages.len(),
+        "Photo group assembled and staged"
+    );
+
+    Ok(ImportResult {
+        document_id,
+        original_filename: original_filename.to_string(),
+        format,
+        staged_path: staged_path.to_string_lossy().to_string(),
+        duplicate_of: None,
+        status: ImportStatus::Staged,
+    })
+}
+
+/// PWD-01: Import a password-protected PDF, trying `passwords` in order.
+///
+/// PDFium opens the file in memory and copies its pages into an unprotected
+/// PDF, which is staged encrypted at rest as the working document. The text
+/// layer is kept, so digital PDFs still skip OCR. The protected original is
+/// staged next to it, untouched. Duplicate detection hashes the original
+/// file, so a re-import is caught before any decryption.
+pub fn import_protected_pdf(
+    source_path: &Path,
+    passwords: &[String],
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<ImportResult, ImportError> {
+    if passwords.is_empty() {
+        return Err(ImportError::EncryptedPdf);
+    }
+
+    let original_filename = sanitize_filename(
+        source_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown"),
+    );
+
+    let original = std::fs::read(source_path)?;
+    let format = FormatDetection {
+        mime_type: "application/pdf".into(),
+        category: FileCategory::ScannedPdf,
+        is_digital_pdf: Some(false),
+        file_size_bytes: original.len() as u64,
+    };
+
+    if format.file_size_bytes > 100 * 1024 * 1024 {
+        return Ok(ImportResult {
+            document_id: Uuid::new_v4(),
+            original_filename,
+            format,
+            staged_path: String::new(),
+            duplicate_of: None,
+            status: ImportStatus::TooLarge,
+        });
+    }
+
+    let hash = compute_hash(source_path, &format.category)?;
+    let document_id = Uuid::new_v4();
+
+    if let Some(existing) = repository::get_document_by_hash(conn, &hash)? {
+        tracing::info!(
+            file = %original_filename,
+            duplicate_of = %existing.id,
+            "Duplicate protected PDF detected"
+        );
+        return Ok(ImportResult {
+            document_id,
+            original_filename,
+            format,
+            staged_path: String::new(),
+            duplicate_of: Some(existing.id),
+            status: ImportStatus::Duplicate,
+        });
+    }
+
+    let unlocked = decrypt_protected_pdf(&original, passwords).map_err(|e| match e {
+        ExtractionError::PdfEncrypted => ImportError::EncryptedPdf,
+        other => ImportError::FileReadError(other.to_string()),
+    })?;
+
+    stage_unlocked_pdf(
+        document_id,
+        original_filename,
+        &original,
+        &unlocked,
+        hash,
+        session,
+        conn,
+    )
+}
+
+/// PWD-01: Stage an unlocked PDF as the working document, keep the protected
+/// original beside it, and record the document.
+fn stage_unlocked_pdf(
+    document_id: Uuid,
+    original_filename: String,
+    original: &[u8],
+    unlocked: &UnlockedPdf,
+    hash: String,
+    session: &ProfileSession,
+    conn: &Connection,
+) -> Result<ImportResult, ImportError> {
+    let format = FormatDetection {
+        mime_type: "application/pdf".into(),
+        category: if unlocked.has_text_layer {
+            FileCategory::DigitalPdf
+        } else {
+            FileCategory::ScannedPdf
+        },
+        is_digital_pdf: Some(unlocked.has_text_layer),
+        file_size_bytes: unlocked.bytes.len() as u64,
+    };
+
+    let staged_path = stage_bytes(&unlocked.bytes, "pdf", &document_id, session)?;
+    stage_bytes(original, PROTECTED_ORIGINAL_EXTENSION, &document_id, session)?;
+
+    let doc = Document {
+        id: document_id,
+        doc_type: DocumentType::Other,
+        title: original_filename.clone(),
+        document_date: None,
+        ingestion_date: chrono::Local::now().naive_local(),
+        professional_id: None,
+        source_file: staged_path.to_string_lossy().to_string(),
+        markdown_file: None,
+        ocr_confidence: None,
+        verified: false,
+        source_deleted: false,
+        perceptual_hash: Some(hash),
+        notes: None,
+        pipeline_status: PipelineStatus::Imported,
+    };
+    repository::insert_document(conn, &doc)?;
+    repository::update_document_page_count(conn, &document_id, unlocked.page_count as u32)?;
+
+    tracing::info!(
+        document_id = %document_id,
+        file = %original_filename,
+        pages = unlocked.page_count,
+        "Protected PDF unlocked and staged"
+    );
+
+    Ok(ImportResult {
+        document_id,
+        original_filename,
+        format,
+        staged_path: staged_path.to_string_lossy().to_string(),
+        duplicate_of: None,
+        status: ImportStatus::Staged,
+    })
+}
+
 /// Import multiple files (batch)
 pub fn import_files(
     source_paths: &[PathBuf],
@@ -427,4 +736,151 @@ mod tests {
             .unwrap();
         assert!(doc.perceptual_hash.is_some());
     }
+
+    fn write_photo(dir: &Path, name: &str, shade: u8) -> PathBuf {
+        let path = dir.join(name);
+        img_hash::image::RgbImage::from_pixel(40, 60, img_hash::image::Rgb([shade, shade, shade]))
+            .save(&path)
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn import_image_group_creates_one_multipage_document() {
+        let (_dir, session, conn) = setup();
+        let source_dir = tempfile::tempdir().unwrap();
+        let paths = vec![
+            write_photo(source_dir.path(), "page1.jpg", 10),
+            write_photo(source_dir.path(), "page2.png", 120),
+            write_photo(source_dir.path(), "page3.jpg", 240),
+        ];
+
+        let result = import_image_group(&paths, &session, &conn).unwrap();
+        assert_eq!(result.status, ImportStatus::Staged);
+        assert_eq!(result.original_filename, "page1.jpg");
+        assert_eq!(result.format.category, FileCategory::ScannedPdf);
+
+        let doc_count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM documents", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(doc_count, 1);
+        let page_count: u32 = conn
+            .query_row(
+                "SELECT page_count FROM documents WHERE id = ?1",
+                [result.document_id.to_string()],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(page_count, 3);
+
+        let staged = super::super::staging::read_staged_file(Path::new(&result.staged_path), &session).unwrap();
+        assert!(staged.starts_with(b"%PDF"));
+
+        // Same photos in the same order → duplicate
+        let again = import_image_group(&paths, &session, &conn).unwrap();
+        assert_eq!(again.status, ImportStatus::Duplicate);
+        assert_eq!(again.duplicate_of, Some(result.document_id));
+    }
+
+    fn write_protected_pdf(dir: &Path) -> PathBuf {
+        let path = dir.join("results.pdf");
+        std::fs::write(
+            &path,
+            b"%PDF-1.6 some content /Encrypt << /Filter /Standard /V 4 >> endobj",
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn import_protected_pdf_without_passwords_is_encrypted_error() {
+        let (_dir, session, conn) = setup();
+        let source_dir = tempfile::tempdir().unwrap();
+        let path = write_protected_pdf(source_dir.path());
+
+        let err = import_file(&path, &session, &conn).unwrap_err();
+        assert!(matches!(err, ImportError::EncryptedPdf));
+        let err = import_protected_pdf(&path, &[], &session, &conn).unwrap_err();
+        assert!(matches!(err, ImportError::EncryptedPdf));
+    }
+
+    #[test]
+    fn import_protected_pdf_detects_duplicate_before_decrypting() {
+        let (_dir, session, conn) = setup();
+        let source_dir = tempfile::tempdir().unwrap();
+        let path = write_protected_pdf(source_dir.path());
+
+        let hash = compute_hash(&path, &FileCategory::ScannedPdf).unwrap();
+        let existing = Document {
+            id: Uuid::new_v4(),
+            doc_type: DocumentType::LabResult,
+            title: "results.pdf".into(),
+            document_date: None,
+            ingestion_date: chrono::Local::now().naive_local(),
+            professional_id: None,
+            source_file: "originals/x.pdf.enc".into(),
+            markdown_file: None,
+            ocr_confidence: None,
+            verified: false,
+            source_deleted: false,
+            perceptual_hash: Some(hash),
+            notes: None,
+            pipeline_status: PipelineStatus::Imported,
+        };
+        repository::insert_document(&conn, &existing).unwrap();
+
+        // Wrong password never reaches PDFium: the original's hash matches.
+        let result =
+            import_protected_pdf(&path, &["wrong".into()], &session, &conn).unwrap();
+        assert_eq!(result.status, ImportStatus::Duplicate);
+        assert_eq!(result.duplicate_of, Some(existing.id));
+    }
+
+    #[test]
+    fn unlocked_pdf_is_staged_next_to_protected_original() {
+        let (_dir, session, conn) = setup();
+        let original = b"%PDF-1.6 /Encrypt << /Filter /Standard /V 4 >> endobj".to_vec();
+        let unlocked = UnlockedPdf {
+            bytes: b"%PDF-1.7 unlocked pages".to_vec(),
+            page_count: 2,
+            has_text_layer: true,
+        };
+        let document_id = Uuid::new_v4();
+
+        let result = stage_unlocked_pdf(
+            document_id,
+            "results.pdf".into(),
+            &original,
+            &unlocked,
+            "hash".into(),
+            &session,
+            &conn,
+        )
+        .unwrap();
+        assert_eq!(result.status, ImportStatus::Staged);
+        assert_eq!(result.format.category, FileCategory::DigitalPdf);
+
+        let working = Path::new(&result.staged_path);
+        let protected = working.with_file_name(format!(
+            "{document_id}.{PROTECTED_ORIGINAL_EXTENSION}.enc"
+        ));
+        let read = |path: &Path| super::super::staging::read_staged_file(path, &session).unwrap();
+        assert_eq!(read(working), unlocked.bytes);
+        assert_eq!(read(&protected), original);
+
+        let doc = repository::get_document(&conn, &document_id).unwrap().unwrap();
+        assert_eq!(doc.source_file, result.staged_path);
+    }
+
+    #[test]
+    fn import_image_group_rejects_non_images() {
+        let (_dir, session, conn) = setup();
+        let source_dir = tempfile::tempdir().unwrap();
+        let text = source_dir.path().join("notes.txt");
+        std::fs::write(&text, "Patient notes").unwrap();
+        let paths = vec![write_photo(source_dir.path(), "page1.jpg", 10), text];
+
+        let err = import_image_group(&paths, &session, &conn).unwrap_err();
+        assert!(matches!(err, ImportError::UnsupportedFormat(_)));
+    }
 }
@@ -1,7 +1,11 @@
+pub mod assembly;
+pub mod email;
 pub mod format;
 pub mod hash;
 pub mod staging;
 pub mod importer;
+pub mod pdf_password;
+pub mod vitals;
 
 pub use format::*;
 pub use hash::*;
@@ -25,7 +29,7 @@ pub enum ImportError {
     #[error("File too large: {size_mb:.1}MB exceeds {max_mb}MB limit")]
     FileTooLarge { size_mb: f64, max_mb: u64 },
 
-    #[error("PDF is password-protected — please decrypt it first")]
+    #[error("PDF is password-protected — enter its password to import it")]
     EncryptedPdf,
 
     #[error("Could not read file: {0}")]
@@ -0,0 +1,251 @@
+//! PWD-01: Password candidates for protected PDF imports.
+//!
+//! Labs often email results as PDFs protected with the patient's birthdate.
+//! At import we try, in order: the password given for the job, the profile's
+//! saved PDF passwords, then common birthdate patterns from the profile DOB.
+//!
+//! Saved passwords live in the profile's encrypted database
+//! (`user_preferences`); job passwords are held in memory only. Only their
+//! IDs and labels ever leave the backend.
+
+use chrono::NaiveDate;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::db::repository;
+use super::ImportError;
+
+/// `user_preferences` key holding the saved password list (JSON array).
+const SAVED_PASSWORDS_KEY: &str = "pdf_passwords";
+
+/// Cap on saved passwords — each candidate costs a PDFium open attempt.
+pub const MAX_SAVED_PASSWORDS: usize = 20;
+
+/// Birthdate layouts seen on protected lab reports (chrono format strings).
+const BIRTHDATE_FORMATS: &[&str] = &["%d%m%Y", "%m%d%Y", "%Y%m%d", "%d%m%y", "%d/%m/%Y", "%d.%m.%Y"];
+
+/// A saved password as stored in the profile database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredPassword {
+    id: Uuid,
+    label: String,
+    password: String,
+}
+
+/// A saved password as shown to the user: never the password itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedPdfPassword {
+    pub id: Uuid,
+    pub label: String,
+}
+
+/// Load the profile's saved PDF passwords, for trying on protected PDFs.
+/// Empty when none are saved.
+pub fn load_saved_passwords(conn: &Connection) -> Result<Vec<String>, ImportError> {
+    Ok(load_stored(conn)?.into_iter().map(|p| p.password).collect())
+}
+
+/// List the saved PDF passwords by ID and label.
+pub fn list_saved_passwords(conn: &Connection) -> Result<Vec<SavedPdfPassword>, ImportError> {
+    Ok(load_stored(conn)?
+        .into_iter()
+        .map(|p| SavedPdfPassword {
+            id: p.id,
+            label: p.label,
+        })
+        .collect())
+}
+
+/// Save a PDF password under a label.
+///
+/// The password is stored exactly as typed: leading or trailing spaces can be
+/// part of it. Saving a password that is already saved returns its entry.
+pub fn add_password(
+    conn: &Connection,
+    label: &str,
+    password: &str,
+) -> Result<SavedPdfPassword, ImportError> {
+    let label = label.trim();
+    if label.is_empty() || password.is_empty() {
+        return Err(ImportError::FileReadError(
+            "A PDF password needs a label and a value".into(),
+        ));
+    }
+
+    let mut stored = load_stored(conn)?;
+    if let Some(existing) = stored.iter().find(|p| p.password == password) {
+        return Ok(SavedPdfPassword {
+            id: existing.id,
+            label: existing.label.clone(),
+        });
+    }
+    if stored.len() >= MAX_SAVED_PASSWORDS {
+        return Err(ImportError::FileReadError(format!(
+            "At most {MAX_SAVED_PASSWORDS} PDF passwords can be saved"
+        )));
+    }
+
+    let entry = StoredPassword {
+        id: Uuid::new_v4(),
+        label: label.to_string(),
+        password: password.to_string(),
+    };
+    let saved = SavedPdfPassword {
+        id: entry.id,
+        label: entry.label.clone(),
+    };
+    stored.push(entry);
+    write_stored(conn, &stored)?;
+    Ok(saved)
+}
+
+/// Remove a saved PDF password. Returns whether it existed.
+pub fn remove_password(conn: &Connection, id: &Uuid) -> Result<bool, ImportError> {
+    let mut stored = load_stored(conn)?;
+    let before = stored.len();
+    stored.retain(|p| p.id != *id);
+    if stored.len() == before {
+        return Ok(false);
+    }
+    write_stored(conn, &stored)?;
+    Ok(true)
+}
+
+fn load_stored(conn: &Connection) -> Result<Vec<StoredPassword>, ImportError> {
+    let Some(raw) = repository::get_user_preference(conn, SAVED_PASSWORDS_KEY)? else {
+        return Ok(vec![]);
+    };
+    serde_json::from_str(&raw)
+        .map_err(|e| ImportError::FileReadError(format!("Saved PDF passwords are corrupt: {e}")))
+}
+
+/// Write the saved list; an empty list clears the setting.
+fn write_stored(conn: &Connection, stored: &[StoredPassword]) -> Result<(), ImportError> {
+    if stored.is_empty() {
+        repository::delete_user_preference(conn, SAVED_PASSWORDS_KEY)?;
+        return Ok(());
+    }
+    let json =
+        serde_json::to_string(stored).map_err(|e| ImportError::FileReadError(e.to_string()))?;
+    repository::set_user_preference(conn, SAVED_PASSWORDS_KEY, &json)?;
+    Ok(())
+}
+
+/// Common birthdate renderings used as PDF passwords (e.g. `15061990`).
+pub fn birthdate_patterns(date_of_birth: NaiveDate) -> Vec<String> {
+    dedup(
+        BIRTHDATE_FORMATS
+            .iter()
+            .map(|fmt| date_of_birth.format(fmt).to_string()),
+    )
+}
+
+/// Ordered, de-duplicated passwords to try on a protected PDF.
+pub fn candidate_passwords(
+    job_password: Option<&str>,
+    saved: &[String],
+    date_of_birth: Option<NaiveDate>,
+) -> Vec<String> {
+    let job = job_password.map(str::to_string);
+    let birthdate = date_of_birth.map(birthdate_patterns).unwrap_or_default();
+    dedup(
+        job.into_iter()
+            .chain(saved.iter().cloned())
+            .chain(birthdate),
+    )
+}
+
+/// Keep the first occurrence of each non-empty password, in order.
+fn dedup(passwords: impl Iterator<Item = String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for password in passwords {
+        if !password.is_empty() && !out.contains(&password) {
+            out.push(password);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::sqlite::open_memory_database;
+
+    fn dob() -> NaiveDate {
+        NaiveDate::from_ymd_opt(1990, 6, 15).unwrap()
+    }
+
+    #[test]
+    fn birthdate_patterns_cover_common_layouts() {
+        let patterns = birthdate_patterns(dob());
+        assert_eq!(patterns[0], "15061990");
+        assert!(patterns.contains(&"06151990".to_string()));
+        assert!(patterns.contains(&"19900615".to_string()));
+        assert!(patterns.contains(&"150690".to_string()));
+        assert!(patterns.contains(&"15/06/1990".to_string()));
+    }
+
+    #[test]
+    fn candidates_try_job_then_saved_then_birthdate() {
+        let saved = vec!["lab-secret".to_string(), "15061990".to_string()];
+        let candidates = candidate_passwords(Some("typed"), &saved, Some(dob()));
+        assert_eq!(candidates[0], "typed");
+        assert_eq!(candidates[1], "lab-secret");
+        assert_eq!(candidates[2], "15061990");
+        // Birthdate already saved is not tried twice
+        assert_eq!(candidates.iter().filter(|c| *c == "15061990").count(), 1);
+    }
+
+    #[test]
+    fn no_sources_means_no_candidates() {
+        assert!(candidate_passwords(None, &[], None).is_empty());
+        assert!(candidate_passwords(Some(""), &[], None).is_empty());
+    }
+
+    #[test]
+    fn saved_passwords_round_trip() {
+        let conn = open_memory_database().unwrap();
+        assert!(load_saved_passwords(&conn).unwrap().is_empty());
+
+        let lab = add_password(&conn, " City Lab ", " abc ").unwrap();
+        let again = add_password(&conn, "Other", " abc ").unwrap();
+        let clinic = add_password(&conn, "Clinic", "xyz").unwrap();
+        assert_eq!(lab.label, "City Lab");
+        assert_eq!(again, lab);
+        // Stored exactly as typed, spaces included
+        assert_eq!(load_saved_passwords(&conn).unwrap(), vec![" abc ", "xyz"]);
+        assert_eq!(list_saved_passwords(&conn).unwrap(), vec![lab.clone(), clinic.clone()]);
+
+        assert!(remove_password(&conn, &lab.id).unwrap());
+        assert!(!remove_password(&conn, &lab.id).unwrap());
+        assert_eq!(load_saved_passwords(&conn).unwrap(), vec!["xyz"]);
+        assert!(remove_password(&conn, &clinic.id).unwrap());
+        assert!(load_saved_passwords(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn listing_never_exposes_passwords() {
+        let conn = open_memory_database().unwrap();
+        add_password(&conn, "City Lab", "15061990").unwrap();
+        let json = serde_json::to_string(&list_saved_passwords(&conn).unwrap()).unwrap();
+        assert!(json.contains("City Lab"));
+        assert!(!json.contains("15061990"));
+    }
+
+    #[test]
+    fn blank_label_or_password_is_rejected() {
+        let conn = open_memory_database().unwrap();
+        assert!(add_password(&conn, "  ", "abc").is_err());
+        assert!(add_password(&conn, "Lab", "").is_err());
+    }
+
+    #[test]
+    fn saved_passwords_are_capped() {
+        let conn = open_memory_database().unwrap();
+        for i in 0..MAX_SAVED_PASSWORDS {
+            add_password(&conn, "Lab", &format!("pw{i}")).unwrap();
+        }
+        assert!(add_password(&conn, "Lab", "one-too-many").is_err());
+    }
+}
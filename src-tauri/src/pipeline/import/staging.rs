@@ -17,6 +17,18 @@ pub fn stage_file(
         .and_then(|e| e.to_str())
         .unwrap_or("bin");
 
+    let plaintext = std::fs::read(source_path)?;
+    stage_bytes(&plaintext, extension, document_id, session)
+}
+
+/// MPA-01: Encrypt in-memory content into the profile's originals/ directory.
+/// Used for content produced during import (e.g. assembled multi-photo PDFs).
+pub fn stage_bytes(
+    plaintext: &[u8],
+    extension: &str,
+    document_id: &Uuid,
+    session: &ProfileSession,
+) -> Result<PathBuf, ImportError> {
     // Target: profiles/<uuid>/originals/<doc_uuid>.<ext>.enc
     let target_dir = session
         .db_path()
@@ -29,9 +41,7 @@ pub fn stage_file(
 
     let target_path = target_dir.join(format!("{}.{}.enc", document_id, extension));
 
-    // Read source, encrypt, write to target
-    let plaintext = std::fs::read(source_path)?;
-    let encrypted = session.encrypt(&plaintext)?;
+    let encrypted = session.encrypt(plaintext)?;
     std::fs::write(&target_path, encrypted.to_bytes())?;
 
     tracing::debug!(
@@ -68,6 +78,62 @@ pub fn remove_staged(
     Ok(())
 }
 
+/// IMQ-01: Staged originals younger than this are left alone — an import
+/// may be between staging and inserting its document row.
+const ORPHAN_GRACE: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+/// IMQ-01: Securely delete staged originals whose document no longer exists
+/// (an import interrupted between staging and the DB insert, or a document
+/// deleted while its file was locked). Returns the number of files removed.
+pub fn remove_orphaned_originals(
+    session: &ProfileSession,
+    conn: &rusqlite::Connection,
+) -> Result<usize, ImportError> {
+    let originals_dir = session
+        .db_path()
+        .parent()
+        .and_then(|p| p.parent())
+        .map(|p| p.join("originals"))
+        .ok_or_else(|| ImportError::FileReadError("Invalid profile path".into()))?;
+    remove_orphans_older_than(&originals_dir, conn, ORPHAN_GRACE)
+}
+
+fn remove_orphans_older_than(
+    originals_dir: &Path,
+    conn: &rusqlite::Connection,
+    min_age: std::time::Duration,
+) -> Result<usize, ImportError> {
+    if !originals_dir.exists() {
+        return Ok(0);
+    }
+
+    let mut removed = 0;
+    for entry in std::fs::read_dir(originals_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        // Staged names are "<doc_uuid>.<ext>.enc"; leave anything else alone
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(doc_id) = name.get(..36).and_then(|prefix| Uuid::parse_str(prefix).ok()) else {
+            continue;
+        };
+        let age = entry
+            .metadata()?
+            .modified()
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .unwrap_or_default();
+        if age < min_age || crate::db::repository::get_document(conn, &doc_id)?.is_some() {
+            continue;
+        }
+        crate::crypto::secure_delete_file(&path)?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
 /// Decrypt a staged file and return its original content
 pub fn read_staged_file(
     staged_path: &Path,
@@ -79,6 +145,18 @@ pub fn read_staged_file(
     Ok(plaintext)
 }
 
+/// Detect the format of an encrypted staged file from its decrypted content.
+///
+/// Staged files are encrypted at rest, so magic-byte detection must run on a
+/// short-lived decrypted temp copy (auto-deleted on return).
+pub fn detect_staged_format(
+    staged_path: &Path,
+    session: &ProfileSession,
+) -> Result<super::FormatDetection, ImportError> {
+    let temp = decrypt_staging_to_temp(staged_path, session.key_bytes())?;
+    super::detect_format(temp.path())
+}
+
 // ---------------------------------------------------------------------------
 // SEC-02-G03/G04: Pre-import staging encryption
 // ---------------------------------------------------------------------------
@@ -186,6 +264,16 @@ mod tests {
         assert_eq!(decrypted, original_content);
     }
 
+    #[test]
+    fn detect_staged_format_reads_decrypted_content() {
+        let (_dir, session) = setup_profile();
+        let doc_id = Uuid::new_v4();
+        let staged_path = stage_bytes(b"Potassium: 4.2 mmol/L", "txt", &doc_id, &session).unwrap();
+
+        let format = detect_staged_format(&staged_path, &session).unwrap();
+        assert_eq!(format.category, super::super::FileCategory::PlainText);
+    }
+
     #[test]
     fn remove_staged_deletes_file() {
         let (_dir, session) = setup_profile();
@@ -201,6 +289,48 @@ mod tests {
         assert!(!staged_path.exists());
     }
 
+    #[test]
+    fn orphaned_originals_are_removed() {
+        use crate::db::repository;
+        use crate::models::{enums::{DocumentType, PipelineStatus}, Document};
+
+        let (_dir, session) = setup_profile();
+        let conn = crate::db::sqlite::open_database(session.db_path(), Some(session.key_bytes())).unwrap();
+
+        let kept_id = Uuid::new_v4();
+        let kept = stage_bytes(b"kept", "pdf", &kept_id, &session).unwrap();
+        repository::insert_document(&conn, &Document {
+            id: kept_id,
+            doc_type: DocumentType::LabResult,
+            title: "Kept".into(),
+            document_date: None,
+            ingestion_date: chrono::Local::now().naive_local(),
+            professional_id: None,
+            source_file: kept.to_string_lossy().into_owned(),
+            markdown_file: None,
+            ocr_confidence: None,
+            verified: false,
+            source_deleted: false,
+            perceptual_hash: None,
+            notes: None,
+            pipeline_status: PipelineStatus::Imported,
+        }).unwrap();
+        let orphan = stage_bytes(b"orphan", "jpg", &Uuid::new_v4(), &session).unwrap();
+        let unrelated = kept.with_file_name("notes.txt");
+        std::fs::write(&unrelated, "not staged").unwrap();
+
+        let originals_dir = kept.parent().unwrap();
+        // Fresh files are inside the grace period
+        assert_eq!(remove_orphaned_originals(&session, &conn).unwrap(), 0);
+        assert_eq!(
+            remove_orphans_older_than(originals_dir, &conn, std::time::Duration::ZERO).unwrap(),
+            1
+        );
+        assert!(kept.exists());
+        assert!(!orphan.exists());
+        assert!(unrelated.exists());
+    }
+
     #[test]
     fn encrypted_staging_roundtrip() {
         let (_dir, session) = setup_profile();
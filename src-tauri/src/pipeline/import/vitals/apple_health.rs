@@ -0,0 +1,204 @@
+//! Apple Health `export.xml` parser.
+//!
+//! The export is a flat list of `<Record type="HKQuantityTypeIdentifier…"
+//! unit value startDate sourceName/>` elements and can run to gigabytes, so
+//! it is streamed. Blood pressure arrives as separate systolic and diastolic
+//! records (also nested in a `<Correlation>`), paired here by timestamp and source.
+
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+use chrono::NaiveDateTime;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::{normalize_value, parse_timestamp, ParsedVitals, VitalReading};
+use crate::models::VitalType;
+use crate::pipeline::import::ImportError;
+
+const SYSTOLIC: &str = "HKQuantityTypeIdentifierBloodPressureSystolic";
+const DIASTOLIC: &str = "HKQuantityTypeIdentifierBloodPressureDiastolic";
+
+fn record_vital_type(hk_type: &str) -> Option<VitalType> {
+    match hk_type {
+        "HKQuantityTypeIdentifierHeartRate" => Some(VitalType::HeartRate),
+        "HKQuantityTypeIdentifierBodyMass" => Some(VitalType::Weight),
+        "HKQuantityTypeIdentifierHeight" => Some(VitalType::Height),
+        "HKQuantityTypeIdentifierBodyTemperature" => Some(VitalType::Temperature),
+        "HKQuantityTypeIdentifierOxygenSaturation" => Some(VitalType::OxygenSaturation),
+        "HKQuantityTypeIdentifierBloodGlucose" => Some(VitalType::BloodGlucose),
+        SYSTOLIC | DIASTOLIC => Some(VitalType::BloodPressure),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct RecordAttrs {
+    hk_type: String,
+    unit: String,
+    value: String,
+    start_date: String,
+    source_name: Option<String>,
+}
+
+fn record_attrs(e: &BytesStart) -> RecordAttrs {
+    let mut attrs = RecordAttrs::default();
+    for attr in e.attributes().flatten() {
+        let Ok(value) = attr.unescape_value() else {
+            continue;
+        };
+        match attr.key.as_ref() {
+            b"type" => attrs.hk_type = value.into_owned(),
+            b"unit" => attrs.unit = value.into_owned(),
+            b"value" => attrs.value = value.into_owned(),
+            b"startDate" => attrs.start_date = value.into_owned(),
+            b"sourceName" => attrs.source_name = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+    attrs
+}
+
+/// Blood pressure halves are paired by (timestamp, source).
+type BpKey = (NaiveDateTime, Option<String>);
+
+/// Stream `export.xml` and collect supported quantity records.
+pub(super) fn parse(reader: impl BufRead) -> Result<ParsedVitals, ImportError> {
+    let mut xml = Reader::from_reader(reader);
+    let mut buf = Vec::new();
+    let mut parsed = ParsedVitals::default();
+    // BTreeMap keeps output order stable
+    let mut bp: BTreeMap<BpKey, (Option<f64>, Option<f64>)> = BTreeMap::new();
+
+    loop {
+        let event = xml
+            .read_event_into(&mut buf)
+            .map_err(|e| ImportError::FileReadError(format!("malformed Apple Health XML: {e}")))?;
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) if e.local_name().as_ref() == b"Record" => {
+                let attrs = record_attrs(e);
+                let Some(vital_type) = record_vital_type(&attrs.hk_type) else {
+                    buf.clear();
+                    continue;
+                };
+                let value = attrs
+                    .value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|v| normalize_value(vital_type, v, &attrs.unit));
+                let (Some(value), Some(recorded_at)) = (value, parse_timestamp(&attrs.start_date))
+                else {
+                    parsed.skipped += 1;
+                    buf.clear();
+                    continue;
+                };
+
+                if vital_type == VitalType::BloodPressure {
+                    let slot = bp.entry((recorded_at, attrs.source_name)).or_default();
+                    if attrs.hk_type == SYSTOLIC {
+                        slot.0 = Some(value);
+                    } else {
+                        slot.1 = Some(value);
+                    }
+                } else {
+                    parsed.readings.push(VitalReading {
+                        vital_type,
+                        recorded_at,
+                        value_primary: value,
+                        value_secondary: None,
+                        source_name: attrs.source_name,
+                        summary: None,
+                    });
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    for ((recorded_at, source_name), pair) in bp {
+        match pair {
+            (Some(systolic), diastolic) => parsed.readings.push(VitalReading {
+                vital_type: VitalType::BloodPressure,
+                recorded_at,
+                value_primary: systolic,
+                value_secondary: diastolic,
+                source_name,
+                summary: None,
+            }),
+            // A lone diastolic value is not a usable blood pressure reading
+            (None, _) => parsed.skipped += 1,
+        }
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE HealthData [ <!ELEMENT HealthData (ExportDate,Me,(Record|Correlation)*)> ]>
+<HealthData locale="en_GB">
+ <ExportDate value="2024-03-05 20:00:00 +0000"/>
+ <Record type="HKQuantityTypeIdentifierHeartRate" sourceName="Apple Watch" unit="count/min" creationDate="2024-03-01 08:01:00 +0000" startDate="2024-03-01 08:00:00 +0000" endDate="2024-03-01 08:00:00 +0000" value="64">
+  <MetadataEntry key="HKMetadataKeyHeartRateMotionContext" value="1"/>
+ </Record>
+ <Record type="HKQuantityTypeIdentifierStepCount" sourceName="iPhone" unit="count" startDate="2024-03-01 08:00:00 +0000" endDate="2024-03-01 08:10:00 +0000" value="512"/>
+ <Record type="HKQuantityTypeIdentifierBodyMass" sourceName="Withings" unit="lb" startDate="2024-03-01 07:00:00 +0000" endDate="2024-03-01 07:00:00 +0000" value="176.4"/>
+ <Record type="HKQuantityTypeIdentifierOxygenSaturation" sourceName="Apple Watch" unit="%" startDate="2024-03-01 03:00:00 +0000" endDate="2024-03-01 03:00:00 +0000" value="0.96"/>
+ <Record type="HKQuantityTypeIdentifierBodyTemperature" sourceName="Kinsa" unit="degF" startDate="2024-03-02 09:00:00 +0000" endDate="2024-03-02 09:00:00 +0000" value="not-a-number"/>
+ <Correlation type="HKCorrelationTypeIdentifierBloodPressure" sourceName="Omron" startDate="2024-03-01 08:30:00 +0100" endDate="2024-03-01 08:30:00 +0100">
+  <Record type="HKQuantityTypeIdentifierBloodPressureSystolic" sourceName="Omron" unit="mmHg" startDate="2024-03-01 08:30:00 +0100" endDate="2024-03-01 08:30:00 +0100" value="142"/>
+  <Record type="HKQuantityTypeIdentifierBloodPressureDiastolic" sourceName="Omron" unit="mmHg" startDate="2024-03-01 08:30:00 +0100" endDate="2024-03-01 08:30:00 +0100" value="91"/>
+ </Correlation>
+ <Record type="HKQuantityTypeIdentifierBloodPressureSystolic" sourceName="Omron" unit="mmHg" startDate="2024-03-01 08:30:00 +0100" endDate="2024-03-01 08:30:00 +0100" value="142"/>
+</HealthData>"#;
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn parses_supported_records_and_pairs_blood_pressure() {
+        let parsed = parse(EXPORT.as_bytes()).unwrap();
+        assert_eq!(parsed.skipped, 1, "unparseable temperature");
+        assert_eq!(parsed.readings.len(), 4, "HR, weight, SpO2, one BP pair");
+
+        let hr = parsed
+            .readings
+            .iter()
+            .find(|r| r.vital_type == VitalType::HeartRate)
+            .unwrap();
+        assert_eq!(hr.value_primary, 64.0);
+        assert_eq!(hr.recorded_at, at("2024-03-01 08:00:00"));
+        assert_eq!(hr.source_name.as_deref(), Some("Apple Watch"));
+
+        let weight = parsed
+            .readings
+            .iter()
+            .find(|r| r.vital_type == VitalType::Weight)
+            .unwrap();
+        assert_eq!(weight.value_primary, 80.0);
+
+        let spo2 = parsed
+            .readings
+            .iter()
+            .find(|r| r.vital_type == VitalType::OxygenSaturation)
+            .unwrap();
+        assert_eq!(spo2.value_primary, 96.0);
+
+        let bp = parsed
+            .readings
+            .iter()
+            .find(|r| r.vital_type == VitalType::BloodPressure)
+            .unwrap();
+        assert_eq!(bp.value_primary, 142.0);
+        assert_eq!(bp.value_secondary, Some(91.0));
+        assert_eq!(bp.recorded_at, at("2024-03-01 08:30:00"));
+        assert_eq!(bp.source_name.as_deref(), Some("Omron"));
+    }
+}
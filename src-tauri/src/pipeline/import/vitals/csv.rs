@@ -0,0 +1,224 @@
+//! Configurable CSV mapper for home devices (scales, cuffs, thermometers).
+//!
+//! Device apps export CSV with their own headers, units and date formats, so
+//! the user maps columns to vital types. Quoted fields are supported; fields
+//! spanning several lines are not (no device export we target uses them).
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use super::{normalize_value, parse_timestamp, ParsedVitals, VitalReading};
+use crate::models::VitalType;
+use crate::pipeline::import::ImportError;
+
+/// Maps one CSV column (two for blood pressure) to a vital type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvColumn {
+    /// Header of the value column (systolic for blood pressure).
+    pub column: String,
+    pub vital_type: VitalType,
+    /// Unit of the column values; defaults to the type's default unit.
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// Header of the diastolic column for blood pressure.
+    #[serde(default)]
+    pub diastolic_column: Option<String>,
+}
+
+/// User-supplied description of a CSV export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsvMapping {
+    pub timestamp_column: String,
+    /// chrono format string, e.g. `%d/%m/%Y %H:%M`. ISO 8601 is tried when absent.
+    #[serde(default)]
+    pub timestamp_format: Option<String>,
+    /// Field separator; detected from the header (`,` `;` or tab) when absent.
+    #[serde(default)]
+    pub delimiter: Option<char>,
+    /// Device or app name recorded as the source of every row.
+    #[serde(default)]
+    pub source_name: Option<String>,
+    pub columns: Vec<CsvColumn>,
+}
+
+fn detect_delimiter(header: &str) -> char {
+    [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| header.matches(*d).count())
+        .unwrap_or(',')
+}
+
+/// Split one CSV line, honouring double-quoted fields and `""` escapes.
+fn split_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields.into_iter().map(|f| f.trim().to_string()).collect()
+}
+
+fn column_index(headers: &[String], name: &str) -> Result<usize, ImportError> {
+    headers
+        .iter()
+        .position(|h| h.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| ImportError::UnsupportedFormat(format!("CSV has no column \"{name}\"")))
+}
+
+fn parse_cell_timestamp(cell: &str, format: Option<&str>) -> Option<NaiveDateTime> {
+    match format {
+        Some(fmt) => NaiveDateTime::parse_from_str(cell, fmt).ok().or_else(|| {
+            NaiveDate::parse_from_str(cell, fmt)
+                .ok()
+                .map(|d| d.and_time(NaiveTime::MIN))
+        }),
+        None => parse_timestamp(cell),
+    }
+}
+
+/// Decimal commas are common in European device exports ("72,5").
+fn parse_number(cell: &str) -> Option<f64> {
+    cell.replace(',', ".").parse().ok()
+}
+
+pub(super) fn parse(text: &str, mapping: &CsvMapping) -> Result<ParsedVitals, ImportError> {
+    let mut lines = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .filter(|l| !l.trim().is_empty());
+    let header = lines
+        .next()
+        .ok_or_else(|| ImportError::UnsupportedFormat("CSV file is empty".into()))?;
+    let delimiter = mapping
+        .delimiter
+        .unwrap_or_else(|| detect_delimiter(header));
+    let headers = split_line(header, delimiter);
+
+    let ts_index = column_index(&headers, &mapping.timestamp_column)?;
+    let mut columns = Vec::with_capacity(mapping.columns.len());
+    for col in &mapping.columns {
+        let index = column_index(&headers, &col.column)?;
+        let diastolic = match (&col.diastolic_column, col.vital_type) {
+            (Some(name), VitalType::BloodPressure) => Some(column_index(&headers, name)?),
+            _ => None,
+        };
+        columns.push((col, index, diastolic));
+    }
+
+    let mut parsed = ParsedVitals::default();
+    for line in lines {
+        let fields = split_line(line, delimiter);
+        let cell = |i: usize| fields.get(i).map(String::as_str).unwrap_or("");
+
+        let Some(recorded_at) =
+            parse_cell_timestamp(cell(ts_index), mapping.timestamp_format.as_deref())
+        else {
+            parsed.skipped += 1;
+            continue;
+        };
+
+        for (col, index, diastolic) in &columns {
+            // Devices leave cells blank for measurements they didn't take
+            if cell(*index).is_empty() {
+                continue;
+            }
+            let unit = col.unit.as_deref().unwrap_or("");
+            let normalize = |i: usize| {
+                parse_number(cell(i)).and_then(|v| normalize_value(col.vital_type, v, unit))
+            };
+            let Some(value_primary) = normalize(*index) else {
+                parsed.skipped += 1;
+                continue;
+            };
+            parsed.readings.push(VitalReading {
+                vital_type: col.vital_type,
+                recorded_at,
+                value_primary,
+                value_secondary: diastolic.and_then(normalize),
+                source_name: mapping.source_name.clone(),
+                summary: None,
+            });
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping() -> CsvMapping {
+        CsvMapping {
+            timestamp_column: "Date".into(),
+            timestamp_format: Some("%d/%m/%Y %H:%M".into()),
+            delimiter: None,
+            source_name: Some("Omron M7".into()),
+            columns: vec![
+                CsvColumn {
+                    column: "SYS (mmHg)".into(),
+                    vital_type: VitalType::BloodPressure,
+                    unit: None,
+                    diastolic_column: Some("DIA (mmHg)".into()),
+                },
+                CsvColumn {
+                    column: "Pulse".into(),
+                    vital_type: VitalType::HeartRate,
+                    unit: None,
+                    diastolic_column: None,
+                },
+                CsvColumn {
+                    column: "Weight".into(),
+                    vital_type: VitalType::Weight,
+                    unit: Some("lb".into()),
+                    diastolic_column: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn maps_columns_with_units_and_semicolons() {
+        let csv = "\u{feff}Date;SYS (mmHg);DIA (mmHg);Pulse;Weight;\"Note; free text\"\n\
+                   01/03/2024 08:15;141;92;68;;\"after \"\"coffee\"\"\"\n\
+                   02/03/2024 08:20;135;88;;180,4;\n\
+                   yesterday;120;80;60;;\n\
+                   03/03/2024 08:05;abc;85;70;;\n";
+
+        let parsed = parse(csv, &mapping()).unwrap();
+        assert_eq!(parsed.skipped, 2, "bad date row, non-numeric systolic");
+        assert_eq!(parsed.readings.len(), 5);
+
+        let bp = &parsed.readings[0];
+        assert_eq!(bp.vital_type, VitalType::BloodPressure);
+        assert_eq!((bp.value_primary, bp.value_secondary), (141.0, Some(92.0)));
+        assert_eq!(
+            bp.recorded_at,
+            NaiveDateTime::parse_from_str("2024-03-01 08:15:00", "%Y-%m-%d %H:%M:%S").unwrap()
+        );
+        assert_eq!(bp.source_name.as_deref(), Some("Omron M7"));
+
+        let weight = parsed
+            .readings
+            .iter()
+            .find(|r| r.vital_type == VitalType::Weight)
+            .unwrap();
+        assert_eq!(weight.value_primary, 81.8);
+    }
+
+    #[test]
+    fn missing_column_is_reported() {
+        let err = parse("Date,Pulse\n2024-03-01,70\n", &mapping()).unwrap_err();
+        assert!(err.to_string().contains("SYS (mmHg)"));
+    }
+}
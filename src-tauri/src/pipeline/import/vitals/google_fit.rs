@@ -0,0 +1,333 @@
+//! Google Fit Takeout and Health Connect JSON parsers.
+//!
+//! Google Fit Takeout writes one file per data source:
+//! `{"Data Source": …, "Data Points": [{"dataTypeName", "startTimeNanos",
+//! "fitValue": [{"value": {"fpVal"}}], "originDataSourceId"}]}`.
+//!
+//! Health Connect has no first-party file export; exporter apps serialise its
+//! records with the SDK field names (`WeightRecord.weight.inKilograms`,
+//! `HeartRateRecord.samples[].beatsPerMinute`, `metadata.dataOrigin.packageName`).
+//! Both a bare array and `{"records": […]}` are accepted.
+
+use serde_json::Value;
+
+use super::{from_epoch_nanos, normalize_value, parse_timestamp, ParsedVitals, VitalReading};
+use crate::models::VitalType;
+
+// ── Google Fit ──────────────────────────────────────────────
+
+/// Google Fit data type → vital type and the unit its `fpVal` is stored in.
+fn fit_vital_type(data_type: &str) -> Option<(VitalType, &'static str)> {
+    match data_type {
+        "com.google.heart_rate.bpm" => Some((VitalType::HeartRate, "bpm")),
+        "com.google.weight" => Some((VitalType::Weight, "kg")),
+        "com.google.height" => Some((VitalType::Height, "m")),
+        "com.google.blood_pressure" => Some((VitalType::BloodPressure, "mmHg")),
+        "com.google.blood_glucose" => Some((VitalType::BloodGlucose, "mmol/L")),
+        "com.google.oxygen_saturation" => Some((VitalType::OxygenSaturation, "%")),
+        "com.google.body.temperature" => Some((VitalType::Temperature, "degC")),
+        _ => None,
+    }
+}
+
+/// `raw:com.google.weight:com.withings.wiscale2:…` → `com.withings.wiscale2`.
+fn fit_origin(point: &Value) -> Option<String> {
+    point
+        .get("originDataSourceId")
+        .and_then(Value::as_str)
+        .and_then(|id| id.split(':').nth(2))
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+fn fit_value(point: &Value, index: usize) -> Option<f64> {
+    let value = point.get("fitValue")?.get(index)?.get("value")?;
+    value
+        .get("fpVal")
+        .and_then(Value::as_f64)
+        .or_else(|| value.get("intVal").and_then(Value::as_f64))
+}
+
+/// Nanosecond timestamps are JSON numbers in some Takeout versions, strings in others.
+fn nanos(point: &Value, key: &str) -> Option<i64> {
+    match point.get(key)? {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+pub(super) fn parse_google_fit(json: &Value) -> ParsedVitals {
+    let mut parsed = ParsedVitals::default();
+    let Some(points) = json.get("Data Points").and_then(Value::as_array) else {
+        return parsed;
+    };
+
+    for point in points {
+        let Some(data_type) = point.get("dataTypeName").and_then(Value::as_str) else {
+            continue;
+        };
+        let Some((vital_type, unit)) = fit_vital_type(data_type) else {
+            continue;
+        };
+        let recorded_at = nanos(point, "startTimeNanos")
+            .or_else(|| nanos(point, "endTimeNanos"))
+            .and_then(from_epoch_nanos);
+        let primary = fit_value(point, 0).and_then(|v| normalize_value(vital_type, v, unit));
+        let (Some(recorded_at), Some(value_primary)) = (recorded_at, primary) else {
+            parsed.skipped += 1;
+            continue;
+        };
+        let value_secondary = (vital_type == VitalType::BloodPressure)
+            .then(|| fit_value(point, 1).and_then(|v| normalize_value(vital_type, v, unit)))
+            .flatten();
+
+        parsed.readings.push(VitalReading {
+            vital_type,
+            recorded_at,
+            value_primary,
+            value_secondary,
+            source_name: fit_origin(point),
+            summary: None,
+        });
+    }
+    parsed
+}
+
+// ── Health Connect ──────────────────────────────────────────
+
+/// Read an SDK measurement: either a bare number in the default unit or an
+/// object such as `{"inKilograms": 80.2}` / `{"inPounds": 176.8}`.
+fn measurement(
+    value: Option<&Value>,
+    vital_type: VitalType,
+    units: &[(&str, &str)],
+) -> Option<f64> {
+    match value? {
+        Value::Number(n) => normalize_value(vital_type, n.as_f64()?, ""),
+        Value::Object(obj) => units.iter().find_map(|(key, unit)| {
+            obj.get(*key)
+                .and_then(Value::as_f64)
+                .and_then(|v| normalize_value(vital_type, v, unit))
+        }),
+        _ => None,
+    }
+}
+
+const KILOGRAMS: &[(&str, &str)] = &[("inKilograms", "kg"), ("inPounds", "lb"), ("inGrams", "g")];
+const METERS: &[(&str, &str)] = &[("inMeters", "m"), ("inInches", "in"), ("inFeet", "ft")];
+const CELSIUS: &[(&str, &str)] = &[("inCelsius", "degC"), ("inFahrenheit", "degF")];
+const MG_DL: &[(&str, &str)] = &[
+    ("inMilligramsPerDeciliter", "mg/dL"),
+    ("inMillimolesPerLiter", "mmol/L"),
+];
+const MMHG: &[(&str, &str)] = &[("inMillimetersOfMercury", "mmHg")];
+const PERCENT: &[(&str, &str)] = &[("value", "%")];
+
+fn record_type(record: &Value) -> Option<&str> {
+    let name = record
+        .get("recordType")
+        .or_else(|| record.get("type"))
+        .and_then(Value::as_str)?;
+    Some(name.strip_suffix("Record").unwrap_or(name))
+}
+
+fn record_time(record: &Value, key: &str) -> Option<chrono::NaiveDateTime> {
+    let raw = record.get(key).and_then(Value::as_str)?;
+    // An explicit zone offset recorded by the device wins over the local zone
+    let offset = record
+        .get("zoneOffset")
+        .and_then(Value::as_str)
+        .and_then(|o| o.parse::<chrono::FixedOffset>().ok());
+    if let (Some(offset), Ok(dt)) = (offset, chrono::DateTime::parse_from_rfc3339(raw)) {
+        return Some(dt.with_timezone(&offset).naive_local());
+    }
+    parse_timestamp(raw)
+}
+
+fn record_origin(record: &Value) -> Option<String> {
+    record
+        .pointer("/metadata/dataOrigin/packageName")
+        .or_else(|| record.pointer("/metadata/dataOrigin"))
+        .or_else(|| record.get("dataOrigin"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+pub(super) fn parse_health_connect(json: &Value) -> ParsedVitals {
+    let mut parsed = ParsedVitals::default();
+    let records = json
+        .as_array()
+        .or_else(|| json.get("records").and_then(Value::as_array));
+    let Some(records) = records else {
+        return parsed;
+    };
+
+    for record in records {
+        let Some(kind) = record_type(record) else {
+            continue;
+        };
+        let source_name = record_origin(record);
+
+        // Heart rate is a series record with per-sample timestamps
+        if kind == "HeartRate" {
+            let samples = record.get("samples").and_then(Value::as_array);
+            for sample in samples.into_iter().flatten() {
+                let value = measurement(sample.get("beatsPerMinute"), VitalType::HeartRate, &[]);
+                match (value, record_time(sample, "time")) {
+                    (Some(value_primary), Some(recorded_at)) => {
+                        parsed.readings.push(VitalReading {
+                            vital_type: VitalType::HeartRate,
+                            recorded_at,
+                            value_primary,
+                            value_secondary: None,
+                            source_name: source_name.clone(),
+                            summary: None,
+                        })
+                    }
+                    _ => parsed.skipped += 1,
+                }
+            }
+            continue;
+        }
+
+        let (vital_type, primary, secondary) = match kind {
+            "Weight" => (VitalType::Weight, record.get("weight"), None),
+            "Height" => (VitalType::Height, record.get("height"), None),
+            "BodyTemperature" => (VitalType::Temperature, record.get("temperature"), None),
+            "OxygenSaturation" => (VitalType::OxygenSaturation, record.get("percentage"), None),
+            "BloodGlucose" => (VitalType::BloodGlucose, record.get("level"), None),
+            "BloodPressure" => (
+                VitalType::BloodPressure,
+                record.get("systolic"),
+                record.get("diastolic"),
+            ),
+            _ => continue,
+        };
+        let units = match vital_type {
+            VitalType::Weight => KILOGRAMS,
+            VitalType::Height => METERS,
+            VitalType::Temperature => CELSIUS,
+            VitalType::BloodGlucose => MG_DL,
+            VitalType::BloodPressure => MMHG,
+            VitalType::OxygenSaturation => PERCENT,
+            VitalType::HeartRate | VitalType::HeadCircumference => &[],
+        };
+
+        let value_primary = measurement(primary, vital_type, units);
+        let recorded_at = record_time(record, "time");
+        let (Some(value_primary), Some(recorded_at)) = (value_primary, recorded_at) else {
+            parsed.skipped += 1;
+            continue;
+        };
+        parsed.readings.push(VitalReading {
+            vital_type,
+            recorded_at,
+            value_primary,
+            value_secondary: measurement(secondary, vital_type, units),
+            source_name,
+            summary: None,
+        });
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn google_fit_data_points() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "Data Source": "derived:com.google.blood_pressure:com.google.android.gms:merged",
+              "Data Points": [
+                {"fitValue": [{"value": {"fpVal": 138.0}}, {"value": {"fpVal": 88.0}}],
+                 "originDataSourceId": "raw:com.google.blood_pressure:com.omronhealthcare.omronconnect:",
+                 "dataTypeName": "com.google.blood_pressure",
+                 "startTimeNanos": 1709280000000000000, "endTimeNanos": 1709280000000000000},
+                {"fitValue": [{"value": {"fpVal": 1.82}}],
+                 "dataTypeName": "com.google.height",
+                 "startTimeNanos": "1709280000000000000"},
+                {"fitValue": [{"value": {"intVal": 4200}}],
+                 "dataTypeName": "com.google.step_count.delta",
+                 "startTimeNanos": 1709280000000000000},
+                {"fitValue": [],
+                 "dataTypeName": "com.google.weight",
+                 "startTimeNanos": 1709280000000000000}
+              ]
+            }"#,
+        )
+        .unwrap();
+
+        let parsed = parse_google_fit(&json);
+        assert_eq!(parsed.readings.len(), 2);
+        assert_eq!(parsed.skipped, 1, "weight without a value");
+
+        let bp = &parsed.readings[0];
+        assert_eq!(bp.vital_type, VitalType::BloodPressure);
+        assert_eq!((bp.value_primary, bp.value_secondary), (138.0, Some(88.0)));
+        assert_eq!(
+            bp.source_name.as_deref(),
+            Some("com.omronhealthcare.omronconnect")
+        );
+
+        let height = &parsed.readings[1];
+        assert_eq!(height.vital_type, VitalType::Height);
+        assert_eq!(height.value_primary, 182.0);
+        assert_eq!(height.recorded_at, bp.recorded_at);
+    }
+
+    #[test]
+    fn health_connect_records() {
+        let json: Value = serde_json::from_str(
+            r#"{"records": [
+              {"recordType": "WeightRecord", "time": "2024-03-01T06:30:00Z", "zoneOffset": "+01:00",
+               "weight": {"inKilograms": 81.3},
+               "metadata": {"dataOrigin": {"packageName": "com.withings.wiscale2"}}},
+              {"recordType": "HeartRateRecord", "startTime": "2024-03-01T10:00:00Z",
+               "samples": [
+                 {"time": "2024-03-01T10:00:00+02:00", "beatsPerMinute": 71},
+                 {"time": "2024-03-01T10:01:00+02:00", "beatsPerMinute": 74}
+               ]},
+              {"type": "OxygenSaturation", "time": "2024-03-01T02:00:00+00:00",
+               "percentage": {"value": 95.0}},
+              {"recordType": "BloodGlucoseRecord", "time": "2024-03-01T08:00:00+01:00",
+               "level": {"inMillimolesPerLiter": 6.1}},
+              {"recordType": "StepsRecord", "count": 900},
+              {"recordType": "BodyTemperatureRecord", "time": "2024-03-01T08:00:00+01:00",
+               "temperature": {"inKelvin": 310.0}}
+            ]}"#,
+        )
+        .unwrap();
+
+        let parsed = parse_health_connect(&json);
+        assert_eq!(parsed.readings.len(), 5);
+        assert_eq!(parsed.skipped, 1, "temperature in an unknown unit");
+
+        let weight = &parsed.readings[0];
+        assert_eq!(weight.value_primary, 81.3);
+        assert_eq!(weight.recorded_at, at("2024-03-01 07:30:00"));
+        assert_eq!(weight.source_name.as_deref(), Some("com.withings.wiscale2"));
+
+        let hr: Vec<_> = parsed
+            .readings
+            .iter()
+            .filter(|r| r.vital_type == VitalType::HeartRate)
+            .collect();
+        assert_eq!(hr.len(), 2);
+        assert_eq!(hr[1].recorded_at, at("2024-03-01 10:01:00"));
+
+        let glucose = parsed
+            .readings
+            .iter()
+            .find(|r| r.vital_type == VitalType::BloodGlucose)
+            .unwrap();
+        assert_eq!(glucose.value_primary, 109.9);
+    }
+}
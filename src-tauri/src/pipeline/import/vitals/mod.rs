@@ -0,0 +1,587 @@
+//! VIT-01: Wearable and home-device vitals import.
+//!
+//! Bulk-imports measurements exported by health platforms into `vital_signs`:
+//! - Apple Health `export.xml` (or the `export.zip` it ships in)
+//! - Google Fit Takeout JSON (`Data Points`) and Health Connect record JSON
+//! - CSV from home devices, via a user-supplied column mapping
+//!
+//! Every parser normalises values to `VitalType::default_unit()` and attributes
+//! each reading to its originating app/device. Dense series (minute-level heart
+//! rate, CGM glucose) are thinned into one daily summary per type so the
+//! earliest-vs-latest comparison in `invariants::enrich` trend detection keeps
+//! working on representative values. Rows are deduplicated by vital type and
+//! timestamp, so re-importing the same export is a no-op.
+
+mod apple_health;
+mod csv;
+mod google_fit;
+
+pub use self::csv::{CsvColumn, CsvMapping};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use chrono::{DateTime, Local, NaiveDateTime, NaiveTime, TimeZone};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::ImportError;
+use crate::models::{VitalSign, VitalSource, VitalType};
+
+/// More readings than this for one type on one day are collapsed into a summary.
+pub const DENSE_READINGS_PER_DAY: usize = 24;
+
+/// Upper bound on a single JSON export file (Google Fit splits per data type).
+const MAX_JSON_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Supported vitals export formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VitalsFormat {
+    AppleHealth,
+    GoogleFit,
+    HealthConnect,
+    Csv,
+}
+
+impl VitalsFormat {
+    /// Human-readable platform name used in source attribution.
+    pub fn label(self) -> &'static str {
+        match self {
+            VitalsFormat::AppleHealth => "Apple Health",
+            VitalsFormat::GoogleFit => "Google Fit",
+            VitalsFormat::HealthConnect => "Health Connect",
+            VitalsFormat::Csv => "CSV",
+        }
+    }
+}
+
+/// One parsed measurement, already converted to the type's default unit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VitalReading {
+    pub vital_type: VitalType,
+    pub recorded_at: NaiveDateTime,
+    pub value_primary: f64,
+    /// Diastolic for blood pressure.
+    pub value_secondary: Option<f64>,
+    /// Originating app or device, e.g. "Apple Watch" or "com.withings.wiscale2".
+    pub source_name: Option<String>,
+    /// Set when this reading stands for a thinned daily series.
+    pub summary: Option<String>,
+}
+
+/// Output of a parser before thinning and storage.
+#[derive(Debug, Default)]
+pub struct ParsedVitals {
+    pub readings: Vec<VitalReading>,
+    /// Records of a supported type whose value, unit or timestamp was unusable.
+    pub skipped: usize,
+}
+
+impl ParsedVitals {
+    fn extend(&mut self, other: ParsedVitals) {
+        self.readings.extend(other.readings);
+        self.skipped += other.skipped;
+    }
+}
+
+/// Result of a vitals import, returned to the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VitalsImportSummary {
+    pub format: VitalsFormat,
+    /// Readings recognised in the export.
+    pub parsed: usize,
+    /// Unusable records of a supported type.
+    pub skipped: usize,
+    /// Daily summaries that replaced dense series.
+    pub summarized_days: usize,
+    /// Rows written to `vital_signs`.
+    pub inserted: usize,
+    /// Rows already present for the same type and timestamp.
+    pub duplicates: usize,
+}
+
+/// Detect the export format from the file content.
+///
+/// ZIP archives are Apple Health when they contain `export.xml`, otherwise
+/// Google Fit Takeout. Anything that is neither XML nor JSON is treated as CSV.
+pub fn detect_vitals_format(path: &Path) -> Result<VitalsFormat, ImportError> {
+    let mut head = Vec::with_capacity(4096);
+    File::open(path)?.take(4096).read_to_end(&mut head)?;
+
+    if head.starts_with(b"PK\x03\x04") {
+        let archive = zip::ZipArchive::new(File::open(path)?)
+            .map_err(|e| ImportError::FileReadError(format!("invalid ZIP archive: {e}")))?;
+        let is_apple = archive.file_names().any(is_apple_export_entry);
+        return Ok(if is_apple {
+            VitalsFormat::AppleHealth
+        } else {
+            VitalsFormat::GoogleFit
+        });
+    }
+
+    let text = String::from_utf8_lossy(&head);
+    let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+    if trimmed.starts_with('<') {
+        if text.contains("HealthData") {
+            return Ok(VitalsFormat::AppleHealth);
+        }
+        return Err(ImportError::UnsupportedFormat(
+            "XML file is not an Apple Health export".into(),
+        ));
+    }
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        return Ok(if text.contains("\"Data Points\"") {
+            VitalsFormat::GoogleFit
+        } else {
+            VitalsFormat::HealthConnect
+        });
+    }
+    Ok(VitalsFormat::Csv)
+}
+
+/// Parse an export file into normalised readings (no thinning, no storage).
+pub fn parse_vitals_file(
+    path: &Path,
+    format: VitalsFormat,
+    csv_mapping: Option<&CsvMapping>,
+) -> Result<ParsedVitals, ImportError> {
+    let is_zip = {
+        let mut magic = [0u8; 4];
+        let n = File::open(path)?.read(&mut magic)?;
+        n == 4 && &magic == b"PK\x03\x04"
+    };
+    if is_zip {
+        return parse_zip(path, format);
+    }
+
+    match format {
+        VitalsFormat::AppleHealth => apple_health::parse(BufReader::new(File::open(path)?)),
+        VitalsFormat::GoogleFit | VitalsFormat::HealthConnect => {
+            let json = read_json(File::open(path)?)?;
+            parse_json(&json, format)
+        }
+        VitalsFormat::Csv => {
+            let mapping = csv_mapping.ok_or_else(|| {
+                ImportError::UnsupportedFormat("CSV import needs a column mapping".into())
+            })?;
+            let mut text = String::new();
+            File::open(path)?.read_to_string(&mut text).map_err(|e| {
+                ImportError::FileReadError(format!("CSV is not valid UTF-8 text: {e}"))
+            })?;
+            csv::parse(&text, mapping)
+        }
+    }
+}
+
+/// Apple Health ships `apple_health_export/export.xml` inside `export.zip`;
+/// Google Fit Takeout holds one JSON file per data source.
+fn parse_zip(path: &Path, format: VitalsFormat) -> Result<ParsedVitals, ImportError> {
+    let mut archive = zip::ZipArchive::new(File::open(path)?)
+        .map_err(|e| ImportError::FileReadError(format!("invalid ZIP archive: {e}")))?;
+
+    if format == VitalsFormat::AppleHealth {
+        let name = archive
+            .file_names()
+            .find(|n| is_apple_export_entry(n))
+            .map(str::to_string)
+            .ok_or_else(|| {
+                ImportError::UnsupportedFormat("archive has no Apple Health export.xml".into())
+            })?;
+        let entry = archive
+            .by_name(&name)
+            .map_err(|e| ImportError::FileReadError(format!("unreadable {name}: {e}")))?;
+        return apple_health::parse(BufReader::new(entry));
+    }
+
+    let mut parsed = ParsedVitals::default();
+    for i in 0..archive.len() {
+        let entry = archive
+            .by_index(i)
+            .map_err(|e| ImportError::FileReadError(format!("unreadable ZIP entry: {e}")))?;
+        if entry.is_dir() || !entry.name().to_ascii_lowercase().ends_with(".json") {
+            continue;
+        }
+        let name = entry.name().to_string();
+        // Takeout also contains non-vitals JSON (sessions, activities) — skip what doesn't parse
+        match read_json(entry).and_then(|json| parse_json(&json, format)) {
+            Ok(p) => parsed.extend(p),
+            Err(e) => tracing::debug!(entry = %name, error = %e, "Skipping vitals archive entry"),
+        }
+    }
+    Ok(parsed)
+}
+
+fn is_apple_export_entry(name: &str) -> bool {
+    name == "export.xml" || name.ends_with("/export.xml")
+}
+
+fn read_json(reader: impl Read) -> Result<serde_json::Value, ImportError> {
+    let mut text = String::new();
+    reader
+        .take(MAX_JSON_BYTES)
+        .read_to_string(&mut text)
+        .map_err(|e| ImportError::FileReadError(format!("JSON is not valid UTF-8 text: {e}")))?;
+    serde_json::from_str(&text)
+        .map_err(|e| ImportError::FileReadError(format!("malformed JSON export: {e}")))
+}
+
+fn parse_json(json: &serde_json::Value, format: VitalsFormat) -> Result<ParsedVitals, ImportError> {
+    if json.get("Data Points").is_some() {
+        return Ok(google_fit::parse_google_fit(json));
+    }
+    if format == VitalsFormat::GoogleFit {
+        return Err(ImportError::UnsupportedFormat(
+            "JSON file has no Google Fit data points".into(),
+        ));
+    }
+    Ok(google_fit::parse_health_connect(json))
+}
+
+/// Parse, thin and store an export. Returns counts for the import report.
+pub fn import_vitals(
+    conn: &Connection,
+    path: &Path,
+    format: Option<VitalsFormat>,
+    csv_mapping: Option<&CsvMapping>,
+) -> Result<VitalsImportSummary, ImportError> {
+    let format = match format {
+        Some(f) => f,
+        None => detect_vitals_format(path)?,
+    };
+    let parsed = parse_vitals_file(path, format, csv_mapping)?;
+    let parsed_count = parsed.readings.len();
+
+    let readings = thin_dense_series(parsed.readings);
+    let summarized_days = readings.iter().filter(|r| r.summary.is_some()).count();
+
+    let now = Local::now().naive_local();
+    let vitals: Vec<VitalSign> = readings
+        .into_iter()
+        .map(|r| reading_to_vital_sign(r, format, now))
+        .collect();
+    let inserted = crate::db::insert_vital_signs_deduped(conn, &vitals)?;
+    // REC-01: Link readings already entered by hand or extracted from documents
+    let reconciled = crate::intelligence::reconciliation::reconcile(conn)?.vital_duplicates;
+
+    tracing::info!(
+        format = format.label(),
+        parsed = parsed_count,
+        inserted,
+        summarized_days,
+        "Vitals export imported"
+    );
+
+    Ok(VitalsImportSummary {
+        format,
+        parsed: parsed_count,
+        skipped: parsed.skipped,
+        summarized_days,
+        inserted,
+        duplicates: vitals.len() - inserted + reconciled,
+    })
+}
+
+fn reading_to_vital_sign(r: VitalReading, format: VitalsFormat, now: NaiveDateTime) -> VitalSign {
+    let attribution = match r.source_name.as_deref() {
+        Some(name) if name != format.label() => format!("{} ({name})", format.label()),
+        _ => format.label().to_string(),
+    };
+    let notes = match r.summary {
+        Some(summary) => format!("{summary} — imported from {attribution}"),
+        None => format!("Imported from {attribution}"),
+    };
+    VitalSign {
+        id: Uuid::new_v4(),
+        vital_type: r.vital_type,
+        value_primary: r.value_primary,
+        value_secondary: r.value_secondary,
+        unit: r.vital_type.default_unit().to_string(),
+        recorded_at: r.recorded_at,
+        notes: Some(notes),
+        source: VitalSource::Imported,
+        created_at: now,
+    }
+}
+
+/// Collapse days with more than [`DENSE_READINGS_PER_DAY`] readings of one
+/// type into a single mean reading at noon, keeping min/max in the summary.
+/// Sparse series (weight, cuff blood pressure) pass through untouched.
+pub fn thin_dense_series(readings: Vec<VitalReading>) -> Vec<VitalReading> {
+    let mut by_day: BTreeMap<(&'static str, chrono::NaiveDate), Vec<VitalReading>> =
+        BTreeMap::new();
+    for r in readings {
+        by_day
+            .entry((r.vital_type.as_str(), r.recorded_at.date()))
+            .or_default()
+            .push(r);
+    }
+
+    let mut out = Vec::new();
+    for ((_, day), mut group) in by_day {
+        if group.len() <= DENSE_READINGS_PER_DAY {
+            group.sort_by_key(|r| r.recorded_at);
+            out.extend(group);
+            continue;
+        }
+
+        let n = group.len() as f64;
+        let mean = round1(group.iter().map(|r| r.value_primary).sum::<f64>() / n);
+        let min = group
+            .iter()
+            .map(|r| r.value_primary)
+            .fold(f64::INFINITY, f64::min);
+        let max = group
+            .iter()
+            .map(|r| r.value_primary)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let secondary: Vec<f64> = group.iter().filter_map(|r| r.value_secondary).collect();
+        let mean_secondary = (!secondary.is_empty())
+            .then(|| round1(secondary.iter().sum::<f64>() / secondary.len() as f64));
+
+        out.push(VitalReading {
+            vital_type: group[0].vital_type,
+            recorded_at: day.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default()),
+            value_primary: mean,
+            value_secondary: mean_secondary,
+            source_name: most_common_source(&group),
+            summary: Some(format!(
+                "Daily mean of {} readings (min {}, max {})",
+                group.len(),
+                round1(min),
+                round1(max)
+            )),
+        });
+    }
+    out
+}
+
+fn most_common_source(group: &[VitalReading]) -> Option<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for name in group.iter().filter_map(|r| r.source_name.as_deref()) {
+        *counts.entry(name).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(name, _)| name.to_string())
+}
+
+fn round1(v: f64) -> f64 {
+    (v * 10.0).round() / 10.0
+}
+
+/// Convert a value in `unit` to the default unit of `vital_type`.
+///
+/// Returns `None` for unknown units and non-positive or non-finite values.
+pub(crate) fn normalize_value(vital_type: VitalType, value: f64, unit: &str) -> Option<f64> {
+    if !value.is_finite() || value <= 0.0 {
+        return None;
+    }
+    let unit = unit.trim().to_ascii_lowercase();
+    let unit = unit.as_str();
+    let converted = match vital_type {
+        VitalType::Temperature => match unit {
+            "" | "degc" | "°c" | "c" | "celsius" => value,
+            "degf" | "°f" | "f" | "fahrenheit" => (value - 32.0) * 5.0 / 9.0,
+            _ => return None,
+        },
+        VitalType::Weight => match unit {
+            "" | "kg" | "kgs" => value,
+            "lb" | "lbs" | "pound" | "pounds" => value * 0.453_592_37,
+            "g" => value / 1000.0,
+            _ => return None,
+        },
+        VitalType::Height | VitalType::HeadCircumference => match unit {
+            "" | "cm" => value,
+            "m" => value * 100.0,
+            "mm" => value / 10.0,
+            "in" | "inch" | "inches" => value * 2.54,
+            "ft" => value * 30.48,
+            _ => return None,
+        },
+        VitalType::HeartRate => match unit {
+            "" | "bpm" | "count/min" | "/min" | "beats/min" => value,
+            _ => return None,
+        },
+        VitalType::BloodGlucose => match unit {
+            "" | "mg/dl" => value,
+            // Apple Health spells it "mmol<180.1558800000541>/L"
+            u if u.starts_with("mmol") => value * 18.0156,
+            _ => return None,
+        },
+        // Apple Health and Health Connect report SpO2 as a 0–1 fraction
+        VitalType::OxygenSaturation => match unit {
+            "" | "%" | "percent" if value <= 1.0 => value * 100.0,
+            "" | "%" | "percent" => value,
+            _ => return None,
+        },
+        VitalType::BloodPressure => match unit {
+            "" | "mmhg" => value,
+            "kpa" => value * 7.500_62,
+            _ => return None,
+        },
+    };
+    Some(round1(converted))
+}
+
+/// Timestamps with an explicit offset keep the wall-clock time at which the
+/// measurement was taken; UTC instants are shown in the device's local time.
+pub(crate) fn parse_timestamp(s: &str) -> Option<NaiveDateTime> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S %z") {
+        return Some(dt.naive_local());
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        if s.ends_with('Z') || s.ends_with('z') {
+            return Some(dt.with_timezone(&Local).naive_local());
+        }
+        return Some(dt.naive_local());
+    }
+    for fmt in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(dt);
+        }
+    }
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .map(|d| d.and_time(NaiveTime::MIN))
+}
+
+/// Epoch nanoseconds (Google Fit) to local wall-clock time.
+pub(crate) fn from_epoch_nanos(nanos: i64) -> Option<NaiveDateTime> {
+    Local
+        .timestamp_opt(nanos.div_euclid(1_000_000_000), 0)
+        .single()
+        .map(|dt| dt.naive_local())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::sqlite::open_memory_database;
+    use std::io::Write;
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn reading(vital_type: VitalType, ts: NaiveDateTime, value: f64) -> VitalReading {
+        VitalReading {
+            vital_type,
+            recorded_at: ts,
+            value_primary: value,
+            value_secondary: None,
+            source_name: Some("Apple Watch".into()),
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn units_are_normalized_to_defaults() {
+        assert_eq!(normalize_value(VitalType::Weight, 165.0, "lb"), Some(74.8));
+        assert_eq!(
+            normalize_value(VitalType::Temperature, 98.6, "degF"),
+            Some(37.0)
+        );
+        assert_eq!(
+            normalize_value(VitalType::OxygenSaturation, 0.97, "%"),
+            Some(97.0)
+        );
+        assert_eq!(
+            normalize_value(VitalType::OxygenSaturation, 96.0, "%"),
+            Some(96.0)
+        );
+        assert_eq!(
+            normalize_value(VitalType::BloodGlucose, 5.5, "mmol<180.1558800000541>/L"),
+            Some(99.1)
+        );
+        assert_eq!(normalize_value(VitalType::Height, 1.78, "m"), Some(178.0));
+        assert_eq!(
+            normalize_value(VitalType::HeartRate, 72.0, "furlongs"),
+            None
+        );
+        assert_eq!(normalize_value(VitalType::Weight, 0.0, "kg"), None);
+    }
+
+    #[test]
+    fn dense_series_collapse_to_daily_summary() {
+        let mut readings: Vec<VitalReading> = (0..120)
+            .map(|m| {
+                let ts = at("2024-03-01 06:00:00") + chrono::Duration::minutes(m);
+                reading(VitalType::HeartRate, ts, 60.0 + (m % 21) as f64)
+            })
+            .collect();
+        readings.push(reading(VitalType::Weight, at("2024-03-01 07:00:00"), 80.0));
+        readings.push(reading(
+            VitalType::HeartRate,
+            at("2024-03-02 07:00:00"),
+            65.0,
+        ));
+
+        let thinned = thin_dense_series(readings);
+        assert_eq!(thinned.len(), 3);
+
+        let summary = thinned
+            .iter()
+            .find(|r| r.summary.is_some())
+            .expect("daily summary");
+        assert_eq!(summary.vital_type, VitalType::HeartRate);
+        assert_eq!(summary.recorded_at, at("2024-03-01 12:00:00"));
+        assert!(summary.value_primary > 60.0 && summary.value_primary < 80.0);
+        assert_eq!(
+            summary.summary.as_deref(),
+            Some("Daily mean of 120 readings (min 60, max 80)")
+        );
+        assert_eq!(summary.source_name.as_deref(), Some("Apple Watch"));
+
+        // Sparse series are untouched
+        assert!(thinned
+            .iter()
+            .any(|r| r.vital_type == VitalType::Weight && r.summary.is_none()));
+        assert!(thinned
+            .iter()
+            .any(|r| r.recorded_at == at("2024-03-02 07:00:00") && r.summary.is_none()));
+    }
+
+    #[test]
+    fn reimport_is_deduplicated() {
+        let conn = open_memory_database().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.xml");
+        let mut f = File::create(&path).unwrap();
+        write!(
+            f,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<HealthData locale="en_US">
+ <Record type="HKQuantityTypeIdentifierBodyMass" sourceName="Withings" unit="kg" startDate="2024-01-10 07:30:00 +0100" endDate="2024-01-10 07:30:00 +0100" value="82.4"/>
+ <Record type="HKQuantityTypeIdentifierBodyMass" sourceName="Withings" unit="kg" startDate="2024-02-20 07:30:00 +0100" endDate="2024-02-20 07:30:00 +0100" value="77.9"/>
+</HealthData>"#
+        )
+        .unwrap();
+
+        assert_eq!(
+            detect_vitals_format(&path).unwrap(),
+            VitalsFormat::AppleHealth
+        );
+        let first = import_vitals(&conn, &path, None, None).unwrap();
+        assert_eq!(first.parsed, 2);
+        assert_eq!(first.inserted, 2);
+
+        let second = import_vitals(&conn, &path, None, None).unwrap();
+        assert_eq!(second.inserted, 0);
+        assert_eq!(second.duplicates, 2);
+
+        let weights = crate::db::get_vital_signs_by_type(&conn, &VitalType::Weight).unwrap();
+        assert_eq!(weights.len(), 2);
+        assert_eq!(weights[0].source, VitalSource::Imported);
+        assert_eq!(
+            weights[0].notes.as_deref(),
+            Some("Imported from Apple Health (Withings)")
+        );
+        assert_eq!(weights[0].recorded_at, at("2024-02-20 07:30:00"));
+    }
+}
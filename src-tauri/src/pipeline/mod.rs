@@ -3,6 +3,7 @@ pub mod extraction;
 pub mod structuring;
 pub mod storage;
 pub mod processor; // E2E-B02: Document Processing Orchestrator
+pub mod checkpoint; // CKP-01: Encrypted per-page OCR/structuring checkpoints
 pub mod diagnostic; // Pipeline diagnostic dump (auto in dev, COHEARA_DUMP_DIR in prod)
 pub mod rag;
 pub mod safety;
@@ -14,3 +15,4 @@ pub mod prompt_templates; // L6-08: Strategy-aware prompt templates
 pub mod strategy; // L6-05: Context-aware strategy resolution
 pub mod quality_gate; // L6-13: Post-generation output diversity validator
 pub mod domain_contracts; // L6-10: Declarative field-to-prompt-to-DB mapping
+pub mod benchmark; // BEN-01: Extraction accuracy benchmark over golden documents
@@ -36,7 +36,7 @@ impl fmt::Display for PromptStrategyKind {
     }
 }
 
-/// Document extraction domains (7 domains from the JSON schema).
+/// Document extraction domains (7 domains from the JSON schema + IMM-01 immunizations).
 ///
 /// Distinct from `ExtractionDomain` (batch_extraction), which covers
 /// chat-based extraction (symptom, medication, appointment).
@@ -50,10 +50,11 @@ pub enum DocumentDomain {
     Procedures,
     Referrals,
     Instructions,
+    Immunizations,
 }
 
 impl DocumentDomain {
-    /// All 7 document domains.
+    /// All 8 document domains.
     pub fn all() -> &'static [DocumentDomain] {
         &[
             Self::Medications,
@@ -63,6 +64,7 @@ impl DocumentDomain {
             Self::Procedures,
             Self::Referrals,
             Self::Instructions,
+            Self::Immunizations,
         ]
     }
 
@@ -75,6 +77,7 @@ impl DocumentDomain {
             Self::Procedures => "procedures",
             Self::Referrals => "referrals",
             Self::Instructions => "instructions",
+            Self::Immunizations => "immunizations",
         }
     }
 }
@@ -237,6 +237,7 @@ mod tests {
             doc_type: "prescription".into(),
             doc_date: Some("2024-01-15".into()),
             professional_name: Some("Dr. Chen".into()),
+            suspicious: false,
         }];
 
         let citations = extract_citations(response, &chunks);
@@ -257,6 +258,7 @@ mod tests {
             doc_type: "prescription".into(),
             doc_date: None,
             professional_name: None,
+            suspicious: false,
         }];
 
         let citations = extract_citations(response, &chunks);
@@ -274,6 +276,7 @@ mod tests {
             doc_type: "note".into(),
             doc_date: None,
             professional_name: None,
+            suspicious: false,
         }];
 
         let citations = extract_citations(response, &chunks);
@@ -38,6 +38,7 @@ pub fn retrieval_strategy(query_type: &QueryType) -> RetrievalParams {
             include_screening_records: true,
             include_entity_connections: true,
             temporal_weight: 0.2,
+            include_sensitive: false,
         },
         QueryType::Exploratory => RetrievalParams {
             semantic_top_k: 8,
@@ -51,6 +52,7 @@ pub fn retrieval_strategy(query_type: &QueryType) -> RetrievalParams {
             include_screening_records: true,
             include_entity_connections: true,
             temporal_weight: 0.5,
+            include_sensitive: false,
         },
         QueryType::Symptom => RetrievalParams {
             semantic_top_k: 5,
@@ -64,6 +66,7 @@ pub fn retrieval_strategy(query_type: &QueryType) -> RetrievalParams {
             include_screening_records: false,
             include_entity_connections: true,
             temporal_weight: 0.7,
+            include_sensitive: false,
         },
         QueryType::Timeline => RetrievalParams {
             semantic_top_k: 3,
@@ -77,6 +80,7 @@ pub fn retrieval_strategy(query_type: &QueryType) -> RetrievalParams {
             include_screening_records: true,
             include_entity_connections: true,
             temporal_weight: 1.0,
+            include_sensitive: false,
         },
         QueryType::General => RetrievalParams {
             semantic_top_k: 5,
@@ -90,6 +94,7 @@ pub fn retrieval_strategy(query_type: &QueryType) -> RetrievalParams {
             include_screening_records: false,
             include_entity_connections: false,
             temporal_weight: 0.3,
+            include_sensitive: false,
         },
     }
 }
@@ -2,6 +2,7 @@ use chrono::Local;
 use rusqlite::Connection;
 use uuid::Uuid;
 
+use super::memory::pin_stated_facts;
 use super::RagError;
 use crate::db::repository;
 use crate::models::enums::{MessageFeedback, MessageRole};
@@ -47,6 +48,11 @@ impl<'a> ConversationManager<'a> {
             feedback: None,
         };
         repository::insert_message(self.conn, &msg)?;
+
+        // MEM-01: Pin first-person health statements; never fails the send.
+        if let Err(e) = pin_stated_facts(self.conn, conversation_id, msg.id, text) {
+            tracing::warn!(error = %e, "MEM-01: Failed to pin stated facts");
+        }
         Ok(msg.id)
     }
 
@@ -96,6 +102,11 @@ impl<'a> ConversationManager<'a> {
         Ok(())
     }
 
+    /// Connection the manager persists through.
+    pub fn connection(&self) -> &'a Connection {
+        self.conn
+    }
+
     fn ensure_conversation_exists(&self, id: Uuid) -> Result<(), RagError> {
         let conv = repository::get_conversation(self.conn, &id)?;
         if conv.is_none() {
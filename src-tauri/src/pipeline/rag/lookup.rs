@@ -0,0 +1,774 @@
+//! LKP-01: Deterministic answers for factual record lookups.
+//!
+//! Questions such as "when was my last HbA1c", "what dose of metformin am I on"
+//! or "who prescribed lisinopril" have exact answers in the structured tables.
+//! This module recognises those intents on top of [`classify_domain`] and
+//! [`extract_medical_keywords`], resolves them with SQL, and renders a
+//! templated answer citing the source documents. Anything it does not
+//! recognise — or cannot resolve to a record — falls through to the SLM.
+
+use std::collections::HashSet;
+
+use rusqlite::Connection;
+use uuid::Uuid;
+
+use super::classify::{classify_query, extract_medical_keywords};
+use super::domain::{classify_domain, QueryDomain};
+use super::scored_context::GroundingLevel;
+use super::types::{BoundaryCheck, Citation, ContextSummary, RagResponse};
+use super::RagError;
+use crate::invariants::lab_trends::series_key;
+use crate::sensitivity;
+
+/// Confidence attached to deterministic answers. Values are read verbatim from
+/// extracted records, so the only remaining uncertainty is extraction itself.
+pub const DETERMINISTIC_CONFIDENCE: f32 = 0.95;
+
+/// Upper bound on records listed in a single templated answer.
+const MAX_LOOKUP_ROWS: usize = 5;
+
+/// A factual question answerable straight from the structured tables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupIntent {
+    /// "When was my last HbA1c?" — most recent result per matching analyte.
+    LatestLab { terms: Vec<String> },
+    /// "What dose of metformin am I on?"
+    MedicationDose { terms: Vec<String> },
+    /// "Who prescribed lisinopril?"
+    Prescriber { terms: Vec<String> },
+    /// "What are my allergies?"
+    AllergyList,
+}
+
+// ── Intent patterns ───────────────────────────────────────────────
+
+const PRESCRIBER_PATTERNS: &[&str] = &[
+    "who prescribed", "which doctor prescribed", "who gave me",
+    "qui a prescrit", "qui m'a prescrit", "quel médecin a prescrit", "quel medecin a prescrit",
+    "wer hat", "welcher arzt hat",
+];
+
+const DOSE_PATTERNS: &[&str] = &[
+    "what dose", "which dose", "what dosage", "what is my", "what's my", "how much",
+    "quelle dose", "quelle posologie", "quel dosage", "combien de",
+    "welche dosis", "wie viel", "wieviel",
+];
+
+const LATEST_LAB_PATTERNS: &[&str] = &[
+    "last", "latest", "most recent", "what is my", "what's my", "what was my", "when was my",
+    "dernier", "dernière", "derniere", "quel est mon", "quelle est ma", "quel était", "quel etait",
+    "letzte", "wann war", "wie hoch ist", "wie hoch war",
+];
+
+const ALLERGY_LIST_PATTERNS: &[&str] = &[
+    "what are my allergies", "what am i allergic to", "list my allergies", "my allergies",
+    "quelles sont mes allergies", "je suis allergique à quoi", "je suis allergique a quoi",
+    "mes allergies", "welche allergien", "meine allergien",
+];
+
+/// Question words that never name a record. Keeps "blood" or "level" from
+/// matching "Blood urea nitrogen" or "Vitamin D level".
+const STOPWORDS: &[&str] = &[
+    // English
+    "what", "what's", "whats", "when", "was", "who", "which", "how", "much", "the", "and",
+    "for", "last", "latest", "most", "recent", "result", "results", "test", "tests", "level",
+    "levels", "value", "values", "blood", "dose", "dosage", "prescribed", "prescribe",
+    "doctor", "gave", "taking", "take", "currently", "current", "did", "does", "are",
+    // French
+    "quel", "quelle", "quels", "est", "mon", "mes", "dernier", "dernière", "derniere",
+    "était", "etait", "dose", "posologie", "dosage", "qui", "prescrit", "m'a", "médecin",
+    "medecin", "prends", "prend", "combien", "taux", "résultat", "resultat",
+    // German
+    "wann", "war", "mein", "meine", "meinen", "letzte", "letzter", "letzten", "welche",
+    "welcher", "dosis", "wer", "hat", "verschrieben", "verordnet", "arzt", "nehme", "ich",
+    "wie", "hoch", "ist", "viel", "wieviel", "wert",
+];
+
+/// Word-boundary match: "last" must not fire inside "elastase".
+fn contains_any(padded: &str, patterns: &[&str]) -> bool {
+    patterns.iter().any(|p| padded.contains(&format!(" {p} ")))
+}
+
+/// Lowercase, punctuation to spaces (apostrophes kept), padded with spaces.
+pub(crate) fn pad_words(text: &str) -> String {
+    let words: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '\'' { c } else { ' ' })
+        .collect();
+    format!(" {} ", words.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Keywords that may name a record: medical keywords minus question words.
+fn lookup_terms(text: &str) -> Vec<String> {
+    extract_medical_keywords(text)
+        .into_iter()
+        .filter(|k| !STOPWORDS.contains(&k.as_str()))
+        .collect()
+}
+
+/// Recognise a deterministic lookup intent. Returns `None` when the question
+/// needs interpretation rather than a record read.
+pub fn parse_intent(text: &str) -> Option<LookupIntent> {
+    let lower = pad_words(text);
+    let terms = lookup_terms(text);
+
+    match classify_domain(text) {
+        QueryDomain::Allergy if contains_any(&lower, ALLERGY_LIST_PATTERNS) => {
+            Some(LookupIntent::AllergyList)
+        }
+        QueryDomain::Medication if !terms.is_empty() => {
+            if contains_any(&lower, PRESCRIBER_PATTERNS) {
+                Some(LookupIntent::Prescriber { terms })
+            } else if contains_any(&lower, DOSE_PATTERNS) {
+                Some(LookupIntent::MedicationDose { terms })
+            } else {
+                None
+            }
+        }
+        // German prescriber questions carry no medication keyword for the
+        // domain classifier ("Wer hat Lisinopril verschrieben?").
+        QueryDomain::General
+            if !terms.is_empty()
+                && contains_any(&lower, &["verschrieben", "verordnet"])
+                && contains_any(&lower, PRESCRIBER_PATTERNS) =>
+        {
+            Some(LookupIntent::Prescriber { terms })
+        }
+        // Analytes outside the classifier's keyword list ("LDL", "vitamin D")
+        // land in General; the record match below decides.
+        QueryDomain::Lab | QueryDomain::General
+            if !terms.is_empty() && contains_any(&lower, LATEST_LAB_PATTERNS) =>
+        {
+            Some(LookupIntent::LatestLab { terms })
+        }
+        _ => None,
+    }
+}
+
+/// Answer a patient question from the structured tables, if possible.
+///
+/// `Ok(None)` means the intent was not recognised or no record matched; the
+/// caller should fall back to the SLM.
+/// SENS-01: Sensitive records are skipped unless `include_sensitive`.
+pub fn answer(
+    conn: &Connection,
+    text: &str,
+    lang: &str,
+    include_sensitive: bool,
+) -> Result<Option<RagResponse>, RagError> {
+    let Some(intent) = parse_intent(text) else {
+        return Ok(None);
+    };
+
+    let answer = match &intent {
+        LookupIntent::LatestLab { terms } => latest_labs(conn, terms, lang, include_sensitive)?,
+        LookupIntent::MedicationDose { terms } => {
+            medication_doses(conn, terms, lang, include_sensitive)?
+        }
+        LookupIntent::Prescriber { terms } => prescribers(conn, terms, lang, include_sensitive)?,
+        LookupIntent::AllergyList => allergy_list(conn, lang, include_sensitive)?,
+    };
+
+    Ok(answer.map(|a| a.into_response(text)))
+}
+
+// ── Templated answers ─────────────────────────────────────────────
+
+struct SourceDoc {
+    id: Uuid,
+    title: String,
+    date: Option<String>,
+    professional: Option<String>,
+}
+
+struct LookupAnswer {
+    text: String,
+    /// (source document, record line) per record used.
+    sources: Vec<(SourceDoc, String)>,
+}
+
+impl LookupAnswer {
+    fn into_response(self, query: &str) -> RagResponse {
+        let records_used = self.sources.len();
+        let mut seen = HashSet::new();
+        let citations = self
+            .sources
+            .into_iter()
+            .filter(|(doc, _)| seen.insert(doc.id))
+            .map(|(doc, line)| Citation {
+                document_id: doc.id,
+                document_title: doc.title,
+                document_date: doc.date,
+                professional_name: doc.professional,
+                chunk_text: line,
+                relevance_score: 1.0,
+            })
+            .collect();
+
+        RagResponse {
+            text: self.text,
+            citations,
+            guideline_citations: vec![],
+            confidence: DETERMINISTIC_CONFIDENCE,
+            query_type: classify_query(query),
+            context_used: ContextSummary {
+                semantic_chunks_used: 0,
+                structured_records_used: records_used,
+                total_context_tokens: 0,
+            },
+            boundary_check: BoundaryCheck::Understanding,
+            grounding: GroundingLevel::High,
+            deterministic: true,
+            grounding_violations: vec![],
+        }
+    }
+}
+
+/// One line per record; a single record reads as a plain sentence.
+fn join_lines(intro: Option<&str>, lines: &[String]) -> String {
+    match (intro, lines) {
+        (None, [single]) => single.clone(),
+        (intro, lines) => {
+            let list = lines.iter().map(|l| format!("- {l}")).collect::<Vec<_>>().join("\n");
+            match intro {
+                Some(intro) => format!("{intro}\n{list}"),
+                None => list,
+            }
+        }
+    }
+}
+
+fn name_words(name: &str) -> Vec<String> {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn name_matches(name: &str, terms: &[String]) -> bool {
+    let words = name_words(name);
+    terms.iter().any(|t| words.contains(t))
+}
+
+/// Lab names also match through analyte aliases ("HbA1c" ↔ "glycated hemoglobin").
+fn lab_name_matches(test_name: &str, terms: &[String]) -> bool {
+    if name_matches(test_name, terms) {
+        return true;
+    }
+    let key = series_key(test_name);
+    let bigrams = terms.windows(2).map(|w| format!("{} {}", w[0], w[1]));
+    terms.iter().cloned().chain(bigrams).any(|t| series_key(&t) == key)
+}
+
+fn date_only(value: Option<String>) -> Option<String> {
+    value.map(|d| d.chars().take(10).collect())
+}
+
+fn source_doc(
+    id: String,
+    title: String,
+    date: Option<String>,
+    professional: Option<String>,
+) -> Option<SourceDoc> {
+    Some(SourceDoc {
+        id: Uuid::parse_str(&id).ok()?,
+        title,
+        date: date_only(date),
+        professional,
+    })
+}
+
+// ── Latest lab ────────────────────────────────────────────────────
+
+fn flag_text(flag: &str, lang: &str) -> Option<&'static str> {
+    let text = match (flag, lang) {
+        ("high" | "critical_high", "fr") => "au-dessus de l'intervalle de référence",
+        ("high" | "critical_high", "de") => "über dem Referenzbereich",
+        ("high" | "critical_high", _) => "above the reference range",
+        ("low" | "critical_low", "fr") => "en dessous de l'intervalle de référence",
+        ("low" | "critical_low", "de") => "unter dem Referenzbereich",
+        ("low" | "critical_low", _) => "below the reference range",
+        _ => return None,
+    };
+    Some(text)
+}
+
+fn latest_labs(
+    conn: &Connection,
+    terms: &[String],
+    lang: &str,
+    include_sensitive: bool,
+) -> Result<Option<LookupAnswer>, RagError> {
+    let sql = format!(
+        "SELECT l.test_name, l.value, l.value_text, l.unit, l.abnormal_flag, l.collection_date,
+                d.id, d.title, d.document_date, p.name
+         FROM lab_results l
+         JOIN documents d ON d.id = l.document_id
+         LEFT JOIN professionals p ON p.id = d.professional_id
+         WHERE l.duplicate_of IS NULL{}
+         ORDER BY l.collection_date DESC",
+        sensitivity::entity_filter_sql("l", include_sensitive)
+    );
+    let mut stmt = conn.prepare(&sql).map_err(crate::db::DatabaseError::from)?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<f64>>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, String>(5)?,
+                row.get::<_, String>(6)?,
+                row.get::<_, String>(7)?,
+                row.get::<_, Option<String>>(8)?,
+                row.get::<_, Option<String>>(9)?,
+            ))
+        })
+        .map_err(crate::db::DatabaseError::from)?;
+
+    let mut seen_tests = HashSet::new();
+    let mut lines = Vec::new();
+    let mut sources = Vec::new();
+    for row in rows {
+        let (test_name, value, value_text, unit, flag, date, doc_id, title, doc_date, prof) =
+            row.map_err(crate::db::DatabaseError::from)?;
+        if !lab_name_matches(&test_name, terms) || !seen_tests.insert(series_key(&test_name)) {
+            continue;
+        }
+        let shown = match (value, value_text) {
+            (Some(v), _) => format!("{v}"),
+            (None, Some(t)) => t,
+            (None, None) => continue,
+        };
+        let unit = unit.map(|u| format!(" {u}")).unwrap_or_default();
+        let mut line = match lang {
+            "fr" => format!("Votre dernier résultat de {test_name} est {shown}{unit}, prélevé le {date}."),
+            "de" => format!("Ihr letzter {test_name}-Wert ist {shown}{unit}, abgenommen am {date}."),
+            _ => format!("Your most recent {test_name} result is {shown}{unit}, collected on {date}."),
+        };
+        if let Some(flag) = flag_text(&flag, lang) {
+            line.push_str(&match lang {
+                "fr" => format!(" Il était {flag}."),
+                "de" => format!(" Er lag {flag}."),
+                _ => format!(" It was {flag}."),
+            });
+        }
+        if let Some(doc) = source_doc(doc_id, title, doc_date, prof) {
+            sources.push((doc, line.clone()));
+        }
+        lines.push(line);
+        if lines.len() == MAX_LOOKUP_ROWS {
+            break;
+        }
+    }
+
+    if lines.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(LookupAnswer { text: join_lines(None, &lines), sources }))
+}
+
+// ── Medications ───────────────────────────────────────────────────
+
+struct MedicationRow {
+    name: String,
+    dose: String,
+    frequency: String,
+    status: String,
+    prescriber: Option<String>,
+    prescriber_specialty: Option<String>,
+    doc: Option<SourceDoc>,
+}
+
+/// Matching medications, active first, then most recently started.
+fn matching_medications(
+    conn: &Connection,
+    terms: &[String],
+    include_sensitive: bool,
+) -> Result<Vec<MedicationRow>, RagError> {
+    let sql = format!(
+        "SELECT m.generic_name, m.brand_name, m.dose, m.frequency, m.status,
+                p.name, p.specialty, d.id, d.title, d.document_date, dp.name
+         FROM medications m
+         JOIN documents d ON d.id = m.document_id
+         LEFT JOIN professionals p ON p.id = m.prescriber_id
+         LEFT JOIN professionals dp ON dp.id = d.professional_id
+         WHERE 1 = 1{}
+         ORDER BY CASE m.status WHEN 'active' THEN 0 WHEN 'paused' THEN 1 ELSE 2 END,
+                  COALESCE(m.start_date, d.document_date) DESC",
+        sensitivity::entity_filter_sql("m", include_sensitive)
+    );
+    let mut stmt = conn.prepare(&sql).map_err(crate::db::DatabaseError::from)?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
+                row.get::<_, String>(7)?,
+                row.get::<_, String>(8)?,
+                row.get::<_, Option<String>>(9)?,
+                row.get::<_, Option<String>>(10)?,
+            ))
+        })
+        .map_err(crate::db::DatabaseError::from)?;
+
+    let mut meds = Vec::new();
+    for row in rows {
+        let (generic, brand, dose, frequency, status, prescriber, specialty, doc_id, title, doc_date, doc_prof) =
+            row.map_err(crate::db::DatabaseError::from)?;
+        let brand_matches = brand.as_deref().is_some_and(|b| name_matches(b, terms));
+        if !name_matches(&generic, terms) && !brand_matches {
+            continue;
+        }
+        let name = match brand {
+            Some(b) if !b.eq_ignore_ascii_case(&generic) => format!("{generic} ({b})"),
+            _ => generic,
+        };
+        meds.push(MedicationRow {
+            name,
+            dose,
+            frequency,
+            status,
+            prescriber,
+            prescriber_specialty: specialty,
+            doc: source_doc(doc_id, title, doc_date, doc_prof),
+        });
+    }
+    Ok(meds)
+}
+
+fn status_text(status: &str, lang: &str) -> &'static str {
+    match (status, lang) {
+        ("paused", "fr") => "suspendu",
+        ("paused", "de") => "pausiert",
+        ("paused", _) => "paused",
+        (_, "fr") => "arrêté",
+        (_, "de") => "abgesetzt",
+        _ => "stopped",
+    }
+}
+
+fn medication_doses(
+    conn: &Connection,
+    terms: &[String],
+    lang: &str,
+    include_sensitive: bool,
+) -> Result<Option<LookupAnswer>, RagError> {
+    let meds = matching_medications(conn, terms, include_sensitive)?;
+    // Active entries answer "what am I on"; otherwise report the latest record.
+    let selected: Vec<MedicationRow> = if meds.iter().any(|m| m.status == "active") {
+        meds.into_iter().filter(|m| m.status == "active").take(MAX_LOOKUP_ROWS).collect()
+    } else {
+        meds.into_iter().take(1).collect()
+    };
+    if selected.is_empty() {
+        return Ok(None);
+    }
+
+    let mut lines = Vec::new();
+    let mut sources = Vec::new();
+    for med in selected {
+        let (name, dose, freq) = (&med.name, &med.dose, &med.frequency);
+        let line = if med.status == "active" {
+            match lang {
+                "fr" => format!("Vous prenez {name} {dose}, {freq}."),
+                "de" => format!("Sie nehmen {name} {dose}, {freq}."),
+                _ => format!("You are taking {name} {dose}, {freq}."),
+            }
+        } else {
+            let status = status_text(&med.status, lang);
+            match lang {
+                "fr" => format!("Vos documents indiquent {name} {dose}, {freq}, actuellement {status}."),
+                "de" => format!("Ihre Unterlagen nennen {name} {dose}, {freq}, derzeit {status}."),
+                _ => format!("Your records show {name} {dose}, {freq}, which is currently {status}."),
+            }
+        };
+        if let Some(doc) = med.doc {
+            sources.push((doc, line.clone()));
+        }
+        lines.push(line);
+    }
+    Ok(Some(LookupAnswer { text: join_lines(None, &lines), sources }))
+}
+
+fn prescribers(
+    conn: &Connection,
+    terms: &[String],
+    lang: &str,
+    include_sensitive: bool,
+) -> Result<Option<LookupAnswer>, RagError> {
+    let mut seen = HashSet::new();
+    let mut lines = Vec::new();
+    let mut sources = Vec::new();
+    for med in matching_medications(conn, terms, include_sensitive)? {
+        // No recorded prescriber: the documents' free text may still name one.
+        let Some(prescriber) = med.prescriber.as_deref() else {
+            continue;
+        };
+        if !seen.insert((med.name.to_lowercase(), prescriber.to_lowercase())) {
+            continue;
+        }
+        let who = match &med.prescriber_specialty {
+            Some(s) => format!("{prescriber} ({s})"),
+            None => prescriber.to_string(),
+        };
+        let name = &med.name;
+        let line = match lang {
+            "fr" => format!("{name} a été prescrit par {who}."),
+            "de" => format!("{name} wurde von {who} verschrieben."),
+            _ => format!("{name} was prescribed by {who}."),
+        };
+        if let Some(doc) = med.doc {
+            sources.push((doc, line.clone()));
+        }
+        lines.push(line);
+        if lines.len() == MAX_LOOKUP_ROWS {
+            break;
+        }
+    }
+
+    if lines.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(LookupAnswer { text: join_lines(None, &lines), sources }))
+}
+
+// ── Allergies ─────────────────────────────────────────────────────
+
+fn severity_text(severity: &str, lang: &str) -> &'static str {
+    match (severity, lang) {
+        ("mild", "fr") => "légère",
+        ("mild", "de") => "leicht",
+        ("mild", _) => "mild",
+        ("moderate", "fr") => "modérée",
+        ("moderate", "de") => "mittel",
+        ("moderate", _) => "moderate",
+        ("severe", "fr") => "sévère",
+        ("severe", "de") => "schwer",
+        ("severe", _) => "severe",
+        (_, "fr") => "potentiellement grave",
+        (_, "de") => "potenziell lebensbedrohlich",
+        _ => "potentially life-threatening",
+    }
+}
+
+fn allergy_list(
+    conn: &Connection,
+    lang: &str,
+    include_sensitive: bool,
+) -> Result<Option<LookupAnswer>, RagError> {
+    let sql = format!(
+        "SELECT a.allergen, a.reaction, a.severity, d.id, d.title, d.document_date, p.name
+         FROM allergies a
+         LEFT JOIN documents d ON d.id = a.document_id
+         LEFT JOIN professionals p ON p.id = d.professional_id
+         WHERE 1 = 1{}
+         ORDER BY a.allergen COLLATE NOCASE",
+        sensitivity::entity_filter_sql("a", include_sensitive)
+    );
+    let mut stmt = conn.prepare(&sql).map_err(crate::db::DatabaseError::from)?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<String>>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
+            ))
+        })
+        .map_err(crate::db::DatabaseError::from)?;
+
+    let mut lines = Vec::new();
+    let mut sources = Vec::new();
+    for row in rows {
+        let (allergen, reaction, severity, doc_id, title, doc_date, prof) =
+            row.map_err(crate::db::DatabaseError::from)?;
+        let severity = severity_text(&severity, lang);
+        let line = match reaction {
+            Some(r) => format!("{allergen} ({severity}): {r}"),
+            None => format!("{allergen} ({severity})"),
+        };
+        if let (Some(id), Some(title)) = (doc_id, title) {
+            if let Some(doc) = source_doc(id, title, doc_date, prof) {
+                sources.push((doc, line.clone()));
+            }
+        }
+        lines.push(line);
+    }
+
+    // An empty table is not evidence of no allergies — let the SLM answer.
+    if lines.is_empty() {
+        return Ok(None);
+    }
+    let intro = match lang {
+        "fr" => "Vos documents mentionnent les allergies suivantes :",
+        "de" => "Ihre Unterlagen nennen folgende Allergien:",
+        _ => "Your records list the following allergies:",
+    };
+    Ok(Some(LookupAnswer { text: join_lines(Some(intro), &lines), sources }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::sqlite::open_memory_database;
+
+    fn insert_doc(conn: &Connection, title: &str) -> String {
+        let id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO documents (id, type, title, document_date, ingestion_date, source_file)
+             VALUES (?1, 'lab_result', ?2, '2024-03-01 00:00:00', '2024-03-02 00:00:00', '/doc.pdf')",
+            rusqlite::params![id, title],
+        )
+        .unwrap();
+        id
+    }
+
+    fn insert_lab(conn: &Connection, doc: &str, name: &str, value: f64, flag: &str, date: &str) {
+        conn.execute(
+            "INSERT INTO lab_results (id, test_name, value, unit, abnormal_flag, collection_date, document_id)
+             VALUES (?1, ?2, ?3, '%', ?4, ?5, ?6)",
+            rusqlite::params![Uuid::new_v4().to_string(), name, value, flag, date, doc],
+        )
+        .unwrap();
+    }
+
+    fn insert_med(conn: &Connection, doc: &str, name: &str, dose: &str, status: &str, prescriber: Option<&str>) {
+        let prescriber_id = prescriber.map(|p| {
+            let id = Uuid::new_v4().to_string();
+            conn.execute(
+                "INSERT INTO professionals (id, name, specialty) VALUES (?1, ?2, 'Cardiology')",
+                rusqlite::params![id, p],
+            )
+            .unwrap();
+            id
+        });
+        conn.execute(
+            "INSERT INTO medications (id, generic_name, dose, frequency, frequency_type, status, prescriber_id, document_id)
+             VALUES (?1, ?2, ?3, 'once daily', 'scheduled', ?4, ?5, ?6)",
+            rusqlite::params![Uuid::new_v4().to_string(), name, dose, status, prescriber_id, doc],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn parses_supported_intents() {
+        assert!(matches!(
+            parse_intent("When was my last HbA1c?"),
+            Some(LookupIntent::LatestLab { terms }) if terms == vec!["hba1c"]
+        ));
+        assert!(matches!(
+            parse_intent("What dose of metformin am I on?"),
+            Some(LookupIntent::MedicationDose { terms }) if terms.contains(&"metformin".to_string())
+        ));
+        assert!(matches!(
+            parse_intent("Who prescribed lisinopril?"),
+            Some(LookupIntent::Prescriber { terms }) if terms == vec!["lisinopril"]
+        ));
+        assert_eq!(parse_intent("What are my allergies?"), Some(LookupIntent::AllergyList));
+        assert!(matches!(
+            parse_intent("Quelle dose de metformine je prends ?"),
+            Some(LookupIntent::MedicationDose { .. })
+        ));
+    }
+
+    #[test]
+    fn interpretive_questions_are_not_lookups() {
+        assert_eq!(parse_intent("Is my HbA1c normal?"), None);
+        assert_eq!(parse_intent("Why am I taking metformin?"), None);
+        assert_eq!(parse_intent("Am I allergic to penicillin?"), None);
+        assert_eq!(parse_intent("I've been feeling dizzy lately"), None);
+    }
+
+    #[test]
+    fn latest_lab_answer_cites_document() {
+        let conn = open_memory_database().unwrap();
+        let old = insert_doc(&conn, "January panel");
+        let new = insert_doc(&conn, "March panel");
+        insert_lab(&conn, &old, "HbA1c", 6.8, "high", "2024-01-10");
+        insert_lab(&conn, &new, "Glycated hemoglobin", 7.2, "high", "2024-03-01");
+
+        let response = answer(&conn, "When was my last HbA1c?", "en", false).unwrap().unwrap();
+        assert!(response.deterministic);
+        assert!(response.text.contains("7.2 %"), "{}", response.text);
+        assert!(response.text.contains("2024-03-01"));
+        assert!(response.text.contains("above the reference range"));
+        assert_eq!(response.citations.len(), 1);
+        assert_eq!(response.citations[0].document_title, "March panel");
+        assert_eq!(response.citations[0].document_date.as_deref(), Some("2024-03-01"));
+    }
+
+    #[test]
+    fn duplicate_lab_rows_are_ignored() {
+        let conn = open_memory_database().unwrap();
+        let doc = insert_doc(&conn, "Panel");
+        insert_lab(&conn, &doc, "HbA1c", 6.5, "normal", "2024-01-10");
+        conn.execute(
+            "UPDATE lab_results SET duplicate_of = 'other' WHERE test_name = 'HbA1c'",
+            [],
+        )
+        .unwrap();
+
+        assert!(answer(&conn, "What is my latest HbA1c?", "en", false).unwrap().is_none());
+    }
+
+    #[test]
+    fn dose_prefers_active_medication() {
+        let conn = open_memory_database().unwrap();
+        let doc = insert_doc(&conn, "Prescription");
+        insert_med(&conn, &doc, "Metformin", "500mg", "stopped", None);
+        insert_med(&conn, &doc, "Metformin", "1000mg", "active", None);
+
+        let response = answer(&conn, "What dose of metformin am I on?", "en", false).unwrap().unwrap();
+        assert_eq!(response.text, "You are taking Metformin 1000mg, once daily.");
+        assert_eq!(response.context_used.structured_records_used, 1);
+    }
+
+    #[test]
+    fn sensitive_records_need_explicit_request() {
+        let conn = open_memory_database().unwrap();
+        let doc = insert_doc(&conn, "Prescription");
+        insert_med(&conn, &doc, "Metformin", "500mg", "active", None);
+        crate::sensitivity::set_document_sensitive(&conn, &Uuid::parse_str(&doc).unwrap(), true)
+            .unwrap();
+
+        let question = "What dose of metformin am I on?";
+        assert!(answer(&conn, question, "en", false).unwrap().is_none());
+        assert!(answer(&conn, question, "en", true).unwrap().is_some());
+    }
+
+    #[test]
+    fn prescriber_answer_and_fallback() {
+        let conn = open_memory_database().unwrap();
+        let doc = insert_doc(&conn, "Cardiology letter");
+        insert_med(&conn, &doc, "Lisinopril", "10mg", "active", Some("Dr. Martin"));
+        insert_med(&conn, &doc, "Atorvastatin", "20mg", "active", None);
+
+        let response = answer(&conn, "Who prescribed lisinopril?", "fr", false).unwrap().unwrap();
+        assert_eq!(response.text, "Lisinopril a été prescrit par Dr. Martin (Cardiology).");
+
+        // No recorded prescriber: defer to the SLM and the document text.
+        assert!(answer(&conn, "Who prescribed atorvastatin?", "en", false).unwrap().is_none());
+    }
+
+    #[test]
+    fn unknown_record_falls_back() {
+        let conn = open_memory_database().unwrap();
+        assert!(answer(&conn, "What dose of metformin am I on?", "en", false).unwrap().is_none());
+        assert!(answer(&conn, "What are my allergies?", "en", false).unwrap().is_none());
+    }
+}
@@ -232,6 +232,7 @@ fn vital_type_display(vt: &VitalType) -> &'static str {
         VitalType::HeartRate => "Heart Rate",
         VitalType::BloodGlucose => "Blood Glucose",
         VitalType::OxygenSaturation => "Oxygen Saturation",
+        VitalType::HeadCircumference => "Head Circumference",
     }
 }
 
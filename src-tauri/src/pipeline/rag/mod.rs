@@ -7,6 +7,10 @@ pub mod citation;
 pub mod conversation;
 pub mod orchestrator;
 pub mod ollama;
+// LKP-01: Deterministic factual lookups (no SLM)
+pub mod lookup;
+// MEM-01: Rolling conversation memory
+pub mod memory;
 // ME-01: Medical Meaning Engine scoring pipeline
 pub mod medical_item;
 pub mod domain;
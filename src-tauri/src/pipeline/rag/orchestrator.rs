@@ -7,7 +7,10 @@ use super::citation::{
 use super::classify::{classify_query, retrieval_strategy};
 use super::context::assemble_context;
 use super::conversation::ConversationManager;
-use super::prompt::{build_conversation_prompt, conversation_system_prompt_i18n, no_context_response_i18n};
+use super::memory::ConversationMemory;
+use super::prompt::{
+    build_conversation_prompt_with_memory, conversation_system_prompt_i18n, no_context_response_i18n,
+};
 use super::retrieval::retrieve;
 use super::types::{
     AssembledContext, ContextSummary, PatientQuery, RagResponse, VectorSearch,
@@ -15,6 +18,7 @@ use super::types::{
 use super::RagError;
 use crate::crypto::profile::PatientDemographics;
 use crate::invariants::InvariantRegistry;
+use crate::pipeline::safety::grounding::{verify_grounding, GroundingEvidence, GroundingVerification};
 use crate::pipeline::safety::output_sanitize::sanitize_llm_output;
 use crate::pipeline::safety::sanitize::sanitize_patient_input;
 use crate::pipeline::storage::types::EmbeddingModel;
@@ -41,10 +45,11 @@ pub trait LlmGenerate {
 
 /// Full RAG pipeline orchestrator.
 ///
-/// Coordinates: classify → retrieve → enrich → assemble → generate → cite → persist.
+/// Coordinates: classify → retrieve → enrich → assemble → generate → cite → verify → persist.
 /// ME-03: Enrichment stage pairs user data with invariant registry to produce
 /// deterministic clinical insights before context assembly.
 /// ME-04: Demographics enable sex/ethnicity-aware enrichment.
+/// LKP-01: Factual record lookups are answered from SQL before any of this runs.
 pub struct DocumentRagPipeline<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> {
     generator: &'a G,
     embedder: &'a E,
@@ -56,6 +61,10 @@ pub struct DocumentRagPipeline<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorS
     lang: String,
     /// ME-04: Patient demographics for personalized enrichment.
     demographics: Option<PatientDemographics>,
+    /// MEM-01: Summary of older turns and patient-stated facts.
+    memory: Option<ConversationMemory>,
+    /// SENS-01: Patient explicitly asked to include sensitive records.
+    include_sensitive: bool,
 }
 
 impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline<'a, G, E, V> {
@@ -74,6 +83,8 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             registry,
             lang: "en".to_string(),
             demographics: None,
+            memory: None,
+            include_sensitive: false,
         }
     }
 
@@ -94,6 +105,8 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             registry,
             lang: lang.to_string(),
             demographics: None,
+            memory: None,
+            include_sensitive: false,
         }
     }
 
@@ -103,6 +116,18 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
         self
     }
 
+    /// MEM-01: Set the conversation memory injected ahead of recent turns.
+    pub fn with_memory(mut self, memory: Option<ConversationMemory>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// SENS-01: Let retrieval and lookups read records flagged sensitive.
+    pub fn with_sensitive(mut self, include_sensitive: bool) -> Self {
+        self.include_sensitive = include_sensitive;
+        self
+    }
+
     /// Execute the full RAG pipeline for a patient query.
     ///
     /// Generates a response AND persists both patient message and response
@@ -137,6 +162,13 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
     /// Use this when the caller manages persistence separately,
     /// e.g., after applying safety filtering to the response text.
     pub fn generate(&self, query: &PatientQuery) -> Result<RagResponse, RagError> {
+        // Step 0: LKP-01 — deterministic answer for recognised record lookups
+        if let Some(response) =
+            super::lookup::answer(self.conn, &query.text, &self.lang, self.include_sensitive)?
+        {
+            return Ok(response);
+        }
+
         // Step 1: Classify query
         let query_type = query
             .query_type
@@ -144,7 +176,8 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             .unwrap_or_else(|| classify_query(&query.text));
 
         // Step 2: Determine retrieval strategy
-        let params = retrieval_strategy(&query_type);
+        let mut params = retrieval_strategy(&query_type);
+        params.include_sensitive = self.include_sensitive;
 
         // Step 3: Retrieve context (semantic + structured)
         let retrieved = retrieve(
@@ -225,7 +258,12 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             .unwrap_or_else(|_| query.text.clone());
 
         // Step 7: Build prompt
-        let prompt = build_conversation_prompt(&sanitized_query, &assembled, &history);
+        let prompt = build_conversation_prompt_with_memory(
+            &sanitized_query,
+            &assembled,
+            &history,
+            self.memory.as_ref(),
+        );
 
         // Step 8: Generate response via LLM (I18N-19: language-keyed system prompt)
         let system_prompt = conversation_system_prompt_i18n(&self.lang);
@@ -248,6 +286,10 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
         // Step 11: Clean citation markers from display text
         let display_text = clean_citations_for_display(&cleaned_response);
 
+        // Step 11b: GRD-01 — verify asserted numbers, dates, drugs and doses
+        let GroundingVerification { text: display_text, violations: grounding_violations } =
+            self.verify_claims(&display_text, &retrieved.structured_data, &assembled);
+
         // Step 12: Calculate confidence (ME-01: data-driven via GroundingLevel)
         let grounding = super::scored_context::compute_grounding(&scoring_result);
         let confidence = calculate_confidence(
@@ -283,6 +325,8 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             context_used,
             boundary_check,
             grounding,
+            deterministic: false,
+            grounding_violations,
         })
     }
 
@@ -296,13 +340,22 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
         query: &PatientQuery,
         token_tx: std::sync::mpsc::Sender<String>,
     ) -> Result<RagResponse, RagError> {
+        // Step 0: LKP-01 — deterministic answers arrive as a single token
+        if let Some(response) =
+            super::lookup::answer(self.conn, &query.text, &self.lang, self.include_sensitive)?
+        {
+            let _ = token_tx.send(response.text.clone());
+            return Ok(response);
+        }
+
         // Steps 1-7: identical to generate()
         let query_type = query
             .query_type
             .clone()
             .unwrap_or_else(|| classify_query(&query.text));
 
-        let params = retrieval_strategy(&query_type);
+        let mut params = retrieval_strategy(&query_type);
+        params.include_sensitive = self.include_sensitive;
 
         let retrieved = retrieve(
             &query.text,
@@ -372,7 +425,12 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             .map(|s| s.text)
             .unwrap_or_else(|_| query.text.clone());
 
-        let prompt = build_conversation_prompt(&sanitized_query, &assembled, &history);
+        let prompt = build_conversation_prompt_with_memory(
+            &sanitized_query,
+            &assembled,
+            &history,
+            self.memory.as_ref(),
+        );
 
         // Step 8: Generate with streaming (tokens flow via channel)
         let system_prompt = conversation_system_prompt_i18n(&self.lang);
@@ -388,6 +446,8 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
         let raw_citations = extract_citations(&cleaned_response, &assembled.chunks_included);
         let citations = validate_citations(self.conn, raw_citations);
         let display_text = clean_citations_for_display(&cleaned_response);
+        let GroundingVerification { text: display_text, violations: grounding_violations } =
+            self.verify_claims(&display_text, &retrieved.structured_data, &assembled);
 
         let grounding = super::scored_context::compute_grounding(&scoring_result);
         let confidence = calculate_confidence(
@@ -419,9 +479,40 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             context_used,
             boundary_check,
             grounding,
+            deterministic: false,
+            grounding_violations,
         })
     }
 
+    /// GRD-01: Check the answer against the records and the chunks it could cite.
+    fn verify_claims(
+        &self,
+        answer: &str,
+        structured: &super::types::StructuredContext,
+        assembled: &AssembledContext,
+    ) -> GroundingVerification {
+        let drug_vocabulary = self
+            .registry
+            .drug_families()
+            .iter()
+            .flat_map(|f| f.members.iter().cloned())
+            .collect();
+        let evidence = GroundingEvidence::new(
+            structured,
+            &assembled.chunks_included,
+            &assembled.text,
+            drug_vocabulary,
+        );
+        let verification = verify_grounding(answer, &evidence, &self.lang);
+        if !verification.violations.is_empty() {
+            tracing::info!(
+                violations = verification.violations.len(),
+                "Grounding verifier amended RAG response"
+            );
+        }
+        verification
+    }
+
     fn no_context_result(&self, query_type: super::types::QueryType) -> RagResponse {
         RagResponse {
             text: no_context_response_i18n(&self.lang),
@@ -436,6 +527,8 @@ impl<'a, G: LlmGenerate, E: EmbeddingModel, V: VectorSearch> DocumentRagPipeline
             },
             boundary_check: super::types::BoundaryCheck::NoContext,
             grounding: super::scored_context::GroundingLevel::None,
+            deterministic: false,
+            grounding_violations: vec![],
         }
     }
 
@@ -811,9 +904,47 @@ mod tests {
 
         let registry = InvariantRegistry::empty();
         let pipeline = DocumentRagPipeline::new(&llm, &embedder, &vector_store, &conn, &registry);
-        let query = make_query(conv_id, "What is my metformin dose?");
+        let query = make_query(conv_id, "Tell me about my metformin");
 
         let result = pipeline.query(&query).unwrap();
+        assert!(!result.deterministic);
         assert!(result.context_used.structured_records_used >= 1);
     }
+
+    #[test]
+    fn factual_lookup_bypasses_llm() {
+        let conn = open_memory_database().unwrap();
+        let doc_id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO documents (id, type, title, ingestion_date, source_file)
+             VALUES (?1, 'lab_result', 'March panel', '2024-03-02 00:00:00', '/lab.pdf')",
+            [&doc_id],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO lab_results (id, test_name, value, unit, collection_date, document_id)
+             VALUES (?1, 'HbA1c', 6.4, '%', '2024-03-01', ?2)",
+            [&Uuid::new_v4().to_string(), &doc_id],
+        )
+        .unwrap();
+
+        let llm = MockLlm::out_of_bounds();
+        let embedder = MockEmbedder;
+        let vector_store = InMemoryVectorSearch::new();
+        let conv_mgr = ConversationManager::new(&conn);
+        let conv_id = conv_mgr.start(Some("Test")).unwrap();
+
+        let registry = InvariantRegistry::empty();
+        let pipeline = DocumentRagPipeline::new(&llm, &embedder, &vector_store, &conn, &registry);
+        let (tx, rx) = std::sync::mpsc::channel();
+        let result = pipeline
+            .generate_streaming(&make_query(conv_id, "When was my last HbA1c?"), tx)
+            .unwrap();
+
+        assert!(result.deterministic);
+        assert_eq!(result.boundary_check, BoundaryCheck::Understanding);
+        assert!(result.text.contains("6.4 %"));
+        assert_eq!(result.citations.len(), 1);
+        assert_eq!(rx.recv().unwrap(), result.text);
+    }
 }
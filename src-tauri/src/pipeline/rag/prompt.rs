@@ -2,6 +2,7 @@ use crate::models::Message;
 use crate::models::enums::MessageRole;
 use crate::pipeline::safety::sanitize::sanitize_patient_input;
 
+use super::memory::{format_memory_sections, ConversationMemory, RECENT_TURNS};
 use super::types::AssembledContext;
 
 pub const CONVERSATION_SYSTEM_PROMPT: &str = r#"You are Coheara, a patient's personal medical document assistant. You help patients understand their medical records. You are NOT a doctor.
@@ -32,13 +33,28 @@ pub fn build_conversation_prompt(
     query: &str,
     context: &AssembledContext,
     conversation_history: &[Message],
+) -> String {
+    build_conversation_prompt_with_memory(query, context, conversation_history, None)
+}
+
+/// MEM-01: Build the conversation prompt with the summary of older turns
+/// and patient-stated facts placed ahead of the recent verbatim history.
+pub fn build_conversation_prompt_with_memory(
+    query: &str,
+    context: &AssembledContext,
+    conversation_history: &[Message],
+    memory: Option<&ConversationMemory>,
 ) -> String {
     let mut prompt = String::new();
 
-    // Include recent conversation history (last 4 messages for context).
+    if let Some(memory) = memory {
+        prompt.push_str(&format_memory_sections(memory));
+    }
+
+    // Include recent conversation history (last RECENT_TURNS messages for context).
     // Sanitize each message to prevent prior injection queries from re-amplifying (SEC-01-G09).
     // G7: Annotate messages with feedback so SLM adjusts behavior.
-    let recent: Vec<_> = conversation_history.iter().rev().take(4).rev().collect();
+    let recent: Vec<_> = conversation_history.iter().rev().take(RECENT_TURNS).rev().collect();
     if !recent.is_empty() {
         let has_negative = recent.iter().any(|m| {
             m.feedback == Some(crate::models::enums::MessageFeedback::NotHelpful)
@@ -191,6 +207,36 @@ mod tests {
         assert!(!prompt.contains("CONVERSATION_HISTORY"));
     }
 
+    #[test]
+    fn prompt_places_memory_before_recent_history() {
+        let context = mock_context("Some context");
+        let history: Vec<Message> = (0..8)
+            .map(|i| Message {
+                id: Uuid::new_v4(),
+                conversation_id: Uuid::new_v4(),
+                role: MessageRole::Patient,
+                content: format!("Turn {i}"),
+                timestamp: chrono::Local::now().naive_local(),
+                source_chunks: None,
+                confidence: None,
+                feedback: None,
+            })
+            .collect();
+        let memory = ConversationMemory {
+            summary: Some("- Patient asked about statins".into()),
+            summarized_through: 4,
+            pinned_facts: vec![],
+        };
+
+        let prompt =
+            build_conversation_prompt_with_memory("Next?", &context, &history, Some(&memory));
+        let summary_at = prompt.find("<CONVERSATION_SUMMARY>").unwrap();
+        let history_at = prompt.find("<CONVERSATION_HISTORY>").unwrap();
+        assert!(summary_at < history_at);
+        assert!(!prompt.contains("Turn 3"));
+        assert!(prompt.contains("Turn 4"));
+    }
+
     #[test]
     fn no_context_response_is_helpful() {
         let response = no_context_response();
@@ -7,6 +7,7 @@ use super::classify::extract_medical_keywords;
 use super::types::{RetrievalParams, RetrievedContext, ScoredChunk, StructuredContext, VectorSearch};
 use crate::db::repository;
 use crate::pipeline::storage::types::EmbeddingModel;
+use crate::sensitivity::HiddenRecords;
 
 /// Minimum cosine similarity threshold for semantic search results (M.8).
 /// Chunks scoring below this are noise and should be filtered out.
@@ -169,6 +170,32 @@ fn deduplicate_chunks(chunks: Vec<ScoredChunk>) -> Vec<ScoredChunk> {
     deduped
 }
 
+/// SENS-01: Remove chunks from sensitive documents and flagged entities.
+fn drop_sensitive(
+    mut chunks: Vec<ScoredChunk>,
+    ctx: &mut StructuredContext,
+    hidden: &HiddenRecords,
+) -> Vec<ScoredChunk> {
+    if hidden.is_empty() {
+        return chunks;
+    }
+    let before = chunks.len();
+    chunks.retain(|c| !hidden.hides(&c.document_id.to_string(), None));
+    ctx.medications.retain(|m| !hidden.hides_entity(&m.id, Some(&m.document_id)));
+    ctx.lab_results.retain(|l| !hidden.hides_entity(&l.id, Some(&l.document_id)));
+    ctx.diagnoses.retain(|d| !hidden.hides_entity(&d.id, Some(&d.document_id)));
+    ctx.allergies.retain(|a| !hidden.hides_entity(&a.id, a.document_id.as_ref()));
+
+    if chunks.len() < before {
+        tracing::debug!(
+            before,
+            after = chunks.len(),
+            "Withheld semantic chunks from sensitive documents"
+        );
+    }
+    chunks
+}
+
 /// Run both semantic and structured retrieval.
 pub fn retrieve(
     query_text: &str,
@@ -185,7 +212,11 @@ pub fn retrieve(
     // M.4: Deduplicate chunks (keep highest-scored occurrence)
     let semantic_chunks = deduplicate_chunks(semantic_chunks);
 
-    let structured_data = structured_search(query_text, params, conn)?;
+    let mut structured_data = structured_search(query_text, params, conn)?;
+
+    // SENS-01: Flagged records stay out of chat context unless explicitly requested
+    let hidden = HiddenRecords::load(conn, params.include_sensitive)?;
+    let semantic_chunks = drop_sensitive(semantic_chunks, &mut structured_data, &hidden);
 
     let dismissed_alerts = repository::get_dismissed_alerts(conn)?
         .into_iter()
@@ -278,6 +309,7 @@ impl VectorSearch for InMemoryVectorSearch {
                 doc_type: entry.doc_type.clone(),
                 doc_date: entry.doc_date.clone(),
                 professional_name: entry.professional_name.clone(),
+                suspicious: false,
             })
             .collect())
     }
@@ -443,6 +475,7 @@ mod tests {
             doc_type: "note".to_string(),
             doc_date: None,
             professional_name: None,
+            suspicious: false,
         }
     }
 
@@ -497,6 +530,7 @@ mod tests {
                 doc_type: "note".to_string(),
                 doc_date: Some(old_date),
                 professional_name: None,
+                suspicious: false,
             },
             ScoredChunk {
                 chunk_id: "recent".to_string(),
@@ -506,6 +540,7 @@ mod tests {
                 doc_type: "note".to_string(),
                 doc_date: Some(today),
                 professional_name: None,
+                suspicious: false,
             },
         ];
 
@@ -721,6 +756,26 @@ mod tests {
         assert_eq!(result.structured_data.diagnoses.len(), 1);
     }
 
+    #[test]
+    fn retrieve_withholds_sensitive_documents_unless_requested() {
+        let (conn, doc_id) = test_db_with_full_profile();
+        let store = test_vector_store(doc_id);
+        crate::sensitivity::set_document_sensitive(&conn, &doc_id, true).unwrap();
+        let mut params = super::super::classify::retrieval_strategy(
+            &super::super::types::QueryType::Factual,
+        );
+
+        let hidden = retrieve("metformin", &MockEmbedder, &store, &params, &conn).unwrap();
+        assert!(hidden.semantic_chunks.is_empty());
+        assert!(hidden.structured_data.medications.is_empty());
+        assert!(hidden.structured_data.diagnoses.is_empty());
+
+        params.include_sensitive = true;
+        let shown = retrieve("metformin", &MockEmbedder, &store, &params, &conn).unwrap();
+        assert!(!shown.semantic_chunks.is_empty());
+        assert_eq!(shown.structured_data.medications.len(), 1);
+    }
+
     #[test]
     fn retrieve_filters_noise_chunks() {
         let (conn, doc_id) = test_db_with_full_profile();
@@ -37,6 +37,12 @@ pub struct RagResponse {
     pub boundary_check: BoundaryCheck,
     /// ME-01: Data-driven grounding level (computed from scored items, not LLM self-report).
     pub grounding: GroundingLevel,
+    /// LKP-01: Templated answer built from structured records; the SLM was not invoked.
+    #[serde(default)]
+    pub deterministic: bool,
+    /// GRD-01: Claims in `text` the records did not support (already annotated or removed).
+    #[serde(default)]
+    pub grounding_violations: Vec<crate::pipeline::safety::types::Violation>,
 }
 
 /// A source citation linking a response claim to a document
@@ -101,6 +107,8 @@ pub struct ScoredChunk {
     pub doc_type: String,
     pub doc_date: Option<String>,
     pub professional_name: Option<String>,
+    /// INJ-01: Chunk text contained prompt-injection patterns at import.
+    pub suspicious: bool,
 }
 
 /// Structured data retrieved from SQLite
@@ -143,6 +151,8 @@ pub struct RetrievalParams {
     /// B2-G6: Include entity connections (semantic graph edges) in RAG context.
     pub include_entity_connections: bool,
     pub temporal_weight: f32,
+    /// SENS-01: Include records flagged sensitive (explicit patient request only).
+    pub include_sensitive: bool,
 }
 
 /// Assembled context ready for prompt
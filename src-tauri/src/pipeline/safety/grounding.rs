@@ -0,0 +1,509 @@
+//! Post-generation grounding verification (GRD-01).
+//!
+//! `GroundingLevel` measures what retrieval found; this layer checks what the
+//! model actually asserted. Quantities, dates, drug names and doses in the
+//! answer are matched against the `StructuredContext` and the chunks the
+//! answer could cite. A sentence contradicting a record is removed; a claim
+//! with no support at all is annotated. Runs AFTER output sanitization and
+//! citation cleanup, BEFORE the boundary filter.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use chrono::NaiveDate;
+use regex::Regex;
+
+use crate::intelligence::helpers::{frequency_to_daily_multiplier, parse_dose_to_mg};
+use crate::invariants::lab_trends::normalize_unit;
+use crate::invariants::labs::find_threshold;
+use crate::pipeline::rag::lookup::pad_words;
+use crate::pipeline::rag::types::{ScoredChunk, StructuredContext};
+
+use super::types::{FilterLayer, Violation, ViolationCategory};
+
+/// Tolerance when comparing an asserted number with a recorded one.
+const VALUE_EPSILON: f64 = 1e-6;
+
+/// Number followed by a unit. The trailing group stops "mg" matching "mgx".
+static QUANTITY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(\d+(?:[.,]\d+)?)\s*(mmol/l|µmol/l|umol/l|mg/dl|g/dl|mg/l|g/l|ml/min|mmhg|bpm|mcg|µg|ug|mg|ml|iu|ui|kg|cm|g|%)(?:[^a-z0-9µ/]|$)",
+    )
+    .expect("valid regex")
+});
+static NUMBER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\d+(?:[.,]\d+)?").expect("valid regex"));
+static ISO_DATE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(\d{4})-(\d{1,2})-(\d{1,2})\b").expect("valid regex"));
+static DMY_DATE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(\d{1,2})[/.](\d{1,2})[/.](\d{4})\b").expect("valid regex"));
+
+/// Everything an answer may legitimately draw on.
+pub struct GroundingEvidence<'a> {
+    structured: &'a StructuredContext,
+    /// Lowercased context + chunk text, one entry per line.
+    lines: Vec<String>,
+    numbers: Vec<f64>,
+    dates: HashSet<NaiveDate>,
+    /// Drug names the verifier can recognise in free text (reference vocabulary).
+    drug_vocabulary: Vec<String>,
+}
+
+impl<'a> GroundingEvidence<'a> {
+    /// `context_text` is the assembled prompt context; `chunks` are the chunks
+    /// the answer could cite.
+    pub fn new(
+        structured: &'a StructuredContext,
+        chunks: &[ScoredChunk],
+        context_text: &str,
+        drug_vocabulary: Vec<String>,
+    ) -> Self {
+        let mut text = context_text.to_lowercase();
+        for chunk in chunks {
+            text.push('\n');
+            text.push_str(&chunk.content.to_lowercase());
+            if let Some(date) = &chunk.doc_date {
+                text.push('\n');
+                text.push_str(date);
+            }
+        }
+
+        let mut numbers = parse_numbers(&text);
+        let mut dates = parse_dates(&text);
+        for lab in &structured.lab_results {
+            numbers.extend(lab.value);
+            numbers.extend(lab.reference_range_low);
+            numbers.extend(lab.reference_range_high);
+            dates.insert(lab.collection_date);
+        }
+        for vital in &structured.vital_signs {
+            numbers.push(vital.value_primary);
+            numbers.extend(vital.value_secondary);
+            dates.insert(vital.recorded_at.date());
+        }
+        for med in &structured.medications {
+            numbers.extend(parse_numbers(&med.dose));
+            dates.extend(med.start_date);
+            dates.extend(med.end_date);
+        }
+        for allergy in &structured.allergies {
+            dates.extend(allergy.date_identified);
+        }
+
+        Self {
+            structured,
+            lines: text.lines().map(str::to_string).collect(),
+            numbers,
+            dates,
+            drug_vocabulary: drug_vocabulary.into_iter().map(|d| d.to_lowercase()).collect(),
+        }
+    }
+
+    fn has_number(&self, value: f64) -> bool {
+        self.numbers.iter().any(|n| (n - value).abs() < VALUE_EPSILON)
+    }
+
+    /// Some evidence line names the entity and states the number.
+    fn line_supports(&self, names: &[String], value: f64) -> bool {
+        self.lines.iter().any(|line| {
+            let padded = pad_words(line);
+            names.iter().any(|n| mentions(&padded, n))
+                && parse_numbers(line).iter().any(|n| (n - value).abs() < VALUE_EPSILON)
+        })
+    }
+
+    fn mentions_drug(&self, drug: &str) -> bool {
+        let recorded = self.structured.medications.iter().any(|m| {
+            m.generic_name.eq_ignore_ascii_case(drug)
+                || m.brand_name.as_deref().is_some_and(|b| b.eq_ignore_ascii_case(drug))
+        }) || self
+            .structured
+            .allergies
+            .iter()
+            .any(|a| a.allergen.eq_ignore_ascii_case(drug));
+        recorded || self.lines.iter().any(|l| mentions(&pad_words(l), drug))
+    }
+}
+
+/// Verified answer text plus one violation per unsupported claim.
+#[derive(Debug, Clone)]
+pub struct GroundingVerification {
+    pub text: String,
+    pub violations: Vec<Violation>,
+}
+
+/// Outcome for a single asserted claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClaimIssue {
+    /// The answer names a recorded entity but gives a different value.
+    Contradicted(&'static str),
+    /// Nothing in the records or cited chunks supports the claim.
+    Unsupported(&'static str),
+}
+
+/// Check every sentence of `answer` against the evidence.
+///
+/// Sentences with a contradicted claim are removed; sentences with
+/// unsupported claims are kept and annotated. Reasons carry no patient data.
+pub fn verify_grounding(answer: &str, evidence: &GroundingEvidence<'_>, lang: &str) -> GroundingVerification {
+    let mut text = String::with_capacity(answer.len());
+    let mut violations = Vec::new();
+    let mut removed_any = false;
+
+    for sentence in split_sentences(answer) {
+        let issues = check_sentence(sentence, evidence);
+        for issue in &issues {
+            let reason = match issue {
+                ClaimIssue::Contradicted(r) | ClaimIssue::Unsupported(r) => *r,
+            };
+            violations.push(Violation {
+                layer: FilterLayer::Grounding,
+                category: ViolationCategory::UnsupportedClaim,
+                reason: reason.to_string(),
+            });
+        }
+
+        if issues.iter().any(|i| matches!(i, ClaimIssue::Contradicted(_))) {
+            removed_any = true;
+        } else if issues.is_empty() {
+            text.push_str(sentence);
+        } else {
+            text.push_str(&annotate(sentence, unsupported_note(lang)));
+        }
+    }
+
+    let mut text = text.trim().to_string();
+    if removed_any {
+        if !text.is_empty() {
+            text.push_str("\n\n");
+        }
+        text.push_str(removed_note(lang));
+    }
+
+    GroundingVerification { text, violations }
+}
+
+fn check_sentence(sentence: &str, evidence: &GroundingEvidence<'_>) -> Vec<ClaimIssue> {
+    let padded = pad_words(sentence);
+    let mut issues = Vec::new();
+
+    // Quantities (lab values, vitals, doses)
+    for caps in QUANTITY_RE.captures_iter(sentence) {
+        let Some(value) = parse_number(&caps[1]) else { continue };
+        let unit = normalize_unit(&caps[2]);
+        if let Some(issue) = check_quantity(&padded, value, &unit, evidence) {
+            issues.push(issue);
+        }
+    }
+
+    // Dates
+    for date in parse_dates(sentence) {
+        if !evidence.dates.contains(&date) {
+            issues.push(ClaimIssue::Unsupported("Date not found in records or cited chunks"));
+        }
+    }
+
+    // Drug names from the reference vocabulary
+    for drug in &evidence.drug_vocabulary {
+        if mentions(&padded, drug) && !evidence.mentions_drug(drug) {
+            issues.push(ClaimIssue::Unsupported("Drug not found in records or cited chunks"));
+        }
+    }
+
+    issues
+}
+
+fn check_quantity(padded: &str, value: f64, unit: &str, evidence: &GroundingEvidence<'_>) -> Option<ClaimIssue> {
+    // Lab values: the sentence names a recorded analyte in the same unit
+    let labs: Vec<_> = evidence
+        .structured
+        .lab_results
+        .iter()
+        .filter(|l| l.unit.as_deref().is_some_and(|u| normalize_unit(u) == unit))
+        .filter(|l| lab_names(&l.test_name).iter().any(|n| mentions(padded, n)))
+        .collect();
+    if !labs.is_empty() {
+        let recorded = labs.iter().flat_map(|l| {
+            [l.value, l.reference_range_low, l.reference_range_high].into_iter().flatten()
+        });
+        let names: Vec<String> = labs.iter().flat_map(|l| lab_names(&l.test_name)).collect();
+        if recorded_or_stated(recorded, &names, value, evidence) {
+            return None;
+        }
+        return Some(ClaimIssue::Contradicted("Quantity contradicts the recorded lab value"));
+    }
+
+    // Doses: the sentence names a recorded medication and gives a mass
+    if let Some(claim_mg) = parse_dose_to_mg(&format!("{value}{unit}")).filter(|_| is_mass_unit(unit)) {
+        let meds: Vec<_> = evidence
+            .structured
+            .medications
+            .iter()
+            .filter(|m| {
+                std::iter::once(m.generic_name.as_str())
+                    .chain(m.brand_name.as_deref())
+                    .any(|n| mentions(padded, n))
+            })
+            .collect();
+        if !meds.is_empty() {
+            let recorded = meds.iter().flat_map(|m| {
+                let unit_mg = parse_dose_to_mg(&m.dose);
+                let daily = unit_mg.zip(frequency_to_daily_multiplier(&m.frequency)).map(|(d, f)| d * f);
+                let max = m.max_daily_dose.as_deref().and_then(parse_dose_to_mg);
+                [unit_mg, daily, max].into_iter().flatten()
+            });
+            let names: Vec<String> = meds
+                .iter()
+                .flat_map(|m| {
+                    std::iter::once(m.generic_name.to_lowercase())
+                        .chain(m.brand_name.as_ref().map(|b| b.to_lowercase()))
+                })
+                .collect();
+            if recorded_or_stated(recorded, &names, claim_mg, evidence)
+                || evidence.line_supports(&names, value)
+            {
+                return None;
+            }
+            return Some(ClaimIssue::Contradicted("Dose contradicts the recorded medication dose"));
+        }
+    }
+
+    if evidence.has_number(value) {
+        None
+    } else {
+        Some(ClaimIssue::Unsupported("Quantity not found in records or cited chunks"))
+    }
+}
+
+/// The value matches a recorded one, or a cited line states it for that entity
+/// (e.g. an older result quoted in a letter).
+fn recorded_or_stated(
+    mut recorded: impl Iterator<Item = f64>,
+    names: &[String],
+    value: f64,
+    evidence: &GroundingEvidence<'_>,
+) -> bool {
+    recorded.any(|r| (r - value).abs() < VALUE_EPSILON) || evidence.line_supports(names, value)
+}
+
+fn lab_names(test_name: &str) -> Vec<String> {
+    let mut names = vec![test_name.trim().to_lowercase()];
+    if let Some(threshold) = find_threshold(test_name) {
+        names.push(threshold.test_key.replace('_', " "));
+        names.extend(threshold.aliases.iter().map(|a| a.to_lowercase()));
+    }
+    names
+}
+
+fn is_mass_unit(unit: &str) -> bool {
+    matches!(unit, "mg" | "g" | "mcg")
+}
+
+fn parse_number(raw: &str) -> Option<f64> {
+    raw.replace(',', ".").parse().ok()
+}
+
+fn parse_numbers(text: &str) -> Vec<f64> {
+    NUMBER_RE.find_iter(text).filter_map(|m| parse_number(m.as_str())).collect()
+}
+
+fn parse_dates(text: &str) -> HashSet<NaiveDate> {
+    let iso = ISO_DATE_RE.captures_iter(text).filter_map(|c| {
+        NaiveDate::from_ymd_opt(c[1].parse().ok()?, c[2].parse().ok()?, c[3].parse().ok()?)
+    });
+    let dmy = DMY_DATE_RE.captures_iter(text).filter_map(|c| {
+        NaiveDate::from_ymd_opt(c[3].parse().ok()?, c[2].parse().ok()?, c[1].parse().ok()?)
+    });
+    iso.chain(dmy).collect()
+}
+
+/// Word-bounded match of `name` in text padded by `pad_words` ("co-amoxiclav"
+/// is padded the same way on both sides).
+fn mentions(padded: &str, name: &str) -> bool {
+    padded.contains(&pad_words(name))
+}
+
+/// Split on sentence terminators and newlines, keeping every character.
+/// A period between digits ("5.1") is not a boundary.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut out = Vec::new();
+    let mut start = 0;
+    for (i, &(idx, c)) in chars.iter().enumerate() {
+        let next = chars.get(i + 1).map(|&(_, n)| n);
+        let boundary = c == '\n'
+            || (matches!(c, '.' | '!' | '?') && next.map_or(true, char::is_whitespace));
+        if boundary {
+            let end = idx + c.len_utf8();
+            out.push(&text[start..end]);
+            start = end;
+        }
+    }
+    if start < text.len() {
+        out.push(&text[start..]);
+    }
+    out
+}
+
+/// Insert `note` before the sentence terminator (or trailing newline).
+fn annotate(sentence: &str, note: &str) -> String {
+    let body = sentence.trim_end_matches('\n');
+    let newline = &sentence[body.len()..];
+    match body.char_indices().last() {
+        Some((idx, c)) if matches!(c, '.' | '!' | '?') => {
+            format!("{} {note}{c}{newline}", &body[..idx])
+        }
+        _ => format!("{body} {note}{newline}"),
+    }
+}
+
+fn unsupported_note(lang: &str) -> &'static str {
+    match lang {
+        "fr" => "(introuvable dans vos documents)",
+        "de" => "(nicht in Ihren Dokumenten gefunden)",
+        _ => "(not found in your documents)",
+    }
+}
+
+fn removed_note(lang: &str) -> &'static str {
+    match lang {
+        "fr" => "Certaines affirmations ont été retirées car elles ne correspondaient pas à vos documents.",
+        "de" => "Einige Aussagen wurden entfernt, weil sie nicht mit Ihren Unterlagen übereinstimmten.",
+        _ => "Some statements were removed because they did not match your records.",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::enums::*;
+    use crate::models::*;
+    use uuid::Uuid;
+
+    fn lab(name: &str, value: f64, unit: &str, date: &str) -> LabResult {
+        LabResult {
+            id: Uuid::new_v4(),
+            test_name: name.into(),
+            test_code: None,
+            value: Some(value),
+            value_text: None,
+            unit: Some(unit.into()),
+            reference_range_low: Some(3.5),
+            reference_range_high: Some(5.0),
+            abnormal_flag: AbnormalFlag::High,
+            collection_date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            lab_facility: None,
+            ordering_physician_id: None,
+            document_id: Uuid::new_v4(),
+        }
+    }
+
+    fn med(name: &str, dose: &str, frequency: &str) -> Medication {
+        Medication {
+            id: Uuid::new_v4(),
+            generic_name: name.into(),
+            brand_name: None,
+            dose: dose.into(),
+            frequency: frequency.into(),
+            frequency_type: FrequencyType::Scheduled,
+            route: "oral".into(),
+            prescriber_id: None,
+            start_date: None,
+            end_date: None,
+            reason_start: None,
+            reason_stop: None,
+            is_otc: false,
+            status: MedicationStatus::Active,
+            administration_instructions: None,
+            max_daily_dose: None,
+            condition: None,
+            dose_type: DoseType::Fixed,
+            is_compound: false,
+            document_id: Uuid::new_v4(),
+        }
+    }
+
+    fn context() -> StructuredContext {
+        StructuredContext {
+            lab_results: vec![lab("Potassium", 5.1, "mmol/L", "2024-03-01")],
+            medications: vec![med("Metformin", "500mg", "twice daily")],
+            ..Default::default()
+        }
+    }
+
+    fn verify(answer: &str, structured: &StructuredContext, chunk: &str) -> GroundingVerification {
+        let chunks = vec![ScoredChunk {
+            chunk_id: "c1".into(),
+            document_id: Uuid::new_v4(),
+            content: chunk.into(),
+            score: 0.9,
+            doc_type: "lab_result".into(),
+            doc_date: None,
+            professional_name: None,
+            suspicious: false,
+        }];
+        let evidence = GroundingEvidence::new(structured, &chunks, "", vec!["metformin".into(), "warfarin".into()]);
+        verify_grounding(answer, &evidence, "en")
+    }
+
+    #[test]
+    fn supported_answer_passes_unchanged() {
+        let answer = "Your potassium was 5.1 mmol/L on 2024-03-01. You take metformin 500 mg twice daily.";
+        let result = verify(answer, &context(), "");
+        assert!(result.violations.is_empty(), "{:?}", result.violations);
+        assert_eq!(result.text, answer);
+    }
+
+    #[test]
+    fn contradicted_lab_value_is_removed() {
+        let result = verify(
+            "Your potassium was 6.1 mmol/L. This is worth discussing with your doctor.",
+            &context(),
+            "",
+        );
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].category, ViolationCategory::UnsupportedClaim);
+        assert_eq!(result.violations[0].layer, FilterLayer::Grounding);
+        assert!(!result.text.contains("6.1"));
+        assert!(result.text.starts_with("This is worth discussing"));
+        assert!(result.text.contains("did not match your records"));
+    }
+
+    #[test]
+    fn daily_total_dose_is_supported() {
+        let result = verify("That is 1000 mg of metformin per day.", &context(), "");
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn wrong_dose_is_removed() {
+        let result = verify("You take metformin 850 mg.", &context(), "");
+        assert_eq!(result.violations.len(), 1);
+        assert!(!result.text.contains("850"));
+    }
+
+    #[test]
+    fn older_value_quoted_in_chunk_is_supported() {
+        let result = verify(
+            "In 2023 your potassium was 4.2 mmol/L.",
+            &context(),
+            "Previous potassium 4.2 mmol/L (2023).",
+        );
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn unsupported_claims_are_annotated() {
+        let result = verify("You were also given warfarin on 2024-05-02.", &context(), "");
+        assert_eq!(result.violations.len(), 2);
+        assert_eq!(
+            result.text,
+            "You were also given warfarin on 2024-05-02 (not found in your documents)."
+        );
+    }
+
+    #[test]
+    fn decimal_point_does_not_split_sentences() {
+        assert_eq!(split_sentences("Value 5.1 mmol/L. Next."), vec!["Value 5.1 mmol/L.", " Next."]);
+    }
+}
@@ -3,3 +3,4 @@ pub mod boundary;
 pub mod sanitize;
 pub mod orchestrator;
 pub mod output_sanitize;
+pub mod grounding;
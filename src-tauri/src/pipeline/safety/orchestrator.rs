@@ -9,8 +9,9 @@ use super::types::{
 
 /// The production safety filter — validates the model-generated BoundaryCheck.
 ///
-/// Medical tone, grounding, urgency, and safety are the SLM's responsibility
-/// via its system prompt. This filter only enforces the structural boundary.
+/// Medical tone, urgency, and safety are the SLM's responsibility via its
+/// system prompt. This filter enforces the structural boundary and surfaces
+/// GRD-01 grounding violations recorded by the RAG pipeline.
 pub struct SafetyFilterImpl {
     /// Maximum input query length (characters).
     max_input_length: usize,
@@ -63,6 +64,7 @@ impl SafetyFilter for SafetyFilterImpl {
                 query_type: response.query_type.clone(),
                 boundary_check: BoundaryCheck::OutOfBounds,
                 grounding: response.grounding,
+                deterministic: response.deterministic,
                 filter_outcome: FilterOutcome::Blocked {
                     violations: boundary_violations,
                     fallback_message: fallback,
@@ -70,8 +72,17 @@ impl SafetyFilter for SafetyFilterImpl {
             });
         }
 
-        // Boundary is acceptable — pass through (SLM handles tone/grounding)
-        log_filter_outcome(&FilterOutcome::Passed);
+        // Boundary is acceptable — pass through (SLM handles tone).
+        // GRD-01: text was already amended; report what the verifier found.
+        let filter_outcome = if response.grounding_violations.is_empty() {
+            FilterOutcome::Passed
+        } else {
+            log_violations(&response.grounding_violations);
+            FilterOutcome::Annotated {
+                violations: response.grounding_violations.clone(),
+            }
+        };
+        log_filter_outcome(&filter_outcome);
         Ok(FilteredResponse {
             text: response.text.clone(),
             citations: response.citations.clone(),
@@ -80,7 +91,8 @@ impl SafetyFilter for SafetyFilterImpl {
             query_type: response.query_type.clone(),
             boundary_check: response.boundary_check.clone(),
             grounding: response.grounding,
-            filter_outcome: FilterOutcome::Passed,
+            deterministic: response.deterministic,
+            filter_outcome,
         })
     }
 
@@ -98,6 +110,13 @@ fn log_filter_outcome(outcome: &FilterOutcome) {
                 "Safety filter: clean pass"
             );
         }
+        FilterOutcome::Annotated { violations } => {
+            tracing::info!(
+                outcome = "annotated",
+                violation_count = violations.len(),
+                "Safety filter: passed with unsupported claims amended"
+            );
+        }
         FilterOutcome::Blocked { violations, .. } => {
             tracing::warn!(
                 outcome = "blocked",
@@ -140,6 +159,8 @@ mod tests {
             },
             boundary_check: boundary,
             grounding: GroundingLevel::Moderate,
+            deterministic: false,
+            grounding_violations: vec![],
         }
     }
 
@@ -257,6 +278,30 @@ mod tests {
         assert_eq!(result.filter_outcome, FilterOutcome::Passed);
     }
 
+    #[test]
+    fn grounding_violations_reported_as_annotated() {
+        use super::super::types::{FilterLayer, Violation, ViolationCategory};
+
+        let mut resp = make_rag_response(
+            "You take warfarin (not found in your documents).",
+            BoundaryCheck::Understanding,
+        );
+        resp.grounding_violations = vec![Violation {
+            layer: FilterLayer::Grounding,
+            category: ViolationCategory::UnsupportedClaim,
+            reason: "Drug not found in records or cited chunks".into(),
+        }];
+        let result = filter().filter_response(&resp).unwrap();
+        match result.filter_outcome {
+            FilterOutcome::Annotated { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].category, ViolationCategory::UnsupportedClaim);
+            }
+            other => panic!("Expected Annotated, got {other:?}"),
+        }
+        assert_eq!(result.text, resp.text);
+    }
+
     // =================================================================
     // I18N FALLBACK MESSAGES
     // =================================================================
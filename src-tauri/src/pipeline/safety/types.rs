@@ -6,9 +6,9 @@ use crate::pipeline::rag::types::{BoundaryCheck, Citation, GuidelineCitation, Qu
 
 /// Outcome of the safety filter pipeline.
 ///
-/// The filter validates only the model-generated `BoundaryCheck` field.
-/// Medical tone, grounding, and urgency are handled by the SLM's system prompt —
-/// NOT by keyword pattern matching.
+/// The filter validates the model-generated `BoundaryCheck` field and reports
+/// GRD-01 grounding violations found after generation. Medical tone and urgency
+/// are handled by the SLM's system prompt — NOT by keyword pattern matching.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FilteredResponse {
     /// The safe text to display (unchanged from RAG — SLM controls tone).
@@ -25,6 +25,8 @@ pub struct FilteredResponse {
     pub boundary_check: BoundaryCheck,
     /// ME-01: Data-driven grounding level (passed through from RAG).
     pub grounding: GroundingLevel,
+    /// LKP-01: Answer came from structured records, not the SLM (passed through).
+    pub deterministic: bool,
     /// Filter outcome summary.
     pub filter_outcome: FilterOutcome,
 }
@@ -39,6 +41,9 @@ pub enum FilterOutcome {
         violations: Vec<Violation>,
         fallback_message: String,
     },
+    /// GRD-01: Response passed boundary check, but claims not supported by the
+    /// records were annotated or removed from the text.
+    Annotated { violations: Vec<Violation> },
 }
 
 /// A specific safety violation detected by the boundary check layer.
@@ -57,6 +62,8 @@ pub struct Violation {
 pub enum FilterLayer {
     /// Layer 1: Model-generated boundary check validation.
     BoundaryCheck,
+    /// Layer 2 (GRD-01): Post-generation claim verification against records.
+    Grounding,
 }
 
 /// Classification of what kind of unsafe content was detected.
@@ -64,6 +71,9 @@ pub enum FilterLayer {
 pub enum ViolationCategory {
     /// BoundaryCheck::OutOfBounds — response is outside medical document scope.
     BoundaryViolation,
+    /// GRD-01: A number, unit, date, drug or dose the answer asserts is
+    /// contradicted by, or absent from, the records and cited chunks.
+    UnsupportedClaim,
 }
 
 /// Result of input sanitization (pre-LLM).
@@ -157,6 +167,10 @@ mod tests {
         };
         let json = serde_json::to_string(&blocked).unwrap();
         assert!(json.contains("Blocked"));
+
+        let annotated = FilterOutcome::Annotated { violations: vec![] };
+        let json = serde_json::to_string(&annotated).unwrap();
+        assert!(json.contains("Annotated"));
     }
 
     #[test]
@@ -29,6 +29,8 @@ pub fn store_entities(
     let mut counts = EntitiesStoredCount::default();
     let mut warnings = Vec::new();
     let mut stored_entities: Vec<StoredEntity> = Vec::new();
+    // PROV-01: (type, extracted index, stored id) for provenance records
+    let mut stored_ids: Vec<(EntityType, usize, Uuid)> = Vec::new();
 
     let professional_id = match &result.professional {
         Some(prof) => match resolve_professional(conn, prof) {
@@ -50,6 +52,7 @@ pub fn store_entities(
         professional_id,
         &mut warnings,
         &mut stored_entities,
+        &mut stored_ids,
     )?;
 
     counts.lab_results = store_lab_results(
@@ -59,6 +62,7 @@ pub fn store_entities(
         professional_id,
         &mut warnings,
         &mut stored_entities,
+        &mut stored_ids,
     )?;
 
     counts.diagnoses = store_diagnoses(
@@ -67,6 +71,7 @@ pub fn store_entities(
         &result.document_id,
         professional_id,
         &mut stored_entities,
+        &mut stored_ids,
     )?;
 
     counts.allergies = store_allergies(
@@ -76,6 +81,7 @@ pub fn store_entities(
         result.document_date,
         registry,
         &mut stored_entities,
+        &mut stored_ids,
     )?;
 
     counts.procedures = store_procedures(
@@ -83,6 +89,7 @@ pub fn store_entities(
         &result.extracted_entities.procedures,
         &result.document_id,
         professional_id,
+        &mut stored_ids,
     )?;
 
     counts.referrals = store_referrals(
@@ -91,6 +98,15 @@ pub fn store_entities(
         &result.document_id,
         professional_id,
         &mut stored_entities,
+        &mut stored_ids,
+    )?;
+
+    counts.immunizations = store_immunizations(
+        conn,
+        &result.extracted_entities.immunizations,
+        &result.document_id,
+        professional_id,
+        &mut stored_ids,
     )?;
 
     counts.instructions = store_instructions(
@@ -99,6 +115,13 @@ pub fn store_entities(
         &result.document_id,
     )?;
 
+    store_entity_sources(conn, &result.document_id, &result.field_sources, &stored_ids)?;
+
+    // REC-01: Link results already reported by another document
+    if counts.lab_results > 0 {
+        crate::intelligence::reconciliation::reconcile(conn)?;
+    }
+
     // C9: Extract and store connections between entities in this document.
     let connections = connection_extractor::extract_connections(
         conn,
@@ -135,10 +158,11 @@ fn store_medications(
     professional_id: Option<Uuid>,
     warnings: &mut Vec<StorageWarning>,
     stored_entities: &mut Vec<StoredEntity>,
+    stored_ids: &mut Vec<(EntityType, usize, Uuid)>,
 ) -> Result<usize, StorageError> {
     let mut count = 0;
 
-    for extracted in meds {
+    for (index, extracted) in meds.iter().enumerate() {
         let generic_name = extracted
             .generic_name
             .clone()
@@ -180,6 +204,7 @@ fn store_medications(
             continue;
         }
 
+        stored_ids.push((EntityType::Medication, index, med_id));
         stored_entities.push(StoredEntity {
             entity_type: EntityType::Medication,
             id: med_id,
@@ -259,10 +284,11 @@ fn store_lab_results(
     professional_id: Option<Uuid>,
     warnings: &mut Vec<StorageWarning>,
     stored_entities: &mut Vec<StoredEntity>,
+    stored_ids: &mut Vec<(EntityType, usize, Uuid)>,
 ) -> Result<usize, StorageError> {
     let mut count = 0;
 
-    for extracted in labs {
+    for (index, extracted) in labs.iter().enumerate() {
         let collection_date = extracted
             .collection_date
             .as_deref()
@@ -301,6 +327,7 @@ fn store_lab_results(
             ordering_physician_id: professional_id,
             document_id: *document_id,
         };
+        stored_ids.push((EntityType::LabResult, index, lab.id));
         stored_entities.push(StoredEntity {
             entity_type: EntityType::LabResult,
             id: lab.id,
@@ -322,10 +349,11 @@ fn store_diagnoses(
     document_id: &Uuid,
     professional_id: Option<Uuid>,
     stored_entities: &mut Vec<StoredEntity>,
+    stored_ids: &mut Vec<(EntityType, usize, Uuid)>,
 ) -> Result<usize, StorageError> {
     let mut count = 0;
 
-    for extracted in diagnoses {
+    for (index, extracted) in diagnoses.iter().enumerate() {
         let date_diagnosed = extracted
             .date
             .as_deref()
@@ -344,6 +372,7 @@ fn store_diagnoses(
             document_id: *document_id,
         };
 
+        stored_ids.push((EntityType::Diagnosis, index, diag.id));
         stored_entities.push(StoredEntity {
             entity_type: EntityType::Diagnosis,
             id: diag.id,
@@ -366,10 +395,11 @@ fn store_allergies(
     document_date: Option<NaiveDate>,
     registry: &crate::invariants::InvariantRegistry,
     stored_entities: &mut Vec<StoredEntity>,
+    stored_ids: &mut Vec<(EntityType, usize, Uuid)>,
 ) -> Result<usize, StorageError> {
     let mut count = 0;
 
-    for extracted in allergies {
+    for (index, extracted) in allergies.iter().enumerate() {
         let severity = extracted
             .severity
             .as_deref()
@@ -393,6 +423,7 @@ fn store_allergies(
             verified: false,
         };
 
+        stored_ids.push((EntityType::Allergy, index, allergy.id));
         stored_entities.push(StoredEntity {
             entity_type: EntityType::Allergy,
             id: allergy.id,
@@ -413,10 +444,11 @@ fn store_procedures(
     procedures: &[crate::pipeline::structuring::types::ExtractedProcedure],
     document_id: &Uuid,
     professional_id: Option<Uuid>,
+    stored_ids: &mut Vec<(EntityType, usize, Uuid)>,
 ) -> Result<usize, StorageError> {
     let mut count = 0;
 
-    for extracted in procedures {
+    for (index, extracted) in procedures.iter().enumerate() {
         let date = extracted
             .date
             .as_deref()
@@ -440,6 +472,7 @@ fn store_procedures(
         };
 
         repository::insert_procedure(conn, &proc)?;
+        stored_ids.push((EntityType::Procedure, index, proc.id));
         count += 1;
     }
 
@@ -452,10 +485,11 @@ fn store_referrals(
     document_id: &Uuid,
     referring_professional_id: Option<Uuid>,
     stored_entities: &mut Vec<StoredEntity>,
+    stored_ids: &mut Vec<(EntityType, usize, Uuid)>,
 ) -> Result<usize, StorageError> {
     let mut count = 0;
 
-    for extracted in referrals {
+    for (index, extracted) in referrals.iter().enumerate() {
         let referred_to = repository::find_or_create_professional(
             conn,
             &extracted.referred_to,
@@ -475,6 +509,7 @@ fn store_referrals(
             document_id: Some(*document_id),
         };
 
+        stored_ids.push((EntityType::Referral, index, referral.id));
         stored_entities.push(StoredEntity {
             entity_type: EntityType::Referral,
             id: referral.id,
@@ -490,6 +525,83 @@ fn store_referrals(
     Ok(count)
 }
 
+/// IMM-01: One row per administered dose. The vaccinator named on the row wins
+/// over the document's professional (booklets are signed by many hands).
+fn store_immunizations(
+    conn: &Connection,
+    immunizations: &[crate::pipeline::structuring::types::ExtractedImmunization],
+    document_id: &Uuid,
+    professional_id: Option<Uuid>,
+    stored_ids: &mut Vec<(EntityType, usize, Uuid)>,
+) -> Result<usize, StorageError> {
+    let mut count = 0;
+
+    for (index, extracted) in immunizations.iter().enumerate() {
+        if extracted.vaccine.trim().is_empty() {
+            continue;
+        }
+
+        let administered_date = extracted
+            .date
+            .as_deref()
+            .and_then(crate::pipeline::structuring::classify::parse_document_date);
+
+        let administering_professional_id = match extracted.administered_by.as_deref() {
+            Some(name) if !name.trim().is_empty() => {
+                Some(repository::find_or_create_professional(conn, name.trim(), None)?.id)
+            }
+            _ => professional_id,
+        };
+
+        let immunization = Immunization {
+            id: Uuid::new_v4(),
+            vaccine: extracted.vaccine.trim().to_string(),
+            dose_number: extracted.dose_number,
+            administered_date,
+            lot_number: extracted.lot_number.clone(),
+            site: extracted.site.clone(),
+            administering_professional_id,
+            document_id: *document_id,
+        };
+
+        repository::insert_immunization(conn, &immunization)?;
+        stored_ids.push((EntityType::Immunization, index, immunization.id));
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// PROV-01: Persist field sources against the stored entity ids.
+/// Sources of entities that were not stored (e.g. failed inserts) are dropped.
+fn store_entity_sources(
+    conn: &Connection,
+    document_id: &Uuid,
+    field_sources: &[FieldSource],
+    stored_ids: &[(EntityType, usize, Uuid)],
+) -> Result<(), StorageError> {
+    for source in field_sources {
+        let Some(&(_, _, entity_id)) = stored_ids
+            .iter()
+            .find(|(t, i, _)| *t == source.entity_type && *i == source.entity_index)
+        else {
+            continue;
+        };
+        repository::insert_entity_source(
+            conn,
+            &EntitySource {
+                id: Uuid::new_v4(),
+                document_id: *document_id,
+                entity_type: source.entity_type.clone(),
+                entity_id,
+                field_name: source.field_name.clone(),
+                location: source.location.clone(),
+            },
+        )?;
+    }
+    Ok(())
+}
+
 fn store_instructions(
     _conn: &Connection,
     instructions: &[crate::pipeline::structuring::types::ExtractedInstruction],
@@ -552,6 +664,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         }
     }
 
@@ -622,6 +736,66 @@ mod tests {
         assert_eq!(counts.lab_results, 1);
     }
 
+    #[test]
+    fn store_persists_field_sources_against_stored_ids() {
+        let conn = test_db();
+        let doc_id = make_document(&conn);
+        let mut result = minimal_structuring_result(doc_id);
+
+        result.extracted_entities.lab_results.push(ExtractedLabResult {
+            test_name: "HbA1c".into(),
+            test_code: None,
+            value: Some(7.2),
+            value_text: None,
+            unit: Some("%".into()),
+            reference_range_low: None,
+            reference_range_high: None,
+            reference_range_text: None,
+            abnormal_flag: None,
+            collection_date: None,
+            confidence: 0.95,
+        });
+        result.field_sources = vec![
+            FieldSource {
+                entity_type: EntityType::LabResult,
+                entity_index: 0,
+                field_name: "value".into(),
+                location: SourceLocation {
+                    page_number: 2,
+                    region: Some(SourceRegion { x: 0.5, y: 0.3, width: 0.1, height: 0.02 }),
+                    region_approximate: false,
+                    text_span: Some(TextSpan { start: 40, end: 43 }),
+                    quote: Some("7.2".into()),
+                },
+            },
+            // Entity that does not exist — dropped
+            FieldSource {
+                entity_type: EntityType::Diagnosis,
+                entity_index: 0,
+                field_name: "name".into(),
+                location: SourceLocation {
+                    page_number: 1,
+                    region: None,
+                    region_approximate: true,
+                    text_span: None,
+                    quote: None,
+                },
+            },
+        ];
+
+        store_entities(&conn, &result, &InvariantRegistry::empty()).unwrap();
+
+        let sources = repository::get_entity_sources_for_document(&conn, &doc_id).unwrap();
+        assert_eq!(sources.len(), 1);
+        let labs = repository::get_lab_results_by_test_name(&conn, "HbA1c").unwrap();
+        assert_eq!(sources[0].entity_id, labs[0].id);
+        assert_eq!(sources[0].location, result.field_sources[0].location);
+
+        // Reprocessing replaces, not duplicates
+        store_entities(&conn, &result, &InvariantRegistry::empty()).unwrap();
+        assert_eq!(repository::get_entity_sources_for_document(&conn, &doc_id).unwrap().len(), 1);
+    }
+
     #[test]
     fn store_diagnosis_entities() {
         let conn = test_db();
@@ -693,6 +867,32 @@ mod tests {
         assert_eq!(counts.referrals, 1);
     }
 
+    #[test]
+    fn store_immunization_entities() {
+        let conn = test_db();
+        let doc_id = make_document(&conn);
+        let mut result = minimal_structuring_result(doc_id);
+
+        result.extracted_entities.immunizations.push(ExtractedImmunization {
+            vaccine: "Hexyon".into(),
+            dose_number: Some(2),
+            date: Some("06.05.2024".into()),
+            lot_number: Some("A21CB123".into()),
+            site: Some("left thigh".into()),
+            administered_by: Some("Dr. Martin".into()),
+            confidence: 0.9,
+        });
+
+        let (counts, _warnings) = store_entities(&conn, &result, &InvariantRegistry::empty()).unwrap();
+        assert_eq!(counts.immunizations, 1);
+
+        let stored = repository::get_all_immunizations(&conn).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].dose_number, Some(2));
+        assert_eq!(stored[0].administered_date, NaiveDate::from_ymd_opt(2024, 5, 6));
+        assert!(stored[0].administering_professional_id.is_some());
+    }
+
     #[test]
     fn professional_created_once_for_document() {
         let conn = test_db();
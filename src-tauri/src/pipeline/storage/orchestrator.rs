@@ -151,8 +151,10 @@ impl<C: Chunker, E: EmbeddingModel, V: VectorStore> StoragePipeline
         match repository::get_document(&tx, &document_id) {
             Ok(Some(mut doc)) => {
                 doc.doc_type = structuring_result.document_type.clone();
-                doc.document_date = structuring_result.document_date;
-                doc.professional_id = professional_id;
+                // EML-01: Keep import-time hints (email Date/From) when
+                // structuring found no date or professional
+                doc.document_date = structuring_result.document_date.or(doc.document_date);
+                doc.professional_id = professional_id.or(doc.professional_id);
                 doc.markdown_file = Some(markdown_path);
                 if let Err(e) = repository::update_document(&tx, &doc) {
                     tracing::warn!(
@@ -177,6 +179,17 @@ impl<C: Chunker, E: EmbeddingModel, V: VectorStore> StoragePipeline
             }
         }
 
+        // INJ-01: Persist the quarantine flag (chunk-level flags were set by the vector store)
+        if structuring_result.suspicious_content {
+            if let Err(e) = repository::mark_document_suspicious(&tx, &document_id) {
+                tracing::warn!(
+                    document_id = %document_id,
+                    error = %e,
+                    "Failed to flag document as suspicious"
+                );
+            }
+        }
+
         // Step 7: Update profile trust (inside transaction)
         if let Err(e) = repository::update_profile_trust_verified(&tx) {
             tracing::warn!(
@@ -194,7 +207,8 @@ impl<C: Chunker, E: EmbeddingModel, V: VectorStore> StoragePipeline
             document_id = %document_id,
             entities = entity_counts.medications + entity_counts.lab_results +
                 entity_counts.diagnoses + entity_counts.allergies +
-                entity_counts.procedures + entity_counts.referrals,
+                entity_counts.procedures + entity_counts.referrals +
+                entity_counts.immunizations,
             "Storage transaction committed"
         );
 
@@ -323,12 +337,15 @@ mod tests {
                     text: "Follow up in 3 months".into(),
                     category: "follow_up".into(),
                 }],
+                immunizations: vec![],
                 blood_type: None,
             },
             structuring_confidence: 0.87,
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         }
     }
 
@@ -464,6 +481,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
 
         let result = pipeline.store(&structuring, &session);
@@ -491,6 +510,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
 
         let result = pipeline.store(&structuring, &session).unwrap();
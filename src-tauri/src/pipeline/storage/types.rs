@@ -27,6 +27,7 @@ pub struct EntitiesStoredCount {
     pub procedures: usize,
     pub referrals: usize,
     pub instructions: usize,
+    pub immunizations: usize,
 }
 
 /// Warnings from storage process
@@ -0,0 +1,823 @@
+//! CAS-01: Record/replay ("cassette") LLM client for offline pipeline tests.
+//!
+//! Recording: an `OllamaClient` built with `with_cassette()` tees every raw
+//! response body (NDJSON stream or JSON) into a cassette, keyed by a hash of
+//! the request. Replay: `CassetteClient` implements `LlmClient` and
+//! `VisionClient` by feeding the recorded bytes through the same collectors
+//! the live client uses — StreamGuard aborts and QualityGate failures
+//! reproduce exactly, without a model or network.
+//!
+//! Cassettes never store prompts or images, only their hash. Response bodies
+//! are stored verbatim, so record against synthetic documents only.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use super::StructuringError;
+use super::ollama::{collect_chat_stream_guarded, collect_generate_stream, parse_error_body};
+use super::ollama_types::OllamaError;
+use super::types::{LlmClient, VisionCallParams, VisionClient};
+
+/// Current on-disk cassette format version.
+pub const CASSETTE_VERSION: u32 = 1;
+
+#[derive(Error, Debug)]
+pub enum CassetteError {
+    #[error("Cassette I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Malformed cassette: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Unsupported cassette version {0} (expected {CASSETTE_VERSION})")]
+    UnsupportedVersion(u32),
+}
+
+/// Which Ollama endpoint produced a recorded response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CassetteEndpoint {
+    /// `/api/generate`, non-streaming JSON (`LlmClient::generate`).
+    Generate,
+    /// `/api/generate` with images, NDJSON stream.
+    GenerateWithImages,
+    /// `/api/chat` with images, NDJSON stream (StreamGuard-monitored).
+    Chat,
+}
+
+/// The request fields that determine a response — hashed into the cassette key.
+#[derive(Debug, Clone, Copy)]
+pub struct CassetteRequest<'a> {
+    pub endpoint: CassetteEndpoint,
+    pub model: &'a str,
+    pub system: Option<&'a str>,
+    pub prompt: &'a str,
+    pub images: &'a [String],
+    pub params: VisionCallParams,
+}
+
+impl CassetteRequest<'_> {
+    /// Stable hex key for this request.
+    ///
+    /// Vision params are normalized to the values the client actually sends
+    /// (temperature 0.0, num_predict 2048 when unset), so `chat_with_images`
+    /// and `chat_with_images_with_params` with default params share a key.
+    pub fn key(&self) -> String {
+        let mut hasher = Sha256::new();
+        let mut field = |bytes: &[u8]| {
+            hasher.update((bytes.len() as u64).to_le_bytes());
+            hasher.update(bytes);
+        };
+        field(format!("{:?}", self.endpoint).as_bytes());
+        field(self.model.as_bytes());
+        field(self.system.unwrap_or("").as_bytes());
+        field(self.prompt.as_bytes());
+        for image in self.images {
+            field(&Sha256::digest(image.as_bytes()));
+        }
+        if self.endpoint != CassetteEndpoint::Generate {
+            field(&self.params.temperature.unwrap_or(0.0).to_bits().to_le_bytes());
+            field(&self.params.num_predict.unwrap_or(2048).to_le_bytes());
+        }
+        let digest = hasher.finalize();
+        digest[..16].iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+/// One recorded request/response pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub key: String,
+    pub endpoint: CassetteEndpoint,
+    pub model: String,
+    pub status: u16,
+    /// Raw response body exactly as Ollama sent it.
+    pub body: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CassetteFile {
+    version: u32,
+    interactions: Vec<Interaction>,
+}
+
+/// An ordered set of recorded interactions, optionally backed by a file.
+///
+/// Identical requests recorded several times replay in recording order;
+/// once exhausted, the last recording repeats.
+pub struct Cassette {
+    path: Option<PathBuf>,
+    interactions: Mutex<Vec<Interaction>>,
+    cursors: Mutex<HashMap<String, usize>>,
+}
+
+impl Cassette {
+    /// Empty cassette that persists to `path` after every recorded interaction.
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: Some(path.to_path_buf()),
+            interactions: Mutex::new(Vec::new()),
+            cursors: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Cassette held in memory only (tests, hand-built fixtures).
+    pub fn from_interactions(interactions: Vec<Interaction>) -> Self {
+        Self {
+            path: None,
+            interactions: Mutex::new(interactions),
+            cursors: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Load a cassette file for replay.
+    pub fn load(path: &Path) -> Result<Self, CassetteError> {
+        let file: CassetteFile = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if file.version != CASSETTE_VERSION {
+            return Err(CassetteError::UnsupportedVersion(file.version));
+        }
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            interactions: Mutex::new(file.interactions),
+            cursors: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Snapshot of all recorded interactions, in recording order.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().map(|i| i.clone()).unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.interactions.lock().map(|i| i.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Write the cassette to its backing file (no-op for in-memory cassettes).
+    pub fn save(&self) -> Result<(), CassetteError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let file = CassetteFile {
+            version: CASSETTE_VERSION,
+            interactions: self.interactions(),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&file)?)?;
+        Ok(())
+    }
+
+    /// Append a response and persist. Failures are logged, never surfaced —
+    /// recording must not break the inference call it observes.
+    pub fn record_response(&self, request: &CassetteRequest<'_>, status: u16, body: &[u8]) {
+        let interaction = Interaction {
+            key: request.key(),
+            endpoint: request.endpoint,
+            model: request.model.to_string(),
+            status,
+            body: String::from_utf8_lossy(body).into_owned(),
+        };
+        if let Ok(mut interactions) = self.interactions.lock() {
+            interactions.push(interaction);
+        }
+        if let Err(e) = self.save() {
+            tracing::warn!(error = %e, "CAS-01: Failed to persist cassette");
+        }
+    }
+
+    /// Next recording for `key`, advancing its replay cursor.
+    fn next(&self, key: &str) -> Option<Interaction> {
+        let interactions = self.interactions.lock().ok()?;
+        let matching: Vec<&Interaction> = interactions.iter().filter(|i| i.key == key).collect();
+        let last = matching.len().checked_sub(1)?;
+        let mut cursors = self.cursors.lock().ok()?;
+        let cursor = cursors.entry(key.to_string()).or_insert(0);
+        let interaction = matching[(*cursor).min(last)].clone();
+        *cursor += 1;
+        Some(interaction)
+    }
+}
+
+/// Reader adapter that copies every byte it yields into a capture buffer.
+///
+/// Wraps the live response stream so the recorded body stops exactly where
+/// the collector stopped reading (e.g. at a StreamGuard abort).
+pub(crate) struct TapReader<R> {
+    inner: R,
+    captured: Option<Vec<u8>>,
+}
+
+impl<R: Read> TapReader<R> {
+    /// `capture = false` makes the tap a zero-cost passthrough.
+    pub(crate) fn new(inner: R, capture: bool) -> Self {
+        Self {
+            inner,
+            captured: capture.then(Vec::new),
+        }
+    }
+
+    pub(crate) fn into_captured(self) -> Option<Vec<u8>> {
+        self.captured
+    }
+}
+
+impl<R: Read> Read for TapReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        if let Some(captured) = &mut self.captured {
+            captured.extend_from_slice(&buf[..n]);
+        }
+        Ok(n)
+    }
+}
+
+/// Replays a cassette as both `LlmClient` and `VisionClient`.
+///
+/// A request with no recording fails with HTTP 404 — a changed prompt shows
+/// up as a test failure, not as a silent fallback.
+pub struct CassetteClient {
+    cassette: Arc<Cassette>,
+}
+
+impl CassetteClient {
+    pub fn new(cassette: Arc<Cassette>) -> Self {
+        Self { cassette }
+    }
+
+    /// Load a cassette file and replay it.
+    pub fn from_file(path: &Path) -> Result<Self, CassetteError> {
+        Ok(Self::new(Arc::new(Cassette::load(path)?)))
+    }
+
+    fn lookup(&self, request: &CassetteRequest<'_>) -> Option<Interaction> {
+        let key = request.key();
+        let interaction = self.cassette.next(&key);
+        if interaction.is_none() {
+            tracing::warn!(
+                key = %key,
+                endpoint = ?request.endpoint,
+                model = %request.model,
+                "CAS-01: No cassette recording for request"
+            );
+        }
+        interaction
+    }
+
+    fn replay_vision(&self, request: &CassetteRequest<'_>) -> Result<String, OllamaError> {
+        let interaction = self.lookup(request).ok_or_else(|| OllamaError::ApiError {
+            status: 404,
+            message: format!("No cassette recording for request {}", request.key()),
+        })?;
+        if !(200..300).contains(&interaction.status) {
+            return Err(OllamaError::ApiError {
+                status: interaction.status,
+                message: parse_error_body(&interaction.body),
+            });
+        }
+
+        let start = std::time::Instant::now();
+        let reader = std::io::Cursor::new(interaction.body.into_bytes());
+        let (text, _metrics) = match request.endpoint {
+            CassetteEndpoint::Chat => collect_chat_stream_guarded(
+                reader,
+                request.model,
+                &start,
+                crate::pipeline::stream_guard::StreamGuardConfig::default(),
+            )?,
+            _ => collect_generate_stream(reader, request.model, &start)?,
+        };
+        Ok(text)
+    }
+
+    fn models(&self) -> Vec<String> {
+        let mut models: Vec<String> = self
+            .cassette
+            .interactions()
+            .into_iter()
+            .map(|i| i.model)
+            .collect();
+        models.sort();
+        models.dedup();
+        models
+    }
+}
+
+impl LlmClient for CassetteClient {
+    fn generate(
+        &self,
+        model: &str,
+        prompt: &str,
+        system: &str,
+    ) -> Result<String, StructuringError> {
+        let request = CassetteRequest {
+            endpoint: CassetteEndpoint::Generate,
+            model,
+            system: Some(system),
+            prompt,
+            images: &[],
+            params: VisionCallParams::default(),
+        };
+        let interaction = self.lookup(&request).ok_or_else(|| {
+            StructuringError::HttpClient(format!(
+                "No cassette recording for request {}",
+                request.key()
+            ))
+        })?;
+        if interaction.status != 200 {
+            return Err(StructuringError::OllamaError {
+                status: interaction.status,
+                body: parse_error_body(&interaction.body),
+            });
+        }
+
+        // Non-streaming body is a single final chunk — same shape as NDJSON.
+        let start = std::time::Instant::now();
+        let reader = std::io::Cursor::new(interaction.body.into_bytes());
+        let (text, _metrics) = collect_generate_stream(reader, model, &start)?;
+        Ok(text)
+    }
+
+    fn is_model_available(&self, model: &str) -> Result<bool, StructuringError> {
+        Ok(self.models().iter().any(|m| m.starts_with(model)))
+    }
+
+    fn list_models(&self) -> Result<Vec<String>, StructuringError> {
+        Ok(self.models())
+    }
+}
+
+impl VisionClient for CassetteClient {
+    fn generate_with_images(
+        &self,
+        model: &str,
+        prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+    ) -> Result<String, OllamaError> {
+        self.replay_vision(&CassetteRequest {
+            endpoint: CassetteEndpoint::GenerateWithImages,
+            model,
+            system,
+            prompt,
+            images,
+            params: VisionCallParams::default(),
+        })
+    }
+
+    fn chat_with_images(
+        &self,
+        model: &str,
+        user_prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+    ) -> Result<String, OllamaError> {
+        self.chat_with_images_with_params(
+            model,
+            user_prompt,
+            images,
+            system,
+            VisionCallParams::default(),
+        )
+    }
+
+    fn chat_with_images_with_params(
+        &self,
+        model: &str,
+        user_prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+        params: VisionCallParams,
+    ) -> Result<String, OllamaError> {
+        self.replay_vision(&CassetteRequest {
+            endpoint: CassetteEndpoint::Chat,
+            model,
+            system,
+            prompt: user_prompt,
+            images,
+            params,
+        })
+    }
+}
+
+/// Scripted stand-in for a model that records what it answers.
+///
+/// Each vision request is answered from `script` (prompt → response text),
+/// unscripted prompts get `fallback`, and every answer is teed into the
+/// cassette as the Ollama body the live client would have received. Builds
+/// replayable fixtures for multi-call sequences (IterativeDrill
+/// enumerate-then-drill) against synthetic documents, without a model.
+pub struct ScriptedRecorder {
+    cassette: Arc<Cassette>,
+    script: Vec<(String, String)>,
+    fallback: String,
+}
+
+impl ScriptedRecorder {
+    pub fn new(cassette: Arc<Cassette>, fallback: &str) -> Self {
+        Self {
+            cassette,
+            script: Vec::new(),
+            fallback: fallback.to_string(),
+        }
+    }
+
+    /// Answer `prompt` with `response`.
+    pub fn answer(mut self, prompt: &str, response: &str) -> Self {
+        self.script.push((prompt.to_string(), response.to_string()));
+        self
+    }
+
+    fn respond(&self, request: &CassetteRequest<'_>) -> String {
+        let text = self
+            .script
+            .iter()
+            .find(|(prompt, _)| prompt == request.prompt)
+            .map_or(self.fallback.as_str(), |(_, response)| response.as_str());
+        let body = match request.endpoint {
+            CassetteEndpoint::Chat => format!(
+                "{}\n{}\n",
+                serde_json::json!({"message": {"content": text}, "done": false}),
+                serde_json::json!({"message": {"content": ""}, "done": true, "done_reason": "stop"}),
+            ),
+            _ => format!("{}\n", serde_json::json!({"response": text, "done": true})),
+        };
+        self.cassette.record_response(request, 200, body.as_bytes());
+        text.to_string()
+    }
+}
+
+impl VisionClient for ScriptedRecorder {
+    fn generate_with_images(
+        &self,
+        model: &str,
+        prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+    ) -> Result<String, OllamaError> {
+        Ok(self.respond(&CassetteRequest {
+            endpoint: CassetteEndpoint::GenerateWithImages,
+            model,
+            system,
+            prompt,
+            images,
+            params: VisionCallParams::default(),
+        }))
+    }
+
+    fn chat_with_images(
+        &self,
+        model: &str,
+        user_prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+    ) -> Result<String, OllamaError> {
+        self.chat_with_images_with_params(
+            model,
+            user_prompt,
+            images,
+            system,
+            VisionCallParams::default(),
+        )
+    }
+
+    fn chat_with_images_with_params(
+        &self,
+        model: &str,
+        user_prompt: &str,
+        images: &[String],
+        system: Option<&str>,
+        params: VisionCallParams,
+    ) -> Result<String, OllamaError> {
+        Ok(self.respond(&CassetteRequest {
+            endpoint: CassetteEndpoint::Chat,
+            model,
+            system,
+            prompt: user_prompt,
+            images,
+            params,
+        }))
+    }
+}
+
+/// Recorder scripted for a one-result lab report (Hemoglobin 13.5 g/dL,
+/// range 12.0–16.0) read by the English IterativeDrill prompts.
+#[cfg(test)]
+pub(crate) fn lab_drill_recorder(cassette: Arc<Cassette>) -> ScriptedRecorder {
+    let locale = crate::pipeline::domain_contracts::locale_for_domain("lab_results", "en");
+    let answer = |text: &str| format!("{}{text}{}", locale.answer_start, locale.answer_end);
+    ScriptedRecorder::new(cassette, &answer(locale.none_keyword))
+        .answer(locale.vision_enumerate, &answer("Hemoglobin"))
+        .answer(&locale.vision_drill_value.replace("{item}", "Hemoglobin"), &answer("13.5 g/dL"))
+        .answer(&locale.vision_drill_range.replace("{item}", "Hemoglobin"), &answer("12.0-16.0"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::butler_service::{FallbackSession, SessionError, VisionSession};
+    use crate::pipeline::strategy::ContextType;
+
+    const MODEL: &str = "medgemma:4b";
+
+    fn chat_stream(tokens: &[&str]) -> String {
+        let mut body = String::new();
+        for token in tokens {
+            body.push_str(&serde_json::json!({"message": {"content": token}, "done": false}).to_string());
+            body.push('\n');
+        }
+        body.push_str(r#"{"message":{"content":""},"done":true,"done_reason":"stop","eval_count":12}"#);
+        body.push('\n');
+        body
+    }
+
+    fn chat_request<'a>(prompt: &'a str, images: &'a [String], params: VisionCallParams) -> CassetteRequest<'a> {
+        CassetteRequest {
+            endpoint: CassetteEndpoint::Chat,
+            model: MODEL,
+            system: None,
+            prompt,
+            images,
+            params,
+        }
+    }
+
+    fn interaction(request: &CassetteRequest<'_>, status: u16, body: &str) -> Interaction {
+        Interaction {
+            key: request.key(),
+            endpoint: request.endpoint,
+            model: request.model.to_string(),
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    fn session_params() -> VisionCallParams {
+        // FallbackSession always passes its strategy's params; record with the
+        // same ones the session will send.
+        let session = FallbackSession::new(MODEL, ContextType::VisionOcr, false);
+        let probe = ParamsProbe::default();
+        let _ = session.chat_with_images(&probe, "probe", &[], None);
+        let params = *probe.params.lock().unwrap();
+        params.expect("session sent params")
+    }
+
+    #[derive(Default)]
+    struct ParamsProbe {
+        params: Mutex<Option<VisionCallParams>>,
+    }
+
+    impl VisionClient for ParamsProbe {
+        fn generate_with_images(&self, _: &str, _: &str, _: &[String], _: Option<&str>) -> Result<String, OllamaError> {
+            Ok(String::new())
+        }
+        fn chat_with_images(&self, _: &str, _: &str, _: &[String], _: Option<&str>) -> Result<String, OllamaError> {
+            Ok(String::new())
+        }
+        fn chat_with_images_with_params(
+            &self,
+            _: &str,
+            _: &str,
+            _: &[String],
+            _: Option<&str>,
+            params: VisionCallParams,
+        ) -> Result<String, OllamaError> {
+            *self.params.lock().unwrap() = Some(params);
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn key_is_stable_and_request_sensitive() {
+        let images = vec!["aW1n".to_string()];
+        let base = chat_request("List medications", &images, VisionCallParams::default());
+        assert_eq!(base.key(), base.key());
+        assert_eq!(base.key().len(), 32);
+
+        let other_prompt = chat_request("List allergies", &images, VisionCallParams::default());
+        assert_ne!(base.key(), other_prompt.key());
+
+        let other_images = vec!["b3RoZXI=".to_string()];
+        assert_ne!(base.key(), chat_request("List medications", &other_images, VisionCallParams::default()).key());
+
+        let hot = VisionCallParams { temperature: Some(0.7), num_predict: None };
+        assert_ne!(base.key(), chat_request("List medications", &images, hot).key());
+    }
+
+    #[test]
+    fn default_params_share_key_with_explicit_defaults() {
+        let images = vec!["aW1n".to_string()];
+        let unset = chat_request("p", &images, VisionCallParams { temperature: None, num_predict: None });
+        let explicit = chat_request("p", &images, VisionCallParams { temperature: Some(0.0), num_predict: Some(2048) });
+        assert_eq!(unset.key(), explicit.key());
+    }
+
+    #[test]
+    fn replays_chat_stream_through_guard() {
+        let images = vec!["aW1n".to_string()];
+        let request = chat_request("Read the page", &images, VisionCallParams::default());
+        let body = chat_stream(&["Metformin", " 500", " mg", " twice", " daily"]);
+        let client = CassetteClient::new(Arc::new(Cassette::from_interactions(vec![
+            interaction(&request, 200, &body),
+        ])));
+
+        let text = client.chat_with_images(MODEL, "Read the page", &images, None).unwrap();
+        assert_eq!(text, "Metformin 500 mg twice daily");
+    }
+
+    #[test]
+    fn degenerate_recording_aborts_in_stream_guard() {
+        let images = vec!["aW1n".to_string()];
+        let params = session_params();
+        let request = chat_request("Read the page", &images, params);
+        let mut tokens = vec!["Titre"];
+        tokens.extend(std::iter::repeat("\n").take(40));
+        let client = CassetteClient::new(Arc::new(Cassette::from_interactions(vec![
+            interaction(&request, 200, &chat_stream(&tokens)),
+        ])));
+
+        let session = FallbackSession::new(MODEL, ContextType::VisionOcr, false);
+        let result = session.chat_with_images(&client, "Read the page", &images, None);
+        assert!(
+            matches!(result, Err(SessionError::Degeneration { ref partial_output, .. }) if partial_output.starts_with("Titre")),
+            "expected StreamGuard degeneration, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn repetitive_recording_fails_quality_gate() {
+        let images = vec!["aW1n".to_string()];
+        let params = session_params();
+        let request = chat_request("Read the page", &images, params);
+        // Whole lines as tokens: too few to trip StreamGuard, but one line
+        // dominates the output.
+        let mut tokens = vec!["Patient: Jane Doe\n"; 8];
+        tokens.push("Date: 2024-01-15\n");
+        let client = CassetteClient::new(Arc::new(Cassette::from_interactions(vec![
+            interaction(&request, 200, &chat_stream(&tokens)),
+        ])));
+
+        let session = FallbackSession::new(MODEL, ContextType::VisionOcr, false);
+        let result = session.chat_with_images(&client, "Read the page", &images, None);
+        assert!(
+            matches!(result, Err(SessionError::QualityGate { .. })),
+            "expected quality gate failure, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn replays_generate_json() {
+        let request = CassetteRequest {
+            endpoint: CassetteEndpoint::Generate,
+            model: MODEL,
+            system: Some("You are a medical extractor."),
+            prompt: "Extract",
+            images: &[],
+            params: VisionCallParams::default(),
+        };
+        let body = r#"{"model":"medgemma:4b","response":"{\"medications\":[]}","done":true,"done_reason":"stop"}"#;
+        let client = CassetteClient::new(Arc::new(Cassette::from_interactions(vec![
+            interaction(&request, 200, body),
+        ])));
+
+        let text = client.generate(MODEL, "Extract", "You are a medical extractor.").unwrap();
+        assert_eq!(text, r#"{"medications":[]}"#);
+        assert!(client.is_model_available("medgemma").unwrap());
+        assert_eq!(client.list_models().unwrap(), vec![MODEL.to_string()]);
+    }
+
+    #[test]
+    fn identical_requests_replay_in_order_then_repeat_last() {
+        let images = vec!["aW1n".to_string()];
+        let request = chat_request("Again", &images, VisionCallParams::default());
+        let client = CassetteClient::new(Arc::new(Cassette::from_interactions(vec![
+            interaction(&request, 200, &chat_stream(&["first"])),
+            interaction(&request, 200, &chat_stream(&["second"])),
+        ])));
+
+        let call = || client.chat_with_images(MODEL, "Again", &images, None).unwrap();
+        assert_eq!(call(), "first");
+        assert_eq!(call(), "second");
+        assert_eq!(call(), "second");
+    }
+
+    #[test]
+    fn missing_recording_is_an_error() {
+        let client = CassetteClient::new(Arc::new(Cassette::from_interactions(vec![])));
+        let vision = client.chat_with_images(MODEL, "unrecorded", &[], None);
+        assert!(matches!(vision, Err(OllamaError::ApiError { status: 404, .. })));
+        let text = client.generate(MODEL, "unrecorded", "sys");
+        assert!(matches!(text, Err(StructuringError::HttpClient(_))));
+    }
+
+    #[test]
+    fn recorded_error_status_replays_as_api_error() {
+        let request = chat_request("Read", &[], VisionCallParams::default());
+        let client = CassetteClient::new(Arc::new(Cassette::from_interactions(vec![
+            interaction(&request, 500, r#"{"error":"model runner crashed"}"#),
+        ])));
+        let result = client.chat_with_images(MODEL, "Read", &[], None);
+        assert!(
+            matches!(result, Err(OllamaError::ApiError { status: 500, ref message }) if message.contains("model runner crashed")),
+            "got {result:?}"
+        );
+    }
+
+    #[test]
+    fn tap_records_and_file_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassettes").join("ocr.json");
+        let cassette = Cassette::new(&path);
+
+        let images = vec!["aW1n".to_string()];
+        let request = chat_request("Read the page", &images, VisionCallParams::default());
+        let body = chat_stream(&["Aspirin", " 100", " mg"]);
+
+        // Collector reads through the tap exactly as the live client does.
+        let mut tap = TapReader::new(std::io::Cursor::new(body.clone().into_bytes()), true);
+        let start = std::time::Instant::now();
+        let (text, _) = collect_chat_stream_guarded(
+            &mut tap,
+            MODEL,
+            &start,
+            crate::pipeline::stream_guard::StreamGuardConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(text, "Aspirin 100 mg");
+        cassette.record_response(&request, 200, &tap.into_captured().unwrap());
+
+        let stored = std::fs::read_to_string(&path).unwrap();
+        assert!(!stored.contains("Read the page"), "prompts must not be persisted");
+
+        let replay = CassetteClient::from_file(&path).unwrap();
+        assert_eq!(
+            replay.chat_with_images(MODEL, "Read the page", &images, None).unwrap(),
+            "Aspirin 100 mg"
+        );
+    }
+
+    #[test]
+    fn drill_sequence_replays_from_recording() {
+        use crate::pipeline::extraction::vision_classifier::UserDocumentType;
+        use crate::pipeline::structuring::strategy_iterative_drill::IterativeDrillStrategy;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("drill.json");
+        let images = vec!["c3ludGhldGljIGxhYiByZXBvcnQ=".to_string()];
+        let drill = IterativeDrillStrategy::new(1);
+        let session = FallbackSession::new(MODEL, ContextType::VisionOcr, false);
+        let run = |client: &dyn VisionClient, images: &[String]| {
+            drill.extract_from_image(&session, client, images, "", None, Some(UserDocumentType::LabReport), "en")
+        };
+
+        // Record: professional + date, then enumerate and drill per domain
+        let recorder = lab_drill_recorder(Arc::new(Cassette::new(&path)));
+        let recorded = run(&recorder, &images).unwrap();
+        let cassette = Cassette::load(&path).unwrap();
+        // 2 meta + 2 enumerate (labs, diagnoses) + value and range drills
+        assert_eq!(cassette.len(), 6);
+
+        let replayed = run(&CassetteClient::new(Arc::new(cassette)), &images).unwrap();
+        assert_eq!(replayed.raw_responses, recorded.raw_responses);
+        assert_eq!(replayed.entities.lab_results.len(), 1);
+        let hb = &replayed.entities.lab_results[0];
+        assert_eq!(hb.test_name, "Hemoglobin");
+        assert_eq!(hb.value, Some(13.5));
+        assert_eq!(hb.unit.as_deref(), Some("g/dL"));
+        assert_eq!((hb.reference_range_low, hb.reference_range_high), (Some(12.0), Some(16.0)));
+
+        // A different page is not in the recording
+        let other = vec!["b3RoZXIgcGFnZQ==".to_string()];
+        assert!(run(&CassetteClient::from_file(&path).unwrap(), &other).is_err());
+    }
+
+    #[test]
+    fn passthrough_tap_captures_nothing() {
+        let mut tap = TapReader::new(std::io::Cursor::new(b"abc".to_vec()), false);
+        let mut out = String::new();
+        tap.read_to_string(&mut out).unwrap();
+        assert_eq!(out, "abc");
+        assert!(tap.into_captured().is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("future.json");
+        std::fs::write(&path, r#"{"version":99,"interactions":[]}"#).unwrap();
+        assert!(matches!(
+            Cassette::load(&path),
+            Err(CassetteError::UnsupportedVersion(99))
+        ));
+    }
+}
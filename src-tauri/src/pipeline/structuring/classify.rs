@@ -61,7 +61,7 @@ pub fn classify_from_entities(entities: &ExtractedEntities) -> DocumentType {
 }
 
 /// Parse a date string from MedGemma output (handles various formats).
-/// Supports: ISO 8601, European DD/MM/YYYY, US MM/DD/YYYY, French textual dates.
+/// Supports: ISO 8601, European DD/MM/YYYY and DD.MM.YYYY, US MM/DD/YYYY, French textual dates.
 pub fn parse_document_date(date_str: &str) -> Option<NaiveDate> {
     let trimmed = date_str.trim();
     if trimmed.is_empty() || trimmed == "null" || trimmed == "NOT_FOUND" {
@@ -80,6 +80,10 @@ pub fn parse_document_date(date_str: &str) -> Option<NaiveDate> {
     if let Ok(d) = NaiveDate::parse_from_str(trimmed, "%d-%m-%Y") {
         return Some(d);
     }
+    // German dotted: DD.MM.YYYY
+    if let Ok(d) = NaiveDate::parse_from_str(trimmed, "%d.%m.%Y") {
+        return Some(d);
+    }
     // US: MM/DD/YYYY
     if let Ok(d) = NaiveDate::parse_from_str(trimmed, "%m/%d/%Y") {
         return Some(d);
@@ -234,6 +238,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_german_dotted_date() {
+        assert_eq!(
+            parse_document_date("15.01.2024"),
+            Some(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+        );
+    }
+
     #[test]
     fn parse_invalid_date_returns_none() {
         assert_eq!(parse_document_date("invalid"), None);
@@ -50,6 +50,7 @@ fn count_entities(entities: &ExtractedEntities) -> usize {
         + entities.procedures.len()
         + entities.referrals.len()
         + entities.instructions.len()
+        + entities.immunizations.len()
 }
 
 /// Adjust individual entity confidence based on OCR confidence.
@@ -101,6 +102,9 @@ pub fn apply_confidence_caps(entities: &mut ExtractedEntities, ocr_confidence: f
     for referral in &mut entities.referrals {
         referral.confidence = adjust_entity_confidence(referral.confidence, ocr_confidence);
     }
+    for imm in &mut entities.immunizations {
+        imm.confidence = adjust_entity_confidence(imm.confidence, ocr_confidence);
+    }
 }
 
 #[cfg(test)]
@@ -150,6 +154,7 @@ mod tests {
             lab_results: vec![],
             procedures: vec![],
             referrals: vec![],
+            immunizations: vec![],
             blood_type: None,
         }
     }
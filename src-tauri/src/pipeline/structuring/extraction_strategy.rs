@@ -1,7 +1,7 @@
 //! STR-01: Extraction strategy trait and output types.
 //!
 //! Defines the `ExtractionStrategy` trait that all extraction strategies implement.
-//! Each strategy owns its LLM call pattern (7 calls, N×M calls, etc.) and returns
+//! Each strategy owns its LLM call pattern (8 calls, N×M calls, etc.) and returns
 //! a common `StrategyOutput` that the orchestrator feeds through shared post-processing.
 //!
 //! Principle: The SLM does ONE thing per call. The CODE orchestrates.
@@ -27,7 +27,7 @@ use crate::pipeline::structuring::StructuringError;
 /// confidence, sanitize) on this output regardless of which strategy produced it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategyOutput {
-    /// Extracted entities across all 7 domains.
+    /// Extracted entities across all 8 domains.
     pub entities: ExtractedEntities,
     /// Combined markdown representation of the extraction.
     pub markdown: String,
@@ -52,7 +52,7 @@ pub struct StrategyOutput {
 /// strategy, then applies shared post-processing on the output.
 ///
 /// Implementations:
-/// - `MarkdownListStrategy`: 7 calls (1 per domain), ~25-token prompts
+/// - `MarkdownListStrategy`: 8 calls (1 per domain), ~25-token prompts
 /// - `IterativeDrillStrategy`: 7 enumerate + N×M drill calls, ~15-token prompts
 pub trait ExtractionStrategy: Send + Sync {
     /// Extract entities from document text using the strategy's LLM call pattern.
@@ -81,7 +81,7 @@ pub trait ExtractionStrategy: Send + Sync {
 /// Build a concrete extraction strategy from a resolved `PromptStrategy`.
 ///
 /// Maps `PromptStrategyKind` to the appropriate strategy implementation:
-/// - `MarkdownList` → `MarkdownListStrategy` (7 calls, 1 per domain)
+/// - `MarkdownList` → `MarkdownListStrategy` (8 calls, 1 per domain)
 /// - `IterativeDrill` → `IterativeDrillStrategy` (7 enumerate + N×M drill)
 pub fn build_strategy(strategy: &PromptStrategy) -> Box<dyn ExtractionStrategy> {
     match strategy.kind {
@@ -9,8 +9,9 @@
 
 use crate::pipeline::prompt_templates::DocumentDomain;
 use crate::pipeline::structuring::types::{
-    ExtractedAllergy, ExtractedDiagnosis, ExtractedEntities, ExtractedInstruction,
-    ExtractedLabResult, ExtractedMedication, ExtractedProcedure, ExtractedReferral,
+    ExtractedAllergy, ExtractedDiagnosis, ExtractedEntities, ExtractedImmunization,
+    ExtractedInstruction, ExtractedLabResult, ExtractedMedication, ExtractedProcedure,
+    ExtractedReferral,
 };
 
 // ═══════════════════════════════════════════════════════════
@@ -26,6 +27,7 @@ pub enum DomainEntities {
     Procedures(Vec<ExtractedProcedure>),
     Referrals(Vec<ExtractedReferral>),
     Instructions(Vec<ExtractedInstruction>),
+    Immunizations(Vec<ExtractedImmunization>),
 }
 
 impl DomainEntities {
@@ -39,6 +41,7 @@ impl DomainEntities {
             Self::Procedures(v) => entities.procedures.extend(v),
             Self::Referrals(v) => entities.referrals.extend(v),
             Self::Instructions(v) => entities.instructions.extend(v),
+            Self::Immunizations(v) => entities.immunizations.extend(v),
         }
     }
 }
@@ -57,6 +60,7 @@ pub fn parse_domain_response(domain: DocumentDomain, response: &str) -> DomainEn
         DocumentDomain::Procedures => DomainEntities::Procedures(parse_procedures_markdown(response)),
         DocumentDomain::Referrals => DomainEntities::Referrals(parse_referrals_markdown(response)),
         DocumentDomain::Instructions => DomainEntities::Instructions(parse_instructions_markdown(response)),
+        DocumentDomain::Immunizations => DomainEntities::Immunizations(parse_immunizations_markdown(response)),
     }
 }
 
@@ -249,6 +253,16 @@ fn parse_f64(s: &str) -> Option<f64> {
     normalized.trim().parse().ok()
 }
 
+/// Parse a dose number such as "2", "dose 2", "2/3" or "2nd" (first number wins).
+pub(crate) fn parse_dose_number(s: &str) -> Option<u32> {
+    let digits: String = s
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok().filter(|n| *n > 0 && *n < 20)
+}
+
 // ═══════════════════════════════════════════════════════════
 // Per-domain parsers
 // ═══════════════════════════════════════════════════════════
@@ -419,6 +433,31 @@ pub fn parse_instructions_markdown(response: &str) -> Vec<ExtractedInstruction>
         .collect()
 }
 
+/// IMM-01: Parse administered vaccine doses from markdown list response.
+pub fn parse_immunizations_markdown(response: &str) -> Vec<ExtractedImmunization> {
+    let bullets = split_bullets(response);
+    bullets
+        .iter()
+        .filter(|b| !b.trim().is_empty())
+        .map(|item| {
+            let name = extract_name(item);
+            ExtractedImmunization {
+                vaccine: name,
+                dose_number: extract_field(item, "dose")
+                    .as_deref()
+                    .and_then(parse_dose_number),
+                date: extract_field(item, "date"),
+                lot_number: extract_field(item, "lot"),
+                site: extract_field(item, "site"),
+                administered_by: extract_field(item, "administered_by")
+                    .or_else(|| extract_field(item, "administered by"))
+                    .or_else(|| extract_field(item, "vaccinateur")),
+                confidence: 0.0,
+            }
+        })
+        .collect()
+}
+
 // ═══════════════════════════════════════════════════════════
 // Tests
 // ═══════════════════════════════════════════════════════════
@@ -746,6 +785,38 @@ mod tests {
         assert!(parse_instructions_markdown("").is_empty());
     }
 
+    // ── Immunizations parser ─────────────────────────────
+
+    #[test]
+    fn immunizations_happy_path() {
+        let response = "\
+- DTaP-IPV-Hib-HepB
+  - dose: 2
+  - date: 2024-05-06
+  - lot: A21CB123
+  - site: left thigh";
+        let imms = parse_immunizations_markdown(response);
+        assert_eq!(imms.len(), 1);
+        assert_eq!(imms[0].vaccine, "DTaP-IPV-Hib-HepB");
+        assert_eq!(imms[0].dose_number, Some(2));
+        assert_eq!(imms[0].date.as_deref(), Some("2024-05-06"));
+        assert_eq!(imms[0].lot_number.as_deref(), Some("A21CB123"));
+        assert_eq!(imms[0].site.as_deref(), Some("left thigh"));
+    }
+
+    #[test]
+    fn immunizations_empty() {
+        assert!(parse_immunizations_markdown("").is_empty());
+    }
+
+    #[test]
+    fn dose_number_variants() {
+        assert_eq!(parse_dose_number("2"), Some(2));
+        assert_eq!(parse_dose_number("dose 3/3"), Some(3));
+        assert_eq!(parse_dose_number("1st"), Some(1));
+        assert_eq!(parse_dose_number("booster"), None);
+    }
+
     // ── Domain dispatcher ────────────────────────────────
 
     #[test]
@@ -771,7 +842,7 @@ mod tests {
     }
 
     #[test]
-    fn dispatcher_all_7_domains() {
+    fn dispatcher_all_8_domains() {
         for domain in DocumentDomain::all() {
             let result = parse_domain_response(*domain, "- Test item");
             let mut entities = ExtractedEntities::default();
@@ -783,7 +854,8 @@ mod tests {
                 + entities.allergies.len()
                 + entities.procedures.len()
                 + entities.referrals.len()
-                + entities.instructions.len();
+                + entities.instructions.len()
+                + entities.immunizations.len();
             assert_eq!(total, 1, "Domain {domain} should produce 1 entity");
         }
     }
@@ -13,6 +13,8 @@ pub mod extraction_strategy;
 pub mod markdown_parser;
 pub mod strategy_markdown_list;
 pub mod strategy_iterative_drill;
+pub mod provenance; // PROV-01: Field-level source locations
+pub mod cassette; // CAS-01: Record/replay LLM client for offline tests
 
 pub use types::*;
 pub use prompt::*;
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::io::BufRead;
 
 use super::StructuringError;
+use super::cassette::{Cassette, CassetteEndpoint, CassetteRequest, TapReader};
 use super::ollama_types::{
     DoneReason, GenerationOptions, InferenceMetrics, ModelCapability, ModelDetail,
     ModelInfo, ModelPreference, OllamaError, OllamaHealth, OllamaShowResponse,
@@ -119,6 +120,8 @@ pub struct OllamaClient {
     /// Defaults to "30m". CPU vision uses "0" (unload to free RAM),
     /// CPU LLM uses "10m", GPU uses "30m".
     keep_alive: String,
+    /// CAS-01: When set, every inference response body is recorded here.
+    cassette: Option<std::sync::Arc<Cassette>>,
 }
 
 // ──────────────────────────────────────────────
@@ -155,7 +158,7 @@ fn classify_send_error(e: &reqwest::Error, timeout_secs: Option<u64>) -> OllamaE
 ///
 /// OLM-C4: Ollama server returns errors as `{"error": "message"}` JSON.
 /// This helper tries to extract the message, falling back to the raw body.
-fn parse_error_body(body: &str) -> String {
+pub(super) fn parse_error_body(body: &str) -> String {
     #[derive(Deserialize)]
     struct OllamaErrorBody {
         error: String,
@@ -295,6 +298,7 @@ impl OllamaClient {
             vision_num_ctx: None,
             last_metrics: std::sync::Mutex::new(None),
             keep_alive: "30m".to_string(),
+            cassette: None,
         }
     }
 
@@ -318,6 +322,20 @@ impl OllamaClient {
         self
     }
 
+    /// CAS-01: Record every inference response into `cassette` for later
+    /// offline replay with `CassetteClient`.
+    pub fn with_cassette(mut self, cassette: std::sync::Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// CAS-01: Record a raw response body if a cassette is attached.
+    fn record_cassette(&self, request: CassetteRequest<'_>, status: u16, body: &[u8]) {
+        if let Some(cassette) = &self.cassette {
+            cassette.record_response(&request, status, body);
+        }
+    }
+
     /// Set the context window size for vision calls (hardware-tiered).
     pub fn set_vision_num_ctx(&mut self, num_ctx: u32) {
         self.vision_num_ctx = Some(num_ctx);
@@ -1519,7 +1537,7 @@ fn log_truncation_warning(model: &str, metrics: &InferenceMetrics) {
 /// Final line: `{ "response": "", "done": true, "done_reason": "stop", ... }`
 ///
 /// OLM-C1: Returns metrics from the final chunk alongside the text.
-fn collect_generate_stream(
+pub(super) fn collect_generate_stream(
     reader: impl std::io::Read,
     model: &str,
     start: &std::time::Instant,
@@ -1603,7 +1621,7 @@ fn collect_generate_stream(
 ///
 /// Returns `OllamaError::VisionDegeneration` if the guard detects a repetition pattern,
 /// otherwise returns the full response string and metrics.
-fn collect_chat_stream_guarded(
+pub(super) fn collect_chat_stream_guarded(
     reader: impl std::io::Read,
     model: &str,
     start: &std::time::Instant,
@@ -1874,6 +1892,19 @@ impl VisionClient for OllamaClient {
             e
         })?;
 
+        self.record_cassette(
+            CassetteRequest {
+                endpoint: CassetteEndpoint::GenerateWithImages,
+                model,
+                system,
+                prompt,
+                images,
+                params: VisionCallParams::default(),
+            },
+            raw.status,
+            &raw.body,
+        );
+
         if raw.status < 200 || raw.status >= 300 {
             let body = parse_error_body(&String::from_utf8_lossy(&raw.body));
             tracing::warn!(
@@ -1989,9 +2020,19 @@ impl VisionClient for OllamaClient {
             e
         })?;
 
+        let cassette_request = CassetteRequest {
+            endpoint: CassetteEndpoint::Chat,
+            model,
+            system,
+            prompt: user_prompt,
+            images,
+            params: VisionCallParams::default(),
+        };
+
         if status < 200 || status >= 300 {
             let mut body_bytes = Vec::new();
             let _ = reader.read_to_end(&mut body_bytes);
+            self.record_cassette(cassette_request, status, &body_bytes);
             let body = parse_error_body(&String::from_utf8_lossy(&body_bytes));
             tracing::warn!(
                 model = %model,
@@ -2007,9 +2048,14 @@ impl VisionClient for OllamaClient {
         }
 
         // SGV-01: StreamGuard monitors LIVE stream — real-time degeneration detection.
+        // CAS-01: The tap records exactly the bytes the guard consumed.
         let guard_config = crate::pipeline::stream_guard::StreamGuardConfig::default();
-        let (full_response, metrics) =
-            collect_chat_stream_guarded(reader, model, &start, guard_config)?;
+        let mut tap = TapReader::new(reader, self.cassette.is_some());
+        let collected = collect_chat_stream_guarded(&mut tap, model, &start, guard_config);
+        if let Some(captured) = tap.into_captured() {
+            self.record_cassette(cassette_request, status, &captured);
+        }
+        let (full_response, metrics) = collected?;
 
         self.store_metrics(metrics);
 
@@ -2098,10 +2144,20 @@ impl VisionClient for OllamaClient {
             e
         })?;
 
+        let cassette_request = CassetteRequest {
+            endpoint: CassetteEndpoint::Chat,
+            model,
+            system,
+            prompt: user_prompt,
+            images,
+            params,
+        };
+
         if status < 200 || status >= 300 {
             // For error responses, read the body to get the error message
             let mut body_bytes = Vec::new();
             let _ = reader.read_to_end(&mut body_bytes);
+            self.record_cassette(cassette_request, status, &body_bytes);
             let body = parse_error_body(&String::from_utf8_lossy(&body_bytes));
             return Err(OllamaError::ApiError {
                 status,
@@ -2111,8 +2167,12 @@ impl VisionClient for OllamaClient {
 
         // SGV-01: StreamGuard now monitors LIVE stream — can abort in real-time
         let guard_config = crate::pipeline::stream_guard::StreamGuardConfig::default();
-        let (full_response, metrics) =
-            collect_chat_stream_guarded(reader, model, &start, guard_config)?;
+        let mut tap = TapReader::new(reader, self.cassette.is_some());
+        let collected = collect_chat_stream_guarded(&mut tap, model, &start, guard_config);
+        if let Some(captured) = tap.into_captured() {
+            self.record_cassette(cassette_request, status, &captured);
+        }
+        let (full_response, metrics) = collected?;
 
         self.store_metrics(metrics);
 
@@ -2312,7 +2372,17 @@ impl LlmClient for OllamaClient {
                 }
             };
 
+            let cassette_request = CassetteRequest {
+                endpoint: CassetteEndpoint::Generate,
+                model,
+                system: Some(system),
+                prompt,
+                images: &[],
+                params: VisionCallParams::default(),
+            };
+
             if raw.status == 200 {
+                self.record_cassette(cassette_request, raw.status, &raw.body);
                 let parsed: OllamaGenerateResponse = serde_json::from_slice(&raw.body)
                     .map_err(|e| StructuringError::ResponseParsing(e.to_string()))?;
 
@@ -2359,6 +2429,7 @@ impl LlmClient for OllamaClient {
             }
 
             // Non-retryable errors (400, 404, etc.)
+            self.record_cassette(cassette_request, status_code, &raw.body);
             tracing::warn!(
                 model = %model,
                 status = status_code,
@@ -3,7 +3,7 @@
 //! Delegates extraction to a pluggable `ExtractionStrategy`, then applies
 //! shared post-processing: validate → classify → confidence → sanitize.
 //!
-//! The strategy controls the LLM call pattern (7 calls for MarkdownList,
+//! The strategy controls the LLM call pattern (8 calls for MarkdownList,
 //! 7+N×M for IterativeDrill). The orchestrator owns post-processing.
 //!
 //! Principle: SLM does ONE thing per call. CODE orchestrates.
@@ -15,7 +15,7 @@ use super::confidence::{
     apply_confidence_caps, compute_structuring_confidence, generate_confidence_warnings,
 };
 use super::extraction_strategy::ExtractionStrategy;
-use super::sanitize::{sanitize_for_llm_with_audit, sanitize_markdown_output};
+use super::sanitize::{sanitize_for_llm_with_report, sanitize_markdown_output};
 use super::types::{LlmClient, MedicalStructurer, StructuringResult};
 use super::validation::validate_extracted_entities;
 use super::StructuringError;
@@ -71,7 +71,9 @@ impl MedicalStructurer for DocumentStructurer {
         }
 
         // Step 2: Sanitize input for LLM safety (with audit logging)
-        let sanitized = sanitize_for_llm_with_audit(raw_text, Some(&document_id.to_string()));
+        let report = sanitize_for_llm_with_report(raw_text, Some(&document_id.to_string()));
+        let suspicious_content = report.is_suspicious();
+        let sanitized = report.text;
         if sanitized.trim().len() < MIN_INPUT_LENGTH {
             return Err(StructuringError::InputTooShort);
         }
@@ -145,8 +147,14 @@ impl MedicalStructurer for DocumentStructurer {
             markdown_file_path: None,
             validation_warnings,
             raw_llm_response,
+            field_sources: vec![],
+            suspicious_content,
         })
     }
+
+    fn fingerprint(&self) -> String {
+        format!("{}|{}", self.model_name, self.strategy.name())
+    }
 }
 
 #[cfg(test)]
@@ -63,6 +63,7 @@ fn parse_entities_json(
         procedures: Option<Vec<serde_json::Value>>,
         referrals: Option<Vec<serde_json::Value>>,
         instructions: Option<Vec<serde_json::Value>>,
+        immunizations: Option<Vec<serde_json::Value>>,
     }
 
     let raw: RawResponse = serde_json::from_str(json_str)
@@ -84,8 +85,9 @@ fn parse_entities_json(
     let (procedures, d5) = parse_array_lenient(raw.procedures.as_deref(), "procedure");
     let (referrals, d6) = parse_array_lenient(raw.referrals.as_deref(), "referral");
     let (instructions, d7) = parse_array_lenient(raw.instructions.as_deref(), "instruction");
+    let (immunizations, d8) = parse_array_lenient(raw.immunizations.as_deref(), "immunization");
 
-    let total_dropped = d1 + d2 + d3 + d4 + d5 + d6 + d7;
+    let total_dropped = d1 + d2 + d3 + d4 + d5 + d6 + d7 + d8;
     if total_dropped > 0 {
         tracing::warn!(
             total_dropped = total_dropped,
@@ -101,6 +103,7 @@ fn parse_entities_json(
         procedures,
         referrals,
         instructions,
+        immunizations,
         blood_type: None,
     };
 
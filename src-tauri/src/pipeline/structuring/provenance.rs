@@ -0,0 +1,469 @@
+//! PROV-01: Field-level provenance — trace extracted values back to the page.
+//!
+//! After per-page structuring and merge, every entity is searched for in the
+//! extracted page texts. The page holding the entity's anchor text (test name,
+//! medication name, ...) becomes its source page; secondary fields (value, unit,
+//! dose, ...) are searched for close to the anchor on the same page.
+//!
+//! Region resolution:
+//! - Digital PDFs carry per-segment bounding boxes from the pdfium text layer
+//!   (`PageExtraction::regions`) — the segment containing the match is used.
+//! - Vision OCR pages have no layout, so the region is estimated as the
+//!   horizontal band of the matching line and marked `region_approximate`.
+//!
+//! Deterministic and infallible: no LLM, no I/O. Unmatched values simply get
+//! no `FieldSource`.
+
+use crate::models::entity_connection::EntityType;
+use crate::models::provenance::{FieldSource, SourceLocation, SourceRegion, TextSpan};
+use crate::pipeline::extraction::types::PageExtraction;
+
+use super::types::ExtractedEntities;
+
+/// Maximum distance (in bytes of page text) between an entity's anchor and
+/// one of its secondary fields for the field to be considered part of the
+/// same row. Roughly two lines of a lab table.
+const MAX_FIELD_DISTANCE: usize = 160;
+
+/// Shortest value worth locating — single characters match everywhere.
+const MIN_LOCATABLE_LEN: usize = 2;
+
+/// Locate every extracted entity on the source pages.
+pub fn locate_field_sources(
+    entities: &ExtractedEntities,
+    pages: &[PageExtraction],
+) -> Vec<FieldSource> {
+    let mut sources = Vec::new();
+
+    for (i, med) in entities.medications.iter().enumerate() {
+        let anchor = med.generic_name.as_deref().or(med.brand_name.as_deref());
+        let anchor_field = if med.generic_name.is_some() { "generic_name" } else { "brand_name" };
+        locate_entity(
+            &mut sources,
+            pages,
+            EntityType::Medication,
+            i,
+            anchor.map(|a| (anchor_field, a.to_string())),
+            vec![
+                ("dose", vec![med.dose.clone()]),
+                ("frequency", vec![med.frequency.clone()]),
+                ("route", vec![med.route.clone()]),
+            ],
+        );
+    }
+
+    for (i, lab) in entities.lab_results.iter().enumerate() {
+        let mut value_candidates = lab.value.map(number_candidates).unwrap_or_default();
+        if let Some(ref text) = lab.value_text {
+            value_candidates.push(text.clone());
+        }
+        locate_entity(
+            &mut sources,
+            pages,
+            EntityType::LabResult,
+            i,
+            Some(("test_name", lab.test_name.clone())),
+            vec![
+                ("value", value_candidates),
+                ("unit", lab.unit.iter().cloned().collect()),
+                ("reference_range", lab.reference_range_text.iter().cloned().collect()),
+                ("collection_date", lab.collection_date.iter().cloned().collect()),
+            ],
+        );
+    }
+
+    for (i, diag) in entities.diagnoses.iter().enumerate() {
+        locate_entity(
+            &mut sources,
+            pages,
+            EntityType::Diagnosis,
+            i,
+            Some(("name", diag.name.clone())),
+            vec![("date", diag.date.iter().cloned().collect())],
+        );
+    }
+
+    for (i, allergy) in entities.allergies.iter().enumerate() {
+        locate_entity(
+            &mut sources,
+            pages,
+            EntityType::Allergy,
+            i,
+            Some(("allergen", allergy.allergen.clone())),
+            vec![
+                ("reaction", allergy.reaction.iter().cloned().collect()),
+                ("severity", allergy.severity.iter().cloned().collect()),
+            ],
+        );
+    }
+
+    for (i, proc) in entities.procedures.iter().enumerate() {
+        locate_entity(
+            &mut sources,
+            pages,
+            EntityType::Procedure,
+            i,
+            Some(("name", proc.name.clone())),
+            vec![
+                ("date", proc.date.iter().cloned().collect()),
+                ("outcome", proc.outcome.iter().cloned().collect()),
+            ],
+        );
+    }
+
+    for (i, referral) in entities.referrals.iter().enumerate() {
+        locate_entity(
+            &mut sources,
+            pages,
+            EntityType::Referral,
+            i,
+            Some(("referred_to", referral.referred_to.clone())),
+            vec![
+                ("specialty", referral.specialty.iter().cloned().collect()),
+                ("reason", referral.reason.iter().cloned().collect()),
+            ],
+        );
+    }
+
+    for (i, imm) in entities.immunizations.iter().enumerate() {
+        locate_entity(
+            &mut sources,
+            pages,
+            EntityType::Immunization,
+            i,
+            Some(("vaccine", imm.vaccine.clone())),
+            vec![
+                ("date", imm.date.iter().cloned().collect()),
+                ("lot_number", imm.lot_number.iter().cloned().collect()),
+            ],
+        );
+    }
+
+    sources
+}
+
+/// Source of a field, falling back to the entity's anchor field when the
+/// field itself was not located (the row is still the right place to look).
+pub fn find_field_source<'a>(
+    sources: &'a [FieldSource],
+    entity_type: &EntityType,
+    entity_index: usize,
+    field_name: &str,
+) -> Option<&'a FieldSource> {
+    let mut entity_sources = sources
+        .iter()
+        .filter(|s| s.entity_type == *entity_type && s.entity_index == entity_index)
+        .peekable();
+    let anchor = entity_sources.peek().copied();
+    entity_sources
+        .find(|s| s.field_name == field_name)
+        .or(anchor)
+}
+
+/// Drop sources of removed entities and shift the indices of the survivors.
+///
+/// `removed` holds the removed indices for `entity_type` (any order).
+pub fn remove_entity_sources(
+    sources: &mut Vec<FieldSource>,
+    entity_type: &EntityType,
+    removed: &[usize],
+) {
+    if removed.is_empty() {
+        return;
+    }
+    sources.retain(|s| !(s.entity_type == *entity_type && removed.contains(&s.entity_index)));
+    for source in sources.iter_mut().filter(|s| s.entity_type == *entity_type) {
+        let shift = removed.iter().filter(|&&r| r < source.entity_index).count();
+        source.entity_index -= shift;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Internals
+// ---------------------------------------------------------------------------
+
+/// Locate one entity: anchor first, then secondary fields near the anchor.
+fn locate_entity(
+    sources: &mut Vec<FieldSource>,
+    pages: &[PageExtraction],
+    entity_type: EntityType,
+    entity_index: usize,
+    anchor: Option<(&str, String)>,
+    fields: Vec<(&str, Vec<String>)>,
+) {
+    let Some((anchor_field, anchor_text)) = anchor else {
+        return;
+    };
+
+    let Some((page, start, end)) = pages.iter().find_map(|page| {
+        find_case_insensitive(&page.text, &anchor_text, 0).map(|(s, e)| (page, s, e))
+    }) else {
+        return;
+    };
+
+    sources.push(FieldSource {
+        entity_type: entity_type.clone(),
+        entity_index,
+        field_name: anchor_field.to_string(),
+        location: build_location(page, start, end),
+    });
+
+    for (field_name, candidates) in fields {
+        let window_end = floor_char_boundary(&page.text, end + MAX_FIELD_DISTANCE);
+        let found = candidates.iter().find_map(|candidate| {
+            find_case_insensitive(&page.text[..window_end], candidate, end)
+        });
+        if let Some((s, e)) = found {
+            sources.push(FieldSource {
+                entity_type: entity_type.clone(),
+                entity_index,
+                field_name: field_name.to_string(),
+                location: build_location(page, s, e),
+            });
+        }
+    }
+}
+
+/// Build a location for the byte range `start..end` of the page text.
+fn build_location(page: &PageExtraction, start: usize, end: usize) -> SourceLocation {
+    let quote = &page.text[start..end];
+    let measured = page.regions.iter().find_map(|region| {
+        let bbox = region.bounding_box?;
+        find_case_insensitive(&region.text, quote, 0).map(|_| SourceRegion::from(bbox))
+    });
+
+    let (region, region_approximate) = match measured {
+        Some(r) => (Some(r), false),
+        None => (estimate_line_band(&page.text, start), true),
+    };
+
+    SourceLocation {
+        page_number: page.page_number,
+        region,
+        region_approximate,
+        text_span: Some(TextSpan {
+            start: page.text[..start].chars().count(),
+            end: page.text[..end].chars().count(),
+        }),
+        quote: Some(quote.to_string()),
+    }
+}
+
+/// Estimate the region of the line containing byte offset `at` as a
+/// full-width horizontal band, assuming lines are spread evenly down the page.
+fn estimate_line_band(text: &str, at: usize) -> Option<SourceRegion> {
+    let total_lines = text.lines().count();
+    if total_lines == 0 {
+        return None;
+    }
+    let line = text[..at].matches('\n').count().min(total_lines - 1);
+    let height = 1.0 / total_lines as f32;
+    Some(
+        SourceRegion {
+            x: 0.0,
+            y: line as f32 * height,
+            width: 1.0,
+            height,
+        }
+        .clamped(),
+    )
+}
+
+/// Textual forms a numeric value may take on the page ("5.1", "5,1", "140").
+fn number_candidates(value: f64) -> Vec<String> {
+    let dotted = value.to_string();
+    let mut candidates = vec![dotted.clone()];
+    if dotted.contains('.') {
+        candidates.push(dotted.replace('.', ","));
+    }
+    candidates
+}
+
+/// Case-insensitive substring search starting at byte offset `from`.
+///
+/// Returns the matched byte range in `haystack`. Comparison is per char
+/// (simple lowercase folding), so offsets always fall on char boundaries.
+fn find_case_insensitive(haystack: &str, needle: &str, from: usize) -> Option<(usize, usize)> {
+    let needle = needle.trim();
+    if needle.chars().count() < MIN_LOCATABLE_LEN || from > haystack.len() {
+        return None;
+    }
+    let needle_lower: Vec<char> = needle.chars().flat_map(char::to_lowercase).collect();
+
+    for (offset, _) in haystack[from..].char_indices() {
+        let start = from + offset;
+        let mut hay = haystack[start..].char_indices();
+        let mut matched = 0;
+        let mut end = start;
+        let mut folded = Vec::new();
+        while folded.len() < needle_lower.len() {
+            let Some((i, c)) = hay.next() else { break };
+            folded.extend(c.to_lowercase());
+            end = start + i + c.len_utf8();
+            matched += 1;
+        }
+        if matched > 0 && folded == needle_lower {
+            return Some((start, end));
+        }
+    }
+    None
+}
+
+/// Largest char boundary `<= index` (clamped to the string length).
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+    let mut i = index;
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::extraction::types::{BoundingBox, RegionConfidence};
+    use crate::pipeline::structuring::types::{ExtractedLabResult, ExtractedMedication};
+
+    fn page(number: usize, text: &str) -> PageExtraction {
+        PageExtraction {
+            page_number: number,
+            text: text.into(),
+            confidence: 0.9,
+            regions: vec![],
+            warnings: vec![],
+            content_type: None,
+            drill_output: None,
+        }
+    }
+
+    fn lab(name: &str, value: f64, unit: &str) -> ExtractedLabResult {
+        ExtractedLabResult {
+            test_name: name.into(),
+            test_code: None,
+            value: Some(value),
+            value_text: None,
+            unit: Some(unit.into()),
+            reference_range_low: None,
+            reference_range_high: None,
+            reference_range_text: None,
+            abnormal_flag: None,
+            collection_date: None,
+            confidence: 0.9,
+        }
+    }
+
+    #[test]
+    fn lab_located_on_second_page() {
+        let pages = vec![
+            page(1, "Patient: Jane Doe\nDate: 2024-01-15"),
+            page(2, "Hemoglobin 13.5 g/dL\nPotassium 5,1 mmol/L\nSodium 140 mmol/L"),
+        ];
+        let entities = ExtractedEntities {
+            lab_results: vec![lab("Potassium", 5.1, "mmol/L")],
+            ..Default::default()
+        };
+
+        let sources = locate_field_sources(&entities, &pages);
+        let anchor = find_field_source(&sources, &EntityType::LabResult, 0, "test_name").unwrap();
+        assert_eq!(anchor.location.page_number, 2);
+        assert_eq!(anchor.location.quote.as_deref(), Some("Potassium"));
+
+        let value = find_field_source(&sources, &EntityType::LabResult, 0, "value").unwrap();
+        assert_eq!(value.field_name, "value");
+        assert_eq!(value.location.quote.as_deref(), Some("5,1"));
+        assert!(value.location.region_approximate);
+        // Second of three lines → band starts a third of the way down.
+        let region = value.location.region.unwrap();
+        assert!((region.y - 1.0 / 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn measured_region_used_for_text_layer_pages() {
+        let mut p = page(1, "Metformin 500 mg twice daily");
+        p.regions = vec![RegionConfidence {
+            text: "Metformin 500 mg twice daily".into(),
+            confidence: 0.95,
+            bounding_box: Some(BoundingBox { x: 0.1, y: 0.2, width: 0.6, height: 0.03 }),
+        }];
+        let entities = ExtractedEntities {
+            medications: vec![ExtractedMedication {
+                generic_name: Some("metformin".into()),
+                brand_name: None,
+                dose: "500 mg".into(),
+                frequency: "twice daily".into(),
+                frequency_type: "scheduled".into(),
+                route: String::new(),
+                reason: None,
+                instructions: vec![],
+                is_compound: false,
+                compound_ingredients: vec![],
+                tapering_steps: vec![],
+                max_daily_dose: None,
+                condition: None,
+                confidence: 0.9,
+            }],
+            ..Default::default()
+        };
+
+        let sources = locate_field_sources(&entities, &[p]);
+        let dose = find_field_source(&sources, &EntityType::Medication, 0, "dose").unwrap();
+        assert_eq!(dose.field_name, "dose");
+        assert!(!dose.location.region_approximate);
+        assert_eq!(dose.location.region.unwrap().y, 0.2);
+        assert_eq!(dose.location.text_span, Some(TextSpan { start: 10, end: 16 }));
+    }
+
+    #[test]
+    fn unmatched_entity_has_no_source() {
+        let pages = vec![page(1, "Nothing relevant here")];
+        let entities = ExtractedEntities {
+            lab_results: vec![lab("Ferritin", 30.0, "ng/mL")],
+            ..Default::default()
+        };
+        assert!(locate_field_sources(&entities, &pages).is_empty());
+    }
+
+    #[test]
+    fn unlocated_field_falls_back_to_anchor() {
+        let pages = vec![page(1, "Ferritin: see attached")];
+        let entities = ExtractedEntities {
+            lab_results: vec![lab("Ferritin", 30.0, "ng/mL")],
+            ..Default::default()
+        };
+        let sources = locate_field_sources(&entities, &pages);
+        let unit = find_field_source(&sources, &EntityType::LabResult, 0, "unit").unwrap();
+        assert_eq!(unit.field_name, "test_name");
+    }
+
+    #[test]
+    fn case_insensitive_search_handles_non_ascii() {
+        let text = "Hémoglobine 11,2 g/dL";
+        let (s, e) = find_case_insensitive(text, "HÉMOGLOBINE", 0).unwrap();
+        assert_eq!(&text[s..e], "Hémoglobine");
+        assert!(find_case_insensitive(text, "x", 0).is_none());
+    }
+
+    #[test]
+    fn removal_reindexes_surviving_sources() {
+        let pages = vec![page(1, "Sodium 140\nPotassium 4.2\nChloride 101")];
+        let entities = ExtractedEntities {
+            lab_results: vec![
+                lab("Sodium", 140.0, "mmol/L"),
+                lab("Potassium", 4.2, "mmol/L"),
+                lab("Chloride", 101.0, "mmol/L"),
+            ],
+            ..Default::default()
+        };
+        let mut sources = locate_field_sources(&entities, &pages);
+        remove_entity_sources(&mut sources, &EntityType::LabResult, &[0]);
+
+        let potassium = find_field_source(&sources, &EntityType::LabResult, 0, "test_name").unwrap();
+        assert_eq!(potassium.location.quote.as_deref(), Some("Potassium"));
+        let chloride = find_field_source(&sources, &EntityType::LabResult, 1, "test_name").unwrap();
+        assert_eq!(chloride.location.quote.as_deref(), Some("Chloride"));
+        assert!(find_field_source(&sources, &EntityType::LabResult, 2, "test_name").is_none());
+    }
+}
@@ -1,7 +1,7 @@
 //! STR-01: IterativeDrill extraction strategy.
 //!
 //! Two-phase extraction:
-//! 1. Enumerate: 7 calls (one per domain) to list item names
+//! 1. Enumerate: 8 calls (one per domain) to list item names
 //! 2. Drill: N×M calls to extract each field for each item
 //!
 //! Most thorough strategy (12/12 lab tests vs 5/12 for MarkdownList in BM-06).
@@ -20,9 +20,9 @@ use crate::pipeline::prompt_templates::{
 use crate::pipeline::safety::output_sanitize::sanitize_llm_output;
 use crate::pipeline::structuring::extraction_strategy::{ExtractionStrategy, StrategyOutput};
 use crate::pipeline::structuring::types::{
-    ExtractedAllergy, ExtractedDiagnosis, ExtractedEntities, ExtractedInstruction,
-    ExtractedLabResult, ExtractedMedication, ExtractedProcedure, ExtractedProfessional,
-    ExtractedReferral, LlmClient, VisionClient,
+    ExtractedAllergy, ExtractedDiagnosis, ExtractedEntities, ExtractedImmunization,
+    ExtractedInstruction, ExtractedLabResult, ExtractedMedication, ExtractedProcedure,
+    ExtractedProfessional, ExtractedReferral, LlmClient, VisionClient,
 };
 use crate::pipeline::structuring::StructuringError;
 
@@ -32,7 +32,7 @@ use crate::pipeline::structuring::StructuringError;
 
 /// IterativeDrill extraction strategy.
 ///
-/// Phase 1: Enumerate item names per domain (7 calls).
+/// Phase 1: Enumerate item names per domain (8 calls).
 /// Phase 2: Drill each field for each item (N × M calls per domain).
 /// Phase 3: Assemble typed entities from collected field values.
 pub struct IterativeDrillStrategy {
@@ -58,7 +58,7 @@ impl IterativeDrillStrategy {
     /// This makes extraction progression visible in real-time (`tail -f`).
     ///
     /// 09-CAE: When `user_doc_type` is Some, only relevant domains are queried
-    /// (e.g., LabReport → LabResults + Diagnoses). When None, all 7 domains
+    /// (e.g., LabReport → LabResults + Diagnoses). When None, all 8 domains
     /// run (legacy/chat fallback).
     ///
     /// 10-LDC: `lang` selects language-matched prompts via `locale_for_domain()`.
@@ -75,7 +75,7 @@ impl IterativeDrillStrategy {
     ) -> Result<StrategyOutput, SessionError> {
         use crate::pipeline::diagnostic;
 
-        // 09-CAE: Filter domains by user category, or run all 7 (legacy/chat fallback)
+        // 09-CAE: Filter domains by user category, or run all 8 (legacy/chat fallback)
         let domains: &[DocumentDomain] = match user_doc_type {
             Some(dt) => crate::pipeline::domain_contracts::domains_for_document_type(dt),
             None => DocumentDomain::all(),
@@ -377,6 +377,7 @@ impl IterativeDrillStrategy {
                     "procedures": entities.procedures.len(),
                     "referrals": entities.referrals.len(),
                     "instructions": entities.instructions.len(),
+                    "immunizations": entities.immunizations.len(),
                 }));
             }
         }
@@ -955,6 +956,9 @@ fn assemble_entity(
         DocumentDomain::Instructions => {
             entities.instructions.push(assemble_instruction(name, fields));
         }
+        DocumentDomain::Immunizations => {
+            entities.immunizations.push(assemble_immunization(name, fields));
+        }
     }
 }
 
@@ -1070,6 +1074,46 @@ fn assemble_instruction(name: &str, fields: &HashMap<String, String>) -> Extract
     }
 }
 
+/// IMM-01: Enumerated names look like "Hexyon (2024-03-04)" — one line per dose.
+/// The locale drill answers "dose | date | lot | site | administered by".
+fn assemble_immunization(name: &str, fields: &HashMap<String, String>) -> ExtractedImmunization {
+    let (vaccine, enumerated_date) = match (name.rfind(" ("), name.ends_with(')')) {
+        (Some(pos), true) => (
+            name[..pos].trim().to_string(),
+            Some(name[pos + 2..name.len() - 1].trim().to_string()),
+        ),
+        _ => (name.to_string(), None),
+    };
+
+    let parts: Vec<Option<String>> = fields
+        .get("value")
+        .map(|v| {
+            v.split('|')
+                .map(|p| {
+                    let p = p.trim();
+                    (!p.is_empty() && !is_not_specified(p)).then(|| p.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let part = |i: usize| parts.get(i).cloned().flatten();
+
+    ExtractedImmunization {
+        vaccine,
+        dose_number: fields
+            .get("dose_number")
+            .cloned()
+            .or_else(|| part(0))
+            .as_deref()
+            .and_then(crate::pipeline::structuring::markdown_parser::parse_dose_number),
+        date: fields.get("date").cloned().or_else(|| part(1)).or(enumerated_date),
+        lot_number: fields.get("lot_number").cloned().or_else(|| part(2)),
+        site: fields.get("site").cloned().or_else(|| part(3)),
+        administered_by: fields.get("administered_by").cloned().or_else(|| part(4)),
+        confidence: 0.0,
+    }
+}
+
 // ═══════════════════════════════════════════════════════════
 // Helpers
 // ═══════════════════════════════════════════════════════════
@@ -1119,6 +1163,7 @@ fn domain_header(domain: DocumentDomain) -> &'static str {
         DocumentDomain::Procedures => "Procedures",
         DocumentDomain::Referrals => "Referrals",
         DocumentDomain::Instructions => "Instructions",
+        DocumentDomain::Immunizations => "Immunizations",
     }
 }
 
@@ -1326,6 +1371,28 @@ Common Drug Names: Do I recognize any common medication names?";
         assert_eq!(lab.value_text.as_deref(), Some("negative"));
     }
 
+    #[test]
+    fn assemble_immunization_from_combined_drill() {
+        let mut fields = HashMap::new();
+        fields.insert("value".into(), "2 | 2024-05-06 | A21CB123 | left thigh | NONE".into());
+
+        let imm = assemble_immunization("Hexyon (06.05.2024)", &fields);
+        assert_eq!(imm.vaccine, "Hexyon");
+        assert_eq!(imm.dose_number, Some(2));
+        assert_eq!(imm.date.as_deref(), Some("2024-05-06"));
+        assert_eq!(imm.lot_number.as_deref(), Some("A21CB123"));
+        assert_eq!(imm.site.as_deref(), Some("left thigh"));
+        assert!(imm.administered_by.is_none());
+    }
+
+    #[test]
+    fn assemble_immunization_falls_back_to_enumerated_date() {
+        let imm = assemble_immunization("MMR (2025-02-10)", &HashMap::new());
+        assert_eq!(imm.vaccine, "MMR");
+        assert_eq!(imm.date.as_deref(), Some("2025-02-10"));
+        assert!(imm.dose_number.is_none());
+    }
+
     // ── Full strategy tests ──────────────────────────────
 
     /// Mock LLM that responds appropriately to enumerate vs drill prompts.
@@ -1743,8 +1810,8 @@ Common Drug Names: Do I recognize any common medication names?";
     }
 
     #[test]
-    fn vision_drill_all_7_domains_checked() {
-        // Use a counting mock to verify all 7 domains get an enumerate call
+    fn vision_drill_all_8_domains_checked() {
+        // Use a counting mock to verify all 8 domains get an enumerate call
         use std::sync::atomic::{AtomicUsize, Ordering};
 
         struct CountingVision {
@@ -1781,7 +1848,7 @@ Common Drug Names: Do I recognize any common medication names?";
         };
         let _ = run_vision_drill(&mock).unwrap();
 
-        assert_eq!(mock.enumerate_count.load(Ordering::Relaxed), 7);
+        assert_eq!(mock.enumerate_count.load(Ordering::Relaxed), 8);
     }
 
     // ── 09-CAE: Domain filtering integration tests ──────
@@ -1865,8 +1932,8 @@ Common Drug Names: Do I recognize any common medication names?";
         let mock = CountingVision { enumerate_count: AtomicUsize::new(0) };
         let _ = run_vision_drill_with_doc_type(&mock, None).unwrap();
 
-        // None (legacy fallback) → all 7 domains
-        assert_eq!(mock.enumerate_count.load(Ordering::Relaxed), 7);
+        // None (legacy fallback) → all 8 domains
+        assert_eq!(mock.enumerate_count.load(Ordering::Relaxed), 8);
     }
 
     #[test]
@@ -1,6 +1,6 @@
 //! STR-01: MarkdownList extraction strategy.
 //!
-//! Makes 7 LLM calls (one per domain), each with a ~25-token prompt.
+//! Makes 8 LLM calls (one per domain), each with a ~25-token prompt.
 //! The SLM extracts one domain at a time as a markdown list.
 //! The CODE parses each response and builds the structured entities.
 //!
@@ -22,7 +22,7 @@ use crate::pipeline::structuring::StructuringError;
 
 /// MarkdownList extraction strategy.
 ///
-/// Iterates over all 7 document domains, makes one LLM call per domain,
+/// Iterates over all 8 document domains, makes one LLM call per domain,
 /// parses the markdown response, and merges into `ExtractedEntities`.
 pub struct MarkdownListStrategy {
     max_retries: u32,
@@ -139,6 +139,7 @@ fn domain_header(domain: DocumentDomain) -> &'static str {
         DocumentDomain::Procedures => "Procedures",
         DocumentDomain::Referrals => "Referrals",
         DocumentDomain::Instructions => "Instructions",
+        DocumentDomain::Immunizations => "Immunizations",
     }
 }
 
@@ -221,8 +222,8 @@ mod tests {
         assert_eq!(output.entities.referrals.len(), 1);
         assert_eq!(output.entities.instructions.len(), 1);
 
-        // 7 LLM calls (one per domain)
-        assert_eq!(llm.call_count.load(Ordering::Relaxed), 7);
+        // 8 LLM calls (one per domain)
+        assert_eq!(llm.call_count.load(Ordering::Relaxed), 8);
     }
 
     #[test]
@@ -248,7 +249,7 @@ mod tests {
             .extract(&llm, "medgemma:latest", "Test doc", 0.90)
             .unwrap();
 
-        assert_eq!(output.raw_responses.len(), 7);
+        assert_eq!(output.raw_responses.len(), 8);
     }
 
     #[test]
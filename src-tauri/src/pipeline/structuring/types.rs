@@ -22,6 +22,12 @@ pub struct StructuringResult {
     /// P.8: Raw LLM response archived for debugging/audit.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub raw_llm_response: Option<String>,
+    /// PROV-01: Page/region of each extracted field, addressed by entity index.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub field_sources: Vec<crate::models::provenance::FieldSource>,
+    /// INJ-01: Source text contained prompt-injection patterns.
+    #[serde(default)]
+    pub suspicious_content: bool,
 }
 
 /// All entities extracted from a single document
@@ -34,6 +40,9 @@ pub struct ExtractedEntities {
     pub procedures: Vec<ExtractedProcedure>,
     pub referrals: Vec<ExtractedReferral>,
     pub instructions: Vec<ExtractedInstruction>,
+    /// IMM-01: Vaccine doses (vaccination booklets, immunization certificates).
+    #[serde(default)]
+    pub immunizations: Vec<ExtractedImmunization>,
     /// BT-01: Blood type extracted from document (scalar, one per patient).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub blood_type: Option<String>,
@@ -136,6 +145,19 @@ pub struct ExtractedReferral {
     pub confidence: f32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedImmunization {
+    pub vaccine: String,
+    pub dose_number: Option<u32>,
+    pub date: Option<String>,
+    pub lot_number: Option<String>,
+    pub site: Option<String>,
+    /// Administering professional as written on the record.
+    pub administered_by: Option<String>,
+    #[serde(default)]
+    pub confidence: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractedInstruction {
     pub text: String,
@@ -159,6 +181,12 @@ pub trait MedicalStructurer {
         ocr_confidence: f32,
         session: &ProfileSession,
     ) -> Result<StructuringResult, StructuringError>;
+
+    /// CKP-01: Identifies what produces the results (model and strategy), so
+    /// checkpoints from another configuration are not reused.
+    fn fingerprint(&self) -> String {
+        String::new()
+    }
 }
 
 /// Ollama LLM client abstraction (allows mocking)
@@ -89,6 +89,7 @@ fn total_entity_count(entities: &ExtractedEntities) -> usize {
         + entities.procedures.len()
         + entities.referrals.len()
         + entities.instructions.len()
+        + entities.immunizations.len()
 }
 
 /// Validate medication entities: remove nameless, detect injection in names, flag bad doses.
@@ -378,6 +379,7 @@ fn check_confidence_uniformity(entities: &ExtractedEntities, warnings: &mut Vec<
         .chain(entities.allergies.iter().map(|a| a.confidence))
         .chain(entities.procedures.iter().map(|p| p.confidence))
         .chain(entities.referrals.iter().map(|r| r.confidence))
+        .chain(entities.immunizations.iter().map(|i| i.confidence))
         .collect();
 
     if confidences.len() >= 3 && confidences.iter().all(|&c| c >= 0.99) {
@@ -443,6 +445,7 @@ mod tests {
             procedures: vec![],
             referrals: vec![],
             instructions: vec![],
+            immunizations: vec![],
             blood_type: None,
         }
     }
@@ -10,8 +10,12 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::db::DatabaseError;
+use crate::models::entity_connection::EntityType;
+use crate::models::provenance::SourceLocation;
+use crate::pipeline::structuring::provenance::{find_field_source, remove_entity_sources};
 use crate::pipeline::structuring::classify::parse_document_date;
 use crate::pipeline::structuring::types::{ExtractedProfessional, StructuringResult};
+use crate::sensitivity::SensitivitySuggestion;
 
 // ---------------------------------------------------------------------------
 // Types
@@ -31,6 +35,15 @@ pub struct ReviewData {
     pub extracted_fields: Vec<ExtractedField>,
     pub plausibility_warnings: Vec<PlausibilityWarning>,
     pub overall_confidence: f32,
+    /// INJ-01: Source text contained prompt-injection patterns.
+    #[serde(default)]
+    pub suspicious_content: bool,
+    /// SENS-01: Document is already flagged sensitive (re-review).
+    #[serde(default)]
+    pub sensitive: bool,
+    /// SENS-01: Keyword-based suggestion to flag the document as sensitive.
+    #[serde(default)]
+    pub sensitivity_suggestion: Option<SensitivitySuggestion>,
 }
 
 /// The type of the original file for rendering.
@@ -52,6 +65,9 @@ pub struct ExtractedField {
     pub confidence: f32,
     pub is_flagged: bool,
     pub source_hint: Option<String>,
+    /// PROV-01: Where the value was read (page + region), when located.
+    #[serde(default)]
+    pub source_location: Option<SourceLocation>,
 }
 
 /// Category of entity for color-coding.
@@ -63,10 +79,27 @@ pub enum EntityCategory {
     Allergy,
     Procedure,
     Referral,
+    Immunization,
     Professional,
     Date,
 }
 
+impl EntityCategory {
+    /// Stored entity type for this category (`None` for document-level singletons).
+    pub fn entity_type(&self) -> Option<EntityType> {
+        match self {
+            Self::Medication => Some(EntityType::Medication),
+            Self::LabResult => Some(EntityType::LabResult),
+            Self::Diagnosis => Some(EntityType::Diagnosis),
+            Self::Allergy => Some(EntityType::Allergy),
+            Self::Procedure => Some(EntityType::Procedure),
+            Self::Referral => Some(EntityType::Referral),
+            Self::Immunization => Some(EntityType::Immunization),
+            Self::Professional | Self::Date => None,
+        }
+    }
+}
+
 /// A plausibility warning from the coherence engine.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlausibilityWarning {
@@ -134,6 +167,7 @@ pub struct EntitiesStoredSummary {
     pub procedures: usize,
     pub referrals: usize,
     pub instructions: usize,
+    pub immunizations: usize,
 }
 
 /// Result of rejecting a review.
@@ -216,6 +250,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: med.confidence,
                 is_flagged: med.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         fields.push(ExtractedField {
@@ -228,6 +263,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: med.confidence,
             is_flagged: med.confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         fields.push(ExtractedField {
             id: deterministic_field_id(&EntityCategory::Medication, i, "frequency"),
@@ -239,6 +275,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: med.confidence,
             is_flagged: med.confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         // 12-ERC B5: route (when non-empty)
         if !med.route.is_empty() {
@@ -252,6 +289,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: med.confidence,
                 is_flagged: med.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
     }
@@ -268,6 +306,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: lab.confidence,
             is_flagged: lab.confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         if let Some(val) = lab.value {
             fields.push(ExtractedField {
@@ -280,6 +319,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: lab.confidence,
                 is_flagged: lab.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         if let Some(ref unit) = lab.unit {
@@ -293,6 +333,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: lab.confidence,
                 is_flagged: lab.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         // 12-ERC B5: reference_range (combined display)
@@ -313,6 +354,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: lab.confidence,
                 is_flagged: lab.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         } else if let Some(ref text) = lab.reference_range_text {
             fields.push(ExtractedField {
@@ -325,6 +367,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: lab.confidence,
                 is_flagged: lab.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         // 12-ERC B5: abnormal_flag (humanized)
@@ -339,6 +382,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: lab.confidence,
                 is_flagged: lab.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         // 12-ERC B5: collection_date
@@ -353,6 +397,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: lab.confidence,
                 is_flagged: lab.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
     }
@@ -369,6 +414,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: diag.confidence,
             is_flagged: diag.confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         // 12-ERC B5: date
         if let Some(ref date) = diag.date {
@@ -382,6 +428,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: diag.confidence,
                 is_flagged: diag.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         // 12-ERC B5: status
@@ -396,6 +443,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: diag.confidence,
                 is_flagged: diag.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
     }
@@ -412,6 +460,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: allergy.confidence,
             is_flagged: allergy.confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         // 12-ERC B5: reaction
         if let Some(ref reaction) = allergy.reaction {
@@ -425,6 +474,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: allergy.confidence,
                 is_flagged: allergy.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         // 12-ERC B5: severity
@@ -439,6 +489,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: allergy.confidence,
                 is_flagged: allergy.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
     }
@@ -455,6 +506,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: proc.confidence,
             is_flagged: proc.confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         // 12-ERC B5: date
         if let Some(ref date) = proc.date {
@@ -468,6 +520,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: proc.confidence,
                 is_flagged: proc.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         // 12-ERC B5: outcome
@@ -482,6 +535,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: proc.confidence,
                 is_flagged: proc.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
     }
@@ -498,6 +552,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: referral.confidence,
             is_flagged: referral.confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         // 12-ERC B5: specialty
         if let Some(ref specialty) = referral.specialty {
@@ -511,6 +566,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: referral.confidence,
                 is_flagged: referral.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
         // 12-ERC B5: reason
@@ -525,10 +581,49 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: referral.confidence,
                 is_flagged: referral.confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
     }
 
+    // IMM-01 Immunizations: vaccine + dose + date + lot + site
+    for (i, imm) in structuring.extracted_entities.immunizations.iter().enumerate() {
+        fields.push(ExtractedField {
+            id: deterministic_field_id(&EntityCategory::Immunization, i, "vaccine"),
+            entity_type: EntityCategory::Immunization,
+            entity_index: i,
+            field_name: "vaccine".into(),
+            display_label: "Vaccine".into(),
+            value: imm.vaccine.clone(),
+            confidence: imm.confidence,
+            is_flagged: imm.confidence < CONFIDENCE_THRESHOLD,
+            source_hint: None,
+            source_location: None,
+        });
+        let optional = [
+            ("dose_number", "Dose", imm.dose_number.map(|n| n.to_string())),
+            ("date", "Date", imm.date.clone()),
+            ("lot_number", "Lot", imm.lot_number.clone()),
+            ("site", "Site", imm.site.clone()),
+        ];
+        for (field_name, label, value) in optional {
+            if let Some(value) = value {
+                fields.push(ExtractedField {
+                    id: deterministic_field_id(&EntityCategory::Immunization, i, field_name),
+                    entity_type: EntityCategory::Immunization,
+                    entity_index: i,
+                    field_name: field_name.into(),
+                    display_label: label.into(),
+                    value,
+                    confidence: imm.confidence,
+                    is_flagged: imm.confidence < CONFIDENCE_THRESHOLD,
+                    source_hint: None,
+                    source_location: None,
+                });
+            }
+        }
+    }
+
     // Professional: name + specialty
     if let Some(ref prof) = structuring.professional {
         fields.push(ExtractedField {
@@ -541,6 +636,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: structuring.structuring_confidence,
             is_flagged: structuring.structuring_confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
         // 12-ERC B5: specialty
         if let Some(ref specialty) = prof.specialty {
@@ -554,6 +650,7 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
                 confidence: structuring.structuring_confidence,
                 is_flagged: structuring.structuring_confidence < CONFIDENCE_THRESHOLD,
                 source_hint: None,
+                source_location: None,
             });
         }
     }
@@ -570,9 +667,26 @@ pub fn flatten_entities_to_fields(structuring: &StructuringResult) -> Vec<Extrac
             confidence: structuring.structuring_confidence,
             is_flagged: structuring.structuring_confidence < CONFIDENCE_THRESHOLD,
             source_hint: None,
+            source_location: None,
         });
     }
 
+    // PROV-01: Attach source page/region to every located field
+    for field in &mut fields {
+        let Some(entity_type) = field.entity_type.entity_type() else {
+            continue;
+        };
+        if let Some(source) = find_field_source(
+            &structuring.field_sources,
+            &entity_type,
+            field.entity_index,
+            &field.field_name,
+        ) {
+            field.source_hint = Some(format!("Page {}", source.location.page_number));
+            field.source_location = Some(source.location.clone());
+        }
+    }
+
     fields
 }
 
@@ -1066,6 +1180,33 @@ pub fn apply_corrections(
                     r.reason = Some(correction.corrected_value.clone()); true
                 } else { false }
             }
+            // === Immunizations ===
+            (EntityCategory::Immunization, "vaccine") => {
+                if let Some(v) = structuring.extracted_entities.immunizations.get_mut(field.entity_index) {
+                    v.vaccine = correction.corrected_value.clone(); true
+                } else { false }
+            }
+            (EntityCategory::Immunization, "dose_number") => {
+                if let Some(v) = structuring.extracted_entities.immunizations.get_mut(field.entity_index) {
+                    v.dose_number = correction.corrected_value.trim().parse().ok();
+                    v.dose_number.is_some()
+                } else { false }
+            }
+            (EntityCategory::Immunization, "date") => {
+                if let Some(v) = structuring.extracted_entities.immunizations.get_mut(field.entity_index) {
+                    v.date = Some(correction.corrected_value.clone()); true
+                } else { false }
+            }
+            (EntityCategory::Immunization, "lot_number") => {
+                if let Some(v) = structuring.extracted_entities.immunizations.get_mut(field.entity_index) {
+                    v.lot_number = Some(correction.corrected_value.clone()); true
+                } else { false }
+            }
+            (EntityCategory::Immunization, "site") => {
+                if let Some(v) = structuring.extracted_entities.immunizations.get_mut(field.entity_index) {
+                    v.site = Some(correction.corrected_value.clone()); true
+                } else { false }
+            }
             // === Professional (on StructuringResult) ===
             (EntityCategory::Professional, "name") => {
                 if let Some(ref mut prof) = structuring.professional {
@@ -1130,15 +1271,32 @@ pub fn remove_excluded_entities(
     let mut ref_indices: Vec<usize> = excluded.iter()
         .filter(|e| e.entity_type == EntityCategory::Referral)
         .map(|e| e.entity_index).collect();
+    let mut imm_indices: Vec<usize> = excluded.iter()
+        .filter(|e| e.entity_type == EntityCategory::Immunization)
+        .map(|e| e.entity_index).collect();
 
     for indices in [
         &mut med_indices, &mut lab_indices, &mut diag_indices,
         &mut allergy_indices, &mut proc_indices, &mut ref_indices,
+        &mut imm_indices,
     ] {
         indices.sort_unstable_by(|a, b| b.cmp(a));
         indices.dedup();
     }
 
+    // PROV-01: Keep field sources aligned with the surviving entity indices
+    for (entity_type, indices) in [
+        (EntityType::Medication, &med_indices),
+        (EntityType::LabResult, &lab_indices),
+        (EntityType::Diagnosis, &diag_indices),
+        (EntityType::Allergy, &allergy_indices),
+        (EntityType::Procedure, &proc_indices),
+        (EntityType::Referral, &ref_indices),
+        (EntityType::Immunization, &imm_indices),
+    ] {
+        remove_entity_sources(&mut structuring.field_sources, &entity_type, indices);
+    }
+
     for &i in &med_indices {
         if i < structuring.extracted_entities.medications.len() {
             structuring.extracted_entities.medications.remove(i);
@@ -1175,6 +1333,12 @@ pub fn remove_excluded_entities(
             removed += 1;
         }
     }
+    for &i in &imm_indices {
+        if i < structuring.extracted_entities.immunizations.len() {
+            structuring.extracted_entities.immunizations.remove(i);
+            removed += 1;
+        }
+    }
 
     // Professional and Date are singletons — remove if excluded
     if excluded.iter().any(|e| e.entity_type == EntityCategory::Professional) {
@@ -1246,6 +1410,7 @@ mod tests {
         update_profile_trust_corrected, get_profile_trust,
     };
     use crate::models::document::Document;
+    use crate::models::provenance::FieldSource;
     use crate::models::enums::{DocumentType, PipelineStatus};
     use crate::pipeline::structuring::types::*;
     use chrono::{NaiveDate, NaiveDateTime};
@@ -1333,12 +1498,15 @@ mod tests {
                     text: "Return in 3 months".into(),
                     category: "follow_up".into(),
                 }],
+                immunizations: vec![],
                 blood_type: None,
             },
             structuring_confidence: 0.85,
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         }
     }
 
@@ -1647,6 +1815,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         assert_eq!(count_extracted_fields(&result), 0);
     }
@@ -2178,6 +2348,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         let fields = flatten_entities_to_fields(&result);
         // test_name + value + unit + reference_range + abnormal_flag + collection_date = 6
@@ -2218,6 +2390,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         let fields = flatten_entities_to_fields(&result);
         // generic_name + dose + frequency + route = 4
@@ -2247,6 +2421,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         let fields = flatten_entities_to_fields(&result);
         // name + date + status = 3
@@ -2276,6 +2452,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         let fields = flatten_entities_to_fields(&result);
         // allergen + reaction + severity = 3
@@ -2307,6 +2485,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         let fields = flatten_entities_to_fields(&result);
         // name + date + outcome = 3
@@ -2336,6 +2516,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         let fields = flatten_entities_to_fields(&result);
         // referred_to + specialty + reason = 3
@@ -2361,6 +2543,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         let fields = flatten_entities_to_fields(&result);
         // name + specialty = 2
@@ -2510,6 +2694,8 @@ mod tests {
             markdown_file_path: None,
             validation_warnings: vec![],
             raw_llm_response: None,
+            field_sources: vec![],
+            suspicious_content: false,
         };
         assert_eq!(count_extracted_fields(&empty), 0);
 
@@ -2546,6 +2732,64 @@ mod tests {
         );
     }
 
+    fn page_source(entity_type: EntityType, entity_index: usize, field: &str, page: usize) -> FieldSource {
+        FieldSource {
+            entity_type,
+            entity_index,
+            field_name: field.into(),
+            location: SourceLocation {
+                page_number: page,
+                region: None,
+                region_approximate: true,
+                text_span: None,
+                quote: None,
+            },
+        }
+    }
+
+    #[test]
+    fn flatten_attaches_field_sources() {
+        let mut result = make_structuring_result();
+        result.field_sources = vec![
+            page_source(EntityType::Medication, 1, "generic_name", 2),
+            page_source(EntityType::Medication, 1, "dose", 3),
+        ];
+
+        let fields = flatten_entities_to_fields(&result);
+        let find = |idx: usize, name: &str| {
+            fields.iter().find(|f| {
+                f.entity_type == EntityCategory::Medication && f.entity_index == idx && f.field_name == name
+            }).unwrap()
+        };
+
+        assert_eq!(find(1, "dose").source_location.as_ref().unwrap().page_number, 3);
+        assert_eq!(find(1, "dose").source_hint.as_deref(), Some("Page 3"));
+        // Unlocated field falls back to the entity's anchor
+        assert_eq!(find(1, "frequency").source_location.as_ref().unwrap().page_number, 2);
+        assert!(find(0, "dose").source_location.is_none());
+    }
+
+    #[test]
+    fn remove_excluded_entities_reindexes_field_sources() {
+        let mut result = make_structuring_result();
+        result.field_sources = vec![
+            page_source(EntityType::Medication, 0, "generic_name", 1),
+            page_source(EntityType::Medication, 1, "generic_name", 2),
+            page_source(EntityType::LabResult, 0, "test_name", 1),
+        ];
+
+        let excluded = vec![ExcludedEntity {
+            entity_type: EntityCategory::Medication,
+            entity_index: 0,
+        }];
+        remove_excluded_entities(&mut result, &excluded);
+
+        assert_eq!(result.field_sources.len(), 2);
+        assert_eq!(result.field_sources[0].entity_index, 0);
+        assert_eq!(result.field_sources[0].location.page_number, 2);
+        assert_eq!(result.field_sources[1].entity_type, EntityType::LabResult);
+    }
+
     #[test]
     fn remove_excluded_entities_removes_lab_result() {
         let mut result = make_structuring_result();
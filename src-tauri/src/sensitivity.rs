@@ -0,0 +1,475 @@
+//! SENS-01: Sensitive-record tagging.
+//!
+//! Psychiatric, sexual-health, genetic and substance-use records can be
+//! flagged at document or entity level. Flagged records are hidden by default
+//! from the home feed, chat retrieval, the timeline and appointment exports,
+//! each of which takes an explicit `include_sensitive` override. Phone sync
+//! caches and the companion API never carry them.
+//!
+//! Key properties:
+//! - A flagged document hides every entity extracted from it
+//! - A flagged entity hides only itself
+//! - Flags are suggested at review time from diagnosis names, the document
+//!   title/type and the professional's specialty — the patient decides
+
+use std::collections::HashSet;
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::db::DatabaseError;
+use crate::pipeline::rag::lookup::pad_words;
+use crate::pipeline::structuring::types::StructuringResult;
+
+// ═══════════════════════════════════════════════════════════
+// Types
+// ═══════════════════════════════════════════════════════════
+
+/// Why a record looks sensitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SensitivityCategory {
+    MentalHealth,
+    SexualHealth,
+    Genetic,
+    SubstanceUse,
+}
+
+/// Review-time suggestion to flag a document as sensitive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SensitivitySuggestion {
+    pub category: SensitivityCategory,
+    /// The text that triggered the suggestion (e.g. a diagnosis name).
+    pub matched: String,
+}
+
+/// Entity tables carrying a `sensitive` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SensitiveEntity {
+    Medication,
+    LabResult,
+    Diagnosis,
+    Allergy,
+    Procedure,
+}
+
+impl SensitiveEntity {
+    pub const ALL: [SensitiveEntity; 5] = [
+        Self::Medication,
+        Self::LabResult,
+        Self::Diagnosis,
+        Self::Allergy,
+        Self::Procedure,
+    ];
+
+    /// SQL table holding this entity.
+    pub fn table(self) -> &'static str {
+        match self {
+            Self::Medication => "medications",
+            Self::LabResult => "lab_results",
+            Self::Diagnosis => "diagnoses",
+            Self::Allergy => "allergies",
+            Self::Procedure => "procedures",
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// Keyword suggestion
+// ═══════════════════════════════════════════════════════════
+
+/// Word-start patterns per category (EN/FR/DE). A trailing space requires a
+/// whole word, for short abbreviations like "hiv" that would otherwise match
+/// inside unrelated words.
+const KEYWORDS: &[(SensitivityCategory, &[&str])] = &[
+    (
+        SensitivityCategory::MentalHealth,
+        &[
+            // English
+            "depress", "bipolar", "schizophren", "schizoaffective", "psychosis", "psychotic",
+            "ptsd ", "post traumatic", "posttraumatic", "obsessive", "ocd ", "anxiety disorder",
+            "generalized anxiety", "panic disorder", "eating disorder", "anorexia nervosa",
+            "bulimi", "suicid", "self harm", "borderline personality",
+            "psychiatr", "psycholog", "psychotherap",
+            // French
+            "dépress", "bipolaire", "schizophrén", "psychose", "trouble anxieux",
+            "trouble panique", "anorexie mentale", "boulimi", "suicidaire", "automutilation",
+            "psychothérap",
+            // German
+            "depressiv", "schizophrenie", "psychose", "angststörung", "panikstörung",
+            "essstörung", "magersucht", "suizid", "selbstverletz", "posttraumatisch",
+        ],
+    ),
+    (
+        SensitivityCategory::SexualHealth,
+        &[
+            // English
+            "hiv ", "aids ", "syphilis", "gonorrh", "chlamydia", "genital herpes",
+            "genital wart", "sexually transmitted", "std ", "sti ", "erectile dysfunction",
+            "abortion", "termination of pregnancy", "venereolog", "sexolog",
+            // French
+            "vih ", "sida ", "sexuellement transmissible", "herpès génital",
+            "interruption volontaire de grossesse", "ivg ", "dysfonction érectile",
+            "vénéréolog",
+            // German
+            "geschlechtskrank", "sexuell übertragbar", "genitalherpes",
+            "schwangerschaftsabbruch", "erektile dysfunktion",
+        ],
+    ),
+    (
+        SensitivityCategory::Genetic,
+        &[
+            // English
+            "genetic", "genome", "genomic", "brca", "karyotyp", "hereditary", "carrier screening",
+            "dna test", "exome",
+            // French
+            "génétique", "génome", "caryotyp", "héréditaire",
+            // German
+            "genetisch", "gentest", "genom ", "erblich", "humangenetik",
+        ],
+    ),
+    (
+        SensitivityCategory::SubstanceUse,
+        &[
+            // English
+            "alcohol use disorder", "alcoholism", "alcohol dependence", "substance use",
+            "opioid use disorder", "opioid dependence", "addiction", "methadone",
+            "buprenorphine",
+            // French
+            "alcoolisme", "dépendance à l'alcool", "toxicomanie", "addictolog", "méthadone",
+            // German
+            "alkoholabhängig", "alkoholkrank", "drogenabhängig", "suchterkrank", "methadon",
+        ],
+    ),
+];
+
+/// Category of the first keyword found in `text`, if any.
+pub fn classify_text(text: &str) -> Option<SensitivityCategory> {
+    let padded = pad_words(text);
+    KEYWORDS.iter().find_map(|(category, patterns)| {
+        patterns
+            .iter()
+            .any(|p| padded.contains(&format!(" {p}")))
+            .then_some(*category)
+    })
+}
+
+/// Suggests a sensitivity flag for a document under review.
+///
+/// Diagnoses are checked first (the strongest signal), then the document
+/// type, title and the professional's specialty.
+pub fn suggest_for_document(
+    structuring: &StructuringResult,
+    title: Option<&str>,
+) -> Option<SensitivitySuggestion> {
+    let diagnoses = structuring
+        .extracted_entities
+        .diagnoses
+        .iter()
+        .map(|d| d.name.as_str());
+    let document_type = std::iter::once(structuring.document_type.as_str());
+    let specialty = structuring
+        .professional
+        .as_ref()
+        .and_then(|p| p.specialty.as_deref());
+
+    diagnoses
+        .chain(document_type)
+        .chain(title)
+        .chain(specialty)
+        .find_map(|text| {
+            classify_text(text).map(|category| SensitivitySuggestion {
+                category,
+                matched: text.to_string(),
+            })
+        })
+}
+
+// ═══════════════════════════════════════════════════════════
+// Flags
+// ═══════════════════════════════════════════════════════════
+
+/// Sets or clears the sensitivity flag on a document.
+pub fn set_document_sensitive(
+    conn: &Connection,
+    document_id: &Uuid,
+    sensitive: bool,
+) -> Result<(), DatabaseError> {
+    let rows = conn.execute(
+        "UPDATE documents SET sensitive = ?2 WHERE id = ?1",
+        params![document_id.to_string(), sensitive as i32],
+    )?;
+    if rows == 0 {
+        return Err(DatabaseError::NotFound {
+            entity_type: "Document".into(),
+            id: document_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Sets or clears the sensitivity flag on a single entity.
+pub fn set_entity_sensitive(
+    conn: &Connection,
+    entity: SensitiveEntity,
+    entity_id: &Uuid,
+    sensitive: bool,
+) -> Result<(), DatabaseError> {
+    let sql = format!("UPDATE {} SET sensitive = ?2 WHERE id = ?1", entity.table());
+    let rows = conn.execute(&sql, params![entity_id.to_string(), sensitive as i32])?;
+    if rows == 0 {
+        return Err(DatabaseError::NotFound {
+            entity_type: entity.table().into(),
+            id: entity_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Whether a document is flagged as sensitive.
+pub fn is_document_sensitive(conn: &Connection, document_id: &Uuid) -> Result<bool, DatabaseError> {
+    let flagged: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM documents WHERE id = ?1 AND sensitive = 1)",
+        params![document_id.to_string()],
+        |row| row.get(0),
+    )?;
+    Ok(flagged)
+}
+
+/// Enables or disables sensitive records in a conversation's chat context.
+pub fn set_conversation_include_sensitive(
+    conn: &Connection,
+    conversation_id: &Uuid,
+    include_sensitive: bool,
+) -> Result<(), DatabaseError> {
+    let rows = conn.execute(
+        "UPDATE conversations SET include_sensitive = ?2 WHERE id = ?1",
+        params![conversation_id.to_string(), include_sensitive as i32],
+    )?;
+    if rows == 0 {
+        return Err(DatabaseError::NotFound {
+            entity_type: "Conversation".into(),
+            id: conversation_id.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Whether the patient enabled sensitive records for a conversation.
+pub fn conversation_includes_sensitive(
+    conn: &Connection,
+    conversation_id: &Uuid,
+) -> Result<bool, DatabaseError> {
+    let enabled: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM conversations WHERE id = ?1 AND include_sensitive = 1)",
+        params![conversation_id.to_string()],
+        |row| row.get(0),
+    )?;
+    Ok(enabled)
+}
+
+// ═══════════════════════════════════════════════════════════
+// Filtering
+// ═══════════════════════════════════════════════════════════
+
+/// SQL condition (prefixed with ` AND `) hiding sensitive rows of an entity
+/// table aliased as `alias`. Empty when `include_sensitive` is set.
+pub fn entity_filter_sql(alias: &str, include_sensitive: bool) -> String {
+    if include_sensitive {
+        return String::new();
+    }
+    format!(
+        " AND {alias}.sensitive = 0 AND NOT EXISTS (
+             SELECT 1 FROM documents sd WHERE sd.id = {alias}.document_id AND sd.sensitive = 1)"
+    )
+}
+
+/// SQL condition (prefixed with ` AND `) hiding sensitive documents aliased
+/// as `alias`. Empty when `include_sensitive` is set.
+pub fn document_filter_sql(alias: &str, include_sensitive: bool) -> String {
+    if include_sensitive {
+        String::new()
+    } else {
+        format!(" AND {alias}.sensitive = 0")
+    }
+}
+
+/// IDs of every hidden document and entity, for filtering results that were
+/// not assembled by SQL in this module's format (RAG chunks, timeline events).
+///
+/// Record IDs are UUIDs, so one set covers documents and all entity tables.
+#[derive(Debug, Clone, Default)]
+pub struct HiddenRecords {
+    ids: HashSet<String>,
+}
+
+impl HiddenRecords {
+    /// Loads the hidden set, or an empty set when `include_sensitive` is set.
+    pub fn load(conn: &Connection, include_sensitive: bool) -> Result<Self, DatabaseError> {
+        if include_sensitive {
+            return Ok(Self::default());
+        }
+
+        let mut ids = HashSet::new();
+        let mut stmt = conn.prepare("SELECT id FROM documents WHERE sensitive = 1")?;
+        for id in stmt.query_map([], |row| row.get::<_, String>(0))? {
+            ids.insert(id?);
+        }
+
+        for entity in SensitiveEntity::ALL {
+            let sql = format!(
+                "SELECT e.id FROM {} e WHERE NOT ({})",
+                entity.table(),
+                entity_filter_sql("e", false).trim_start_matches(" AND ")
+            );
+            let mut stmt = conn.prepare(&sql)?;
+            for id in stmt.query_map([], |row| row.get::<_, String>(0))? {
+                ids.insert(id?);
+            }
+        }
+
+        Ok(Self { ids })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Whether a record (or the document it came from) is hidden.
+    pub fn hides(&self, id: &str, document_id: Option<&str>) -> bool {
+        self.ids.contains(id) || document_id.is_some_and(|d| self.ids.contains(d))
+    }
+
+    /// `hides` for typed entity models.
+    pub fn hides_entity(&self, id: &Uuid, document_id: Option<&Uuid>) -> bool {
+        !self.ids.is_empty()
+            && self.hides(&id.to_string(), document_id.map(|d| d.to_string()).as_deref())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// Tests
+// ═══════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::open_memory_database;
+
+    fn insert_doc(conn: &Connection, title: &str) -> String {
+        let id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO documents (id, type, title, ingestion_date, source_file)
+             VALUES (?1, 'clinical_note', ?2, datetime('now'), 'x.pdf')",
+            params![id, title],
+        )
+        .unwrap();
+        id
+    }
+
+    fn insert_diagnosis(conn: &Connection, doc: &str, name: &str) -> String {
+        let id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO diagnoses (id, name, status, document_id) VALUES (?1, ?2, 'active', ?3)",
+            params![id, name, doc],
+        )
+        .unwrap();
+        id
+    }
+
+    fn visible_diagnoses(conn: &Connection, include_sensitive: bool) -> Vec<String> {
+        let sql = format!(
+            "SELECT dg.name FROM diagnoses dg WHERE 1 = 1{} ORDER BY dg.name",
+            entity_filter_sql("dg", include_sensitive)
+        );
+        let mut stmt = conn.prepare(&sql).unwrap();
+        stmt.query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn classifies_keywords_in_three_languages() {
+        assert_eq!(classify_text("Major depressive disorder"), Some(SensitivityCategory::MentalHealth));
+        assert_eq!(classify_text("Trouble bipolaire de type II"), Some(SensitivityCategory::MentalHealth));
+        assert_eq!(classify_text("HIV-1 infection"), Some(SensitivityCategory::SexualHealth));
+        assert_eq!(classify_text("Humangenetische Beratung: BRCA1"), Some(SensitivityCategory::Genetic));
+        assert_eq!(classify_text("Alkoholabhängigkeit"), Some(SensitivityCategory::SubstanceUse));
+        assert_eq!(classify_text("Type 2 diabetes"), None);
+        // Abbreviations only match as whole words
+        assert_eq!(classify_text("Standard panel, HIVAN excluded"), None);
+    }
+
+    #[test]
+    fn entity_and_document_flags_hide_rows() {
+        let conn = open_memory_database().unwrap();
+        let doc = insert_doc(&conn, "GP letter");
+        let psych_doc = insert_doc(&conn, "Psychiatry consult");
+        let hypertension = insert_diagnosis(&conn, &doc, "Hypertension");
+        let depression = insert_diagnosis(&conn, &doc, "Depression");
+        insert_diagnosis(&conn, &psych_doc, "Insomnia");
+
+        set_entity_sensitive(
+            &conn,
+            SensitiveEntity::Diagnosis,
+            &Uuid::parse_str(&depression).unwrap(),
+            true,
+        )
+        .unwrap();
+        let psych_uuid = Uuid::parse_str(&psych_doc).unwrap();
+        set_document_sensitive(&conn, &psych_uuid, true).unwrap();
+        assert!(is_document_sensitive(&conn, &psych_uuid).unwrap());
+
+        assert_eq!(visible_diagnoses(&conn, false), vec!["Hypertension"]);
+        assert_eq!(visible_diagnoses(&conn, true).len(), 3);
+
+        let hidden = HiddenRecords::load(&conn, false).unwrap();
+        assert!(hidden.hides(&depression, Some(&doc)));
+        assert!(hidden.hides("unrelated", Some(&psych_doc)));
+        assert!(!hidden.hides(&hypertension, Some(&doc)));
+        assert!(HiddenRecords::load(&conn, true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn setting_flag_on_missing_record_fails() {
+        let conn = open_memory_database().unwrap();
+        assert!(set_document_sensitive(&conn, &Uuid::new_v4(), true).is_err());
+        assert!(set_entity_sensitive(&conn, SensitiveEntity::Medication, &Uuid::new_v4(), true).is_err());
+    }
+
+    #[test]
+    fn conversation_override_round_trips() {
+        let conn = open_memory_database().unwrap();
+        let conv = Uuid::new_v4();
+        conn.execute(
+            "INSERT INTO conversations (id, started_at) VALUES (?1, datetime('now'))",
+            params![conv.to_string()],
+        )
+        .unwrap();
+
+        assert!(!conversation_includes_sensitive(&conn, &conv).unwrap());
+        set_conversation_include_sensitive(&conn, &conv, true).unwrap();
+        assert!(conversation_includes_sensitive(&conn, &conv).unwrap());
+        assert!(set_conversation_include_sensitive(&conn, &Uuid::new_v4(), true).is_err());
+    }
+
+    #[test]
+    fn document_flag_bumps_sync_versions() {
+        let conn = open_memory_database().unwrap();
+        let doc = Uuid::parse_str(&insert_doc(&conn, "Genetic panel")).unwrap();
+        let version = |conn: &Connection| -> i64 {
+            conn.query_row(
+                "SELECT version FROM sync_versions WHERE entity_type = 'medications'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap()
+        };
+        let before = version(&conn);
+        set_document_sensitive(&conn, &doc, true).unwrap();
+        assert_eq!(version(&conn), before + 1);
+    }
+}
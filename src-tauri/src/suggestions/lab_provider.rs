@@ -1,4 +1,5 @@
-//! Signal provider for lab results — surfaces abnormal/critical labs not yet discussed.
+//! Signal provider for lab results — surfaces abnormal/critical labs and
+//! significant changes (LTR-01) not yet discussed.
 
 use std::collections::HashMap;
 
@@ -6,7 +7,8 @@ use chrono::{Duration, Local};
 use rusqlite::Connection;
 
 use crate::db::DatabaseError;
-use crate::db::repository::{get_critical_labs, get_lab_results_since};
+use crate::db::repository::{get_all_lab_results, get_critical_labs, get_lab_results_since};
+use crate::invariants::lab_trends;
 use crate::models::enums::AbnormalFlag;
 
 use super::{recency_decay, ScoredSuggestion, SignalProvider, SuggestionIntent};
@@ -41,6 +43,36 @@ impl SignalProvider for LabSignalProvider {
             });
         }
 
+        // LTR-01: Recent change beyond the reference change value
+        if candidates.len() < 2 {
+            let trends = lab_trends::analyze_all(&get_all_lab_results(conn)?);
+            for trend in trends.iter().filter(|t| t.last_date >= since) {
+                if candidates.len() >= 2 {
+                    break;
+                }
+                let significant = trend.last_change.as_ref().is_some_and(|c| c.significant);
+                if !significant {
+                    continue;
+                }
+                let key = trend.test_name.to_lowercase();
+                if candidates.iter().any(|c| c.entity_id.as_deref() == Some(&key))
+                    || recent_topics.contains(&key)
+                {
+                    continue;
+                }
+                let days = (today - trend.last_date).num_days() as f32;
+                candidates.push(ScoredSuggestion {
+                    template_key: "chat.suggest_lab_trend".into(),
+                    params: HashMap::from([("test_name".into(), trend.test_name.clone())]),
+                    intent: SuggestionIntent::Query,
+                    score: 0.7 * recency_decay(days, 14.0),
+                    domain: "lab",
+                    entity_id: Some(key),
+                    category: "labs".into(),
+                });
+            }
+        }
+
         // Abnormal (non-critical) labs
         if candidates.len() < 2 {
             let recent = get_lab_results_since(conn, &since)?;
@@ -135,6 +167,28 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn significant_change_surfaces() {
+        let conn = open_memory_database().unwrap();
+        let first = seed_lab(&conn, "eGFR", "normal");
+        let second = seed_lab(&conn, "eGFR", "normal");
+        conn.execute(
+            "UPDATE lab_results SET value = 90.0, unit = 'mL/min', collection_date = date('now', '-120 days') WHERE id = ?1",
+            params![first],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE lab_results SET value = 65.0, unit = 'mL/min' WHERE id = ?1",
+            params![second],
+        )
+        .unwrap();
+        let provider = LabSignalProvider;
+        let results = provider.collect(&conn, "").unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].template_key, "chat.suggest_lab_trend");
+        assert_eq!(results[0].params["test_name"], "eGFR");
+    }
+
     #[test]
     fn normal_lab_ignored() {
         let conn = open_memory_database().unwrap();
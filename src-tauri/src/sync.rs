@@ -4,20 +4,25 @@
 //! database. Each entity type has a monotonic version counter. When the phone
 //! connects, it sends its known versions; the desktop returns only what changed.
 //!
-//! Six entity types: medications, labs, timeline, alerts, appointments, profile.
+//! Entity types: medications, labs, timeline, alerts, appointments, profile,
+//! conversations and immunizations (IMM-01).
 //!
 //! Journal entries flow phone → desktop (piggybacked on sync requests).
+//!
+//! SENS-01: Records flagged sensitive never reach the phone cache.
 
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
 
+use crate::authorization::{AccessScope, EntityCategory};
 use crate::db::DatabaseError;
+use crate::sensitivity;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // Sync Version Types
 // ═══════════════════════════════════════════════════════════════════════════
 
-/// Version counters for all 7 entity types.
+/// Version counters for all 8 entity types.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncVersions {
@@ -29,6 +34,8 @@ pub struct SyncVersions {
     pub profile: i64,
     #[serde(default)]
     pub conversations: i64,
+    #[serde(default)]
+    pub immunizations: i64,
 }
 
 /// Sync request from phone.
@@ -56,10 +63,17 @@ pub struct SyncResponse {
     pub appointment: Option<CachedAppointment>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<CachedProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub immunizations: Option<Vec<CachedImmunization>>,
     pub versions: SyncVersions,
     pub synced_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub journal_sync: Option<JournalSyncResult>,
+    /// EMC-01: Lock-screen emergency card (opted-in fields only). Sent with
+    /// profile or medication changes to unscoped devices; see
+    /// [`attach_lock_screen_card`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emergency_card: Option<crate::emergency_card::LockScreenCard>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -161,6 +175,19 @@ pub struct CachedAllergy {
     pub verified: bool,
 }
 
+/// IMM-01: Administered vaccine dose for phone cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedImmunization {
+    pub id: String,
+    pub vaccine: String,
+    pub dose_number: Option<u32>,
+    pub administered_date: Option<String>,
+    pub lot_number: Option<String>,
+    pub site: Option<String>,
+    pub administered_by: Option<String>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Journal Sync Types (phone → desktop)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -223,6 +250,7 @@ pub fn get_sync_versions(conn: &Connection) -> Result<SyncVersions, DatabaseErro
             "appointments" => versions.appointments = version,
             "profile" => versions.profile = version,
             "conversations" => versions.conversations = version,
+            "immunizations" => versions.immunizations = version,
             _ => {}
         }
     }
@@ -255,6 +283,9 @@ pub fn diff_versions(phone: &SyncVersions, desktop: &SyncVersions) -> Vec<String
     if phone.conversations < desktop.conversations {
         changed.push("conversations".to_string());
     }
+    if phone.immunizations < desktop.immunizations {
+        changed.push("immunizations".to_string());
+    }
     changed
 }
 
@@ -264,16 +295,17 @@ pub fn diff_versions(phone: &SyncVersions, desktop: &SyncVersions) -> Vec<String
 
 /// Assemble curated medication payload (all active + recently discontinued).
 pub fn assemble_medications(conn: &Connection) -> Result<Vec<CachedMedication>, DatabaseError> {
-    let mut stmt = conn.prepare(
+    let mut stmt = conn.prepare(&format!(
         "SELECT m.id, m.generic_name, m.brand_name, m.dose, m.frequency, m.route,
                 m.status, m.start_date, m.end_date, m.condition, m.is_otc,
                 p.name AS prescriber_name
          FROM medications m
          LEFT JOIN professionals p ON m.prescriber_id = p.id
-         WHERE m.status = 'active'
-            OR (m.status = 'stopped' AND m.end_date >= date('now', '-6 months'))
+         WHERE (m.status = 'active'
+            OR (m.status = 'stopped' AND m.end_date >= date('now', '-6 months'))){}
          ORDER BY m.status ASC, m.generic_name ASC",
-    )?;
+        sensitivity::entity_filter_sql("m", false)
+    ))?;
 
     let rows = stmt.query_map([], |row| {
         Ok(CachedMedication {
@@ -295,6 +327,31 @@ pub fn assemble_medications(conn: &Connection) -> Result<Vec<CachedMedication>,
     rows.map(|r| r.map_err(DatabaseError::from)).collect()
 }
 
+/// IMM-01: Assemble the full immunization history, oldest dose first.
+pub fn assemble_immunizations(conn: &Connection) -> Result<Vec<CachedImmunization>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT i.id, i.vaccine, i.dose_number, i.administered_date, i.lot_number, i.site,
+                p.name AS administered_by
+         FROM immunizations i
+         LEFT JOIN professionals p ON i.administering_professional_id = p.id
+         ORDER BY i.administered_date ASC, i.dose_number ASC",
+    )?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok(CachedImmunization {
+            id: row.get(0)?,
+            vaccine: row.get(1)?,
+            dose_number: row.get(2)?,
+            administered_date: row.get(3)?,
+            lot_number: row.get(4)?,
+            site: row.get(5)?,
+            administered_by: row.get(6)?,
+        })
+    })?;
+
+    rows.map(|r| r.map_err(DatabaseError::from)).collect()
+}
+
 /// Assemble recent lab results with abnormal flag and trend direction.
 pub fn assemble_recent_labs(
     conn: &Connection,
@@ -302,7 +359,7 @@ pub fn assemble_recent_labs(
 ) -> Result<Vec<CachedLabResult>, DatabaseError> {
     // Subquery computes trend by comparing each result's value to the prior
     // result of the same test type (by collection_date).
-    let mut stmt = conn.prepare(
+    let mut stmt = conn.prepare(&format!(
         "SELECT lr.id, lr.test_name, lr.value, lr.value_text, lr.unit,
                 lr.reference_range_low, lr.reference_range_high, lr.abnormal_flag,
                 lr.collection_date,
@@ -310,12 +367,15 @@ pub fn assemble_recent_labs(
                  WHERE prev.test_name = lr.test_name
                    AND prev.collection_date < lr.collection_date
                    AND prev.value IS NOT NULL
+                   AND prev.duplicate_of IS NULL
                  ORDER BY prev.collection_date DESC
                  LIMIT 1) AS prev_value
          FROM lab_results lr
+         WHERE lr.duplicate_of IS NULL{}
          ORDER BY lr.collection_date DESC
          LIMIT ?1",
-    )?;
+        sensitivity::entity_filter_sql("lr", false)
+    ))?;
 
     let rows = stmt.query_map(params![limit], |row| {
         let abnormal_flag: String = row.get(7)?;
@@ -398,8 +458,11 @@ pub fn assemble_alerts(conn: &Connection) -> Result<Vec<CachedAlert>, DatabaseEr
 
     // 1. Active alerts from coherence_alerts (IMP-001 fix)
     if table_exists(conn, "coherence_alerts") {
+        // SENS-01: Alerts citing a hidden record or document stay off phones
+        let hidden = sensitivity::HiddenRecords::load(conn, false)?;
         let mut stmt = conn.prepare(
-            "SELECT id, alert_type, severity, patient_message, detected_at
+            "SELECT id, alert_type, severity, patient_message, detected_at,
+                    entity_ids, source_document_ids
              FROM coherence_alerts
              WHERE dismissed = 0
              ORDER BY
@@ -413,18 +476,29 @@ pub fn assemble_alerts(conn: &Connection) -> Result<Vec<CachedAlert>, DatabaseEr
             let severity: String = row.get(2)?;
             let patient_message: String = row.get(3)?;
             let detected_at: String = row.get(4)?;
-            Ok(CachedAlert {
+            let entity_ids: String = row.get(5)?;
+            let source_document_ids: String = row.get(6)?;
+            let cited: Vec<String> = [entity_ids, source_document_ids]
+                .iter()
+                .flat_map(|json| serde_json::from_str::<Vec<String>>(json).unwrap_or_default())
+                .collect();
+            let alert = CachedAlert {
                 id,
                 title: format_alert_title(&alert_type),
                 description: patient_message,
                 severity,
                 created_at: detected_at,
                 dismissed: false,
-            })
+            };
+            Ok((alert, cited))
         })?;
 
         for row in active_rows {
-            alerts.push(row.map_err(DatabaseError::from)?);
+            let (alert, cited) = row.map_err(DatabaseError::from)?;
+            if cited.iter().any(|id| hidden.hides(id, None)) {
+                continue;
+            }
+            alerts.push(alert);
         }
     }
 
@@ -529,9 +603,10 @@ pub fn assemble_profile_summary(
         |row| Ok((row.get(0)?, row.get(1)?)),
     )?;
 
-    let mut stmt = conn.prepare(
-        "SELECT allergen, severity, verified FROM allergies",
-    )?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT a.allergen, a.severity, a.verified FROM allergies a WHERE 1=1{}",
+        sensitivity::entity_filter_sql("a", false)
+    ))?;
 
     let valid_severities = ["mild", "moderate", "severe", "life_threatening"];
     let mut allergies: Vec<CachedAllergy> = Vec::new();
@@ -662,15 +737,16 @@ fn find_medication_correlations(
 ) -> Result<Vec<JournalCorrelation>, DatabaseError> {
     let onset_date = entry.created_at.split('T').next().unwrap_or(&entry.created_at);
 
-    let mut stmt = conn.prepare(
+    let mut stmt = conn.prepare(&format!(
         "SELECT m.generic_name, dc.change_date,
                 julianday(?1) - julianday(dc.change_date) AS days_diff
          FROM dose_changes dc
          JOIN medications m ON dc.medication_id = m.id
          WHERE dc.change_date >= date(?1, '-14 days')
-           AND dc.change_date <= ?1
+           AND dc.change_date <= ?1{}
          ORDER BY dc.change_date DESC",
-    )?;
+        sensitivity::entity_filter_sql("m", false)
+    ))?;
 
     let rows = stmt.query_map(params![onset_date], |row| {
         let med_name: String = row.get(0)?;
@@ -696,17 +772,29 @@ fn find_medication_correlations(
 
 /// Build the complete sync response by comparing versions and assembling changed payloads.
 ///
+/// GRANT-01: `scope` is the requesting device's grant scope. Entity types
+/// outside it are never assembled, allergies are stripped from the profile
+/// summary unless allowed, and journal entries are only accepted with the
+/// journal category.
+///
 /// Returns `None` if nothing changed and no journal entries were submitted.
 pub fn build_sync_response(
     conn: &Connection,
     request: &SyncRequest,
     profile_name: &str,
+    scope: &AccessScope,
 ) -> Result<Option<SyncResponse>, DatabaseError> {
     let current = get_sync_versions(conn)?;
-    let changed = diff_versions(&request.versions, &current);
+    let changed: Vec<String> = diff_versions(&request.versions, &current)
+        .into_iter()
+        .filter(|entity_type| match sync_category(entity_type) {
+            Some(category) => scope.allows(category),
+            None => true,
+        })
+        .collect();
 
     // Process journal entries (always, even if nothing else changed)
-    let journal_sync = if !request.journal_entries.is_empty() {
+    let journal_sync = if !request.journal_entries.is_empty() && scope.allows(EntityCategory::Journal) {
         Some(process_journal_sync(conn, &request.journal_entries)?)
     } else {
         None
@@ -742,7 +830,14 @@ pub fn build_sync_response(
                 response.appointment = assemble_next_appointment(conn)?;
             }
             "profile" => {
-                response.profile = Some(assemble_profile_summary(conn, profile_name)?);
+                let mut profile = assemble_profile_summary(conn, profile_name)?;
+                if !scope.allows(EntityCategory::Allergies) {
+                    profile.allergies.clear();
+                }
+                response.profile = Some(profile);
+            }
+            "immunizations" => {
+                response.immunizations = Some(assemble_immunizations(conn)?);
             }
             _ => {}
         }
@@ -751,6 +846,46 @@ pub fn build_sync_response(
     Ok(Some(response))
 }
 
+/// EMC-01: Refresh the lock-screen card alongside its source data.
+///
+/// The card carries identity, diagnoses and emergency contacts, which no
+/// grant category covers, so only unscoped access (the owner's own devices
+/// and unscoped grants) receives it (GRANT-01).
+pub fn attach_lock_screen_card(
+    conn: &Connection,
+    response: &mut SyncResponse,
+    profile_name: &str,
+    date_of_birth: Option<chrono::NaiveDate>,
+    blood_type: Option<&crate::models::enums::BloodType>,
+    scope: &AccessScope,
+) -> Result<(), DatabaseError> {
+    if !scope.is_all() || (response.profile.is_none() && response.medications.is_none()) {
+        return Ok(());
+    }
+    response.emergency_card = Some(crate::emergency_card::lock_screen_card(
+        conn,
+        profile_name,
+        date_of_birth,
+        blood_type,
+    )?);
+    Ok(())
+}
+
+/// GRANT-01: Category guarding a sync entity type. `None` = always synced
+/// (the profile summary carries no category data besides allergies).
+fn sync_category(entity_type: &str) -> Option<EntityCategory> {
+    match entity_type {
+        "medications" => Some(EntityCategory::Medications),
+        "labs" => Some(EntityCategory::Labs),
+        "timeline" => Some(EntityCategory::Timeline),
+        "alerts" => Some(EntityCategory::Alerts),
+        "appointments" => Some(EntityCategory::Appointments),
+        "immunizations" => Some(EntityCategory::Immunizations),
+        "conversations" => Some(EntityCategory::Conversations),
+        _ => None,
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tests
 // ═══════════════════════════════════════════════════════════════════════════
@@ -984,6 +1119,33 @@ mod tests {
         assert_eq!(versions.conversations, 0);
     }
 
+    #[test]
+    fn immunization_insert_increments_version() {
+        let conn = test_db();
+        let doc_id = insert_doc(&conn);
+        let prof_id = insert_professional(&conn);
+        conn.execute(
+            "INSERT INTO immunizations (id, vaccine, dose_number, administered_date, lot_number,
+                                        administering_professional_id, document_id)
+             VALUES (?1, 'MMR', 1, '2025-02-10', 'X123', ?2, ?3)",
+            params![Uuid::new_v4().to_string(), prof_id, doc_id],
+        )
+        .unwrap();
+
+        let versions = get_sync_versions(&conn).unwrap();
+        assert_eq!(versions.immunizations, 1);
+
+        let request = SyncRequest {
+            versions: SyncVersions::default(),
+            journal_entries: vec![],
+        };
+        let response = build_sync_response(&conn, &request, "Test", &AccessScope::All).unwrap().unwrap();
+        let imms = response.immunizations.unwrap();
+        assert_eq!(imms.len(), 1);
+        assert_eq!(imms[0].dose_number, Some(1));
+        assert_eq!(imms[0].administered_by.as_deref(), Some("Dr. Smith"));
+    }
+
     #[test]
     fn diff_versions_detects_conversation_changes() {
         let phone = SyncVersions {
@@ -1026,6 +1188,7 @@ mod tests {
             appointments: 1,
             profile: 4,
             conversations: 2,
+            immunizations: 3,
         };
         let desktop = phone.clone();
         assert!(diff_versions(&phone, &desktop).is_empty());
@@ -1056,6 +1219,7 @@ mod tests {
             appointments: 1,
             profile: 0,
             conversations: 0,
+            immunizations: 0,
         };
         let changed = diff_versions(&phone, &desktop);
         assert_eq!(changed.len(), 3);
@@ -1075,9 +1239,10 @@ mod tests {
             appointments: 1,
             profile: 1,
             conversations: 1,
+            immunizations: 1,
         };
         let changed = diff_versions(&phone, &desktop);
-        assert_eq!(changed.len(), 7);
+        assert_eq!(changed.len(), 8);
     }
 
     // -----------------------------------------------------------------------
@@ -1116,6 +1281,107 @@ mod tests {
         assert_eq!(meds[0].generic_name, "Metformin");
     }
 
+    #[test]
+    fn assemble_medications_excludes_sensitive() {
+        let conn = test_db();
+        let doc_id = insert_doc(&conn);
+        let psych_doc = insert_doc(&conn);
+
+        for (name, doc) in [("Metformin", &doc_id), ("Sertraline", &psych_doc)] {
+            conn.execute(
+                "INSERT INTO medications (id, generic_name, dose, frequency, frequency_type, status, document_id)
+                 VALUES (?1, ?2, '50mg', 'daily', 'scheduled', 'active', ?3)",
+                params![Uuid::new_v4().to_string(), name, doc],
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "UPDATE documents SET sensitive = 1 WHERE id = ?1",
+            params![psych_doc],
+        )
+        .unwrap();
+
+        let meds = assemble_medications(&conn).unwrap();
+        assert_eq!(meds.len(), 1);
+        assert_eq!(meds[0].generic_name, "Metformin");
+    }
+
+    #[test]
+    fn assemble_alerts_excludes_alerts_on_sensitive_records() {
+        let conn = test_db();
+        let doc_id = insert_doc(&conn);
+        let (metformin, sertraline) = (Uuid::new_v4().to_string(), Uuid::new_v4().to_string());
+        for (id, name) in [(&metformin, "Metformin"), (&sertraline, "Sertraline")] {
+            conn.execute(
+                "INSERT INTO medications (id, generic_name, dose, frequency, frequency_type, status, document_id)
+                 VALUES (?1, ?2, '50mg', 'daily', 'scheduled', 'active', ?3)",
+                params![id, name, doc_id],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO coherence_alerts (id, alert_type, severity, entity_ids, source_document_ids,
+                     patient_message, detail_json, detected_at)
+                 VALUES (?1, 'dose', 'standard', ?2, ?3, ?4, '{}', datetime('now'))",
+                params![
+                    Uuid::new_v4().to_string(),
+                    serde_json::json!([id]).to_string(),
+                    serde_json::json!([doc_id]).to_string(),
+                    format!("Check the {name} dose"),
+                ],
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "UPDATE medications SET sensitive = 1 WHERE id = ?1",
+            params![sertraline],
+        )
+        .unwrap();
+
+        let alerts = assemble_alerts(&conn).unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].description, "Check the Metformin dose");
+
+        // A document flag hides every alert citing it
+        conn.execute("UPDATE documents SET sensitive = 1 WHERE id = ?1", params![doc_id])
+            .unwrap();
+        assert!(assemble_alerts(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn sensitivity_flags_bump_alert_and_timeline_versions() {
+        let conn = test_db();
+        let doc_id = insert_doc(&conn);
+        let med_id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO medications (id, generic_name, dose, frequency, frequency_type, status, document_id)
+             VALUES (?1, 'Sertraline', '50mg', 'daily', 'scheduled', 'active', ?2)",
+            params![med_id, doc_id],
+        )
+        .unwrap();
+        let versions = |conn: &Connection| -> (i64, i64) {
+            let version = |entity_type: &str| {
+                conn.query_row(
+                    "SELECT version FROM sync_versions WHERE entity_type = ?1",
+                    params![entity_type],
+                    |row| row.get(0),
+                )
+                .unwrap()
+            };
+            (version("alerts"), version("timeline"))
+        };
+
+        let before = versions(&conn);
+        conn.execute("UPDATE documents SET sensitive = 1 WHERE id = ?1", params![doc_id])
+            .unwrap();
+        let after_document = versions(&conn);
+        assert!(after_document.0 > before.0 && after_document.1 > before.1);
+
+        conn.execute("UPDATE medications SET sensitive = 1 WHERE id = ?1", params![med_id])
+            .unwrap();
+        let after_entity = versions(&conn);
+        assert!(after_entity.0 > after_document.0 && after_entity.1 > after_document.1);
+    }
+
     #[test]
     fn assemble_medications_with_prescriber() {
         let conn = test_db();
@@ -1723,7 +1989,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_none());
     }
 
@@ -1744,7 +2010,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_some());
         let resp = response.unwrap();
         assert!(resp.medications.is_some());
@@ -1754,6 +2020,87 @@ mod tests {
         assert_eq!(resp.versions.medications, 1);
     }
 
+    #[test]
+    fn build_sync_respects_grant_scope() {
+        let conn = test_db();
+        let doc_id = insert_doc(&conn);
+
+        conn.execute(
+            "INSERT INTO medications (id, generic_name, dose, frequency, frequency_type, status, document_id)
+             VALUES (?1, 'Metformin', '500mg', 'daily', 'scheduled', 'active', ?2)",
+            params![Uuid::new_v4().to_string(), doc_id],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO lab_results (id, test_name, abnormal_flag, collection_date, document_id)
+             VALUES (?1, 'HbA1c', 'high', '2026-01-10', ?2)",
+            params![Uuid::new_v4().to_string(), doc_id],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO allergies (id, allergen, severity, source, verified)
+             VALUES (?1, 'Penicillin', 'severe', 'patient_reported', 1)",
+            params![Uuid::new_v4().to_string()],
+        )
+        .unwrap();
+
+        let request = SyncRequest {
+            versions: SyncVersions::default(),
+            journal_entries: vec![],
+        };
+
+        // Medications only: no labs, profile summary without allergies
+        let meds_only = AccessScope::Categories(vec![EntityCategory::Medications]);
+        let resp = build_sync_response(&conn, &request, "Mom", &meds_only).unwrap().unwrap();
+        assert_eq!(resp.medications.unwrap().len(), 1);
+        assert!(resp.labs.is_none());
+        assert!(resp.profile.unwrap().allergies.is_empty());
+
+        // Medications + allergies: allergies included
+        let with_allergies =
+            AccessScope::Categories(vec![EntityCategory::Medications, EntityCategory::Allergies]);
+        let resp = build_sync_response(&conn, &request, "Mom", &with_allergies).unwrap().unwrap();
+        assert_eq!(resp.profile.unwrap().allergies.len(), 1);
+    }
+
+    #[test]
+    fn lock_screen_card_only_syncs_to_unscoped_devices() {
+        use crate::emergency_card::{set_lock_screen_fields, EmergencyField};
+
+        let conn = test_db();
+        let doc_id = insert_doc(&conn);
+        conn.execute(
+            "INSERT INTO medications (id, generic_name, dose, frequency, frequency_type, status, document_id)
+             VALUES (?1, 'Metformin', '500mg', 'daily', 'scheduled', 'active', ?2)",
+            params![Uuid::new_v4().to_string(), doc_id],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO diagnoses (id, name, status, document_id)
+             VALUES (?1, 'Type 2 diabetes', 'active', ?2)",
+            params![Uuid::new_v4().to_string(), doc_id],
+        )
+        .unwrap();
+        set_lock_screen_fields(&conn, &EmergencyField::ALL).unwrap();
+        let request = SyncRequest {
+            versions: SyncVersions::default(),
+            journal_entries: vec![],
+        };
+        let dob = chrono::NaiveDate::from_ymd_opt(1958, 3, 2);
+
+        let meds_only = AccessScope::Categories(vec![EntityCategory::Medications]);
+        let mut resp = build_sync_response(&conn, &request, "Mom", &meds_only).unwrap().unwrap();
+        attach_lock_screen_card(&conn, &mut resp, "Mom", dob, None, &meds_only).unwrap();
+        assert_eq!(resp.medications.as_ref().unwrap().len(), 1);
+        assert!(resp.emergency_card.is_none());
+
+        let mut resp = build_sync_response(&conn, &request, "Mom", &AccessScope::All).unwrap().unwrap();
+        attach_lock_screen_card(&conn, &mut resp, "Mom", dob, None, &AccessScope::All).unwrap();
+        let card = resp.emergency_card.unwrap();
+        assert_eq!(card.name.as_deref(), Some("Mom"));
+        assert_eq!(card.diagnoses, ["Type 2 diabetes"]);
+    }
+
     #[test]
     fn build_sync_multiple_types_changed() {
         let conn = test_db();
@@ -1780,7 +2127,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_some());
         let resp = response.unwrap();
         assert!(resp.medications.is_some());
@@ -1809,7 +2156,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_none());
     }
 
@@ -1830,7 +2177,7 @@ mod tests {
             }],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_some());
         let resp = response.unwrap();
         assert!(resp.journal_sync.is_some());
@@ -1880,7 +2227,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_some());
         let resp = response.unwrap();
         assert!(resp.medications.is_some());
@@ -1981,6 +2328,7 @@ mod tests {
         assert!(!obj.contains_key("appointment"));
         assert!(!obj.contains_key("profile"));
         assert!(!obj.contains_key("journalSync")); // camelCase (CA-03)
+        assert!(!obj.contains_key("emergencyCard"));
         // versions always present; synced_at → syncedAt
         assert!(obj.contains_key("versions"));
         assert!(obj.contains_key("syncedAt")); // camelCase (CA-03)
@@ -2033,7 +2381,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_some());
         let resp = response.unwrap();
         assert!(resp.journal_sync.is_none());
@@ -2138,6 +2486,7 @@ mod tests {
             appointments: 42,
             profile: 100,
             conversations: 50,
+            immunizations: 7,
         };
         let desktop = SyncVersions {
             medications: 1_000_000,
@@ -2147,6 +2496,7 @@ mod tests {
             appointments: 42,
             profile: 100,
             conversations: 50,
+            immunizations: 7,
         };
         let changed = diff_versions(&phone, &desktop);
         assert_eq!(changed.len(), 2);
@@ -2220,7 +2570,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let response = build_sync_response(&conn, &request, "Léa").unwrap();
+        let response = build_sync_response(&conn, &request, "Léa", &AccessScope::All).unwrap();
         assert!(response.is_some());
         let resp = response.unwrap();
 
@@ -2321,7 +2671,7 @@ mod tests {
             journal_entries: vec![],
         };
 
-        let resp = build_sync_response(&conn, &request, "Léa")
+        let resp = build_sync_response(&conn, &request, "Léa", &AccessScope::All)
             .unwrap()
             .unwrap();
 
@@ -2,6 +2,7 @@ use chrono::NaiveDate;
 use rusqlite::{params, Connection};
 
 use crate::db::DatabaseError;
+use crate::sensitivity::{self, HiddenRecords};
 use super::correlations::{detect_correlations, fetch_explicit_correlations};
 use super::fetch::*;
 use super::types::*;
@@ -13,12 +14,18 @@ pub fn assemble_timeline_events(
     filter: &TimelineFilter,
 ) -> Result<Vec<TimelineEvent>, DatabaseError> {
     let (date_from, date_to) = resolve_date_bounds(conn, filter)?;
+    let include_sensitive = filter.include_sensitive.unwrap_or(false);
 
     let mut events: Vec<TimelineEvent> = Vec::new();
 
     events.extend(fetch_medication_starts(conn, &date_from, &date_to)?);
     events.extend(fetch_medication_stops(conn, &date_from, &date_to)?);
-    events.extend(fetch_dose_changes(conn, &date_from, &date_to)?);
+    events.extend(fetch_dose_changes(
+        conn,
+        &date_from,
+        &date_to,
+        &sensitivity::entity_filter_sql("m", include_sensitive),
+    )?);
     events.extend(fetch_lab_events(conn, &date_from, &date_to)?);
     events.extend(fetch_symptom_events(conn, &date_from, &date_to)?);
     events.extend(fetch_procedure_events(conn, &date_from, &date_to)?);
@@ -29,6 +36,12 @@ pub fn assemble_timeline_events(
     events.extend(fetch_coherence_alert_events(conn, &date_from, &date_to, include_dismissed)?);
     events.extend(fetch_vital_sign_events(conn, &date_from, &date_to)?);
 
+    // SENS-01: Drop flagged records (stop events carry a "-stop" id suffix)
+    let hidden = HiddenRecords::load(conn, include_sensitive)?;
+    if !hidden.is_empty() {
+        events.retain(|e| !hidden.hides(e.id.trim_end_matches("-stop"), e.document_id.as_deref()));
+    }
+
     // Apply event_type filter
     if let Some(ref types) = filter.event_types {
         events.retain(|e| types.contains(&e.event_type));
@@ -88,14 +101,14 @@ pub fn compute_event_counts(conn: &Connection) -> Result<EventCounts, DatabaseEr
     Ok(EventCounts {
         medications: count("SELECT COUNT(*) FROM medications WHERE start_date IS NOT NULL")?
             + count("SELECT COUNT(*) FROM dose_changes")?,
-        lab_results: count("SELECT COUNT(*) FROM lab_results")?,
+        lab_results: count("SELECT COUNT(*) FROM lab_results WHERE duplicate_of IS NULL")?,
         symptoms: count("SELECT COUNT(*) FROM symptoms")?,
         procedures: count("SELECT COUNT(*) FROM procedures WHERE date IS NOT NULL")?,
         appointments: count("SELECT COUNT(*) FROM appointments")?,
         documents: count("SELECT COUNT(*) FROM documents")?,
         diagnoses: count("SELECT COUNT(*) FROM diagnoses WHERE date_diagnosed IS NOT NULL")?,
         coherence_alerts: count("SELECT COUNT(*) FROM coherence_alerts WHERE dismissed = 0")?,
-        vital_signs: count("SELECT COUNT(*) FROM vital_signs")?,
+        vital_signs: count("SELECT COUNT(*) FROM vital_signs WHERE duplicate_of IS NULL")?,
     })
 }
 
@@ -139,7 +152,9 @@ pub fn get_timeline_data(
     let events = assemble_timeline_events(conn, filter)?;
 
     let mut correlations = detect_correlations(&events);
-    let explicit = fetch_explicit_correlations(conn)?;
+    let mut explicit = fetch_explicit_correlations(conn)?;
+    let hidden = HiddenRecords::load(conn, filter.include_sensitive.unwrap_or(false))?;
+    explicit.retain(|c| !hidden.hides(&c.target_id, None));
     correlations.extend(explicit);
 
     // Deduplicate (same source+target pair)
@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
 use rusqlite::Connection;
 
 use crate::db::DatabaseError;
+use crate::invariants::lab_trends::{self, LabChange, LabPoint};
 use super::types::*;
 
 /// Helper: builds dynamic WHERE clause with date bounds.
@@ -168,6 +172,7 @@ pub(super) fn fetch_dose_changes(
     conn: &Connection,
     date_from: &Option<String>,
     date_to: &Option<String>,
+    sensitivity_clause: &str,
 ) -> Result<Vec<TimelineEvent>, DatabaseError> {
     let bounds = DateBoundQuery::new("dc.change_date", date_from, date_to);
     let sql = format!(
@@ -178,7 +183,8 @@ pub(super) fn fetch_dose_changes(
          FROM dose_changes dc
          JOIN medications m ON dc.medication_id = m.id
          LEFT JOIN professionals p ON dc.changed_by_id = p.id
-         WHERE 1=1{}",
+         WHERE 1=1{}{}",
+        sensitivity_clause,
         bounds.sql_suffix()
     );
 
@@ -210,11 +216,38 @@ pub(super) fn fetch_dose_changes(
     rows.collect::<Result<Vec<_>, _>>().map_err(DatabaseError::from)
 }
 
+/// LTR-01: Change since the previous result for every numeric lab result,
+/// keyed by lab result ID.
+pub(crate) fn fetch_lab_changes(
+    conn: &Connection,
+) -> Result<HashMap<String, LabChange>, DatabaseError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, test_name, unit, collection_date, value
+         FROM lab_results
+         WHERE value IS NOT NULL AND duplicate_of IS NULL",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        let date: String = row.get("collection_date")?;
+        Ok(LabPoint {
+            key: row.get::<_, String>("id")?,
+            test_name: row.get("test_name")?,
+            unit: row.get("unit")?,
+            date: NaiveDate::parse_from_str(&date, "%Y-%m-%d").unwrap_or_default(),
+            value: row.get("value")?,
+        })
+    })?;
+    let points = rows.collect::<Result<Vec<_>, _>>()?;
+    Ok(lab_trends::changes_by_key(&points))
+}
+
 pub(super) fn fetch_lab_events(
     conn: &Connection,
     date_from: &Option<String>,
     date_to: &Option<String>,
 ) -> Result<Vec<TimelineEvent>, DatabaseError> {
+    // LTR-01: changes are computed over the full history so the first result
+    // inside the date window still compares against its predecessor.
+    let changes = fetch_lab_changes(conn)?;
     let bounds = DateBoundQuery::new("l.collection_date", date_from, date_to);
     let sql = format!(
         "SELECT l.id, l.test_name, l.value, l.value_text, l.unit,
@@ -223,12 +256,13 @@ pub(super) fn fetch_lab_events(
                 l.ordering_physician_id, p.name AS prof_name, l.document_id
          FROM lab_results l
          LEFT JOIN professionals p ON l.ordering_physician_id = p.id
-         WHERE 1=1{}",
+         WHERE l.duplicate_of IS NULL{}",
         bounds.sql_suffix()
     );
 
     let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(bounds.param_refs().as_slice(), |row| {
+        let id: String = row.get("id")?;
         let test_name: String = row.get("test_name")?;
         let flag: String = row.get("abnormal_flag")?;
         let value: Option<f64> = row.get("value")?;
@@ -242,8 +276,10 @@ pub(super) fn fetch_lab_events(
             _ => None,
         };
 
+        let change = changes.get(&id).cloned();
+
         Ok(TimelineEvent {
-            id: row.get::<_, String>("id")?,
+            id,
             event_type: EventType::LabResult,
             date: row.get::<_, String>("collection_date")?,
             title: test_name.clone(),
@@ -260,6 +296,7 @@ pub(super) fn fetch_lab_events(
                 reference_low: row.get("reference_range_low")?,
                 reference_high: row.get("reference_range_high")?,
                 abnormal_flag: flag,
+                change,
             },
         })
     })?;
@@ -609,7 +646,7 @@ pub(super) fn fetch_vital_sign_events(
         "SELECT vs.id, vs.vital_type, vs.value_primary, vs.value_secondary,
                 vs.unit, vs.recorded_at, vs.notes, vs.source
          FROM vital_signs vs
-         WHERE 1=1{}",
+         WHERE vs.duplicate_of IS NULL{}",
         bounds.sql_suffix()
     );
 
@@ -13,6 +13,7 @@ mod types;
 pub use aggregates::*;
 pub use correlations::*;
 pub use types::*;
+pub(crate) use fetch::fetch_lab_changes;
 
 // ── Tests ──────────────────────────────────────────────────────────────────
 
@@ -144,6 +145,35 @@ mod tests {
         assert_eq!(labs[0].severity, Some(EventSeverity::High));
     }
 
+    #[test]
+    fn test_lab_change_compares_against_result_outside_window() {
+        let conn = setup_db();
+        insert_document(&conn, "doc-1", "Lab Report", "2026-01-10", None);
+
+        conn.execute(
+            "INSERT INTO lab_results (id, test_name, value, unit, abnormal_flag, collection_date, document_id)
+             VALUES ('lab-1', 'HbA1c', 6.2, '%', 'normal', '2025-07-10', 'doc-1'),
+                    ('lab-2', 'HbA1c', 7.0, '%', 'high', '2026-01-10', 'doc-1')",
+            [],
+        ).unwrap();
+
+        let filter = TimelineFilter {
+            date_from: Some("2026-01-01".into()),
+            ..Default::default()
+        };
+        let events = assemble_timeline_events(&conn, &filter).unwrap();
+
+        let labs: Vec<_> = events.iter().filter(|e| e.event_type == EventType::LabResult).collect();
+        assert_eq!(labs.len(), 1);
+        match &labs[0].metadata {
+            EventMetadata::Lab { change: Some(change), .. } => {
+                assert_eq!(change.previous_date.to_string(), "2025-07-10");
+                assert!(change.significant);
+            }
+            other => panic!("expected lab change, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_assemble_symptoms() {
         let conn = setup_db();
@@ -802,6 +832,28 @@ mod tests {
         assert_eq!(alerts_incl.len(), 2);
     }
 
+    #[test]
+    fn test_sensitive_records_hidden_unless_requested() {
+        let conn = setup_db();
+        insert_document(&conn, "doc-1", "GP visit", "2026-01-10", None);
+        insert_document(&conn, "doc-2", "Psychiatry letter", "2026-01-12", None);
+        insert_medication(&conn, "med-1", "Metformin", "500mg", "2026-01-10", None, "active", "doc-1", None);
+        insert_medication(&conn, "med-2", "Sertraline", "50mg", "2026-01-12", Some("2026-02-01"), "stopped", "doc-2", None);
+        conn.execute("UPDATE documents SET sensitive = 1 WHERE id = 'doc-2'", []).unwrap();
+
+        let events = assemble_timeline_events(&conn, &TimelineFilter::default()).unwrap();
+        assert!(events.iter().all(|e| !e.title.contains("Sertraline")));
+        assert!(events.iter().all(|e| e.id != "doc-2"));
+        assert!(events.iter().any(|e| e.title.contains("Metformin")));
+
+        let filter = TimelineFilter {
+            include_sensitive: Some(true),
+            ..Default::default()
+        };
+        let events = assemble_timeline_events(&conn, &filter).unwrap();
+        assert_eq!(events.iter().filter(|e| e.title.contains("Sertraline")).count(), 2);
+    }
+
     #[test]
     fn test_filter_by_new_event_types() {
         let conn = setup_db();
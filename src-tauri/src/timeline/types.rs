@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::invariants::lab_trends::LabChange;
+
 /// A single event on the timeline — unified across all entity tables.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimelineEvent {
@@ -72,6 +74,9 @@ pub enum EventMetadata {
         reference_low: Option<f64>,
         reference_high: Option<f64>,
         abnormal_flag: String,
+        /// LTR-01: Change since the previous result of the same analyte.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        change: Option<LabChange>,
     },
     Symptom {
         category: String,
@@ -157,6 +162,8 @@ pub struct TimelineFilter {
     pub date_to: Option<String>,
     pub since_appointment_id: Option<String>,
     pub include_dismissed_alerts: Option<bool>,
+    /// SENS-01: Show records flagged sensitive (hidden by default).
+    pub include_sensitive: Option<bool>,
 }
 
 /// Complete timeline data — single response.
@@ -61,7 +61,8 @@ pub fn fetch_critical_alerts(conn: &Connection) -> Result<Vec<CriticalLabAlert>,
                 reference_range_low, reference_range_high,
                 abnormal_flag, collection_date, document_id
          FROM lab_results
-         WHERE abnormal_flag IN ('critical_low', 'critical_high')",
+         WHERE abnormal_flag IN ('critical_low', 'critical_high')
+           AND duplicate_of IS NULL",
     )?;
 
     let rows = stmt.query_map([], |row| {
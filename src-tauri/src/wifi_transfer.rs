@@ -453,9 +453,9 @@ async fn handle_upload(
         }
     }
 
-    // Parse multipart fields
+    // Parse multipart fields (MPA-01: several "file" fields = pages of one document)
     let mut pin_provided = String::new();
-    let mut file_data: Option<(String, Vec<u8>)> = None;
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
 
     while let Ok(Some(field)) = multipart.next_field().await {
         let name = field.name().unwrap_or("").to_string();
@@ -467,7 +467,7 @@ async fn handle_upload(
                 let filename = field.file_name().unwrap_or("document").to_string();
                 match field.bytes().await {
                     Ok(bytes) => {
-                        file_data = Some((filename, bytes.to_vec()));
+                        files.push((filename, bytes.to_vec()));
                     }
                     Err(e) => {
                         tracing::warn!("Failed to read upload bytes: {e}");
@@ -499,50 +499,78 @@ async fn handle_upload(
     }
 
     // Validate file presence
-    let (filename, bytes) = match file_data {
-        Some(data) => data,
-        None => {
+    if files.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "No file provided.".into(),
+            }),
+        )
+            .into_response();
+    }
+
+    let mut detected_mimes = Vec::with_capacity(files.len());
+    for (_, bytes) in &files {
+        // Check file size
+        if bytes.len() as u64 > state.max_file_size {
             return (
-                StatusCode::BAD_REQUEST,
+                StatusCode::PAYLOAD_TOO_LARGE,
                 Json(ErrorResponse {
-                    error: "No file provided.".into(),
+                    error: format!(
+                        "File too large. Maximum {}MB.",
+                        state.max_file_size / (1024 * 1024)
+                    ),
                 }),
             )
                 .into_response();
         }
-    };
 
-    // Check file size
-    if bytes.len() as u64 > state.max_file_size {
-        return (
-            StatusCode::PAYLOAD_TOO_LARGE,
-            Json(ErrorResponse {
-                error: format!(
-                    "File too large. Maximum {}MB.",
-                    state.max_file_size / (1024 * 1024)
-                ),
-            }),
-        )
-            .into_response();
+        // Validate MIME type via magic bytes
+        let detected_mime = detect_mime_from_bytes(bytes);
+        if !state.allowed_mime_types.contains(&detected_mime) {
+            return (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                Json(ErrorResponse {
+                    error: "File type not supported. Please send an image or PDF.".into(),
+                }),
+            )
+                .into_response();
+        }
+        detected_mimes.push(detected_mime);
     }
 
-    // Validate MIME type via magic bytes
-    let detected_mime = detect_mime_from_bytes(&bytes);
-    if !state.allowed_mime_types.contains(&detected_mime) {
+    // MPA-01: Only photos can be combined into one document
+    let group_id = (files.len() > 1).then(Uuid::new_v4);
+    if group_id.is_some() && !detected_mimes.iter().all(|m| m.starts_with("image/")) {
         return (
             StatusCode::UNSUPPORTED_MEDIA_TYPE,
             Json(ErrorResponse {
-                error: "File type not supported. Please send an image or PDF.".into(),
+                error: "Only photos can be sent as pages of one document.".into(),
             }),
         )
             .into_response();
     }
 
-    // Sanitize filename and stage the file
-    let safe_filename = sanitize_filename(&filename);
+    // Each page counts against the session upload limit. Check and reserve
+    // under one lock so concurrent uploads cannot overshoot the cap.
+    let pages = files.len() as u32;
+    {
+        let mut count = state.upload_count.lock().await;
+        if *count + pages > state.max_uploads {
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(ErrorResponse {
+                    error: "Upload limit reached for this session.".into(),
+                }),
+            )
+                .into_response();
+        }
+        *count += pages;
+    }
 
     if let Err(e) = std::fs::create_dir_all(&state.staging_dir) {
         tracing::error!("Failed to create staging dir: {e}");
+        release_upload_slots(&state, pages).await;
         return (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
@@ -552,58 +580,95 @@ async fn handle_upload(
             .into_response();
     }
 
-    let staged_path = state
-        .staging_dir
-        .join(format!("{}_{}", Uuid::new_v4(), safe_filename));
+    let mut staged_names = Vec::with_capacity(files.len());
+    for (index, ((filename, bytes), detected_mime)) in files.iter().zip(&detected_mimes).enumerate() {
+        // Sanitize filename and stage the file
+        let safe_filename = sanitize_filename(filename);
+        let staged_name = match group_id {
+            Some(group_id) => group_staging_name(&group_id, index, &safe_filename),
+            None => format!("{}_{}", Uuid::new_v4(), safe_filename),
+        };
+        let staged_path = state.staging_dir.join(&staged_name);
+
+        // SEC-02-G04: Encrypt staging file so plaintext never hits disk
+        if let Err(e) = crate::pipeline::import::staging::write_encrypted_staging(
+            bytes,
+            &staged_path,
+            &state.encryption_key,
+        ) {
+            tracing::error!("Failed to stage file: {e}");
+            // Don't leave a partial page group behind
+            for name in &staged_names {
+                let _ = std::fs::remove_file(state.staging_dir.join(name));
+            }
+            release_upload_slots(&state, pages).await;
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: "Failed to save file.".into(),
+                }),
+            )
+                .into_response();
+        }
+        staged_names.push(staged_name);
 
-    // SEC-02-G04: Encrypt staging file so plaintext never hits disk
-    if let Err(e) = crate::pipeline::import::staging::write_encrypted_staging(
-        &bytes,
-        &staged_path,
-        &state.encryption_key,
-    ) {
-        tracing::error!("Failed to stage file: {e}");
-        return (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: "Failed to save file.".into(),
-            }),
-        )
-            .into_response();
-    }
+        // Record received file
+        let upload_result = UploadResult {
+            filename: safe_filename.clone(),
+            size_bytes: bytes.len() as u64,
+            mime_type: detected_mime.clone(),
+            received_at: chrono::Local::now().naive_local(),
+        };
+        state.received_files.lock().await.push(upload_result);
 
-    // Increment upload count
-    {
-        let mut count = state.upload_count.lock().await;
-        *count += 1;
+        tracing::info!(
+            filename = %safe_filename,
+            size = bytes.len(),
+            mime = %detected_mime,
+            "File received via WiFi transfer"
+        );
     }
 
-    // Record received file
-    let upload_result = UploadResult {
-        filename: safe_filename.clone(),
-        size_bytes: bytes.len() as u64,
-        mime_type: detected_mime.clone(),
-        received_at: chrono::Local::now().naive_local(),
+    let message = if files.len() > 1 {
+        format!("Document received! {} pages", files.len())
+    } else {
+        format!("Document received! {}", sanitize_filename(&files[0].0))
     };
-    state.received_files.lock().await.push(upload_result);
-
-    tracing::info!(
-        filename = %safe_filename,
-        size = bytes.len(),
-        mime = %detected_mime,
-        "File received via WiFi transfer"
-    );
 
     (
         StatusCode::OK,
         Json(UploadResponse {
             success: true,
-            message: format!("Document received! {safe_filename}"),
+            message,
         }),
     )
         .into_response()
 }
 
+/// Returns slots reserved by an upload that failed before staging completed.
+async fn release_upload_slots(state: &ServerState, pages: u32) {
+    let mut count = state.upload_count.lock().await;
+    *count = count.saturating_sub(pages);
+}
+
+/// MPA-01: Staging file name for one page of a multi-photo upload.
+///
+/// Pages of the same document share the group ID; the index keeps their order.
+fn group_staging_name(group_id: &Uuid, index: usize, safe_filename: &str) -> String {
+    format!("group-{group_id}-{index:03}_{safe_filename}")
+}
+
+/// Parse a staging file name produced by [`group_staging_name`].
+///
+/// Returns the group ID and page index, or `None` for single-file uploads.
+pub fn parse_group_staging_name(name: &str) -> Option<(Uuid, usize)> {
+    let rest = name.strip_prefix("group-")?;
+    let (group_id, rest) = rest.split_at_checked(36)?;
+    let group_id = Uuid::parse_str(group_id).ok()?;
+    let (index, _) = rest.strip_prefix('-')?.split_once('_')?;
+    Some((group_id, index.parse().ok()?))
+}
+
 // ---------------------------------------------------------------------------
 // Upload page HTML (self-contained, mobile-optimized, no external resources)
 // ---------------------------------------------------------------------------
@@ -658,7 +723,7 @@ const UPLOAD_PAGE_HTML: &str = r#"<!DOCTYPE html>
 </head>
 <body>
   <h1>Send to Coheara</h1>
-  <p>Enter the PIN shown on your computer, then choose a document to send.</p>
+  <p>Enter the PIN shown on your computer, then choose a document to send. Select several photos to send them as the pages of one document.</p>
 
   <div class="pin-input" id="pin-container">
     <input type="tel" maxlength="1" data-index="0" autocomplete="off">
@@ -681,7 +746,7 @@ const UPLOAD_PAGE_HTML: &str = r#"<!DOCTYPE html>
     </button>
   </div>
 
-  <input type="file" id="file-input" accept="image/*,application/pdf">
+  <input type="file" id="file-input" accept="image/*,application/pdf" multiple>
   <input type="file" id="camera-input" accept="image/*" capture="environment">
 
   <div class="progress" id="progress">
@@ -731,16 +796,17 @@ const UPLOAD_PAGE_HTML: &str = r#"<!DOCTYPE html>
     fileInput.addEventListener('change', handleFile);
 
     function handleFile(e) {
-      var file = e.target.files[0];
-      if (!file) return;
+      var files = Array.from(e.target.files);
+      if (files.length === 0) return;
 
-      if (file.size > 50 * 1024 * 1024) {
+      if (files.some(function(f) { return f.size > 50 * 1024 * 1024; })) {
         showStatus('File too large. Maximum 50MB.', 'error');
         return;
       }
 
+      // Several selected photos are sent, in order, as pages of one document
       var formData = new FormData();
-      formData.append('file', file);
+      files.forEach(function(f) { formData.append('file', f); });
       formData.append('pin', pin);
 
       progressEl.style.display = 'block';
@@ -950,6 +1016,22 @@ mod tests {
         assert!(!result.contains('\\'));
     }
 
+    // -- Page group staging names ---------------------------------------------
+
+    #[test]
+    fn group_staging_name_round_trips() {
+        let group_id = Uuid::new_v4();
+        let name = group_staging_name(&group_id, 7, "page_2.jpg");
+        assert_eq!(parse_group_staging_name(&name), Some((group_id, 7)));
+    }
+
+    #[test]
+    fn single_upload_name_is_not_a_group() {
+        let name = format!("{}_report.pdf", Uuid::new_v4());
+        assert_eq!(parse_group_staging_name(&name), None);
+        assert_eq!(parse_group_staging_name("group-not-a-uuid-001_x.jpg"), None);
+    }
+
     // -- QR code generation ---------------------------------------------------
 
     #[test]
@@ -1,8 +1,31 @@
 // L4-02: Appointment — Tauri invoke wrappers.
 
 import { invoke } from '@tauri-apps/api/core';
-import type { StoredAppointment } from '$lib/types/appointment';
+import type { StoredAppointment, ChatAttachment } from '$lib/types/appointment';
 
 export async function listAppointments(): Promise<StoredAppointment[]> {
   return invoke<StoredAppointment[]>('list_appointments');
 }
+
+/** EXP-01: Attach a conversation (or selected messages) to an appointment prep. */
+export async function attachConversationToAppointment(
+  appointmentId: string,
+  conversationId: string,
+  messageIds: string[] | null = null,
+): Promise<string> {
+  return invoke<string>('attach_conversation_to_appointment', {
+    appointmentId,
+    conversationId,
+    messageIds,
+  });
+}
+
+export async function listAppointmentChatAttachments(
+  appointmentId: string,
+): Promise<ChatAttachment[]> {
+  return invoke<ChatAttachment[]>('list_appointment_chat_attachments', { appointmentId });
+}
+
+export async function detachConversationFromAppointment(attachmentId: string): Promise<void> {
+  return invoke('detach_conversation_from_appointment', { attachmentId });
+}
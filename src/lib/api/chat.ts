@@ -5,6 +5,8 @@ import type {
   PromptSuggestion,
   ChatQueueSnapshot,
   ChatQueueItem,
+  ChatExportFormat,
+  ChatExportResult,
 } from '$lib/types/chat';
 
 export async function startConversation(): Promise<string> {
@@ -55,3 +57,12 @@ export async function setMessageFeedback(
 export async function getPromptSuggestions(): Promise<PromptSuggestion[]> {
   return invoke<PromptSuggestion[]>('get_prompt_suggestions');
 }
+
+/** EXP-01: Export a conversation (or selected messages) with its sources. */
+export async function exportConversation(
+  conversationId: string,
+  format: ChatExportFormat,
+  messageIds: string[] | null = null,
+): Promise<ChatExportResult> {
+  return invoke<ChatExportResult>('export_conversation', { conversationId, messageIds, format });
+}
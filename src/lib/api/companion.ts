@@ -18,6 +18,29 @@ export interface EnrichedGrant {
 	grantee_name: string;
 	access_level: string;
 	granted_at: string;
+	/** GRANT-01: Shared categories; null = everything. */
+	categories: GrantCategory[] | null;
+	/** GRANT-01: RFC 3339 UTC expiry; null = never. */
+	expires_at: string | null;
+}
+
+/** GRANT-01: Entity categories a grant can be limited to. */
+export type GrantCategory =
+	| 'medications'
+	| 'allergies'
+	| 'labs'
+	| 'immunizations'
+	| 'appointments'
+	| 'alerts'
+	| 'timeline'
+	| 'documents'
+	| 'journal'
+	| 'conversations';
+
+/** GRANT-01: Optional limits on a grant. Omitted fields mean no limit. */
+export interface GrantLimits {
+	categories?: GrantCategory[];
+	expiresAt?: string;
 }
 
 // ── Companion unlock (E6) ─────────────────────────────────
@@ -50,12 +73,31 @@ export async function listGrantsToMe(): Promise<EnrichedGrant[]> {
 export async function grantProfileAccess(
 	granterProfileId: string,
 	granteeProfileId: string,
-	accessLevel: 'full' | 'read_only'
+	accessLevel: 'full' | 'read_only',
+	limits: GrantLimits = {}
 ): Promise<void> {
 	return invoke<void>('grant_profile_access', {
 		granterProfileId,
 		granteeProfileId,
-		accessLevel
+		accessLevel,
+		categories: limits.categories ?? null,
+		expiresAt: limits.expiresAt ?? null
+	});
+}
+
+/** GRANT-01: Set a paired device's level, categories and expiry for a profile. */
+export async function setDeviceProfileAccess(
+	deviceId: string,
+	profileId: string,
+	accessLevel: 'full' | 'read_only',
+	limits: GrantLimits = {}
+): Promise<void> {
+	return invoke<void>('set_device_profile_access', {
+		deviceId,
+		profileId,
+		accessLevel,
+		categories: limits.categories ?? null,
+		expiresAt: limits.expiresAt ?? null
 	});
 }
 
@@ -0,0 +1,45 @@
+// EMC-01: Emergency medical ID — Tauri invoke wrappers.
+
+import { invoke } from '@tauri-apps/api/core';
+import type {
+  EmergencyCard,
+  EmergencyCardExportResult,
+  EmergencyContact,
+  EmergencyContactInput,
+  EmergencyField,
+} from '$lib/types/emergency';
+
+/** Summary plus signed QR. Sensitive records only when explicitly included. */
+export async function getEmergencyCard(includeSensitive = false): Promise<EmergencyCard> {
+  return invoke<EmergencyCard>('get_emergency_card', { includeSensitive });
+}
+
+/** Writes the printable wallet card PDF to the profile's exports folder. */
+export async function exportEmergencyCardPdf(
+  includeSensitive = false,
+): Promise<EmergencyCardExportResult> {
+  return invoke<EmergencyCardExportResult>('export_emergency_card_pdf', { includeSensitive });
+}
+
+export async function listEmergencyContacts(): Promise<EmergencyContact[]> {
+  return invoke<EmergencyContact[]>('list_emergency_contacts');
+}
+
+export async function addEmergencyContact(
+  contact: EmergencyContactInput,
+): Promise<EmergencyContact> {
+  return invoke<EmergencyContact>('add_emergency_contact', { contact });
+}
+
+export async function removeEmergencyContact(contactId: string): Promise<void> {
+  return invoke('remove_emergency_contact', { contactId });
+}
+
+export async function getLockScreenFields(): Promise<EmergencyField[]> {
+  return invoke<EmergencyField[]>('get_lock_screen_fields');
+}
+
+/** Fields shown on the phone lock screen; synced on the next phone sync. */
+export async function setLockScreenFields(fields: EmergencyField[]): Promise<void> {
+  return invoke('set_lock_screen_fields', { fields });
+}
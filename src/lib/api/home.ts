@@ -1,15 +1,17 @@
 import { invoke } from '@tauri-apps/api/core';
 import type { HomeData, DocumentCard, RecentSymptomCard, ExtractionSuggestion } from '$lib/types/home';
 
-export async function getHomeData(): Promise<HomeData> {
-  return invoke<HomeData>('get_home_data');
+/** SENS-01: Sensitive documents are left out unless `includeSensitive` is set. */
+export async function getHomeData(includeSensitive = false): Promise<HomeData> {
+  return invoke<HomeData>('get_home_data', { includeSensitive });
 }
 
 export async function getMoreDocuments(
   offset: number,
   limit: number,
+  includeSensitive = false,
 ): Promise<DocumentCard[]> {
-  return invoke<DocumentCard[]>('get_more_documents', { offset, limit });
+  return invoke<DocumentCard[]>('get_more_documents', { offset, limit, includeSensitive });
 }
 
 export async function dismissAlert(
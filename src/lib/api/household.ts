@@ -0,0 +1,9 @@
+// HH-01: Household overview — Tauri invoke wrappers.
+
+import { invoke } from '@tauri-apps/api/core';
+import type { HouseholdOverview } from '$lib/types/household';
+
+/** Merged alerts, appointments, vaccines, screenings and refills across unlocked profiles. */
+export async function getHouseholdOverview(): Promise<HouseholdOverview> {
+  return invoke<HouseholdOverview>('get_household_overview');
+}
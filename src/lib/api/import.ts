@@ -1,8 +1,9 @@
 /** E2E-B01 & B02: Document Import & Processing API — Tauri IPC wrappers. */
 
 import { invoke } from '@tauri-apps/api/core';
-import type { ImportResult, ProcessingOutcome } from '$lib/types/import';
+import type { ImportResult, ProcessingOutcome, SavedPdfPassword } from '$lib/types/import';
 import type { QueueSnapshot } from '$lib/types/import-queue';
+import type { InboxActivity, InboxConfig } from '$lib/types/inbox';
 
 /** Import a single document from a local file path (staging only). */
 export async function importDocument(filePath: string): Promise<ImportResult> {
@@ -29,14 +30,17 @@ export async function processDocumentsBatch(filePaths: string[]): Promise<Proces
 // ---------------------------------------------------------------------------
 
 /** Enqueue files for import. Returns job IDs.
- * UC-01: `documentType` bypasses LLM classification when provided. */
+ * UC-01: `documentType` bypasses LLM classification when provided.
+ * PWD-01: `pdfPassword` is tried first on password-protected PDFs. */
 export async function enqueueImports(
   filePaths: string[],
   documentType?: string,
+  pdfPassword?: string,
 ): Promise<string[]> {
   return invoke<string[]>('enqueue_imports', {
     filePaths,
     documentType: documentType ?? null,
+    pdfPassword: pdfPassword ?? null,
   });
 }
 
@@ -50,9 +54,40 @@ export async function cancelImport(jobId: string): Promise<void> {
   return invoke<void>('cancel_import', { jobId });
 }
 
-/** Retry a failed import job. Returns new job ID. */
-export async function retryImport(jobId: string): Promise<string> {
-  return invoke<string>('retry_import', { jobId });
+/** Retry a failed import job. Returns new job ID.
+ * PWD-01: `pdfPassword` replaces the password of a protected PDF job. */
+export async function retryImport(jobId: string, pdfPassword?: string): Promise<string> {
+  return invoke<string>('retry_import', { jobId, pdfPassword: pdfPassword ?? null });
+}
+
+/** PWD-01: List the profile's saved PDF passwords (labels only). */
+export async function getPdfPasswords(): Promise<SavedPdfPassword[]> {
+  return invoke<SavedPdfPassword[]>('get_pdf_passwords');
+}
+
+/** PWD-01: Save a PDF password under a label. The value is stored as typed. */
+export async function addPdfPassword(label: string, password: string): Promise<SavedPdfPassword> {
+  return invoke<SavedPdfPassword>('add_pdf_password', { label, password });
+}
+
+/** PWD-01: Remove a saved PDF password. */
+export async function removePdfPassword(passwordId: string): Promise<void> {
+  return invoke<void>('remove_pdf_password', { passwordId });
+}
+
+/** INB-01: Get the profile's watched inbox folder configuration. */
+export async function getInboxConfig(): Promise<InboxConfig> {
+  return invoke<InboxConfig>('get_inbox_config');
+}
+
+/** INB-01: Save the inbox folder configuration. Returns the saved config. */
+export async function setInboxConfig(config: InboxConfig): Promise<InboxConfig> {
+  return invoke<InboxConfig>('set_inbox_config', { config });
+}
+
+/** INB-01: Recent inbox activity, newest first. */
+export async function getInboxActivity(): Promise<InboxActivity[]> {
+  return invoke<InboxActivity[]>('get_inbox_activity');
 }
 
 /** Delete a terminal import job from the queue. */
@@ -1,7 +1,13 @@
 /** L3-06: Me Screen API — single IPC call for health overview. */
 
 import { invoke } from '@tauri-apps/api/core';
-import type { MeOverview, VitalTrendPoint } from '$lib/types/me';
+import type {
+	CsvMapping,
+	MeOverview,
+	VitalsFormat,
+	VitalsImportSummary,
+	VitalTrendPoint,
+} from '$lib/types/me';
 
 /** ME-04: `lang` is the UI locale — ensures backend labels match display language. */
 export async function getMeOverview(lang: string): Promise<MeOverview> {
@@ -23,6 +29,19 @@ export async function recordVitalSign(
 	});
 }
 
+/** VIT-01: Import an Apple Health, Google Fit, Health Connect or CSV vitals export. */
+export async function importVitals(
+	path: string,
+	format?: VitalsFormat | null,
+	csvMapping?: CsvMapping | null,
+): Promise<VitalsImportSummary> {
+	return invoke('import_vitals', {
+		path,
+		format: format ?? null,
+		csvMapping: csvMapping ?? null,
+	});
+}
+
 /** ME-06: Record a screening or vaccination date. */
 export async function recordScreening(
 	screeningKey: string,
@@ -29,8 +29,14 @@ export async function confirmReview(
   documentId: string,
   corrections: FieldCorrection[],
   excludedEntities: ExcludedEntity[] = [],
+  sensitive: boolean | null = null,
 ): Promise<ReviewConfirmResult> {
-  return invoke<ReviewConfirmResult>('confirm_review', { documentId, corrections, excludedEntities });
+  return invoke<ReviewConfirmResult>('confirm_review', {
+    documentId,
+    corrections,
+    excludedEntities,
+    sensitive,
+  });
 }
 
 export async function rejectReview(
@@ -0,0 +1,28 @@
+// SENS-01: Sensitive-record tagging — Tauri invoke wrappers.
+
+import { invoke } from '@tauri-apps/api/core';
+
+export type SensitiveEntity = 'medication' | 'lab_result' | 'diagnosis' | 'allergy' | 'procedure';
+
+export async function setDocumentSensitivity(
+  documentId: string,
+  sensitive: boolean,
+): Promise<void> {
+  return invoke('set_document_sensitivity', { documentId, sensitive });
+}
+
+export async function setEntitySensitivity(
+  entityType: SensitiveEntity,
+  entityId: string,
+  sensitive: boolean,
+): Promise<void> {
+  return invoke('set_entity_sensitivity', { entityType, entityId, sensitive });
+}
+
+/** Lets chat read sensitive records in this conversation only. */
+export async function setConversationSensitiveContext(
+  conversationId: string,
+  includeSensitive: boolean,
+): Promise<void> {
+  return invoke('set_conversation_sensitive_context', { conversationId, includeSensitive });
+}
@@ -111,10 +111,11 @@ class ImportQueueStore {
   }
 
   /** Enqueue files for import.
-   * UC-01: `documentType` bypasses LLM classification when provided. */
-  async enqueue(filePaths: string[], documentType?: string): Promise<string[]> {
+   * UC-01: `documentType` bypasses LLM classification when provided.
+   * PWD-01: `pdfPassword` is tried first on password-protected PDFs. */
+  async enqueue(filePaths: string[], documentType?: string, pdfPassword?: string): Promise<string[]> {
     try {
-      const jobIds = await enqueueImports(filePaths, documentType);
+      const jobIds = await enqueueImports(filePaths, documentType, pdfPassword);
       await this.refresh();
       return jobIds;
     } catch (e) {
@@ -129,10 +130,11 @@ class ImportQueueStore {
     await this.refresh();
   }
 
-  /** Retry a failed job. Returns new job ID. */
-  async retry(jobId: string): Promise<string | null> {
+  /** Retry a failed job. Returns new job ID.
+   * PWD-01: `pdfPassword` replaces the password of a protected PDF job. */
+  async retry(jobId: string, pdfPassword?: string): Promise<string | null> {
     try {
-      const newId = await retryImport(jobId);
+      const newId = await retryImport(jobId, pdfPassword);
       await this.refresh();
       return newId;
     } catch (e) {
@@ -9,3 +9,13 @@ export interface StoredAppointment {
   prep_generated: boolean;
   has_post_notes: boolean;
 }
+
+/** EXP-01: A conversation attached to an appointment prep. */
+export interface ChatAttachment {
+  id: string;
+  appointment_id: string;
+  conversation_id: string;
+  conversation_title: string;
+  message_ids: string[] | null;
+  attached_at: string;
+}
@@ -36,7 +36,7 @@ export type StreamChunkPayload =
   | { type: 'Token'; text: string }
   | { type: 'Citation'; citation: CitationView }
   | { type: 'GuidelineCitations'; citations: GuidelineCitationView[] }
-  | { type: 'Done'; full_text: string; confidence: number; boundary_check: string; grounding: string }
+  | { type: 'Done'; full_text: string; confidence: number; boundary_check: string; grounding: string; deterministic?: boolean }
   | { type: 'Error'; message: string };
 
 export interface ChatStreamEvent {
@@ -81,3 +81,12 @@ export interface ChatQueueSnapshot {
   items: ChatQueueItem[];
   is_processing: boolean;
 }
+
+// EXP-01: Chat export (PDF/Markdown) and appointment attachments
+
+export type ChatExportFormat = 'pdf' | 'markdown';
+
+export interface ChatExportResult {
+  path: string;
+  phi_warning: string;
+}
@@ -59,7 +59,8 @@ export type AlertDetail =
 	| { Temporal: TemporalDetail }
 	| { Allergy: AllergyDetail }
 	| { Dose: DoseDetail }
-	| { Critical: CriticalDetail };
+	| { Critical: CriticalDetail }
+	| { LabConflict: LabConflictDetail };
 
 export interface PrescriberRef {
 	professional_id: string;
@@ -167,6 +168,19 @@ export interface CriticalDetail {
 	document_id: string;
 }
 
+/** REC-01: Two documents report different values for the same test and date. */
+export interface LabConflictDetail {
+	test_name: string;
+	collection_date: string;
+	unit: string;
+	value_a: number;
+	lab_result_id_a: string;
+	document_id_a: string;
+	value_b: number;
+	lab_result_id_b: string;
+	document_id_b: string;
+}
+
 export type EmergencyActionType = 'LabCritical' | 'AllergyMatch' | 'Other';
 
 export interface EmergencyAction {
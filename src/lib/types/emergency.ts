@@ -0,0 +1,65 @@
+// EMC-01: Emergency medical ID — TypeScript interfaces matching Rust backend types.
+
+export type EmergencyField =
+  | 'name'
+  | 'date_of_birth'
+  | 'blood_type'
+  | 'allergies'
+  | 'medications'
+  | 'diagnoses'
+  | 'contacts';
+
+export const EMERGENCY_FIELDS: EmergencyField[] = [
+  'name', 'date_of_birth', 'blood_type', 'allergies', 'medications', 'diagnoses', 'contacts'
+];
+
+export interface EmergencyContact {
+  id: string;
+  name: string;
+  relationship: string | null;
+  phone: string;
+}
+
+export interface EmergencyContactInput {
+  name: string;
+  relationship: string | null;
+  phone: string;
+}
+
+export interface EmergencyAllergy {
+  allergen: string;
+  severity: string;
+  reaction: string | null;
+}
+
+export interface EmergencyMedication {
+  name: string;
+  dose: string;
+  frequency: string;
+}
+
+export interface EmergencySummary {
+  profile_name: string;
+  date_of_birth: string | null;
+  /** Display label, e.g. "O+". */
+  blood_type: string | null;
+  allergies: EmergencyAllergy[];
+  medications: EmergencyMedication[];
+  diagnoses: string[];
+  contacts: EmergencyContact[];
+  generated_at: string;
+}
+
+export interface EmergencyCard {
+  summary: EmergencySummary;
+  /** Signed payload encoded in the QR code. */
+  qr_payload: string;
+  qr_svg: string;
+  key_fingerprint: string;
+  lock_screen_fields: EmergencyField[];
+}
+
+export interface EmergencyCardExportResult {
+  path: string;
+  phi_warning: string;
+}
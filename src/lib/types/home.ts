@@ -29,6 +29,8 @@ export interface DocumentCard {
   status: DocumentLifecycleStatus;
   error_message: string | null;
   entity_summary: EntitySummary;
+  /** SENS-01: Only present when sensitive documents were requested. */
+  sensitive?: boolean;
 }
 
 export interface ProfileStats {
@@ -0,0 +1,46 @@
+// HH-01: Household overview — TypeScript interfaces matching Rust backend types.
+
+export type HouseholdItemKind =
+  | 'critical_alert'
+  | 'appointment'
+  | 'immunization_due'
+  | 'screening_due'
+  | 'medication_refill';
+
+export type HouseholdPriority = 'critical' | 'attention' | 'info';
+
+/** One item of the merged overview, tagged with its profile. */
+export interface HouseholdItem {
+  profile_id: string;
+  profile_name: string;
+  color_index: number | null;
+  kind: HouseholdItemKind;
+  priority: HouseholdPriority;
+  title: string;
+  detail: string | null;
+  /** Machine status: overdue, due, upcoming, ends_soon, ended, expired, never_done, ... */
+  status: string | null;
+  date: string | null;
+  entity_id: string | null;
+}
+
+export interface HouseholdMember {
+  profile_id: string;
+  profile_name: string;
+  color_index: number | null;
+  access_level: 'full' | 'read_only';
+  item_count: number;
+}
+
+export interface SkippedProfile {
+  profile_id: string;
+  profile_name: string;
+  reason: 'access_denied' | 'unavailable';
+}
+
+export interface HouseholdOverview {
+  members: HouseholdMember[];
+  items: HouseholdItem[];
+  skipped: SkippedProfile[];
+  generated_at: string;
+}
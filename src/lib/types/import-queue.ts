@@ -11,7 +11,7 @@ export type ImportJobState =
   | 'Cancelled';
 
 /** UC-01: User-selected document type at import time. */
-export type UserDocumentType = 'lab_report' | 'prescription' | 'medical_image';
+export type UserDocumentType = 'lab_report' | 'prescription' | 'vaccination_record' | 'medical_image';
 
 export interface ImportQueueItem {
   id: string;
@@ -97,3 +97,9 @@ export interface ProcessingBatchProgressEvent {
   file_name: string;
   stage: string;
 }
+
+/** PWD-01: A saved PDF password, identified by label. The value never leaves the backend. */
+export interface SavedPdfPassword {
+  id: string;
+  label: string;
+}
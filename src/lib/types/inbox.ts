@@ -0,0 +1,20 @@
+/** INB-01: Watched inbox folder types — mirrors Rust inbox_watcher.rs. */
+
+/** What happens to an inbox file once it is imported. */
+export type InboxDisposal = 'move' | 'secure_delete';
+
+export interface InboxConfig {
+  enabled: boolean;
+  /** Absolute path of the watched folder. */
+  folder: string | null;
+  after_import: InboxDisposal;
+}
+
+export type InboxAction = 'queued' | 'duplicate' | 'possible_duplicate' | 'moved' | 'deleted' | 'skipped' | 'error';
+
+export interface InboxActivity {
+  at: string;
+  filename: string;
+  action: InboxAction;
+  detail: string | null;
+}
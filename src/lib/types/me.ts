@@ -97,4 +97,46 @@ export interface CompletedDose {
 export interface VitalTrendPoint {
 	value: number;
 	recorded_at: string;
+	/** GRW-01: Growth-chart position (child profiles only). */
+	growth?: GrowthPercentile;
+}
+
+/** GRW-01: WHO/CDC growth-chart position with 3rd/50th/97th reference curves. */
+export interface GrowthPercentile {
+	age_months: number;
+	z_score: number;
+	percentile: number;
+	p3: number;
+	p50: number;
+	p97: number;
+}
+
+/** VIT-01: Wearable / home-device export formats. */
+export type VitalsFormat = 'apple_health' | 'google_fit' | 'health_connect' | 'csv';
+
+/** VIT-01: Maps one CSV column (two for blood pressure) to a vital type. */
+export interface CsvColumn {
+	column: string;
+	vital_type: string;
+	unit?: string | null;
+	diastolic_column?: string | null;
+}
+
+/** VIT-01: User-supplied description of a CSV vitals export. */
+export interface CsvMapping {
+	timestamp_column: string;
+	timestamp_format?: string | null;
+	delimiter?: string | null;
+	source_name?: string | null;
+	columns: CsvColumn[];
+}
+
+/** VIT-01: Counts reported after a vitals import. */
+export interface VitalsImportSummary {
+	format: VitalsFormat;
+	parsed: number;
+	skipped: number;
+	summarized_days: number;
+	inserted: number;
+	duplicates: number;
 }
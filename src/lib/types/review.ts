@@ -12,6 +12,20 @@ export interface ReviewData {
   extracted_fields: ExtractedField[];
   plausibility_warnings: PlausibilityWarning[];
   overall_confidence: number;
+  /** INJ-01: Source text contained instructions aimed at the AI. */
+  suspicious_content?: boolean;
+  /** SENS-01: Document is already flagged sensitive. */
+  sensitive?: boolean;
+  /** SENS-01: Why the document looks sensitive, if it does. */
+  sensitivity_suggestion?: SensitivitySuggestion | null;
+}
+
+/** SENS-01: Category behind a sensitivity suggestion. */
+export type SensitivityCategory = 'mental_health' | 'sexual_health' | 'genetic' | 'substance_use';
+
+export interface SensitivitySuggestion {
+  category: SensitivityCategory;
+  matched: string;
 }
 
 export interface ExtractedField {
@@ -33,6 +47,7 @@ export type EntityCategory =
   | 'Allergy'
   | 'Procedure'
   | 'Referral'
+  | 'Immunization'
   | 'Professional'
   | 'Date';
 
@@ -70,6 +85,7 @@ export interface EntitiesStoredSummary {
   procedures: number;
   referrals: number;
   instructions: number;
+  immunizations: number;
 }
 
 export interface ReviewRejectResult {
@@ -104,7 +120,7 @@ export const SECTIONS_BY_DOC_TYPE: Record<string, EntityCategory[]> = {
   'Pharmacy Record': ['Medication', 'Professional', 'Date'],
   'Other': [
     'Medication', 'LabResult', 'Diagnosis', 'Allergy',
-    'Procedure', 'Referral', 'Professional', 'Date',
+    'Procedure', 'Referral', 'Immunization', 'Professional', 'Date',
   ],
 };
 
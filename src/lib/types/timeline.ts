@@ -33,7 +33,7 @@ export interface TimelineEvent {
 export type EventMetadata =
   | { kind: 'Medication'; generic_name: string; brand_name: string | null; dose: string; frequency: string; status: string; reason: string | null; route: string | null; frequency_type: string | null; is_otc: boolean | null; condition: string | null; administration_instructions: string | null }
   | { kind: 'DoseChange'; generic_name: string; old_dose: string | null; new_dose: string; old_frequency: string | null; new_frequency: string | null; reason: string | null }
-  | { kind: 'Lab'; test_name: string; value: number | null; value_text: string | null; unit: string | null; reference_low: number | null; reference_high: number | null; abnormal_flag: string }
+  | { kind: 'Lab'; test_name: string; value: number | null; value_text: string | null; unit: string | null; reference_low: number | null; reference_high: number | null; abnormal_flag: string; change?: LabChange }
   | { kind: 'Symptom'; category: string; specific: string; severity: number; body_region: string | null; still_active: boolean; duration: string | null; character: string | null; aggravating: string | null; relieving: string | null; timing_pattern: string | null; resolved_date: string | null; notes: string | null; source: string | null; related_medication_id: string | null; related_diagnosis_id: string | null }
   | { kind: 'Procedure'; name: string; facility: string | null; outcome: string | null; follow_up_required: boolean }
   | { kind: 'Appointment'; appointment_type: string; professional_specialty: string | null; pre_summary_generated: boolean | null; post_notes: string | null }
@@ -42,6 +42,16 @@ export type EventMetadata =
   | { kind: 'CoherenceAlert'; alert_type: string; severity: string; patient_message: string | null; entity_ids: string[]; dismissed: boolean; two_step_confirmed: boolean }
   | { kind: 'VitalSign'; vital_type: string; value_primary: number; value_secondary: number | null; unit: string; notes: string | null; source: string };
 
+/** LTR-01: Change since the previous result of the same analyte. */
+export interface LabChange {
+  previous_value: number;
+  previous_date: string;
+  delta: number;
+  delta_pct: number;
+  rcv_pct: number;
+  significant: boolean;
+}
+
 export interface TimelineCorrelation {
   source_id: string;
   target_id: string;
@@ -56,6 +66,8 @@ export interface TimelineFilter {
   date_to: string | null;
   since_appointment_id: string | null;
   include_dismissed_alerts: boolean | null;
+  /** SENS-01: Show records flagged sensitive (hidden by default). */
+  include_sensitive?: boolean | null;
 }
 
 export interface TimelineData {